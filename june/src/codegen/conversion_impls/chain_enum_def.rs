@@ -126,6 +126,14 @@ impl JuneConverter {
 
         let name = self.cache_string_value(StringValue(expr.name.value.clone()));
 
+        let tag = match &*expr.nominal {
+            jnode::OptNominalKw::None => None,
+            jnode::OptNominalKw::Some(_) => {
+                let tag = mint_ind_tag(&self.source_id, &expr.name.value, expr.name.start.0);
+                Some(self.cache_string_value(tag))
+            }
+        };
+
         let (_, index_types) = self.convert_optional_typed_nonfun_param_defs_to_context_extension(
             expr.indices.to_std_option(),
             context,
@@ -141,6 +149,7 @@ impl JuneConverter {
         Ok(znode::Ind {
             universe: universe_level,
             name,
+            tag,
             index_types,
             vcon_defs,
             aux_data: (),
@@ -176,7 +185,9 @@ impl JuneConverter {
         let index_args =
             self.convert_optional_exprs(case.index_args.to_std_option(), extended_context)?;
 
+        let name = self.cache_string_value(StringValue(case.name.value.clone()));
         Ok(znode::VconDef {
+            name: Some(name),
             param_types,
             index_args,
             aux_data: (),
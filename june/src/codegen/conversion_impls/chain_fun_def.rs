@@ -41,12 +41,14 @@ impl JuneConverter {
 
         let param_types = self.cache_expr_vec(param_types);
 
-        Ok(self.cache_fun(znode::Fun {
+        let fun = znode::Fun {
             decreasing_index,
             param_types,
             return_type,
             return_val,
             aux_data: (),
-        }))
+        };
+        self.record_name_hints(&fun, extension.iter().chain(&recursive_fun_param_singleton));
+        Ok(self.cache_fun(fun))
     }
 }
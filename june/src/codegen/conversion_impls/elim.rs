@@ -0,0 +1,28 @@
+use super::*;
+
+use zoc::eliminator::get_eliminator;
+
+impl JuneConverter {
+    pub(crate) fn convert_elim(
+        &mut self,
+        expr: &jnode::Elim,
+        context: Context,
+    ) -> Result<znode::Expr, SemanticError> {
+        let enum_ = self.convert(&expr.enum_, context)?;
+
+        // The target may be a parameterized enum applied to its params,
+        // so we normalize it before checking whether it is an ind.
+        let ind = match self.zo_typechecker.evaluator.eval(enum_).into_raw() {
+            znode::Expr::Ind(ind) => ind,
+            _ => return Err(SemanticError::ElimTargetIsNotEnum(expr.clone())),
+        };
+        let motive_universe = Universe {
+            level: UniverseLevel(expr.motive_universe.level),
+            erasable: expr.motive_universe.erasable,
+        };
+
+        let eliminator = get_eliminator(ind, motive_universe)
+            .map_err(|err| SemanticError::CannotGenerateEliminator(expr.clone(), err))?;
+        Ok(self.cache_expr(eliminator.term))
+    }
+}
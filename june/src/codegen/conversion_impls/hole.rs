@@ -0,0 +1,8 @@
+use super::*;
+
+impl JuneConverter {
+    pub(crate) fn convert_hole(&mut self, _: Context) -> Result<znode::Expr, SemanticError> {
+        let converted_leaf = self.cache_hole(znode::HoleNode { aux_data: () });
+        Ok(converted_leaf)
+    }
+}
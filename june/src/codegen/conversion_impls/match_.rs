@@ -17,30 +17,68 @@ impl JuneConverter {
 
         let return_type = self.get_match_return_type(expr, context)?;
 
-        let cases = self.convert_match_cases(&expr.cases, context)?;
+        let cases = self.convert_match_cases(expr, context)?;
 
-        let converted_leaf = self.cache_match(znode::Match {
+        let match_ = znode::Match {
             matchee,
             return_type_arity,
             return_type,
             cases,
             aux_data: (),
-        });
+        };
+        self.record_name_hints(&match_, &extension);
+        let converted_leaf = self.cache_match(match_);
         Ok(converted_leaf)
     }
 
-    // TODO: Check that the correct cases are covered.
-    // Currently, case names are ignored.
-    // For example, you can legally match a nat against `b` and `a(foo)`,
-    // since that is isomorphic to `zero` and `succ(pred)` (assuming alphabetical sorting).
+    /// Orders the cases by the vcon index of the vcon they name,
+    /// where the names are looked up in the matchee type's vcon defs.
     fn convert_match_cases(
         &mut self,
-        cases: &jnode::ZeroOrMoreMatchCases,
+        expr: &jnode::Match,
         context: Context,
     ) -> Result<RcHashedVec<znode::MatchCase>, SemanticError> {
-        let mut cases = cases.to_vec();
-        cases.sort_unstable_by(|a, b| a.name.value.cmp(&b.name.value));
-        self.convert_ordered_match_cases(&cases, context)
+        let matchee_type_ind = self.get_matchee_type_ind(&expr.matchee, context)?;
+        let vcon_defs = &matchee_type_ind.hashee.vcon_defs.hashee;
+
+        let mut ordered_cases: Vec<Option<&jnode::MatchCase>> = vec![None; vcon_defs.len()];
+        for case in expr.cases.to_vec() {
+            let Some(vcon_index) = vcon_defs.iter().position(|def| {
+                def.name
+                    .as_ref()
+                    .is_some_and(|name| name.0 == case.name.value)
+            }) else {
+                return Err(SemanticError::MatchCaseHasUnknownName(
+                    case.clone(),
+                    matchee_type_ind,
+                ));
+            };
+
+            if let Some(original) = ordered_cases[vcon_index] {
+                return Err(SemanticError::DuplicateMatchCases(
+                    original.clone(),
+                    case.clone(),
+                ));
+            }
+            ordered_cases[vcon_index] = Some(case);
+        }
+
+        let missing_case_indices: Vec<usize> = ordered_cases
+            .iter()
+            .enumerate()
+            .filter(|(_, case)| case.is_none())
+            .map(|(vcon_index, _)| vcon_index)
+            .collect();
+        if !missing_case_indices.is_empty() {
+            return Err(SemanticError::MissingMatchCases(
+                expr.clone(),
+                matchee_type_ind,
+                missing_case_indices,
+            ));
+        }
+
+        let ordered_cases: Vec<&jnode::MatchCase> = ordered_cases.into_iter().flatten().collect();
+        self.convert_ordered_match_cases(&ordered_cases, context)
     }
 
     fn convert_ordered_match_cases(
@@ -67,11 +105,13 @@ impl JuneConverter {
 
         let return_val = self.convert(&case.return_val, context_with_params)?;
 
-        Ok(znode::MatchCase {
+        let case = znode::MatchCase {
             arity,
-            return_val,
+            return_val: Some(return_val),
             aux_data: (),
-        })
+        };
+        self.record_name_hints(&case, &extension);
+        Ok(case)
     }
 
     fn get_match_return_type(
@@ -4,7 +4,9 @@ mod chain_enum_def;
 mod chain_fun_def;
 mod chain_var_def;
 
+mod elim;
 mod for_;
+mod hole;
 mod match_;
 mod universe;
 mod var_or_app;
@@ -26,6 +28,8 @@ impl JuneConverter {
             jnode::Expr::For(e) => self.convert_for(e, context),
             jnode::Expr::VarOrApp(e) => self.convert_var_or_app(e, context),
             jnode::Expr::Universe(e) => self.convert_universe(e, context),
+            jnode::Expr::Hole(_) => self.convert_hole(context),
+            jnode::Expr::Elim(e) => self.convert_elim(e, context),
         }
     }
 }
@@ -115,6 +119,14 @@ impl JuneConverter {
         &mut self,
         context: Context,
     ) -> Vec<znode::NormalForm> {
+        // Nondeb entries are substituted during conversion,
+        // so a context without deb entries corresponds to the empty tcon.
+        if !context_has_deb_entries(context) {
+            return vec![];
+        }
+
+        // TODO: Handle deb entries.
+        // This requires the entries' types, which the context does not store.
         todo!()
     }
 
@@ -155,6 +167,15 @@ impl JuneConverter {
     // }
 }
 
+fn context_has_deb_entries(context: Context) -> bool {
+    match context {
+        Context::Base(entries) => entries.iter().any(|entry| entry.is_deb),
+        Context::Snoc(rdc, rac) => {
+            context_has_deb_entries(*rdc) || rac.iter().any(|entry| entry.is_deb)
+        }
+    }
+}
+
 impl JuneConverter {
     pub fn convert_mod_and_typecheck(
         &mut self,
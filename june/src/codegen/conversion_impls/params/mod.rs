@@ -181,12 +181,21 @@ impl JuneConverter {
         matchee: &jnode::Expr,
         context: Context,
     ) -> Result<usize, SemanticError> {
+        let matchee_type_ind = self.get_matchee_type_ind(matchee, context)?;
+        Ok(1 + matchee_type_ind.hashee.index_types.hashee.len())
+    }
+
+    pub(crate) fn get_matchee_type_ind(
+        &mut self,
+        matchee: &jnode::Expr,
+        context: Context,
+    ) -> Result<znode::RcHashed<znode::Ind>, SemanticError> {
         let matchee_type = self.convert_and_typecheck(matchee, context)?.type_;
         match matchee_type.raw() {
-            znode::Expr::Ind(_) => Ok(1),
+            znode::Expr::Ind(ind) => Ok(ind.clone()),
 
             znode::Expr::App(matchee_type_app) => match &matchee_type_app.hashee.callee {
-                znode::Expr::Ind(_) => Ok(1 + matchee_type_app.hashee.args.hashee.len()),
+                znode::Expr::Ind(ind) => Ok(ind.clone()),
 
                 znode::Expr::Vcon(_)
                 | znode::Expr::Match(_)
@@ -194,7 +203,11 @@ impl JuneConverter {
                 | znode::Expr::App(_)
                 | znode::Expr::For(_)
                 | znode::Expr::Deb(_)
-                | znode::Expr::Universe(_) => Err(SemanticError::MatcheeHasUnmatchableType(
+                | znode::Expr::Universe(_)
+                | znode::Expr::Lit(_)
+                | znode::Expr::Prim(_)
+                | znode::Expr::Meta(_)
+                | znode::Expr::Hole(_) => Err(SemanticError::MatcheeHasUnmatchableType(
                     matchee.clone(),
                     matchee_type,
                 )),
@@ -206,7 +219,11 @@ impl JuneConverter {
             | znode::Expr::App(_)
             | znode::Expr::For(_)
             | znode::Expr::Deb(_)
-            | znode::Expr::Universe(_) => Err(SemanticError::MatcheeHasUnmatchableType(
+            | znode::Expr::Universe(_)
+            | znode::Expr::Lit(_)
+            | znode::Expr::Prim(_)
+            | znode::Expr::Meta(_)
+            | znode::Expr::Hole(_) => Err(SemanticError::MatcheeHasUnmatchableType(
                 matchee.clone(),
                 matchee_type,
             )),
@@ -13,7 +13,9 @@ mod jnode {
 
 use zoc::syntax_tree::replace_debs::{DebUpshifter, ReplaceDebs};
 use zoc::{
-    hash::{Digest, GetDigest, NoHashHashMap},
+    hash::{Hashed, Interner, Rc},
+    pretty_print::NameHints,
+    syntax_tree::ast::mint_ind_tag,
     syntax_tree::ast::prelude::minimal_ast::{
         rc_hashed as bypass_cache_and_rc_hash, Deb, RcHashedVec, StringValue, Universe,
         UniverseLevel,
@@ -22,7 +24,7 @@ use zoc::{
 
 type ZoError = zoc::typecheck::TypeError<UnitAuxDataFamily>;
 
-use std::{collections::HashSet, rc::Rc};
+use std::collections::HashSet;
 
 mod cache_expr;
 
@@ -32,23 +34,76 @@ pub use context::*;
 mod conversion_impls;
 
 // TODO: Support multifile compilation.
-pub fn june_to_zo(expr: jnode::Expr) -> Result<znode::Expr, SemanticError> {
-    JuneConverter::default().convert(&expr, Context::empty())
+/// `source_id` identifies the source `expr` was parsed from (e.g., its path).
+/// It is folded into the tags of nominal inds,
+/// so that nominal declarations in different sources
+/// get different tags (see `mint_ind_tag`).
+pub fn june_to_zo(expr: jnode::Expr, source_id: &str) -> Result<znode::Expr, SemanticError> {
+    JuneConverter::new(source_id).convert(&expr, Context::empty())
 }
 
-#[derive(Debug, Default)]
+/// Like `june_to_zo`, but also returns the names of the binders in `expr`,
+/// so that the converted expression can be pretty printed
+/// with its original variable names.
+pub fn june_to_zo_with_name_hints(
+    expr: jnode::Expr,
+    source_id: &str,
+) -> Result<(znode::Expr, NameHints), SemanticError> {
+    let mut converter = JuneConverter::new(source_id);
+    let converted_expr = converter.convert(&expr, Context::empty())?;
+    Ok((converted_expr, converter.name_hints))
+}
+
+#[derive(Debug)]
 struct JuneConverter {
-    znode_cache: NoHashHashMap<Digest, znode::Expr>,
-    znode_vec_cache: NoHashHashMap<Digest, RcHashedVec<znode::Expr>>,
+    interner: Interner,
     str_val_cache: HashSet<Rc<StringValue>>,
+    name_hints: NameHints,
+    /// See `mint_ind_tag`.
+    source_id: String,
 
     zo_typechecker: zoc::typecheck::TypeChecker,
 }
 
+impl JuneConverter {
+    fn new(source_id: &str) -> Self {
+        // The typechecker shares the converter's interner,
+        // so the nodes it creates are shared with the converted tree.
+        let interner = Interner::new();
+        Self {
+            interner: interner.clone(),
+            str_val_cache: HashSet::new(),
+            name_hints: NameHints::new(),
+            source_id: source_id.to_string(),
+            zo_typechecker: zoc::typecheck::TypeChecker::with_interner(interner),
+        }
+    }
+
+    /// Records the keys of `entries` as the names of the binders
+    /// introduced by `binder`.
+    fn record_name_hints<'a, T: std::hash::Hash>(
+        &mut self,
+        binder: &T,
+        entries: impl IntoIterator<Item = &'a UnshiftedEntry<'a>>,
+    ) {
+        let names = entries
+            .into_iter()
+            .map(|entry| entry.key.to_string())
+            .collect();
+        self.name_hints.insert(Hashed::new(binder).digest, names);
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum SemanticError {
     VarNotDefined(jnode::Ident),
     MultipleDecreasingParams(jnode::FunParamDef, jnode::FunParamDef),
     ConvertedExprHasZoErr(jnode::Expr, znode::Expr, ZoError),
     MatcheeHasUnmatchableType(jnode::Expr, znode::NormalForm),
+    MatchCaseHasUnknownName(jnode::MatchCase, znode::RcHashed<znode::Ind>),
+    DuplicateMatchCases(jnode::MatchCase, jnode::MatchCase),
+    /// The `Vec<usize>` holds the vcon indices of the missing cases.
+    MissingMatchCases(jnode::Match, znode::RcHashed<znode::Ind>, Vec<usize>),
+    ElimTargetIsNotEnum(jnode::Elim),
+    CannotGenerateEliminator(jnode::Elim, zoc::eliminator::EliminatorError),
 }
@@ -92,6 +92,7 @@ impl Lexer<'_> {
             ':' => self.out.push(Token::Colon(current_index)),
             ',' => self.out.push(Token::Comma(current_index)),
             '^' => self.out.push(Token::Caret(current_index)),
+            '?' => self.out.push(Token::Question(current_index)),
             '-' => self.state = State::Dash(current_index),
             '/' => self.state = State::Slash(current_index),
             _ => {
@@ -263,6 +264,8 @@ fn parse_word(s: &str, start: ByteIndex) -> Option<Token> {
         "use" => return Some(Token::UseKw(start)),
         "end" => return Some(Token::EndKw(start)),
         "dec" => return Some(Token::DecKw(start)),
+        "elim" => return Some(Token::ElimKw(start)),
+        "nominal" => return Some(Token::NominalKw(start)),
 
         _ => {}
     }
@@ -141,9 +141,27 @@ fn dashes_and_thin_arrows() {
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn holes() {
+    let src = r#"f(?, ?)"#;
+    let actual = lex(src);
+    let expected = Ok(vec![
+        Token::Ident(Ident {
+            value: "f".to_owned(),
+            start: ByteIndex(0),
+        }),
+        Token::LParen(ByteIndex(1)),
+        Token::Question(ByteIndex(2)),
+        Token::Comma(ByteIndex(3)),
+        Token::Question(ByteIndex(5)),
+        Token::RParen(ByteIndex(6)),
+    ]);
+    assert_eq!(expected, actual);
+}
+
 #[test]
 fn keywords() {
-    let src = r#"_ enum enum1 enum33 enum* enum1* enum33* def match For case use end dec Type Type1 Type33 Type* Type1* Type33*"#;
+    let src = r#"_ enum enum1 enum33 enum* enum1* enum33* def match For case use end dec elim nominal Type Type1 Type33 Type* Type1* Type33*"#;
     let actual = lex(src);
     let expected = Ok(vec![
         Token::Underscore(ByteIndex(src.find("_").unwrap())),
@@ -184,6 +202,8 @@ fn keywords() {
         Token::UseKw(ByteIndex(src.find("use").unwrap())),
         Token::EndKw(ByteIndex(src.find("end").unwrap())),
         Token::DecKw(ByteIndex(src.find("dec").unwrap())),
+        Token::ElimKw(ByteIndex(src.find("elim").unwrap())),
+        Token::NominalKw(ByteIndex(src.find("nominal").unwrap())),
         Token::UniverseLiteral(UniverseLiteral {
             level: 0,
             start: ByteIndex(src.find("Type").unwrap()),
@@ -3,7 +3,7 @@
 // You can read more at https://crates.io/crates/kiki
 //
 // This code was generated from a grammar with the following hash:
-// @sha256 cce760b1785d9d05d601d675426a506a380d45dfd56b7483a08e1dd235177dbc
+// @sha256 fe7907593820168991a6550a4800c6b2ae0aa06a96edebb17403e3ac032520c7
 
 // Since this code is automatically generated,
 // some parts may be unidiomatic.
@@ -26,6 +26,7 @@ pub enum Token {
     Dash(crate::token::ByteIndex),
     Caret(crate::token::ByteIndex),
     Underscore(crate::token::ByteIndex),
+    Question(crate::token::ByteIndex),
     EnumKw(crate::token::EnumKw),
     DefKw(crate::token::ByteIndex),
     MatchKw(crate::token::ByteIndex),
@@ -34,6 +35,8 @@ pub enum Token {
     UseKw(crate::token::ByteIndex),
     EndKw(crate::token::ByteIndex),
     DecKw(crate::token::ByteIndex),
+    ElimKw(crate::token::ByteIndex),
+    NominalKw(crate::token::ByteIndex),
     Ident(crate::token::Ident),
     NumberLiteral(crate::token::NumberLiteral),
     StringLiteral(crate::token::StringLiteral),
@@ -85,6 +88,12 @@ pub enum Expr {
     Universe(
         crate::token::UniverseLiteral,
     ),
+    Hole(
+        crate::token::ByteIndex,
+    ),
+    Elim(
+        Box<Elim>,
+    ),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -108,6 +117,7 @@ pub struct ChainEnumDef {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EnumDef {
     pub universe: crate::token::EnumKw,
+    pub nominal: Box<OptNominalKw>,
     pub name: crate::token::Ident,
     pub params: Box<OptParenthesizedNonfunParamDefs>,
     pub indices: Box<OptCaretParenthesizedParamDefs>,
@@ -115,6 +125,14 @@ pub struct EnumDef {
     pub endkw: crate::token::ByteIndex,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OptNominalKw {
+    None,
+    Some(
+        crate::token::ByteIndex,
+    ),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum OptParenthesizedNonfunParamDefs {
     None,
@@ -265,6 +283,13 @@ pub struct Match {
     pub endkw: crate::token::ByteIndex,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Elim {
+    pub elimkw: crate::token::ByteIndex,
+    pub enum_: Box<Expr>,
+    pub motive_universe: crate::token::UniverseLiteral,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum OptMatchReturnTypeClause {
     None,
@@ -452,19 +477,22 @@ enum QuasiterminalKind {
     Dash = 8,
     Caret = 9,
     Underscore = 10,
-    EnumKw = 11,
-    DefKw = 12,
-    MatchKw = 13,
-    ForKw = 14,
-    CaseKw = 15,
-    UseKw = 16,
-    EndKw = 17,
-    DecKw = 18,
-    Ident = 19,
-    NumberLiteral = 20,
-    StringLiteral = 21,
-    UniverseLiteral = 22,
-    Eof = 23,
+    Question = 11,
+    EnumKw = 12,
+    DefKw = 13,
+    MatchKw = 14,
+    ForKw = 15,
+    CaseKw = 16,
+    UseKw = 17,
+    EndKw = 18,
+    DecKw = 19,
+    ElimKw = 20,
+    NominalKw = 21,
+    Ident = 22,
+    NumberLiteral = 23,
+    StringLiteral = 24,
+    UniverseLiteral = 25,
+    Eof = 26,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -476,38 +504,40 @@ enum NonterminalKind {
     VarDef = 4,
     ChainEnumDef = 5,
     EnumDef = 6,
-    OptParenthesizedNonfunParamDefs = 7,
-    ParenthesizedCommaSeparatedNonfunParamDefs = 8,
-    OptComma = 9,
-    CommaSeparatedNonfunParamDefs = 10,
-    NonfunParamDef = 11,
-    OptCaretParenthesizedParamDefs = 12,
-    ZeroOrMoreEnumCases = 13,
-    EnumCase = 14,
-    OptCaretParenthesizedExprs = 15,
-    ParenthesizedCommaSeparatedExprs = 16,
-    CommaSeparatedExprs = 17,
-    ChainFunDef = 18,
-    Def = 19,
-    ParenthesizedCommaSeparatedFunParamDefs = 20,
-    CommaSeparatedFunParamDefs = 21,
-    FunParamDef = 22,
-    OptDecKw = 23,
-    Match = 24,
-    OptMatchReturnTypeClause = 25,
-    MatchReturnTypeClause = 26,
-    ReturnParamClause = 27,
-    CaretParenthesizedCommaSeparatedIdentsOrUnderscores = 28,
-    CommaSeparatedIdentsOrUnderscores = 29,
-    ZeroOrMoreMatchCases = 30,
-    MatchCase = 31,
-    OptParenthesizedCommaSeparatedIdentsOrUnderscores = 32,
-    ParenthesizedCommaSeparatedIdentsOrUnderscores = 33,
-    OptIdent = 34,
-    App = 35,
-    VarOrApp = 36,
-    For = 37,
-    IdentOrUnderscore = 38,
+    OptNominalKw = 7,
+    OptParenthesizedNonfunParamDefs = 8,
+    ParenthesizedCommaSeparatedNonfunParamDefs = 9,
+    OptComma = 10,
+    CommaSeparatedNonfunParamDefs = 11,
+    NonfunParamDef = 12,
+    OptCaretParenthesizedParamDefs = 13,
+    ZeroOrMoreEnumCases = 14,
+    EnumCase = 15,
+    OptCaretParenthesizedExprs = 16,
+    ParenthesizedCommaSeparatedExprs = 17,
+    CommaSeparatedExprs = 18,
+    ChainFunDef = 19,
+    Def = 20,
+    ParenthesizedCommaSeparatedFunParamDefs = 21,
+    CommaSeparatedFunParamDefs = 22,
+    FunParamDef = 23,
+    OptDecKw = 24,
+    Match = 25,
+    Elim = 26,
+    OptMatchReturnTypeClause = 27,
+    MatchReturnTypeClause = 28,
+    ReturnParamClause = 29,
+    CaretParenthesizedCommaSeparatedIdentsOrUnderscores = 30,
+    CommaSeparatedIdentsOrUnderscores = 31,
+    ZeroOrMoreMatchCases = 32,
+    MatchCase = 33,
+    OptParenthesizedCommaSeparatedIdentsOrUnderscores = 34,
+    ParenthesizedCommaSeparatedIdentsOrUnderscores = 35,
+    OptIdent = 36,
+    App = 37,
+    VarOrApp = 38,
+    For = 39,
+    IdentOrUnderscore = 40,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -631,6 +661,14 @@ enum State {
     S116 = 116,
     S117 = 117,
     S118 = 118,
+    S119 = 119,
+    S120 = 120,
+    S121 = 121,
+    S122 = 122,
+    S123 = 123,
+    S124 = 124,
+    S125 = 125,
+    S126 = 126,
 }
 
 enum Node {
@@ -641,6 +679,7 @@ enum Node {
     VarDef(VarDef),
     ChainEnumDef(ChainEnumDef),
     EnumDef(EnumDef),
+    OptNominalKw(OptNominalKw),
     OptParenthesizedNonfunParamDefs(OptParenthesizedNonfunParamDefs),
     ParenthesizedCommaSeparatedNonfunParamDefs(ParenthesizedCommaSeparatedNonfunParamDefs),
     OptComma(OptComma),
@@ -659,6 +698,7 @@ enum Node {
     FunParamDef(FunParamDef),
     OptDecKw(OptDecKw),
     Match(Match),
+    Elim(Elim),
     OptMatchReturnTypeClause(OptMatchReturnTypeClause),
     MatchReturnTypeClause(MatchReturnTypeClause),
     ReturnParamClause(ReturnParamClause),
@@ -684,6 +724,7 @@ enum Node {
     Dash(crate::token::ByteIndex),
     Caret(crate::token::ByteIndex),
     Underscore(crate::token::ByteIndex),
+    Question(crate::token::ByteIndex),
     EnumKw(crate::token::EnumKw),
     DefKw(crate::token::ByteIndex),
     MatchKw(crate::token::ByteIndex),
@@ -692,6 +733,8 @@ enum Node {
     UseKw(crate::token::ByteIndex),
     EndKw(crate::token::ByteIndex),
     DecKw(crate::token::ByteIndex),
+    ElimKw(crate::token::ByteIndex),
+    NominalKw(crate::token::ByteIndex),
     Ident(crate::token::Ident),
     NumberLiteral(crate::token::NumberLiteral),
     StringLiteral(crate::token::StringLiteral),
@@ -775,1194 +818,2142 @@ enum RuleKind {
     R64 = 64,
     R65 = 65,
     R66 = 66,
+    R67 = 67,
+    R68 = 68,
+    R69 = 69,
+    R70 = 70,
+    R71 = 71,
 }
 
 fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
     match rule_kind {
-        RuleKind::R0 => {
-            (
-                Node::Module(Module::Nil),
-                NonterminalKind::Module,
-            )
-        }
-        RuleKind::R1 => {
-            let t1 = Box::new(ModuleItem::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(Module::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::Module(Module::Snoc(
-                    t0,
-                    t1,
-                )),
-                NonterminalKind::Module,
-            )
-        }
-        RuleKind::R2 => {
-            let t0 = Box::new(VarDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::ModuleItem(ModuleItem::VarDef(
-                    t0,
-                )),
-                NonterminalKind::ModuleItem,
-            )
-        }
-        RuleKind::R3 => {
-            let t0 = Box::new(EnumDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::ModuleItem(ModuleItem::EnumDef(
-                    t0,
-                )),
-                NonterminalKind::ModuleItem,
-            )
-        }
-        RuleKind::R4 => {
-            let t0 = Box::new(Def::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::ModuleItem(ModuleItem::FunDef(
-                    t0,
-                )),
-                NonterminalKind::ModuleItem,
-            )
-        }
-        RuleKind::R5 => {
-            let t0 = Box::new(ChainVarDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::VarDef(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R6 => {
-            let t0 = Box::new(ChainEnumDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::EnumDef(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R7 => {
-            let t0 = Box::new(ChainFunDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::FunDef(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R8 => {
-            let t0 = Box::new(Match::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Match(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R9 => {
-            let t0 = Box::new(For::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::For(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R10 => {
-            let t0 = Box::new(VarOrApp::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::VarOrApp(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R11 => {
-            let t0 = nodes.pop().unwrap().try_into_universe_literal_22().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Universe(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R12 => {
-            let next_val_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let standalone_0 = Box::new(VarDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::ChainVarDef(ChainVarDef {
-                    standalone: standalone_0,
-                    next_val: next_val_1,
-                }),
-                NonterminalKind::ChainVarDef,
-            )
-        }
-        RuleKind::R13 => {
-            let val_2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let name_0 = nodes.pop().unwrap().try_into_ident_19().ok().unwrap();
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::VarDef(VarDef {
-                    name: name_0,
-                    val: val_2,
-                }),
-                NonterminalKind::VarDef,
-            )
-        }
-        RuleKind::R14 => {
-            let next_val_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let standalone_0 = Box::new(EnumDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::ChainEnumDef(ChainEnumDef {
-                    standalone: standalone_0,
-                    next_val: next_val_1,
-                }),
-                NonterminalKind::ChainEnumDef,
-            )
-        }
-        RuleKind::R15 => {
-            let endkw_5 = nodes.pop().unwrap().try_into_end_kw_17().ok().unwrap();
-            let cases_4 = Box::new(ZeroOrMoreEnumCases::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let indices_3 = Box::new(OptCaretParenthesizedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let params_2 = Box::new(OptParenthesizedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_1 = nodes.pop().unwrap().try_into_ident_19().ok().unwrap();
-            let universe_0 = nodes.pop().unwrap().try_into_enum_kw_11().ok().unwrap();
-            
-            states.truncate(states.len() - 6);
-            
-            (
-                Node::EnumDef(EnumDef {
-                    universe: universe_0,
-                    name: name_1,
-                    params: params_2,
-                    indices: indices_3,
-                    cases: cases_4,
-                    endkw: endkw_5,
-                }),
-                NonterminalKind::EnumDef,
-            )
-        }
-        RuleKind::R16 => {
-            (
-                Node::OptParenthesizedNonfunParamDefs(OptParenthesizedNonfunParamDefs::None),
-                NonterminalKind::OptParenthesizedNonfunParamDefs,
-            )
-        }
-        RuleKind::R17 => {
-            let t0 = Box::new(ParenthesizedCommaSeparatedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptParenthesizedNonfunParamDefs(OptParenthesizedNonfunParamDefs::Some(
-                    t0,
-                )),
-                NonterminalKind::OptParenthesizedNonfunParamDefs,
-            )
-        }
-        RuleKind::R18 => {
-            let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let params_1 = Box::new(CommaSeparatedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::ParenthesizedCommaSeparatedNonfunParamDefs(ParenthesizedCommaSeparatedNonfunParamDefs {
-                    lparen: lparen_0,
-                    params: params_1,
-                    trailing_comma: trailing_comma_2,
-                    rparen: rparen_3,
-                }),
-                NonterminalKind::ParenthesizedCommaSeparatedNonfunParamDefs,
-            )
-        }
-        RuleKind::R19 => {
-            (
-                Node::OptComma(OptComma::None),
-                NonterminalKind::OptComma,
-            )
-        }
-        RuleKind::R20 => {
-            let t0 = nodes.pop().unwrap().try_into_comma_6().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptComma(OptComma::Some(
-                    t0,
-                )),
-                NonterminalKind::OptComma,
-            )
-        }
-        RuleKind::R21 => {
-            let t0 = Box::new(NonfunParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::CommaSeparatedNonfunParamDefs(CommaSeparatedNonfunParamDefs::One(
-                    t0,
-                )),
-                NonterminalKind::CommaSeparatedNonfunParamDefs,
-            )
-        }
-        RuleKind::R22 => {
-            let t2 = Box::new(NonfunParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let t0 = Box::new(CommaSeparatedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::CommaSeparatedNonfunParamDefs(CommaSeparatedNonfunParamDefs::Snoc(
-                    t0,
-                    t2,
-                )),
-                NonterminalKind::CommaSeparatedNonfunParamDefs,
-            )
-        }
-        RuleKind::R23 => {
-            let type__2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let name_0 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::NonfunParamDef(NonfunParamDef {
-                    name: name_0,
-                    type_: type__2,
-                }),
-                NonterminalKind::NonfunParamDef,
-            )
-        }
-        RuleKind::R24 => {
-            (
-                Node::OptCaretParenthesizedParamDefs(OptCaretParenthesizedParamDefs::None),
-                NonterminalKind::OptCaretParenthesizedParamDefs,
-            )
-        }
-        RuleKind::R25 => {
-            let t1 = Box::new(ParenthesizedCommaSeparatedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::OptCaretParenthesizedParamDefs(OptCaretParenthesizedParamDefs::Some(
-                    t1,
-                )),
-                NonterminalKind::OptCaretParenthesizedParamDefs,
-            )
-        }
-        RuleKind::R26 => {
-            (
-                Node::ZeroOrMoreEnumCases(ZeroOrMoreEnumCases::Nil),
-                NonterminalKind::ZeroOrMoreEnumCases,
-            )
-        }
-        RuleKind::R27 => {
-            let t1 = Box::new(EnumCase::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(ZeroOrMoreEnumCases::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::ZeroOrMoreEnumCases(ZeroOrMoreEnumCases::Snoc(
-                    t0,
-                    t1,
-                )),
-                NonterminalKind::ZeroOrMoreEnumCases,
-            )
-        }
-        RuleKind::R28 => {
-            let index_args_2 = Box::new(OptCaretParenthesizedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let params_1 = Box::new(OptParenthesizedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_0 = nodes.pop().unwrap().try_into_ident_19().ok().unwrap();
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::EnumCase(EnumCase {
-                    name: name_0,
-                    params: params_1,
-                    index_args: index_args_2,
-                }),
-                NonterminalKind::EnumCase,
-            )
-        }
-        RuleKind::R29 => {
-            (
-                Node::OptCaretParenthesizedExprs(OptCaretParenthesizedExprs::None),
-                NonterminalKind::OptCaretParenthesizedExprs,
-            )
-        }
-        RuleKind::R30 => {
-            let t1 = Box::new(ParenthesizedCommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::OptCaretParenthesizedExprs(OptCaretParenthesizedExprs::Some(
-                    t1,
-                )),
-                NonterminalKind::OptCaretParenthesizedExprs,
-            )
-        }
-        RuleKind::R31 => {
-            let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let exprs_1 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::ParenthesizedCommaSeparatedExprs(ParenthesizedCommaSeparatedExprs {
-                    lparen: lparen_0,
-                    exprs: exprs_1,
-                    trailing_comma: trailing_comma_2,
-                    rparen: rparen_3,
-                }),
-                NonterminalKind::ParenthesizedCommaSeparatedExprs,
-            )
-        }
-        RuleKind::R32 => {
-            let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::CommaSeparatedExprs(CommaSeparatedExprs::One(
-                    t0,
-                )),
-                NonterminalKind::CommaSeparatedExprs,
-            )
-        }
-        RuleKind::R33 => {
-            let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let t0 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::CommaSeparatedExprs(CommaSeparatedExprs::Snoc(
-                    t0,
-                    t2,
-                )),
-                NonterminalKind::CommaSeparatedExprs,
-            )
-        }
-        RuleKind::R34 => {
-            let next_val_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let standalone_0 = Box::new(Def::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::ChainFunDef(ChainFunDef {
-                    standalone: standalone_0,
-                    next_val: next_val_1,
-                }),
-                NonterminalKind::ChainFunDef,
-            )
-        }
-        RuleKind::R35 => {
-            let return_val_5 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let return_type_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let params_2 = Box::new(ParenthesizedCommaSeparatedFunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_1 = nodes.pop().unwrap().try_into_ident_19().ok().unwrap();
-            let defkw_0 = nodes.pop().unwrap().try_into_def_kw_12().ok().unwrap();
-            
-            states.truncate(states.len() - 6);
-            
-            (
-                Node::Def(Def {
-                    defkw: defkw_0,
-                    name: name_1,
-                    params: params_2,
-                    return_type: return_type_4,
-                    return_val: return_val_5,
-                }),
-                NonterminalKind::Def,
-            )
-        }
-        RuleKind::R36 => {
-            let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let params_1 = Box::new(CommaSeparatedFunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::ParenthesizedCommaSeparatedFunParamDefs(ParenthesizedCommaSeparatedFunParamDefs {
-                    lparen: lparen_0,
-                    params: params_1,
-                    trailing_comma: trailing_comma_2,
-                    rparen: rparen_3,
-                }),
-                NonterminalKind::ParenthesizedCommaSeparatedFunParamDefs,
-            )
-        }
-        RuleKind::R37 => {
-            let t0 = Box::new(FunParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::CommaSeparatedFunParamDefs(CommaSeparatedFunParamDefs::One(
-                    t0,
-                )),
-                NonterminalKind::CommaSeparatedFunParamDefs,
-            )
-        }
-        RuleKind::R38 => {
-            let t2 = Box::new(FunParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let t0 = Box::new(CommaSeparatedFunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::CommaSeparatedFunParamDefs(CommaSeparatedFunParamDefs::Snoc(
-                    t0,
-                    t2,
-                )),
-                NonterminalKind::CommaSeparatedFunParamDefs,
-            )
-        }
-        RuleKind::R39 => {
-            let type__3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let name_1 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let deckw_0 = Box::new(OptDecKw::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::FunParamDef(FunParamDef {
-                    deckw: deckw_0,
-                    name: name_1,
-                    type_: type__3,
-                }),
-                NonterminalKind::FunParamDef,
-            )
-        }
-        RuleKind::R40 => {
-            (
-                Node::OptDecKw(OptDecKw::None),
-                NonterminalKind::OptDecKw,
-            )
-        }
-        RuleKind::R41 => {
-            let t0 = nodes.pop().unwrap().try_into_dec_kw_18().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptDecKw(OptDecKw::Some(
-                    t0,
-                )),
-                NonterminalKind::OptDecKw,
-            )
-        }
-        RuleKind::R42 => {
-            let endkw_4 = nodes.pop().unwrap().try_into_end_kw_17().ok().unwrap();
-            let cases_3 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let return_type_2 = Box::new(OptMatchReturnTypeClause::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let matchee_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let matchkw_0 = nodes.pop().unwrap().try_into_match_kw_13().ok().unwrap();
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::Match(Match {
-                    matchkw: matchkw_0,
-                    matchee: matchee_1,
-                    return_type: return_type_2,
-                    cases: cases_3,
-                    endkw: endkw_4,
-                }),
-                NonterminalKind::Match,
-            )
-        }
-        RuleKind::R43 => {
-            (
-                Node::OptMatchReturnTypeClause(OptMatchReturnTypeClause::None),
-                NonterminalKind::OptMatchReturnTypeClause,
-            )
-        }
-        RuleKind::R44 => {
-            let t0 = Box::new(MatchReturnTypeClause::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptMatchReturnTypeClause(OptMatchReturnTypeClause::Some(
-                    t0,
-                )),
-                NonterminalKind::OptMatchReturnTypeClause,
-            )
-        }
-        RuleKind::R45 => {
-            let return_type_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let return_params_0 = Box::new(ReturnParamClause::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::MatchReturnTypeClause(MatchReturnTypeClause {
-                    return_params: return_params_0,
-                    return_type: return_type_1,
-                }),
-                NonterminalKind::MatchReturnTypeClause,
-            )
-        }
-        RuleKind::R46 => {
-            let t0 = nodes.pop().unwrap().try_into_thin_arrow_7().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::ReturnParamClause(ReturnParamClause::None(
-                    t0,
-                )),
-                NonterminalKind::ReturnParamClause,
-            )
-        }
-        RuleKind::R47 => {
-            let t2 = nodes.pop().unwrap().try_into_thin_arrow_7().ok().unwrap();
-            let t1 = nodes.pop().unwrap().try_into_ident_19().ok().unwrap();
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::ReturnParamClause(ReturnParamClause::Matchee(
-                    t1,
-                    t2,
-                )),
-                NonterminalKind::ReturnParamClause,
-            )
-        }
-        RuleKind::R48 => {
-            nodes.pop().unwrap();
-            let t1 = Box::new(CaretParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::ReturnParamClause(ReturnParamClause::Indices(
-                    t1,
-                )),
-                NonterminalKind::ReturnParamClause,
-            )
-        }
-        RuleKind::R49 => {
-            nodes.pop().unwrap();
-            let t2 = Box::new(CaretParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t1 = nodes.pop().unwrap().try_into_ident_19().ok().unwrap();
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::ReturnParamClause(ReturnParamClause::MatcheeAndIndices(
-                    t1,
-                    t2,
-                )),
-                NonterminalKind::ReturnParamClause,
-            )
-        }
-        RuleKind::R50 => {
-            let rsquare_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let trailing_comma_3 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let idents_2 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lsquare_1 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            let caret_0 = nodes.pop().unwrap().try_into_caret_9().ok().unwrap();
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::CaretParenthesizedCommaSeparatedIdentsOrUnderscores(CaretParenthesizedCommaSeparatedIdentsOrUnderscores {
-                    caret: caret_0,
-                    lsquare: lsquare_1,
-                    idents: idents_2,
-                    trailing_comma: trailing_comma_3,
-                    rsquare: rsquare_4,
-                }),
-                NonterminalKind::CaretParenthesizedCommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R51 => {
-            let t0 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::CommaSeparatedIdentsOrUnderscores(CommaSeparatedIdentsOrUnderscores::One(
-                    t0,
-                )),
-                NonterminalKind::CommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R52 => {
-            let t2 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let t0 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::CommaSeparatedIdentsOrUnderscores(CommaSeparatedIdentsOrUnderscores::Snoc(
-                    t0,
-                    t2,
-                )),
-                NonterminalKind::CommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R53 => {
-            (
-                Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Nil),
-                NonterminalKind::ZeroOrMoreMatchCases,
-            )
-        }
-        RuleKind::R54 => {
-            let t1 = Box::new(MatchCase::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Snoc(
-                    t0,
-                    t1,
-                )),
-                NonterminalKind::ZeroOrMoreMatchCases,
-            )
-        }
-        RuleKind::R55 => {
-            let return_val_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let params_2 = Box::new(OptParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_1 = nodes.pop().unwrap().try_into_ident_19().ok().unwrap();
-            let casekw_0 = nodes.pop().unwrap().try_into_case_kw_15().ok().unwrap();
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::MatchCase(MatchCase {
-                    casekw: casekw_0,
-                    name: name_1,
-                    params: params_2,
-                    return_val: return_val_4,
-                }),
-                NonterminalKind::MatchCase,
-            )
-        }
-        RuleKind::R56 => {
-            (
-                Node::OptParenthesizedCommaSeparatedIdentsOrUnderscores(OptParenthesizedCommaSeparatedIdentsOrUnderscores::None),
-                NonterminalKind::OptParenthesizedCommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R57 => {
-            let t0 = Box::new(ParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptParenthesizedCommaSeparatedIdentsOrUnderscores(OptParenthesizedCommaSeparatedIdentsOrUnderscores::Some(
-                    t0,
-                )),
-                NonterminalKind::OptParenthesizedCommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R58 => {
-            let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let idents_1 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::ParenthesizedCommaSeparatedIdentsOrUnderscores(ParenthesizedCommaSeparatedIdentsOrUnderscores {
-                    lparen: lparen_0,
-                    idents: idents_1,
-                    trailing_comma: trailing_comma_2,
-                    rparen: rparen_3,
-                }),
-                NonterminalKind::ParenthesizedCommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R59 => {
-            (
-                Node::OptIdent(OptIdent::None),
-                NonterminalKind::OptIdent,
-            )
-        }
-        RuleKind::R60 => {
-            let t0 = nodes.pop().unwrap().try_into_ident_19().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptIdent(OptIdent::Some(
-                    t0,
-                )),
-                NonterminalKind::OptIdent,
-            )
-        }
-        RuleKind::R61 => {
-            let rparen_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let trailing_comma_3 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let args_2 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lparen_1 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            let callee_0 = Box::new(VarOrApp::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::App(App {
-                    callee: callee_0,
-                    lparen: lparen_1,
-                    args: args_2,
-                    trailing_comma: trailing_comma_3,
-                    rparen: rparen_4,
-                }),
-                NonterminalKind::App,
-            )
-        }
-        RuleKind::R62 => {
-            let t0 = nodes.pop().unwrap().try_into_ident_19().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::VarOrApp(VarOrApp::Var(
-                    t0,
-                )),
-                NonterminalKind::VarOrApp,
-            )
-        }
-        RuleKind::R63 => {
-            let t0 = Box::new(App::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::VarOrApp(VarOrApp::App(
-                    t0,
-                )),
-                NonterminalKind::VarOrApp,
-            )
-        }
-        RuleKind::R64 => {
-            let return_type_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let params_1 = Box::new(ParenthesizedCommaSeparatedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let forkw_0 = nodes.pop().unwrap().try_into_for_kw_14().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::For(For {
-                    forkw: forkw_0,
-                    params: params_1,
-                    return_type: return_type_3,
-                }),
-                NonterminalKind::For,
-            )
-        }
-        RuleKind::R65 => {
-            let t0 = nodes.pop().unwrap().try_into_ident_19().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::IdentOrUnderscore(IdentOrUnderscore::Ident(
-                    t0,
-                )),
-                NonterminalKind::IdentOrUnderscore,
-            )
-        }
-        RuleKind::R66 => {
-            let t0 = nodes.pop().unwrap().try_into_underscore_10().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::IdentOrUnderscore(IdentOrUnderscore::Underscore(
-                    t0,
-                )),
-                NonterminalKind::IdentOrUnderscore,
-            )
-        }
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+        RuleKind::R6 => reduce_r6(states, nodes),
+        RuleKind::R7 => reduce_r7(states, nodes),
+        RuleKind::R8 => reduce_r8(states, nodes),
+        RuleKind::R9 => reduce_r9(states, nodes),
+        RuleKind::R10 => reduce_r10(states, nodes),
+        RuleKind::R11 => reduce_r11(states, nodes),
+        RuleKind::R12 => reduce_r12(states, nodes),
+        RuleKind::R13 => reduce_r13(states, nodes),
+        RuleKind::R14 => reduce_r14(states, nodes),
+        RuleKind::R15 => reduce_r15(states, nodes),
+        RuleKind::R16 => reduce_r16(states, nodes),
+        RuleKind::R17 => reduce_r17(states, nodes),
+        RuleKind::R18 => reduce_r18(states, nodes),
+        RuleKind::R19 => reduce_r19(states, nodes),
+        RuleKind::R20 => reduce_r20(states, nodes),
+        RuleKind::R21 => reduce_r21(states, nodes),
+        RuleKind::R22 => reduce_r22(states, nodes),
+        RuleKind::R23 => reduce_r23(states, nodes),
+        RuleKind::R24 => reduce_r24(states, nodes),
+        RuleKind::R25 => reduce_r25(states, nodes),
+        RuleKind::R26 => reduce_r26(states, nodes),
+        RuleKind::R27 => reduce_r27(states, nodes),
+        RuleKind::R28 => reduce_r28(states, nodes),
+        RuleKind::R29 => reduce_r29(states, nodes),
+        RuleKind::R30 => reduce_r30(states, nodes),
+        RuleKind::R31 => reduce_r31(states, nodes),
+        RuleKind::R32 => reduce_r32(states, nodes),
+        RuleKind::R33 => reduce_r33(states, nodes),
+        RuleKind::R34 => reduce_r34(states, nodes),
+        RuleKind::R35 => reduce_r35(states, nodes),
+        RuleKind::R36 => reduce_r36(states, nodes),
+        RuleKind::R37 => reduce_r37(states, nodes),
+        RuleKind::R38 => reduce_r38(states, nodes),
+        RuleKind::R39 => reduce_r39(states, nodes),
+        RuleKind::R40 => reduce_r40(states, nodes),
+        RuleKind::R41 => reduce_r41(states, nodes),
+        RuleKind::R42 => reduce_r42(states, nodes),
+        RuleKind::R43 => reduce_r43(states, nodes),
+        RuleKind::R44 => reduce_r44(states, nodes),
+        RuleKind::R45 => reduce_r45(states, nodes),
+        RuleKind::R46 => reduce_r46(states, nodes),
+        RuleKind::R47 => reduce_r47(states, nodes),
+        RuleKind::R48 => reduce_r48(states, nodes),
+        RuleKind::R49 => reduce_r49(states, nodes),
+        RuleKind::R50 => reduce_r50(states, nodes),
+        RuleKind::R51 => reduce_r51(states, nodes),
+        RuleKind::R52 => reduce_r52(states, nodes),
+        RuleKind::R53 => reduce_r53(states, nodes),
+        RuleKind::R54 => reduce_r54(states, nodes),
+        RuleKind::R55 => reduce_r55(states, nodes),
+        RuleKind::R56 => reduce_r56(states, nodes),
+        RuleKind::R57 => reduce_r57(states, nodes),
+        RuleKind::R58 => reduce_r58(states, nodes),
+        RuleKind::R59 => reduce_r59(states, nodes),
+        RuleKind::R60 => reduce_r60(states, nodes),
+        RuleKind::R61 => reduce_r61(states, nodes),
+        RuleKind::R62 => reduce_r62(states, nodes),
+        RuleKind::R63 => reduce_r63(states, nodes),
+        RuleKind::R64 => reduce_r64(states, nodes),
+        RuleKind::R65 => reduce_r65(states, nodes),
+        RuleKind::R66 => reduce_r66(states, nodes),
+        RuleKind::R67 => reduce_r67(states, nodes),
+        RuleKind::R68 => reduce_r68(states, nodes),
+        RuleKind::R69 => reduce_r69(states, nodes),
+        RuleKind::R70 => reduce_r70(states, nodes),
+        RuleKind::R71 => reduce_r71(states, nodes),
     }
 }
 
-impl QuasiterminalKind {
-    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
-        match quasiterminal {
-            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
-            Quasiterminal::Eof => Self::Eof,
-        }
-    }
+fn reduce_r0(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::Module(Module::Nil),
+        NonterminalKind::Module,
+    )
+}
 
-    fn from_terminal(terminal: &Token) -> Self {
-        match terminal {
-            Token::LParen(_) => Self::LParen,
-            Token::RParen(_) => Self::RParen,
-            Token::LSquare(_) => Self::LSquare,
-            Token::RSquare(_) => Self::RSquare,
-            Token::Eq(_) => Self::Eq,
-            Token::Colon(_) => Self::Colon,
-            Token::Comma(_) => Self::Comma,
-            Token::ThinArrow(_) => Self::ThinArrow,
-            Token::Dash(_) => Self::Dash,
-            Token::Caret(_) => Self::Caret,
-            Token::Underscore(_) => Self::Underscore,
-            Token::EnumKw(_) => Self::EnumKw,
-            Token::DefKw(_) => Self::DefKw,
-            Token::MatchKw(_) => Self::MatchKw,
-            Token::ForKw(_) => Self::ForKw,
-            Token::CaseKw(_) => Self::CaseKw,
-            Token::UseKw(_) => Self::UseKw,
-            Token::EndKw(_) => Self::EndKw,
-            Token::DecKw(_) => Self::DecKw,
-            Token::Ident(_) => Self::Ident,
-            Token::NumberLiteral(_) => Self::NumberLiteral,
-            Token::StringLiteral(_) => Self::StringLiteral,
-            Token::UniverseLiteral(_) => Self::UniverseLiteral,
-        }
-    }
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(ModuleItem::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let t0 = Box::new(Module::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::Module(Module::Snoc(
+            t0,
+            t1,
+        )),
+        NonterminalKind::Module,
+    )
 }
 
-impl Node {
-    fn from_terminal(terminal: Token) -> Self {
-        match terminal {
-            Token::LParen(t) => Self::LParen(t),
-            Token::RParen(t) => Self::RParen(t),
-            Token::LSquare(t) => Self::LSquare(t),
-            Token::RSquare(t) => Self::RSquare(t),
-            Token::Eq(t) => Self::Eq(t),
-            Token::Colon(t) => Self::Colon(t),
-            Token::Comma(t) => Self::Comma(t),
-            Token::ThinArrow(t) => Self::ThinArrow(t),
-            Token::Dash(t) => Self::Dash(t),
-            Token::Caret(t) => Self::Caret(t),
-            Token::Underscore(t) => Self::Underscore(t),
-            Token::EnumKw(t) => Self::EnumKw(t),
-            Token::DefKw(t) => Self::DefKw(t),
-            Token::MatchKw(t) => Self::MatchKw(t),
-            Token::ForKw(t) => Self::ForKw(t),
-            Token::CaseKw(t) => Self::CaseKw(t),
-            Token::UseKw(t) => Self::UseKw(t),
-            Token::EndKw(t) => Self::EndKw(t),
-            Token::DecKw(t) => Self::DecKw(t),
-            Token::Ident(t) => Self::Ident(t),
-            Token::NumberLiteral(t) => Self::NumberLiteral(t),
-            Token::StringLiteral(t) => Self::StringLiteral(t),
-            Token::UniverseLiteral(t) => Self::UniverseLiteral(t),
-        }
-    }
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(VarDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::ModuleItem(ModuleItem::VarDef(
+            t0,
+        )),
+        NonterminalKind::ModuleItem,
+    )
 }
 
-impl Quasiterminal {
-    fn try_into_terminal(self) -> Result<Token, ()> {
-        match self {
-            Self::Terminal(terminal) => Ok(terminal),
-            Self::Eof => Err(()),
-        }
-    }
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(EnumDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::ModuleItem(ModuleItem::EnumDef(
+            t0,
+        )),
+        NonterminalKind::ModuleItem,
+    )
 }
 
-const ACTION_TABLE: [[Action; 24]; 119] = [
-    [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R0),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R0),
-    ],
-    [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S30),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Accept,
-    ],
-    [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R1),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R1),
-    ],
-    [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R2),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Def::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::ModuleItem(ModuleItem::FunDef(
+            t0,
+        )),
+        NonterminalKind::ModuleItem,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(ChainVarDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::VarDef(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r6(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(ChainEnumDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::EnumDef(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r7(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(ChainFunDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::FunDef(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r8(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Match::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Match(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r9(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(For::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::For(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r10(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(VarOrApp::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::VarOrApp(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r11(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_universe_literal_25().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Universe(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r12(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_question_11().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Hole(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r13(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Elim::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Elim(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r14(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let next_val_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let standalone_0 = Box::new(VarDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::ChainVarDef(ChainVarDef {
+            standalone: standalone_0,
+            next_val: next_val_1,
+        }),
+        NonterminalKind::ChainVarDef,
+    )
+}
+
+fn reduce_r15(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let val_2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let name_0 = nodes.pop().unwrap().try_into_ident_22().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::VarDef(VarDef {
+            name: name_0,
+            val: val_2,
+        }),
+        NonterminalKind::VarDef,
+    )
+}
+
+fn reduce_r16(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let next_val_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let standalone_0 = Box::new(EnumDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::ChainEnumDef(ChainEnumDef {
+            standalone: standalone_0,
+            next_val: next_val_1,
+        }),
+        NonterminalKind::ChainEnumDef,
+    )
+}
+
+fn reduce_r17(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let endkw_6 = nodes.pop().unwrap().try_into_end_kw_18().ok().unwrap();
+    let cases_5 = Box::new(ZeroOrMoreEnumCases::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let indices_4 = Box::new(OptCaretParenthesizedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let params_3 = Box::new(OptParenthesizedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_2 = nodes.pop().unwrap().try_into_ident_22().ok().unwrap();
+    let nominal_1 = Box::new(OptNominalKw::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let universe_0 = nodes.pop().unwrap().try_into_enum_kw_12().ok().unwrap();
+
+    states.truncate(states.len() - 7);
+
+    (
+        Node::EnumDef(EnumDef {
+            universe: universe_0,
+            nominal: nominal_1,
+            name: name_2,
+            params: params_3,
+            indices: indices_4,
+            cases: cases_5,
+            endkw: endkw_6,
+        }),
+        NonterminalKind::EnumDef,
+    )
+}
+
+fn reduce_r18(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptNominalKw(OptNominalKw::None),
+        NonterminalKind::OptNominalKw,
+    )
+}
+
+fn reduce_r19(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_nominal_kw_21().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptNominalKw(OptNominalKw::Some(
+            t0,
+        )),
+        NonterminalKind::OptNominalKw,
+    )
+}
+
+fn reduce_r20(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptParenthesizedNonfunParamDefs(OptParenthesizedNonfunParamDefs::None),
+        NonterminalKind::OptParenthesizedNonfunParamDefs,
+    )
+}
+
+fn reduce_r21(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(ParenthesizedCommaSeparatedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptParenthesizedNonfunParamDefs(OptParenthesizedNonfunParamDefs::Some(
+            t0,
+        )),
+        NonterminalKind::OptParenthesizedNonfunParamDefs,
+    )
+}
+
+fn reduce_r22(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let params_1 = Box::new(CommaSeparatedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::ParenthesizedCommaSeparatedNonfunParamDefs(ParenthesizedCommaSeparatedNonfunParamDefs {
+            lparen: lparen_0,
+            params: params_1,
+            trailing_comma: trailing_comma_2,
+            rparen: rparen_3,
+        }),
+        NonterminalKind::ParenthesizedCommaSeparatedNonfunParamDefs,
+    )
+}
+
+fn reduce_r23(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptComma(OptComma::None),
+        NonterminalKind::OptComma,
+    )
+}
+
+fn reduce_r24(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_comma_6().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptComma(OptComma::Some(
+            t0,
+        )),
+        NonterminalKind::OptComma,
+    )
+}
+
+fn reduce_r25(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(NonfunParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::CommaSeparatedNonfunParamDefs(CommaSeparatedNonfunParamDefs::One(
+            t0,
+        )),
+        NonterminalKind::CommaSeparatedNonfunParamDefs,
+    )
+}
+
+fn reduce_r26(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(NonfunParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(CommaSeparatedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::CommaSeparatedNonfunParamDefs(CommaSeparatedNonfunParamDefs::Snoc(
+            t0,
+            t2,
+        )),
+        NonterminalKind::CommaSeparatedNonfunParamDefs,
+    )
+}
+
+fn reduce_r27(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let type__2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let name_0 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::NonfunParamDef(NonfunParamDef {
+            name: name_0,
+            type_: type__2,
+        }),
+        NonterminalKind::NonfunParamDef,
+    )
+}
+
+fn reduce_r28(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptCaretParenthesizedParamDefs(OptCaretParenthesizedParamDefs::None),
+        NonterminalKind::OptCaretParenthesizedParamDefs,
+    )
+}
+
+fn reduce_r29(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(ParenthesizedCommaSeparatedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::OptCaretParenthesizedParamDefs(OptCaretParenthesizedParamDefs::Some(
+            t1,
+        )),
+        NonterminalKind::OptCaretParenthesizedParamDefs,
+    )
+}
+
+fn reduce_r30(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::ZeroOrMoreEnumCases(ZeroOrMoreEnumCases::Nil),
+        NonterminalKind::ZeroOrMoreEnumCases,
+    )
+}
+
+fn reduce_r31(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(EnumCase::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let t0 = Box::new(ZeroOrMoreEnumCases::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::ZeroOrMoreEnumCases(ZeroOrMoreEnumCases::Snoc(
+            t0,
+            t1,
+        )),
+        NonterminalKind::ZeroOrMoreEnumCases,
+    )
+}
+
+fn reduce_r32(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let index_args_2 = Box::new(OptCaretParenthesizedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let params_1 = Box::new(OptParenthesizedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_0 = nodes.pop().unwrap().try_into_ident_22().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::EnumCase(EnumCase {
+            name: name_0,
+            params: params_1,
+            index_args: index_args_2,
+        }),
+        NonterminalKind::EnumCase,
+    )
+}
+
+fn reduce_r33(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptCaretParenthesizedExprs(OptCaretParenthesizedExprs::None),
+        NonterminalKind::OptCaretParenthesizedExprs,
+    )
+}
+
+fn reduce_r34(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(ParenthesizedCommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::OptCaretParenthesizedExprs(OptCaretParenthesizedExprs::Some(
+            t1,
+        )),
+        NonterminalKind::OptCaretParenthesizedExprs,
+    )
+}
+
+fn reduce_r35(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let exprs_1 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::ParenthesizedCommaSeparatedExprs(ParenthesizedCommaSeparatedExprs {
+            lparen: lparen_0,
+            exprs: exprs_1,
+            trailing_comma: trailing_comma_2,
+            rparen: rparen_3,
+        }),
+        NonterminalKind::ParenthesizedCommaSeparatedExprs,
+    )
+}
+
+fn reduce_r36(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::CommaSeparatedExprs(CommaSeparatedExprs::One(
+            t0,
+        )),
+        NonterminalKind::CommaSeparatedExprs,
+    )
+}
+
+fn reduce_r37(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::CommaSeparatedExprs(CommaSeparatedExprs::Snoc(
+            t0,
+            t2,
+        )),
+        NonterminalKind::CommaSeparatedExprs,
+    )
+}
+
+fn reduce_r38(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let next_val_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let standalone_0 = Box::new(Def::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::ChainFunDef(ChainFunDef {
+            standalone: standalone_0,
+            next_val: next_val_1,
+        }),
+        NonterminalKind::ChainFunDef,
+    )
+}
+
+fn reduce_r39(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let return_val_5 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let return_type_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let params_2 = Box::new(ParenthesizedCommaSeparatedFunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_1 = nodes.pop().unwrap().try_into_ident_22().ok().unwrap();
+    let defkw_0 = nodes.pop().unwrap().try_into_def_kw_13().ok().unwrap();
+
+    states.truncate(states.len() - 6);
+
+    (
+        Node::Def(Def {
+            defkw: defkw_0,
+            name: name_1,
+            params: params_2,
+            return_type: return_type_4,
+            return_val: return_val_5,
+        }),
+        NonterminalKind::Def,
+    )
+}
+
+fn reduce_r40(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let params_1 = Box::new(CommaSeparatedFunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::ParenthesizedCommaSeparatedFunParamDefs(ParenthesizedCommaSeparatedFunParamDefs {
+            lparen: lparen_0,
+            params: params_1,
+            trailing_comma: trailing_comma_2,
+            rparen: rparen_3,
+        }),
+        NonterminalKind::ParenthesizedCommaSeparatedFunParamDefs,
+    )
+}
+
+fn reduce_r41(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(FunParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::CommaSeparatedFunParamDefs(CommaSeparatedFunParamDefs::One(
+            t0,
+        )),
+        NonterminalKind::CommaSeparatedFunParamDefs,
+    )
+}
+
+fn reduce_r42(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(FunParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(CommaSeparatedFunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::CommaSeparatedFunParamDefs(CommaSeparatedFunParamDefs::Snoc(
+            t0,
+            t2,
+        )),
+        NonterminalKind::CommaSeparatedFunParamDefs,
+    )
+}
+
+fn reduce_r43(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let type__3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let name_1 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let deckw_0 = Box::new(OptDecKw::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::FunParamDef(FunParamDef {
+            deckw: deckw_0,
+            name: name_1,
+            type_: type__3,
+        }),
+        NonterminalKind::FunParamDef,
+    )
+}
+
+fn reduce_r44(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptDecKw(OptDecKw::None),
+        NonterminalKind::OptDecKw,
+    )
+}
+
+fn reduce_r45(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_dec_kw_19().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptDecKw(OptDecKw::Some(
+            t0,
+        )),
+        NonterminalKind::OptDecKw,
+    )
+}
+
+fn reduce_r46(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let endkw_4 = nodes.pop().unwrap().try_into_end_kw_18().ok().unwrap();
+    let cases_3 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let return_type_2 = Box::new(OptMatchReturnTypeClause::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let matchee_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let matchkw_0 = nodes.pop().unwrap().try_into_match_kw_14().ok().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::Match(Match {
+            matchkw: matchkw_0,
+            matchee: matchee_1,
+            return_type: return_type_2,
+            cases: cases_3,
+            endkw: endkw_4,
+        }),
+        NonterminalKind::Match,
+    )
+}
+
+fn reduce_r47(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let motive_universe_3 = nodes.pop().unwrap().try_into_universe_literal_25().ok().unwrap();
+    nodes.pop().unwrap();
+    let enum__1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let elimkw_0 = nodes.pop().unwrap().try_into_elim_kw_20().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::Elim(Elim {
+            elimkw: elimkw_0,
+            enum_: enum__1,
+            motive_universe: motive_universe_3,
+        }),
+        NonterminalKind::Elim,
+    )
+}
+
+fn reduce_r48(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptMatchReturnTypeClause(OptMatchReturnTypeClause::None),
+        NonterminalKind::OptMatchReturnTypeClause,
+    )
+}
+
+fn reduce_r49(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(MatchReturnTypeClause::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptMatchReturnTypeClause(OptMatchReturnTypeClause::Some(
+            t0,
+        )),
+        NonterminalKind::OptMatchReturnTypeClause,
+    )
+}
+
+fn reduce_r50(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let return_type_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let return_params_0 = Box::new(ReturnParamClause::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::MatchReturnTypeClause(MatchReturnTypeClause {
+            return_params: return_params_0,
+            return_type: return_type_1,
+        }),
+        NonterminalKind::MatchReturnTypeClause,
+    )
+}
+
+fn reduce_r51(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_thin_arrow_7().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::ReturnParamClause(ReturnParamClause::None(
+            t0,
+        )),
+        NonterminalKind::ReturnParamClause,
+    )
+}
+
+fn reduce_r52(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = nodes.pop().unwrap().try_into_thin_arrow_7().ok().unwrap();
+    let t1 = nodes.pop().unwrap().try_into_ident_22().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::ReturnParamClause(ReturnParamClause::Matchee(
+            t1,
+            t2,
+        )),
+        NonterminalKind::ReturnParamClause,
+    )
+}
+
+fn reduce_r53(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = Box::new(CaretParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::ReturnParamClause(ReturnParamClause::Indices(
+            t1,
+        )),
+        NonterminalKind::ReturnParamClause,
+    )
+}
+
+fn reduce_r54(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t2 = Box::new(CaretParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let t1 = nodes.pop().unwrap().try_into_ident_22().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::ReturnParamClause(ReturnParamClause::MatcheeAndIndices(
+            t1,
+            t2,
+        )),
+        NonterminalKind::ReturnParamClause,
+    )
+}
+
+fn reduce_r55(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rsquare_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let trailing_comma_3 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let idents_2 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lsquare_1 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+    let caret_0 = nodes.pop().unwrap().try_into_caret_9().ok().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::CaretParenthesizedCommaSeparatedIdentsOrUnderscores(CaretParenthesizedCommaSeparatedIdentsOrUnderscores {
+            caret: caret_0,
+            lsquare: lsquare_1,
+            idents: idents_2,
+            trailing_comma: trailing_comma_3,
+            rsquare: rsquare_4,
+        }),
+        NonterminalKind::CaretParenthesizedCommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r56(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::CommaSeparatedIdentsOrUnderscores(CommaSeparatedIdentsOrUnderscores::One(
+            t0,
+        )),
+        NonterminalKind::CommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r57(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::CommaSeparatedIdentsOrUnderscores(CommaSeparatedIdentsOrUnderscores::Snoc(
+            t0,
+            t2,
+        )),
+        NonterminalKind::CommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r58(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Nil),
+        NonterminalKind::ZeroOrMoreMatchCases,
+    )
+}
+
+fn reduce_r59(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(MatchCase::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let t0 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Snoc(
+            t0,
+            t1,
+        )),
+        NonterminalKind::ZeroOrMoreMatchCases,
+    )
+}
+
+fn reduce_r60(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let return_val_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let params_2 = Box::new(OptParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_1 = nodes.pop().unwrap().try_into_ident_22().ok().unwrap();
+    let casekw_0 = nodes.pop().unwrap().try_into_case_kw_16().ok().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::MatchCase(MatchCase {
+            casekw: casekw_0,
+            name: name_1,
+            params: params_2,
+            return_val: return_val_4,
+        }),
+        NonterminalKind::MatchCase,
+    )
+}
+
+fn reduce_r61(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptParenthesizedCommaSeparatedIdentsOrUnderscores(OptParenthesizedCommaSeparatedIdentsOrUnderscores::None),
+        NonterminalKind::OptParenthesizedCommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r62(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(ParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptParenthesizedCommaSeparatedIdentsOrUnderscores(OptParenthesizedCommaSeparatedIdentsOrUnderscores::Some(
+            t0,
+        )),
+        NonterminalKind::OptParenthesizedCommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r63(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let idents_1 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::ParenthesizedCommaSeparatedIdentsOrUnderscores(ParenthesizedCommaSeparatedIdentsOrUnderscores {
+            lparen: lparen_0,
+            idents: idents_1,
+            trailing_comma: trailing_comma_2,
+            rparen: rparen_3,
+        }),
+        NonterminalKind::ParenthesizedCommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r64(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptIdent(OptIdent::None),
+        NonterminalKind::OptIdent,
+    )
+}
+
+fn reduce_r65(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_ident_22().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptIdent(OptIdent::Some(
+            t0,
+        )),
+        NonterminalKind::OptIdent,
+    )
+}
+
+fn reduce_r66(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let trailing_comma_3 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let args_2 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lparen_1 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+    let callee_0 = Box::new(VarOrApp::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::App(App {
+            callee: callee_0,
+            lparen: lparen_1,
+            args: args_2,
+            trailing_comma: trailing_comma_3,
+            rparen: rparen_4,
+        }),
+        NonterminalKind::App,
+    )
+}
+
+fn reduce_r67(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_ident_22().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::VarOrApp(VarOrApp::Var(
+            t0,
+        )),
+        NonterminalKind::VarOrApp,
+    )
+}
+
+fn reduce_r68(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(App::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::VarOrApp(VarOrApp::App(
+            t0,
+        )),
+        NonterminalKind::VarOrApp,
+    )
+}
+
+fn reduce_r69(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let return_type_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let params_1 = Box::new(ParenthesizedCommaSeparatedNonfunParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let forkw_0 = nodes.pop().unwrap().try_into_for_kw_15().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::For(For {
+            forkw: forkw_0,
+            params: params_1,
+            return_type: return_type_3,
+        }),
+        NonterminalKind::For,
+    )
+}
+
+fn reduce_r70(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_ident_22().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::IdentOrUnderscore(IdentOrUnderscore::Ident(
+            t0,
+        )),
+        NonterminalKind::IdentOrUnderscore,
+    )
+}
+
+fn reduce_r71(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_underscore_10().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::IdentOrUnderscore(IdentOrUnderscore::Underscore(
+            t0,
+        )),
+        NonterminalKind::IdentOrUnderscore,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::LParen(_) => Self::LParen,
+            Token::RParen(_) => Self::RParen,
+            Token::LSquare(_) => Self::LSquare,
+            Token::RSquare(_) => Self::RSquare,
+            Token::Eq(_) => Self::Eq,
+            Token::Colon(_) => Self::Colon,
+            Token::Comma(_) => Self::Comma,
+            Token::ThinArrow(_) => Self::ThinArrow,
+            Token::Dash(_) => Self::Dash,
+            Token::Caret(_) => Self::Caret,
+            Token::Underscore(_) => Self::Underscore,
+            Token::Question(_) => Self::Question,
+            Token::EnumKw(_) => Self::EnumKw,
+            Token::DefKw(_) => Self::DefKw,
+            Token::MatchKw(_) => Self::MatchKw,
+            Token::ForKw(_) => Self::ForKw,
+            Token::CaseKw(_) => Self::CaseKw,
+            Token::UseKw(_) => Self::UseKw,
+            Token::EndKw(_) => Self::EndKw,
+            Token::DecKw(_) => Self::DecKw,
+            Token::ElimKw(_) => Self::ElimKw,
+            Token::NominalKw(_) => Self::NominalKw,
+            Token::Ident(_) => Self::Ident,
+            Token::NumberLiteral(_) => Self::NumberLiteral,
+            Token::StringLiteral(_) => Self::StringLiteral,
+            Token::UniverseLiteral(_) => Self::UniverseLiteral,
+        }
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::LParen(t) => Self::LParen(t),
+            Token::RParen(t) => Self::RParen(t),
+            Token::LSquare(t) => Self::LSquare(t),
+            Token::RSquare(t) => Self::RSquare(t),
+            Token::Eq(t) => Self::Eq(t),
+            Token::Colon(t) => Self::Colon(t),
+            Token::Comma(t) => Self::Comma(t),
+            Token::ThinArrow(t) => Self::ThinArrow(t),
+            Token::Dash(t) => Self::Dash(t),
+            Token::Caret(t) => Self::Caret(t),
+            Token::Underscore(t) => Self::Underscore(t),
+            Token::Question(t) => Self::Question(t),
+            Token::EnumKw(t) => Self::EnumKw(t),
+            Token::DefKw(t) => Self::DefKw(t),
+            Token::MatchKw(t) => Self::MatchKw(t),
+            Token::ForKw(t) => Self::ForKw(t),
+            Token::CaseKw(t) => Self::CaseKw(t),
+            Token::UseKw(t) => Self::UseKw(t),
+            Token::EndKw(t) => Self::EndKw(t),
+            Token::DecKw(t) => Self::DecKw(t),
+            Token::ElimKw(t) => Self::ElimKw(t),
+            Token::NominalKw(t) => Self::NominalKw(t),
+            Token::Ident(t) => Self::Ident(t),
+            Token::NumberLiteral(t) => Self::NumberLiteral(t),
+            Token::StringLiteral(t) => Self::StringLiteral(t),
+            Token::UniverseLiteral(t) => Self::UniverseLiteral(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 27]; 127] = [
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S33),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
         Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R24),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Shift(State::S36),
+        Action::Shift(State::S75),
+        Action::Shift(State::S10),
+        Action::Shift(State::S51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S22),
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
         Action::Err,
+        Action::Reduce(RuleKind::R8),
         Action::Err,
+        Action::Reduce(RuleKind::R8),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
         Action::Err,
-        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R9),
         Action::Err,
+        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
     ],
     [
+        Action::Shift(State::S17),
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
         Action::Err,
+        Action::Reduce(RuleKind::R10),
         Action::Err,
+        Action::Reduce(RuleKind::R10),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R11),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
         Action::Err,
-        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R11),
         Action::Err,
+        Action::Reduce(RuleKind::R11),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R12),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
         Action::Err,
+        Action::Reduce(RuleKind::R12),
         Action::Err,
+        Action::Reduce(RuleKind::R12),
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
         Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R13),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
         Action::Err,
+        Action::Reduce(RuleKind::R13),
         Action::Err,
+        Action::Reduce(RuleKind::R13),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
         Action::Err,
-        Action::Shift(State::S29),
+        Action::Reduce(RuleKind::R14),
         Action::Err,
+        Action::Reduce(RuleKind::R14),
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
     ],
     [
+        Action::Reduce(RuleKind::R67),
+        Action::Reduce(RuleKind::R67),
         Action::Err,
         Action::Err,
+        Action::Shift(State::S19),
         Action::Err,
+        Action::Reduce(RuleKind::R67),
+        Action::Reduce(RuleKind::R67),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R67),
+        Action::Reduce(RuleKind::R67),
+        Action::Reduce(RuleKind::R67),
+        Action::Reduce(RuleKind::R67),
+        Action::Reduce(RuleKind::R67),
+        Action::Reduce(RuleKind::R67),
+        Action::Reduce(RuleKind::R67),
+        Action::Reduce(RuleKind::R67),
         Action::Err,
+        Action::Reduce(RuleKind::R67),
         Action::Err,
+        Action::Reduce(RuleKind::R67),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R67),
+        Action::Reduce(RuleKind::R67),
+    ],
+    [
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S19),
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1974,18 +2965,12 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
     ],
     [
@@ -2000,47 +2985,60 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S29),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
+        Action::Reduce(RuleKind::R15),
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
     ],
     [
+        Action::Err,
+        Action::Reduce(RuleKind::R16),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
         Action::Err,
+        Action::Reduce(RuleKind::R16),
         Action::Err,
+        Action::Reduce(RuleKind::R16),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2052,49 +3050,27 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S43),
+        Action::Reduce(RuleKind::R18),
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R5),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
         Action::Err,
-        Action::Reduce(RuleKind::R5),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R20),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2104,21 +3080,17 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S38),
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
     ],
     [
+        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2127,76 +3099,62 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R20),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
+        Action::Reduce(RuleKind::R20),
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
-    ],
-    [
         Action::Err,
+        Action::Reduce(RuleKind::R20),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S50),
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R28),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R28),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2208,18 +3166,14 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
+        Action::Reduce(RuleKind::R30),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R30),
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
     ],
     [
@@ -2234,18 +3188,21 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
+        Action::Err,
+        Action::Shift(State::S42),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S44),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
     ],
     [
@@ -2260,19 +3217,22 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S29),
+        Action::Reduce(RuleKind::R17),
         Action::Err,
+        Action::Reduce(RuleKind::R17),
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
     ],
     [
         Action::Err,
@@ -2286,235 +3246,118 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
-        Action::Shift(State::S70),
-        Action::Shift(State::S10),
-        Action::Shift(State::S46),
-        Action::Err,
-        Action::Err,
-        Action::Err,
         Action::Err,
-        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S27),
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R6),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R19),
         Action::Err,
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
         Action::Err,
-        Action::Reduce(RuleKind::R6),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
     ],
     [
+        Action::Shift(State::S46),
         Action::Err,
-        Action::Reduce(RuleKind::R7),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R20),
         Action::Err,
-        Action::Reduce(RuleKind::R7),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R8),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R20),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R20),
+        Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R8),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Err,
-        Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-    ],
-    [
-        Action::Shift(State::S17),
-        Action::Reduce(RuleKind::R10),
         Action::Err,
+        Action::Reduce(RuleKind::R21),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
         Action::Err,
-        Action::Reduce(RuleKind::R10),
         Action::Err,
+        Action::Reduce(RuleKind::R21),
         Action::Err,
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R11),
         Action::Err,
+        Action::Reduce(RuleKind::R21),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
         Action::Err,
-        Action::Reduce(RuleKind::R11),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R12),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
+        Action::Shift(State::S126),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
         Action::Err,
-        Action::Reduce(RuleKind::R12),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-    ],
-    [
-        Action::Reduce(RuleKind::R62),
-        Action::Reduce(RuleKind::R62),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S19),
         Action::Err,
-        Action::Reduce(RuleKind::R62),
-        Action::Reduce(RuleKind::R62),
         Action::Err,
         Action::Err,
+        Action::Shift(State::S125),
         Action::Err,
-        Action::Reduce(RuleKind::R62),
-        Action::Reduce(RuleKind::R62),
-        Action::Reduce(RuleKind::R62),
-        Action::Reduce(RuleKind::R62),
-        Action::Reduce(RuleKind::R62),
-        Action::Reduce(RuleKind::R62),
-        Action::Reduce(RuleKind::R62),
         Action::Err,
-        Action::Reduce(RuleKind::R62),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R62),
-        Action::Reduce(RuleKind::R62),
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R23),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S19),
         Action::Err,
+        Action::Shift(State::S57),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2533,11 +3376,12 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Shift(State::S49),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2546,45 +3390,23 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R13),
-        Action::Reduce(RuleKind::R13),
-        Action::Reduce(RuleKind::R13),
-        Action::Reduce(RuleKind::R13),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R13),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R13),
-        Action::Reduce(RuleKind::R13),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
         Action::Err,
-        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
     ],
     [
         Action::Err,
@@ -2594,7 +3416,9 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R22),
         Action::Err,
+        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2603,17 +3427,18 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S34),
+        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
-        Action::Shift(State::S41),
+        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2622,7 +3447,6 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R16),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2630,25 +3454,23 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R16),
         Action::Err,
-        Action::Reduce(RuleKind::R16),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
+        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S45),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2656,15 +3478,11 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R24),
         Action::Err,
-        Action::Reduce(RuleKind::R24),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2676,21 +3494,21 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R23),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S14),
         Action::Err,
-        Action::Reduce(RuleKind::R26),
         Action::Err,
-        Action::Reduce(RuleKind::R26),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2705,18 +3523,18 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R23),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S38),
         Action::Err,
-        Action::Shift(State::S39),
         Action::Err,
+        Action::Shift(State::S14),
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2728,22 +3546,21 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
     ],
     [
-        Action::Shift(State::S41),
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S58),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2752,7 +3569,6 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R16),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2760,9 +3576,7 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R16),
         Action::Err,
-        Action::Reduce(RuleKind::R16),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2770,6 +3584,12 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S59),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2778,7 +3598,6 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R17),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2786,26 +3605,28 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R17),
         Action::Err,
-        Action::Reduce(RuleKind::R17),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S59),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S118),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2814,7 +3635,6 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S117),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2822,16 +3642,16 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R19),
+        Action::Reduce(RuleKind::R24),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S52),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S126),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2843,12 +3663,15 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S125),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
         Action::Err,
-        Action::Shift(State::S44),
+        Action::Reduce(RuleKind::R24),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2857,6 +3680,7 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R44),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2865,8 +3689,10 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S87),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R44),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2874,15 +3700,16 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R24),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R18),
         Action::Err,
-        Action::Reduce(RuleKind::R18),
+        Action::Err,
+        Action::Shift(State::S126),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2890,19 +3717,24 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R18),
         Action::Err,
-        Action::Reduce(RuleKind::R18),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S125),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
         Action::Err,
-    ],
-    [
-        Action::Shift(State::S41),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R25),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2925,10 +3757,13 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
     ],
     [
-        Action::Shift(State::S41),
+        Action::Err,
+        Action::Reduce(RuleKind::R26),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2952,12 +3787,15 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R19),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S14),
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2978,12 +3816,12 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R19),
+        Action::Reduce(RuleKind::R27),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S14),
+        Action::Reduce(RuleKind::R27),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3001,15 +3839,13 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R19),
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
-        Action::Shift(State::S53),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3026,17 +3862,17 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R29),
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R19),
         Action::Err,
+        Action::Reduce(RuleKind::R29),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S54),
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3053,19 +3889,19 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R19),
         Action::Err,
+        Action::Reduce(RuleKind::R31),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S54),
+        Action::Reduce(RuleKind::R31),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3075,40 +3911,37 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S68),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R20),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R33),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R33),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S118),
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S117),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R20),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3116,25 +3949,24 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R32),
         Action::Err,
-        Action::Reduce(RuleKind::R40),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R32),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
+        Action::Shift(State::S16),
         Action::Err,
-        Action::Shift(State::S82),
-        Action::Reduce(RuleKind::R40),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R20),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3143,7 +3975,6 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S118),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3152,7 +3983,6 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S117),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3160,12 +3990,10 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R21),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R21),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3179,6 +4007,11 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R34),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R34),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3186,12 +4019,15 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R22),
+        Action::Shift(State::S71),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3216,7 +4052,6 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S15),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3230,20 +4065,27 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R35),
+        Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R35),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R23),
+        Action::Reduce(RuleKind::R36),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R23),
+        Action::Reduce(RuleKind::R36),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3263,10 +4105,15 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
     ],
     [
+        Action::Err,
+        Action::Reduce(RuleKind::R37),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R37),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3280,9 +4127,7 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R25),
         Action::Err,
-        Action::Reduce(RuleKind::R25),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3290,31 +4135,43 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R38),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
         Action::Err,
+        Action::Reduce(RuleKind::R38),
         Action::Err,
+        Action::Reduce(RuleKind::R38),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R27),
-        Action::Err,
-        Action::Reduce(RuleKind::R27),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3324,23 +4181,22 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S63),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S76),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R29),
         Action::Err,
-        Action::Reduce(RuleKind::R29),
+    ],
+    [
+        Action::Shift(State::S79),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3358,17 +4214,13 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R28),
         Action::Err,
-        Action::Reduce(RuleKind::R28),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
-        Action::Shift(State::S16),
-        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3376,6 +4228,7 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S21),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3391,12 +4244,12 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3408,19 +4261,25 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R30),
         Action::Err,
-        Action::Reduce(RuleKind::R30),
         Action::Err,
+        Action::Reduce(RuleKind::R39),
         Action::Err,
+        Action::Reduce(RuleKind::R39),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
     ],
     [
         Action::Err,
-        Action::Shift(State::S66),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3430,6 +4289,8 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R44),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3437,8 +4298,10 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S87),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R44),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3446,6 +4309,7 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
+        Action::Shift(State::S81),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3462,28 +4326,25 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R31),
         Action::Err,
-        Action::Reduce(RuleKind::R31),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R32),
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
-        Action::Reduce(RuleKind::R32),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R40),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3495,23 +4356,23 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R33),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R33),
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R41),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R41),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3521,38 +4382,27 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R34),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
         Action::Err,
-        Action::Reduce(RuleKind::R34),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
     ],
     [
+        Action::Err,
+        Action::Reduce(RuleKind::R42),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R42),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3568,14 +4418,12 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S71),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
-        Action::Shift(State::S74),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3586,6 +4434,8 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S126),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3596,6 +4446,8 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S125),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3606,9 +4458,12 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S21),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3628,31 +4483,24 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R43),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R43),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R35),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3661,9 +4509,10 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R40),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3671,26 +4520,26 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S82),
-        Action::Reduce(RuleKind::R40),
+        Action::Err,
+        Action::Reduce(RuleKind::R45),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Shift(State::S76),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R45),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3698,25 +4547,28 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S97),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R48),
+        Action::Shift(State::S98),
+        Action::Reduce(RuleKind::R48),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R36),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3729,15 +4581,13 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R37),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R58),
         Action::Err,
-        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R58),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3746,6 +4596,8 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3755,17 +4607,16 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R38),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R38),
         Action::Err,
         Action::Err,
+        Action::Shift(State::S113),
+        Action::Err,
+        Action::Shift(State::S91),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3774,18 +4625,37 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R46),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
+        Action::Err,
+        Action::Reduce(RuleKind::R46),
+        Action::Err,
+        Action::Reduce(RuleKind::R46),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3793,7 +4663,7 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S118),
+        Action::Shift(State::S93),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3802,21 +4672,19 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S117),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S18),
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3833,32 +4701,46 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R39),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R39),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S94),
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R47),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
         Action::Err,
+        Action::Reduce(RuleKind::R47),
         Action::Err,
+        Action::Reduce(RuleKind::R47),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
     ],
     [
         Action::Err,
@@ -3871,16 +4753,19 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R41),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R49),
+        Action::Err,
+        Action::Reduce(RuleKind::R49),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R41),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3894,7 +4779,6 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S89),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3902,22 +4786,21 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R43),
-        Action::Shift(State::S90),
-        Action::Reduce(RuleKind::R43),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R50),
         Action::Err,
+        Action::Reduce(RuleKind::R50),
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3928,14 +4811,22 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R53),
         Action::Err,
-        Action::Reduce(RuleKind::R53),
+        Action::Reduce(RuleKind::R51),
+        Action::Reduce(RuleKind::R51),
+        Action::Reduce(RuleKind::R51),
+        Action::Reduce(RuleKind::R51),
+        Action::Reduce(RuleKind::R51),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R51),
+        Action::Err,
+        Action::Reduce(RuleKind::R51),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R51),
         Action::Err,
     ],
     [
@@ -3948,49 +4839,36 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S105),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S105),
         Action::Err,
-        Action::Shift(State::S86),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S99),
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R42),
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R42),
-        Action::Reduce(RuleKind::R42),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R42),
-        Action::Reduce(RuleKind::R42),
-        Action::Reduce(RuleKind::R42),
-        Action::Reduce(RuleKind::R42),
-        Action::Reduce(RuleKind::R42),
-        Action::Reduce(RuleKind::R42),
-        Action::Reduce(RuleKind::R42),
         Action::Err,
-        Action::Reduce(RuleKind::R42),
         Action::Err,
+        Action::Shift(State::S100),
         Action::Err,
-        Action::Reduce(RuleKind::R42),
-        Action::Reduce(RuleKind::R42),
-    ],
-    [
+        Action::Shift(State::S105),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4006,43 +4884,48 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R44),
         Action::Err,
-        Action::Reduce(RuleKind::R44),
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R52),
+        Action::Reduce(RuleKind::R52),
+        Action::Reduce(RuleKind::R52),
+        Action::Reduce(RuleKind::R52),
+        Action::Reduce(RuleKind::R52),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R52),
         Action::Err,
+        Action::Reduce(RuleKind::R52),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R52),
         Action::Err,
+    ],
+    [
         Action::Err,
-        Action::Reduce(RuleKind::R45),
         Action::Err,
-        Action::Reduce(RuleKind::R45),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S102),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4054,18 +4937,12 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R46),
-        Action::Reduce(RuleKind::R46),
-        Action::Reduce(RuleKind::R46),
-        Action::Reduce(RuleKind::R46),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R46),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R46),
         Action::Err,
     ],
     [
@@ -4078,33 +4955,39 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S97),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R53),
+        Action::Reduce(RuleKind::R53),
+        Action::Reduce(RuleKind::R53),
+        Action::Reduce(RuleKind::R53),
+        Action::Reduce(RuleKind::R53),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R53),
         Action::Err,
+        Action::Reduce(RuleKind::R53),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S91),
+        Action::Reduce(RuleKind::R53),
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S104),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S92),
         Action::Err,
-        Action::Shift(State::S97),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4132,21 +5015,25 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R47),
-        Action::Reduce(RuleKind::R47),
-        Action::Reduce(RuleKind::R47),
-        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R54),
+        Action::Reduce(RuleKind::R54),
+        Action::Reduce(RuleKind::R54),
+        Action::Reduce(RuleKind::R54),
+        Action::Reduce(RuleKind::R54),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R54),
         Action::Err,
+        Action::Reduce(RuleKind::R54),
         Action::Err,
-        Action::Reduce(RuleKind::R47),
+        Action::Err,
+        Action::Reduce(RuleKind::R54),
         Action::Err,
     ],
     [
+        Action::Shift(State::S106),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4154,9 +5041,6 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S94),
-        Action::Err,
-        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4171,34 +5055,27 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R48),
-        Action::Reduce(RuleKind::R48),
-        Action::Reduce(RuleKind::R48),
-        Action::Reduce(RuleKind::R48),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R48),
+        Action::Shift(State::S126),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R48),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4206,13 +5083,16 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S96),
         Action::Err,
+        Action::Shift(State::S125),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Shift(State::S108),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4223,8 +5103,6 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4236,26 +5114,20 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R49),
-        Action::Reduce(RuleKind::R49),
-        Action::Reduce(RuleKind::R49),
-        Action::Reduce(RuleKind::R49),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R49),
+    ],
+    [
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R49),
         Action::Err,
-    ],
-    [
-        Action::Shift(State::S98),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R55),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4287,7 +5159,9 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S118),
+        Action::Shift(State::S126),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4296,7 +5170,8 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S117),
+        Action::Err,
+        Action::Shift(State::S125),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4304,10 +5179,13 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
-        Action::Shift(State::S100),
+        Action::Reduce(RuleKind::R56),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R56),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4329,14 +5207,17 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
     ],
     [
+        Action::Err,
+        Action::Reduce(RuleKind::R57),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R57),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R50),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4365,16 +5246,19 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S118),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R59),
+        Action::Err,
+        Action::Reduce(RuleKind::R59),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S117),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4382,12 +5266,10 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R51),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R51),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4405,15 +5287,23 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S114),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
     ],
     [
+        Action::Shift(State::S109),
+        Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R52),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R61),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R52),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4438,6 +5328,11 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S12),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4448,9 +5343,7 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R54),
         Action::Err,
-        Action::Reduce(RuleKind::R54),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4475,22 +5368,28 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R60),
+        Action::Err,
+        Action::Reduce(RuleKind::R60),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S106),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
-        Action::Shift(State::S101),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R56),
+        Action::Err,
+        Action::Reduce(RuleKind::R62),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4511,12 +5410,15 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
     ],
     [
+        Action::Err,
+        Action::Shift(State::S119),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S12),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4542,6 +5444,11 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R63),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4552,9 +5459,7 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R55),
         Action::Err,
-        Action::Reduce(RuleKind::R55),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4563,12 +5468,15 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
     ],
     [
+        Action::Err,
+        Action::Shift(State::S121),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R57),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4589,30 +5497,62 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S111),
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
         Action::Err,
+        Action::Reduce(RuleKind::R66),
         Action::Err,
+        Action::Reduce(RuleKind::R66),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
+    ],
+    [
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
         Action::Err,
+        Action::Reduce(RuleKind::R68),
         Action::Err,
+        Action::Reduce(RuleKind::R68),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
     ],
     [
         Action::Err,
@@ -4620,13 +5560,9 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R58),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S9),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4639,94 +5575,83 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Shift(State::S113),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R69),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R69),
+        Action::Reduce(RuleKind::R69),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R69),
+        Action::Reduce(RuleKind::R69),
+        Action::Reduce(RuleKind::R69),
+        Action::Reduce(RuleKind::R69),
+        Action::Reduce(RuleKind::R69),
+        Action::Reduce(RuleKind::R69),
+        Action::Reduce(RuleKind::R69),
+        Action::Reduce(RuleKind::R69),
         Action::Err,
+        Action::Reduce(RuleKind::R69),
         Action::Err,
+        Action::Reduce(RuleKind::R69),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R69),
+        Action::Reduce(RuleKind::R69),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R70),
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Reduce(RuleKind::R61),
-        Action::Reduce(RuleKind::R61),
+        Action::Reduce(RuleKind::R70),
+        Action::Reduce(RuleKind::R70),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R61),
-        Action::Reduce(RuleKind::R61),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R61),
-        Action::Reduce(RuleKind::R61),
-        Action::Reduce(RuleKind::R61),
-        Action::Reduce(RuleKind::R61),
-        Action::Reduce(RuleKind::R61),
-        Action::Reduce(RuleKind::R61),
-        Action::Reduce(RuleKind::R61),
         Action::Err,
-        Action::Reduce(RuleKind::R61),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R61),
-        Action::Reduce(RuleKind::R61),
-    ],
-    [
-        Action::Reduce(RuleKind::R63),
-        Action::Reduce(RuleKind::R63),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R63),
-        Action::Reduce(RuleKind::R63),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R63),
-        Action::Reduce(RuleKind::R63),
-        Action::Reduce(RuleKind::R63),
-        Action::Reduce(RuleKind::R63),
-        Action::Reduce(RuleKind::R63),
-        Action::Reduce(RuleKind::R63),
-        Action::Reduce(RuleKind::R63),
         Action::Err,
-        Action::Reduce(RuleKind::R63),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R63),
-        Action::Reduce(RuleKind::R63),
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R71),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R71),
+        Action::Reduce(RuleKind::R71),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S9),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4743,100 +5668,388 @@ const ACTION_TABLE: [[Action; 24]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Err,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static GOTO_TABLE: [[Option<State>; 41]; 127] = [
+    [
+        Some(State::S1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S2),
+        None,
+        None,
+        Some(State::S3),
+        None,
+        Some(State::S4),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S5),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R64),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R64),
-        Action::Reduce(RuleKind::R64),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R64),
-        Action::Reduce(RuleKind::R64),
-        Action::Reduce(RuleKind::R64),
-        Action::Reduce(RuleKind::R64),
-        Action::Reduce(RuleKind::R64),
-        Action::Reduce(RuleKind::R64),
-        Action::Reduce(RuleKind::R64),
-        Action::Err,
-        Action::Reduce(RuleKind::R64),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R64),
-        Action::Reduce(RuleKind::R64),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R65),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R65),
-        Action::Reduce(RuleKind::R65),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        Some(State::S31),
+        Some(State::S13),
+        Some(State::S6),
+        Some(State::S23),
+        Some(State::S7),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S24),
+        Some(State::S8),
+        None,
+        None,
+        None,
+        None,
+        Some(State::S25),
+        Some(State::S30),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S122),
+        Some(State::S27),
+        Some(State::S26),
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R66),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R66),
-        Action::Reduce(RuleKind::R66),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        Some(State::S35),
+        Some(State::S13),
+        Some(State::S6),
+        Some(State::S23),
+        Some(State::S7),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S24),
+        Some(State::S8),
+        None,
+        None,
+        None,
+        None,
+        Some(State::S25),
+        Some(State::S30),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S122),
+        Some(State::S27),
+        Some(State::S26),
+        None,
     ],
-];
-
-fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
-    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
-}
-
-const GOTO_TABLE: [[Option<State>; 39]; 119] = [
     [
-        Some(State::S1),
+        None,
+        None,
+        Some(State::S74),
+        Some(State::S13),
+        Some(State::S6),
+        Some(State::S23),
+        Some(State::S7),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
         None,
         None,
         None,
+        Some(State::S24),
+        Some(State::S8),
+        None,
+        None,
+        None,
+        None,
+        Some(State::S25),
+        Some(State::S30),
+        None,
         None,
         None,
         None,
@@ -4846,6 +6059,23 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S122),
+        Some(State::S27),
+        Some(State::S26),
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S124),
+        Some(State::S13),
+        Some(State::S6),
+        Some(State::S23),
+        Some(State::S7),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -4854,10 +6084,14 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S24),
+        Some(State::S8),
         None,
         None,
         None,
         None,
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -4868,16 +6102,22 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S122),
+        Some(State::S27),
+        Some(State::S26),
         None,
     ],
     [
         None,
-        Some(State::S2),
+        None,
+        Some(State::S88),
+        Some(State::S13),
+        Some(State::S6),
+        Some(State::S23),
+        Some(State::S7),
         None,
         None,
-        Some(State::S3),
         None,
-        Some(State::S4),
         None,
         None,
         None,
@@ -4887,18 +6127,57 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S24),
+        Some(State::S8),
+        None,
+        None,
+        None,
+        None,
+        Some(State::S25),
+        Some(State::S30),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S122),
+        Some(State::S27),
+        Some(State::S26),
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S96),
+        Some(State::S13),
+        Some(State::S6),
+        Some(State::S23),
+        Some(State::S7),
+        None,
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
-        Some(State::S5),
         None,
         None,
         None,
         None,
+        Some(State::S24),
+        Some(State::S8),
         None,
         None,
         None,
         None,
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -4909,11 +6188,19 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S122),
+        Some(State::S27),
+        Some(State::S26),
         None,
     ],
     [
         None,
         None,
+        Some(State::S116),
+        Some(State::S13),
+        Some(State::S6),
+        Some(State::S23),
+        Some(State::S7),
         None,
         None,
         None,
@@ -4926,10 +6213,14 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S24),
+        Some(State::S8),
         None,
         None,
         None,
         None,
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -4940,7 +6231,12 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S122),
+        Some(State::S27),
+        Some(State::S26),
         None,
+    ],
+    [
         None,
         None,
         None,
@@ -4951,8 +6247,6 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -4984,26 +6278,35 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
+        Some(State::S73),
+        Some(State::S13),
+        Some(State::S6),
+        Some(State::S23),
+        Some(State::S7),
         None,
         None,
         None,
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
         None,
         None,
         None,
+        Some(State::S24),
+        Some(State::S8),
         None,
         None,
         None,
         None,
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -5014,9 +6317,19 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S122),
+        Some(State::S27),
+        Some(State::S26),
         None,
+    ],
+    [
         None,
         None,
+        Some(State::S63),
+        Some(State::S13),
+        Some(State::S6),
+        Some(State::S23),
+        Some(State::S7),
         None,
         None,
         None,
@@ -5029,12 +6342,14 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S24),
+        Some(State::S8),
         None,
         None,
         None,
         None,
-    ],
-    [
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -5045,9 +6360,19 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S122),
+        Some(State::S27),
+        Some(State::S26),
         None,
+    ],
+    [
         None,
         None,
+        Some(State::S72),
+        Some(State::S13),
+        Some(State::S6),
+        Some(State::S23),
+        Some(State::S7),
         None,
         None,
         None,
@@ -5059,10 +6384,15 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S52),
+        Some(State::S24),
+        Some(State::S8),
         None,
         None,
         None,
         None,
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -5073,15 +6403,18 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S122),
+        Some(State::S27),
+        Some(State::S26),
         None,
     ],
     [
         None,
         None,
-        Some(State::S28),
+        Some(State::S72),
         Some(State::S13),
         Some(State::S6),
-        Some(State::S22),
+        Some(State::S23),
         Some(State::S7),
         None,
         None,
@@ -5094,13 +6427,15 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
+        Some(State::S53),
+        Some(State::S24),
         Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -5111,18 +6446,18 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
+        Some(State::S122),
+        Some(State::S27),
         Some(State::S26),
-        Some(State::S25),
         None,
     ],
     [
         None,
         None,
-        Some(State::S32),
+        Some(State::S86),
         Some(State::S13),
         Some(State::S6),
-        Some(State::S22),
+        Some(State::S23),
         Some(State::S7),
         None,
         None,
@@ -5135,13 +6470,15 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
+        None,
+        Some(State::S24),
         Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -5152,18 +6489,18 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
+        Some(State::S122),
+        Some(State::S27),
         Some(State::S26),
-        Some(State::S25),
         None,
     ],
     [
         None,
         None,
-        Some(State::S69),
+        Some(State::S34),
         Some(State::S13),
         Some(State::S6),
-        Some(State::S22),
+        Some(State::S23),
         Some(State::S7),
         None,
         None,
@@ -5176,13 +6513,15 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
+        None,
+        Some(State::S24),
         Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -5193,18 +6532,18 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
+        Some(State::S122),
+        Some(State::S27),
         Some(State::S26),
-        Some(State::S25),
         None,
     ],
     [
         None,
         None,
-        Some(State::S116),
+        Some(State::S78),
         Some(State::S13),
         Some(State::S6),
-        Some(State::S22),
+        Some(State::S23),
         Some(State::S7),
         None,
         None,
@@ -5217,13 +6556,15 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
+        None,
+        Some(State::S24),
         Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -5234,18 +6575,18 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
+        Some(State::S122),
+        Some(State::S27),
         Some(State::S26),
-        Some(State::S25),
         None,
     ],
     [
         None,
         None,
-        Some(State::S83),
+        Some(State::S20),
         Some(State::S13),
         Some(State::S6),
-        Some(State::S22),
+        Some(State::S23),
         Some(State::S7),
         None,
         None,
@@ -5258,13 +6599,15 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
+        None,
+        Some(State::S24),
         Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -5275,18 +6618,18 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
+        Some(State::S122),
+        Some(State::S27),
         Some(State::S26),
-        Some(State::S25),
         None,
     ],
     [
         None,
         None,
-        Some(State::S88),
+        Some(State::S92),
         Some(State::S13),
         Some(State::S6),
-        Some(State::S22),
+        Some(State::S23),
         Some(State::S7),
         None,
         None,
@@ -5299,13 +6642,15 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
+        None,
+        Some(State::S24),
         Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
+        Some(State::S25),
+        Some(State::S30),
         None,
         None,
         None,
@@ -5316,19 +6661,14 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
+        Some(State::S122),
+        Some(State::S27),
         Some(State::S26),
-        Some(State::S25),
         None,
     ],
     [
         None,
         None,
-        Some(State::S108),
-        Some(State::S13),
-        Some(State::S6),
-        Some(State::S22),
-        Some(State::S7),
         None,
         None,
         None,
@@ -5340,13 +6680,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
-        Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
         None,
         None,
         None,
@@ -5357,12 +6694,7 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
-        Some(State::S26),
-        Some(State::S25),
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -5376,6 +6708,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
@@ -5402,15 +6736,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
-        Some(State::S68),
-        Some(State::S13),
-        Some(State::S6),
-        Some(State::S22),
-        Some(State::S7),
         None,
         None,
         None,
@@ -5422,13 +6749,12 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
-        Some(State::S8),
         None,
         None,
+    ],
+    [
         None,
         None,
-        Some(State::S24),
         None,
         None,
         None,
@@ -5439,19 +6765,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
-        Some(State::S26),
-        Some(State::S25),
         None,
-    ],
-    [
         None,
         None,
-        Some(State::S58),
-        Some(State::S13),
-        Some(State::S6),
-        Some(State::S22),
-        Some(State::S7),
         None,
         None,
         None,
@@ -5463,13 +6779,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
-        Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
         None,
         None,
         None,
@@ -5480,19 +6793,11 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
-        Some(State::S26),
-        Some(State::S25),
         None,
     ],
     [
         None,
         None,
-        Some(State::S67),
-        Some(State::S13),
-        Some(State::S6),
-        Some(State::S22),
-        Some(State::S7),
         None,
         None,
         None,
@@ -5503,14 +6808,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S47),
-        Some(State::S23),
-        Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
         None,
         None,
         None,
@@ -5521,19 +6822,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
-        Some(State::S26),
-        Some(State::S25),
         None,
-    ],
-    [
         None,
         None,
-        Some(State::S67),
-        Some(State::S13),
-        Some(State::S6),
-        Some(State::S22),
-        Some(State::S7),
         None,
         None,
         None,
@@ -5544,14 +6835,12 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S48),
-        Some(State::S23),
-        Some(State::S8),
         None,
         None,
+    ],
+    [
         None,
         None,
-        Some(State::S24),
         None,
         None,
         None,
@@ -5562,19 +6851,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
-        Some(State::S26),
-        Some(State::S25),
         None,
-    ],
-    [
         None,
         None,
-        Some(State::S81),
-        Some(State::S13),
-        Some(State::S6),
-        Some(State::S22),
-        Some(State::S7),
         None,
         None,
         None,
@@ -5586,13 +6865,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
-        Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
         None,
         None,
         None,
@@ -5603,19 +6879,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
-        Some(State::S26),
-        Some(State::S25),
         None,
     ],
     [
-        None,
-        None,
-        Some(State::S31),
-        Some(State::S13),
-        Some(State::S6),
-        Some(State::S22),
-        Some(State::S7),
         None,
         None,
         None,
@@ -5627,13 +6893,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
-        Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
         None,
         None,
         None,
@@ -5644,19 +6907,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
-        Some(State::S26),
-        Some(State::S25),
         None,
-    ],
-    [
         None,
         None,
-        Some(State::S73),
-        Some(State::S13),
-        Some(State::S6),
-        Some(State::S22),
-        Some(State::S7),
         None,
         None,
         None,
@@ -5668,13 +6921,12 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
-        Some(State::S8),
         None,
         None,
+    ],
+    [
         None,
         None,
-        Some(State::S24),
         None,
         None,
         None,
@@ -5685,19 +6937,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
-        Some(State::S26),
-        Some(State::S25),
         None,
-    ],
-    [
         None,
         None,
-        Some(State::S20),
-        Some(State::S13),
-        Some(State::S6),
-        Some(State::S22),
-        Some(State::S7),
         None,
         None,
         None,
@@ -5709,13 +6951,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S23),
-        Some(State::S8),
         None,
         None,
         None,
         None,
-        Some(State::S24),
         None,
         None,
         None,
@@ -5726,9 +6965,6 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
-        Some(State::S26),
-        Some(State::S25),
         None,
     ],
     [
@@ -5771,6 +7007,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5812,6 +7050,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5853,6 +7093,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5894,6 +7136,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5935,6 +7179,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5976,6 +7222,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5985,6 +7233,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S37),
+        None,
         None,
         None,
         None,
@@ -6058,6 +7308,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -6068,6 +7320,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S39),
+        Some(State::S45),
         None,
         None,
         None,
@@ -6114,6 +7368,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S40),
+        None,
         None,
         None,
         None,
@@ -6156,6 +7412,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S41),
+        None,
         None,
         None,
         None,
@@ -6198,6 +7456,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S65),
+        None,
         None,
         None,
         None,
@@ -6231,8 +7491,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S35),
-        Some(State::S40),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6277,7 +7539,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S36),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6314,12 +7578,14 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S66),
+        Some(State::S45),
+        None,
         None,
         None,
         None,
         None,
         None,
-        Some(State::S37),
         None,
         None,
         None,
@@ -6361,7 +7627,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S60),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6399,6 +7667,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S47),
+        Some(State::S60),
         None,
         None,
         None,
@@ -6426,7 +7696,7 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        None,
+        Some(State::S62),
     ],
     [
         None,
@@ -6436,8 +7706,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S61),
-        Some(State::S40),
+        None,
+        None,
+        None,
+        Some(State::S48),
         None,
         None,
         None,
@@ -6509,6 +7781,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -6521,8 +7795,6 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S42),
-        Some(State::S55),
         None,
         None,
         None,
@@ -6549,7 +7821,11 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S57),
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -6561,7 +7837,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S43),
+        Some(State::S64),
+        None,
+        None,
         None,
         None,
         None,
@@ -6602,6 +7880,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S123),
+        None,
         None,
         None,
         None,
@@ -6644,6 +7924,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S70),
+        None,
         None,
         None,
         None,
@@ -6683,7 +7965,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S59),
+        None,
+        None,
+        Some(State::S120),
         None,
         None,
         None,
@@ -6724,7 +8008,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S115),
+        None,
+        None,
+        Some(State::S80),
         None,
         None,
         None,
@@ -6766,7 +8052,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S65),
+        None,
+        Some(State::S107),
+        None,
         None,
         None,
         None,
@@ -6807,7 +8095,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S112),
+        None,
+        Some(State::S118),
+        None,
         None,
         None,
         None,
@@ -6848,10 +8138,11 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S75),
         None,
         None,
         None,
+        Some(State::S61),
+        None,
         None,
         None,
         None,
@@ -6878,6 +8169,7 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S62),
     ],
     [
         None,
@@ -6889,7 +8181,6 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S99),
         None,
         None,
         None,
@@ -6904,6 +8195,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S83),
+        Some(State::S84),
+        None,
         None,
         None,
         None,
@@ -6930,7 +8224,6 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S110),
         None,
         None,
         None,
@@ -6960,6 +8253,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
+        Some(State::S111),
     ],
     [
         None,
@@ -6973,7 +8269,6 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S56),
         None,
         None,
         None,
@@ -7000,7 +8295,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S57),
+        None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -7025,8 +8323,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S78),
-        Some(State::S79),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -7082,7 +8382,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S103),
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -7124,6 +8426,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7165,6 +8469,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7206,6 +8512,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7224,6 +8532,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S67),
+        None,
         None,
         None,
         None,
@@ -7288,6 +8598,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7307,6 +8619,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S69),
+        None,
         None,
         None,
         None,
@@ -7346,7 +8660,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S62),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -7411,6 +8727,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7429,7 +8747,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S64),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -7493,6 +8813,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7534,6 +8856,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7575,6 +8899,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7616,6 +8942,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7639,6 +8967,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S77),
+        None,
         None,
         None,
         None,
@@ -7698,6 +9028,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7739,6 +9071,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7761,9 +9095,11 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S72),
         None,
         None,
+        Some(State::S54),
+        Some(State::S82),
+        Some(State::S84),
         None,
         None,
         None,
@@ -7821,6 +9157,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7862,6 +9200,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7885,9 +9225,11 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S49),
-        Some(State::S77),
-        Some(State::S79),
+        None,
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -7944,6 +9286,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7985,6 +9329,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        Some(State::S85),
     ],
     [
         None,
@@ -8026,6 +9372,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8067,6 +9415,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8107,7 +9457,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S80),
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -8137,7 +9489,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        None,
+        Some(State::S89),
+        Some(State::S95),
+        Some(State::S11),
         None,
         None,
         None,
@@ -8183,6 +9537,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S90),
+        None,
         None,
         None,
         None,
@@ -8225,6 +9581,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        Some(State::S112),
+        None,
         None,
         None,
         None,
@@ -8258,9 +9616,11 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S84),
-        Some(State::S87),
-        Some(State::S11),
+        None,
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -8304,7 +9664,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S85),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -8346,7 +9708,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S104),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -8395,6 +9759,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8436,6 +9802,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8477,6 +9845,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8518,6 +9888,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8548,7 +9920,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S93),
+        None,
+        None,
+        Some(State::S101),
         None,
         None,
         None,
@@ -8589,7 +9963,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S95),
+        None,
+        None,
+        Some(State::S103),
         None,
         None,
         None,
@@ -8641,6 +10017,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8682,6 +10060,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8723,6 +10103,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8764,6 +10146,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8805,6 +10189,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8846,6 +10232,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8877,7 +10265,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S50),
+        None,
+        None,
+        Some(State::S55),
         None,
         None,
         None,
@@ -8886,7 +10276,7 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S102),
+        Some(State::S110),
     ],
     [
         None,
@@ -8928,6 +10318,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8969,6 +10361,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9000,7 +10394,9 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S51),
+        None,
+        None,
+        Some(State::S56),
         None,
         None,
         None,
@@ -9009,7 +10405,7 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S102),
+        Some(State::S110),
     ],
     [
         None,
@@ -9051,6 +10447,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9092,6 +10490,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9133,6 +10533,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9174,6 +10576,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9208,8 +10612,10 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
-        Some(State::S107),
-        Some(State::S109),
+        None,
+        None,
+        Some(State::S115),
+        Some(State::S117),
         None,
         None,
         None,
@@ -9256,6 +10662,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9297,6 +10705,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9338,6 +10748,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9379,6 +10791,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9420,6 +10834,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9461,6 +10877,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9502,6 +10920,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9543,6 +10963,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9584,6 +11006,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9625,6 +11049,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9666,6 +11092,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9707,6 +11135,8 @@ const GOTO_TABLE: [[Option<State>; 39]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
 ];
 
@@ -9791,6 +11221,17 @@ impl TryFrom<Node> for EnumDef {
     }
 }
 
+impl TryFrom<Node> for OptNominalKw {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptNominalKw(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl TryFrom<Node> for OptParenthesizedNonfunParamDefs {
     type Error = Node;
 
@@ -9989,6 +11430,17 @@ impl TryFrom<Node> for Match {
     }
 }
 
+impl TryFrom<Node> for Elim {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::Elim(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl TryFrom<Node> for OptMatchReturnTypeClause {
     type Error = Node;
 
@@ -10150,155 +11602,176 @@ impl Node {
             _ => Err(self),
         }
     }
-    
+
     fn try_into_r_paren_1(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::RParen(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_l_square_2(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::LSquare(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_r_square_3(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::RSquare(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_eq_4(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Eq(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_colon_5(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Colon(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_comma_6(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Comma(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_thin_arrow_7(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::ThinArrow(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_dash_8(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Dash(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_caret_9(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Caret(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_underscore_10(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Underscore(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_enum_kw_11(self) -> Result<crate::token::EnumKw, Self> {
+
+    fn try_into_question_11(self) -> Result<crate::token::ByteIndex, Self> {
+        match self {
+            Self::Question(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_enum_kw_12(self) -> Result<crate::token::EnumKw, Self> {
         match self {
             Self::EnumKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_def_kw_12(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_def_kw_13(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::DefKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_match_kw_13(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_match_kw_14(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::MatchKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_for_kw_14(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_for_kw_15(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::ForKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_case_kw_15(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_case_kw_16(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::CaseKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_use_kw_16(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_use_kw_17(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::UseKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_end_kw_17(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_end_kw_18(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::EndKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_dec_kw_18(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_dec_kw_19(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::DecKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_ident_19(self) -> Result<crate::token::Ident, Self> {
+
+    fn try_into_elim_kw_20(self) -> Result<crate::token::ByteIndex, Self> {
+        match self {
+            Self::ElimKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_nominal_kw_21(self) -> Result<crate::token::ByteIndex, Self> {
+        match self {
+            Self::NominalKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_ident_22(self) -> Result<crate::token::Ident, Self> {
         match self {
             Self::Ident(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_number_literal_20(self) -> Result<crate::token::NumberLiteral, Self> {
+
+    fn try_into_number_literal_23(self) -> Result<crate::token::NumberLiteral, Self> {
         match self {
             Self::NumberLiteral(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_string_literal_21(self) -> Result<crate::token::StringLiteral, Self> {
+
+    fn try_into_string_literal_24(self) -> Result<crate::token::StringLiteral, Self> {
         match self {
             Self::StringLiteral(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_universe_literal_22(self) -> Result<crate::token::UniverseLiteral, Self> {
+
+    fn try_into_universe_literal_25(self) -> Result<crate::token::UniverseLiteral, Self> {
         match self {
             Self::UniverseLiteral(t) => Ok(t),
             _ => Err(self),
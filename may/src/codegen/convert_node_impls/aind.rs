@@ -52,6 +52,14 @@ impl MayConverter {
         };
         let name = self.cache_string_value(name);
 
+        let tag = match &*expr.nominal {
+            mnode::OptNominalKw::None => None,
+            mnode::OptNominalKw::Some(_) => {
+                let tag = mint_ind_tag(&self.source_id, &expr.name.value, expr.name.start.0);
+                Some(self.cache_string_value(tag))
+            }
+        };
+
         let (_, index_types, _) = self.convert_optional_typed_param_defs_to_context_extension(
             expr.indices.to_std_option(),
             context,
@@ -68,6 +76,7 @@ impl MayConverter {
         Ok(znode::Ind {
             universe: universe_level,
             name,
+            tag,
             index_types,
             vcon_defs,
             aux_data: (),
@@ -104,7 +113,9 @@ impl MayConverter {
         let index_args =
             self.convert_optional_exprs(case.return_type.to_std_option(), extended_context)?;
 
+        let name = self.cache_string_value(StringValue(case.name.value.clone()));
         Ok(znode::VconDef {
+            name: Some(name),
             param_types,
             index_args,
             aux_data: (),
@@ -0,0 +1,30 @@
+use super::*;
+
+use zoc::eliminator::get_eliminator;
+
+impl MayConverter {
+    pub(crate) fn convert_elim<C: ContextToOwned>(
+        &mut self,
+        expr: &mnode::Elim,
+        context: Context,
+        converter: &C,
+    ) -> Result<(znode::Expr, C::Out), SemanticError> {
+        let (ind, _) = self.convert(&expr.ind, context, &DropContext)?;
+
+        // The target may be a parameterized ind applied to its params,
+        // so we normalize it before checking whether it is an ind.
+        let ind = match self.zo_typechecker.evaluator.eval(ind).into_raw() {
+            znode::Expr::Ind(ind) => ind,
+            _ => return Err(SemanticError::ElimTargetIsNotInd(expr.clone())),
+        };
+        let motive_universe = Universe {
+            level: UniverseLevel(expr.motive_universe.level),
+            erasable: expr.motive_universe.erasable,
+        };
+
+        let eliminator = get_eliminator(ind, motive_universe)
+            .map_err(|err| SemanticError::CannotGenerateEliminator(expr.clone(), err))?;
+        let converted_leaf = self.cache_expr(eliminator.term);
+        Ok((converted_leaf, converter.convert_context_to_owned(context)))
+    }
+}
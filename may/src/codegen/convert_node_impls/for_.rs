@@ -17,11 +17,13 @@ impl MayConverter {
 
         let param_types = self.cache_expr_vec(param_types);
 
-        let converted_leaf = self.cache_for(znode::For {
+        let for_ = znode::For {
             param_types,
             return_type,
             aux_data: (),
-        });
+        };
+        self.record_name_hints(&for_, &extension);
+        let converted_leaf = self.cache_for(for_);
         Ok((converted_leaf, converter.convert_context_to_owned(context)))
     }
 }
@@ -0,0 +1,12 @@
+use super::*;
+
+impl MayConverter {
+    pub(crate) fn convert_hole<C: ContextToOwned>(
+        &mut self,
+        context: Context,
+        converter: &C,
+    ) -> Result<(znode::Expr, C::Out), SemanticError> {
+        let converted_leaf = self.cache_hole(znode::HoleNode { aux_data: () });
+        Ok((converted_leaf, converter.convert_context_to_owned(context)))
+    }
+}
@@ -18,13 +18,15 @@ impl MayConverter {
 
         let cases = self.convert_match_cases(&expr.cases, context)?;
 
-        let converted_leaf = self.cache_match(znode::Match {
+        let match_ = znode::Match {
             matchee,
             return_type_arity,
             return_type,
             cases,
             aux_data: (),
-        });
+        };
+        self.record_name_hints(&match_, &extension);
+        let converted_leaf = self.cache_match(match_);
         Ok((converted_leaf, converter.convert_context_to_owned(context)))
     }
 
@@ -62,10 +64,12 @@ impl MayConverter {
 
         let (return_val, _) = self.convert(&case.return_val, context_with_params, &DropContext)?;
 
-        Ok(znode::MatchCase {
+        let case = znode::MatchCase {
             arity,
-            return_val,
+            return_val: Some(return_val),
             aux_data: (),
-        })
+        };
+        self.record_name_hints(&case, &extension);
+        Ok(case)
     }
 }
@@ -6,7 +6,9 @@ mod let_;
 
 mod afun;
 mod aind;
+mod elim;
 mod for_;
+mod hole;
 mod match_;
 mod universe;
 mod var_or_app;
@@ -30,6 +32,8 @@ impl MayConverter {
             mnode::Expr::For(e) => self.convert_for(e, context, converter),
             mnode::Expr::VarOrApp(e) => self.convert_var_or_app(e, context, converter),
             mnode::Expr::Universe(e) => self.convert_universe(e, context, converter),
+            mnode::Expr::Hole(_) => self.convert_hole(context, converter),
+            mnode::Expr::Elim(e) => self.convert_elim(e, context, converter),
         }
     }
 }
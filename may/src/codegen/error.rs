@@ -7,4 +7,6 @@ pub enum SemanticError {
     MultipleDashedParams(mnode::ParamDef, mnode::ParamDef),
     ReturnArityIsZero(mnode::ReturnArityLiteral),
     InvalidVconIndex(mnode::VconIndexLiteral),
+    ElimTargetIsNotInd(mnode::Elim),
+    CannotGenerateEliminator(mnode::Elim, zoc::eliminator::EliminatorError),
 }
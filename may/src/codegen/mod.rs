@@ -6,14 +6,16 @@ mod mnode {
 
 use zoc::syntax_tree::replace_debs::{DebUpshifter, ReplaceDebs};
 use zoc::{
-    hash::{Digest, GetDigest, NoHashHashMap},
+    hash::{Hashed, Interner, Rc},
+    pretty_print::NameHints,
+    syntax_tree::ast::mint_ind_tag,
     syntax_tree::ast::prelude::minimal_ast::{
         rc_hashed as bypass_cache_and_rc_hash, Deb, RcHashedVec, StringValue, Universe,
         UniverseLevel,
     },
 };
 
-use std::{collections::HashSet, rc::Rc};
+use std::collections::HashSet;
 
 mod cache_expr;
 
@@ -116,8 +118,35 @@ mod tests;
 /// Therefore, if you call this function with the above example code,
 /// `toprightmost_defs` will contain the Zo representations for `ind Bool` and `fun and`,
 /// in that order.
-pub fn may_to_zo(expr: &mnode::Expr) -> Result<(znode::Expr, Vec<znode::Expr>), SemanticError> {
-    MayConverter::default().convert(expr, Context::empty(), &ContextToUnshiftedSubstitutableDefs)
+///
+/// ## Source IDs
+///
+/// `source_id` identifies the source `expr` was parsed from (e.g., its path).
+/// It is folded into the tags of nominal inds,
+/// so that nominal declarations in different sources
+/// get different tags (see `mint_ind_tag`).
+pub fn may_to_zo(
+    expr: &mnode::Expr,
+    source_id: &str,
+) -> Result<(znode::Expr, Vec<znode::Expr>), SemanticError> {
+    MayConverter::new(source_id).convert(
+        expr,
+        Context::empty(),
+        &ContextToUnshiftedSubstitutableDefs,
+    )
+}
+
+/// Like `may_to_zo`, but also returns the names of the binders in `expr`,
+/// so that the converted expressions can be pretty printed
+/// with their original variable names.
+pub fn may_to_zo_with_name_hints(
+    expr: &mnode::Expr,
+    source_id: &str,
+) -> Result<(znode::Expr, Vec<znode::Expr>, NameHints), SemanticError> {
+    let mut converter = MayConverter::new(source_id);
+    let (converted_expr, toprightmost_defs) =
+        converter.convert(expr, Context::empty(), &ContextToUnshiftedSubstitutableDefs)?;
+    Ok((converted_expr, toprightmost_defs, converter.name_hints))
 }
 
 trait ContextToOwned {
@@ -161,11 +190,42 @@ fn get_unshifted_substitutable_defs_from_entries<'a>(
     })
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 struct MayConverter {
-    znode_cache: NoHashHashMap<Digest, znode::Expr>,
-    znode_vec_cache: NoHashHashMap<Digest, RcHashedVec<znode::Expr>>,
+    interner: Interner,
     str_val_cache: HashSet<Rc<StringValue>>,
+    name_hints: NameHints,
+    /// See `mint_ind_tag`.
+    source_id: String,
 
     zo_typechecker: zoc::typecheck::TypeChecker,
 }
+
+impl MayConverter {
+    fn new(source_id: &str) -> Self {
+        // The typechecker shares the converter's interner,
+        // so the nodes it creates are shared with the converted tree.
+        let interner = Interner::new();
+        Self {
+            interner: interner.clone(),
+            str_val_cache: HashSet::new(),
+            name_hints: NameHints::new(),
+            source_id: source_id.to_string(),
+            zo_typechecker: zoc::typecheck::TypeChecker::with_interner(interner),
+        }
+    }
+
+    /// Records the keys of `entries` as the names of the binders
+    /// introduced by `binder`.
+    fn record_name_hints<'a, T: std::hash::Hash>(
+        &mut self,
+        binder: &T,
+        entries: impl IntoIterator<Item = &'a UnshiftedEntry<'a>>,
+    ) {
+        let names = entries
+            .into_iter()
+            .map(|entry| entry.key.to_string())
+            .collect();
+        self.name_hints.insert(Hashed::new(binder).digest, names);
+    }
+}
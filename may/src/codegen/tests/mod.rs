@@ -10,6 +10,7 @@ fn parse_or_panic(src: &str) -> mnode::Expr {
 fn assert_expr_is_well_typed_under_empty_tcon(ast: znode::Expr) {
     use zoc::{
         eval::Normalized,
+        kernel,
         pretty_print::PrettyUnwrap,
         syntax_tree::{lexer::lex as zo_lex, parser::parse as zo_parse},
         typecheck::{LazyTypeContext, TypeChecker},
@@ -20,12 +21,22 @@ fn assert_expr_is_well_typed_under_empty_tcon(ast: znode::Expr) {
     let ost = zo_parse(tokens).unwrap();
 
     let empty = Normalized::<[_; 0]>::new();
-    TypeChecker::default()
+    let type_ = TypeChecker::default()
         .get_type(
             ost.into(),
             LazyTypeContext::Base(empty.as_ref().convert_ref()),
         )
         .pretty_unwrap();
+
+    // The kernel should agree with the typechecker.
+    let kernel_type = match kernel::check_closed_minimal(&ast) {
+        Ok(kernel_type) => kernel_type,
+        Err(err) => panic!("kernel rejected {src}: {err:?}"),
+    };
+    assert_eq!(
+        kernel::Term::from_minimal(type_.raw()).unwrap(),
+        kernel_type
+    );
 }
 
 fn assert_expr_is_ill_typed_under_empty_tcon(ast: znode::Expr) {
@@ -54,7 +65,7 @@ fn assert_expression_and_its_topright_defs_are_well_typed_under_empty_tcon(
     src: &str,
 ) -> znode::Expr {
     let cst = parse_or_panic(src);
-    let (converted_leaf, topright_defs) = may_to_zo(&cst).unwrap();
+    let (converted_leaf, topright_defs) = may_to_zo(&cst, "test.may").unwrap();
 
     assert_expr_is_well_typed_under_empty_tcon(converted_leaf.clone());
 
@@ -83,7 +94,7 @@ fun bad(n: Nat): Nat
 succ(succ(zero))
 "#;
     let cst = parse_or_panic(src);
-    let (converted_leaf, substitutable_defs) = may_to_zo(&cst).unwrap();
+    let (converted_leaf, substitutable_defs) = may_to_zo(&cst, "test.may").unwrap();
 
     assert_expr_is_well_typed_under_empty_tcon(converted_leaf.clone());
 
@@ -244,3 +255,137 @@ fn ill_typed_internal_def() {
         assert_expression_and_its_topright_defs_are_well_typed_under_empty_tcon(src);
     insta::assert_display_snapshot!(PrettyPrint(&converted_leaf));
 }
+
+#[test]
+fn elim() {
+    let src = include_str!("samples/elim.may");
+    let converted_leaf =
+        assert_expression_and_its_topright_defs_are_well_typed_under_empty_tcon(src);
+    let normalized = zoc::eval::Evaluator::default().eval(converted_leaf);
+    insta::assert_display_snapshot!(PrettyPrint(normalized.raw()));
+}
+
+#[test]
+fn elim_of_non_ind_is_rejected() {
+    let src = r#"
+ind Nat
+    case zero
+    case succ(_: Nat)
+    return Set0
+
+elim succ(zero) return Set0"#;
+    let err = may_to_zo(&parse_or_panic(src), "test.may").unwrap_err();
+    assert!(
+        matches!(err, SemanticError::ElimTargetIsNotInd(_)),
+        "expected ElimTargetIsNotInd, but got {err:?}"
+    );
+}
+
+fn meters_and_seconds_src(modifier: &str) -> String {
+    format!(
+        r#"
+ind {modifier}Meters "Unit"
+    case mk
+    return Set0
+
+let meters = mk
+
+ind {modifier}Seconds "Unit"
+    case mk
+    return Set0
+
+let id_seconds = afun(s: Seconds): Seconds s
+
+id_seconds(meters)"#
+    )
+}
+
+#[test]
+fn structural_inds_with_the_same_zo_name_are_interchangeable() {
+    let cst = parse_or_panic(&meters_and_seconds_src(""));
+    let (converted_leaf, _) = may_to_zo(&cst, "test.may").unwrap();
+    assert_expr_is_well_typed_under_empty_tcon(converted_leaf);
+}
+
+#[test]
+fn nominal_inds_with_the_same_zo_name_are_distinct() {
+    let cst = parse_or_panic(&meters_and_seconds_src("nominal "));
+    let (converted_leaf, _) = may_to_zo(&cst, "test.may").unwrap();
+    assert_expr_is_ill_typed_under_empty_tcon(converted_leaf);
+}
+
+#[test]
+fn nominal_inds_in_different_sources_are_distinct() {
+    use zoc::hash::GetDigest;
+
+    // Both declarations have the same name and byte index,
+    // so only the source ID tells them apart.
+    let cst = parse_or_panic(
+        r#"ind nominal Nat "Nat"
+    case zero
+    return Set0
+
+zero"#,
+    );
+    let (in_a, _) = may_to_zo(&cst, "a.may").unwrap();
+    let (in_a_again, _) = may_to_zo(&cst, "a.may").unwrap();
+    let (in_b, _) = may_to_zo(&cst, "b.may").unwrap();
+
+    assert_eq!(in_a.digest(), in_a_again.digest());
+    assert_ne!(in_a.digest(), in_b.digest());
+}
+
+fn typecheck_under_empty_tcon_with(
+    typechecker: &mut zoc::typecheck::TypeChecker,
+    ast: znode::Expr,
+) {
+    use zoc::{
+        eval::Normalized,
+        pretty_print::PrettyUnwrap,
+        syntax_tree::{lexer::lex as zo_lex, parser::parse as zo_parse},
+        typecheck::LazyTypeContext,
+    };
+
+    let src = PrettyPrint(&ast).to_string();
+    let tokens = zo_lex(&src).unwrap();
+    let ost = zo_parse(tokens).unwrap();
+
+    let empty = Normalized::<[_; 0]>::new();
+    typechecker
+        .get_type(
+            ost.into(),
+            LazyTypeContext::Base(empty.as_ref().convert_ref()),
+        )
+        .pretty_unwrap();
+}
+
+/// Typechecks the converted leaf and every topright def
+/// of each program, using a single typechecker.
+fn typecheck_programs_with(typechecker: &mut zoc::typecheck::TypeChecker, srcs: &[&str]) {
+    for src in srcs {
+        let cst = parse_or_panic(src);
+        let (converted_leaf, topright_defs) = may_to_zo(&cst, "test.may").unwrap();
+        typecheck_under_empty_tcon_with(typechecker, converted_leaf);
+        for def in topright_defs {
+            typecheck_under_empty_tcon_with(typechecker, def);
+        }
+    }
+}
+
+const LARGE_PROGRAMS: [&str; 3] = [
+    include_str!("samples/std.may"),
+    include_str!("samples/add_commutative.may"),
+    include_str!("samples/comprehensive_syntax_check.may"),
+];
+
+#[test]
+fn type_cache_hits_on_repeated_inds() {
+    let mut typechecker = zoc::typecheck::TypeChecker::default();
+    typecheck_programs_with(&mut typechecker, &LARGE_PROGRAMS);
+
+    let stats = typechecker.type_cache.stats;
+    assert!(
+        stats.hits > stats.misses,
+        "expected more hits than misses, but got {stats:?}"
+    );
+}
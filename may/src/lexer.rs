@@ -85,6 +85,7 @@ impl Lexer<'_> {
             '=' => self.out.push(Token::Eq(current_index)),
             ':' => self.out.push(Token::Colon(current_index)),
             ',' => self.out.push(Token::Comma(current_index)),
+            '?' => self.out.push(Token::Question(current_index)),
             '-' => self.state = State::Dash(current_index),
             '/' => self.state = State::Slash(current_index),
             _ => {
@@ -250,6 +251,8 @@ fn parse_word(s: &str, start: ByteIndex) -> Option<Token> {
         "case" => return Some(Token::CaseKw(start)),
         "return" => return Some(Token::ReturnKw(start)),
         "use" => return Some(Token::UseKw(start)),
+        "elim" => return Some(Token::ElimKw(start)),
+        "nominal" => return Some(Token::NominalKw(start)),
 
         _ => {}
     }
@@ -739,7 +742,7 @@ mod tests {
 
     #[test]
     fn keywords() {
-        let src = r#"_ let ind fun aind match afun For case return use Set0 Set1 Set33 Prop0 Prop1 Prop33 vcon0 vcon1 vcon33 return0 return1 return33"#;
+        let src = r#"_ let ind fun aind match afun For case return use elim nominal Set0 Set1 Set33 Prop0 Prop1 Prop33 vcon0 vcon1 vcon33 return0 return1 return33"#;
         let actual = lex(src);
         let expected = Ok(vec![
             Token::Underscore(ByteIndex(src.find("_").unwrap())),
@@ -753,6 +756,8 @@ mod tests {
             Token::CaseKw(ByteIndex(src.find("case").unwrap())),
             Token::ReturnKw(ByteIndex(src.find("return").unwrap())),
             Token::UseKw(ByteIndex(src.find("use").unwrap())),
+            Token::ElimKw(ByteIndex(src.find("elim").unwrap())),
+            Token::NominalKw(ByteIndex(src.find("nominal").unwrap())),
             Token::Universe(UniverseLiteral {
                 level: 0,
                 start: ByteIndex(src.find("Set0").unwrap()),
@@ -902,4 +907,22 @@ use)"#;
         let expected = Err(LexError(ByteIndex(0), ByteIndex(src.len())));
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn holes() {
+        let src = r#"f(?, ?)"#;
+        let actual = lex(src);
+        let expected = Ok(vec![
+            Token::Ident(Ident {
+                value: "f".to_owned(),
+                start: ByteIndex(0),
+            }),
+            Token::LParen(ByteIndex(1)),
+            Token::Question(ByteIndex(2)),
+            Token::Comma(ByteIndex(3)),
+            Token::Question(ByteIndex(5)),
+            Token::RParen(ByteIndex(6)),
+        ]);
+        assert_eq!(expected, actual);
+    }
 }
@@ -3,7 +3,7 @@
 // You can read more at https://crates.io/crates/kiki
 //
 // This code was generated from a grammar with the following hash:
-// @sha256 e548c27aeff2df8ac58c24d78e5c208b5afa169c62a902d14a584a2f51a220cc
+// @sha256 9bfbb4515e5f78bcff994e9143be6989e65d3807ea25b60ffba49e66bf852d1b
 
 // Since this code is automatically generated,
 // some parts may be unidiomatic.
@@ -25,6 +25,7 @@ pub enum Token {
     ThinArrow(crate::token::ByteIndex),
     Dash(crate::token::ByteIndex),
     Underscore(crate::token::ByteIndex),
+    Question(crate::token::ByteIndex),
     LetKw(crate::token::ByteIndex),
     IndKw(crate::token::ByteIndex),
     FunKw(crate::token::ByteIndex),
@@ -35,6 +36,8 @@ pub enum Token {
     CaseKw(crate::token::ByteIndex),
     ReturnKw(crate::token::ByteIndex),
     UseKw(crate::token::ByteIndex),
+    ElimKw(crate::token::ByteIndex),
+    NominalKw(crate::token::ByteIndex),
     Ident(crate::token::Ident),
     Number(crate::token::NumberLiteral),
     String(crate::token::StringLiteral),
@@ -75,6 +78,12 @@ pub enum Expr {
     Universe(
         crate::token::UniverseLiteral,
     ),
+    Hole(
+        crate::token::ByteIndex,
+    ),
+    Elim(
+        Box<Elim>,
+    ),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -94,6 +103,7 @@ pub struct Ind {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IndCommonInnards {
+    pub nominal: Box<OptNominalKw>,
     pub params: Box<OptParenthesizedParamDefs>,
     pub name: crate::token::Ident,
     pub custom_zo_name: Box<OptString>,
@@ -103,6 +113,14 @@ pub struct IndCommonInnards {
     pub universe: crate::token::UniverseLiteral,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OptNominalKw {
+    None,
+    Some(
+        crate::token::ByteIndex,
+    ),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum OptString {
     None,
@@ -360,6 +378,14 @@ pub struct For {
     pub return_type: Box<Expr>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Elim {
+    pub elimkw: crate::token::ByteIndex,
+    pub ind: Box<Expr>,
+    pub returnkw: crate::token::ByteIndex,
+    pub motive_universe: crate::token::UniverseLiteral,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum IdentOrUnderscore {
     Ident(
@@ -378,7 +404,7 @@ where S: IntoIterator<Item = Token> {
         .map(Quasiterminal::Terminal)
         .chain(std::iter::once(Quasiterminal::Eof))
         .peekable();
-    let mut states = vec![State::S15];
+    let mut states = vec![State::S16];
     let mut nodes: Vec<Node> = vec![];
     loop {
         let top_state = *states.last().unwrap();
@@ -427,23 +453,26 @@ enum QuasiterminalKind {
     ThinArrow = 7,
     Dash = 8,
     Underscore = 9,
-    LetKw = 10,
-    IndKw = 11,
-    FunKw = 12,
-    AindKw = 13,
-    MatchKw = 14,
-    AfunKw = 15,
-    ForKw = 16,
-    CaseKw = 17,
-    ReturnKw = 18,
-    UseKw = 19,
-    Ident = 20,
-    Number = 21,
-    String = 22,
-    Universe = 23,
-    VconIndex = 24,
-    ReturnArity = 25,
-    Eof = 26,
+    Question = 10,
+    LetKw = 11,
+    IndKw = 12,
+    FunKw = 13,
+    AindKw = 14,
+    MatchKw = 15,
+    AfunKw = 16,
+    ForKw = 17,
+    CaseKw = 18,
+    ReturnKw = 19,
+    UseKw = 20,
+    ElimKw = 21,
+    NominalKw = 22,
+    Ident = 23,
+    Number = 24,
+    String = 25,
+    Universe = 26,
+    VconIndex = 27,
+    ReturnArity = 28,
+    Eof = 29,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -452,37 +481,39 @@ enum NonterminalKind {
     Let = 1,
     Ind = 2,
     IndCommonInnards = 3,
-    OptString = 4,
-    OptParenthesizedParamDefs = 5,
-    ParenthesizedCommaSeparatedParamDefs = 6,
-    OptComma = 7,
-    CommaSeparatedParamDefs = 8,
-    ParamDef = 9,
-    OptDash = 10,
-    OptSquareBracketedParamDefs = 11,
-    SquareBracketedParamDefs = 12,
-    ZeroOrMoreIndCases = 13,
-    IndCase = 14,
-    OptColonSquareBracketedExprs = 15,
-    Fun = 16,
-    FunCommonInnards = 17,
-    Aind = 18,
-    Vcon = 19,
-    Match = 20,
-    ReturnArityClause = 21,
-    SquareBracketedCommaSeparatedIdentsOrUnderscores = 22,
-    CommaSeparatedIdentsOrUnderscores = 23,
-    ZeroOrMoreMatchCases = 24,
-    MatchCase = 25,
-    OptParenthesizedCommaSeparatedIdentsOrUnderscores = 26,
-    ParenthesizedCommaSeparatedIdentsOrUnderscores = 27,
-    Afun = 28,
-    OptIdent = 29,
-    App = 30,
-    VarOrApp = 31,
-    CommaSeparatedExprs = 32,
-    For = 33,
-    IdentOrUnderscore = 34,
+    OptNominalKw = 4,
+    OptString = 5,
+    OptParenthesizedParamDefs = 6,
+    ParenthesizedCommaSeparatedParamDefs = 7,
+    OptComma = 8,
+    CommaSeparatedParamDefs = 9,
+    ParamDef = 10,
+    OptDash = 11,
+    OptSquareBracketedParamDefs = 12,
+    SquareBracketedParamDefs = 13,
+    ZeroOrMoreIndCases = 14,
+    IndCase = 15,
+    OptColonSquareBracketedExprs = 16,
+    Fun = 17,
+    FunCommonInnards = 18,
+    Aind = 19,
+    Vcon = 20,
+    Match = 21,
+    ReturnArityClause = 22,
+    SquareBracketedCommaSeparatedIdentsOrUnderscores = 23,
+    CommaSeparatedIdentsOrUnderscores = 24,
+    ZeroOrMoreMatchCases = 25,
+    MatchCase = 26,
+    OptParenthesizedCommaSeparatedIdentsOrUnderscores = 27,
+    ParenthesizedCommaSeparatedIdentsOrUnderscores = 28,
+    Afun = 29,
+    OptIdent = 30,
+    App = 31,
+    VarOrApp = 32,
+    CommaSeparatedExprs = 33,
+    For = 34,
+    Elim = 35,
+    IdentOrUnderscore = 36,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -606,6 +637,14 @@ enum State {
     S116 = 116,
     S117 = 117,
     S118 = 118,
+    S119 = 119,
+    S120 = 120,
+    S121 = 121,
+    S122 = 122,
+    S123 = 123,
+    S124 = 124,
+    S125 = 125,
+    S126 = 126,
 }
 
 enum Node {
@@ -613,6 +652,7 @@ enum Node {
     Let(Let),
     Ind(Ind),
     IndCommonInnards(IndCommonInnards),
+    OptNominalKw(OptNominalKw),
     OptString(OptString),
     OptParenthesizedParamDefs(OptParenthesizedParamDefs),
     ParenthesizedCommaSeparatedParamDefs(ParenthesizedCommaSeparatedParamDefs),
@@ -643,6 +683,7 @@ enum Node {
     VarOrApp(VarOrApp),
     CommaSeparatedExprs(CommaSeparatedExprs),
     For(For),
+    Elim(Elim),
     IdentOrUnderscore(IdentOrUnderscore),
     LParen(crate::token::ByteIndex),
     RParen(crate::token::ByteIndex),
@@ -654,6 +695,7 @@ enum Node {
     ThinArrow(crate::token::ByteIndex),
     Dash(crate::token::ByteIndex),
     Underscore(crate::token::ByteIndex),
+    Question(crate::token::ByteIndex),
     LetKw(crate::token::ByteIndex),
     IndKw(crate::token::ByteIndex),
     FunKw(crate::token::ByteIndex),
@@ -664,6 +706,8 @@ enum Node {
     CaseKw(crate::token::ByteIndex),
     ReturnKw(crate::token::ByteIndex),
     UseKw(crate::token::ByteIndex),
+    ElimKw(crate::token::ByteIndex),
+    NominalKw(crate::token::ByteIndex),
     Ident(crate::token::Ident),
     Number(crate::token::NumberLiteral),
     String(crate::token::StringLiteral),
@@ -744,1032 +788,2115 @@ enum RuleKind {
     R59 = 59,
     R60 = 60,
     R61 = 61,
+    R62 = 62,
+    R63 = 63,
+    R64 = 64,
+    R65 = 65,
+    R66 = 66,
 }
 
 fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
     match rule_kind {
-        RuleKind::R0 => {
-            let t0 = Box::new(Let::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Let(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R1 => {
-            let t0 = Box::new(Ind::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Ind(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R2 => {
-            let t0 = Box::new(Fun::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Fun(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R3 => {
-            let t0 = Box::new(Aind::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Aind(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R4 => {
-            let t0 = Box::new(Vcon::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Vcon(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R5 => {
-            let t0 = Box::new(Match::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Match(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R6 => {
-            let t0 = Box::new(Afun::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Afun(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R7 => {
-            let t0 = Box::new(For::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::For(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R8 => {
-            let t0 = Box::new(VarOrApp::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::VarOrApp(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R9 => {
-            let t0 = nodes.pop().unwrap().try_into_universe_23().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Universe(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R10 => {
-            let next_val_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let val_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let name_1 = nodes.pop().unwrap().try_into_ident_20().ok().unwrap();
-            let letkw_0 = nodes.pop().unwrap().try_into_let_kw_10().ok().unwrap();
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::Let(Let {
-                    letkw: letkw_0,
-                    name: name_1,
-                    val: val_3,
-                    next_val: next_val_4,
-                }),
-                NonterminalKind::Let,
-            )
-        }
-        RuleKind::R11 => {
-            let next_val_2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let innards_1 = Box::new(IndCommonInnards::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let indkw_0 = nodes.pop().unwrap().try_into_ind_kw_11().ok().unwrap();
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::Ind(Ind {
-                    indkw: indkw_0,
-                    innards: innards_1,
-                    next_val: next_val_2,
-                }),
-                NonterminalKind::Ind,
-            )
-        }
-        RuleKind::R12 => {
-            let universe_6 = nodes.pop().unwrap().try_into_universe_23().ok().unwrap();
-            let returnkw_5 = nodes.pop().unwrap().try_into_return_kw_18().ok().unwrap();
-            let cases_4 = Box::new(ZeroOrMoreIndCases::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let indices_3 = Box::new(OptSquareBracketedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let custom_zo_name_2 = Box::new(OptString::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_1 = nodes.pop().unwrap().try_into_ident_20().ok().unwrap();
-            let params_0 = Box::new(OptParenthesizedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 7);
-            
-            (
-                Node::IndCommonInnards(IndCommonInnards {
-                    params: params_0,
-                    name: name_1,
-                    custom_zo_name: custom_zo_name_2,
-                    indices: indices_3,
-                    cases: cases_4,
-                    returnkw: returnkw_5,
-                    universe: universe_6,
-                }),
-                NonterminalKind::IndCommonInnards,
-            )
-        }
-        RuleKind::R13 => {
-            (
-                Node::OptString(OptString::None),
-                NonterminalKind::OptString,
-            )
-        }
-        RuleKind::R14 => {
-            let t0 = nodes.pop().unwrap().try_into_string_22().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptString(OptString::Some(
-                    t0,
-                )),
-                NonterminalKind::OptString,
-            )
-        }
-        RuleKind::R15 => {
-            (
-                Node::OptParenthesizedParamDefs(OptParenthesizedParamDefs::None),
-                NonterminalKind::OptParenthesizedParamDefs,
-            )
-        }
-        RuleKind::R16 => {
-            let t0 = Box::new(ParenthesizedCommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptParenthesizedParamDefs(OptParenthesizedParamDefs::Some(
-                    t0,
-                )),
-                NonterminalKind::OptParenthesizedParamDefs,
-            )
-        }
-        RuleKind::R17 => {
-            let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let params_1 = Box::new(CommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::ParenthesizedCommaSeparatedParamDefs(ParenthesizedCommaSeparatedParamDefs {
-                    lparen: lparen_0,
-                    params: params_1,
-                    trailing_comma: trailing_comma_2,
-                    rparen: rparen_3,
-                }),
-                NonterminalKind::ParenthesizedCommaSeparatedParamDefs,
-            )
-        }
-        RuleKind::R18 => {
-            (
-                Node::OptComma(OptComma::None),
-                NonterminalKind::OptComma,
-            )
-        }
-        RuleKind::R19 => {
-            let t0 = nodes.pop().unwrap().try_into_comma_6().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptComma(OptComma::Some(
-                    t0,
-                )),
-                NonterminalKind::OptComma,
-            )
-        }
-        RuleKind::R20 => {
-            let t0 = Box::new(ParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::CommaSeparatedParamDefs(CommaSeparatedParamDefs::One(
-                    t0,
-                )),
-                NonterminalKind::CommaSeparatedParamDefs,
-            )
-        }
-        RuleKind::R21 => {
-            let t2 = Box::new(ParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let t0 = Box::new(CommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::CommaSeparatedParamDefs(CommaSeparatedParamDefs::Snoc(
-                    t0,
-                    t2,
-                )),
-                NonterminalKind::CommaSeparatedParamDefs,
-            )
-        }
-        RuleKind::R22 => {
-            let type__3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let name_1 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let dash_0 = Box::new(OptDash::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::ParamDef(ParamDef {
-                    dash: dash_0,
-                    name: name_1,
-                    type_: type__3,
-                }),
-                NonterminalKind::ParamDef,
-            )
-        }
-        RuleKind::R23 => {
-            (
-                Node::OptDash(OptDash::None),
-                NonterminalKind::OptDash,
-            )
-        }
-        RuleKind::R24 => {
-            let t0 = nodes.pop().unwrap().try_into_dash_8().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptDash(OptDash::Some(
-                    t0,
-                )),
-                NonterminalKind::OptDash,
-            )
-        }
-        RuleKind::R25 => {
-            (
-                Node::OptSquareBracketedParamDefs(OptSquareBracketedParamDefs::None),
-                NonterminalKind::OptSquareBracketedParamDefs,
-            )
-        }
-        RuleKind::R26 => {
-            let t0 = Box::new(SquareBracketedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptSquareBracketedParamDefs(OptSquareBracketedParamDefs::Some(
-                    t0,
-                )),
-                NonterminalKind::OptSquareBracketedParamDefs,
-            )
-        }
-        RuleKind::R27 => {
-            let rsquare_3 = nodes.pop().unwrap().try_into_r_square_3().ok().unwrap();
-            let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let params_1 = Box::new(CommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lsquare_0 = nodes.pop().unwrap().try_into_l_square_2().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::SquareBracketedParamDefs(SquareBracketedParamDefs {
-                    lsquare: lsquare_0,
-                    params: params_1,
-                    trailing_comma: trailing_comma_2,
-                    rsquare: rsquare_3,
-                }),
-                NonterminalKind::SquareBracketedParamDefs,
-            )
-        }
-        RuleKind::R28 => {
-            (
-                Node::ZeroOrMoreIndCases(ZeroOrMoreIndCases::Nil),
-                NonterminalKind::ZeroOrMoreIndCases,
-            )
-        }
-        RuleKind::R29 => {
-            let t1 = Box::new(IndCase::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(ZeroOrMoreIndCases::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::ZeroOrMoreIndCases(ZeroOrMoreIndCases::Snoc(
-                    t0,
-                    t1,
-                )),
-                NonterminalKind::ZeroOrMoreIndCases,
-            )
-        }
-        RuleKind::R30 => {
-            let return_type_3 = Box::new(OptColonSquareBracketedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let params_2 = Box::new(OptParenthesizedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_1 = nodes.pop().unwrap().try_into_ident_20().ok().unwrap();
-            let casekw_0 = nodes.pop().unwrap().try_into_case_kw_17().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::IndCase(IndCase {
-                    casekw: casekw_0,
-                    name: name_1,
-                    params: params_2,
-                    return_type: return_type_3,
-                }),
-                NonterminalKind::IndCase,
-            )
-        }
-        RuleKind::R31 => {
-            (
-                Node::OptColonSquareBracketedExprs(OptColonSquareBracketedExprs::None),
-                NonterminalKind::OptColonSquareBracketedExprs,
-            )
-        }
-        RuleKind::R32 => {
-            nodes.pop().unwrap();
-            nodes.pop().unwrap();
-            let t2 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::OptColonSquareBracketedExprs(OptColonSquareBracketedExprs::Some(
-                    t2,
-                )),
-                NonterminalKind::OptColonSquareBracketedExprs,
-            )
-        }
-        RuleKind::R33 => {
-            let next_val_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let innards_2 = Box::new(FunCommonInnards::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_1 = nodes.pop().unwrap().try_into_ident_20().ok().unwrap();
-            let funkw_0 = nodes.pop().unwrap().try_into_fun_kw_12().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::Fun(Fun {
-                    funkw: funkw_0,
-                    name: name_1,
-                    innards: innards_2,
-                    next_val: next_val_3,
-                }),
-                NonterminalKind::Fun,
-            )
-        }
-        RuleKind::R34 => {
-            let return_val_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let return_type_2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let params_0 = Box::new(ParenthesizedCommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::FunCommonInnards(FunCommonInnards {
-                    params: params_0,
-                    return_type: return_type_2,
-                    return_val: return_val_3,
-                }),
-                NonterminalKind::FunCommonInnards,
-            )
-        }
-        RuleKind::R35 => {
-            let innards_1 = Box::new(IndCommonInnards::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let aindkw_0 = nodes.pop().unwrap().try_into_aind_kw_13().ok().unwrap();
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::Aind(Aind {
-                    aindkw: aindkw_0,
-                    innards: innards_1,
-                }),
-                NonterminalKind::Aind,
-            )
-        }
-        RuleKind::R36 => {
-            let innards_1 = Box::new(IndCommonInnards::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let vcon_index_0 = nodes.pop().unwrap().try_into_vcon_index_24().ok().unwrap();
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::Vcon(Vcon {
-                    vcon_index: vcon_index_0,
-                    innards: innards_1,
-                }),
-                NonterminalKind::Vcon,
-            )
-        }
-        RuleKind::R37 => {
-            let return_type_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let return_arity_3 = Box::new(ReturnArityClause::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let cases_2 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let matchee_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let matchkw_0 = nodes.pop().unwrap().try_into_match_kw_14().ok().unwrap();
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::Match(Match {
-                    matchkw: matchkw_0,
-                    matchee: matchee_1,
-                    cases: cases_2,
-                    return_arity: return_arity_3,
-                    return_type: return_type_4,
-                }),
-                NonterminalKind::Match,
-            )
-        }
-        RuleKind::R38 => {
-            let t0 = nodes.pop().unwrap().try_into_return_arity_25().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::ReturnArityClause(ReturnArityClause::Unnamed(
-                    t0,
-                )),
-                NonterminalKind::ReturnArityClause,
-            )
-        }
-        RuleKind::R39 => {
-            let t2 = nodes.pop().unwrap().try_into_return_arity_25().ok().unwrap();
-            let t1 = nodes.pop().unwrap().try_into_ident_20().ok().unwrap();
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::ReturnArityClause(ReturnArityClause::Matchee(
-                    t1,
-                    t2,
-                )),
-                NonterminalKind::ReturnArityClause,
-            )
-        }
-        RuleKind::R40 => {
-            nodes.pop().unwrap();
-            let t1 = Box::new(SquareBracketedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::ReturnArityClause(ReturnArityClause::Indices(
-                    t1,
-                )),
-                NonterminalKind::ReturnArityClause,
-            )
-        }
-        RuleKind::R41 => {
-            nodes.pop().unwrap();
-            let t3 = Box::new(SquareBracketedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let t1 = nodes.pop().unwrap().try_into_ident_20().ok().unwrap();
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::ReturnArityClause(ReturnArityClause::MatcheeAndIndices(
-                    t1,
-                    t3,
-                )),
-                NonterminalKind::ReturnArityClause,
-            )
-        }
-        RuleKind::R42 => {
-            let rsquare_3 = nodes.pop().unwrap().try_into_r_square_3().ok().unwrap();
-            let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let idents_1 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lsquare_0 = nodes.pop().unwrap().try_into_l_square_2().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::SquareBracketedCommaSeparatedIdentsOrUnderscores(SquareBracketedCommaSeparatedIdentsOrUnderscores {
-                    lsquare: lsquare_0,
-                    idents: idents_1,
-                    trailing_comma: trailing_comma_2,
-                    rsquare: rsquare_3,
-                }),
-                NonterminalKind::SquareBracketedCommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R43 => {
-            let t0 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::CommaSeparatedIdentsOrUnderscores(CommaSeparatedIdentsOrUnderscores::One(
-                    t0,
-                )),
-                NonterminalKind::CommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R44 => {
-            let t2 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let t0 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::CommaSeparatedIdentsOrUnderscores(CommaSeparatedIdentsOrUnderscores::Snoc(
-                    t0,
-                    t2,
-                )),
-                NonterminalKind::CommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R45 => {
-            (
-                Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Nil),
-                NonterminalKind::ZeroOrMoreMatchCases,
-            )
-        }
-        RuleKind::R46 => {
-            let t1 = Box::new(MatchCase::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Snoc(
-                    t0,
-                    t1,
-                )),
-                NonterminalKind::ZeroOrMoreMatchCases,
-            )
-        }
-        RuleKind::R47 => {
-            let return_val_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let params_2 = Box::new(OptParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_1 = nodes.pop().unwrap().try_into_ident_20().ok().unwrap();
-            let casekw_0 = nodes.pop().unwrap().try_into_case_kw_17().ok().unwrap();
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::MatchCase(MatchCase {
-                    casekw: casekw_0,
-                    name: name_1,
-                    params: params_2,
-                    return_val: return_val_4,
-                }),
-                NonterminalKind::MatchCase,
-            )
-        }
-        RuleKind::R48 => {
-            (
-                Node::OptParenthesizedCommaSeparatedIdentsOrUnderscores(OptParenthesizedCommaSeparatedIdentsOrUnderscores::None),
-                NonterminalKind::OptParenthesizedCommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R49 => {
-            let t0 = Box::new(ParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptParenthesizedCommaSeparatedIdentsOrUnderscores(OptParenthesizedCommaSeparatedIdentsOrUnderscores::Some(
-                    t0,
-                )),
-                NonterminalKind::OptParenthesizedCommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R50 => {
-            let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let idents_1 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::ParenthesizedCommaSeparatedIdentsOrUnderscores(ParenthesizedCommaSeparatedIdentsOrUnderscores {
-                    lparen: lparen_0,
-                    idents: idents_1,
-                    trailing_comma: trailing_comma_2,
-                    rparen: rparen_3,
-                }),
-                NonterminalKind::ParenthesizedCommaSeparatedIdentsOrUnderscores,
-            )
-        }
-        RuleKind::R51 => {
-            let innards_2 = Box::new(FunCommonInnards::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_1 = Box::new(OptIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let afunkw_0 = nodes.pop().unwrap().try_into_afun_kw_15().ok().unwrap();
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::Afun(Afun {
-                    afunkw: afunkw_0,
-                    name: name_1,
-                    innards: innards_2,
-                }),
-                NonterminalKind::Afun,
-            )
-        }
-        RuleKind::R52 => {
-            (
-                Node::OptIdent(OptIdent::None),
-                NonterminalKind::OptIdent,
-            )
-        }
-        RuleKind::R53 => {
-            let t0 = nodes.pop().unwrap().try_into_ident_20().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::OptIdent(OptIdent::Some(
-                    t0,
-                )),
-                NonterminalKind::OptIdent,
-            )
-        }
-        RuleKind::R54 => {
-            let rparen_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let trailing_comma_3 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let args_2 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lparen_1 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            let callee_0 = Box::new(VarOrApp::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::App(App {
-                    callee: callee_0,
-                    lparen: lparen_1,
-                    args: args_2,
-                    trailing_comma: trailing_comma_3,
-                    rparen: rparen_4,
-                }),
-                NonterminalKind::App,
-            )
-        }
-        RuleKind::R55 => {
-            let t0 = nodes.pop().unwrap().try_into_ident_20().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::VarOrApp(VarOrApp::Var(
-                    t0,
-                )),
-                NonterminalKind::VarOrApp,
-            )
-        }
-        RuleKind::R56 => {
-            let t0 = Box::new(App::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::VarOrApp(VarOrApp::App(
-                    t0,
-                )),
-                NonterminalKind::VarOrApp,
-            )
-        }
-        RuleKind::R57 => {
-            let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::CommaSeparatedExprs(CommaSeparatedExprs::One(
-                    t0,
-                )),
-                NonterminalKind::CommaSeparatedExprs,
-            )
-        }
-        RuleKind::R58 => {
-            let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let t0 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 3);
-            
-            (
-                Node::CommaSeparatedExprs(CommaSeparatedExprs::Snoc(
-                    t0,
-                    t2,
-                )),
-                NonterminalKind::CommaSeparatedExprs,
-            )
-        }
-        RuleKind::R59 => {
-            let return_type_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let params_1 = Box::new(ParenthesizedCommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let forkw_0 = nodes.pop().unwrap().try_into_for_kw_16().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::For(For {
-                    forkw: forkw_0,
-                    params: params_1,
-                    return_type: return_type_3,
-                }),
-                NonterminalKind::For,
-            )
-        }
-        RuleKind::R60 => {
-            let t0 = nodes.pop().unwrap().try_into_ident_20().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::IdentOrUnderscore(IdentOrUnderscore::Ident(
-                    t0,
-                )),
-                NonterminalKind::IdentOrUnderscore,
-            )
-        }
-        RuleKind::R61 => {
-            let t0 = nodes.pop().unwrap().try_into_underscore_9().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::IdentOrUnderscore(IdentOrUnderscore::Underscore(
-                    t0,
-                )),
-                NonterminalKind::IdentOrUnderscore,
-            )
-        }
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+        RuleKind::R6 => reduce_r6(states, nodes),
+        RuleKind::R7 => reduce_r7(states, nodes),
+        RuleKind::R8 => reduce_r8(states, nodes),
+        RuleKind::R9 => reduce_r9(states, nodes),
+        RuleKind::R10 => reduce_r10(states, nodes),
+        RuleKind::R11 => reduce_r11(states, nodes),
+        RuleKind::R12 => reduce_r12(states, nodes),
+        RuleKind::R13 => reduce_r13(states, nodes),
+        RuleKind::R14 => reduce_r14(states, nodes),
+        RuleKind::R15 => reduce_r15(states, nodes),
+        RuleKind::R16 => reduce_r16(states, nodes),
+        RuleKind::R17 => reduce_r17(states, nodes),
+        RuleKind::R18 => reduce_r18(states, nodes),
+        RuleKind::R19 => reduce_r19(states, nodes),
+        RuleKind::R20 => reduce_r20(states, nodes),
+        RuleKind::R21 => reduce_r21(states, nodes),
+        RuleKind::R22 => reduce_r22(states, nodes),
+        RuleKind::R23 => reduce_r23(states, nodes),
+        RuleKind::R24 => reduce_r24(states, nodes),
+        RuleKind::R25 => reduce_r25(states, nodes),
+        RuleKind::R26 => reduce_r26(states, nodes),
+        RuleKind::R27 => reduce_r27(states, nodes),
+        RuleKind::R28 => reduce_r28(states, nodes),
+        RuleKind::R29 => reduce_r29(states, nodes),
+        RuleKind::R30 => reduce_r30(states, nodes),
+        RuleKind::R31 => reduce_r31(states, nodes),
+        RuleKind::R32 => reduce_r32(states, nodes),
+        RuleKind::R33 => reduce_r33(states, nodes),
+        RuleKind::R34 => reduce_r34(states, nodes),
+        RuleKind::R35 => reduce_r35(states, nodes),
+        RuleKind::R36 => reduce_r36(states, nodes),
+        RuleKind::R37 => reduce_r37(states, nodes),
+        RuleKind::R38 => reduce_r38(states, nodes),
+        RuleKind::R39 => reduce_r39(states, nodes),
+        RuleKind::R40 => reduce_r40(states, nodes),
+        RuleKind::R41 => reduce_r41(states, nodes),
+        RuleKind::R42 => reduce_r42(states, nodes),
+        RuleKind::R43 => reduce_r43(states, nodes),
+        RuleKind::R44 => reduce_r44(states, nodes),
+        RuleKind::R45 => reduce_r45(states, nodes),
+        RuleKind::R46 => reduce_r46(states, nodes),
+        RuleKind::R47 => reduce_r47(states, nodes),
+        RuleKind::R48 => reduce_r48(states, nodes),
+        RuleKind::R49 => reduce_r49(states, nodes),
+        RuleKind::R50 => reduce_r50(states, nodes),
+        RuleKind::R51 => reduce_r51(states, nodes),
+        RuleKind::R52 => reduce_r52(states, nodes),
+        RuleKind::R53 => reduce_r53(states, nodes),
+        RuleKind::R54 => reduce_r54(states, nodes),
+        RuleKind::R55 => reduce_r55(states, nodes),
+        RuleKind::R56 => reduce_r56(states, nodes),
+        RuleKind::R57 => reduce_r57(states, nodes),
+        RuleKind::R58 => reduce_r58(states, nodes),
+        RuleKind::R59 => reduce_r59(states, nodes),
+        RuleKind::R60 => reduce_r60(states, nodes),
+        RuleKind::R61 => reduce_r61(states, nodes),
+        RuleKind::R62 => reduce_r62(states, nodes),
+        RuleKind::R63 => reduce_r63(states, nodes),
+        RuleKind::R64 => reduce_r64(states, nodes),
+        RuleKind::R65 => reduce_r65(states, nodes),
+        RuleKind::R66 => reduce_r66(states, nodes),
     }
 }
 
-impl QuasiterminalKind {
-    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
-        match quasiterminal {
-            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
-            Quasiterminal::Eof => Self::Eof,
-        }
-    }
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Let::try_from(nodes.pop().unwrap()).ok().unwrap());
 
-    fn from_terminal(terminal: &Token) -> Self {
-        match terminal {
-            Token::LParen(_) => Self::LParen,
-            Token::RParen(_) => Self::RParen,
-            Token::LSquare(_) => Self::LSquare,
-            Token::RSquare(_) => Self::RSquare,
-            Token::Eq(_) => Self::Eq,
-            Token::Colon(_) => Self::Colon,
-            Token::Comma(_) => Self::Comma,
-            Token::ThinArrow(_) => Self::ThinArrow,
-            Token::Dash(_) => Self::Dash,
-            Token::Underscore(_) => Self::Underscore,
-            Token::LetKw(_) => Self::LetKw,
-            Token::IndKw(_) => Self::IndKw,
-            Token::FunKw(_) => Self::FunKw,
-            Token::AindKw(_) => Self::AindKw,
-            Token::MatchKw(_) => Self::MatchKw,
-            Token::AfunKw(_) => Self::AfunKw,
-            Token::ForKw(_) => Self::ForKw,
-            Token::CaseKw(_) => Self::CaseKw,
-            Token::ReturnKw(_) => Self::ReturnKw,
-            Token::UseKw(_) => Self::UseKw,
-            Token::Ident(_) => Self::Ident,
-            Token::Number(_) => Self::Number,
-            Token::String(_) => Self::String,
-            Token::Universe(_) => Self::Universe,
-            Token::VconIndex(_) => Self::VconIndex,
-            Token::ReturnArity(_) => Self::ReturnArity,
-        }
-    }
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Let(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
 }
 
-impl Node {
-    fn from_terminal(terminal: Token) -> Self {
-        match terminal {
-            Token::LParen(t) => Self::LParen(t),
-            Token::RParen(t) => Self::RParen(t),
-            Token::LSquare(t) => Self::LSquare(t),
-            Token::RSquare(t) => Self::RSquare(t),
-            Token::Eq(t) => Self::Eq(t),
-            Token::Colon(t) => Self::Colon(t),
-            Token::Comma(t) => Self::Comma(t),
-            Token::ThinArrow(t) => Self::ThinArrow(t),
-            Token::Dash(t) => Self::Dash(t),
-            Token::Underscore(t) => Self::Underscore(t),
-            Token::LetKw(t) => Self::LetKw(t),
-            Token::IndKw(t) => Self::IndKw(t),
-            Token::FunKw(t) => Self::FunKw(t),
-            Token::AindKw(t) => Self::AindKw(t),
-            Token::MatchKw(t) => Self::MatchKw(t),
-            Token::AfunKw(t) => Self::AfunKw(t),
-            Token::ForKw(t) => Self::ForKw(t),
-            Token::CaseKw(t) => Self::CaseKw(t),
-            Token::ReturnKw(t) => Self::ReturnKw(t),
-            Token::UseKw(t) => Self::UseKw(t),
-            Token::Ident(t) => Self::Ident(t),
-            Token::Number(t) => Self::Number(t),
-            Token::String(t) => Self::String(t),
-            Token::Universe(t) => Self::Universe(t),
-            Token::VconIndex(t) => Self::VconIndex(t),
-            Token::ReturnArity(t) => Self::ReturnArity(t),
-        }
-    }
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Ind::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Ind(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
 }
 
-impl Quasiterminal {
-    fn try_into_terminal(self) -> Result<Token, ()> {
-        match self {
-            Self::Terminal(terminal) => Ok(terminal),
-            Self::Eof => Err(()),
-        }
-    }
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Fun::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Fun(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
 }
 
-const ACTION_TABLE: [[Action; 27]; 119] = [
-    [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S110),
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
-        Action::Err,
-        Action::Err,
-    ],
-    [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S110),
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
-        Action::Err,
-        Action::Err,
-    ],
-    [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S110),
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
-        Action::Err,
-        Action::Err,
-    ],
-    [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S110),
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
-        Action::Err,
-        Action::Err,
-    ],
-    [
-        Action::Err,
-        Action::Err,
-        Action::Err,
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Aind::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Aind(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Vcon::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Vcon(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Match::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Match(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r6(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Afun::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Afun(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r7(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(For::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::For(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r8(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(VarOrApp::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::VarOrApp(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r9(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_universe_26().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Universe(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r10(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_question_10().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Hole(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r11(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Elim::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Elim(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r12(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let next_val_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let val_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let name_1 = nodes.pop().unwrap().try_into_ident_23().ok().unwrap();
+    let letkw_0 = nodes.pop().unwrap().try_into_let_kw_11().ok().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::Let(Let {
+            letkw: letkw_0,
+            name: name_1,
+            val: val_3,
+            next_val: next_val_4,
+        }),
+        NonterminalKind::Let,
+    )
+}
+
+fn reduce_r13(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let next_val_2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let innards_1 = Box::new(IndCommonInnards::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let indkw_0 = nodes.pop().unwrap().try_into_ind_kw_12().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Ind(Ind {
+            indkw: indkw_0,
+            innards: innards_1,
+            next_val: next_val_2,
+        }),
+        NonterminalKind::Ind,
+    )
+}
+
+fn reduce_r14(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let universe_7 = nodes.pop().unwrap().try_into_universe_26().ok().unwrap();
+    let returnkw_6 = nodes.pop().unwrap().try_into_return_kw_19().ok().unwrap();
+    let cases_5 = Box::new(ZeroOrMoreIndCases::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let indices_4 = Box::new(OptSquareBracketedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let custom_zo_name_3 = Box::new(OptString::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_2 = nodes.pop().unwrap().try_into_ident_23().ok().unwrap();
+    let params_1 = Box::new(OptParenthesizedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let nominal_0 = Box::new(OptNominalKw::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 8);
+
+    (
+        Node::IndCommonInnards(IndCommonInnards {
+            nominal: nominal_0,
+            params: params_1,
+            name: name_2,
+            custom_zo_name: custom_zo_name_3,
+            indices: indices_4,
+            cases: cases_5,
+            returnkw: returnkw_6,
+            universe: universe_7,
+        }),
+        NonterminalKind::IndCommonInnards,
+    )
+}
+
+fn reduce_r15(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptNominalKw(OptNominalKw::None),
+        NonterminalKind::OptNominalKw,
+    )
+}
+
+fn reduce_r16(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_nominal_kw_22().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptNominalKw(OptNominalKw::Some(
+            t0,
+        )),
+        NonterminalKind::OptNominalKw,
+    )
+}
+
+fn reduce_r17(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptString(OptString::None),
+        NonterminalKind::OptString,
+    )
+}
+
+fn reduce_r18(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_string_25().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptString(OptString::Some(
+            t0,
+        )),
+        NonterminalKind::OptString,
+    )
+}
+
+fn reduce_r19(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptParenthesizedParamDefs(OptParenthesizedParamDefs::None),
+        NonterminalKind::OptParenthesizedParamDefs,
+    )
+}
+
+fn reduce_r20(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(ParenthesizedCommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptParenthesizedParamDefs(OptParenthesizedParamDefs::Some(
+            t0,
+        )),
+        NonterminalKind::OptParenthesizedParamDefs,
+    )
+}
+
+fn reduce_r21(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let params_1 = Box::new(CommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::ParenthesizedCommaSeparatedParamDefs(ParenthesizedCommaSeparatedParamDefs {
+            lparen: lparen_0,
+            params: params_1,
+            trailing_comma: trailing_comma_2,
+            rparen: rparen_3,
+        }),
+        NonterminalKind::ParenthesizedCommaSeparatedParamDefs,
+    )
+}
+
+fn reduce_r22(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptComma(OptComma::None),
+        NonterminalKind::OptComma,
+    )
+}
+
+fn reduce_r23(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_comma_6().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptComma(OptComma::Some(
+            t0,
+        )),
+        NonterminalKind::OptComma,
+    )
+}
+
+fn reduce_r24(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(ParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::CommaSeparatedParamDefs(CommaSeparatedParamDefs::One(
+            t0,
+        )),
+        NonterminalKind::CommaSeparatedParamDefs,
+    )
+}
+
+fn reduce_r25(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(ParamDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(CommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::CommaSeparatedParamDefs(CommaSeparatedParamDefs::Snoc(
+            t0,
+            t2,
+        )),
+        NonterminalKind::CommaSeparatedParamDefs,
+    )
+}
+
+fn reduce_r26(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let type__3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let name_1 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let dash_0 = Box::new(OptDash::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::ParamDef(ParamDef {
+            dash: dash_0,
+            name: name_1,
+            type_: type__3,
+        }),
+        NonterminalKind::ParamDef,
+    )
+}
+
+fn reduce_r27(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptDash(OptDash::None),
+        NonterminalKind::OptDash,
+    )
+}
+
+fn reduce_r28(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_dash_8().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptDash(OptDash::Some(
+            t0,
+        )),
+        NonterminalKind::OptDash,
+    )
+}
+
+fn reduce_r29(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptSquareBracketedParamDefs(OptSquareBracketedParamDefs::None),
+        NonterminalKind::OptSquareBracketedParamDefs,
+    )
+}
+
+fn reduce_r30(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(SquareBracketedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptSquareBracketedParamDefs(OptSquareBracketedParamDefs::Some(
+            t0,
+        )),
+        NonterminalKind::OptSquareBracketedParamDefs,
+    )
+}
+
+fn reduce_r31(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rsquare_3 = nodes.pop().unwrap().try_into_r_square_3().ok().unwrap();
+    let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let params_1 = Box::new(CommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lsquare_0 = nodes.pop().unwrap().try_into_l_square_2().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::SquareBracketedParamDefs(SquareBracketedParamDefs {
+            lsquare: lsquare_0,
+            params: params_1,
+            trailing_comma: trailing_comma_2,
+            rsquare: rsquare_3,
+        }),
+        NonterminalKind::SquareBracketedParamDefs,
+    )
+}
+
+fn reduce_r32(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::ZeroOrMoreIndCases(ZeroOrMoreIndCases::Nil),
+        NonterminalKind::ZeroOrMoreIndCases,
+    )
+}
+
+fn reduce_r33(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(IndCase::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let t0 = Box::new(ZeroOrMoreIndCases::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::ZeroOrMoreIndCases(ZeroOrMoreIndCases::Snoc(
+            t0,
+            t1,
+        )),
+        NonterminalKind::ZeroOrMoreIndCases,
+    )
+}
+
+fn reduce_r34(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let return_type_3 = Box::new(OptColonSquareBracketedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let params_2 = Box::new(OptParenthesizedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_1 = nodes.pop().unwrap().try_into_ident_23().ok().unwrap();
+    let casekw_0 = nodes.pop().unwrap().try_into_case_kw_18().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::IndCase(IndCase {
+            casekw: casekw_0,
+            name: name_1,
+            params: params_2,
+            return_type: return_type_3,
+        }),
+        NonterminalKind::IndCase,
+    )
+}
+
+fn reduce_r35(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptColonSquareBracketedExprs(OptColonSquareBracketedExprs::None),
+        NonterminalKind::OptColonSquareBracketedExprs,
+    )
+}
+
+fn reduce_r36(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    nodes.pop().unwrap();
+    let t2 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::OptColonSquareBracketedExprs(OptColonSquareBracketedExprs::Some(
+            t2,
+        )),
+        NonterminalKind::OptColonSquareBracketedExprs,
+    )
+}
+
+fn reduce_r37(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let next_val_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let innards_2 = Box::new(FunCommonInnards::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_1 = nodes.pop().unwrap().try_into_ident_23().ok().unwrap();
+    let funkw_0 = nodes.pop().unwrap().try_into_fun_kw_13().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::Fun(Fun {
+            funkw: funkw_0,
+            name: name_1,
+            innards: innards_2,
+            next_val: next_val_3,
+        }),
+        NonterminalKind::Fun,
+    )
+}
+
+fn reduce_r38(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let return_val_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let return_type_2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let params_0 = Box::new(ParenthesizedCommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::FunCommonInnards(FunCommonInnards {
+            params: params_0,
+            return_type: return_type_2,
+            return_val: return_val_3,
+        }),
+        NonterminalKind::FunCommonInnards,
+    )
+}
+
+fn reduce_r39(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let innards_1 = Box::new(IndCommonInnards::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let aindkw_0 = nodes.pop().unwrap().try_into_aind_kw_14().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::Aind(Aind {
+            aindkw: aindkw_0,
+            innards: innards_1,
+        }),
+        NonterminalKind::Aind,
+    )
+}
+
+fn reduce_r40(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let innards_1 = Box::new(IndCommonInnards::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let vcon_index_0 = nodes.pop().unwrap().try_into_vcon_index_27().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::Vcon(Vcon {
+            vcon_index: vcon_index_0,
+            innards: innards_1,
+        }),
+        NonterminalKind::Vcon,
+    )
+}
+
+fn reduce_r41(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let return_type_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let return_arity_3 = Box::new(ReturnArityClause::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let cases_2 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let matchee_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let matchkw_0 = nodes.pop().unwrap().try_into_match_kw_15().ok().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::Match(Match {
+            matchkw: matchkw_0,
+            matchee: matchee_1,
+            cases: cases_2,
+            return_arity: return_arity_3,
+            return_type: return_type_4,
+        }),
+        NonterminalKind::Match,
+    )
+}
+
+fn reduce_r42(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_return_arity_28().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::ReturnArityClause(ReturnArityClause::Unnamed(
+            t0,
+        )),
+        NonterminalKind::ReturnArityClause,
+    )
+}
+
+fn reduce_r43(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = nodes.pop().unwrap().try_into_return_arity_28().ok().unwrap();
+    let t1 = nodes.pop().unwrap().try_into_ident_23().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::ReturnArityClause(ReturnArityClause::Matchee(
+            t1,
+            t2,
+        )),
+        NonterminalKind::ReturnArityClause,
+    )
+}
+
+fn reduce_r44(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = Box::new(SquareBracketedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::ReturnArityClause(ReturnArityClause::Indices(
+            t1,
+        )),
+        NonterminalKind::ReturnArityClause,
+    )
+}
+
+fn reduce_r45(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t3 = Box::new(SquareBracketedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t1 = nodes.pop().unwrap().try_into_ident_23().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::ReturnArityClause(ReturnArityClause::MatcheeAndIndices(
+            t1,
+            t3,
+        )),
+        NonterminalKind::ReturnArityClause,
+    )
+}
+
+fn reduce_r46(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rsquare_3 = nodes.pop().unwrap().try_into_r_square_3().ok().unwrap();
+    let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let idents_1 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lsquare_0 = nodes.pop().unwrap().try_into_l_square_2().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::SquareBracketedCommaSeparatedIdentsOrUnderscores(SquareBracketedCommaSeparatedIdentsOrUnderscores {
+            lsquare: lsquare_0,
+            idents: idents_1,
+            trailing_comma: trailing_comma_2,
+            rsquare: rsquare_3,
+        }),
+        NonterminalKind::SquareBracketedCommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r47(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::CommaSeparatedIdentsOrUnderscores(CommaSeparatedIdentsOrUnderscores::One(
+            t0,
+        )),
+        NonterminalKind::CommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r48(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::CommaSeparatedIdentsOrUnderscores(CommaSeparatedIdentsOrUnderscores::Snoc(
+            t0,
+            t2,
+        )),
+        NonterminalKind::CommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r49(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Nil),
+        NonterminalKind::ZeroOrMoreMatchCases,
+    )
+}
+
+fn reduce_r50(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(MatchCase::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let t0 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Snoc(
+            t0,
+            t1,
+        )),
+        NonterminalKind::ZeroOrMoreMatchCases,
+    )
+}
+
+fn reduce_r51(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let return_val_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let params_2 = Box::new(OptParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_1 = nodes.pop().unwrap().try_into_ident_23().ok().unwrap();
+    let casekw_0 = nodes.pop().unwrap().try_into_case_kw_18().ok().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::MatchCase(MatchCase {
+            casekw: casekw_0,
+            name: name_1,
+            params: params_2,
+            return_val: return_val_4,
+        }),
+        NonterminalKind::MatchCase,
+    )
+}
+
+fn reduce_r52(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptParenthesizedCommaSeparatedIdentsOrUnderscores(OptParenthesizedCommaSeparatedIdentsOrUnderscores::None),
+        NonterminalKind::OptParenthesizedCommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r53(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(ParenthesizedCommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptParenthesizedCommaSeparatedIdentsOrUnderscores(OptParenthesizedCommaSeparatedIdentsOrUnderscores::Some(
+            t0,
+        )),
+        NonterminalKind::OptParenthesizedCommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r54(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let trailing_comma_2 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let idents_1 = Box::new(CommaSeparatedIdentsOrUnderscores::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::ParenthesizedCommaSeparatedIdentsOrUnderscores(ParenthesizedCommaSeparatedIdentsOrUnderscores {
+            lparen: lparen_0,
+            idents: idents_1,
+            trailing_comma: trailing_comma_2,
+            rparen: rparen_3,
+        }),
+        NonterminalKind::ParenthesizedCommaSeparatedIdentsOrUnderscores,
+    )
+}
+
+fn reduce_r55(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let innards_2 = Box::new(FunCommonInnards::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_1 = Box::new(OptIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let afunkw_0 = nodes.pop().unwrap().try_into_afun_kw_16().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Afun(Afun {
+            afunkw: afunkw_0,
+            name: name_1,
+            innards: innards_2,
+        }),
+        NonterminalKind::Afun,
+    )
+}
+
+fn reduce_r56(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptIdent(OptIdent::None),
+        NonterminalKind::OptIdent,
+    )
+}
+
+fn reduce_r57(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_ident_23().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptIdent(OptIdent::Some(
+            t0,
+        )),
+        NonterminalKind::OptIdent,
+    )
+}
+
+fn reduce_r58(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let trailing_comma_3 = Box::new(OptComma::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let args_2 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lparen_1 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+    let callee_0 = Box::new(VarOrApp::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::App(App {
+            callee: callee_0,
+            lparen: lparen_1,
+            args: args_2,
+            trailing_comma: trailing_comma_3,
+            rparen: rparen_4,
+        }),
+        NonterminalKind::App,
+    )
+}
+
+fn reduce_r59(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_ident_23().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::VarOrApp(VarOrApp::Var(
+            t0,
+        )),
+        NonterminalKind::VarOrApp,
+    )
+}
+
+fn reduce_r60(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(App::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::VarOrApp(VarOrApp::App(
+            t0,
+        )),
+        NonterminalKind::VarOrApp,
+    )
+}
+
+fn reduce_r61(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::CommaSeparatedExprs(CommaSeparatedExprs::One(
+            t0,
+        )),
+        NonterminalKind::CommaSeparatedExprs,
+    )
+}
+
+fn reduce_r62(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(CommaSeparatedExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::CommaSeparatedExprs(CommaSeparatedExprs::Snoc(
+            t0,
+            t2,
+        )),
+        NonterminalKind::CommaSeparatedExprs,
+    )
+}
+
+fn reduce_r63(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let return_type_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let params_1 = Box::new(ParenthesizedCommaSeparatedParamDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let forkw_0 = nodes.pop().unwrap().try_into_for_kw_17().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::For(For {
+            forkw: forkw_0,
+            params: params_1,
+            return_type: return_type_3,
+        }),
+        NonterminalKind::For,
+    )
+}
+
+fn reduce_r64(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let motive_universe_3 = nodes.pop().unwrap().try_into_universe_26().ok().unwrap();
+    let returnkw_2 = nodes.pop().unwrap().try_into_return_kw_19().ok().unwrap();
+    let ind_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let elimkw_0 = nodes.pop().unwrap().try_into_elim_kw_21().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::Elim(Elim {
+            elimkw: elimkw_0,
+            ind: ind_1,
+            returnkw: returnkw_2,
+            motive_universe: motive_universe_3,
+        }),
+        NonterminalKind::Elim,
+    )
+}
+
+fn reduce_r65(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_ident_23().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::IdentOrUnderscore(IdentOrUnderscore::Ident(
+            t0,
+        )),
+        NonterminalKind::IdentOrUnderscore,
+    )
+}
+
+fn reduce_r66(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_underscore_9().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::IdentOrUnderscore(IdentOrUnderscore::Underscore(
+            t0,
+        )),
+        NonterminalKind::IdentOrUnderscore,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::LParen(_) => Self::LParen,
+            Token::RParen(_) => Self::RParen,
+            Token::LSquare(_) => Self::LSquare,
+            Token::RSquare(_) => Self::RSquare,
+            Token::Eq(_) => Self::Eq,
+            Token::Colon(_) => Self::Colon,
+            Token::Comma(_) => Self::Comma,
+            Token::ThinArrow(_) => Self::ThinArrow,
+            Token::Dash(_) => Self::Dash,
+            Token::Underscore(_) => Self::Underscore,
+            Token::Question(_) => Self::Question,
+            Token::LetKw(_) => Self::LetKw,
+            Token::IndKw(_) => Self::IndKw,
+            Token::FunKw(_) => Self::FunKw,
+            Token::AindKw(_) => Self::AindKw,
+            Token::MatchKw(_) => Self::MatchKw,
+            Token::AfunKw(_) => Self::AfunKw,
+            Token::ForKw(_) => Self::ForKw,
+            Token::CaseKw(_) => Self::CaseKw,
+            Token::ReturnKw(_) => Self::ReturnKw,
+            Token::UseKw(_) => Self::UseKw,
+            Token::ElimKw(_) => Self::ElimKw,
+            Token::NominalKw(_) => Self::NominalKw,
+            Token::Ident(_) => Self::Ident,
+            Token::Number(_) => Self::Number,
+            Token::String(_) => Self::String,
+            Token::Universe(_) => Self::Universe,
+            Token::VconIndex(_) => Self::VconIndex,
+            Token::ReturnArity(_) => Self::ReturnArity,
+        }
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::LParen(t) => Self::LParen(t),
+            Token::RParen(t) => Self::RParen(t),
+            Token::LSquare(t) => Self::LSquare(t),
+            Token::RSquare(t) => Self::RSquare(t),
+            Token::Eq(t) => Self::Eq(t),
+            Token::Colon(t) => Self::Colon(t),
+            Token::Comma(t) => Self::Comma(t),
+            Token::ThinArrow(t) => Self::ThinArrow(t),
+            Token::Dash(t) => Self::Dash(t),
+            Token::Underscore(t) => Self::Underscore(t),
+            Token::Question(t) => Self::Question(t),
+            Token::LetKw(t) => Self::LetKw(t),
+            Token::IndKw(t) => Self::IndKw(t),
+            Token::FunKw(t) => Self::FunKw(t),
+            Token::AindKw(t) => Self::AindKw(t),
+            Token::MatchKw(t) => Self::MatchKw(t),
+            Token::AfunKw(t) => Self::AfunKw(t),
+            Token::ForKw(t) => Self::ForKw(t),
+            Token::CaseKw(t) => Self::CaseKw(t),
+            Token::ReturnKw(t) => Self::ReturnKw(t),
+            Token::UseKw(t) => Self::UseKw(t),
+            Token::ElimKw(t) => Self::ElimKw(t),
+            Token::NominalKw(t) => Self::NominalKw(t),
+            Token::Ident(t) => Self::Ident(t),
+            Token::Number(t) => Self::Number(t),
+            Token::String(t) => Self::String(t),
+            Token::Universe(t) => Self::Universe(t),
+            Token::VconIndex(t) => Self::VconIndex(t),
+            Token::ReturnArity(t) => Self::ReturnArity(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 30]; 127] = [
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S31),
+        Action::Shift(State::S78),
+        Action::Shift(State::S33),
+        Action::Shift(State::S9),
+        Action::Shift(State::S110),
+        Action::Shift(State::S53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Shift(State::S115),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+    ],
+    [
+        Action::Shift(State::S13),
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S29),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+    ],
+    [
+        Action::Reduce(RuleKind::R15),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1777,27 +2904,61 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S43),
+        Action::Reduce(RuleKind::R15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R13),
         Action::Err,
+        Action::Reduce(RuleKind::R13),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R13),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Err,
+        Action::Reduce(RuleKind::R13),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+    ],
+    [
+        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1806,25 +2967,35 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S43),
+        Action::Reduce(RuleKind::R15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
+        Action::Reduce(RuleKind::R15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1835,25 +3006,28 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
+        Action::Err,
+        Action::Shift(State::S43),
+        Action::Reduce(RuleKind::R15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
+        Action::Shift(State::S47),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1864,56 +3038,57 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R19),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R0),
         Action::Err,
-        Action::Reduce(RuleKind::R0),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R0),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
         Action::Err,
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
-        Action::Reduce(RuleKind::R0),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S37),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
     ],
     [
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R17),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1922,21 +3097,87 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S44),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S61),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R29),
+        Action::Reduce(RuleKind::R29),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R32),
+        Action::Reduce(RuleKind::R32),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
     ],
@@ -1951,54 +3192,43 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S72),
+        Action::Shift(State::S41),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R19),
         Action::Err,
-        Action::Reduce(RuleKind::R19),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2009,54 +3239,57 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
+        Action::Err,
+        Action::Shift(State::S42),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R14),
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
+        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
-    ],
-    [
+        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
         Action::Err,
+        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+    ],
+    [
+        Action::Reduce(RuleKind::R16),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2067,27 +3300,19 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
         Action::Err,
+        Action::Reduce(RuleKind::R16),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
         Action::Err,
         Action::Err,
     ],
     [
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R18),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2096,289 +3321,130 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S25),
-        Action::Shift(State::S28),
-        Action::Shift(State::S73),
-        Action::Shift(State::S30),
-        Action::Shift(State::S9),
-        Action::Shift(State::S105),
-        Action::Shift(State::S48),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S110),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
-        Action::Shift(State::S31),
         Action::Err,
         Action::Err,
-    ],
-    [
+        Action::Reduce(RuleKind::R18),
+        Action::Reduce(RuleKind::R18),
         Action::Err,
-        Action::Reduce(RuleKind::R1),
         Action::Err,
-        Action::Reduce(RuleKind::R1),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R1),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
         Action::Err,
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
     ],
     [
+        Action::Shift(State::S47),
         Action::Err,
-        Action::Reduce(RuleKind::R2),
         Action::Err,
-        Action::Reduce(RuleKind::R2),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R19),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
         Action::Err,
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R3),
         Action::Err,
-        Action::Reduce(RuleKind::R3),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R3),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R19),
+        Action::Reduce(RuleKind::R19),
         Action::Err,
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
         Action::Err,
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R4),
         Action::Err,
-        Action::Reduce(RuleKind::R4),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R4),
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
         Action::Err,
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R20),
         Action::Err,
-        Action::Reduce(RuleKind::R5),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R5),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
         Action::Err,
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R6),
         Action::Err,
-        Action::Reduce(RuleKind::R6),
         Action::Err,
+        Action::Reduce(RuleKind::R20),
+        Action::Reduce(RuleKind::R20),
         Action::Err,
-        Action::Reduce(RuleKind::R6),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R20),
         Action::Err,
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
         Action::Err,
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R7),
         Action::Err,
-        Action::Reduce(RuleKind::R7),
+    ],
+    [
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R7),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
         Action::Err,
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-    ],
-    [
-        Action::Shift(State::S13),
-        Action::Reduce(RuleKind::R8),
+        Action::Shift(State::S67),
+        Action::Reduce(RuleKind::R27),
         Action::Err,
-        Action::Reduce(RuleKind::R8),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R8),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
         Action::Err,
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R27),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
     ],
     [
+        Action::Err,
+        Action::Reduce(RuleKind::R22),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S59),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2396,7 +3462,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2405,11 +3470,14 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
     ],
     [
+        Action::Err,
+        Action::Shift(State::S50),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S6),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2435,93 +3503,58 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R10),
         Action::Err,
-        Action::Reduce(RuleKind::R10),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R10),
         Action::Err,
+        Action::Reduce(RuleKind::R21),
         Action::Err,
+        Action::Reduce(RuleKind::R21),
         Action::Err,
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
         Action::Err,
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-    ],
-    [
-        Action::Shift(State::S42),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R21),
+        Action::Reduce(RuleKind::R21),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R21),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
+        Action::Shift(State::S47),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R11),
         Action::Err,
-        Action::Reduce(RuleKind::R11),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R11),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
         Action::Err,
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-    ],
-    [
-        Action::Shift(State::S42),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2531,6 +3564,9 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
+        Action::Shift(State::S47),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2541,16 +3577,12 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Shift(State::S42),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2564,21 +3596,21 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
+        Action::Shift(State::S47),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2596,21 +3628,21 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S33),
         Action::Err,
         Action::Err,
+        Action::Shift(State::S60),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R13),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2625,21 +3657,19 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R13),
-        Action::Reduce(RuleKind::R13),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S39),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
+        Action::Shift(State::S11),
         Action::Err,
-        Action::Shift(State::S56),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2654,8 +3684,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R25),
-        Action::Reduce(RuleKind::R25),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2669,8 +3697,10 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
+        Action::Shift(State::S59),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2683,28 +3713,26 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R28),
-        Action::Reduce(RuleKind::R28),
-        Action::Err,
-        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
+        Action::Shift(State::S11),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2712,8 +3740,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S67),
-        Action::Shift(State::S37),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2722,8 +3748,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2732,11 +3756,15 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
+        Action::Shift(State::S60),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2747,47 +3775,31 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S38),
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R12),
         Action::Err,
-        Action::Reduce(RuleKind::R12),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R12),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
         Action::Err,
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
         Action::Err,
-        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S67),
+        Action::Reduce(RuleKind::R27),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2799,10 +3811,9 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R27),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2811,14 +3822,16 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
     ],
     [
-        Action::Shift(State::S42),
         Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
+        Action::Shift(State::S125),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2828,12 +3841,13 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S124),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2845,10 +3859,11 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R16),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S67),
+        Action::Reduce(RuleKind::R27),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2857,10 +3872,12 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R16),
-        Action::Reduce(RuleKind::R16),
         Action::Err,
-        Action::Reduce(RuleKind::R16),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R27),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2869,16 +3886,20 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
     ],
     [
+        Action::Err,
+        Action::Reduce(RuleKind::R24),
+        Action::Err,
+        Action::Reduce(RuleKind::R24),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R24),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S62),
-        Action::Reduce(RuleKind::R23),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2889,7 +3910,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R23),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2899,12 +3919,15 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R18),
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R25),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S54),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2928,7 +3951,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
     ],
     [
         Action::Err,
-        Action::Shift(State::S45),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2937,6 +3959,7 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S125),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2950,6 +3973,9 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S124),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2961,9 +3987,15 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R17),
+        Action::Shift(State::S12),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R17),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2973,10 +4005,7 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R17),
-        Action::Reduce(RuleKind::R17),
         Action::Err,
-        Action::Reduce(RuleKind::R17),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -2985,10 +4014,13 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
     ],
     [
-        Action::Shift(State::S42),
         Action::Err,
+        Action::Reduce(RuleKind::R26),
+        Action::Err,
+        Action::Reduce(RuleKind::R26),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3014,7 +4046,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
     ],
     [
-        Action::Shift(State::S42),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3024,6 +4055,9 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R28),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3035,6 +4069,7 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R28),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3043,7 +4078,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
     ],
     [
-        Action::Shift(State::S42),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3062,6 +4096,10 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R30),
+        Action::Reduce(RuleKind::R30),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3073,12 +4111,15 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R18),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S70),
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S55),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3102,12 +4143,10 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R18),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S11),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3121,6 +4160,11 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3133,10 +4177,8 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R18),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S54),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3150,6 +4192,11 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R33),
+        Action::Reduce(RuleKind::R33),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3162,10 +4209,7 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R18),
-        Action::Err,
         Action::Err,
-        Action::Shift(State::S11),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3185,21 +4229,21 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S45),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R18),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S55),
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S75),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3212,22 +4256,20 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R19),
         Action::Err,
-        Action::Reduce(RuleKind::R19),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S62),
-        Action::Reduce(RuleKind::R23),
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3237,44 +4279,40 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R23),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R19),
         Action::Err,
-        Action::Reduce(RuleKind::R19),
         Action::Err,
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S117),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
+        Action::Shift(State::S14),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S116),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3283,8 +4321,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S62),
-        Action::Reduce(RuleKind::R23),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3295,22 +4331,19 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R23),
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S77),
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R20),
         Action::Err,
-        Action::Reduce(RuleKind::R20),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R20),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3334,12 +4367,9 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R21),
         Action::Err,
-        Action::Reduce(RuleKind::R21),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R21),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3354,6 +4384,12 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R36),
+        Action::Reduce(RuleKind::R36),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3371,8 +4407,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S117),
-        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3382,51 +4416,67 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S116),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Err,
-    ],
-    [
+        Action::Shift(State::S51),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S12),
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R37),
         Action::Err,
+        Action::Reduce(RuleKind::R37),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R37),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
         Action::Err,
+        Action::Reduce(RuleKind::R37),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S7),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R22),
         Action::Err,
-        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3442,40 +4492,102 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R38),
         Action::Err,
+        Action::Reduce(RuleKind::R38),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R38),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
         Action::Err,
+        Action::Reduce(RuleKind::R38),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R39),
         Action::Err,
+        Action::Reduce(RuleKind::R39),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R24),
+        Action::Reduce(RuleKind::R39),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
         Action::Err,
+        Action::Reduce(RuleKind::R39),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R40),
         Action::Err,
+        Action::Reduce(RuleKind::R40),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R24),
+        Action::Reduce(RuleKind::R40),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
         Action::Err,
+        Action::Reduce(RuleKind::R40),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
     ],
     [
         Action::Err,
@@ -3495,8 +4607,10 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R26),
-        Action::Reduce(RuleKind::R26),
+        Action::Err,
+        Action::Reduce(RuleKind::R49),
+        Action::Err,
+        Action::Reduce(RuleKind::R49),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3504,13 +4618,13 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R49),
         Action::Err,
     ],
     [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S65),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3526,6 +4640,9 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S103),
+        Action::Err,
+        Action::Shift(State::S88),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3533,16 +4650,42 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S87),
         Action::Err,
     ],
     [
+        Action::Err,
+        Action::Reduce(RuleKind::R41),
+        Action::Err,
+        Action::Reduce(RuleKind::R41),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R41),
+        Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
         Action::Err,
+        Action::Reduce(RuleKind::R41),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3553,20 +4696,31 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R27),
-        Action::Reduce(RuleKind::R27),
+        Action::Reduce(RuleKind::R42),
+        Action::Reduce(RuleKind::R42),
+        Action::Reduce(RuleKind::R42),
+        Action::Reduce(RuleKind::R42),
+        Action::Reduce(RuleKind::R42),
+        Action::Reduce(RuleKind::R42),
+        Action::Reduce(RuleKind::R42),
+        Action::Reduce(RuleKind::R42),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R42),
         Action::Err,
+        Action::Reduce(RuleKind::R42),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R42),
+        Action::Reduce(RuleKind::R42),
         Action::Err,
         Action::Err,
     ],
     [
         Action::Err,
         Action::Err,
+        Action::Shift(State::S96),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3582,27 +4736,26 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R29),
-        Action::Reduce(RuleKind::R29),
-        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S89),
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S93),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3614,22 +4767,21 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S40),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S70),
+        Action::Shift(State::S90),
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3640,14 +4792,24 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R31),
-        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R43),
+        Action::Reduce(RuleKind::R43),
+        Action::Reduce(RuleKind::R43),
+        Action::Reduce(RuleKind::R43),
+        Action::Reduce(RuleKind::R43),
+        Action::Reduce(RuleKind::R43),
+        Action::Reduce(RuleKind::R43),
+        Action::Reduce(RuleKind::R43),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R43),
         Action::Err,
+        Action::Reduce(RuleKind::R43),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R43),
+        Action::Reduce(RuleKind::R43),
         Action::Err,
         Action::Err,
     ],
@@ -3669,27 +4831,21 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R30),
-        Action::Reduce(RuleKind::R30),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S92),
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
-        Action::Shift(State::S14),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3700,20 +4856,31 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R44),
+        Action::Reduce(RuleKind::R44),
+        Action::Reduce(RuleKind::R44),
+        Action::Reduce(RuleKind::R44),
+        Action::Reduce(RuleKind::R44),
+        Action::Reduce(RuleKind::R44),
+        Action::Reduce(RuleKind::R44),
+        Action::Reduce(RuleKind::R44),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R44),
         Action::Err,
+        Action::Reduce(RuleKind::R44),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R44),
+        Action::Reduce(RuleKind::R44),
         Action::Err,
         Action::Err,
     ],
     [
         Action::Err,
         Action::Err,
-        Action::Err,
-        Action::Shift(State::S72),
+        Action::Shift(State::S96),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3737,12 +4904,12 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3756,18 +4923,15 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R32),
-        Action::Reduce(RuleKind::R32),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S95),
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3776,6 +4940,8 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3786,52 +4952,44 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R45),
+        Action::Reduce(RuleKind::R45),
+        Action::Reduce(RuleKind::R45),
+        Action::Reduce(RuleKind::R45),
+        Action::Reduce(RuleKind::R45),
+        Action::Reduce(RuleKind::R45),
+        Action::Reduce(RuleKind::R45),
+        Action::Reduce(RuleKind::R45),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S46),
         Action::Err,
+        Action::Reduce(RuleKind::R45),
         Action::Err,
+        Action::Reduce(RuleKind::R45),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R45),
+        Action::Reduce(RuleKind::R45),
         Action::Err,
         Action::Err,
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R33),
         Action::Err,
-        Action::Reduce(RuleKind::R33),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R33),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
         Action::Err,
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
         Action::Err,
+        Action::Shift(State::S125),
         Action::Err,
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S7),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3839,109 +4997,56 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S124),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
+        Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S98),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R34),
         Action::Err,
-        Action::Reduce(RuleKind::R34),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R34),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
         Action::Err,
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R35),
         Action::Err,
-        Action::Reduce(RuleKind::R35),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R35),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
         Action::Err,
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
-        Action::Reduce(RuleKind::R35),
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R36),
-        Action::Err,
-        Action::Reduce(RuleKind::R36),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R36),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
         Action::Err,
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
-        Action::Reduce(RuleKind::R36),
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3952,6 +5057,7 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R46),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3959,23 +5065,21 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R45),
         Action::Err,
-        Action::Reduce(RuleKind::R45),
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R45),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S125),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -3988,47 +5092,29 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S98),
         Action::Err,
-        Action::Shift(State::S83),
+        Action::Shift(State::S124),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S82),
         Action::Err,
     ],
     [
         Action::Err,
-        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R47),
         Action::Err,
-        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R47),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R47),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
         Action::Err,
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
-        Action::Reduce(RuleKind::R37),
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4039,28 +5125,22 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R38),
-        Action::Reduce(RuleKind::R38),
-        Action::Reduce(RuleKind::R38),
-        Action::Reduce(RuleKind::R38),
-        Action::Reduce(RuleKind::R38),
-        Action::Reduce(RuleKind::R38),
-        Action::Reduce(RuleKind::R38),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R38),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R38),
-        Action::Reduce(RuleKind::R38),
         Action::Err,
         Action::Err,
     ],
     [
+        Action::Err,
+        Action::Reduce(RuleKind::R48),
+        Action::Err,
+        Action::Reduce(RuleKind::R48),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S91),
+        Action::Reduce(RuleKind::R48),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4078,7 +5158,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S84),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4092,7 +5171,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S88),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4106,13 +5184,17 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R50),
+        Action::Err,
+        Action::Reduce(RuleKind::R50),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S85),
+        Action::Reduce(RuleKind::R50),
         Action::Err,
     ],
     [
@@ -4126,44 +5208,36 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R39),
-        Action::Reduce(RuleKind::R39),
-        Action::Reduce(RuleKind::R39),
-        Action::Reduce(RuleKind::R39),
-        Action::Reduce(RuleKind::R39),
-        Action::Reduce(RuleKind::R39),
-        Action::Reduce(RuleKind::R39),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R39),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R39),
-        Action::Reduce(RuleKind::R39),
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S104),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
+        Action::Shift(State::S99),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R52),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S87),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4172,8 +5246,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4184,28 +5256,18 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R40),
-        Action::Reduce(RuleKind::R40),
-        Action::Reduce(RuleKind::R40),
-        Action::Reduce(RuleKind::R40),
-        Action::Reduce(RuleKind::R40),
-        Action::Reduce(RuleKind::R40),
-        Action::Reduce(RuleKind::R40),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R40),
         Action::Err,
+    ],
+    [
         Action::Err,
-        Action::Reduce(RuleKind::R40),
-        Action::Reduce(RuleKind::R40),
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
-        Action::Shift(State::S91),
+        Action::Shift(State::S10),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4250,7 +5312,9 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S90),
+        Action::Reduce(RuleKind::R51),
+        Action::Err,
+        Action::Reduce(RuleKind::R51),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4258,6 +5322,7 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R51),
         Action::Err,
     ],
     [
@@ -4266,30 +5331,12 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R53),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R41),
-        Action::Reduce(RuleKind::R41),
-        Action::Reduce(RuleKind::R41),
-        Action::Reduce(RuleKind::R41),
-        Action::Reduce(RuleKind::R41),
-        Action::Reduce(RuleKind::R41),
-        Action::Reduce(RuleKind::R41),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R41),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R41),
-        Action::Reduce(RuleKind::R41),
-        Action::Err,
-        Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4299,7 +5346,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S117),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4310,19 +5356,18 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S116),
+    ],
+    [
         Action::Err,
+        Action::Shift(State::S109),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S93),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4343,13 +5388,14 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R54),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4366,7 +5412,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R42),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4377,6 +5422,7 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
     ],
     [
+        Action::Reduce(RuleKind::R56),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4386,8 +5432,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S117),
-        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4397,32 +5441,52 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S116),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S112),
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R43),
         Action::Err,
-        Action::Reduce(RuleKind::R43),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R43),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R55),
         Action::Err,
+        Action::Reduce(RuleKind::R55),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R55),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
         Action::Err,
+        Action::Reduce(RuleKind::R55),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+        Action::Reduce(RuleKind::R55),
+    ],
+    [
+        Action::Reduce(RuleKind::R57),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4433,15 +5497,10 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R44),
         Action::Err,
-        Action::Reduce(RuleKind::R44),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R44),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4457,13 +5516,14 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Shift(State::S114),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4481,53 +5541,120 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R46),
         Action::Err,
-        Action::Reduce(RuleKind::R46),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R46),
         Action::Err,
     ],
     [
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Err,
+        Action::Reduce(RuleKind::R58),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R58),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
         Action::Err,
+        Action::Reduce(RuleKind::R58),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+        Action::Reduce(RuleKind::R58),
+    ],
+    [
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
         Action::Err,
+        Action::Reduce(RuleKind::R59),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R59),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
         Action::Err,
+        Action::Reduce(RuleKind::R59),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+        Action::Reduce(RuleKind::R59),
+    ],
+    [
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
         Action::Err,
+        Action::Reduce(RuleKind::R60),
         Action::Err,
         Action::Err,
-        Action::Shift(State::S99),
+        Action::Reduce(RuleKind::R60),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
         Action::Err,
+        Action::Reduce(RuleKind::R60),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
+        Action::Reduce(RuleKind::R60),
     ],
     [
-        Action::Shift(State::S94),
         Action::Err,
+        Action::Reduce(RuleKind::R61),
         Action::Err,
+        Action::Reduce(RuleKind::R61),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R61),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R48),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4552,11 +5679,14 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R62),
         Action::Err,
+        Action::Reduce(RuleKind::R62),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R62),
+        Action::Err,
         Action::Err,
-        Action::Shift(State::S10),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4587,6 +5717,7 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S5),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4597,49 +5728,59 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R47),
-        Action::Err,
-        Action::Reduce(RuleKind::R47),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R47),
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R49),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R63),
         Action::Err,
+        Action::Reduce(RuleKind::R63),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R63),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
         Action::Err,
+        Action::Reduce(RuleKind::R63),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+        Action::Reduce(RuleKind::R63),
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Shift(State::S104),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4652,6 +5793,7 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S122),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4662,17 +5804,16 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R50),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4691,24 +5832,51 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S123),
         Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
-        Action::Reduce(RuleKind::R52),
         Action::Err,
+        Action::Reduce(RuleKind::R64),
         Action::Err,
+        Action::Reduce(RuleKind::R64),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R64),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
         Action::Err,
+        Action::Reduce(RuleKind::R64),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+        Action::Reduce(RuleKind::R64),
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R65),
         Action::Err,
+        Action::Reduce(RuleKind::R65),
+        Action::Err,
+        Action::Reduce(RuleKind::R65),
+        Action::Reduce(RuleKind::R65),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4716,48 +5884,31 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S107),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Reduce(RuleKind::R51),
         Action::Err,
-        Action::Reduce(RuleKind::R51),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R51),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
         Action::Err,
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
-        Action::Reduce(RuleKind::R51),
     ],
     [
-        Action::Reduce(RuleKind::R53),
         Action::Err,
+        Action::Reduce(RuleKind::R66),
         Action::Err,
+        Action::Reduce(RuleKind::R66),
         Action::Err,
+        Action::Reduce(RuleKind::R66),
+        Action::Reduce(RuleKind::R66),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4783,13 +5934,6 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S109),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -4810,308 +5954,457 @@ const ACTION_TABLE: [[Action; 27]; 119] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
         Action::Err,
-        Action::Reduce(RuleKind::R54),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R54),
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
         Action::Err,
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
-        Action::Reduce(RuleKind::R54),
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static GOTO_TABLE: [[Option<State>; 37]; 127] = [
+    [
+        Some(State::S30),
+        Some(State::S8),
+        Some(State::S17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        Some(State::S19),
+        Some(State::S20),
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        Some(State::S116),
+        Some(State::S24),
+        None,
+        Some(State::S23),
+        Some(State::S27),
+        None,
     ],
     [
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Err,
-        Action::Reduce(RuleKind::R55),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R55),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Err,
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
-        Action::Reduce(RuleKind::R55),
+        Some(State::S32),
+        Some(State::S8),
+        Some(State::S17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        Some(State::S19),
+        Some(State::S20),
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        Some(State::S116),
+        Some(State::S24),
+        None,
+        Some(State::S23),
+        Some(State::S27),
+        None,
+    ],
+    [
+        Some(State::S79),
+        Some(State::S8),
+        Some(State::S17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        Some(State::S19),
+        Some(State::S20),
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        Some(State::S116),
+        Some(State::S24),
+        None,
+        Some(State::S23),
+        Some(State::S27),
+        None,
     ],
     [
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Err,
-        Action::Reduce(RuleKind::R56),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R56),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Err,
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
-        Action::Reduce(RuleKind::R56),
+        Some(State::S81),
+        Some(State::S8),
+        Some(State::S17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        Some(State::S19),
+        Some(State::S20),
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        Some(State::S116),
+        Some(State::S24),
+        None,
+        Some(State::S23),
+        Some(State::S27),
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R57),
-        Action::Err,
-        Action::Reduce(RuleKind::R57),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R57),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        Some(State::S86),
+        Some(State::S8),
+        Some(State::S17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        Some(State::S19),
+        Some(State::S20),
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        Some(State::S116),
+        Some(State::S24),
+        None,
+        Some(State::S23),
+        Some(State::S27),
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R58),
-        Action::Err,
-        Action::Reduce(RuleKind::R58),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R58),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        Some(State::S120),
+        Some(State::S8),
+        Some(State::S17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        Some(State::S19),
+        Some(State::S20),
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        Some(State::S116),
+        Some(State::S24),
+        None,
+        Some(State::S23),
+        Some(State::S27),
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S5),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        Some(State::S0),
+        Some(State::S8),
+        Some(State::S17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        Some(State::S19),
+        Some(State::S20),
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        Some(State::S116),
+        Some(State::S24),
+        None,
+        Some(State::S23),
+        Some(State::S27),
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R59),
-        Action::Err,
-        Action::Reduce(RuleKind::R59),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R59),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
-        Action::Err,
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
-        Action::Reduce(RuleKind::R59),
+        Some(State::S3),
+        Some(State::S8),
+        Some(State::S17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        Some(State::S19),
+        Some(State::S20),
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        Some(State::S116),
+        Some(State::S24),
+        None,
+        Some(State::S23),
+        Some(State::S27),
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R60),
-        Action::Err,
-        Action::Reduce(RuleKind::R60),
-        Action::Err,
-        Action::Reduce(RuleKind::R60),
-        Action::Reduce(RuleKind::R60),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R61),
-        Action::Err,
-        Action::Reduce(RuleKind::R61),
-        Action::Err,
-        Action::Reduce(RuleKind::R61),
-        Action::Reduce(RuleKind::R61),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        Some(State::S84),
+        Some(State::S8),
+        Some(State::S17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        Some(State::S19),
+        Some(State::S20),
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        Some(State::S116),
+        Some(State::S24),
+        None,
+        Some(State::S23),
+        Some(State::S27),
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Accept,
+        Some(State::S106),
+        Some(State::S8),
+        Some(State::S17),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        Some(State::S19),
+        Some(State::S20),
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        Some(State::S116),
+        Some(State::S24),
+        None,
+        Some(State::S23),
+        Some(State::S27),
+        None,
     ],
-];
-
-fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
-    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
-}
-
-const GOTO_TABLE: [[Option<State>; 35]; 119] = [
     [
-        Some(State::S27),
+        Some(State::S118),
         Some(State::S8),
-        Some(State::S16),
+        Some(State::S17),
         None,
         None,
         None,
@@ -5125,11 +6418,12 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
         Some(State::S18),
+        None,
         Some(State::S19),
         Some(State::S20),
+        Some(State::S21),
         None,
         None,
         None,
@@ -5137,18 +6431,19 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
+        Some(State::S22),
         None,
-        Some(State::S111),
-        Some(State::S23),
+        Some(State::S116),
+        Some(State::S24),
         None,
-        Some(State::S22),
+        Some(State::S23),
+        Some(State::S27),
         None,
     ],
     [
-        Some(State::S29),
+        Some(State::S66),
         Some(State::S8),
-        Some(State::S16),
+        Some(State::S17),
         None,
         None,
         None,
@@ -5162,11 +6457,12 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
         Some(State::S18),
+        None,
         Some(State::S19),
         Some(State::S20),
+        Some(State::S21),
         None,
         None,
         None,
@@ -5174,18 +6470,19 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
+        Some(State::S22),
         None,
-        Some(State::S111),
-        Some(State::S23),
+        Some(State::S116),
+        Some(State::S24),
         None,
-        Some(State::S22),
+        Some(State::S23),
+        Some(State::S27),
         None,
     ],
     [
-        Some(State::S74),
+        Some(State::S117),
         Some(State::S8),
-        Some(State::S16),
+        Some(State::S17),
         None,
         None,
         None,
@@ -5199,11 +6496,12 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
         Some(State::S18),
+        None,
         Some(State::S19),
         Some(State::S20),
+        Some(State::S21),
         None,
         None,
         None,
@@ -5211,18 +6509,19 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
+        Some(State::S22),
         None,
-        Some(State::S111),
+        Some(State::S116),
+        Some(State::S24),
+        Some(State::S55),
         Some(State::S23),
-        None,
-        Some(State::S22),
+        Some(State::S27),
         None,
     ],
     [
-        Some(State::S76),
+        Some(State::S117),
         Some(State::S8),
-        Some(State::S16),
+        Some(State::S17),
         None,
         None,
         None,
@@ -5236,11 +6535,12 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
         Some(State::S18),
+        None,
         Some(State::S19),
         Some(State::S20),
+        Some(State::S21),
         None,
         None,
         None,
@@ -5248,18 +6548,19 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
+        Some(State::S22),
         None,
-        Some(State::S111),
+        Some(State::S116),
+        Some(State::S24),
+        Some(State::S57),
         Some(State::S23),
-        None,
-        Some(State::S22),
+        Some(State::S27),
         None,
     ],
     [
-        Some(State::S81),
+        Some(State::S121),
         Some(State::S8),
-        Some(State::S16),
+        Some(State::S17),
         None,
         None,
         None,
@@ -5273,11 +6574,12 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
         Some(State::S18),
+        None,
         Some(State::S19),
         Some(State::S20),
+        Some(State::S21),
         None,
         None,
         None,
@@ -5285,18 +6587,19 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
+        Some(State::S22),
         None,
-        Some(State::S111),
-        Some(State::S23),
+        Some(State::S116),
+        Some(State::S24),
         None,
-        Some(State::S22),
+        Some(State::S23),
+        Some(State::S27),
         None,
     ],
     [
-        Some(State::S115),
+        Some(State::S126),
         Some(State::S8),
-        Some(State::S16),
+        Some(State::S17),
         None,
         None,
         None,
@@ -5310,11 +6613,12 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
         Some(State::S18),
+        None,
         Some(State::S19),
         Some(State::S20),
+        Some(State::S21),
         None,
         None,
         None,
@@ -5322,18 +6626,16 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
+        Some(State::S22),
         None,
-        Some(State::S111),
-        Some(State::S23),
+        Some(State::S116),
+        Some(State::S24),
         None,
-        Some(State::S22),
+        Some(State::S23),
+        Some(State::S27),
         None,
     ],
     [
-        Some(State::S0),
-        Some(State::S8),
-        Some(State::S16),
         None,
         None,
         None,
@@ -5347,11 +6649,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
-        Some(State::S18),
-        Some(State::S19),
-        Some(State::S20),
         None,
         None,
         None,
@@ -5359,18 +6657,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
         None,
-        Some(State::S111),
-        Some(State::S23),
         None,
-        Some(State::S22),
         None,
-    ],
-    [
-        Some(State::S3),
-        Some(State::S8),
-        Some(State::S16),
         None,
         None,
         None,
@@ -5384,11 +6673,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
+    ],
+    [
         None,
-        Some(State::S18),
-        Some(State::S19),
-        Some(State::S20),
         None,
         None,
         None,
@@ -5396,15 +6683,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
         None,
-        Some(State::S111),
-        Some(State::S23),
         None,
-        Some(State::S22),
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -5431,6 +6712,12 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -5440,11 +6727,6 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-    ],
-    [
-        Some(State::S79),
-        Some(State::S8),
-        Some(State::S16),
         None,
         None,
         None,
@@ -5458,11 +6740,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
-        Some(State::S18),
-        Some(State::S19),
-        Some(State::S20),
         None,
         None,
         None,
@@ -5470,18 +6748,11 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
         None,
-        Some(State::S111),
-        Some(State::S23),
         None,
-        Some(State::S22),
         None,
     ],
     [
-        Some(State::S101),
-        Some(State::S8),
-        Some(State::S16),
         None,
         None,
         None,
@@ -5495,11 +6766,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
-        Some(State::S18),
-        Some(State::S19),
-        Some(State::S20),
         None,
         None,
         None,
@@ -5507,18 +6774,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
         None,
-        Some(State::S111),
-        Some(State::S23),
         None,
-        Some(State::S22),
         None,
-    ],
-    [
-        Some(State::S113),
-        Some(State::S8),
-        Some(State::S16),
         None,
         None,
         None,
@@ -5532,11 +6790,11 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
+    ],
+    [
+        None,
+        None,
         None,
-        Some(State::S18),
-        Some(State::S19),
-        Some(State::S20),
         None,
         None,
         None,
@@ -5544,18 +6802,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
         None,
-        Some(State::S111),
-        Some(State::S23),
         None,
-        Some(State::S22),
         None,
-    ],
-    [
-        Some(State::S61),
-        Some(State::S8),
-        Some(State::S16),
         None,
         None,
         None,
@@ -5569,11 +6818,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
-        Some(State::S18),
-        Some(State::S19),
-        Some(State::S20),
         None,
         None,
         None,
@@ -5581,18 +6826,11 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
         None,
-        Some(State::S111),
-        Some(State::S23),
         None,
-        Some(State::S22),
         None,
     ],
     [
-        Some(State::S112),
-        Some(State::S8),
-        Some(State::S16),
         None,
         None,
         None,
@@ -5606,11 +6844,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
-        Some(State::S18),
-        Some(State::S19),
-        Some(State::S20),
         None,
         None,
         None,
@@ -5618,18 +6852,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
         None,
-        Some(State::S111),
-        Some(State::S23),
-        Some(State::S50),
-        Some(State::S22),
         None,
-    ],
-    [
-        Some(State::S112),
-        Some(State::S8),
-        Some(State::S16),
         None,
         None,
         None,
@@ -5643,11 +6867,13 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
-        Some(State::S18),
-        Some(State::S19),
-        Some(State::S20),
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -5655,18 +6881,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
         None,
-        Some(State::S111),
-        Some(State::S23),
-        Some(State::S52),
-        Some(State::S22),
         None,
-    ],
-    [
-        Some(State::S118),
-        Some(State::S8),
-        Some(State::S16),
         None,
         None,
         None,
@@ -5680,11 +6896,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S17),
         None,
-        Some(State::S18),
-        Some(State::S19),
-        Some(State::S20),
         None,
         None,
         None,
@@ -5692,12 +6904,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S21),
         None,
-        Some(State::S111),
-        Some(State::S23),
         None,
-        Some(State::S22),
         None,
     ],
     [
@@ -5736,6 +6944,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5773,6 +6983,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5810,6 +7022,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5847,6 +7061,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5884,6 +7100,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5921,6 +7139,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -5958,11 +7178,15 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
         None,
         None,
+        Some(State::S1),
+        Some(State::S35),
         None,
         None,
         None,
@@ -6032,11 +7256,15 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
         None,
         None,
+        Some(State::S82),
+        Some(State::S35),
         None,
         None,
         None,
@@ -6074,6 +7302,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S83),
+        Some(State::S35),
         None,
         None,
         None,
@@ -6114,6 +7344,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S36),
+        Some(State::S46),
         None,
         None,
         None,
@@ -6148,10 +7380,12 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S1),
         None,
-        Some(State::S32),
-        Some(State::S41),
+        None,
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6187,6 +7421,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S38),
+        None,
         None,
         None,
         None,
@@ -6222,10 +7458,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S77),
         None,
-        Some(State::S32),
-        Some(State::S41),
         None,
         None,
         None,
@@ -6234,6 +7467,11 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S39),
+        Some(State::S68),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6259,10 +7497,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S78),
         None,
-        Some(State::S32),
-        Some(State::S41),
         None,
         None,
         None,
@@ -6273,6 +7508,11 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S40),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6308,6 +7548,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S71),
+        None,
         None,
         None,
         None,
@@ -6334,7 +7576,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S34),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6378,8 +7622,10 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S35),
-        Some(State::S63),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6417,7 +7663,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S36),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6455,7 +7703,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S66),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6484,6 +7734,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S73),
+        Some(State::S46),
         None,
         None,
         None,
@@ -6550,6 +7802,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -6561,7 +7815,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        None,
+        Some(State::S48),
+        Some(State::S62),
+        Some(State::S64),
         None,
         None,
         None,
@@ -6594,8 +7850,10 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S68),
-        Some(State::S41),
+        None,
+        None,
+        None,
+        Some(State::S49),
         None,
         None,
         None,
@@ -6661,6 +7919,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -6671,9 +7931,11 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S43),
-        Some(State::S57),
-        Some(State::S59),
+        None,
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -6707,7 +7969,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S44),
+        Some(State::S80),
+        None,
         None,
         None,
         None,
@@ -6717,6 +7980,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S2),
         None,
         None,
         None,
@@ -6744,6 +8008,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S80),
         None,
         None,
         None,
@@ -6754,6 +8019,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S111),
         None,
         None,
         None,
@@ -6781,6 +8047,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S119),
+        None,
         None,
         None,
         None,
@@ -6817,7 +8085,10 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S75),
+        None,
+        None,
+        Some(State::S108),
+        None,
         None,
         None,
         None,
@@ -6828,7 +8099,6 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S2),
         None,
         None,
         None,
@@ -6854,7 +8124,10 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S75),
+        None,
+        None,
+        Some(State::S113),
+        None,
         None,
         None,
         None,
@@ -6865,7 +8138,6 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S106),
         None,
         None,
         None,
@@ -6891,7 +8163,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S114),
+        None,
+        None,
+        Some(State::S69),
         None,
         None,
         None,
@@ -6929,7 +8203,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S103),
+        None,
+        Some(State::S76),
+        None,
         None,
         None,
         None,
@@ -6966,7 +8242,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S108),
+        None,
+        Some(State::S97),
+        None,
         None,
         None,
         None,
@@ -7003,10 +8281,12 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S64),
         None,
         None,
         None,
+        Some(State::S63),
+        Some(State::S64),
+        None,
         None,
         None,
         None,
@@ -7040,7 +8320,6 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S71),
         None,
         None,
         None,
@@ -7068,6 +8347,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
+        Some(State::S101),
     ],
     [
         None,
@@ -7077,9 +8359,11 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S92),
         None,
         None,
+        Some(State::S56),
+        Some(State::S62),
+        Some(State::S64),
         None,
         None,
         None,
@@ -7116,8 +8400,10 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S58),
-        Some(State::S59),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -7178,7 +8464,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S96),
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -7189,9 +8477,6 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S51),
-        Some(State::S57),
-        Some(State::S59),
         None,
         None,
         None,
@@ -7216,6 +8501,11 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S65),
     ],
     [
         None,
@@ -7253,6 +8543,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7290,6 +8582,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7326,7 +8620,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S60),
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -7364,6 +8660,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7401,6 +8699,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7438,6 +8738,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7475,6 +8777,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7512,6 +8816,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7530,6 +8836,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S74),
+        None,
         None,
         None,
         None,
@@ -7586,6 +8894,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7623,6 +8933,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7640,7 +8952,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S69),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -7697,6 +9011,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7734,6 +9050,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7771,6 +9089,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7808,6 +9128,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7845,6 +9167,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7882,6 +9206,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7919,6 +9245,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -7946,6 +9274,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S85),
+        None,
         None,
         None,
         None,
@@ -7980,9 +9310,11 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S4),
         None,
         None,
         None,
+        Some(State::S102),
         None,
         None,
         None,
@@ -8030,6 +9362,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8056,7 +9390,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S80),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -8090,11 +9426,13 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S4),
+        None,
+        None,
+        Some(State::S91),
+        None,
         None,
         None,
         None,
-        Some(State::S97),
         None,
         None,
         None,
@@ -8141,6 +9479,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8178,6 +9518,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8202,7 +9544,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S86),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -8252,6 +9596,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8277,6 +9623,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S94),
+        None,
         None,
         None,
         None,
@@ -8326,6 +9674,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8363,6 +9713,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8387,9 +9739,10 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S89),
         None,
         None,
+        Some(State::S58),
+        None,
         None,
         None,
         None,
@@ -8400,6 +9753,7 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S100),
     ],
     [
         None,
@@ -8437,6 +9791,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8474,6 +9830,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8499,7 +9857,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S53),
+        None,
+        Some(State::S54),
         None,
         None,
         None,
@@ -8510,7 +9869,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S95),
+        None,
+        Some(State::S100),
     ],
     [
         None,
@@ -8548,6 +9908,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8585,6 +9947,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8610,7 +9974,6 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S49),
         None,
         None,
         None,
@@ -8621,7 +9984,10 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S95),
+        None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -8659,6 +10025,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8688,6 +10056,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S105),
+        Some(State::S107),
         None,
         None,
         None,
@@ -8733,6 +10103,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8770,6 +10142,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8798,8 +10172,10 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S100),
-        Some(State::S102),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -8844,6 +10220,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8881,6 +10259,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8913,6 +10293,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        Some(State::S52),
+        None,
         None,
         None,
         None,
@@ -8955,6 +10337,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -8992,6 +10376,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9023,7 +10409,9 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
-        Some(State::S47),
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -9066,6 +10454,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9103,6 +10493,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9140,6 +10532,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9177,6 +10571,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9214,6 +10610,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9251,6 +10649,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9288,6 +10688,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9325,6 +10727,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9362,6 +10766,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9399,6 +10805,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9436,6 +10844,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9473,6 +10883,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
     [
         None,
@@ -9510,6 +10922,8 @@ const GOTO_TABLE: [[Option<State>; 35]; 119] = [
         None,
         None,
         None,
+        None,
+        None,
     ],
 ];
 
@@ -9561,6 +10975,17 @@ impl TryFrom<Node> for IndCommonInnards {
     }
 }
 
+impl TryFrom<Node> for OptNominalKw {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptNominalKw(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl TryFrom<Node> for OptString {
     type Error = Node;
 
@@ -9891,6 +11316,17 @@ impl TryFrom<Node> for For {
     }
 }
 
+impl TryFrom<Node> for Elim {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::Elim(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl TryFrom<Node> for IdentOrUnderscore {
     type Error = Node;
 
@@ -9909,176 +11345,197 @@ impl Node {
             _ => Err(self),
         }
     }
-    
+
     fn try_into_r_paren_1(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::RParen(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_l_square_2(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::LSquare(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_r_square_3(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::RSquare(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_eq_4(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Eq(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_colon_5(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Colon(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_comma_6(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Comma(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_thin_arrow_7(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::ThinArrow(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_dash_8(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Dash(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
+
     fn try_into_underscore_9(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::Underscore(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_let_kw_10(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_question_10(self) -> Result<crate::token::ByteIndex, Self> {
+        match self {
+            Self::Question(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_let_kw_11(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::LetKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_ind_kw_11(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_ind_kw_12(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::IndKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_fun_kw_12(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_fun_kw_13(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::FunKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_aind_kw_13(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_aind_kw_14(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::AindKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_match_kw_14(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_match_kw_15(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::MatchKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_afun_kw_15(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_afun_kw_16(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::AfunKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_for_kw_16(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_for_kw_17(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::ForKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_case_kw_17(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_case_kw_18(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::CaseKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_return_kw_18(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_return_kw_19(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::ReturnKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_use_kw_19(self) -> Result<crate::token::ByteIndex, Self> {
+
+    fn try_into_use_kw_20(self) -> Result<crate::token::ByteIndex, Self> {
         match self {
             Self::UseKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_ident_20(self) -> Result<crate::token::Ident, Self> {
+
+    fn try_into_elim_kw_21(self) -> Result<crate::token::ByteIndex, Self> {
+        match self {
+            Self::ElimKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_nominal_kw_22(self) -> Result<crate::token::ByteIndex, Self> {
+        match self {
+            Self::NominalKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_ident_23(self) -> Result<crate::token::Ident, Self> {
         match self {
             Self::Ident(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_number_21(self) -> Result<crate::token::NumberLiteral, Self> {
+
+    fn try_into_number_24(self) -> Result<crate::token::NumberLiteral, Self> {
         match self {
             Self::Number(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_string_22(self) -> Result<crate::token::StringLiteral, Self> {
+
+    fn try_into_string_25(self) -> Result<crate::token::StringLiteral, Self> {
         match self {
             Self::String(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_universe_23(self) -> Result<crate::token::UniverseLiteral, Self> {
+
+    fn try_into_universe_26(self) -> Result<crate::token::UniverseLiteral, Self> {
         match self {
             Self::Universe(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_vcon_index_24(self) -> Result<crate::token::VconIndexLiteral, Self> {
+
+    fn try_into_vcon_index_27(self) -> Result<crate::token::VconIndexLiteral, Self> {
         match self {
             Self::VconIndex(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_return_arity_25(self) -> Result<crate::token::ReturnArityLiteral, Self> {
+
+    fn try_into_return_arity_28(self) -> Result<crate::token::ReturnArityLiteral, Self> {
         match self {
             Self::ReturnArity(t) => Ok(t),
             _ => Err(self),
@@ -0,0 +1,158 @@
+//! The June frontend.
+//!
+//! June source is a module (i.e., a list of definitions).
+//! The REPL keeps the items the user has entered so far,
+//! and converts each expression by chaining those items in front of it.
+
+use crate::zo;
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use june::{
+    codegen::{june_to_zo, SemanticError},
+    lexer::lex,
+    parser::parse,
+};
+use zoc::pretty_print::PrettyPrint;
+
+pub use june::cst;
+
+/// The source ID of everything entered in the REPL (see `june_to_zo`).
+const SOURCE_ID: &str = "repl";
+
+/// June's parser only accepts modules,
+/// so we parse an expression as the value of a variable
+/// with this name.
+const EXPR_VAR_NAME: &str = "it";
+
+pub enum Input {
+    Expr(cst::Expr),
+    Defs(Vec<cst::ModuleItem>),
+}
+
+pub fn parse_input(src: &str) -> Result<Input, String> {
+    if let Ok(module) = parse_src(src) {
+        return Ok(Input::Defs(get_items(&module)));
+    }
+
+    let module = parse_src(&wrap_expr(src))?;
+    match get_items(&module).pop() {
+        Some(cst::ModuleItem::VarDef(def)) => Ok(Input::Expr((*def.val).clone())),
+        _ => Err("expected an expression or definitions".to_string()),
+    }
+}
+
+/// Returns true if `src` is a prefix of a valid input,
+/// so the user should be prompted to keep typing.
+pub fn is_incomplete(src: &str) -> bool {
+    let is_prefix = |src: &str| {
+        let Ok(tokens) = lex(src) else {
+            return false;
+        };
+        matches!(parse(tokens), Err(None))
+    };
+    is_prefix(src) && is_prefix(&wrap_expr(src))
+}
+
+fn parse_src(src: &str) -> Result<cst::Module, String> {
+    let tokens = lex(src).map_err(|err| format!("lex error: {err:?}"))?;
+    parse(tokens).map_err(|err| match err {
+        Some(token) => format!("syntax error: unexpected token {token:?}"),
+        None => "syntax error: unexpected end of input".to_string(),
+    })
+}
+
+fn wrap_expr(src: &str) -> String {
+    format!("{EXPR_VAR_NAME} = {src}")
+}
+
+pub fn get_items(module: &cst::Module) -> Vec<cst::ModuleItem> {
+    match module {
+        cst::Module::Nil => vec![],
+        cst::Module::Snoc(rdc, rac) => {
+            let mut rdc = get_items(rdc);
+            rdc.push((**rac).clone());
+            rdc
+        }
+    }
+}
+
+pub fn item_name(item: &cst::ModuleItem) -> String {
+    match item {
+        cst::ModuleItem::VarDef(def) => def.name.value.clone(),
+        cst::ModuleItem::EnumDef(def) => def.name.value.clone(),
+        cst::ModuleItem::FunDef(def) => def.name.value.clone(),
+    }
+}
+
+/// Chains `items` in front of `leaf`.
+fn chain(items: &[cst::ModuleItem], leaf: cst::Expr) -> cst::Expr {
+    items.iter().rev().fold(leaf, |next_val, item| {
+        let next_val = Box::new(next_val);
+        match item {
+            cst::ModuleItem::VarDef(standalone) => cst::Expr::VarDef(Box::new(cst::ChainVarDef {
+                standalone: standalone.clone(),
+                next_val,
+            })),
+            cst::ModuleItem::EnumDef(standalone) => {
+                cst::Expr::EnumDef(Box::new(cst::ChainEnumDef {
+                    standalone: standalone.clone(),
+                    next_val,
+                }))
+            }
+            cst::ModuleItem::FunDef(standalone) => cst::Expr::FunDef(Box::new(cst::ChainFunDef {
+                standalone: standalone.clone(),
+                next_val,
+            })),
+        }
+    })
+}
+
+/// Converts `expr` (under `items`) to closed Zo source.
+///
+/// The June compiler is still incomplete,
+/// and panics on code it does not support yet.
+/// We report such panics as errors instead of crashing the REPL.
+pub fn to_zo_src(items: &[cst::ModuleItem], expr: cst::Expr) -> Result<String, String> {
+    let chained = chain(items, expr);
+    let converted = catch_unwind(AssertUnwindSafe(|| {
+        june_to_zo(chained, SOURCE_ID).map_err(|err| render_semantic_error(&err))
+    }))
+    .map_err(|_| "the June compiler does not support this input yet".to_string())??;
+    Ok(PrettyPrint(&converted).to_string())
+}
+
+fn render_semantic_error(err: &SemanticError) -> String {
+    match err {
+        SemanticError::VarNotDefined(ident) => format!("`{}` is not defined", ident.value),
+        SemanticError::MultipleDecreasingParams(_, _) => {
+            "a function can have at most one `dec` param".to_string()
+        }
+        SemanticError::ConvertedExprHasZoErr(_, _, zo_err) => format!("{:#}", PrettyPrint(zo_err)),
+        SemanticError::MatcheeHasUnmatchableType(_, type_) => format!(
+            "cannot match on a value of type {}",
+            PrettyPrint(type_.raw())
+        ),
+        SemanticError::MatchCaseHasUnknownName(case, ind) => format!(
+            "`{}` has no variant named `{}`",
+            ind.hashee.name.0, case.name.value
+        ),
+        SemanticError::DuplicateMatchCases(_, case) => {
+            format!("the case `{}` is matched more than once", case.name.value)
+        }
+        SemanticError::MissingMatchCases(_, ind, missing_vcon_indices) => {
+            let names: Vec<String> = missing_vcon_indices
+                .iter()
+                .map(|&i| match &ind.hashee.vcon_defs.hashee[i].name {
+                    Some(name) => format!("`{}`", name.0),
+                    None => format!("#{i}"),
+                })
+                .collect();
+            format!("the match is missing the cases {}", names.join(", "))
+        }
+        SemanticError::ElimTargetIsNotEnum(_) => {
+            "`elim` can only be applied to an `enum`".to_string()
+        }
+        SemanticError::CannotGenerateEliminator(_, err) => zo::render_eliminator_error(err),
+    }
+}
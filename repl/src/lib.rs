@@ -0,0 +1,20 @@
+//! An interactive REPL for Zo, May, and June.
+//!
+//! The REPL keeps a growing context of definitions,
+//! and typechecks, evaluates, and inspects expressions
+//! that refer to them.
+//! See `session::HELP` for the list of commands.
+//!
+//! All the logic lives in `Session`, so it can be tested
+//! without a terminal; the `zo-repl` binary only reads lines
+//! and prints results.
+
+mod june;
+mod may;
+mod zo;
+
+mod session;
+pub use session::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,67 @@
+use std::io::{BufRead, Write};
+
+use repl::{Lang, Session};
+
+fn main() {
+    let mut session = Session::new();
+    let stdin = std::io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    if let Some(lang) = std::env::args().nth(1) {
+        match Lang::from_name(&lang) {
+            Some(lang) => session.lang = lang,
+            None => {
+                eprintln!("unknown language `{lang}` (expected zo, may, or june)");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    println!("Zo REPL. Type `:help` for a list of commands.");
+    while let Some(input) = read_input(&session, &mut lines) {
+        if input.trim() == ":quit" {
+            break;
+        }
+
+        match session.run(&input) {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => println!("{output}"),
+            Err(err) => println!("{err}"),
+        }
+    }
+}
+
+/// Reads lines until the input is complete (or the user enters a blank line).
+/// Returns `None` at the end of stdin.
+fn read_input(
+    session: &Session,
+    lines: &mut impl Iterator<Item = std::io::Result<String>>,
+) -> Option<String> {
+    let mut input = String::new();
+    loop {
+        let prompt = if input.is_empty() {
+            format!("{}> ", session.lang.name())
+        } else {
+            "... ".to_string()
+        };
+        print!("{prompt}");
+        std::io::stdout().flush().ok()?;
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => return (!input.is_empty()).then_some(input),
+        };
+
+        if line.trim().is_empty() {
+            return Some(input);
+        }
+        if !input.is_empty() {
+            input.push('\n');
+        }
+        input.push_str(&line);
+
+        if !session.is_incomplete(&input) {
+            return Some(input);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! The May frontend.
+//!
+//! A May program is a chain of definitions (`let`, `ind`, and `fun`)
+//! that ends in a value.
+//! The REPL keeps the definitions the user has entered so far,
+//! and converts each expression by appending it to the end of that chain.
+
+use crate::zo;
+
+use may::{
+    codegen::{may_to_zo, SemanticError},
+    lexer::lex,
+    parser::parse,
+    token::ByteIndex,
+};
+use zoc::pretty_print::PrettyPrint;
+
+pub use may::cst;
+
+/// The source ID of everything entered in the REPL (see `may_to_zo`).
+const SOURCE_ID: &str = "repl";
+
+pub enum Input {
+    Expr(cst::Expr),
+    Defs(Vec<cst::Expr>),
+}
+
+pub fn parse_input(src: &str) -> Result<Input, String> {
+    let err = match parse_src(src) {
+        Ok(expr) => return Ok(Input::Expr(expr)),
+        Err(err) => err,
+    };
+
+    // A definition must be followed by a value,
+    // so a lone definition only parses once we supply a hole.
+    match parse_src(&with_hole_leaf(src)) {
+        Ok(chain) => Ok(Input::Defs(get_defs(&chain))),
+        Err(_) => Err(err),
+    }
+}
+
+/// Returns true if `src` is a prefix of a valid input,
+/// so the user should be prompted to keep typing.
+///
+/// Since a definition can always be followed by another definition,
+/// input that consists of definitions is never considered complete.
+/// The user ends it with a blank line.
+pub fn is_incomplete(src: &str) -> bool {
+    let Ok(tokens) = lex(src) else {
+        return false;
+    };
+    matches!(parse(tokens), Err(None))
+}
+
+fn parse_src(src: &str) -> Result<cst::Expr, String> {
+    let tokens = lex(src).map_err(|err| format!("lex error: {err:?}"))?;
+    parse(tokens).map_err(|err| match err {
+        Some(token) => format!("syntax error: unexpected token {token:?}"),
+        None => "syntax error: unexpected end of input".to_string(),
+    })
+}
+
+fn with_hole_leaf(src: &str) -> String {
+    format!("{src}\n?")
+}
+
+/// Returns the definitions at the start of `chain`.
+pub fn get_defs(chain: &cst::Expr) -> Vec<cst::Expr> {
+    let mut defs = vec![];
+    let mut current = chain;
+    loop {
+        let next_val = match current {
+            cst::Expr::Let(e) => &e.next_val,
+            cst::Expr::Ind(e) => &e.next_val,
+            cst::Expr::Fun(e) => &e.next_val,
+            _ => return defs,
+        };
+        defs.push(current.clone());
+        current = next_val;
+    }
+}
+
+/// Returns the name `def` binds, if `def` is a definition.
+pub fn def_name(def: &cst::Expr) -> Option<String> {
+    match def {
+        cst::Expr::Let(e) => Some(e.name.value.clone()),
+        cst::Expr::Ind(e) => Some(e.innards.name.value.clone()),
+        cst::Expr::Fun(e) => Some(e.name.value.clone()),
+        _ => None,
+    }
+}
+
+/// Returns the value at the end of `chain`.
+pub fn get_leaf(chain: &cst::Expr) -> &cst::Expr {
+    match chain {
+        cst::Expr::Let(e) => get_leaf(&e.next_val),
+        cst::Expr::Ind(e) => get_leaf(&e.next_val),
+        cst::Expr::Fun(e) => get_leaf(&e.next_val),
+        _ => chain,
+    }
+}
+
+/// Replaces the `next_val` of each definition in `defs`
+/// so that they form a chain ending in `leaf`.
+fn chain(defs: &[cst::Expr], leaf: cst::Expr) -> cst::Expr {
+    defs.iter()
+        .rev()
+        .fold(leaf, |next_val, def| with_next_val(def, next_val))
+}
+
+fn with_next_val(def: &cst::Expr, next_val: cst::Expr) -> cst::Expr {
+    let next_val = Box::new(next_val);
+    match def {
+        cst::Expr::Let(e) => cst::Expr::Let(Box::new(cst::Let {
+            next_val,
+            ..(**e).clone()
+        })),
+        cst::Expr::Ind(e) => cst::Expr::Ind(Box::new(cst::Ind {
+            next_val,
+            ..(**e).clone()
+        })),
+        cst::Expr::Fun(e) => cst::Expr::Fun(Box::new(cst::Fun {
+            next_val,
+            ..(**e).clone()
+        })),
+        _ => panic!("expected a definition"),
+    }
+}
+
+/// Converts `expr` (under `defs`) to closed Zo source.
+pub fn to_zo_src(defs: &[cst::Expr], expr: cst::Expr) -> Result<String, String> {
+    let (converted, _) =
+        may_to_zo(&chain(defs, expr), SOURCE_ID).map_err(|err| render_semantic_error(&err))?;
+    Ok(PrettyPrint(&converted).to_string())
+}
+
+/// Returns an error if any of `defs` is ill-typed.
+pub fn check_defs(defs: &[cst::Expr]) -> Result<(), String> {
+    let hole = cst::Expr::Hole(ByteIndex(0));
+    let (_, substitutable_defs) =
+        may_to_zo(&chain(defs, hole), SOURCE_ID).map_err(|err| render_semantic_error(&err))?;
+    for def in substitutable_defs {
+        zo::typecheck_src(&PrettyPrint(&def).to_string())?;
+    }
+    Ok(())
+}
+
+fn render_semantic_error(err: &SemanticError) -> String {
+    match err {
+        SemanticError::VarNotDefined(ident) => format!("`{}` is not defined", ident.value),
+        SemanticError::IllegalDashedParam(_) => {
+            "only `fun` params can be marked as decreasing (with `-`)".to_string()
+        }
+        SemanticError::MultipleDashedParams(_, _) => {
+            "a `fun` can have at most one decreasing (`-`) param".to_string()
+        }
+        SemanticError::ReturnArityIsZero(_) => "return arity must be at least 1".to_string(),
+        SemanticError::InvalidVconIndex(_) => "vcon index is out of range".to_string(),
+        SemanticError::ElimTargetIsNotInd(_) => {
+            "`elim` can only be applied to an `ind`".to_string()
+        }
+        SemanticError::CannotGenerateEliminator(_, err) => zo::render_eliminator_error(err),
+    }
+}
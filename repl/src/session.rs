@@ -0,0 +1,422 @@
+use crate::{june, may, zo};
+
+use std::path::Path;
+
+use zoc::kernel::Certificate;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Lang {
+    #[default]
+    Zo,
+    May,
+    June,
+}
+
+impl Lang {
+    pub fn name(self) -> &'static str {
+        match self {
+            Lang::Zo => "zo",
+            Lang::May => "may",
+            Lang::June => "june",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "zo" => Some(Lang::Zo),
+            "may" => Some(Lang::May),
+            "june" => Some(Lang::June),
+            _ => None,
+        }
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "zo" => Some(Lang::Zo),
+            "may" => Some(Lang::May),
+            "jn" => Some(Lang::June),
+            _ => None,
+        }
+    }
+}
+
+pub const HELP: &str = r#"Commands:
+  <expr>              Evaluate <expr> (in May and June, definitions are added to the context)
+  :type <expr>        Print the type of <expr>
+  :eval <expr>        Print the normal form of <expr>
+  :whnf <expr>        Print the weak head normal form of <expr>
+  :erasable <expr>    Check that <expr> can be erased
+  :digest <expr>      Print the digest of <expr> (after expanding definitions)
+  :def <name> <expr>  Define <name> as <expr> (Zo only); refer to it as `<name>`
+  :defs               List the definitions in the context
+  :load <file>        Load the definitions in <file> (`.zo`, `.may`, or `.jn`)
+  :certify <file>     Write a proof certificate of the definitions to <file>
+  :audit <file>       Check the certificate in <file> with the minimal kernel
+  :lang [zo|may|june] Print or set the input language
+  :history            List the previous inputs
+  :rerun <n>          Run the <n>th previous input again
+  :help               Print this message
+  :quit               Exit
+
+Input continues onto the next line until it is complete.
+End it early with a blank line."#;
+
+/// The state of a REPL session.
+///
+/// Every language's expressions are converted to closed Zo source
+/// (by expanding the definitions in the context),
+/// which is then typechecked and evaluated.
+#[derive(Default)]
+pub struct Session {
+    pub lang: Lang,
+    /// Zo has no definition syntax,
+    /// so Zo definitions are textual placeholders
+    /// (like the ones our tests use).
+    /// Each value is already expanded,
+    /// so it does not contain any placeholders.
+    zo_defs: Vec<(String, String)>,
+    may_defs: Vec<may::cst::Expr>,
+    june_items: Vec<june::cst::ModuleItem>,
+    history: Vec<String>,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Returns true if `input` is a prefix of a valid input,
+    /// so the user should be prompted to keep typing.
+    pub fn is_incomplete(&self, input: &str) -> bool {
+        let src = match split_command(input) {
+            Some((name, arg)) if takes_expr(name) => arg,
+            Some((DEF_COMMAND, arg)) => split_first_word(arg).1,
+            Some(_) => return false,
+            None => input,
+        };
+        if src.trim().is_empty() {
+            return false;
+        }
+
+        match self.lang {
+            Lang::Zo => zo::is_incomplete(src),
+            Lang::May => may::is_incomplete(src),
+            Lang::June => june::is_incomplete(src),
+        }
+    }
+
+    /// Runs `input` (a command or an expression),
+    /// and returns the text to print.
+    pub fn run(&mut self, input: &str) -> Result<String, String> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(String::new());
+        }
+
+        if let Some((RERUN_COMMAND, arg)) = split_command(input) {
+            let entry = arg
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| self.history.get(n.checked_sub(1)?))
+                .ok_or_else(|| format!("no history entry `{arg}`"))?
+                .clone();
+            return self.run(&entry);
+        }
+
+        if split_command(input).map(|(name, _)| name) != Some(HISTORY_COMMAND) {
+            self.history.push(input.to_string());
+        }
+        self.run_without_recording(input)
+    }
+
+    fn run_without_recording(&mut self, input: &str) -> Result<String, String> {
+        let Some((name, arg)) = split_command(input) else {
+            return self.run_bare(input);
+        };
+
+        match name {
+            "type" => Ok(zo::print_type(&self.typecheck(arg)?)),
+            "eval" => Ok(zo::print_normal_form(&self.typecheck(arg)?)),
+            "whnf" => Ok(zo::print_weak_head_normal_form(&self.typecheck(arg)?)),
+            "erasable" => Ok(zo::print_erasability(&self.typecheck(arg)?)),
+            "digest" => {
+                let src = self.to_zo_src(arg)?;
+                Ok(zo::print_digest(&zo::parse_minimal_src(&src)?))
+            }
+            DEF_COMMAND => {
+                let (def_name, val) = split_first_word(arg);
+                self.define_zo(def_name, val)?;
+                Ok(format!("defined <{def_name}>"))
+            }
+            "defs" => Ok(self.print_defs()),
+            "load" => self.load(Path::new(arg)),
+            "certify" => self.certify(Path::new(arg)),
+            "audit" => {
+                let src = std::fs::read_to_string(arg)
+                    .map_err(|err| format!("cannot read `{arg}`: {err}"))?;
+                zo::audit(&src)
+            }
+            "lang" => {
+                if !arg.is_empty() {
+                    self.lang =
+                        Lang::from_name(arg).ok_or_else(|| format!("unknown language `{arg}`"))?;
+                }
+                Ok(self.lang.name().to_string())
+            }
+            HISTORY_COMMAND => Ok(self.print_history()),
+            "help" => Ok(HELP.to_string()),
+            _ => Err(format!("unknown command `:{name}` (try `:help`)")),
+        }
+    }
+
+    /// Evaluates expressions, and adds definitions to the context.
+    fn run_bare(&mut self, input: &str) -> Result<String, String> {
+        match self.lang {
+            Lang::Zo => Ok(zo::print_normal_form(&self.typecheck(input)?)),
+
+            Lang::May => match may::parse_input(input)? {
+                may::Input::Expr(expr) => {
+                    let src = may::to_zo_src(&self.may_defs, expr)?;
+                    Ok(zo::print_normal_form(&zo::typecheck_src(&src)?))
+                }
+                may::Input::Defs(defs) => self.define_may(defs),
+            },
+
+            Lang::June => match june::parse_input(input)? {
+                june::Input::Expr(expr) => {
+                    let src = june::to_zo_src(&self.june_items, expr)?;
+                    Ok(zo::print_normal_form(&zo::typecheck_src(&src)?))
+                }
+                june::Input::Defs(items) => Ok(self.define_june(items)),
+            },
+        }
+    }
+
+    fn typecheck(&self, src: &str) -> Result<zo::Checked, String> {
+        zo::typecheck_src(&self.to_zo_src(src)?)
+    }
+
+    /// Converts the expression `src` (in the current language)
+    /// to closed Zo source.
+    fn to_zo_src(&self, src: &str) -> Result<String, String> {
+        match self.lang {
+            Lang::Zo => self.expand_zo_defs(src),
+
+            Lang::May => match may::parse_input(src)? {
+                may::Input::Expr(expr) => may::to_zo_src(&self.may_defs, expr),
+                may::Input::Defs(_) => Err("expected an expression, not definitions".to_string()),
+            },
+
+            Lang::June => match june::parse_input(src)? {
+                june::Input::Expr(expr) => june::to_zo_src(&self.june_items, expr),
+                june::Input::Defs(_) => Err("expected an expression, not definitions".to_string()),
+            },
+        }
+    }
+
+    fn expand_zo_defs(&self, src: &str) -> Result<String, String> {
+        let mut expanded = src.to_string();
+        for (name, val) in &self.zo_defs {
+            expanded = expanded.replace(&format!("<{name}>"), val);
+        }
+
+        if let Some(name) = find_placeholder(&expanded) {
+            return Err(format!("`<{name}>` is not defined"));
+        }
+
+        Ok(expanded)
+    }
+
+    fn define_zo(&mut self, name: &str, src: &str) -> Result<(), String> {
+        if self.lang != Lang::Zo {
+            return Err(format!(
+                "`:{DEF_COMMAND}` is only for Zo; in {}, enter the definition directly",
+                self.lang.name()
+            ));
+        }
+        if !is_valid_zo_def_name(name) {
+            return Err(format!("invalid definition name `{name}`"));
+        }
+
+        let expanded = self.expand_zo_defs(src)?;
+        zo::typecheck_src(&expanded)?;
+
+        self.zo_defs.retain(|(existing, _)| existing != name);
+        self.zo_defs.push((name.to_string(), expanded));
+        Ok(())
+    }
+
+    fn define_may(&mut self, defs: Vec<may::cst::Expr>) -> Result<String, String> {
+        let mut new_defs = self.may_defs.clone();
+        new_defs.extend(defs.iter().cloned());
+        may::check_defs(&new_defs)?;
+
+        self.may_defs = new_defs;
+        Ok(format!("added {} definition(s)", defs.len()))
+    }
+
+    /// The June compiler cannot yet convert definitions on their own,
+    /// so June definitions are only checked when an expression uses them.
+    fn define_june(&mut self, items: Vec<june::cst::ModuleItem>) -> String {
+        let count = items.len();
+        self.june_items.extend(items);
+        format!("added {count} definition(s)")
+    }
+
+    fn load(&mut self, path: &Path) -> Result<String, String> {
+        let src = std::fs::read_to_string(path)
+            .map_err(|err| format!("cannot read `{}`: {err}", path.display()))?;
+        let lang = path
+            .extension()
+            .and_then(|extension| Lang::from_extension(extension.to_str()?))
+            .unwrap_or(self.lang);
+
+        match lang {
+            // A Zo file is a single expression,
+            // which we define under the file's name.
+            Lang::Zo => {
+                let name = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .unwrap_or_default();
+                let previous_lang = std::mem::replace(&mut self.lang, Lang::Zo);
+                let result = self.define_zo(name, &src);
+                self.lang = previous_lang;
+                result?;
+                Ok(format!("defined <{name}>"))
+            }
+
+            // A May file is a chain of definitions ending in a value.
+            // We add the definitions, and evaluate the value
+            // (unless it is a hole).
+            Lang::May => match may::parse_input(&src)? {
+                may::Input::Defs(defs) => self.define_may(defs),
+                may::Input::Expr(chain) => {
+                    let added = self.define_may(may::get_defs(&chain))?;
+                    let leaf = may::get_leaf(&chain).clone();
+                    if matches!(leaf, may::cst::Expr::Hole(_)) {
+                        return Ok(added);
+                    }
+                    let zo_src = may::to_zo_src(&self.may_defs, leaf)?;
+                    let value = zo::print_normal_form(&zo::typecheck_src(&zo_src)?);
+                    Ok(format!("{added}\n{value}"))
+                }
+            },
+
+            Lang::June => match june::parse_input(&src)? {
+                june::Input::Defs(items) => Ok(self.define_june(items)),
+                june::Input::Expr(_) => Err("expected a June module".to_string()),
+            },
+        }
+    }
+
+    /// Writes a certificate of the definitions in the context
+    /// (in the current language) to `path`.
+    fn certify(&self, path: &Path) -> Result<String, String> {
+        let mut certificate = Certificate::default();
+        for name in self.def_names() {
+            if certificate.entries.iter().any(|entry| entry.name == name) {
+                continue;
+            }
+            let src = match self.lang {
+                Lang::Zo => self.expand_zo_defs(&format!("<{name}>"))?,
+                Lang::May | Lang::June => self.to_zo_src(&name)?,
+            };
+            certificate.entries.push(zo::certify(name, &src)?);
+        }
+        certificate.entries.reverse();
+
+        std::fs::write(path, certificate.to_string())
+            .map_err(|err| format!("cannot write `{}`: {err}", path.display()))?;
+        Ok(format!(
+            "wrote {} definition(s) to `{}`",
+            certificate.entries.len(),
+            path.display()
+        ))
+    }
+
+    /// Returns the names of the definitions in the context,
+    /// latest first.
+    fn def_names(&self) -> Vec<String> {
+        match self.lang {
+            Lang::Zo => self
+                .zo_defs
+                .iter()
+                .rev()
+                .map(|(name, _)| name.clone())
+                .collect(),
+            Lang::May => self
+                .may_defs
+                .iter()
+                .rev()
+                .filter_map(may::def_name)
+                .collect(),
+            Lang::June => self.june_items.iter().rev().map(june::item_name).collect(),
+        }
+    }
+
+    fn print_defs(&self) -> String {
+        match self.lang {
+            Lang::Zo => self
+                .zo_defs
+                .iter()
+                .map(|(name, val)| format!("<{name}> = {val}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Lang::May => format!("{} definition(s)", self.may_defs.len()),
+            Lang::June => format!("{} definition(s)", self.june_items.len()),
+        }
+    }
+
+    fn print_history(&self) -> String {
+        self.history
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| format!("{:>4}  {}", i + 1, entry.replace('\n', "\n      ")))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+const DEF_COMMAND: &str = "def";
+const HISTORY_COMMAND: &str = "history";
+const RERUN_COMMAND: &str = "rerun";
+
+/// Splits `:name arg` into `("name", "arg")`.
+/// Returns `None` if `input` is not a command.
+fn split_command(input: &str) -> Option<(&str, &str)> {
+    let rest = input.trim_start().strip_prefix(':')?;
+    Some(split_first_word(rest))
+}
+
+fn split_first_word(s: &str) -> (&str, &str) {
+    let s = s.trim_start();
+    match s.find(char::is_whitespace) {
+        Some(end) => (&s[..end], s[end..].trim()),
+        None => (s, ""),
+    }
+}
+
+fn takes_expr(command: &str) -> bool {
+    matches!(command, "type" | "eval" | "whnf" | "erasable" | "digest")
+}
+
+fn is_valid_zo_def_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Returns the name of the first `<name>` placeholder in `src`, if any.
+fn find_placeholder(src: &str) -> Option<&str> {
+    src.match_indices('<').find_map(|(start, _)| {
+        let rest = &src[start + 1..];
+        let name = &rest[..rest.find('>')?];
+        is_valid_zo_def_name(name).then_some(name)
+    })
+}
@@ -0,0 +1,331 @@
+use super::*;
+
+fn run_or_panic(session: &mut Session, input: &str) -> String {
+    match session.run(input) {
+        Ok(output) => output,
+        Err(err) => panic!("failed to run {input:?}: {err}"),
+    }
+}
+
+fn get_zo_session_with_nat() -> Session {
+    let mut session = Session::new();
+    run_or_panic(
+        &mut session,
+        r#":def nat (ind Set0 "Nat" () ((() ()) ((0) ())))"#,
+    );
+    run_or_panic(&mut session, ":def zero (vcon <nat> 0)");
+    run_or_panic(&mut session, ":def succ (vcon <nat> 1)");
+    session
+}
+
+#[test]
+fn zo_defs_are_expanded() {
+    let mut session = get_zo_session_with_nat();
+    assert_eq!(
+        r#"(ind Set0 "Nat" () ((() ()) ((0) ())))"#,
+        run_or_panic(&mut session, ":type (<succ> <zero>)")
+    );
+}
+
+#[test]
+fn bare_zo_expr_is_evaluated() {
+    let mut session = Session::new();
+    assert_eq!("3u64", run_or_panic(&mut session, "(u64_add 1u64 2u64)"));
+}
+
+#[test]
+fn whnf_does_not_reduce_under_fun() {
+    let mut session = Session::new();
+    assert_eq!(
+        "(fun nonrec (U64) U64 (u64_add 1u64 2u64))",
+        run_or_panic(
+            &mut session,
+            ":whnf (fun nonrec (U64) U64 (u64_add 1u64 2u64))"
+        )
+    );
+}
+
+#[test]
+fn erasable_and_digest() {
+    let mut session = get_zo_session_with_nat();
+    assert_eq!(
+        "erasability ok",
+        run_or_panic(&mut session, ":erasable (<succ> <zero>)")
+    );
+
+    let digest = run_or_panic(&mut session, ":digest <zero>");
+    let expanded_digest = run_or_panic(
+        &mut session,
+        r#":digest (vcon (ind Set0 "Nat" () ((() ()) ((0) ()))) 0)"#,
+    );
+    assert_eq!(expanded_digest, digest);
+}
+
+#[test]
+fn undefined_placeholder_is_reported() {
+    let mut session = Session::new();
+    assert_eq!(
+        Err("`<foo>` is not defined".to_string()),
+        session.run(":type <foo>")
+    );
+}
+
+#[test]
+fn ill_typed_zo_def_is_rejected() {
+    let mut session = Session::new();
+    assert!(session.run(":def bad (u64_add 1u64 Set0)").is_err());
+    assert!(session.run(":type <bad>").is_err());
+}
+
+#[test]
+fn unclosed_parens_are_incomplete() {
+    let session = Session::new();
+    assert!(session.is_incomplete(":eval (u64_add"));
+    assert!(session.is_incomplete("(u64_add 1u64 \"a"));
+    assert!(!session.is_incomplete("(u64_add 1u64 2u64)"));
+    assert!(!session.is_incomplete(":history"));
+}
+
+#[test]
+fn history_can_be_rerun() {
+    let mut session = Session::new();
+    run_or_panic(&mut session, "(u64_add 1u64 2u64)");
+    run_or_panic(&mut session, ":history");
+    assert_eq!("3u64", run_or_panic(&mut session, ":rerun 1"));
+    assert_eq!(
+        vec!["(u64_add 1u64 2u64)", "(u64_add 1u64 2u64)"],
+        session.history()
+    );
+    assert!(session.run(":rerun 3").is_err());
+}
+
+#[test]
+fn may_defs_are_added_to_context() {
+    let mut session = Session::new();
+    run_or_panic(&mut session, ":lang may");
+
+    let nat_def = "ind Nat
+    case zero
+    case succ(_: Nat)
+    return Set0";
+    assert!(session.is_incomplete(nat_def));
+    assert_eq!("added 1 definition(s)", run_or_panic(&mut session, nat_def));
+    run_or_panic(&mut session, "let one = succ(zero)");
+
+    assert_eq!(
+        run_or_panic(&mut session, ":eval Nat"),
+        run_or_panic(&mut session, ":type one")
+    );
+    insta::assert_display_snapshot!(run_or_panic(&mut session, "succ(one)"));
+}
+
+#[test]
+fn may_file_is_loaded() {
+    let mut session = Session::new();
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../may/src/codegen/tests/samples/add_two_three.may"
+    );
+    insta::assert_display_snapshot!(run_or_panic(&mut session, &format!(":load {path}")));
+
+    // The file's definitions stay in the context.
+    session.lang = Lang::May;
+    assert_eq!(
+        run_or_panic(&mut session, "_3"),
+        run_or_panic(&mut session, "add(succ(zero), _2)")
+    );
+}
+
+#[test]
+fn june_enum_is_added_to_context() {
+    let mut session = Session::new();
+    run_or_panic(&mut session, ":lang june");
+    run_or_panic(
+        &mut session,
+        "enum Nat
+    zero
+    succ(pred: Nat)
+end",
+    );
+    insta::assert_display_snapshot!(run_or_panic(&mut session, "succ(zero)"));
+}
+
+#[test]
+fn june_elim_is_well_typed() {
+    let mut session = Session::new();
+    run_or_panic(&mut session, ":lang june");
+    run_or_panic(
+        &mut session,
+        "enum Nat
+    zero
+    succ(pred: Nat)
+end",
+    );
+    assert_eq!(
+        "erasability ok",
+        run_or_panic(&mut session, ":erasable elim Nat -> Type")
+    );
+    assert_eq!(
+        Err("`elim` can only be applied to an `enum`".to_string()),
+        session.run("elim zero -> Type")
+    );
+}
+
+#[test]
+fn may_elim_of_prop_ind_must_return_prop() {
+    let mut session = Session::new();
+    run_or_panic(&mut session, ":lang may");
+    run_or_panic(
+        &mut session,
+        "ind True
+    case intro
+    return Prop0",
+    );
+    assert_eq!(
+        "erasability ok",
+        run_or_panic(&mut session, ":erasable elim True return Prop0")
+    );
+    assert_eq!(
+        Err("the `elim` of an erasable type must return an erasable type".to_string()),
+        session.run(":type elim True return Set0")
+    );
+}
+
+#[test]
+fn june_match_cases_are_matched_by_name() {
+    let mut session = Session::new();
+    run_or_panic(&mut session, ":lang june");
+    run_or_panic(
+        &mut session,
+        "enum Nat
+    zero
+    succ(pred: Nat)
+end",
+    );
+    assert_eq!(
+        run_or_panic(&mut session, "zero"),
+        run_or_panic(
+            &mut session,
+            "match succ(zero) -> Nat
+    case succ(pred): pred
+    case zero: succ(zero)
+end"
+        )
+    );
+    assert_eq!(
+        Err("`Nat` has no variant named `suc`".to_string()),
+        session.run(
+            "match zero -> Nat
+    case suc(pred): pred
+    case zero: zero
+end"
+        )
+    );
+    assert_eq!(
+        Err("the match is missing the cases `succ`".to_string()),
+        session.run(
+            "match zero -> Nat
+    case zero: zero
+end"
+        )
+    );
+}
+
+#[test]
+fn june_match_cases_use_the_names_of_the_matchee_type() {
+    let mut session = Session::new();
+    run_or_panic(&mut session, ":lang june");
+    run_or_panic(
+        &mut session,
+        "enum Answer
+    yes
+    no
+end",
+    );
+    run_or_panic(
+        &mut session,
+        "enum Bool
+    true
+    false
+end",
+    );
+    // Same shape, but with the vcon names swapped.
+    run_or_panic(
+        &mut session,
+        "enum Bool
+    false
+    true
+end",
+    );
+    assert_eq!(
+        run_or_panic(&mut session, "yes"),
+        run_or_panic(
+            &mut session,
+            "match true -> Answer
+    case true: yes
+    case false: no
+end"
+        )
+    );
+}
+
+#[test]
+fn june_nominal_enum_is_tagged() {
+    let mut session = Session::new();
+    run_or_panic(&mut session, ":lang june");
+    run_or_panic(
+        &mut session,
+        "enum nominal Meters
+    mk
+end",
+    );
+    insta::assert_display_snapshot!(run_or_panic(&mut session, "mk"));
+}
+
+fn get_temp_path(file_name: &str) -> String {
+    std::env::temp_dir()
+        .join(format!("zo-repl-{}-{file_name}", std::process::id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[test]
+fn zo_certificate_is_audited() {
+    let mut session = get_zo_session_with_nat();
+    let path = get_temp_path("nat.zocert");
+    assert_eq!(
+        format!("wrote 3 definition(s) to `{path}`"),
+        run_or_panic(&mut session, &format!(":certify {path}"))
+    );
+    assert_eq!(
+        "kernel checked 3 definition(s)",
+        run_or_panic(&mut session, &format!(":audit {path}"))
+    );
+
+    let tampered = std::fs::read_to_string(&path).unwrap().replacen(
+        "(vcon (ind Set0 \"Nat\" () ((() ()) ((0) ()))) 0)",
+        "(vcon (ind Set0 \"Nat\" () ((() ()) ((0) ()))) 1)",
+        1,
+    );
+    std::fs::write(&path, tampered).unwrap();
+    let err = session.run(&format!(":audit {path}")).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert!(err.starts_with("`zero`: the digest is"), "{err}");
+}
+
+#[test]
+fn may_certificate_is_audited() {
+    let mut session = Session::new();
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../may/src/codegen/tests/samples/add_two_three.may"
+    );
+    run_or_panic(&mut session, &format!(":load {path}"));
+    session.lang = Lang::May;
+
+    let cert_path = get_temp_path("add_two_three.zocert");
+    run_or_panic(&mut session, &format!(":certify {cert_path}"));
+    let result = session.run(&format!(":audit {cert_path}"));
+    std::fs::remove_file(&cert_path).unwrap();
+    assert_eq!(Ok("kernel checked 4 definition(s)".to_string()), result);
+}
@@ -0,0 +1,247 @@
+//! The Zo backend shared by all three languages.
+//!
+//! Every REPL input is eventually converted to closed Zo source,
+//! which the functions in this module parse, typecheck, and evaluate.
+
+use zoc::{
+    check_erasability::ErasabilityChecker,
+    diagnostic::{Diagnostic, ToDiagnostic},
+    eliminator::EliminatorError,
+    eval::{Evaluator, NormalForm, Normalized, ReductionStrategy, Tracer},
+    hash::GetDigest,
+    kernel::{Certificate, CertificateEntry, CertificateError, KernelError, Term},
+    pretty_print::PrettyPrint,
+    syntax_tree::{
+        ast::prelude::{minimal_ast, spanned_ast},
+        lexer::{lex, lex_with_recovery, LexErrorKind},
+        parser::parse,
+        remove_ast_aux_data::AuxDataRemover,
+        token::Token,
+    },
+    typecheck::{LazyTypeContext, TypeChecker},
+};
+
+/// A closed, well-typed Zo expression.
+pub struct Checked {
+    pub expr: minimal_ast::Expr,
+    pub type_: NormalForm,
+}
+
+/// Zo is fully parenthesized,
+/// so input is incomplete exactly when it has unclosed parentheses
+/// (or an unterminated string).
+pub fn is_incomplete(src: &str) -> bool {
+    let (tokens, _, errors) = lex_with_recovery(src);
+    if errors
+        .iter()
+        .any(|err| *err.kind() == LexErrorKind::UnterminatedString)
+    {
+        return true;
+    }
+
+    let depth = tokens.iter().fold(0isize, |depth, token| match token {
+        Token::LParen(_) => depth + 1,
+        Token::RParen(_) => depth - 1,
+        _ => depth,
+    });
+    depth > 0
+}
+
+pub fn parse_src(src: &str) -> Result<spanned_ast::Expr, String> {
+    let tokens = lex(src).map_err(|err| render_diagnostic(&err.to_diagnostic()))?;
+    let cst = parse(tokens).map_err(|err| render_diagnostic(&err.to_diagnostic()))?;
+    Ok(cst.into())
+}
+
+pub fn parse_minimal_src(src: &str) -> Result<minimal_ast::Expr, String> {
+    let spanned = parse_src(src)?;
+    Ok(AuxDataRemover::default().convert(spanned))
+}
+
+pub fn typecheck_src(src: &str) -> Result<Checked, String> {
+    let spanned = parse_src(src)?;
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    let type_ = TypeChecker::default()
+        .get_type(spanned.clone(), tcon)
+        .map_err(|err| render_diagnostic(&err.to_diagnostic()))?;
+    let expr = AuxDataRemover::default().convert(spanned);
+    Ok(Checked { expr, type_ })
+}
+
+pub fn print_type(checked: &Checked) -> String {
+    PrettyPrint(checked.type_.raw()).to_string()
+}
+
+pub fn print_normal_form(checked: &Checked) -> String {
+    let normalized = Evaluator::default().eval(checked.expr.clone());
+    PrettyPrint(normalized.raw()).to_string()
+}
+
+pub fn print_weak_head_normal_form(checked: &Checked) -> String {
+    let trace = Tracer::new(ReductionStrategy::WeakHead).trace(checked.expr.clone());
+    let whnf = PrettyPrint(trace.final_expr()).to_string();
+    if trace.reached_normal_form {
+        whnf
+    } else {
+        format!(
+            "{whnf}\n(stopped after {} steps before reaching a weak head normal form)",
+            trace.steps.len()
+        )
+    }
+}
+
+pub fn print_erasability(checked: &Checked) -> String {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    let normalized = Evaluator::default().eval(checked.expr.clone());
+    match ErasabilityChecker::default().check_erasability_of_well_typed_expr(normalized, tcon) {
+        Ok(()) => "erasability ok".to_string(),
+        Err(err) => render_diagnostic(&err.to_diagnostic()),
+    }
+}
+
+pub fn print_digest(expr: &minimal_ast::Expr) -> String {
+    format!("{:?}", expr.digest())
+}
+
+/// Returns a certificate entry claiming that `src`
+/// has the type `TypeChecker` infers for it.
+pub fn certify(name: String, src: &str) -> Result<CertificateEntry, String> {
+    let checked = typecheck_src(src)?;
+    Ok(CertificateEntry::new(
+        name,
+        &checked.expr,
+        checked.type_.raw(),
+    ))
+}
+
+/// Checks the certificate `src` with the kernel.
+pub fn audit(src: &str) -> Result<String, String> {
+    let certificate = Certificate::parse(src).map_err(|err| render_certificate_error(&err))?;
+    certificate
+        .check()
+        .map_err(|err| render_certificate_error(&err))?;
+    Ok(format!(
+        "kernel checked {} definition(s)",
+        certificate.entries.len()
+    ))
+}
+
+/// Renders `diagnostic` without its spans,
+/// since they point into the expanded source,
+/// not into what the user typed.
+pub fn render_diagnostic(diagnostic: &Diagnostic) -> String {
+    let mut out = format!("error[{}]: {}", diagnostic.code, diagnostic.message);
+    if let Some(expected) = &diagnostic.expected_type {
+        out.push_str(&format!("\n  expected type: {expected}"));
+    }
+    if let Some(actual) = &diagnostic.actual_type {
+        out.push_str(&format!("\n  actual type: {actual}"));
+    }
+    out
+}
+
+pub fn render_eliminator_error(err: &EliminatorError) -> String {
+    match err {
+        EliminatorError::NonErasableMotiveForErasableInd { .. } => {
+            "the `elim` of an erasable type must return an erasable type".to_string()
+        }
+        EliminatorError::UnsupportedRecursiveParam {
+            vcon_index,
+            param_index,
+            ..
+        } => format!(
+            "cannot generate an `elim`, since param {param_index} of vcon {vcon_index} \
+             refers to the type other than as a direct recursive param"
+        ),
+    }
+}
+
+pub fn render_certificate_error(err: &CertificateError) -> String {
+    match err {
+        CertificateError::Malformed { line, message } => {
+            format!("malformed certificate (line {line}): {message}")
+        }
+        CertificateError::Syntax { name, err } => {
+            format!("`{name}`: {}", render_diagnostic(&err.to_diagnostic()))
+        }
+        CertificateError::DigestMismatch {
+            name,
+            claimed,
+            actual,
+        } => format!("`{name}`: the digest is {actual}, not {claimed}"),
+        CertificateError::Kernel { name, err } => {
+            format!("`{name}`: kernel error: {}", render_kernel_error(err))
+        }
+        CertificateError::TypeMismatch {
+            name,
+            claimed_type,
+            actual_type,
+        } => format!(
+            "`{name}`: the kernel infers a different type\n  claimed type: {}\n  actual type: {}",
+            print_term(claimed_type),
+            print_term(actual_type)
+        ),
+    }
+}
+
+fn render_kernel_error(err: &KernelError) -> String {
+    match err {
+        KernelError::UnelaboratedTerm => "the term contains a meta or a hole".to_string(),
+        KernelError::InvalidDeb(deb) => format!("`{deb}` is not bound"),
+        KernelError::InvalidVconIndex(term) => {
+            format!("invalid vcon index in {}", print_term(term))
+        }
+        KernelError::EmptyParamList(term) => format!("{} has no params", print_term(term)),
+        KernelError::NotAType { term, type_ } => format!(
+            "{} is not a type (its type is {})",
+            print_term(term),
+            print_term(type_)
+        ),
+        KernelError::TypeMismatch {
+            term,
+            expected_type,
+            actual_type,
+        } => format!(
+            "type mismatch in {}\n  expected type: {}\n  actual type: {}",
+            print_term(term),
+            print_term(expected_type),
+            print_term(actual_type)
+        ),
+        KernelError::NotAFunction { callee, type_ } => format!(
+            "{} is not a function (its type is {})",
+            print_term(callee),
+            print_term(type_)
+        ),
+        KernelError::WrongNumberOfArgs { callee, type_ } => format!(
+            "{} (of type {}) is applied to the wrong number of args",
+            print_term(callee),
+            print_term(type_)
+        ),
+        KernelError::WrongNumberOfIndexArgs(_) => {
+            "a vcon def has the wrong number of index args".to_string()
+        }
+        KernelError::UniverseInconsistency(ind) => {
+            format!("`{}` is too small for its params", ind.name)
+        }
+        KernelError::NonPositiveInd(ind) => {
+            format!("`{}` is not strictly positive", ind.name)
+        }
+        KernelError::NonInductiveMatchee(_) => "the matchee's type is not an ind".to_string(),
+        KernelError::MalformedMatch(_) => {
+            "a match has the wrong number of cases, or an arity is wrong".to_string()
+        }
+        KernelError::OmittedPossibleCase(_) => {
+            "a match omits a case the kernel cannot prove impossible".to_string()
+        }
+        KernelError::DecreasingIndexTooBig(_) => "a decreasing index is too big".to_string(),
+        KernelError::IllegalRecursion(term) => {
+            format!("illegal recursion in {}", print_term(term))
+        }
+    }
+}
+
+fn print_term(term: &Term) -> String {
+    PrettyPrint(&term.to_minimal()).to_string()
+}
@@ -85,9 +85,21 @@ impl ErasabilityChecker {
             //   a non-erasable output.
             //
             // - We can skip checking universes since we can erase them entirely.
-            Expr::Ind(_) | Expr::Vcon(_) | Expr::For(_) | Expr::Deb(_) | Expr::Universe(_) => {
-                Ok(())
-            }
+            //
+            // - We can skip checking literals and prims since they are
+            //   closed leaf nodes, so they cannot depend on an erasable value.
+            //
+            // - We can skip checking metas since they should have been
+            //   replaced by their solutions before erasability checking.
+            Expr::Ind(_)
+            | Expr::Vcon(_)
+            | Expr::For(_)
+            | Expr::Deb(_)
+            | Expr::Universe(_)
+            | Expr::Lit(_)
+            | Expr::Prim(_)
+            | Expr::Meta(_)
+            | Expr::Hole(_) => Ok(()),
         }
     }
 
@@ -167,16 +179,19 @@ impl ErasabilityChecker {
 
         let case_g1 = &match_g0.hashee.cases.hashee[i];
 
-        let vcon_param_types = matchee_type_ind_g0
-            .to_hashee()
-            .vcon_defs()
-            .hashee()
-            .index(i)
-            .param_types();
+        // The vcon def's param types refer to the `ind` itself,
+        // so we take the param types from the vcon's type instead.
+        let vcon_param_types_g0 = self
+            .typechecker
+            .get_type_of_vcon_from_well_typed_ind_and_valid_vcon_index(matchee_type_ind_g0, i)
+            .for_param_types_or_empty_vec();
         let tcon_with_case_params_g1 =
-            LazyTypeContext::Snoc(&tcon_g0, vcon_param_types.hashee().convert_ref());
+            LazyTypeContext::Snoc(&tcon_g0, vcon_param_types_g0.to_hashee().derefed());
 
-        self.check(case_g1.return_val.clone(), tcon_with_case_params_g1)
+        match &case_g1.return_val {
+            Some(return_val) => self.check(return_val.clone(), tcon_with_case_params_g1),
+            None => Ok(()),
+        }
     }
 
     fn check_fun(
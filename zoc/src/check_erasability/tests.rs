@@ -263,3 +263,33 @@ fn ok_nonerasable_to_erasable() {
     let src = substitute_with_compounding(src_defs, unsubstituted_src);
     check_erasability_under_empty_tcon_or_panic(&src);
 }
+
+#[test]
+fn ok_nested_match_on_recursive_ind_case_param() {
+    let nat_def = (
+        "<NAT>",
+        r#"
+(ind Set0 "Nat" () (
+    (() ())
+    ((0) ())
+))"#,
+    );
+    let zero_def = ("<ZERO>", r#"(vcon <NAT> 0)"#);
+    let src_defs = [nat_def, zero_def];
+
+    // The inner matchee is the outer case's param,
+    // whose type is `<NAT>` (not the vcon def's raw param type `0`).
+    let unsubstituted_src = r#"
+(fun nonrec (<NAT>) <NAT>
+    (match 1 1 <NAT> (
+        (0 <ZERO>)
+        (1 (match 0 1 <NAT> (
+            (0 <ZERO>)
+            (1 0)
+        )))
+    ))
+)"#;
+
+    let src = substitute_with_compounding(src_defs, unsubstituted_src);
+    check_erasability_under_empty_tcon_or_panic(&src);
+}
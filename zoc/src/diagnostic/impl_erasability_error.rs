@@ -0,0 +1,26 @@
+use super::*;
+
+use crate::{check_erasability::ErasabilityError, pretty_print::PrettyPrinted};
+
+/// Erasability is checked on normalized (and thus span-less) expressions,
+/// so erasability diagnostics have no spans.
+impl ToDiagnostic for ErasabilityError {
+    fn to_diagnostic(&self) -> Diagnostic {
+        match self {
+            ErasabilityError::MatcheeTypeTypeIsErasableButReturnTypeTypeIsNotErasable {
+                matchee_type_type,
+                match_return_type_type,
+                ..
+            } => Diagnostic::error(
+                "Z2001",
+                "MatcheeTypeTypeIsErasableButReturnTypeTypeIsNotErasable",
+                format!(
+                    "cannot match on a value of an erasable type (whose type is {}) \
+                     to produce a value of a non-erasable type (whose type is {})",
+                    matchee_type_type.pretty_printed(),
+                    match_return_type_type.pretty_printed(),
+                ),
+            ),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use super::*;
+
+use crate::syntax_tree::{
+    lexer::{LexError, LexErrorKind},
+    parser::{ParseError, SyntaxError},
+    token::{Token, TokenKind},
+};
+
+impl ToDiagnostic for LexError {
+    fn to_diagnostic(&self) -> Diagnostic {
+        Diagnostic::error("Z0001", "LexError", lex_error_message(self.kind()))
+            .with_primary_span(Some(self.span()))
+    }
+}
+
+fn lex_error_message(kind: &LexErrorKind) -> String {
+    match kind {
+        LexErrorKind::UnexpectedChar(c) => format!("unexpected character `{c}`"),
+        LexErrorKind::UnrecognizedWord(word) => {
+            format!("`{word}` is not a keyword, literal, universe, or primitive")
+        }
+        LexErrorKind::UnterminatedString => {
+            "unterminated string literal: missing closing `\"`".to_string()
+        }
+        LexErrorKind::LoneSlash => "unexpected `/` (comments start with `//`)".to_string(),
+        LexErrorKind::InvalidEscapeSequence => {
+            "invalid escape sequence (expected a hex code point like `{0x7B}`)".to_string()
+        }
+        LexErrorKind::UnterminatedEscapeSequence => {
+            "unterminated escape sequence: missing closing `}`".to_string()
+        }
+        LexErrorKind::UnescapedRightCurly => {
+            "unescaped `}` in string literal (write `{0x7D}` instead)".to_string()
+        }
+    }
+}
+
+/// The parser reports the first token it could not accept,
+/// or `None` if it reached the end of the input unexpectedly.
+impl ToDiagnostic for Option<Token> {
+    fn to_diagnostic(&self) -> Diagnostic {
+        match self {
+            Some(token) => Diagnostic::error("Z0002", "UnexpectedToken", "unexpected token")
+                .with_primary_span(Some(token.span())),
+            None => Diagnostic::error("Z0003", "UnexpectedEof", "unexpected end of input"),
+        }
+    }
+}
+
+impl ToDiagnostic for ParseError {
+    fn to_diagnostic(&self) -> Diagnostic {
+        let unexpected = self.unexpected.to_diagnostic();
+        let expected = expected_tokens_message(&self.expected);
+        Diagnostic {
+            message: format!("{}; expected {expected}", unexpected.message),
+            ..unexpected
+        }
+    }
+}
+
+fn expected_tokens_message(expected: &[Option<TokenKind>]) -> String {
+    let descriptions: Vec<&str> = expected
+        .iter()
+        .map(|kind| match kind {
+            Some(kind) => kind.description(),
+            None => "end of input",
+        })
+        .collect();
+
+    match descriptions.as_slice() {
+        [] => "nothing".to_string(),
+        [only] => only.to_string(),
+        [rdc @ .., rac] => format!("{} or {rac}", rdc.join(", ")),
+    }
+}
+
+impl ToDiagnostic for SyntaxError {
+    fn to_diagnostic(&self) -> Diagnostic {
+        match self {
+            SyntaxError::Lex(err) => err.to_diagnostic(),
+            SyntaxError::Parse(err) => err.to_diagnostic(),
+        }
+    }
+}
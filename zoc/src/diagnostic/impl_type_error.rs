@@ -0,0 +1,352 @@
+use super::*;
+
+use crate::{
+    eval::NormalForm,
+    pretty_print::{GetOptSpan, PrettyPrinted},
+    syntax_tree::ast::prelude::{
+        minimal_ast,
+        spanned_ast::{self, SpanAuxDataFamily},
+    },
+    typecheck::TypeError,
+};
+
+impl ToDiagnostic for TypeError<SpanAuxDataFamily> {
+    fn to_diagnostic(&self) -> Diagnostic {
+        let diagnostic = match self {
+            TypeError::InvalidDeb { deb, tcon_len } => Diagnostic::error(
+                "Z1001",
+                "InvalidDeb",
+                format!(
+                    "de Bruijn index {} is out of scope (the context only has {} entries)",
+                    deb.deb.0, tcon_len
+                ),
+            ),
+
+            TypeError::InvalidVconIndex(vcon) => Diagnostic::error(
+                "Z1002",
+                "InvalidVconIndex",
+                format!(
+                    "vcon index {} is out of range (the ind only has {} vcon defs{})",
+                    vcon.vcon_index,
+                    vcon.ind.hashee.vcon_defs.hashee.len(),
+                    vcon_names_suffix(&vcon.ind.hashee.vcon_defs.hashee),
+                ),
+            )
+            .with_related("ind", vcon.ind.hashee.opt_span()),
+
+            TypeError::UnknownVconName(vcon) => Diagnostic::error(
+                "Z1027",
+                "UnknownVconName",
+                format!(
+                    "the ind has no vcon def with this name (the ind has {} vcon defs{})",
+                    vcon.ind.hashee.vcon_defs.hashee.len(),
+                    vcon_names_suffix(&vcon.ind.hashee.vcon_defs.hashee),
+                ),
+            )
+            .with_related("ind", vcon.ind.hashee.opt_span()),
+
+            TypeError::UnexpectedNonTypeExpression { type_, .. } => Diagnostic::error(
+                "Z1003",
+                "UnexpectedNonTypeExpression",
+                "expected a type, but found an expression whose type is not a universe",
+            )
+            .with_actual_type(pretty(type_)),
+
+            TypeError::UniverseInconsistencyInIndDef { universe, ind, .. } => Diagnostic::error(
+                "Z1004",
+                "UniverseInconsistencyInIndDef",
+                format!(
+                    "this type lives in universe level {}, which is above the ind's level {}",
+                    universe.level.0, ind.universe.level.0
+                ),
+            )
+            .with_related("ind", ind.opt_span()),
+
+            TypeError::WrongNumberOfIndexArguments {
+                expected, actual, ..
+            } => Diagnostic::error(
+                "Z1005",
+                "WrongNumberOfIndexArguments",
+                format!("expected {expected} index arguments, but found {actual}"),
+            ),
+
+            TypeError::NonInductiveMatcheeType { type_, .. } => Diagnostic::error(
+                "Z1006",
+                "NonInductiveMatcheeType",
+                "the matchee's type is not an inductive type",
+            )
+            .with_actual_type(pretty(type_)),
+
+            TypeError::WrongNumberOfMatchCases {
+                match_,
+                matchee_type_ind,
+            } => Diagnostic::error(
+                "Z1007",
+                "WrongNumberOfMatchCases",
+                format!(
+                    "expected {} match cases, but found {}",
+                    matchee_type_ind.raw().vcon_defs.hashee.len(),
+                    match_.cases.hashee.len()
+                ),
+            )
+            .with_actual_type(matchee_type_ind.raw().pretty_printed().named().to_string()),
+
+            TypeError::WrongMatchReturnTypeArity {
+                match_,
+                matchee_type_args,
+            } => Diagnostic::error(
+                "Z1008",
+                "WrongMatchReturnTypeArity",
+                format!(
+                    "expected a return type arity of {}, but found {}",
+                    1 + matchee_type_args.len(),
+                    match_.return_type_arity
+                ),
+            )
+            .with_related("return_type", match_.return_type.opt_span()),
+
+            TypeError::WrongMatchCaseArity {
+                stated_arity,
+                expected,
+                match_,
+                match_case_index,
+            } => Diagnostic::error(
+                "Z1009",
+                "WrongMatchCaseArity",
+                format!(
+                    "expected match case {match_case_index} to have arity {expected}, \
+                     but found {stated_arity}"
+                ),
+            )
+            .with_related(
+                "match_case",
+                match_
+                    .cases
+                    .hashee
+                    .get(*match_case_index)
+                    .and_then(GetOptSpan::opt_span),
+            ),
+
+            TypeError::TypeMismatch {
+                expected_type,
+                actual_type,
+                ..
+            } => Diagnostic::error(
+                "Z1010",
+                "TypeMismatch",
+                type_mismatch_message(expected_type, actual_type),
+            )
+            .with_expected_type(pretty(expected_type))
+            .with_actual_type(pretty(actual_type)),
+
+            TypeError::CalleeTypeIsNotAForExpression { app, callee_type } => Diagnostic::error(
+                "Z1011",
+                "CalleeTypeIsNotAForExpression",
+                "the callee's type is not a function type",
+            )
+            .with_related("callee", app.callee.opt_span())
+            .with_actual_type(pretty(callee_type)),
+
+            TypeError::WrongNumberOfAppArguments {
+                app,
+                callee_type,
+                expected,
+                actual,
+            } => Diagnostic::error(
+                "Z1012",
+                "WrongNumberOfAppArguments",
+                format!("expected {expected} arguments, but found {actual}"),
+            )
+            .with_related("callee", app.callee.opt_span())
+            .with_actual_type(callee_type.raw().pretty_printed().named().to_string()),
+
+            TypeError::FunHasZeroParams { .. } => Diagnostic::error(
+                "Z1013",
+                "FunHasZeroParams",
+                "a fun must have at least one param",
+            ),
+
+            TypeError::AppHasZeroArgs { .. } => Diagnostic::error(
+                "Z1014",
+                "AppHasZeroArgs",
+                "an app must have at least one arg",
+            ),
+
+            TypeError::ForHasZeroParams { .. } => Diagnostic::error(
+                "Z1015",
+                "ForHasZeroParams",
+                "a for must have at least one param",
+            ),
+
+            TypeError::IllegalRecursiveCall {
+                callee_deb_definition_src,
+                required_decreasing_arg_index,
+                required_strict_superstruct,
+                ..
+            } => Diagnostic::error(
+                "Z1016",
+                "IllegalRecursiveCall",
+                format!(
+                    "arg {} of a recursive call must be a strict substruct of deb {}",
+                    required_decreasing_arg_index, required_strict_superstruct.0
+                ),
+            )
+            .with_related(
+                "callee_deb_definition_src",
+                callee_deb_definition_src.opt_span(),
+            ),
+
+            TypeError::RecursiveFunParamInNonCalleePosition { definition_src, .. } => {
+                Diagnostic::error(
+                    "Z1017",
+                    "RecursiveFunParamInNonCalleePosition",
+                    "a recursive fun can only be used as the callee of an app",
+                )
+                .with_related("definition_src", definition_src.opt_span())
+            }
+
+            TypeError::DeclaredFunNonrecursiveButUsedRecursiveFunParam {
+                definition_src, ..
+            } => Diagnostic::error(
+                "Z1018",
+                "DeclaredFunNonrecursiveButUsedRecursiveFunParam",
+                "a nonrec fun cannot refer to itself",
+            )
+            .with_related("definition_src", definition_src.opt_span()),
+
+            TypeError::DecreasingArgIndexTooBig { fun } => Diagnostic::error(
+                "Z1019",
+                "DecreasingArgIndexTooBig",
+                format!(
+                    "the decreasing arg index must be less than the param count ({})",
+                    fun.param_types.hashee.len()
+                ),
+            ),
+
+            TypeError::VconDefParamTypeFailsStrictPositivityCondition {
+                param_type_index,
+                normalized_param_type,
+                ..
+            } => Diagnostic::error(
+                "Z1020",
+                "VconDefParamTypeFailsStrictPositivityCondition",
+                format!("param type {param_type_index} is not strictly positive"),
+            )
+            .with_actual_type(pretty(normalized_param_type)),
+
+            TypeError::RecursiveIndParamAppearsInVconDefIndexArg {
+                index_arg_index,
+                normalized_index_arg,
+                ..
+            } => Diagnostic::error(
+                "Z1021",
+                "RecursiveIndParamAppearsInVconDefIndexArg",
+                format!("index arg {index_arg_index} refers to the ind being defined"),
+            )
+            .with_actual_type(pretty(normalized_index_arg)),
+
+            TypeError::CannotInferMetaType { meta } => Diagnostic::error(
+                "Z1022",
+                "CannotInferMetaType",
+                format!(
+                    "cannot infer the type of _{} (it must first appear in a checking position)",
+                    meta.id.0
+                ),
+            ),
+
+            TypeError::CannotInferHoleType { .. } => Diagnostic::error(
+                "Z1023",
+                "CannotInferHoleType",
+                "holes are only allowed in checking positions, when reporting hole goals",
+            ),
+
+            TypeError::UnsolvedMetas { metas } => {
+                let names: Vec<String> =
+                    metas.iter().map(|meta| format!("_{}", meta.id.0)).collect();
+                metas.iter().skip(1).fold(
+                    Diagnostic::error(
+                        "Z1024",
+                        "UnsolvedMetas",
+                        format!("unsolved metas: {}", names.join(", ")),
+                    ),
+                    |diagnostic, meta| diagnostic.with_related("unsolved_meta", meta.opt_span()),
+                )
+            }
+
+            TypeError::MatchCaseIsNotImpossible {
+                match_case_index, ..
+            } => Diagnostic::error(
+                "Z1025",
+                "MatchCaseIsNotImpossible",
+                format!(
+                    "match case {match_case_index} was omitted, \
+                     but its vcon's index args do not contradict the matchee's type"
+                ),
+            ),
+
+            TypeError::InvalidCode { .. } => Diagnostic::error(
+                "Z1026",
+                "InvalidCode",
+                "this code could not be parsed, so it cannot be typechecked",
+            ),
+        };
+
+        diagnostic.with_primary_span(self.opt_span())
+    }
+}
+
+fn pretty(type_: &NormalForm) -> String {
+    type_.raw().pretty_printed().named().to_string()
+}
+
+/// Nominal inds can have the same name but different tags,
+/// in which case the pretty-printed types can look alike.
+/// So, we point out the tag difference explicitly.
+fn type_mismatch_message(expected_type: &NormalForm, actual_type: &NormalForm) -> String {
+    let (Some(expected_ind), Some(actual_ind)) = (
+        get_type_ind(expected_type.raw()),
+        get_type_ind(actual_type.raw()),
+    ) else {
+        return "type mismatch".to_string();
+    };
+
+    if expected_ind.name == actual_ind.name && expected_ind.tag != actual_ind.tag {
+        return format!(
+            "type mismatch (the types are different nominal inds named {:?})",
+            expected_ind.name.0
+        );
+    }
+
+    "type mismatch".to_string()
+}
+
+/// Returns the ind if `type_` is an ind or an application of an ind.
+fn get_type_ind(type_: &minimal_ast::Expr) -> Option<&minimal_ast::Ind> {
+    match type_ {
+        minimal_ast::Expr::Ind(ind) => Some(&ind.hashee),
+        minimal_ast::Expr::App(app) => match &app.hashee.callee {
+            minimal_ast::Expr::Ind(ind) => Some(&ind.hashee),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns `: "a", "b"` for vcon defs named `a` and `b`,
+/// or the empty string if none of the defs are named.
+/// Unnamed defs are listed by index.
+fn vcon_names_suffix(defs: &[spanned_ast::VconDef]) -> String {
+    if defs.iter().all(|def| def.name.is_none()) {
+        return String::new();
+    }
+
+    let names: Vec<String> = defs
+        .iter()
+        .enumerate()
+        .map(|(i, def)| match &def.name {
+            Some(name) => format!("{:?}", name.0),
+            None => i.to_string(),
+        })
+        .collect();
+    format!(": {}", names.join(", "))
+}
@@ -0,0 +1,174 @@
+//! Machine-readable diagnostics.
+//!
+//! Every error the compiler can report (lex, parse, type, and erasability errors)
+//! can be converted into a `Diagnostic`,
+//! which can then be serialized to JSON.
+//!
+//! The JSON schema is versioned by `DIAGNOSTIC_SCHEMA_VERSION`.
+//! Any change to the schema (including changing an error code)
+//! must bump the version.
+
+use crate::syntax_tree::token::Span;
+
+pub use crate::json::JsonValue;
+
+mod impl_erasability_error;
+mod impl_syntax_error;
+mod impl_type_error;
+
+#[cfg(test)]
+mod tests;
+
+/// Version history:
+/// - 1: The initial schema.
+/// - 2: Added `Z1025` (`MatchCaseIsNotImpossible`),
+///   `Z1026` (`InvalidCode`), and `Z1027` (`UnknownVconName`).
+///   Unknown vcon names were previously reported as `Z1002`.
+///   Some messages (e.g., `Z1002`'s and `Z1023`'s) changed.
+pub const DIAGNOSTIC_SCHEMA_VERSION: usize = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// A stable code that identifies the kind of error
+    /// (e.g., `"Z1010"` for a type mismatch).
+    pub code: &'static str,
+    /// The name of the error variant (e.g., `"TypeMismatch"`).
+    pub kind: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    pub primary_span: Option<Span>,
+    pub related: Vec<RelatedSpan>,
+    pub expected_type: Option<String>,
+    pub actual_type: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A secondary location that helps explain a diagnostic
+/// (e.g., the definition of the function
+/// that was called illegally).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RelatedSpan {
+    pub label: &'static str,
+    pub span: Span,
+}
+
+pub trait ToDiagnostic {
+    fn to_diagnostic(&self) -> Diagnostic;
+}
+
+impl Diagnostic {
+    fn error(code: &'static str, kind: &'static str, message: impl Into<String>) -> Self {
+        Diagnostic {
+            code,
+            kind,
+            severity: Severity::Error,
+            message: message.into(),
+            primary_span: None,
+            related: vec![],
+            expected_type: None,
+            actual_type: None,
+        }
+    }
+
+    fn with_primary_span(self, primary_span: Option<Span>) -> Self {
+        Diagnostic {
+            primary_span,
+            ..self
+        }
+    }
+
+    fn with_related(mut self, label: &'static str, span: Option<Span>) -> Self {
+        if let Some(span) = span {
+            self.related.push(RelatedSpan { label, span });
+        }
+        self
+    }
+
+    fn with_expected_type(self, expected_type: String) -> Self {
+        Diagnostic {
+            expected_type: Some(expected_type),
+            ..self
+        }
+    }
+
+    fn with_actual_type(self, actual_type: String) -> Self {
+        Diagnostic {
+            actual_type: Some(actual_type),
+            ..self
+        }
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        JsonValue::object([
+            ("code", JsonValue::string(self.code)),
+            ("kind", JsonValue::string(self.kind)),
+            ("severity", JsonValue::string(self.severity.as_str())),
+            ("message", JsonValue::string(self.message.clone())),
+            ("primary_span", opt_span_to_json(self.primary_span)),
+            (
+                "related",
+                JsonValue::Array(
+                    self.related
+                        .iter()
+                        .map(|related| {
+                            JsonValue::object([
+                                ("label", JsonValue::string(related.label)),
+                                ("span", span_to_json(related.span)),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+            (
+                "expected_type",
+                JsonValue::opt_string(self.expected_type.clone()),
+            ),
+            (
+                "actual_type",
+                JsonValue::opt_string(self.actual_type.clone()),
+            ),
+        ])
+    }
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// Returns the top-level JSON document for `diagnostics`.
+pub fn diagnostics_to_json<'a>(diagnostics: impl IntoIterator<Item = &'a Diagnostic>) -> JsonValue {
+    JsonValue::object([
+        (
+            "schema_version",
+            JsonValue::Number(DIAGNOSTIC_SCHEMA_VERSION as u64),
+        ),
+        (
+            "diagnostics",
+            JsonValue::Array(diagnostics.into_iter().map(Diagnostic::to_json).collect()),
+        ),
+    ])
+}
+
+fn span_to_json(span: Span) -> JsonValue {
+    JsonValue::object([
+        ("start", JsonValue::Number(span.0 .0 as u64)),
+        ("end", JsonValue::Number(span.1 .0 as u64)),
+    ])
+}
+
+fn opt_span_to_json(span: Option<Span>) -> JsonValue {
+    match span {
+        Some(span) => span_to_json(span),
+        None => JsonValue::Null,
+    }
+}
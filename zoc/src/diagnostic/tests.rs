@@ -0,0 +1,152 @@
+use super::*;
+
+use crate::{
+    syntax_tree::{
+        lexer::lex,
+        parser::{parse, parse_src_with_recovery},
+    },
+    test_utils::*,
+};
+
+/// If this test fails, the schema has changed.
+/// Bump `DIAGNOSTIC_SCHEMA_VERSION` and update the snapshot.
+#[test]
+fn schema_v2() {
+    assert_eq!(2, DIAGNOSTIC_SCHEMA_VERSION);
+
+    let src = r#"((fun nonrec (U64) U64 1) "a")"#;
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+    let json = diagnostics_to_json([&err.to_diagnostic()]);
+    insta::assert_display_snapshot!(format!("{json:#}"));
+}
+
+#[test]
+fn compact_json() {
+    let src = r#"((fun nonrec (U64) U64 1) "a")"#;
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+    let json = diagnostics_to_json([&err.to_diagnostic()]);
+    insta::assert_display_snapshot!(json);
+}
+
+#[test]
+fn illegal_recursive_call_has_related_definition_span() {
+    let nat_def = (
+        "<NAT>",
+        r#"
+(ind Set0 "Nat" () (
+    (() ())
+    ((0) ())
+))"#,
+    );
+    let unsubstituted_src = r#"
+(fun 0 (<NAT>) <NAT>
+    (match 2 1 <NAT> (
+        (0 1)
+        (1 (1 2))
+    ))
+)"#;
+    let src = substitute_with_compounding([nat_def], unsubstituted_src);
+    let err = get_type_error_under_empty_tcon_or_panic(&src);
+    insta::assert_display_snapshot!(format!("{:#}", err.to_diagnostic().to_json()));
+}
+
+#[test]
+fn unknown_vcon_name_lists_vcon_names() {
+    let src = r#"(vcon (ind Set0 "Nat" () (("zero" () ()) ("succ" (0) ()))) "suc")"#;
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(format!("{:#}", err.to_diagnostic().to_json()));
+}
+
+#[test]
+fn nominal_type_mismatch() {
+    let meters = r#"(ind Set0 "Unit" "meters" () ((() ())))"#;
+    let seconds = r#"(ind Set0 "Unit" "seconds" () ((() ())))"#;
+    let src = format!("((fun nonrec ({seconds}) {seconds} 1) (vcon {meters} 0))");
+    let err = get_type_error_under_empty_tcon_or_panic(&src);
+    insta::assert_display_snapshot!(format!("{:#}", err.to_diagnostic().to_json()));
+}
+
+#[test]
+fn match_case_is_not_impossible() {
+    let src = r#"(fun nonrec (Set0 (ind Set0 "Bool" () ((() ()) (() ())))) U64 (match 1 1 U64 ((0 0u64) (0))))"#;
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(format!("{:#}", err.to_diagnostic().to_json()));
+}
+
+#[test]
+fn collected_errors() {
+    let src = r#"((fun nonrec (U64 String) U64 2) "a" 3u64)"#;
+    let errors = get_type_errors_under_empty_tcon_or_panic(src);
+    let diagnostics: Vec<Diagnostic> = errors.iter().map(ToDiagnostic::to_diagnostic).collect();
+    insta::assert_display_snapshot!(format!("{:#}", diagnostics_to_json(&diagnostics)));
+}
+
+#[test]
+fn erasability_error() {
+    let bool_prop_def = (
+        "<BOOL_PROP>",
+        r#"
+(ind Prop0 "Bool" () (
+    (() ())
+    (() ())
+))"#,
+    );
+    let bool_set_def = (
+        "<BOOL_SET>",
+        r#"
+(ind Set0 "Bool" () (
+    (() ())
+    (() ())
+))"#,
+    );
+    let true_set_def = ("<TRUE_SET>", r#"(vcon <BOOL_SET> 0)"#);
+    let false_set_def = ("<FALSE_SET>", r#"(vcon <BOOL_SET> 1)"#);
+    let src_defs = [bool_prop_def, bool_set_def, true_set_def, false_set_def];
+    let unsubstituted_src = r#"
+(fun nonrec (<BOOL_PROP>) <BOOL_SET>
+    (match 1 1 <BOOL_SET> (
+        (0 <TRUE_SET>)
+        (0 <FALSE_SET>)
+    ))
+)"#;
+    let src = substitute_with_compounding(src_defs, unsubstituted_src);
+    let err = get_erasability_error_under_empty_tcon_or_panic(&src);
+    insta::assert_display_snapshot!(format!("{:#}", err.to_diagnostic().to_json()));
+}
+
+#[test]
+fn lex_error() {
+    let err = lex("(fun #)").unwrap_err();
+    insta::assert_display_snapshot!(format!("{:#}", err.to_diagnostic().to_json()));
+}
+
+#[test]
+fn parse_error_unexpected_token() {
+    let err = parse(lex("(fun nonrec))").unwrap()).unwrap_err();
+    insta::assert_display_snapshot!(format!("{:#}", err.to_diagnostic().to_json()));
+}
+
+#[test]
+fn parse_error_unexpected_eof() {
+    let err = parse(lex("(fun").unwrap()).unwrap_err();
+    insta::assert_display_snapshot!(format!("{:#}", err.to_diagnostic().to_json()));
+}
+
+#[test]
+fn lex_error_unterminated_string() {
+    let err = lex(r#"(fun "abc)"#).unwrap_err();
+    insta::assert_display_snapshot!(format!("{:#}", err.to_diagnostic().to_json()));
+}
+
+#[test]
+fn recovered_syntax_errors() {
+    let (_, errors) = parse_src_with_recovery("(u64_add (fun) # (for U64))");
+    let diagnostics: Vec<Diagnostic> = errors.iter().map(ToDiagnostic::to_diagnostic).collect();
+    insta::assert_display_snapshot!(format!("{:#}", diagnostics_to_json(&diagnostics)));
+}
+
+#[test]
+fn strings_are_escaped() {
+    let json = JsonValue::object([("a\"b", JsonValue::string("line1\nline2\t\\ \u{1}"))]);
+    assert_eq!(r#"{"a\"b":"line1\nline2\t\\ \u0001"}"#, json.to_string());
+}
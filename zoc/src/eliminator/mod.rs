@@ -0,0 +1,422 @@
+//! Generates the dependent eliminator (i.e., the recursor,
+//! or induction principle) of an inductive type.
+//!
+//! For an `ind` `I` with index types `(T_0 ... T_{n-1})`
+//! and vcons `c_0 ... c_{k-1}`, the eliminator is a recursive `fun`
+//! with the params
+//!
+//! ```text
+//! motive: (for (T_0 ... T_{n-1} (I x_0 ... x_{n-1})) <motive_universe>)
+//! case_0: <case type of c_0>
+//! ...
+//! case_{k-1}: <case type of c_{k-1}>
+//! x_0: T_0
+//! ...
+//! x_{n-1}: T_{n-1}
+//! t: (I x_0 ... x_{n-1})
+//! ```
+//!
+//! that returns `(motive x_0 ... x_{n-1} t)`.
+//! The case type of `c_i` takes `c_i`'s params,
+//! followed by an induction hypothesis for each recursive param,
+//! and returns `(motive <c_i's index args> (c_i <params>))`.
+//! The eliminator's body matches on `t`,
+//! and passes each case its params
+//! and the results of the corresponding recursive calls.
+//!
+//! We generate the eliminator syntactically,
+//! so we never get the `return_type_arity`
+//! (or any of the de Bruijn bookkeeping) wrong.
+
+use crate::{
+    eval::Evaluator,
+    syntax_tree::{ast::prelude::minimal_ast::*, replace_debs::*},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Eliminator {
+    /// The eliminator `fun`.
+    pub term: Expr,
+    /// The type of `term` (a `for`).
+    pub type_: Expr,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EliminatorError {
+    /// Matching on a value of an erasable type
+    /// can only produce a value of an erasable type
+    /// (see `ErasabilityChecker`),
+    /// so the motive of an erasable `ind` must be erasable too.
+    NonErasableMotiveForErasableInd {
+        ind: RcHashed<Ind>,
+        motive_universe: Universe,
+    },
+    /// A vcon param mentions the `ind` other than as
+    /// `(I <index args>)` (e.g., `(for (U64) (I <index args>))`).
+    /// We cannot generate induction hypotheses for such params,
+    /// since the recursion checker only accepts recursive calls
+    /// on match case params themselves.
+    UnsupportedRecursiveParam {
+        ind: RcHashed<Ind>,
+        vcon_index: usize,
+        param_index: usize,
+    },
+}
+
+/// Returns the dependent eliminator of `ind`,
+/// whose motive returns types in `motive_universe`.
+///
+/// `ind` must be well-typed.
+/// It may have free variables,
+/// in which case the eliminator is well-typed
+/// in the same context as `ind`.
+pub fn get_eliminator(
+    ind: RcHashed<Ind>,
+    motive_universe: Universe,
+) -> Result<Eliminator, EliminatorError> {
+    if ind.hashee.universe.erasable && !motive_universe.erasable {
+        return Err(EliminatorError::NonErasableMotiveForErasableInd {
+            ind,
+            motive_universe,
+        });
+    }
+
+    // We normalize the `ind`, so that we can recognize
+    // recursive params syntactically.
+    let ind = match Evaluator::default().eval(ind.into()).into_raw() {
+        Expr::Ind(ind) => ind,
+        _ => panic!("an `ind` should evaluate to an `ind`"),
+    };
+
+    let recursive_params = get_recursive_params(&ind)?;
+    let builder = EliminatorBuilder {
+        ind,
+        recursive_params,
+    };
+    Ok(builder.build(motive_universe))
+}
+
+/// A recursive vcon param,
+/// whose type is `(I <index_args>)`.
+struct RecursiveParam {
+    param_index: usize,
+    /// These are under the vcon def's context
+    /// (i.e., the `ind` itself, followed by the
+    /// vcon params before this one).
+    index_args: Vec<Expr>,
+}
+
+/// Returns the recursive params of each vcon def.
+fn get_recursive_params(ind: &RcHashed<Ind>) -> Result<Vec<Vec<RecursiveParam>>, EliminatorError> {
+    ind.hashee
+        .vcon_defs
+        .hashee
+        .iter()
+        .enumerate()
+        .map(|(vcon_index, def)| {
+            let mut recursive_params = vec![];
+            for (param_index, param_type) in def.param_types.hashee.iter().enumerate() {
+                // In the `param_index`th param type,
+                // the `ind` itself is `param_index`.
+                let self_deb = Deb(param_index);
+                if let Some(index_args) = try_as_self_capp(param_type, self_deb) {
+                    recursive_params.push(RecursiveParam {
+                        param_index,
+                        index_args,
+                    });
+                } else if contains_deb(param_type, self_deb) {
+                    return Err(EliminatorError::UnsupportedRecursiveParam {
+                        ind: ind.clone(),
+                        vcon_index,
+                        param_index,
+                    });
+                }
+            }
+            Ok(recursive_params)
+        })
+        .collect()
+}
+
+/// If `expr` is `self_deb` applied to args that do not mention `self_deb`,
+/// this returns the args.
+fn try_as_self_capp(expr: &Expr, self_deb: Deb) -> Option<Vec<Expr>> {
+    match expr {
+        Expr::Deb(deb) if deb.hashee.deb == self_deb => Some(vec![]),
+        Expr::App(app) => match &app.hashee.callee {
+            Expr::Deb(callee) if callee.hashee.deb == self_deb => {
+                let args = &app.hashee.args.hashee;
+                if args.iter().any(|arg| contains_deb(arg, self_deb)) {
+                    return None;
+                }
+                Some(args.clone())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// In the comments below, `G` is the context of the `ind`,
+/// and `N` is the number of eliminator params
+/// (i.e., `1 + vcon_count + index_count + 1`).
+struct EliminatorBuilder {
+    ind: RcHashed<Ind>,
+    recursive_params: Vec<Vec<RecursiveParam>>,
+}
+
+impl EliminatorBuilder {
+    fn build(&self, motive_universe: Universe) -> Eliminator {
+        let vcon_count = self.vcon_count();
+        let index_count = self.index_count();
+        let param_count = self.param_count();
+
+        let mut param_types = vec![self.motive_type(motive_universe)];
+        param_types.extend((0..vcon_count).map(|vcon_index| self.case_type(vcon_index)));
+        param_types.extend(
+            self.ind
+                .hashee
+                .index_types
+                .hashee
+                .iter()
+                .enumerate()
+                .map(|(i, index_type)| upshift(index_type.clone(), 1 + vcon_count, i)),
+        );
+        param_types.push(self.ind_capp_of_descending_debs(1 + vcon_count + index_count, 0));
+
+        // Under `G, <params>`:
+        // `motive` is `N - 1`, `x_i` is `index_count - i`, and `t` is `0`.
+        let return_type = capp(
+            deb(param_count - 1),
+            (0..index_count)
+                .map(|i| deb(index_count - i))
+                .chain([deb(0)])
+                .collect(),
+        );
+
+        let term = Fun {
+            decreasing_index: Some(param_count - 1),
+            param_types: rc_hashed(param_types.clone()),
+            return_type: return_type.clone(),
+            return_val: self.body(),
+            aux_data: (),
+        }
+        .into();
+        let type_ = For {
+            param_types: rc_hashed(param_types),
+            return_type,
+            aux_data: (),
+        }
+        .into();
+        Eliminator { term, type_ }
+    }
+
+    /// Returns the motive type, under `G`.
+    fn motive_type(&self, motive_universe: Universe) -> Expr {
+        let index_count = self.index_count();
+        let mut param_types = self.ind.hashee.index_types.hashee.to_vec();
+        param_types.push(self.ind_capp_of_descending_debs(index_count, 0));
+        cfor(
+            param_types,
+            UniverseNode {
+                universe: motive_universe,
+                aux_data: (),
+            }
+            .into(),
+        )
+    }
+
+    /// Returns the type of the `vcon_index`th case,
+    /// under `G, motive, case_0, ..., case_{vcon_index - 1}`.
+    fn case_type(&self, vcon_index: usize) -> Expr {
+        let def = &self.ind.hashee.vcon_defs.hashee[vcon_index];
+        let outer_count = 1 + vcon_index;
+        let vcon_param_count = def.param_types.hashee.len();
+        let recursive_params = &self.recursive_params[vcon_index];
+        let ih_count = recursive_params.len();
+
+        let mut param_types: Vec<Expr> = def
+            .param_types
+            .hashee
+            .iter()
+            .enumerate()
+            .map(|(i, param_type)| self.instantiate_self(param_type.clone(), i, outer_count))
+            .collect();
+
+        // The `q`th induction hypothesis type is under
+        // `G, motive, <previous cases>, <vcon params>, <previous IHs>`.
+        for (q, param) in recursive_params.iter().enumerate() {
+            let motive = deb(vcon_index + vcon_param_count + q);
+            let args = param
+                .index_args
+                .iter()
+                .map(|arg| {
+                    let arg = self.instantiate_self(arg.clone(), param.param_index, outer_count);
+                    upshift(arg, vcon_param_count - param.param_index + q, 0)
+                })
+                .chain([deb(vcon_param_count - 1 - param.param_index + q)])
+                .collect();
+            param_types.push(capp(motive, args));
+        }
+
+        // The return type is under
+        // `G, motive, <previous cases>, <vcon params>, <IHs>`.
+        let motive = deb(vcon_index + vcon_param_count + ih_count);
+        let vcon = Vcon {
+            ind: self.upshifted_ind(outer_count + vcon_param_count + ih_count),
+            vcon_index,
+            aux_data: (),
+        };
+        let vcon_capp = capp(
+            vcon.into(),
+            (0..vcon_param_count)
+                .map(|i| deb(ih_count + vcon_param_count - 1 - i))
+                .collect(),
+        );
+        let args = def
+            .index_args
+            .hashee
+            .iter()
+            .map(|arg| {
+                let arg = self.instantiate_self(arg.clone(), vcon_param_count, outer_count);
+                upshift(arg, ih_count, 0)
+            })
+            .chain([vcon_capp])
+            .collect();
+        cfor(param_types, capp(motive, args))
+    }
+
+    /// Returns the eliminator's body, under `G, <params>, <eliminator>`.
+    fn body(&self) -> Expr {
+        let index_count = self.index_count();
+        let param_count = self.param_count();
+
+        // The return type is under
+        // `G, <params>, <eliminator>, <matchee indices>, <matchee>`.
+        let return_type = capp(
+            deb(param_count + index_count + 1),
+            (0..index_count)
+                .map(|i| deb(index_count - i))
+                .chain([deb(0)])
+                .collect(),
+        );
+
+        let cases: Vec<MatchCase> = (0..self.vcon_count())
+            .map(|vcon_index| self.match_case(vcon_index))
+            .collect();
+
+        Match {
+            // `t`
+            matchee: deb(1),
+            return_type_arity: index_count + 1,
+            return_type,
+            cases: rc_hashed(cases),
+            aux_data: (),
+        }
+        .into()
+    }
+
+    /// Returns the `vcon_index`th match case,
+    /// whose return value is under
+    /// `G, <params>, <eliminator>, <vcon params>`.
+    fn match_case(&self, vcon_index: usize) -> MatchCase {
+        let def = &self.ind.hashee.vcon_defs.hashee[vcon_index];
+        let vcon_param_count = def.param_types.hashee.len();
+        let vcon_count = self.vcon_count();
+        let param_count = self.param_count();
+
+        let eliminator = deb(vcon_param_count);
+        let motive = deb(param_count + vcon_param_count);
+        let case = |i: usize| deb(param_count - 1 - i + vcon_param_count);
+        let vcon_param = |i: usize| deb(vcon_param_count - 1 - i);
+
+        let ihs = self.recursive_params[vcon_index].iter().map(|param| {
+            let args = [motive.clone()]
+                .into_iter()
+                .chain((0..vcon_count).map(case))
+                .chain(param.index_args.iter().map(|arg| {
+                    let arg =
+                        self.instantiate_self(arg.clone(), param.param_index, param_count + 1);
+                    upshift(arg, vcon_param_count - param.param_index, 0)
+                }))
+                .chain([vcon_param(param.param_index)])
+                .collect();
+            capp(eliminator.clone(), args)
+        });
+        let args = (0..vcon_param_count).map(vcon_param).chain(ihs).collect();
+
+        MatchCase {
+            arity: vcon_param_count,
+            return_val: Some(capp(case(vcon_index), args)),
+            aux_data: (),
+        }
+    }
+
+    /// `expr` is under a vcon def's context
+    /// (i.e., `G, <the ind itself>, <local_count vcon params>`).
+    /// This returns `expr` under `G, <outer_count binders>, <the same vcon params>`,
+    /// with the `ind` substituted for itself.
+    fn instantiate_self(&self, expr: Expr, local_count: usize, outer_count: usize) -> Expr {
+        let ind: Expr = self.upshifted_ind(outer_count).into();
+        upshift(expr, outer_count, local_count + 1)
+            .replace_debs(&DebDownshiftSubstituter { new_exprs: &[ind] }, local_count)
+    }
+
+    /// Returns `(I <index_count debs, descending from start + index_count - 1 to start>)`,
+    /// under `G, <outer_count binders>`.
+    fn ind_capp_of_descending_debs(&self, outer_count: usize, start: usize) -> Expr {
+        let index_count = self.index_count();
+        capp(
+            self.upshifted_ind(outer_count).into(),
+            (0..index_count).rev().map(|i| deb(start + i)).collect(),
+        )
+    }
+
+    fn upshifted_ind(&self, amount: usize) -> RcHashed<Ind> {
+        self.ind.clone().replace_debs(&DebUpshifter(amount), 0)
+    }
+
+    fn vcon_count(&self) -> usize {
+        self.ind.hashee.vcon_defs.hashee.len()
+    }
+
+    fn index_count(&self) -> usize {
+        self.ind.hashee.index_types.hashee.len()
+    }
+
+    fn param_count(&self) -> usize {
+        1 + self.vcon_count() + self.index_count() + 1
+    }
+}
+
+fn deb(deb: usize) -> Expr {
+    DebNode {
+        deb: Deb(deb),
+        aux_data: (),
+    }
+    .into()
+}
+
+fn capp(callee: Expr, args: Vec<Expr>) -> Expr {
+    App {
+        callee,
+        args: rc_hashed(args),
+        aux_data: (),
+    }
+    .collapse_if_nullary()
+}
+
+fn cfor(param_types: Vec<Expr>, return_type: Expr) -> Expr {
+    For {
+        param_types: rc_hashed(param_types),
+        return_type,
+        aux_data: (),
+    }
+    .collapse_if_nullary()
+}
+
+fn upshift(expr: Expr, amount: usize, cutoff: usize) -> Expr {
+    expr.replace_debs(&DebUpshifter(amount), cutoff)
+}
@@ -0,0 +1,188 @@
+use super::*;
+
+use crate::{hash::GetDigest, pretty_print::*, test_utils::*};
+
+use pretty_assertions::assert_eq;
+
+const NAT_DEF: (&str, &str) = (
+    "<NAT>",
+    r#"(ind Set0 "Nat" () (
+    (() ())
+    ((0) ())
+))"#,
+);
+const ZERO_DEF: (&str, &str) = ("<ZERO>", "(vcon <NAT> 0)");
+const SUCC_DEF: (&str, &str) = ("<SUCC>", "(vcon <NAT> 1)");
+
+const SET0: Universe = Universe {
+    level: UniverseLevel(0),
+    erasable: false,
+};
+const PROP0: Universe = Universe {
+    level: UniverseLevel(0),
+    erasable: true,
+};
+
+fn parse_ind_or_panic(src: &str) -> RcHashed<Ind> {
+    match parse_minimal_ast_or_panic(src) {
+        Expr::Ind(ind) => ind,
+        other => panic!("expected an ind, but got {}", PrettyPrint(&other)),
+    }
+}
+
+/// Checks that the eliminator is well-typed,
+/// that its type is `eliminator.type_`,
+/// and that it passes the erasability checker.
+fn check_eliminator_or_panic(eliminator: &Eliminator) {
+    let term_src = PrettyPrint(&eliminator.term).to_string();
+    let type_src = PrettyPrint(&eliminator.type_).to_string();
+
+    let actual_type = get_type_under_empty_tcon_or_panic(&term_src).into_raw();
+    let expected_type = eval_or_panic(&type_src).into_raw();
+    if actual_type.digest() != expected_type.digest() {
+        assert_eq!(
+            PrettyPrint(&expected_type).to_string(),
+            PrettyPrint(&actual_type).to_string()
+        );
+    }
+
+    check_erasability_under_empty_tcon_or_panic(&term_src);
+}
+
+fn get_checked_eliminator_or_panic(ind_src: &str, motive_universe: Universe) -> Eliminator {
+    let eliminator = get_eliminator(parse_ind_or_panic(ind_src), motive_universe).unwrap();
+    check_eliminator_or_panic(&eliminator);
+    eliminator
+}
+
+#[test]
+fn nat() {
+    let ind_src = substitute_with_compounding([NAT_DEF], "<NAT>");
+    let eliminator = get_checked_eliminator_or_panic(&ind_src, SET0);
+    insta::assert_display_snapshot!(PrettyPrint(&eliminator.term));
+}
+
+#[test]
+fn nat_eliminator_computes_add() {
+    let ind_src = substitute_with_compounding([NAT_DEF], "<NAT>");
+    let eliminator = get_checked_eliminator_or_panic(&ind_src, SET0);
+    let elim_src = PrettyPrint(&eliminator.term).to_string();
+
+    let defs = [
+        NAT_DEF,
+        ZERO_DEF,
+        SUCC_DEF,
+        ("<ELIM>", elim_src.as_str()),
+        ("<MOTIVE>", "(fun nonrec (<NAT>) Set0 <NAT>)"),
+        ("<SUCC_CASE>", "(fun nonrec (<NAT> <NAT>) <NAT> (<SUCC> 1))"),
+        ("<2>", "(<SUCC> (<SUCC> <ZERO>))"),
+        ("<3>", "(<SUCC> <2>)"),
+    ];
+    let add_src = substitute_with_compounding(defs, "(<ELIM> <MOTIVE> <3> <SUCC_CASE> <2>)");
+    let five_src =
+        substitute_with_compounding(defs, "(<SUCC> (<SUCC> (<SUCC> (<SUCC> (<SUCC> <ZERO>)))))");
+
+    let actual = typecheck_and_eval_under_empty_tcon_or_panic(&add_src).into_raw();
+    let expected = parse_minimal_ast_or_panic(&five_src);
+    assert_eq!(
+        PrettyPrint(&expected).to_string(),
+        PrettyPrint(&actual).to_string()
+    );
+}
+
+#[test]
+fn bool() {
+    let ind_src = r#"(ind Set0 "Bool" () (
+    (() ())
+    (() ())
+))"#;
+    let eliminator = get_checked_eliminator_or_panic(ind_src, SET0);
+    insta::assert_display_snapshot!(PrettyPrint(&eliminator.type_));
+}
+
+#[test]
+fn list_of_nat() {
+    let ind_src = substitute_with_compounding(
+        [NAT_DEF],
+        r#"(ind Set0 "NatList" () (
+    (() ())
+    ((<NAT> 1) ())
+))"#,
+    );
+    get_checked_eliminator_or_panic(&ind_src, SET0);
+}
+
+#[test]
+fn indexed_family() {
+    // `Even n` holds when `n` is even,
+    // with the vcons `even_zero: Even zero` and
+    // `even_succ_succ: (n: Nat, Even n) -> Even (succ (succ n))`.
+    let ind_src = substitute_with_compounding(
+        [NAT_DEF, ZERO_DEF, SUCC_DEF],
+        r#"(ind Set0 "Even" (<NAT>) (
+    (() (<ZERO>))
+    ((<NAT> (1 0)) ((<SUCC> (<SUCC> 1))))
+))"#,
+    );
+    let eliminator = get_checked_eliminator_or_panic(&ind_src, SET0);
+    insta::assert_display_snapshot!(PrettyPrint(&eliminator.type_));
+}
+
+#[test]
+fn multiple_indices_and_recursive_params() {
+    // `Le m n` holds when `m <= n`.
+    let ind_src = substitute_with_compounding(
+        [NAT_DEF, ZERO_DEF, SUCC_DEF],
+        r#"(ind Set0 "Le" (<NAT> <NAT>) (
+    ((<NAT>) (<ZERO> 0))
+    ((<NAT> <NAT> (2 1 0)) ((<SUCC> 2) (<SUCC> 1)))
+))"#,
+    );
+    get_checked_eliminator_or_panic(&ind_src, SET0);
+}
+
+#[test]
+fn prop_ind_with_prop_motive() {
+    let ind_src = substitute_with_compounding(
+        [NAT_DEF, ZERO_DEF, SUCC_DEF],
+        r#"(ind Prop0 "Even" (<NAT>) (
+    (() (<ZERO>))
+    ((<NAT> (1 0)) ((<SUCC> (<SUCC> 1))))
+))"#,
+    );
+    get_checked_eliminator_or_panic(&ind_src, PROP0);
+}
+
+#[test]
+fn set_ind_with_prop_motive() {
+    let ind_src = substitute_with_compounding([NAT_DEF], "<NAT>");
+    get_checked_eliminator_or_panic(&ind_src, PROP0);
+}
+
+#[test]
+fn prop_ind_with_set_motive() {
+    let ind = parse_ind_or_panic(r#"(ind Prop0 "True" () ((() ())))"#);
+    let err = get_eliminator(ind.clone(), SET0).unwrap_err();
+    assert_eq!(
+        EliminatorError::NonErasableMotiveForErasableInd {
+            ind,
+            motive_universe: SET0,
+        },
+        err
+    );
+}
+
+#[test]
+fn unsupported_recursive_param() {
+    // The second vcon's param has the type `(for (U64) Tree)`.
+    let ind = parse_ind_or_panic(r#"(ind Set0 "Tree" () ((() ()) (((for (U64) 1)) ())))"#);
+    let err = get_eliminator(ind.clone(), SET0).unwrap_err();
+    assert_eq!(
+        EliminatorError::UnsupportedRecursiveParam {
+            ind,
+            vcon_index: 1,
+            param_index: 0,
+        },
+        err
+    );
+}
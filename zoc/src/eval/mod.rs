@@ -1,30 +1,60 @@
 use crate::syntax_tree::{ast::prelude::minimal_ast::*, replace_debs::*};
 
+use std::hash::Hash;
+
 #[cfg(test)]
 mod tests;
 
 mod normalized;
 pub use normalized::*;
 
+mod shared_cache;
+#[cfg(feature = "sync")]
+pub use shared_cache::*;
+
+mod trace;
+pub use trace::*;
+
 #[derive(Clone, Debug, Default)]
 pub struct Evaluator {
     pub eval_expr_cache: NoHashHashMap<Digest, NormalForm>,
     pub eval_exprs_cache: NoHashHashMap<Digest, Normalized<RcHashedVec<Expr>>>,
+    #[cfg(feature = "sync")]
+    pub shared_cache: Option<SharedEvalCache>,
+    /// Every normal form the evaluator returns is interned with this.
+    pub interner: Interner,
 }
 
 impl Evaluator {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn with_interner(interner: Interner) -> Self {
+        Self {
+            interner,
+            ..Self::default()
+        }
+    }
 }
 
 impl Evaluator {
     pub fn eval(&mut self, expr: Expr) -> NormalForm {
-        if let Some(result) = self.eval_expr_cache.get(&expr.digest()) {
-            result.clone()
-        } else {
-            self.eval_unseen_expr(expr)
+        if let Some(result) = self.eval_expr_cache.get(expr.digest()) {
+            return result.clone();
         }
+
+        let digest = expr.digest().clone();
+        if let Some(result) = self.get_from_shared_cache(&digest) {
+            // Other threads intern with their own interners.
+            let result = Normalized(self.interner.intern_expr(result.into_raw()));
+            self.eval_expr_cache.insert(digest, result.clone());
+            return result;
+        }
+
+        let normalized = self.eval_unseen_expr(expr);
+        self.publish_to_shared_cache(digest, &normalized);
+        normalized
     }
 
     pub fn eval_ind(&mut self, ind: RcHashed<Ind>) -> Normalized<RcHashed<Ind>> {
@@ -66,7 +96,12 @@ impl Evaluator {
             Expr::App(e) => self.eval_unseen_app(e),
             Expr::For(e) => self.eval_unseen_for(e),
 
-            Expr::Deb(_) | Expr::Universe(_) => Normalized(expr),
+            Expr::Deb(_)
+            | Expr::Universe(_)
+            | Expr::Lit(_)
+            | Expr::Prim(_)
+            | Expr::Meta(_)
+            | Expr::Hole(_) => Normalized(self.interner.intern_expr(expr)),
         }
     }
 
@@ -75,14 +110,15 @@ impl Evaluator {
         let ind = &ind.hashee;
         let normalized = Ind {
             name: ind.name.clone(),
+            tag: ind.tag.clone(),
             universe: ind.universe,
             index_types: self.eval_expressions(ind.index_types.clone()).into_raw(),
             vcon_defs: self
                 .eval_vcon_defs_without_caching(ind.vcon_defs.clone())
                 .into_raw(),
             aux_data: (),
-        }
-        .convert_to_expr_and_wrap_in_normalized();
+        };
+        let normalized = self.intern_normal_form(normalized);
 
         self.eval_expr_cache.insert(ind_digest, normalized.clone());
         self.cache_self_loop(normalized.clone());
@@ -96,8 +132,8 @@ impl Evaluator {
             ind: self.eval_ind(vcon.ind.clone()).into_raw(),
             vcon_index: vcon.vcon_index,
             aux_data: (),
-        }
-        .convert_to_expr_and_wrap_in_normalized();
+        };
+        let normalized = self.intern_normal_form(normalized);
 
         self.eval_expr_cache.insert(vcon_digest, normalized.clone());
         self.cache_self_loop(normalized.clone());
@@ -114,14 +150,19 @@ impl Evaluator {
                 // The `match` expression does not have enough cases.
                 // Therefore, it is a "stuck" term.
                 // Since we don't emit errors, we just return the term as-is.
-                return m.convert_to_expr_and_wrap_in_normalized();
+                return Normalized(self.interner.intern_expr(m.into()));
             }
 
             let case = &match_.cases.hashee[vcon_index];
 
-            let unsubstituted = case.return_val.clone();
-            let substituted = substitute_and_downshift_debs(unsubstituted, args);
-            return self.eval(substituted);
+            // Omitted cases are impossible in well-typed terms
+            // (under consistent contexts).
+            // If we reach one anyway, the match is stuck,
+            // so we fall through and normalize its parts.
+            if let Some(unsubstituted) = case.return_val.clone() {
+                let substituted = substitute_and_downshift_debs(unsubstituted, args);
+                return self.eval(substituted);
+            }
         }
 
         let match_digest = m.digest.clone();
@@ -133,8 +174,8 @@ impl Evaluator {
                 .eval_match_cases_without_caching(match_.cases.clone())
                 .into_raw(),
             aux_data: (),
-        }
-        .convert_to_expr_and_wrap_in_normalized();
+        };
+        let normalized = self.intern_normal_form(normalized);
 
         self.eval_expr_cache
             .insert(match_digest, normalized.clone());
@@ -151,8 +192,8 @@ impl Evaluator {
             return_type: self.eval(fun.return_type.clone()).into_raw(),
             return_val: self.eval(fun.return_val.clone()).into_raw(),
             aux_data: (),
-        }
-        .convert_to_expr_and_wrap_in_normalized();
+        };
+        let normalized = self.intern_normal_form(normalized);
 
         self.eval_expr_cache.insert(fun_digest, normalized.clone());
         self.cache_self_loop(normalized.clone());
@@ -177,13 +218,19 @@ impl Evaluator {
             }
         }
 
+        if let Expr::Prim(callee) = &normalized_callee {
+            if let Some(lit) = try_apply_prim(callee.hashee.prim, &normalized_args.hashee) {
+                return self.intern_normal_form(LitNode { lit, aux_data: () });
+            }
+        }
+
         let app_digest = app.digest.clone();
         let normalized = App {
             callee: normalized_callee,
             args: normalized_args,
             aux_data: (),
-        }
-        .convert_to_expr_and_wrap_in_normalized();
+        };
+        let normalized = self.intern_normal_form(normalized);
 
         self.eval_expr_cache.insert(app_digest, normalized.clone());
         self.cache_self_loop(normalized.clone());
@@ -197,8 +244,8 @@ impl Evaluator {
             param_types: self.eval_expressions(for_.param_types.clone()).into_raw(),
             return_type: self.eval(for_.return_type.clone()).into_raw(),
             aux_data: (),
-        }
-        .convert_to_expr_and_wrap_in_normalized();
+        };
+        let normalized = self.intern_normal_form(normalized);
 
         self.eval_expr_cache.insert(for_digest, normalized.clone());
         self.cache_self_loop(normalized.clone());
@@ -214,8 +261,8 @@ impl Evaluator {
         let normalized = exprs
             .iter()
             .map(|expr| self.eval(expr.clone()).into_raw())
-            .collect::<Vec<_>>()
-            .rc_hash_and_wrap_in_normalized();
+            .collect::<Vec<_>>();
+        let normalized = self.intern_normalized_vec(normalized);
 
         self.eval_exprs_cache
             .insert(exprs_digest, normalized.clone());
@@ -233,13 +280,13 @@ impl Evaluator {
         let normalized = defs
             .iter()
             .map(|def| self.eval_vcon_def_without_caching(def.clone()).into_raw())
-            .collect::<Vec<_>>()
-            .rc_hash_and_wrap_in_normalized();
-        normalized
+            .collect::<Vec<_>>();
+        self.intern_normalized_vec(normalized)
     }
 
     fn eval_vcon_def_without_caching(&mut self, def: VconDef) -> Normalized<VconDef> {
         Normalized(VconDef {
+            name: def.name.clone(),
             param_types: self.eval_expressions(def.param_types.clone()).into_raw(),
             index_args: self.eval_expressions(def.index_args.clone()).into_raw(),
             aux_data: (),
@@ -251,22 +298,43 @@ impl Evaluator {
         cases: RcHashedVec<MatchCase>,
     ) -> Normalized<RcHashedVec<MatchCase>> {
         let cases = &cases.hashee;
-        cases
+        let normalized = cases
             .iter()
             .map(|original| self.eval_match_case_without_caching(original).0)
-            .collect::<Vec<_>>()
-            .rc_hash_and_wrap_in_normalized()
+            .collect::<Vec<_>>();
+        self.intern_normalized_vec(normalized)
     }
 
     fn eval_match_case_without_caching(&mut self, case: &MatchCase) -> Normalized<MatchCase> {
         Normalized(MatchCase {
             arity: case.arity,
-            return_val: self.eval(case.return_val.clone()).into_raw(),
+            return_val: case
+                .return_val
+                .clone()
+                .map(|return_val| self.eval(return_val).into_raw()),
             aux_data: (),
         })
     }
 }
 
+impl Evaluator {
+    /// `normalized` must be a normal form.
+    fn intern_normal_form<T: 'static + Hash + MaybeSendSync>(&self, normalized: T) -> NormalForm
+    where
+        RcHashed<T>: Into<Expr>,
+    {
+        Normalized(self.interner.intern(normalized).into())
+    }
+
+    /// `normalized` must be a normal form.
+    fn intern_normalized_vec<T: 'static + Hash + MaybeSendSync>(
+        &self,
+        normalized: Vec<T>,
+    ) -> Normalized<RcHashedVec<T>> {
+        Normalized(self.interner.intern(normalized))
+    }
+}
+
 impl Evaluator {
     /// The normal form of every normal form `normalized` is `normalized` itself.
     /// If we record this in the cache,
@@ -308,7 +376,7 @@ fn is_vcon_or_vcon_app(expr: Expr) -> bool {
     try_as_vcon_or_vcon_app(&expr).is_some()
 }
 
-fn try_as_vcon_or_vcon_app(expr: &Expr) -> Option<(RcHashed<Vcon>, &[Expr])> {
+pub(crate) fn try_as_vcon_or_vcon_app(expr: &Expr) -> Option<(RcHashed<Vcon>, &[Expr])> {
     if let Expr::Vcon(vcon) = &expr {
         return Some((vcon.clone(), &[]));
     }
@@ -322,6 +390,46 @@ fn try_as_vcon_or_vcon_app(expr: &Expr) -> Option<(RcHashed<Vcon>, &[Expr])> {
     None
 }
 
+/// Returns `None` if the application is "stuck"
+/// (i.e., `prim` is not an operation,
+/// or some of `args` are not literals).
+fn try_apply_prim(prim: Prim, args: &[Expr]) -> Option<Lit> {
+    match (prim, args) {
+        (Prim::U64Add, [a, b]) => Some(Lit::U64(as_u64(a)?.wrapping_add(as_u64(b)?))),
+        (Prim::U64Sub, [a, b]) => Some(Lit::U64(as_u64(a)?.wrapping_sub(as_u64(b)?))),
+        (Prim::U64Mul, [a, b]) => Some(Lit::U64(as_u64(a)?.wrapping_mul(as_u64(b)?))),
+
+        (Prim::StringConcat, [a, b]) => {
+            let concatenated = format!("{}{}", as_string(a)?.0, as_string(b)?.0);
+            Some(Lit::String(Rc::new(StringValue(concatenated))))
+        }
+
+        (Prim::StringLen, [a]) => Some(Lit::U64(as_string(a)?.0.len() as u64)),
+
+        _ => None,
+    }
+}
+
+fn as_u64(expr: &Expr) -> Option<u64> {
+    match expr {
+        Expr::Lit(lit) => match &lit.hashee.lit {
+            Lit::U64(value) => Some(*value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn as_string(expr: &Expr) -> Option<&StringValue> {
+    match expr {
+        Expr::Lit(lit) => match &lit.hashee.lit {
+            Lit::String(value) => Some(value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn substitute_and_downshift_debs(expr: Expr, new_exprs: &[Expr]) -> Expr {
     expr.replace_debs(&DebDownshiftSubstituter { new_exprs }, 0)
 }
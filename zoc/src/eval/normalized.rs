@@ -1,6 +1,6 @@
 use crate::syntax_tree::{ast::prelude::minimal_ast::*, replace_debs::*};
 
-use std::{hash::Hash, ops::Deref, rc::Rc};
+use std::{hash::Hash, ops::Deref};
 
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Normalized<T>(pub(in crate::eval) T);
@@ -518,44 +518,3 @@ impl From<Normalized<UniverseNode>> for NormalForm {
         Normalized(e.0.into())
     }
 }
-
-pub(in crate::eval) use unchecked::*;
-mod unchecked {
-    use super::*;
-
-    pub trait WrapInNormalized: Sized {
-        fn wrap_in_normalized(self) -> Normalized<Self>;
-    }
-
-    impl<T> WrapInNormalized for T {
-        fn wrap_in_normalized(self) -> Normalized<T> {
-            Normalized(self)
-        }
-    }
-
-    pub trait ConvertToExprAndWrapInNormalized {
-        fn convert_to_expr_and_wrap_in_normalized(self) -> NormalForm;
-    }
-
-    impl<T> ConvertToExprAndWrapInNormalized for T
-    where
-        T: Into<Expr>,
-    {
-        fn convert_to_expr_and_wrap_in_normalized(self) -> NormalForm {
-            Normalized(self.into())
-        }
-    }
-
-    pub trait RcHashAndWrapInNormalized: Sized {
-        fn rc_hash_and_wrap_in_normalized(self) -> Normalized<RcHashed<Self>>;
-    }
-
-    impl<T> RcHashAndWrapInNormalized for T
-    where
-        T: Hash,
-    {
-        fn rc_hash_and_wrap_in_normalized(self) -> Normalized<RcHashed<Self>> {
-            Normalized(rc_hashed(self))
-        }
-    }
-}
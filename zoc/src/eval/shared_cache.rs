@@ -0,0 +1,73 @@
+use super::*;
+
+#[cfg(feature = "sync")]
+use std::sync::{Arc, RwLock};
+
+/// An evaluation cache that can be shared between
+/// the evaluators of multiple threads.
+///
+/// Each `Evaluator` still has its own local cache.
+/// The shared cache is consulted on a local cache miss,
+/// and every newly evaluated expression is published to it.
+#[cfg(feature = "sync")]
+#[derive(Clone, Debug, Default)]
+pub struct SharedEvalCache(Arc<RwLock<NoHashHashMap<Digest, NormalForm>>>);
+
+#[cfg(feature = "sync")]
+impl SharedEvalCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get(&self, digest: &Digest) -> Option<NormalForm> {
+        self.0
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(digest)
+            .cloned()
+    }
+
+    fn insert(&self, digest: Digest, normalized: NormalForm) {
+        self.0
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(digest, normalized);
+    }
+}
+
+#[cfg(feature = "sync")]
+impl Evaluator {
+    pub fn with_shared_cache(shared_cache: SharedEvalCache) -> Self {
+        Self {
+            shared_cache: Some(shared_cache),
+            ..Self::default()
+        }
+    }
+
+    pub(super) fn get_from_shared_cache(&self, digest: &Digest) -> Option<NormalForm> {
+        self.shared_cache.as_ref()?.get(digest)
+    }
+
+    pub(super) fn publish_to_shared_cache(&self, digest: Digest, normalized: &NormalForm) {
+        if let Some(shared_cache) = &self.shared_cache {
+            shared_cache.insert(digest, normalized.clone());
+        }
+    }
+}
+
+#[cfg(not(feature = "sync"))]
+impl Evaluator {
+    pub(super) fn get_from_shared_cache(&self, _: &Digest) -> Option<NormalForm> {
+        None
+    }
+
+    pub(super) fn publish_to_shared_cache(&self, _: Digest, _: &NormalForm) {}
+}
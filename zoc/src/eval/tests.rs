@@ -613,3 +613,206 @@ fn substitution_upshifts_new_expr_debs() {
 
     assert_exprs_eq!(expected, actual);
 }
+
+#[test]
+fn u64_arithmetic() {
+    let src = r#"(u64_mul (u64_add 2u64 3u64) (u64_sub 10u64 3u64))"#;
+    let expected_src = r#"35u64"#;
+
+    let actual = eval_or_panic(src).into_raw();
+    let expected = parse_minimal_ast_or_panic(expected_src);
+
+    assert_exprs_eq!(expected, actual);
+}
+
+#[test]
+fn u64_arithmetic_wraps() {
+    let src = r#"(u64_sub 0u64 1u64)"#;
+    let expected_src = r#"18446744073709551615u64"#;
+
+    let actual = eval_or_panic(src).into_raw();
+    let expected = parse_minimal_ast_or_panic(expected_src);
+
+    assert_exprs_eq!(expected, actual);
+}
+
+#[test]
+fn string_concat_and_len() {
+    let src = r#"(string_len (string_concat "hello " "world"))"#;
+    let expected_src = r#"11u64"#;
+
+    let actual = eval_or_panic(src).into_raw();
+    let expected = parse_minimal_ast_or_panic(expected_src);
+
+    assert_exprs_eq!(expected, actual);
+}
+
+#[test]
+fn prim_app_with_non_literal_arg_is_stuck() {
+    let src = r#"(fun nonrec (U64) U64 (u64_add 1 1u64))"#;
+
+    let actual = eval_or_panic(src).into_raw();
+    let expected = parse_minimal_ast_or_panic(src);
+
+    assert_exprs_eq!(expected, actual);
+}
+
+fn get_add_two_three_src() -> String {
+    let nat_def = (
+        "<NAT>",
+        r#"(ind Set0 "Nat" () (
+(() ())
+((0) ())
+))"#,
+    );
+    substitute_with_compounding(
+        [
+            nat_def,
+            ("<ZERO>", "(vcon <NAT> 0)"),
+            ("<SUCC>", "(vcon <NAT> 1)"),
+            (
+                "<ADD>",
+                "(fun 0 (<NAT> <NAT>) <NAT>
+    (match 2 1 <NAT> (
+        (0 1)
+        (1 (1 0 (<SUCC> 2)))
+    ))
+)",
+            ),
+            ("<2>", "(<SUCC> (<SUCC> <ZERO>))"),
+            ("<3>", "(<SUCC> <2>)"),
+        ],
+        r#"(<ADD> <2> <3>)"#,
+    )
+}
+
+fn get_trace_step_kinds_and_paths(trace: &Trace) -> Vec<(ReductionKind, Vec<usize>)> {
+    trace
+        .steps
+        .iter()
+        .map(|step| (step.kind, step.path.iter().map(|edge| edge.0).collect()))
+        .collect()
+}
+
+#[test]
+fn trace_reaches_eval_normal_form() {
+    let expr = parse_minimal_ast_or_panic(&get_add_two_three_src());
+    let expected = eval_or_panic(&get_add_two_three_src()).into_raw();
+
+    for strategy in [
+        ReductionStrategy::LeftmostOutermost,
+        ReductionStrategy::CallByValue,
+    ] {
+        let trace = Tracer::new(strategy).trace(expr.clone());
+        assert!(trace.reached_normal_form);
+        assert_exprs_eq!(expected, trace.final_expr().clone());
+    }
+}
+
+#[test]
+fn trace_records_unfold_and_iota_steps() {
+    let expr = parse_minimal_ast_or_panic(&get_add_two_three_src());
+    let trace = Tracer::default().trace(expr);
+
+    use ReductionKind::*;
+    assert_eq!(
+        vec![
+            (Unfold, vec![]),
+            (Iota, vec![]),
+            (Unfold, vec![]),
+            (Iota, vec![]),
+            (Unfold, vec![]),
+            (Iota, vec![]),
+        ],
+        get_trace_step_kinds_and_paths(&trace)
+    );
+}
+
+#[test]
+fn leftmost_outermost_does_not_reduce_unused_arg() {
+    let src = r#"((fun nonrec (U64) U64 3u64) (u64_add 1u64 2u64))"#;
+    let expr = parse_minimal_ast_or_panic(src);
+    let trace = Tracer::new(ReductionStrategy::LeftmostOutermost).trace(expr);
+
+    assert_eq!(
+        vec![(ReductionKind::Beta, vec![])],
+        get_trace_step_kinds_and_paths(&trace)
+    );
+}
+
+#[test]
+fn call_by_value_reduces_args_first() {
+    let src = r#"((fun nonrec (U64) U64 3u64) (u64_add 1u64 2u64))"#;
+    let expr = parse_minimal_ast_or_panic(src);
+    let trace = Tracer::new(ReductionStrategy::CallByValue).trace(expr);
+
+    assert_eq!(
+        vec![
+            (ReductionKind::Prim, vec![1, 0]),
+            (ReductionKind::Beta, vec![])
+        ],
+        get_trace_step_kinds_and_paths(&trace)
+    );
+}
+
+#[test]
+fn trace_stops_at_max_steps() {
+    let expr = parse_minimal_ast_or_panic(&get_add_two_three_src());
+    let tracer = Tracer {
+        max_steps: 2,
+        ..Tracer::default()
+    };
+    let trace = tracer.trace(expr);
+
+    assert_eq!(2, trace.steps.len());
+    assert!(!trace.reached_normal_form);
+}
+
+#[test]
+fn eval_with_trace_returns_eval_normal_form() {
+    let src = r#"(u64_mul (u64_add 1u64 2u64) 4u64)"#;
+    let expr = parse_minimal_ast_or_panic(src);
+    let (normalized, trace) = Evaluator::new().eval_with_trace(expr, Tracer::default());
+
+    let normalized = normalized.into_raw();
+    assert_exprs_eq!(normalized, trace.final_expr().clone());
+    assert_eq!(2, trace.steps.len());
+}
+
+#[test]
+fn weak_head_does_not_reduce_under_fun() {
+    let src = r#"(fun nonrec (U64) U64 (u64_add 1u64 2u64))"#;
+    let expr = parse_minimal_ast_or_panic(src);
+    let trace = Tracer::new(ReductionStrategy::WeakHead).trace(expr.clone());
+
+    assert!(trace.reached_normal_form);
+    assert!(trace.steps.is_empty());
+    assert_exprs_eq!(expr, trace.final_expr().clone());
+}
+
+#[test]
+fn weak_head_reduces_blocking_prim_arg() {
+    let src = r#"(u64_mul (u64_add 1u64 2u64) 4u64)"#;
+    let expr = parse_minimal_ast_or_panic(src);
+    let trace = Tracer::new(ReductionStrategy::WeakHead).trace(expr);
+
+    assert_eq!(
+        vec![
+            (ReductionKind::Prim, vec![1, 0]),
+            (ReductionKind::Prim, vec![])
+        ],
+        get_trace_step_kinds_and_paths(&trace)
+    );
+}
+
+#[test]
+fn weak_head_reaches_vcon_app_head() {
+    let expr = parse_minimal_ast_or_panic(&get_add_two_three_src());
+    let expected = eval_or_panic(&get_add_two_three_src()).into_raw();
+    let trace = Tracer::new(ReductionStrategy::WeakHead).trace(expr);
+
+    // `add` is tail recursive, so its result is already normal
+    // once it is in weak head normal form.
+    assert!(trace.reached_normal_form);
+    assert_exprs_eq!(expected, trace.final_expr().clone());
+}
@@ -0,0 +1,555 @@
+use super::*;
+
+use crate::syntax_tree::ast::node_path::{self, NodeEdge, NodePath};
+
+pub const DEFAULT_MAX_TRACE_STEPS: usize = 10_000;
+
+/// Which redex the tracer contracts next.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum ReductionStrategy {
+    /// Contract the leftmost redex that is not inside another redex
+    /// (i.e., normal order).
+    #[default]
+    LeftmostOutermost,
+    /// Contract the leftmost redex that does not contain another redex
+    /// (i.e., applicative order).
+    /// Thus, the callee and arguments of an application
+    /// (and the matchee of a match)
+    /// are fully normalized before the application (or match) is reduced.
+    CallByValue,
+    /// Contract only the redex in head position,
+    /// stopping once the term is headed by a binder,
+    /// a vcon, or a stuck variable.
+    /// Unlike the other strategies, this never reduces
+    /// under a `fun` or `for`, or inside arguments
+    /// (except for the arguments a stuck head is waiting on).
+    /// Thus, tracing to completion yields
+    /// the weak head normal form, not the normal form.
+    WeakHead,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReductionKind {
+    /// Applying a non-recursive function.
+    Beta,
+    /// Matching on a vcon (or vcon application).
+    Iota,
+    /// Applying a recursive function
+    /// whose decreasing argument is a vcon (or vcon application).
+    Unfold,
+    /// Applying a primitive operation to literals.
+    Prim,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep {
+    pub kind: ReductionKind,
+    /// The location of the contracted redex
+    /// in the term _before_ this step.
+    pub path: Vec<NodeEdge>,
+    /// The whole term _after_ this step.
+    pub result: Expr,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace {
+    pub initial: Expr,
+    pub steps: Vec<TraceStep>,
+    /// This is false if the tracer gave up
+    /// because it reached `Tracer::max_steps`.
+    ///
+    /// For `ReductionStrategy::WeakHead`,
+    /// this refers to the weak head normal form.
+    pub reached_normal_form: bool,
+}
+
+impl Trace {
+    /// Returns the last term in the trace.
+    /// If `reached_normal_form` is true, this is the normal form.
+    pub fn final_expr(&self) -> &Expr {
+        self.steps
+            .last()
+            .map(|step| &step.result)
+            .unwrap_or(&self.initial)
+    }
+}
+
+/// Reduces expressions one step at a time,
+/// recording every step.
+///
+/// The tracer is much slower than the `Evaluator`,
+/// since it does no caching and rebuilds the whole term after every step.
+/// It is intended for debugging surprising normal forms,
+/// not for typechecking.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tracer {
+    pub strategy: ReductionStrategy,
+    pub max_steps: usize,
+}
+
+impl Default for Tracer {
+    fn default() -> Self {
+        Self {
+            strategy: ReductionStrategy::default(),
+            max_steps: DEFAULT_MAX_TRACE_STEPS,
+        }
+    }
+}
+
+impl Tracer {
+    pub fn new(strategy: ReductionStrategy) -> Self {
+        Self {
+            strategy,
+            ..Self::default()
+        }
+    }
+}
+
+impl Evaluator {
+    /// Returns the normal form of `expr`,
+    /// along with a trace of how `tracer` reaches it.
+    ///
+    /// The normal form is computed by `eval`, not by the tracer,
+    /// so it is still correct if the trace is cut short.
+    pub fn eval_with_trace(&mut self, expr: Expr, tracer: Tracer) -> (NormalForm, Trace) {
+        let trace = tracer.trace(expr.clone());
+        (self.eval(expr), trace)
+    }
+}
+
+impl Tracer {
+    pub fn trace(&self, expr: Expr) -> Trace {
+        let mut steps = vec![];
+        let mut current = expr.clone();
+
+        while steps.len() < self.max_steps {
+            let Some(step) = self.step(&current) else {
+                return Trace {
+                    initial: expr,
+                    steps,
+                    reached_normal_form: true,
+                };
+            };
+            current = step.result.clone();
+            steps.push(step);
+        }
+
+        let reached_normal_form = self.step(&current).is_none();
+        Trace {
+            initial: expr,
+            steps,
+            reached_normal_form,
+        }
+    }
+
+    /// Returns `None` if `expr` is a normal form.
+    pub fn step(&self, expr: &Expr) -> Option<TraceStep> {
+        let (redex, result) = self.step_expr(expr, NodePath::Nil)?;
+        Some(TraceStep {
+            kind: redex.kind,
+            path: redex.path,
+            result,
+        })
+    }
+}
+
+struct Redex {
+    kind: ReductionKind,
+    path: Vec<NodeEdge>,
+}
+
+type Stepped<T> = Option<(Redex, T)>;
+
+impl Tracer {
+    fn step_expr(&self, expr: &Expr, path: NodePath) -> Stepped<Expr> {
+        let contract_here = || {
+            let (kind, contractum) = contract(expr)?;
+            let redex = Redex {
+                kind,
+                path: path.to_vec(),
+            };
+            Some((redex, contractum))
+        };
+
+        match self.strategy {
+            ReductionStrategy::LeftmostOutermost => {
+                contract_here().or_else(|| self.step_children(expr, path))
+            }
+            ReductionStrategy::CallByValue => self.step_children(expr, path).or_else(contract_here),
+            ReductionStrategy::WeakHead => contract_here().or_else(|| self.step_head(expr, path)),
+        }
+    }
+
+    /// Steps the subterm that blocks `expr` from being a redex, if any.
+    fn step_head(&self, expr: &Expr, path: NodePath) -> Stepped<Expr> {
+        match expr {
+            Expr::Match(e) => {
+                let match_ = &e.hashee;
+                let (redex, matchee) = self.step_expr(
+                    &match_.matchee,
+                    NodePath::Snoc(&path, node_path::MATCH_MATCHEE),
+                )?;
+                let match_ = Match {
+                    matchee,
+                    ..match_.clone()
+                };
+                Some((redex, Expr::Match(rc_hashed(match_))))
+            }
+
+            Expr::App(e) => {
+                let app = &e.hashee;
+                if let Some((redex, callee)) =
+                    self.step_expr(&app.callee, NodePath::Snoc(&path, node_path::APP_CALLEE))
+                {
+                    let app = App {
+                        callee,
+                        ..app.clone()
+                    };
+                    return Some((redex, Expr::App(rc_hashed(app))));
+                }
+
+                // A recursive function application is blocked on
+                // its decreasing argument,
+                // and a primitive application is blocked on
+                // its first non-literal argument.
+                let blocking_arg_index = match &app.callee {
+                    Expr::Fun(callee) => callee.hashee.decreasing_index?,
+                    Expr::Prim(_) => app
+                        .args
+                        .hashee
+                        .iter()
+                        .position(|arg| !matches!(arg, Expr::Lit(_)))?,
+                    _ => return None,
+                };
+                let args_path = NodePath::Snoc(&path, node_path::APP_ARGS);
+                let (redex, arg) = self.step_expr(
+                    app.args.hashee.get(blocking_arg_index)?,
+                    NodePath::Snoc(&args_path, NodeEdge(blocking_arg_index)),
+                )?;
+                let mut args = app.args.hashee.clone();
+                args[blocking_arg_index] = arg;
+                let app = App {
+                    args: rc_hashed(args),
+                    ..app.clone()
+                };
+                Some((redex, Expr::App(rc_hashed(app))))
+            }
+
+            _ => None,
+        }
+    }
+
+    fn step_children(&self, expr: &Expr, path: NodePath) -> Stepped<Expr> {
+        match expr {
+            Expr::Ind(e) => {
+                let (redex, ind) = self.step_ind(&e.hashee, path)?;
+                Some((redex, Expr::Ind(rc_hashed(ind))))
+            }
+            Expr::Vcon(e) => self.step_vcon(&e.hashee, path),
+            Expr::Match(e) => self.step_match(&e.hashee, path),
+            Expr::Fun(e) => self.step_fun(&e.hashee, path),
+            Expr::App(e) => self.step_app(&e.hashee, path),
+            Expr::For(e) => self.step_for(&e.hashee, path),
+
+            Expr::Deb(_)
+            | Expr::Universe(_)
+            | Expr::Lit(_)
+            | Expr::Prim(_)
+            | Expr::Meta(_)
+            | Expr::Hole(_) => None,
+        }
+    }
+
+    fn step_ind(&self, ind: &Ind, path: NodePath) -> Stepped<Ind> {
+        if let Some((redex, index_types)) = self.step_exprs(
+            &ind.index_types,
+            NodePath::Snoc(&path, node_path::IND_INDEX_TYPES),
+        ) {
+            return Some((
+                redex,
+                Ind {
+                    index_types,
+                    ..ind.clone()
+                },
+            ));
+        }
+
+        let (redex, vcon_defs) = step_vec(
+            &ind.vcon_defs,
+            NodePath::Snoc(&path, node_path::IND_VCON_DEFS),
+            |def, def_path| self.step_vcon_def(def, def_path),
+        )?;
+        Some((
+            redex,
+            Ind {
+                vcon_defs,
+                ..ind.clone()
+            },
+        ))
+    }
+
+    fn step_vcon_def(&self, def: &VconDef, path: NodePath) -> Stepped<VconDef> {
+        if let Some((redex, param_types)) = self.step_exprs(
+            &def.param_types,
+            NodePath::Snoc(&path, node_path::VCON_DEF_PARAM_TYPES),
+        ) {
+            return Some((
+                redex,
+                VconDef {
+                    param_types,
+                    ..def.clone()
+                },
+            ));
+        }
+
+        let (redex, index_args) = self.step_exprs(
+            &def.index_args,
+            NodePath::Snoc(&path, node_path::VCON_DEF_INDEX_ARGS),
+        )?;
+        Some((
+            redex,
+            VconDef {
+                index_args,
+                ..def.clone()
+            },
+        ))
+    }
+
+    fn step_vcon(&self, vcon: &Vcon, path: NodePath) -> Stepped<Expr> {
+        let (redex, ind) =
+            self.step_ind(&vcon.ind.hashee, NodePath::Snoc(&path, node_path::VCON_IND))?;
+        let vcon = Vcon {
+            ind: rc_hashed(ind),
+            ..vcon.clone()
+        };
+        Some((redex, Expr::Vcon(rc_hashed(vcon))))
+    }
+
+    fn step_match(&self, match_: &Match, path: NodePath) -> Stepped<Expr> {
+        let stepped = if let Some((redex, matchee)) = self.step_expr(
+            &match_.matchee,
+            NodePath::Snoc(&path, node_path::MATCH_MATCHEE),
+        ) {
+            (
+                redex,
+                Match {
+                    matchee,
+                    ..match_.clone()
+                },
+            )
+        } else if let Some((redex, return_type)) = self.step_expr(
+            &match_.return_type,
+            NodePath::Snoc(&path, node_path::MATCH_RETURN_TYPE),
+        ) {
+            (
+                redex,
+                Match {
+                    return_type,
+                    ..match_.clone()
+                },
+            )
+        } else {
+            let (redex, cases) = step_vec(
+                &match_.cases,
+                NodePath::Snoc(&path, node_path::MATCH_CASES),
+                |case, case_path| {
+                    let (redex, return_val) =
+                        self.step_expr(case.return_val.as_ref()?, case_path)?;
+                    Some((
+                        redex,
+                        MatchCase {
+                            return_val: Some(return_val),
+                            ..case.clone()
+                        },
+                    ))
+                },
+            )?;
+            (
+                redex,
+                Match {
+                    cases,
+                    ..match_.clone()
+                },
+            )
+        };
+
+        let (redex, match_) = stepped;
+        Some((redex, Expr::Match(rc_hashed(match_))))
+    }
+
+    fn step_fun(&self, fun: &Fun, path: NodePath) -> Stepped<Expr> {
+        let stepped = if let Some((redex, param_types)) = self.step_exprs(
+            &fun.param_types,
+            NodePath::Snoc(&path, node_path::FUN_PARAM_TYPES),
+        ) {
+            (
+                redex,
+                Fun {
+                    param_types,
+                    ..fun.clone()
+                },
+            )
+        } else if let Some((redex, return_type)) = self.step_expr(
+            &fun.return_type,
+            NodePath::Snoc(&path, node_path::FUN_RETURN_TYPE),
+        ) {
+            (
+                redex,
+                Fun {
+                    return_type,
+                    ..fun.clone()
+                },
+            )
+        } else {
+            let (redex, return_val) = self.step_expr(
+                &fun.return_val,
+                NodePath::Snoc(&path, node_path::FUN_RETURN_VAL),
+            )?;
+            (
+                redex,
+                Fun {
+                    return_val,
+                    ..fun.clone()
+                },
+            )
+        };
+
+        let (redex, fun) = stepped;
+        Some((redex, Expr::Fun(rc_hashed(fun))))
+    }
+
+    fn step_app(&self, app: &App, path: NodePath) -> Stepped<Expr> {
+        let stepped = if let Some((redex, callee)) =
+            self.step_expr(&app.callee, NodePath::Snoc(&path, node_path::APP_CALLEE))
+        {
+            (
+                redex,
+                App {
+                    callee,
+                    ..app.clone()
+                },
+            )
+        } else {
+            let (redex, args) =
+                self.step_exprs(&app.args, NodePath::Snoc(&path, node_path::APP_ARGS))?;
+            (
+                redex,
+                App {
+                    args,
+                    ..app.clone()
+                },
+            )
+        };
+
+        let (redex, app) = stepped;
+        Some((redex, Expr::App(rc_hashed(app))))
+    }
+
+    fn step_for(&self, for_: &For, path: NodePath) -> Stepped<Expr> {
+        let stepped = if let Some((redex, param_types)) = self.step_exprs(
+            &for_.param_types,
+            NodePath::Snoc(&path, node_path::FOR_PARAM_TYPES),
+        ) {
+            (
+                redex,
+                For {
+                    param_types,
+                    ..for_.clone()
+                },
+            )
+        } else {
+            let (redex, return_type) = self.step_expr(
+                &for_.return_type,
+                NodePath::Snoc(&path, node_path::FOR_RETURN_TYPE),
+            )?;
+            (
+                redex,
+                For {
+                    return_type,
+                    ..for_.clone()
+                },
+            )
+        };
+
+        let (redex, for_) = stepped;
+        Some((redex, Expr::For(rc_hashed(for_))))
+    }
+
+    fn step_exprs(&self, exprs: &RcHashedVec<Expr>, path: NodePath) -> Stepped<RcHashedVec<Expr>> {
+        step_vec(exprs, path, |expr, expr_path| {
+            self.step_expr(expr, expr_path)
+        })
+    }
+}
+
+/// Steps the first item that can be stepped.
+fn step_vec<T: Clone + Hash>(
+    items: &RcHashedVec<T>,
+    path: NodePath,
+    mut step_item: impl FnMut(&T, NodePath) -> Stepped<T>,
+) -> Stepped<RcHashedVec<T>> {
+    for (i, item) in items.hashee.iter().enumerate() {
+        if let Some((redex, stepped)) = step_item(item, NodePath::Snoc(&path, NodeEdge(i))) {
+            let mut items = items.hashee.clone();
+            items[i] = stepped;
+            return Some((redex, rc_hashed(items)));
+        }
+    }
+    None
+}
+
+/// Returns `None` if `expr` is not a redex.
+///
+/// The redexes are exactly the terms the `Evaluator` reduces,
+/// so for well-typed terms, tracing reaches
+/// the same normal form as `Evaluator::eval`.
+fn contract(expr: &Expr) -> Option<(ReductionKind, Expr)> {
+    match expr {
+        Expr::Match(m) => {
+            let match_ = &m.hashee;
+            let (vcon, args) = try_as_vcon_or_vcon_app(&match_.matchee)?;
+            let case = match_.cases.hashee.get(vcon.hashee.vcon_index)?;
+            let unsubstituted = case.return_val.clone()?;
+            let contractum = substitute_and_downshift_debs(unsubstituted, args);
+            Some((ReductionKind::Iota, contractum))
+        }
+
+        Expr::App(app) => {
+            let app = &app.hashee;
+            match &app.callee {
+                Expr::Fun(callee) => {
+                    if !can_unfold_app(callee.clone(), app.args.clone()) {
+                        return None;
+                    }
+
+                    let kind = match callee.hashee.decreasing_index {
+                        None => ReductionKind::Beta,
+                        Some(_) => ReductionKind::Unfold,
+                    };
+                    let new_exprs: Vec<Expr> = app
+                        .args
+                        .hashee
+                        .iter()
+                        .cloned()
+                        .chain(std::iter::once(app.callee.clone()))
+                        .collect();
+                    let contractum =
+                        substitute_and_downshift_debs(callee.hashee.return_val.clone(), &new_exprs);
+                    Some((kind, contractum))
+                }
+
+                Expr::Prim(callee) => {
+                    let lit = try_apply_prim(callee.hashee.prim, &app.args.hashee)?;
+                    Some((
+                        ReductionKind::Prim,
+                        Expr::Lit(rc_hashed(LitNode { lit, aux_data: () })),
+                    ))
+                }
+
+                _ => None,
+            }
+        }
+
+        _ => None,
+    }
+}
@@ -0,0 +1,378 @@
+use super::*;
+
+use std::collections::BTreeMap;
+
+/// Returns a Coq (Gallina) file that defines `name` as `expr`.
+///
+/// `expr` must be closed.
+/// Each ind becomes an `Inductive` (see the `export` module docs),
+/// a recursive `fun` becomes a `Fixpoint` (or a `fix`),
+/// `Prop0` and `Set0` become `Prop` and `Set`,
+/// and every other universe becomes `Type`.
+/// If `expr` uses prims, the file starts with Coq definitions of them
+/// (`U64` is `N`, with arithmetic modulo `2^64`).
+///
+/// Coq is stricter than `TypeChecker` in a few ways
+/// (e.g., it does not refine match case return types with
+/// solved index args), so Coq may reject the export of a well-typed term.
+pub fn export_to_coq(name: &str, expr: &Expr) -> Result<String, ExportError> {
+    let mut exporter = CoqExporter::default();
+    let type_ = exporter
+        .core
+        .get_type(expr, &Context::default())
+        .map_err(ExportError::TypeError)?;
+    let name = exporter.names.fresh(name);
+    let def = exporter.definition(&name, expr, type_.raw())?;
+
+    let mut blocks = vec![];
+    if exporter.uses_prims {
+        blocks.push(PRIM_PRELUDE.to_string());
+    }
+    blocks.extend(exporter.decls);
+    blocks.push(def);
+    Ok(blocks.join("\n\n") + "\n")
+}
+
+const PRIM_PRELUDE: &str = "Require Import Coq.NArith.NArith.
+Require Import Coq.Strings.String.
+
+Definition U64 : Set := N.
+Definition u64_add (a b : N) : N := N.modulo (N.add a b) (N.pow 2 64).
+Definition u64_sub (a b : N) : N := N.modulo (N.sub (N.add a (N.pow 2 64)) b) (N.pow 2 64).
+Definition u64_mul (a b : N) : N := N.modulo (N.mul a b) (N.pow 2 64).
+Definition string_concat (a b : string) : string := String.append a b.
+Definition string_len (a : string) : N := N.of_nat (String.length a).";
+
+/// Besides these, the names of the prims are reserved.
+const RESERVED_NAMES: [&str; 30] = [
+    "as", "at", "cofix", "else", "end", "exists", "exists2", "fix", "for", "forall", "fun", "if",
+    "IF", "in", "let", "match", "mod", "return", "then", "using", "where", "with", "Prop", "Set",
+    "SProp", "Type", "Coq", "N", "String", "string",
+];
+
+struct CoqExporter {
+    core: ExportCore,
+    names: NameSet,
+    inds: BTreeMap<LiftKey, CoqInd>,
+    /// The `Inductive` declarations,
+    /// each after the declarations it depends on.
+    decls: Vec<String>,
+    uses_prims: bool,
+}
+
+#[derive(Clone, Debug)]
+struct CoqInd {
+    name: String,
+    vcon_names: Vec<String>,
+}
+
+impl Default for CoqExporter {
+    fn default() -> Self {
+        CoqExporter {
+            core: ExportCore::default(),
+            names: NameSet::with_reserved(&RESERVED_NAMES),
+            inds: BTreeMap::new(),
+            decls: vec![],
+            uses_prims: false,
+        }
+    }
+}
+
+impl CoqExporter {
+    fn definition(&mut self, name: &str, expr: &Expr, type_: &Expr) -> Result<String, ExportError> {
+        let context = Context::default();
+        let Expr::Fun(fun) = expr else {
+            let type_ = self.expr(type_, &context)?;
+            let expr = self.expr(expr, &context)?;
+            return Ok(format!("Definition {name} : {type_} :=\n  {expr}."));
+        };
+
+        let self_binder = Binder {
+            head: name.to_string(),
+            args: vec![],
+            type_: self.core.fun_self_type(fun),
+        };
+        let fun = self.fun_parts(fun, self_binder, &context)?;
+        Ok(match fun.decreasing_param {
+            Some(decreasing_param) => format!(
+                "Fixpoint {name} {} {{struct {decreasing_param}}} : {} :=\n  {}.",
+                fun.params, fun.return_type, fun.return_val
+            ),
+            None => format!(
+                "Definition {name} {} : {} :=\n  {}.",
+                fun.params, fun.return_type, fun.return_val
+            ),
+        })
+    }
+
+    fn expr(&mut self, expr: &Expr, context: &Context) -> Result<String, ExportError> {
+        match expr {
+            Expr::Ind(_) | Expr::Vcon(_) | Expr::App(_) | Expr::Deb(_) => {
+                let (head, args) = self.app_parts(expr, context)?;
+                Ok(app(head, &args))
+            }
+            Expr::Match(match_) => self.match_(match_, context),
+            Expr::Fun(fun) => {
+                let self_binder = Binder {
+                    head: format!("x{}", context.len() + fun.hashee.param_types.hashee.len()),
+                    args: vec![],
+                    type_: self.core.fun_self_type(fun),
+                };
+                let self_name = self_binder.head.clone();
+                let fun = self.fun_parts(fun, self_binder, context)?;
+                Ok(match fun.decreasing_param {
+                    Some(decreasing_param) => format!(
+                        "(fix {self_name} {} {{struct {decreasing_param}}} : {} := {})",
+                        fun.params, fun.return_type, fun.return_val
+                    ),
+                    None => format!("(fun {} => {})", fun.params, fun.return_val),
+                })
+            }
+            Expr::For(for_) => {
+                let (params, context) = self.params(&for_.hashee.param_types.hashee, context)?;
+                let return_type = self.expr(&for_.hashee.return_type, &context)?;
+                Ok(format!("(forall {params}, {return_type})"))
+            }
+            Expr::Universe(universe) => Ok(sort(universe.hashee.universe).to_string()),
+            Expr::Lit(lit) => {
+                self.uses_prims = true;
+                Ok(match &lit.hashee.lit {
+                    Lit::U64(value) => format!("{value}%N"),
+                    Lit::String(value) => format!("\"{}\"%string", value.0.replace('"', "\"\"")),
+                })
+            }
+            Expr::Prim(prim) => {
+                self.uses_prims = true;
+                Ok(match prim.hashee.prim {
+                    // Coq's own string type.
+                    Prim::String => "string",
+                    prim => prim.name(),
+                }
+                .to_string())
+            }
+            Expr::Meta(_) | Expr::Hole(_) => Err(ExportError::UnelaboratedTerm),
+        }
+    }
+
+    /// Splits `expr` into a head and args,
+    /// flattening nested apps,
+    /// and expanding inds, vcons, and debs
+    /// into their names and params.
+    fn app_parts(
+        &mut self,
+        expr: &Expr,
+        context: &Context,
+    ) -> Result<(String, Vec<String>), ExportError> {
+        match expr {
+            Expr::Ind(ind) => {
+                let (ind, args) = self.ind(ind.clone(), context)?;
+                Ok((ind.name, args))
+            }
+            Expr::Vcon(vcon) => {
+                let (ind, args) = self.ind(vcon.hashee.ind.clone(), context)?;
+                Ok((ind.vcon_names[vcon.hashee.vcon_index].clone(), args))
+            }
+            Expr::Deb(deb) => {
+                let binder = context.get(deb.hashee.deb);
+                Ok((binder.head.clone(), binder.args.clone()))
+            }
+            Expr::App(app) => {
+                let (head, mut args) = self.app_parts(&app.hashee.callee, context)?;
+                for arg in app.hashee.args.hashee.iter() {
+                    args.push(self.expr(arg, context)?);
+                }
+                Ok((head, args))
+            }
+            other => Ok((self.expr(other, context)?, vec![])),
+        }
+    }
+
+    /// Returns the declaration of `ind` (declaring it if needed),
+    /// and the args its params are instantiated with.
+    fn ind(
+        &mut self,
+        ind: RcHashed<Ind>,
+        context: &Context,
+    ) -> Result<(CoqInd, Vec<String>), ExportError> {
+        let lifted = self.core.lift_ind(ind, context);
+        let mut args = vec![];
+        for arg in &lifted.args {
+            args.push(self.expr(arg, context)?);
+        }
+
+        if let Some(declared) = self.inds.get(&lifted.key) {
+            return Ok((declared.clone(), args));
+        }
+        let declared = self.declare_ind(&lifted)?;
+        self.inds.insert(lifted.key, declared.clone());
+        Ok((declared, args))
+    }
+
+    fn declare_ind(&mut self, lifted: &LiftedInd) -> Result<CoqInd, ExportError> {
+        let ind = &lifted.ind.hashee;
+        let name = self.names.fresh(&ind.name.0);
+        let vcon_names = ind
+            .vcon_defs
+            .hashee
+            .iter()
+            .enumerate()
+            .map(|(i, def)| match &def.name {
+                Some(vcon_name) => self.names.fresh(&vcon_name.0),
+                None => self.names.fresh(&format!("{name}_{i}")),
+            })
+            .collect();
+
+        let mut context = Context::default();
+        let mut params = String::new();
+        for type_ in &lifted.param_types {
+            let type_text = self.expr(type_.raw(), &context)?;
+            params += &format!(" ({} : {type_text})", context.next_var_name());
+            context = context.with_var(type_.clone());
+        }
+        let param_names: Vec<String> = (0..context.len())
+            .map(|level| format!("x{level}"))
+            .collect();
+
+        let (index_params, _) = self.params(&ind.index_types.hashee, &context)?;
+        let sort = sort(ind.universe);
+        let arity = if index_params.is_empty() {
+            sort.to_string()
+        } else {
+            format!("forall {index_params}, {sort}")
+        };
+        let self_binder = Binder {
+            head: name.clone(),
+            args: param_names,
+            type_: self.core.eval(ind_type(ind)),
+        };
+        let context = context.with(self_binder.clone());
+
+        let mut decl = format!("Inductive {name}{params} : {arity} :=");
+        for (def, vcon_name) in ind.vcon_defs.hashee.iter().zip(&vcon_names) {
+            let (def_params, def_context) = self.params(&def.param_types.hashee, &context)?;
+            let mut conclusion_args = self_binder.args.clone();
+            for index_arg in def.index_args.hashee.iter() {
+                conclusion_args.push(self.expr(index_arg, &def_context)?);
+            }
+            let conclusion = app(name.clone(), &conclusion_args);
+            if def_params.is_empty() {
+                decl += &format!("\n| {vcon_name} : {conclusion}");
+            } else {
+                decl += &format!("\n| {vcon_name} : forall {def_params}, {conclusion}");
+            }
+        }
+        decl.push('.');
+        self.decls.push(decl);
+
+        Ok(CoqInd { name, vcon_names })
+    }
+
+    /// Returns the binders `(x<i> : T_i) ...` for the dependent `param_types`,
+    /// and the context extended with them.
+    fn params(
+        &mut self,
+        param_types: &[Expr],
+        context: &Context,
+    ) -> Result<(String, Context), ExportError> {
+        let mut context = context.clone();
+        let mut binders = vec![];
+        for param_type in param_types {
+            let type_text = self.expr(param_type, &context)?;
+            binders.push(format!("({} : {type_text})", context.next_var_name()));
+            let type_ = self.core.eval(param_type.clone());
+            context = context.with_var(type_);
+        }
+        Ok((binders.join(" "), context))
+    }
+
+    fn fun_parts(
+        &mut self,
+        fun: &RcHashed<Fun>,
+        self_binder: Binder,
+        context: &Context,
+    ) -> Result<FunParts, ExportError> {
+        let fun = &fun.hashee;
+        let (params, param_context) = self.params(&fun.param_types.hashee, context)?;
+        let return_type = self.expr(&fun.return_type, &param_context)?;
+        let return_val = self.expr(&fun.return_val, &param_context.with(self_binder))?;
+        Ok(FunParts {
+            params,
+            decreasing_param: fun
+                .decreasing_index
+                .map(|index| format!("x{}", context.len() + index)),
+            return_type,
+            return_val,
+        })
+    }
+
+    fn match_(
+        &mut self,
+        match_: &RcHashed<Match>,
+        context: &Context,
+    ) -> Result<String, ExportError> {
+        let binders = self.core.match_binders(match_, context);
+        let matchee = self.expr(&match_.hashee.matchee, context)?;
+        let (ind, ind_args) = self.ind(binders.ind.clone(), context)?;
+
+        let mut return_type_context = context.clone();
+        for type_ in binders.return_type_binder_types {
+            return_type_context = return_type_context.with_var(type_);
+        }
+        let index_count = match_.hashee.return_type_arity - 1;
+        let in_args: Vec<String> = std::iter::repeat_n("_".to_string(), ind_args.len())
+            .chain((0..index_count).map(|i| format!("x{}", context.len() + i)))
+            .collect();
+        let matchee_name = format!("x{}", context.len() + index_count);
+        let return_type = self.expr(&match_.hashee.return_type, &return_type_context)?;
+
+        let mut cases = String::new();
+        for (vcon_index, (case, param_types)) in match_
+            .hashee
+            .cases
+            .hashee
+            .iter()
+            .zip(binders.case_param_types)
+            .enumerate()
+        {
+            let Some(return_val) = &case.return_val else {
+                return Err(ExportError::OmittedCase(match_.clone()));
+            };
+            let mut case_context = context.clone();
+            let mut pattern = ind.vcon_names[vcon_index].clone();
+            for type_ in param_types {
+                pattern += &format!(" {}", case_context.next_var_name());
+                case_context = case_context.with_var(type_);
+            }
+            let return_val = self.expr(return_val, &case_context)?;
+            cases += &format!(" | {pattern} => {return_val}");
+        }
+
+        Ok(format!(
+            "(match {matchee} as {matchee_name} in {} return {return_type} with{cases} end)",
+            app(ind.name, &in_args)
+        ))
+    }
+}
+
+struct FunParts {
+    params: String,
+    decreasing_param: Option<String>,
+    return_type: String,
+    return_val: String,
+}
+
+fn app(head: String, args: &[String]) -> String {
+    if args.is_empty() {
+        head
+    } else {
+        format!("({head} {})", args.join(" "))
+    }
+}
+
+fn sort(universe: Universe) -> &'static str {
+    match (universe.level, universe.erasable) {
+        (UniverseLevel(0), true) => "Prop",
+        (UniverseLevel(0), false) => "Set",
+        _ => "Type",
+    }
+}
@@ -0,0 +1,869 @@
+use super::*;
+
+use std::collections::BTreeMap;
+
+/// Returns a Dedukti (λΠ-calculus modulo rewriting) file
+/// that defines `name` as `expr`.
+///
+/// `expr` must be closed.
+/// The file starts with an encoding of Zo's universes
+/// (see `PRELUDE`), so it can be checked on its own.
+/// A term `t` of type `T` (where `T` has type universe `u`)
+/// is exported as a term of type `El u T`.
+///
+/// Each ind becomes a static symbol (see the `export` module docs),
+/// together with a symbol for each vcon,
+/// and a `<Ind>_case` symbol that matches are exported as.
+/// `<Ind>_case` has one rewrite rule per vcon.
+/// Each recursive `fun` becomes a symbol (whose params include
+/// the fun's free debs; see `ExportCore::lift_fun`)
+/// with one rewrite rule per vcon of its decreasing param's type,
+/// so it only unfolds when the decreasing arg is a vcon app.
+///
+/// If `expr` uses prims, the file also encodes them (see `PRIM_PRELUDE`),
+/// with rewrite rules that compute them the way `eval` does,
+/// so a term whose typing relies on evaluating a prim
+/// still typechecks in Dedukti.
+pub fn export_to_dedukti(name: &str, expr: &Expr) -> Result<String, ExportError> {
+    let mut exporter = DeduktiExporter::default();
+    let type_ = exporter
+        .core
+        .get_type(expr, &Context::default())
+        .map_err(ExportError::TypeError)?;
+    let name = exporter.names.fresh(name);
+    let def = exporter.definition(&name, expr, type_.raw())?;
+
+    let mut blocks = vec![PRELUDE.to_string()];
+    if exporter.uses_prims {
+        blocks.push(PRIM_PRELUDE.to_string());
+    }
+    blocks.extend(exporter.decls);
+    blocks.push(def);
+    Ok(blocks.join("\n\n") + "\n")
+}
+
+/// Encodes Zo's universes.
+///
+/// `Univ s` is the type of the codes of the types in universe `s`,
+/// and `El s a` is the type of the terms of the type `a` codes.
+/// `next` and `pi_sort` follow `TypeChecker`:
+/// a universe's type is the erasable universe one level up,
+/// and a `for`'s universe has the maximum level of its
+/// param and return types, and the erasability of its return type.
+const PRELUDE: &str = "Lvl : Type.
+lz : Lvl.
+ls : Lvl -> Lvl.
+def lmax : Lvl -> Lvl -> Lvl.
+[l] lmax lz l --> l.
+[l] lmax l lz --> l.
+[l, m] lmax (ls l) (ls m) --> ls (lmax l m).
+
+Sort : Type.
+set : Lvl -> Sort.
+prop : Lvl -> Sort.
+def next : Sort -> Sort.
+[l] next (set l) --> prop (ls l).
+[l] next (prop l) --> prop (ls l).
+def level : Sort -> Lvl.
+[l] level (set l) --> l.
+[l] level (prop l) --> l.
+def pi_sort : Lvl -> Sort -> Sort.
+[l, m] pi_sort l (set m) --> set (lmax l m).
+[l, m] pi_sort l (prop m) --> prop (lmax l m).
+
+Univ : Sort -> Type.
+def El : s : Sort -> Univ s -> Type.
+univ : s : Sort -> Univ (next s).
+[s] El _ (univ s) --> Univ s.
+pi : s : Sort -> t : Sort -> a : Univ s -> (El s a -> Univ t) -> Univ (pi_sort (level s) t).
+[s, t, a, b] El _ (pi s t a b) --> x : El s a -> El t (b x).";
+
+/// Encodes the prims.
+///
+/// A `U64` is a binary numeral, as in Coq's `N`:
+/// `N0` is zero, and `Npos p` is the positive number `p`,
+/// where `xI p` is `2p + 1`, `xO p` is `2p`, and `xH` is one.
+/// The numerals are canonical (no leading zeros),
+/// so equal numbers are convertible.
+/// The `U64` ops compute in `N` and then keep the low 64 bits
+/// (`n_trunc c64`), so they wrap like `eval`'s.
+/// `u64_sub a b` is `a + (2^64 - 1 - b) + 1`, truncated.
+///
+/// A `String` is the list of its UTF-8 bytes.
+const PRIM_PRELUDE: &str = "Pos : Type.
+xH : Pos.
+xO : Pos -> Pos.
+xI : Pos -> Pos.
+def pos_succ : Pos -> Pos.
+[] pos_succ xH --> xO xH.
+[p] pos_succ (xO p) --> xI p.
+[p] pos_succ (xI p) --> xO (pos_succ p).
+def pos_add : Pos -> Pos -> Pos.
+def pos_add_carry : Pos -> Pos -> Pos.
+[p, q] pos_add (xI p) (xI q) --> xO (pos_add_carry p q).
+[p, q] pos_add (xI p) (xO q) --> xI (pos_add p q).
+[p] pos_add (xI p) xH --> xO (pos_succ p).
+[p, q] pos_add (xO p) (xI q) --> xI (pos_add p q).
+[p, q] pos_add (xO p) (xO q) --> xO (pos_add p q).
+[p] pos_add (xO p) xH --> xI p.
+[q] pos_add xH (xI q) --> xO (pos_succ q).
+[q] pos_add xH (xO q) --> xI q.
+[] pos_add xH xH --> xO xH.
+[p, q] pos_add_carry (xI p) (xI q) --> xI (pos_add_carry p q).
+[p, q] pos_add_carry (xI p) (xO q) --> xO (pos_add_carry p q).
+[p] pos_add_carry (xI p) xH --> xI (pos_succ p).
+[p, q] pos_add_carry (xO p) (xI q) --> xO (pos_add_carry p q).
+[p, q] pos_add_carry (xO p) (xO q) --> xI (pos_add p q).
+[p] pos_add_carry (xO p) xH --> xO (pos_succ p).
+[q] pos_add_carry xH (xI q) --> xI (pos_succ q).
+[q] pos_add_carry xH (xO q) --> xO (pos_succ q).
+[] pos_add_carry xH xH --> xI xH.
+def pos_mul : Pos -> Pos -> Pos.
+[q] pos_mul xH q --> q.
+[p, q] pos_mul (xO p) q --> xO (pos_mul p q).
+[p, q] pos_mul (xI p) q --> pos_add q (xO (pos_mul p q)).
+
+U64 : Univ (set lz).
+def N : Type := El (set lz) U64.
+N0 : N.
+Npos : Pos -> N.
+def n_double : N -> N.
+[] n_double N0 --> N0.
+[p] n_double (Npos p) --> Npos (xO p).
+def n_succ_double : N -> N.
+[] n_succ_double N0 --> Npos xH.
+[p] n_succ_double (Npos p) --> Npos (xI p).
+def n_add : N -> N -> N.
+[n] n_add N0 n --> n.
+[n] n_add n N0 --> n.
+[p, q] n_add (Npos p) (Npos q) --> Npos (pos_add p q).
+def n_mul : N -> N -> N.
+[n] n_mul N0 n --> N0.
+[n] n_mul n N0 --> N0.
+[p, q] n_mul (Npos p) (Npos q) --> Npos (pos_mul p q).
+
+Cnt : Type.
+cz : Cnt.
+cs : Cnt -> Cnt.
+def c_double : Cnt -> Cnt.
+[] c_double cz --> cz.
+[c] c_double (cs c) --> cs (cs (c_double c)).
+def c64 : Cnt := c_double (c_double (c_double (c_double (c_double (c_double (cs cz)))))).
+def n_trunc : Cnt -> N -> N.
+[c] n_trunc c N0 --> N0.
+[n] n_trunc cz n --> N0.
+[c] n_trunc (cs c) (Npos xH) --> Npos xH.
+[c, p] n_trunc (cs c) (Npos (xO p)) --> n_double (n_trunc c (Npos p)).
+[c, p] n_trunc (cs c) (Npos (xI p)) --> n_succ_double (n_trunc c (Npos p)).
+def n_compl : Cnt -> N -> N.
+[n] n_compl cz n --> N0.
+[c] n_compl (cs c) N0 --> n_succ_double (n_compl c N0).
+[c] n_compl (cs c) (Npos xH) --> n_double (n_compl c N0).
+[c, p] n_compl (cs c) (Npos (xO p)) --> n_succ_double (n_compl c (Npos p)).
+[c, p] n_compl (cs c) (Npos (xI p)) --> n_double (n_compl c (Npos p)).
+
+def u64_add : N -> N -> N.
+[a, b] u64_add a b --> n_trunc c64 (n_add a b).
+def u64_sub : N -> N -> N.
+[a, b] u64_sub a b --> n_trunc c64 (n_add (n_add a (n_compl c64 b)) (Npos xH)).
+def u64_mul : N -> N -> N.
+[a, b] u64_mul a b --> n_trunc c64 (n_mul a b).
+
+String : Univ (set lz).
+snil : El (set lz) String.
+scons : N -> El (set lz) String -> El (set lz) String.
+def string_concat : El (set lz) String -> El (set lz) String -> El (set lz) String.
+[t] string_concat snil t --> t.
+[b, s, t] string_concat (scons b s) t --> scons b (string_concat s t).
+def string_len : El (set lz) String -> N.
+[] string_len snil --> N0.
+[b, s] string_len (scons b s) --> u64_add (string_len s) (Npos xH).";
+
+/// Besides these, the names of the prims are reserved.
+const RESERVED_NAMES: [&str; 53] = [
+    "def",
+    "thm",
+    "inj",
+    "defac",
+    "defacu",
+    "private",
+    "injective",
+    "require",
+    "assert",
+    "eval",
+    "infer",
+    "check",
+    "Type",
+    "Lvl",
+    "lz",
+    "ls",
+    "lmax",
+    "Sort",
+    "set",
+    "prop",
+    "next",
+    "level",
+    "pi_sort",
+    "Univ",
+    "El",
+    "univ",
+    "pi",
+    // The helpers of `PRIM_PRELUDE`.
+    "Pos",
+    "xH",
+    "xO",
+    "xI",
+    "pos_succ",
+    "pos_add",
+    "pos_add_carry",
+    "pos_mul",
+    "N",
+    "N0",
+    "Npos",
+    "n_double",
+    "n_succ_double",
+    "n_add",
+    "n_mul",
+    "Cnt",
+    "cz",
+    "cs",
+    "c_double",
+    "c64",
+    "n_trunc",
+    "n_compl",
+    "snil",
+    "scons",
+    // The sort and motive binders of `<Ind>_case`.
+    "s",
+    "P",
+];
+
+struct DeduktiExporter {
+    core: ExportCore,
+    names: NameSet,
+    inds: BTreeMap<LiftKey, DeduktiInd>,
+    funs: BTreeMap<LiftKey, String>,
+    lits: BTreeMap<Lit, String>,
+    /// The symbol declarations (and their rewrite rules),
+    /// each after the declarations it depends on.
+    decls: Vec<String>,
+    uses_prims: bool,
+}
+
+#[derive(Clone, Debug)]
+struct DeduktiInd {
+    name: String,
+    vcon_names: Vec<String>,
+    /// `vcon_arities[i]` is the number of params of vcon `i`.
+    vcon_arities: Vec<usize>,
+    case_name: String,
+}
+
+impl Default for DeduktiExporter {
+    fn default() -> Self {
+        DeduktiExporter {
+            core: ExportCore::default(),
+            names: NameSet::with_reserved(&RESERVED_NAMES),
+            inds: BTreeMap::new(),
+            funs: BTreeMap::new(),
+            lits: BTreeMap::new(),
+            decls: vec![],
+            uses_prims: false,
+        }
+    }
+}
+
+impl DeduktiExporter {
+    fn definition(&mut self, name: &str, expr: &Expr, type_: &Expr) -> Result<String, ExportError> {
+        let context = Context::default();
+        if let Expr::Fun(fun) = expr {
+            if fun.hashee.decreasing_index.is_some() {
+                let lifted = self.core.lift_fun(fun.clone(), &context);
+                let decl = self.declare_fun(name, &lifted)?;
+                self.funs.insert(lifted.key, name.to_string());
+                return Ok(decl);
+            }
+        }
+
+        let type_ = self.el(type_, &context)?;
+        let expr = self.expr(expr, &context)?;
+        Ok(format!("def {name} : {type_} :=\n  {expr}."))
+    }
+
+    fn expr(&mut self, expr: &Expr, context: &Context) -> Result<String, ExportError> {
+        match expr {
+            Expr::Ind(_) | Expr::Vcon(_) | Expr::App(_) | Expr::Deb(_) => {
+                let (head, args) = self.app_parts(expr, context)?;
+                Ok(app(head, &args))
+            }
+            Expr::Match(match_) => self.match_(match_, context),
+            Expr::Fun(fun) => {
+                if fun.hashee.decreasing_index.is_some() {
+                    return self.recursive_fun(fun, context);
+                }
+                let (params, param_context) =
+                    self.params(&fun.hashee.param_types.hashee, context)?;
+                // The self deb is unused, since the fun is nonrecursive.
+                let self_binder = Binder {
+                    head: param_context.next_var_name(),
+                    args: vec![],
+                    type_: self.core.fun_self_type(fun),
+                };
+                let return_val =
+                    self.expr(&fun.hashee.return_val, &param_context.with(self_binder))?;
+                Ok(lambda(&params, return_val))
+            }
+            Expr::For(for_) => {
+                let mut context = context.clone();
+                let mut params = vec![];
+                for param_type in for_.hashee.param_types.hashee.iter() {
+                    let universe = self.universe_of(param_type, &context);
+                    let code = self.expr(param_type, &context)?;
+                    params.push((universe, code, context.next_var_name()));
+                    let type_ = self.core.eval(param_type.clone());
+                    context = context.with_var(type_);
+                }
+
+                let return_type = &for_.hashee.return_type;
+                let mut universe = self.universe_of(return_type, &context);
+                let mut code = self.expr(return_type, &context)?;
+                for (param_universe, param_code, param_name) in params.into_iter().rev() {
+                    let param_sort = sort(param_universe);
+                    code = format!(
+                        "(pi {param_sort} {} {param_code} ({param_name} : El {param_sort} {param_code} => {code}))",
+                        sort(universe)
+                    );
+                    universe = Universe {
+                        level: universe.level.max(param_universe.level),
+                        erasable: universe.erasable,
+                    };
+                }
+                Ok(code)
+            }
+            Expr::Universe(universe) => Ok(format!("(univ {})", sort(universe.hashee.universe))),
+            Expr::Lit(lit) => Ok(self.lit(&lit.hashee.lit)),
+            Expr::Prim(prim) => {
+                self.uses_prims = true;
+                Ok(prim.hashee.prim.name().to_string())
+            }
+            Expr::Meta(_) | Expr::Hole(_) => Err(ExportError::UnelaboratedTerm),
+        }
+    }
+
+    /// Splits `expr` into a head and args,
+    /// flattening nested apps,
+    /// and expanding inds, vcons, and debs
+    /// into their names and params.
+    fn app_parts(
+        &mut self,
+        expr: &Expr,
+        context: &Context,
+    ) -> Result<(String, Vec<String>), ExportError> {
+        match expr {
+            Expr::Ind(ind) => {
+                let (ind, args) = self.ind(ind.clone(), context)?;
+                Ok((ind.name, args))
+            }
+            Expr::Vcon(vcon) => {
+                let (ind, args) = self.ind(vcon.hashee.ind.clone(), context)?;
+                Ok((ind.vcon_names[vcon.hashee.vcon_index].clone(), args))
+            }
+            Expr::Deb(deb) => {
+                let binder = context.get(deb.hashee.deb);
+                Ok((binder.head.clone(), binder.args.clone()))
+            }
+            Expr::App(app) => {
+                let (head, mut args) = self.app_parts(&app.hashee.callee, context)?;
+                for arg in app.hashee.args.hashee.iter() {
+                    args.push(self.expr(arg, context)?);
+                }
+                Ok((head, args))
+            }
+            other => Ok((self.expr(other, context)?, vec![])),
+        }
+    }
+
+    /// Returns `El s T`, the Dedukti type of the terms of type `type_`.
+    fn el(&mut self, type_: &Expr, context: &Context) -> Result<String, ExportError> {
+        let sort = sort(self.universe_of(type_, context));
+        let code = self.expr(type_, context)?;
+        Ok(format!("El {sort} {code}"))
+    }
+
+    fn universe_of(&mut self, type_: &Expr, context: &Context) -> Universe {
+        match self.core.get_type_of_subterm(type_, context).raw() {
+            Expr::Universe(universe) => universe.hashee.universe,
+            other => unreachable!("the type of a type should be a universe, but got {other:?}"),
+        }
+    }
+
+    fn lit(&mut self, lit: &Lit) -> String {
+        self.uses_prims = true;
+        if let Some(name) = self.lits.get(lit) {
+            return name.clone();
+        }
+
+        let (name, decl) = match lit {
+            Lit::U64(value) => {
+                let name = self.names.fresh(&format!("u64_{value}"));
+                let decl = format!("def {name} : N := {}.", numeral(*value));
+                (name, decl)
+            }
+            Lit::String(value) => {
+                let bytes: Vec<String> = value
+                    .0
+                    .bytes()
+                    .map(|byte| self.lit(&Lit::U64(byte.into())))
+                    .collect();
+                let list = bytes.iter().rev().fold("snil".to_string(), |list, byte| {
+                    format!("(scons {byte} {list})")
+                });
+                let name = self.names.fresh("string");
+                let comment = format!("{:?}", value.0).replace(";)", "; )");
+                let decl = format!("(; {comment} ;)\ndef {name} : El (set lz) String := {list}.");
+                (name, decl)
+            }
+        };
+        self.decls.push(decl);
+        self.lits.insert(lit.clone(), name.clone());
+        name
+    }
+
+    /// Returns the declaration of `ind` (declaring it if needed),
+    /// and the args its params are instantiated with.
+    fn ind(
+        &mut self,
+        ind: RcHashed<Ind>,
+        context: &Context,
+    ) -> Result<(DeduktiInd, Vec<String>), ExportError> {
+        let lifted = self.core.lift_ind(ind, context);
+        let mut args = vec![];
+        for arg in &lifted.args {
+            args.push(self.expr(arg, context)?);
+        }
+
+        if let Some(declared) = self.inds.get(&lifted.key) {
+            return Ok((declared.clone(), args));
+        }
+        let declared = self.declare_ind(&lifted)?;
+        self.inds.insert(lifted.key, declared.clone());
+        Ok((declared, args))
+    }
+
+    fn declare_ind(&mut self, lifted: &LiftedInd) -> Result<DeduktiInd, ExportError> {
+        let ind = &lifted.ind.hashee;
+        let name = self.names.fresh(&ind.name.0);
+        let vcon_names: Vec<String> = ind
+            .vcon_defs
+            .hashee
+            .iter()
+            .enumerate()
+            .map(|(i, def)| match &def.name {
+                Some(vcon_name) => self.names.fresh(&vcon_name.0),
+                None => self.names.fresh(&format!("{name}_{i}")),
+            })
+            .collect();
+        let vcon_arities: Vec<usize> = ind
+            .vcon_defs
+            .hashee
+            .iter()
+            .map(|def| def.param_types.hashee.len())
+            .collect();
+        let case_name = self.names.fresh(&format!("{name}_case"));
+
+        let mut context = Context::default();
+        let mut params = vec![];
+        for type_ in &lifted.param_types {
+            params.push(format!(
+                "{} : {}",
+                context.next_var_name(),
+                self.el(type_.raw(), &context)?
+            ));
+            context = context.with_var(type_.clone());
+        }
+        let param_names: Vec<String> = (0..context.len())
+            .map(|level| format!("x{level}"))
+            .collect();
+
+        let (index_params, index_context) = self.params(&ind.index_types.hashee, &context)?;
+        let ind_sort = sort(ind.universe);
+        let index_names: Vec<String> = (context.len()..index_context.len())
+            .map(|level| format!("x{level}"))
+            .collect();
+        let matchee_name = index_context.next_var_name();
+        let matchee_param = format!(
+            "{matchee_name} : El {ind_sort} {}",
+            app(
+                name.clone(),
+                &[param_names.clone(), index_names.clone()].concat()
+            )
+        );
+
+        let mut decl = format!(
+            "{name} : {}.",
+            pi(
+                &[params.clone(), index_params.clone()].concat(),
+                format!("Univ {ind_sort}")
+            )
+        );
+
+        let self_binder = Binder {
+            head: name.clone(),
+            args: param_names.clone(),
+            type_: self.core.eval(ind_type(ind)),
+        };
+        let self_context = context.with(self_binder);
+        let mut case_params = vec![];
+        for (def, vcon_name) in ind.vcon_defs.hashee.iter().zip(&vcon_names) {
+            let (def_params, def_context) = self.params(&def.param_types.hashee, &self_context)?;
+            let mut conclusion_args = param_names.clone();
+            for index_arg in def.index_args.hashee.iter() {
+                conclusion_args.push(self.expr(index_arg, &def_context)?);
+            }
+            let conclusion = app(name.clone(), &conclusion_args);
+            decl += &format!(
+                "\n{vcon_name} : {}.",
+                pi(
+                    &[params.clone(), def_params.clone()].concat(),
+                    format!("El {ind_sort} {conclusion}")
+                )
+            );
+
+            let def_param_names: Vec<String> = (self_context.len()..def_context.len())
+                .map(|level| format!("x{level}"))
+                .collect();
+            let vcon_app = app(
+                vcon_name.clone(),
+                &[param_names.clone(), def_param_names].concat(),
+            );
+            let motive_args = [
+                conclusion_args[param_names.len()..].to_vec(),
+                vec![vcon_app],
+            ]
+            .concat();
+            case_params.push(format!(
+                "c{} : ({})",
+                case_params.len(),
+                pi(
+                    &def_params,
+                    format!("El s {}", app("P".to_string(), &motive_args))
+                )
+            ));
+        }
+
+        let motive_param = format!(
+            "P : ({})",
+            pi(
+                &[index_params.clone(), vec![matchee_param.clone()]].concat(),
+                "Univ s".to_string()
+            )
+        );
+        let case_type = pi(
+            &[
+                vec!["s : Sort".to_string()],
+                params,
+                vec![motive_param],
+                case_params,
+                index_params,
+                vec![matchee_param],
+            ]
+            .concat(),
+            format!(
+                "El s {}",
+                app("P".to_string(), &[index_names, vec![matchee_name]].concat())
+            ),
+        );
+        decl += &format!("\ndef {case_name} : {case_type}.");
+
+        let case_count = vcon_names.len();
+        for (vcon_index, (vcon_name, &arity)) in vcon_names.iter().zip(&vcon_arities).enumerate() {
+            let vcon_args: Vec<String> = (0..arity).map(|i| format!("y{i}")).collect();
+            let pattern = app(
+                vcon_name.clone(),
+                &[vec!["_".to_string(); param_names.len()], vcon_args.clone()].concat(),
+            );
+            let lhs_args: Vec<String> =
+                std::iter::repeat_n("_".to_string(), 1 + param_names.len() + 1)
+                    .chain((0..case_count).map(|i| {
+                        if i == vcon_index {
+                            format!("c{i}")
+                        } else {
+                            "_".to_string()
+                        }
+                    }))
+                    .chain(std::iter::repeat_n(
+                        "_".to_string(),
+                        ind.index_types.hashee.len(),
+                    ))
+                    .chain([pattern])
+                    .collect();
+            let case_param = format!("c{vcon_index}");
+            let pattern_vars = [vec![case_param.clone()], vcon_args.clone()].concat();
+            decl += &format!(
+                "\n{}",
+                rule(
+                    &pattern_vars,
+                    &case_name,
+                    &lhs_args,
+                    app(case_param, &vcon_args)
+                )
+            );
+        }
+        self.decls.push(decl);
+
+        Ok(DeduktiInd {
+            name,
+            vcon_names,
+            vcon_arities,
+            case_name,
+        })
+    }
+
+    /// Returns the binders `x<i> : El s_i T_i` for the dependent `param_types`,
+    /// and the context extended with them.
+    fn params(
+        &mut self,
+        param_types: &[Expr],
+        context: &Context,
+    ) -> Result<(Vec<String>, Context), ExportError> {
+        let mut context = context.clone();
+        let mut binders = vec![];
+        for param_type in param_types {
+            let type_text = self.el(param_type, &context)?;
+            binders.push(format!("{} : {type_text}", context.next_var_name()));
+            let type_ = self.core.eval(param_type.clone());
+            context = context.with_var(type_);
+        }
+        Ok((binders, context))
+    }
+
+    fn recursive_fun(
+        &mut self,
+        fun: &RcHashed<Fun>,
+        context: &Context,
+    ) -> Result<String, ExportError> {
+        let lifted = self.core.lift_fun(fun.clone(), context);
+        let mut args = vec![];
+        for arg in &lifted.args {
+            args.push(self.expr(arg, context)?);
+        }
+
+        if let Some(name) = self.funs.get(&lifted.key) {
+            return Ok(app(name.clone(), &args));
+        }
+        let name = self.names.fresh("fix");
+        let decl = self.declare_fun(&name, &lifted)?;
+        self.decls.push(decl);
+        self.funs.insert(lifted.key, name.clone());
+        Ok(app(name, &args))
+    }
+
+    /// Returns the declaration of the recursive fun `lifted`,
+    /// with one rewrite rule per vcon of its decreasing param's type.
+    fn declare_fun(&mut self, name: &str, lifted: &LiftedFun) -> Result<String, ExportError> {
+        let mut context = Context::default();
+        let mut params = vec![];
+        for type_ in &lifted.param_types {
+            params.push(format!(
+                "{} : {}",
+                context.next_var_name(),
+                self.el(type_.raw(), &context)?
+            ));
+            context = context.with_var(type_.clone());
+        }
+        let closure_names: Vec<String> = (0..context.len())
+            .map(|level| format!("x{level}"))
+            .collect();
+
+        let fun = &lifted.fun.hashee;
+        let (fun_params, param_context) = self.params(&fun.param_types.hashee, &context)?;
+        let return_type = self.el(&fun.return_type, &param_context)?;
+        let mut decl = format!(
+            "def {name} : {}.",
+            pi(&[params, fun_params].concat(), return_type)
+        );
+
+        let self_binder = Binder {
+            head: name.to_string(),
+            args: closure_names,
+            type_: self.core.fun_self_type(&lifted.fun),
+        };
+        let decreasing_level = context.len()
+            + fun
+                .decreasing_index
+                .expect("a recursive fun should have a decreasing index");
+        let decreasing_type = param_context.0[decreasing_level].type_.clone();
+        let (ind, _) = decreasing_type
+            .clone()
+            .ind_or_ind_app()
+            .expect("the decreasing param of a well-typed fun should have an ind type");
+        let ind_context = Context(param_context.0[..decreasing_level].to_vec());
+        let (ind, ind_args) = self.ind(ind.into_raw(), &ind_context)?;
+
+        let param_names: Vec<String> = (0..param_context.len())
+            .map(|level| format!("x{level}"))
+            .collect();
+        for (vcon_name, &arity) in ind.vcon_names.iter().zip(&ind.vcon_arities) {
+            let vcon_args: Vec<String> = (0..arity).map(|i| format!("y{i}")).collect();
+            let pattern = app(
+                vcon_name.clone(),
+                &[vec!["_".to_string(); ind_args.len()], vcon_args.clone()].concat(),
+            );
+            let mut lhs_args = param_names.clone();
+            lhs_args[decreasing_level] = pattern;
+            let pattern_vars: Vec<String> = param_names
+                .iter()
+                .enumerate()
+                .filter(|(level, _)| *level != decreasing_level)
+                .map(|(_, name)| name.clone())
+                .chain(vcon_args.iter().cloned())
+                .collect();
+
+            let mut rule_context = param_context.clone();
+            rule_context.0[decreasing_level] = Binder {
+                head: vcon_name.clone(),
+                args: [ind_args.clone(), vcon_args].concat(),
+                type_: decreasing_type.clone(),
+            };
+            let rule_context = rule_context.with(self_binder.clone());
+            let return_val = self.expr(&fun.return_val, &rule_context)?;
+            decl += &format!("\n{}", rule(&pattern_vars, name, &lhs_args, return_val));
+        }
+        Ok(decl)
+    }
+
+    /// Exports `match_` as an app of its ind's `<Ind>_case` symbol.
+    fn match_(
+        &mut self,
+        match_: &RcHashed<Match>,
+        context: &Context,
+    ) -> Result<String, ExportError> {
+        let binders = self.core.match_binders(match_, context);
+        let (ind, ind_args) = self.ind(binders.ind.clone(), context)?;
+        let matchee = &match_.hashee.matchee;
+        let (_, index_args) = self
+            .core
+            .get_type_of_subterm(matchee, context)
+            .ind_or_ind_app()
+            .expect("the matchee of a well-typed match should have an ind type");
+        let mut index_arg_texts = vec![];
+        for index_arg in index_args.raw().hashee.iter() {
+            index_arg_texts.push(self.expr(index_arg, context)?);
+        }
+        let matchee = self.expr(matchee, context)?;
+
+        let mut return_type_context = context.clone();
+        let mut motive_params = vec![];
+        for type_ in binders.return_type_binder_types {
+            motive_params.push(format!(
+                "{} : {}",
+                return_type_context.next_var_name(),
+                self.el(type_.raw(), &return_type_context)?
+            ));
+            return_type_context = return_type_context.with_var(type_);
+        }
+        let return_type = &match_.hashee.return_type;
+        let return_sort = sort(self.universe_of(return_type, &return_type_context));
+        let motive = lambda(
+            &motive_params,
+            self.expr(return_type, &return_type_context)?,
+        );
+
+        let mut cases = vec![];
+        for (case, param_types) in match_
+            .hashee
+            .cases
+            .hashee
+            .iter()
+            .zip(binders.case_param_types)
+        {
+            let Some(return_val) = &case.return_val else {
+                return Err(ExportError::OmittedCase(match_.clone()));
+            };
+            let mut case_context = context.clone();
+            let mut case_params = vec![];
+            for type_ in param_types {
+                case_params.push(format!(
+                    "{} : {}",
+                    case_context.next_var_name(),
+                    self.el(type_.raw(), &case_context)?
+                ));
+                case_context = case_context.with_var(type_);
+            }
+            let return_val = self.expr(return_val, &case_context)?;
+            cases.push(lambda(&case_params, return_val));
+        }
+
+        let args = [
+            vec![return_sort],
+            ind_args,
+            vec![motive],
+            cases,
+            index_arg_texts,
+            vec![matchee],
+        ]
+        .concat();
+        Ok(app(ind.case_name, &args))
+    }
+}
+
+fn app(head: String, args: &[String]) -> String {
+    if args.is_empty() {
+        head
+    } else {
+        format!("({head} {})", args.join(" "))
+    }
+}
+
+/// Returns the rewrite rule `[vars] head args --> rhs.`
+/// (Unlike other apps, the left-hand side is not parenthesized.)
+fn rule(pattern_vars: &[String], head: &str, args: &[String], rhs: String) -> String {
+    format!(
+        "[{}] {head} {} --> {rhs}.",
+        pattern_vars.join(", "),
+        args.join(" ")
+    )
+}
+
+fn lambda(params: &[String], body: String) -> String {
+    if params.is_empty() {
+        body
+    } else {
+        format!("({} => {body})", params.join(" => "))
+    }
+}
+
+fn pi(params: &[String], codomain: String) -> String {
+    params
+        .iter()
+        .map(|param| format!("{param} -> "))
+        .chain([codomain])
+        .collect()
+}
+
+/// Returns the `PRIM_PRELUDE` numeral of `value`.
+fn numeral(value: u64) -> String {
+    if value == 0 {
+        return "N0".to_string();
+    }
+    // `xH` is the highest set bit, and each `xO` or `xI` around it
+    // appends the next lower bit.
+    let highest_bit = 63 - value.leading_zeros();
+    let pos = (0..highest_bit).rev().fold("xH".to_string(), |pos, bit| {
+        let constructor = if (value >> bit) & 1 == 1 { "xI" } else { "xO" };
+        format!("({constructor} {pos})")
+    });
+    format!("Npos {pos}")
+}
+
+fn sort(universe: Universe) -> String {
+    let mut level = "lz".to_string();
+    for _ in 0..universe.level.0 {
+        level = format!("(ls {level})");
+    }
+    if universe.erasable {
+        format!("(prop {level})")
+    } else {
+        format!("(set {level})")
+    }
+}
@@ -0,0 +1,508 @@
+use super::*;
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+};
+
+/// Identifies the declaration of a lifted ind or fun.
+pub(super) type LiftKey = (Vec<Digest>, Digest);
+
+/// An ind whose external subterms have been turned into params.
+#[derive(Clone, Debug)]
+pub(super) struct LiftedInd {
+    pub key: LiftKey,
+    /// `param_types[i]` is in a context of length `i`.
+    pub param_types: Vec<NormalForm>,
+    /// The normalized ind, in a context of length `param_types.len()`.
+    pub ind: RcHashed<Ind>,
+    /// The args the params are instantiated with
+    /// (in the original context).
+    pub args: Vec<Expr>,
+}
+
+/// A fun whose free debs have been turned into params.
+#[derive(Clone, Debug)]
+pub(super) struct LiftedFun {
+    pub key: LiftKey,
+    /// `param_types[i]` is in a context of length `i`.
+    pub param_types: Vec<NormalForm>,
+    /// The fun, in a context of length `param_types.len()`.
+    pub fun: RcHashed<Fun>,
+    /// The debs the params are instantiated with
+    /// (in the original context).
+    pub args: Vec<Expr>,
+}
+
+impl ExportCore {
+    /// Turns the free debs of `fun` into params.
+    ///
+    /// Unlike `lift_ind`, this only lifts free debs
+    /// (and the free debs of their types, and so on),
+    /// since abstracting a closed subterm of a fun
+    /// could make its body ill-typed
+    /// (e.g., if a proof in the body relies on the subterm reducing).
+    /// So a fun and its substitution instances usually get different
+    /// declarations.
+    pub(super) fn lift_fun(&mut self, fun: RcHashed<Fun>, context: &Context) -> LiftedFun {
+        let len = context.len();
+        let mut levels = BTreeSet::new();
+        let mut pending: Vec<usize> = free_debs(&Expr::Fun(fun.clone()))
+            .into_iter()
+            .map(|deb| len - 1 - deb)
+            .collect();
+        while let Some(level) = pending.pop() {
+            if levels.insert(level) {
+                pending.extend(
+                    free_debs(context.0[level].type_.raw())
+                        .into_iter()
+                        .map(|deb| level - 1 - deb),
+                );
+            }
+        }
+        let levels: Vec<usize> = levels.into_iter().collect();
+        let positions: BTreeMap<usize, usize> = levels
+            .iter()
+            .enumerate()
+            .map(|(position, &level)| (level, position))
+            .collect();
+
+        let param_types: Vec<NormalForm> = levels
+            .iter()
+            .enumerate()
+            .map(|(position, &level)| {
+                let type_ = context.0[level].type_.raw().clone();
+                self.eval(type_.replace_debs(
+                    &DebRelabeler {
+                        old_len: level,
+                        new_len: position,
+                        positions: &positions,
+                    },
+                    0,
+                ))
+            })
+            .collect();
+        let fun = match Expr::Fun(fun).replace_debs(
+            &DebRelabeler {
+                old_len: len,
+                new_len: levels.len(),
+                positions: &positions,
+            },
+            0,
+        ) {
+            Expr::Fun(fun) => fun,
+            other => unreachable!("a fun should relabel to a fun, but got {other:?}"),
+        };
+
+        let key = (
+            param_types
+                .iter()
+                .map(|type_| type_.raw().digest().clone())
+                .collect(),
+            fun.digest().clone(),
+        );
+        let args = levels
+            .into_iter()
+            .map(|level| {
+                Expr::Deb(rc_hashed(DebNode {
+                    deb: Deb(len - 1 - level),
+                    aux_data: (),
+                }))
+            })
+            .collect();
+        LiftedFun {
+            key,
+            param_types,
+            fun,
+            args,
+        }
+    }
+
+    /// Normalizes `ind`, and turns its external subterms into params.
+    ///
+    /// A subterm is external if it does not mention any of the ind's
+    /// own binders (i.e., its index binders, its self deb,
+    /// or its vcon params), and is not a universe.
+    /// Every maximal external subterm becomes a param
+    /// (with equal subterms sharing one param),
+    /// as does every free deb of the params' types.
+    ///
+    /// Lifting external subterms (rather than just free debs)
+    /// means that an ind and its substitution instances
+    /// (e.g., `(ind Set0 "List" () ((() ()) ((2 1) ())))` under a binder `T`,
+    /// and the same ind with `T` replaced by `Nat`)
+    /// usually get the same declaration, with different args.
+    /// This is what lets other proof assistants see that
+    /// `((fun (T) List) Nat)` is the same type as `List` with `T := Nat`.
+    /// The exceptions are substitutions that replace a deb with a universe,
+    /// or that make two different params equal.
+    pub(super) fn lift_ind(&mut self, ind: RcHashed<Ind>, context: &Context) -> LiftedInd {
+        let ind = match self.eval(Expr::Ind(ind)).into_raw() {
+            Expr::Ind(ind) => ind,
+            other => unreachable!("an ind should normalize to an ind, but got {other:?}"),
+        };
+
+        let mut externals = Externals::default();
+        map_ind_subterms(&ind, 0, &mut |subterm, cutoff| {
+            let external = external_part(subterm, cutoff)?;
+            externals.insert(external);
+            Some(subterm.clone())
+        });
+
+        // `externals` may grow while we iterate,
+        // since we also lift the free debs of the params' types.
+        let mut types = vec![];
+        let mut dependencies = vec![];
+        let mut i = 0;
+        while i < externals.exprs.len() {
+            let type_ = self
+                .get_type_of_subterm(&externals.exprs[i], context)
+                .into_raw();
+            let mut type_dependencies = BTreeSet::new();
+            map_subterms(&type_, 0, &mut |subterm, cutoff| {
+                let external = closed_part(subterm, cutoff)?;
+                if externals.get(&external).is_none() && !matches!(external, Expr::Deb(_)) {
+                    return None;
+                }
+                type_dependencies.insert(externals.insert(external));
+                Some(subterm.clone())
+            });
+            types.push(type_);
+            dependencies.push(type_dependencies);
+            i += 1;
+        }
+
+        let order = topological_order(&dependencies);
+        let mut positions = vec![0; order.len()];
+        for (position, &i) in order.iter().enumerate() {
+            positions[i] = position;
+        }
+        let param_deb = |externals: &Externals, external: &Expr, cutoff: usize, len: usize| {
+            let position = positions[externals.get(external)?];
+            Some(Expr::Deb(rc_hashed(DebNode {
+                deb: Deb(cutoff + len - 1 - position),
+                aux_data: (),
+            })))
+        };
+
+        let param_types: Vec<NormalForm> = order
+            .iter()
+            .enumerate()
+            .map(|(position, &i)| {
+                let type_ = map_subterms(&types[i], 0, &mut |subterm, cutoff| {
+                    let external = closed_part(subterm, cutoff)?;
+                    param_deb(&externals, &external, cutoff, position)
+                });
+                self.eval(type_)
+            })
+            .collect();
+
+        let param_count = order.len();
+        let ind = map_ind_subterms(&ind, 0, &mut |subterm, cutoff| {
+            let external = external_part(subterm, cutoff)?;
+            param_deb(&externals, &external, cutoff, param_count)
+        });
+        let ind = match self.eval(Expr::Ind(ind)).into_raw() {
+            Expr::Ind(ind) => ind,
+            other => unreachable!("an ind should normalize to an ind, but got {other:?}"),
+        };
+
+        let key = (
+            param_types
+                .iter()
+                .map(|type_| type_.raw().digest().clone())
+                .collect(),
+            ind.digest().clone(),
+        );
+        let args = order
+            .into_iter()
+            .map(|i| externals.exprs[i].clone())
+            .collect();
+        LiftedInd {
+            key,
+            param_types,
+            ind,
+            args,
+        }
+    }
+}
+
+/// Distinct exprs (in the original context), in insertion order.
+#[derive(Default)]
+struct Externals {
+    exprs: Vec<Expr>,
+    indices: BTreeMap<Digest, usize>,
+}
+
+impl Externals {
+    fn insert(&mut self, expr: Expr) -> usize {
+        let next_index = self.exprs.len();
+        let index = *self
+            .indices
+            .entry(expr.digest().clone())
+            .or_insert(next_index);
+        if index == next_index {
+            self.exprs.push(expr);
+        }
+        index
+    }
+
+    fn get(&self, expr: &Expr) -> Option<usize> {
+        self.indices.get(expr.digest()).copied()
+    }
+}
+
+/// Orders the params so that each param comes after
+/// the params its type depends on.
+fn topological_order(dependencies: &[BTreeSet<usize>]) -> Vec<usize> {
+    fn visit(
+        i: usize,
+        dependencies: &[BTreeSet<usize>],
+        visited: &mut [bool],
+        order: &mut Vec<usize>,
+    ) {
+        if visited[i] {
+            return;
+        }
+        visited[i] = true;
+        for &dependency in &dependencies[i] {
+            visit(dependency, dependencies, visited, order);
+        }
+        order.push(i);
+    }
+
+    let mut visited = vec![false; dependencies.len()];
+    let mut order = vec![];
+    for i in 0..dependencies.len() {
+        visit(i, dependencies, &mut visited, &mut order);
+    }
+    order
+}
+
+/// If `subterm` (which is under `cutoff` binders) is external,
+/// returns it in the original context.
+fn external_part(subterm: &Expr, cutoff: usize) -> Option<Expr> {
+    if let Expr::Universe(_) = subterm {
+        return None;
+    }
+    closed_part(subterm, cutoff)
+}
+
+/// If `subterm` (which is under `cutoff` binders)
+/// does not mention any of those binders,
+/// returns it in the context outside the binders.
+fn closed_part(subterm: &Expr, cutoff: usize) -> Option<Expr> {
+    if free_debs(subterm).into_iter().any(|deb| deb < cutoff) {
+        return None;
+    }
+    Some(subterm.clone().replace_debs(&DebDownshifter(cutoff), 0))
+}
+
+/// Returns the free debs of `expr` (relative to cutoff `0`).
+fn free_debs(expr: &Expr) -> Vec<usize> {
+    let collector = FreeDebCollector {
+        debs: RefCell::new(vec![]),
+    };
+    let _ = expr.clone().replace_debs(&collector, 0);
+    collector.debs.into_inner()
+}
+
+struct FreeDebCollector {
+    /// The free debs (relative to cutoff `0`).
+    debs: RefCell<Vec<usize>>,
+}
+
+impl DebReplacer for FreeDebCollector {
+    fn replace_deb(&self, original: RcHashed<DebNode>, cutoff: usize) -> Expr {
+        if let Some(deb) = original.hashee.deb.0.checked_sub(cutoff) {
+            self.debs.borrow_mut().push(deb);
+        }
+        Expr::Deb(original)
+    }
+}
+
+/// Downshifts the free debs by `self.0`.
+/// There must not be any free debs less than `self.0`.
+struct DebDownshifter(usize);
+
+impl DebReplacer for DebDownshifter {
+    fn replace_deb(&self, original: RcHashed<DebNode>, cutoff: usize) -> Expr {
+        if original.hashee.deb.0 < cutoff {
+            return Expr::Deb(original);
+        }
+
+        Expr::Deb(rc_hashed(DebNode {
+            deb: Deb(original.hashee.deb.0 - self.0),
+            aux_data: (),
+        }))
+    }
+}
+
+/// Moves the free debs from a context of length `old_len`
+/// to a context of length `new_len`,
+/// where the binder at level `level` in the old context
+/// is at level `positions[&level]` in the new one.
+struct DebRelabeler<'a> {
+    old_len: usize,
+    new_len: usize,
+    positions: &'a BTreeMap<usize, usize>,
+}
+
+impl DebReplacer for DebRelabeler<'_> {
+    fn replace_deb(&self, original: RcHashed<DebNode>, cutoff: usize) -> Expr {
+        if original.hashee.deb.0 < cutoff {
+            return Expr::Deb(original);
+        }
+
+        let level = self.old_len - 1 - (original.hashee.deb.0 - cutoff);
+        Expr::Deb(rc_hashed(DebNode {
+            deb: Deb(cutoff + self.new_len - 1 - self.positions[&level]),
+            aux_data: (),
+        }))
+    }
+}
+
+/// Visits the subterms of `expr` (outermost first),
+/// including `expr` itself.
+/// If `f` returns a replacement for a subterm,
+/// the subterm is replaced, and its own subterms are not visited.
+/// `f` also receives the number of binders the subterm is under
+/// (plus `cutoff`).
+fn map_subterms<F>(expr: &Expr, cutoff: usize, f: &mut F) -> Expr
+where
+    F: FnMut(&Expr, usize) -> Option<Expr>,
+{
+    if let Some(replacement) = f(expr, cutoff) {
+        return replacement;
+    }
+
+    match expr {
+        Expr::Ind(ind) => Expr::Ind(map_ind_subterms(ind, cutoff, f)),
+        Expr::Vcon(vcon) => Expr::Vcon(rc_hashed(Vcon {
+            ind: map_ind_subterms(&vcon.hashee.ind, cutoff, f),
+            vcon_index: vcon.hashee.vcon_index,
+            aux_data: (),
+        })),
+        Expr::Match(match_) => {
+            let match_ = &match_.hashee;
+            Expr::Match(rc_hashed(Match {
+                matchee: map_subterms(&match_.matchee, cutoff, f),
+                return_type_arity: match_.return_type_arity,
+                return_type: map_subterms(
+                    &match_.return_type,
+                    cutoff + match_.return_type_arity,
+                    f,
+                ),
+                cases: rc_hashed(
+                    match_
+                        .cases
+                        .hashee
+                        .iter()
+                        .map(|case| MatchCase {
+                            arity: case.arity,
+                            return_val: case
+                                .return_val
+                                .as_ref()
+                                .map(|return_val| map_subterms(return_val, cutoff + case.arity, f)),
+                            aux_data: (),
+                        })
+                        .collect(),
+                ),
+                aux_data: (),
+            }))
+        }
+        Expr::Fun(fun) => {
+            let fun = &fun.hashee;
+            let param_count = fun.param_types.hashee.len();
+            Expr::Fun(rc_hashed(Fun {
+                decreasing_index: fun.decreasing_index,
+                param_types: map_dependent_subterms(&fun.param_types, cutoff, f),
+                return_type: map_subterms(&fun.return_type, cutoff + param_count, f),
+                return_val: map_subterms(&fun.return_val, cutoff + param_count + 1, f),
+                aux_data: (),
+            }))
+        }
+        Expr::App(app) => Expr::App(rc_hashed(App {
+            callee: map_subterms(&app.hashee.callee, cutoff, f),
+            args: rc_hashed(
+                app.hashee
+                    .args
+                    .hashee
+                    .iter()
+                    .map(|arg| map_subterms(arg, cutoff, f))
+                    .collect(),
+            ),
+            aux_data: (),
+        })),
+        Expr::For(for_) => {
+            let for_ = &for_.hashee;
+            let param_count = for_.param_types.hashee.len();
+            Expr::For(rc_hashed(For {
+                param_types: map_dependent_subterms(&for_.param_types, cutoff, f),
+                return_type: map_subterms(&for_.return_type, cutoff + param_count, f),
+                aux_data: (),
+            }))
+        }
+        Expr::Deb(_)
+        | Expr::Universe(_)
+        | Expr::Lit(_)
+        | Expr::Prim(_)
+        | Expr::Meta(_)
+        | Expr::Hole(_) => expr.clone(),
+    }
+}
+
+/// Like `map_subterms`, but only visits the ind's
+/// index types and vcon defs (and not the ind itself).
+fn map_ind_subterms<F>(ind: &RcHashed<Ind>, cutoff: usize, f: &mut F) -> RcHashed<Ind>
+where
+    F: FnMut(&Expr, usize) -> Option<Expr>,
+{
+    let ind = &ind.hashee;
+    rc_hashed(Ind {
+        name: ind.name.clone(),
+        tag: ind.tag.clone(),
+        universe: ind.universe,
+        index_types: map_dependent_subterms(&ind.index_types, cutoff, f),
+        vcon_defs: rc_hashed(
+            ind.vcon_defs
+                .hashee
+                .iter()
+                .map(|def| {
+                    let param_count = def.param_types.hashee.len();
+                    VconDef {
+                        name: def.name.clone(),
+                        param_types: map_dependent_subterms(&def.param_types, cutoff + 1, f),
+                        index_args: rc_hashed(
+                            def.index_args
+                                .hashee
+                                .iter()
+                                .map(|arg| map_subterms(arg, cutoff + 1 + param_count, f))
+                                .collect(),
+                        ),
+                        aux_data: (),
+                    }
+                })
+                .collect(),
+        ),
+        aux_data: (),
+    })
+}
+
+fn map_dependent_subterms<F>(
+    exprs: &RcHashedVec<Expr>,
+    cutoff: usize,
+    f: &mut F,
+) -> RcHashedVec<Expr>
+where
+    F: FnMut(&Expr, usize) -> Option<Expr>,
+{
+    rc_hashed(
+        exprs
+            .hashee
+            .iter()
+            .enumerate()
+            .map(|(i, expr)| map_subterms(expr, cutoff + i, f))
+            .collect(),
+    )
+}
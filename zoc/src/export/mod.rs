@@ -0,0 +1,273 @@
+//! Exports well-typed `minimal_ast` terms to the languages of
+//! other proof assistants, so that their kernels can give us
+//! a second opinion on our proofs.
+//!
+//! Zo inds are ordinary terms that may appear anywhere
+//! (and may have free variables),
+//! whereas other proof assistants declare inductive types at the top level.
+//! So we hoist every ind to the top level,
+//! turning its external subterms (e.g., its free variables)
+//! into params of the declaration (see `ExportCore::lift_ind`).
+//! Occurrences of inds that are the same after lifting
+//! share one declaration.
+//! Targets without local recursive definitions
+//! hoist recursive funs in the same way
+//! (but only lift their free debs; see `ExportCore::lift_fun`).
+//!
+//! Debs are exported as names derived from their de Bruijn levels
+//! (i.e., the `i`th binder, counting from the outermost, is named `x<i>`),
+//! so a generated name never shadows another.
+
+use crate::{
+    eval::*,
+    hash::*,
+    syntax_tree::{ast::prelude::minimal_ast::*, replace_debs::*},
+    typecheck::*,
+};
+
+use std::collections::HashSet;
+
+mod coq;
+pub use coq::*;
+
+mod dedukti;
+pub use dedukti::*;
+
+mod lift;
+use lift::*;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug)]
+pub enum ExportError {
+    TypeError(TypeError<UnitAuxDataFamily>),
+    /// The term contains a meta or a hole.
+    UnelaboratedTerm,
+    /// The target language has no way to omit impossible match cases.
+    OmittedCase(RcHashed<Match>),
+}
+
+/// How a deb is exported.
+#[derive(Clone, Debug)]
+struct Binder {
+    /// The name of the binder.
+    /// The `ind` deb in a vcon def is exported as the
+    /// declaration's name applied to the declaration's params,
+    /// so we store the params separately in `args`,
+    /// which lets an app with a deb callee avoid nested apps.
+    head: String,
+    args: Vec<String>,
+    type_: NormalForm,
+}
+
+/// The binder at index `i` is the `i`th binder
+/// (counting from the outermost).
+#[derive(Clone, Debug, Default)]
+struct Context(Vec<Binder>);
+
+impl Context {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get(&self, deb: Deb) -> &Binder {
+        &self.0[self.0.len() - 1 - deb.0]
+    }
+
+    fn next_var_name(&self) -> String {
+        format!("x{}", self.0.len())
+    }
+
+    /// Returns a copy of `self` extended with a variable binder of type `type_`.
+    fn with_var(&self, type_: NormalForm) -> Context {
+        self.with(Binder {
+            head: self.next_var_name(),
+            args: vec![],
+            type_,
+        })
+    }
+
+    fn with(&self, binder: Binder) -> Context {
+        let mut extended = self.clone();
+        extended.0.push(binder);
+        extended
+    }
+}
+
+/// The binder types a match introduces.
+#[derive(Clone, Debug)]
+struct MatchBinders {
+    /// The normalized ind of the matchee's type.
+    ind: RcHashed<Ind>,
+    /// The types of the return type's binders
+    /// (the ind's index types, followed by the matchee's type).
+    return_type_binder_types: Vec<NormalForm>,
+    /// `case_param_types[i]` holds the param types of vcon `i`.
+    case_param_types: Vec<Vec<NormalForm>>,
+}
+
+/// The language-independent part of an exporter.
+#[derive(Default)]
+struct ExportCore {
+    typechecker: TypeChecker,
+}
+
+impl ExportCore {
+    fn eval(&mut self, expr: Expr) -> NormalForm {
+        self.typechecker.evaluator.eval(expr)
+    }
+
+    fn get_type(
+        &mut self,
+        expr: &Expr,
+        context: &Context,
+    ) -> Result<NormalForm, TypeError<UnitAuxDataFamily>> {
+        let types: Normalized<Vec<Expr>> = context.0.iter().map(|b| b.type_.clone()).collect();
+        let tcon = LazyTypeContext::Base(types.as_ref().convert_ref());
+        self.typechecker.get_type(expr.clone(), tcon)
+    }
+
+    /// Returns the type of `expr`,
+    /// which must be a subterm of a well-typed term.
+    fn get_type_of_subterm(&mut self, expr: &Expr, context: &Context) -> NormalForm {
+        self.get_type(expr, context)
+            .expect("a subterm of a well-typed term should be well-typed")
+    }
+
+    /// Returns the type of `fun`'s self deb,
+    /// in a context extended with `fun`'s params.
+    fn fun_self_type(&mut self, fun: &RcHashed<Fun>) -> NormalForm {
+        let type_ = Expr::For(rc_hashed(For {
+            param_types: fun.hashee.param_types.clone(),
+            return_type: fun.hashee.return_type.clone(),
+            aux_data: (),
+        }));
+        let param_count = fun.hashee.param_types.hashee.len();
+        self.eval(type_.replace_debs(&DebUpshifter(param_count), 0))
+    }
+
+    fn match_binders(&mut self, match_: &RcHashed<Match>, context: &Context) -> MatchBinders {
+        let matchee_type = self.get_type_of_subterm(&match_.hashee.matchee, context);
+        let (ind, _) = matchee_type
+            .ind_or_ind_app()
+            .expect("the matchee of a well-typed match should have an ind type");
+        let ind = ind.into_raw();
+
+        let index_types = ind.hashee.index_types.hashee.clone();
+        let index_count = index_types.len();
+        let mut return_type_binder_types: Vec<NormalForm> = index_types
+            .into_iter()
+            .map(|type_| self.eval(type_))
+            .collect();
+        let matchee_binder_type = App {
+            callee: Expr::Ind(ind.clone()).replace_debs(&DebUpshifter(index_count), 0),
+            args: rc_hashed(
+                (0..index_count)
+                    .rev()
+                    .map(|deb| {
+                        Expr::Deb(rc_hashed(DebNode {
+                            deb: Deb(deb),
+                            aux_data: (),
+                        }))
+                    })
+                    .collect(),
+            ),
+            aux_data: (),
+        }
+        .collapse_if_nullary();
+        return_type_binder_types.push(self.eval(matchee_binder_type));
+
+        let types: Normalized<Vec<Expr>> = context.0.iter().map(|b| b.type_.clone()).collect();
+        let tcon = LazyTypeContext::Base(types.as_ref().convert_ref());
+        let case_param_types = (0..ind.hashee.vcon_defs.hashee.len())
+            .map(|vcon_index| {
+                let vcon = rc_hashed(Vcon {
+                    ind: ind.clone(),
+                    vcon_index,
+                    aux_data: (),
+                });
+                let vcon_type = self
+                    .typechecker
+                    .get_type_of_vcon(vcon, tcon)
+                    .expect("the vcon of a well-typed ind should be well-typed");
+                vcon_type
+                    .for_param_types_or_empty_vec()
+                    .into_raw()
+                    .hashee
+                    .clone()
+                    .into_iter()
+                    .map(|type_| self.eval(type_))
+                    .collect()
+            })
+            .collect();
+
+        MatchBinders {
+            ind,
+            return_type_binder_types,
+            case_param_types,
+        }
+    }
+}
+
+/// Returns the type of `ind` (in the context `ind` is in).
+fn ind_type(ind: &Ind) -> Expr {
+    For {
+        param_types: ind.index_types.clone(),
+        return_type: Expr::Universe(rc_hashed(UniverseNode {
+            universe: ind.universe,
+            aux_data: (),
+        })),
+        aux_data: (),
+    }
+    .collapse_if_nullary()
+}
+
+/// Generates unique identifiers.
+#[derive(Clone, Debug, Default)]
+struct NameSet {
+    used: HashSet<String>,
+}
+
+impl NameSet {
+    /// Reserves `reserved` and the names of the prims.
+    fn with_reserved(reserved: &[&str]) -> Self {
+        NameSet {
+            used: reserved
+                .iter()
+                .copied()
+                .chain(Prim::ALL.map(Prim::name))
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    /// Returns an unused identifier based on `name`,
+    /// and marks it as used.
+    ///
+    /// The identifier only contains ASCII alphanumerics and underscores,
+    /// starts with a letter, and never has the form `<letter><digits>`
+    /// (which is reserved for local names, such as debs).
+    fn fresh(&mut self, name: &str) -> String {
+        let mut base: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        if !base.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            base.insert(0, 'I');
+        }
+        let is_local_name = base.len() > 1 && base[1..].bytes().all(|b| b.is_ascii_digit());
+        if is_local_name {
+            base.push('_');
+        }
+
+        let mut candidate = base.clone();
+        let mut suffix = 1;
+        while self.used.contains(&candidate) {
+            candidate = format!("{base}_{suffix}");
+            suffix += 1;
+        }
+        self.used.insert(candidate.clone());
+        candidate
+    }
+}
@@ -0,0 +1,28 @@
+use super::*;
+
+fn export_to_dedukti_or_panic(name: &str, src: &str) -> String {
+    let expr = parse_minimal_ast_or_panic(src);
+    match export_to_dedukti(name, &expr) {
+        Ok(dedukti) => dedukti,
+        Err(err) => panic!("failed to export {src}: {err:?}"),
+    }
+}
+
+#[test]
+fn examples() {
+    for (name, src) in EXAMPLES {
+        insta::assert_display_snapshot!(name, export_to_dedukti_or_panic(name, &src()));
+    }
+}
+
+#[test]
+fn omitted_case() {
+    let src = substitute_with_compounding(
+        nat_defs()
+            .into_iter()
+            .chain([("<EQ_0>", r#"(ind Prop0 "Eq0" (<NAT>) ((() (<0>))))"#)]),
+        "(fun nonrec ((<EQ_0> <1>)) U64 (match 1 2 U64 ((0))))",
+    );
+    let err = export_to_dedukti("omitted_case", &parse_minimal_ast_or_panic(&src)).unwrap_err();
+    assert!(matches!(err, ExportError::OmittedCase(_)), "{err:?}");
+}
@@ -0,0 +1,48 @@
+//! The examples from `crate::test_utils::examples`.
+//!
+//! The snapshots have not been checked by Coq or Dedukti;
+//! they only record the current output.
+
+use super::*;
+
+use crate::test_utils::{examples::*, *};
+
+mod coq;
+mod dedukti;
+
+/// The `(name, src)` pairs that each exporter is snapshot tested on.
+const EXAMPLES: [(&str, fn() -> String); 14] = [
+    ("add_2_3", add_2_3_src),
+    ("rev_1_2_3", rev_1_2_3_src),
+    ("polymorphic_rev_1_2_3", polymorphic_rev_1_2_3_src),
+    ("ex_falso", ex_falso_src),
+    ("eq_zero_one", eq_zero_one_src),
+    ("eq_one_zero", eq_one_zero_src),
+    ("eq_commutative", eq_commutative_src),
+    ("eq_transitive", eq_transitive_src),
+    ("precise", precise_src),
+    ("add_zero", add_zero_src),
+    ("add_succ", add_succ_src),
+    ("mutual", mutual_src),
+    ("prims", prims_src),
+    ("prim_computation", prim_computation_src),
+];
+
+fn prims_src() -> String {
+    r#"(fun nonrec (U64 String) U64 (u64_add (u64_mul 2 5u64) (string_len (string_concat 1 "ab"))))"#
+        .to_string()
+}
+
+/// A term that only typechecks because `(u64_add 2u64 3u64)` evaluates to `5u64`.
+fn prim_computation_src() -> String {
+    let eq_u64_def = (
+        "<EQ>",
+        r#"(ind Prop0 "Eq" (U64 U64) (
+    ((U64) (0 0))
+))"#,
+    );
+    substitute_with_compounding(
+        [eq_u64_def],
+        "((fun nonrec ((<EQ> (u64_add 2u64 3u64) 5u64)) U64 0u64) ((vcon <EQ> 0) 5u64))",
+    )
+}
@@ -0,0 +1,176 @@
+use super::*;
+
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    fmt::{self, Debug},
+    hash::{BuildHasherDefault, Hash, Hasher},
+    mem::size_of,
+};
+
+/// Hash-conses nodes, guaranteeing that there is at most
+/// one `Rc<Hashed<T>>` per digest (for each node type `T`).
+///
+/// `Interner` is a handle, so cloning it is cheap,
+/// and all clones share the same nodes.
+/// Passing clones of one interner to multiple components
+/// (e.g., a front end's converter, the `AuxDataRemover`,
+/// and the `Evaluator`) makes them share their nodes too.
+///
+/// Since `Rc`'s `PartialEq` impl checks pointer equality
+/// before comparing the pointees,
+/// comparing two interned nodes never needs to compare digests
+/// when the nodes are equal.
+///
+/// Interned nodes are kept alive for as long as the interner is.
+///
+/// With the `sync` feature, the state is behind a `Mutex`,
+/// so an interner can be shared between threads.
+#[derive(Clone, Default)]
+pub struct Interner(Rc<Lock<InternerState>>);
+
+#[cfg(not(feature = "sync"))]
+type Lock<T> = std::cell::RefCell<T>;
+
+#[cfg(feature = "sync")]
+type Lock<T> = std::sync::Mutex<T>;
+
+#[derive(Default)]
+struct InternerState {
+    /// Maps the `TypeId` of `T` to a `NodeTable<T>`.
+    tables: TableMap,
+    stats: InternerStats,
+}
+
+type TableMap = HashMap<TypeId, Box<DynAny>, BuildHasherDefault<TypeIdHasher>>;
+
+type NodeTable<T> = NoHashHashMap<Digest, Rc<Hashed<T>>>;
+
+/// `TypeId`s are already hashes, so we use them as-is
+/// instead of rehashing them with the default (and slow) hasher.
+#[derive(Default)]
+struct TypeIdHasher(u64);
+
+impl Hasher for TypeIdHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = self.0.rotate_left(8) ^ u64::from(byte);
+        }
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 ^= n;
+    }
+}
+
+/// Memory statistics for an `Interner`.
+///
+/// Sizes are shallow (i.e., they count `size_of::<Hashed<T>>()`,
+/// but not any heap data owned by `T`),
+/// which is exact for nodes whose children are all interned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InternerStats {
+    /// The number of distinct nodes.
+    pub nodes: usize,
+    /// The number of bytes used by the distinct nodes.
+    pub node_bytes: usize,
+    /// The number of times a node was replaced
+    /// by an existing node with the same digest.
+    pub hits: usize,
+    /// The number of bytes that would have been used
+    /// by the replaced nodes.
+    pub saved_bytes: usize,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[cfg(not(feature = "sync"))]
+    fn state(&self) -> std::cell::RefMut<'_, InternerState> {
+        self.0.borrow_mut()
+    }
+
+    #[cfg(feature = "sync")]
+    fn state(&self) -> std::sync::MutexGuard<'_, InternerState> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn stats(&self) -> InternerStats {
+        self.state().stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.stats().nodes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if `self` and `other` share the same nodes.
+    pub fn ptr_eq(&self, other: &Interner) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Returns the interned node equal to `value`,
+    /// allocating a new node only if there is none.
+    pub fn intern<T: 'static + Hash + MaybeSendSync>(&self, value: T) -> Rc<Hashed<T>> {
+        let hashed = Hashed::new(value);
+        if let Some(existing) = self.get(&hashed.digest) {
+            return existing;
+        }
+
+        self.insert(Rc::new(hashed))
+    }
+
+    /// Returns the interned node equal to `node`.
+    /// If there is none, `node` itself becomes the interned node.
+    pub fn intern_rc<T: 'static + MaybeSendSync>(&self, node: Rc<Hashed<T>>) -> Rc<Hashed<T>> {
+        if let Some(existing) = self.get(&node.digest) {
+            return existing;
+        }
+
+        self.insert(node)
+    }
+
+    /// Returns the interned node with the given digest, if there is one.
+    /// This counts as a hit.
+    pub fn get<T: 'static + MaybeSendSync>(&self, digest: &Digest) -> Option<Rc<Hashed<T>>> {
+        let mut state = self.state();
+        let existing = get_table::<T>(&mut state.tables).get(digest).cloned()?;
+        state.stats.hits += 1;
+        state.stats.saved_bytes += size_of::<Hashed<T>>();
+        Some(existing)
+    }
+
+    /// Inserts `node` without checking whether
+    /// there is already an interned node with the same digest.
+    /// Callers should check with `get` first.
+    pub(crate) fn insert<T: 'static + MaybeSendSync>(&self, node: Rc<Hashed<T>>) -> Rc<Hashed<T>> {
+        let mut state = self.state();
+        get_table::<T>(&mut state.tables).insert(node.digest.clone(), node.clone());
+        state.stats.nodes += 1;
+        state.stats.node_bytes += size_of::<Hashed<T>>();
+        node
+    }
+}
+
+fn get_table<T: 'static + MaybeSendSync>(tables: &mut TableMap) -> &mut NodeTable<T> {
+    tables
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::new(NodeTable::<T>::default()))
+        .downcast_mut()
+        .expect("the table for `T` should be keyed by `T`'s `TypeId`")
+}
+
+impl Debug for Interner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Interner").field(&self.stats()).finish()
+    }
+}
@@ -9,5 +9,14 @@ pub use hashed::*;
 mod hasher;
 pub use hasher::*;
 
+mod interner;
+pub use interner::*;
+
 mod nohash_hashmap;
 pub use nohash_hashmap::*;
+
+mod shared_ptr;
+pub use shared_ptr::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,41 @@
+//! The pointer type used to share hashed nodes.
+//!
+//! By default, this is `std::rc::Rc`.
+//! With the `sync` feature, it is `std::sync::Arc` instead,
+//! so that expressions (and their types) can be sent between threads.
+//!
+//! The name `Rc` is kept in both cases,
+//! so code that is agnostic to the pointer type
+//! (which is almost all code) does not need to change.
+
+#[cfg(not(feature = "sync"))]
+pub use std::rc::Rc;
+
+#[cfg(feature = "sync")]
+pub use std::sync::Arc as Rc;
+
+/// With the `sync` feature, this is `Send + Sync`.
+/// Otherwise, every type implements it.
+///
+/// Values that are stored alongside shared nodes
+/// (e.g., aux data, and the entries of an `Interner`)
+/// must implement this.
+#[cfg(not(feature = "sync"))]
+pub trait MaybeSendSync {}
+
+#[cfg(not(feature = "sync"))]
+impl<T: ?Sized> MaybeSendSync for T {}
+
+#[cfg(feature = "sync")]
+pub trait MaybeSendSync: Send + Sync {}
+
+#[cfg(feature = "sync")]
+impl<T: ?Sized + Send + Sync> MaybeSendSync for T {}
+
+/// A type-erased value.
+/// With the `sync` feature, it is `Send + Sync`.
+#[cfg(not(feature = "sync"))]
+pub type DynAny = dyn std::any::Any;
+
+#[cfg(feature = "sync")]
+pub type DynAny = dyn std::any::Any + Send + Sync;
@@ -0,0 +1,144 @@
+use super::*;
+
+use crate::{
+    eval::Evaluator,
+    syntax_tree::{
+        ast::{mint_ind_tag, prelude::*},
+        remove_ast_aux_data::AuxDataRemover,
+    },
+    test_utils::*,
+    typecheck::TypeChecker,
+};
+
+const BOOL_DEF: (&str, &str) = (
+    "<BOOL>",
+    r#"(ind Set0 "Bool" () (
+    (() ())
+    (() ())
+))"#,
+);
+
+fn parse_with_bool(unsubstituted_src: &str) -> minimal_ast::Expr {
+    parse_minimal_ast_or_panic(&substitute_with_compounding([BOOL_DEF], unsubstituted_src))
+}
+
+#[test]
+fn equal_values_are_interned_once() {
+    let interner = Interner::new();
+    let a = interner.intern(String::from("foo"));
+    let b = interner.intern(String::from("foo"));
+    let c = interner.intern(String::from("bar"));
+
+    assert!(Rc::ptr_eq(&a, &b));
+    assert!(!Rc::ptr_eq(&a, &c));
+
+    let stats = interner.stats();
+    assert_eq!(2, stats.nodes);
+    assert_eq!(1, stats.hits);
+    assert_eq!(2 * std::mem::size_of::<Hashed<String>>(), stats.node_bytes);
+    assert_eq!(std::mem::size_of::<Hashed<String>>(), stats.saved_bytes);
+}
+
+#[test]
+fn clones_share_nodes() {
+    let interner = Interner::new();
+    let clone = interner.clone();
+    let a = interner.intern(String::from("foo"));
+    let b = clone.intern(String::from("foo"));
+
+    assert!(Rc::ptr_eq(&a, &b));
+    assert!(interner.ptr_eq(&clone));
+    assert!(!interner.ptr_eq(&Interner::new()));
+}
+
+#[test]
+fn intern_expr_shares_equal_subtrees() {
+    let interner = Interner::new();
+    let first = interner.intern_expr(parse_with_bool("(for (<BOOL>) <BOOL>)"));
+    let second = interner.intern_expr(parse_with_bool("(for (<BOOL>) <BOOL>)"));
+
+    let (ast::Expr::For(first), ast::Expr::For(second)) = (first, second) else {
+        panic!("expected `for` expressions");
+    };
+    assert!(Rc::ptr_eq(&first, &second));
+
+    let param_type = &first.hashee.param_types.hashee[0];
+    let return_type = &first.hashee.return_type;
+    let (ast::Expr::Ind(param_type), ast::Expr::Ind(return_type)) = (param_type, return_type)
+    else {
+        panic!("expected `ind` expressions");
+    };
+    assert!(Rc::ptr_eq(param_type, return_type));
+}
+
+#[test]
+fn intern_expr_preserves_digests() {
+    let interner = Interner::new();
+    let expr = parse_with_bool("((fun nonrec (<BOOL>) <BOOL> 1) (vcon <BOOL> 0))");
+    let interned = interner.intern_expr(expr.clone());
+
+    assert_eq!(expr.digest(), interned.digest());
+}
+
+#[test]
+fn aux_data_remover_and_evaluator_share_nodes() {
+    let interner = Interner::new();
+    let mut aux_remover = AuxDataRemover::with_interner(interner.clone());
+    let mut evaluator = Evaluator::with_interner(interner.clone());
+
+    let src = substitute_with_compounding([BOOL_DEF], "(vcon <BOOL> 0)");
+    let removed = aux_remover.convert(parse_spanned_ast_or_panic(&src));
+    let normalized = evaluator.eval(removed.clone()).into_raw();
+
+    let (ast::Expr::Vcon(removed), ast::Expr::Vcon(normalized)) = (removed, normalized) else {
+        panic!("expected `vcon` expressions");
+    };
+    assert!(Rc::ptr_eq(&removed, &normalized));
+}
+
+#[test]
+fn default_typechecker_shares_one_interner() {
+    let typechecker = TypeChecker::default();
+    assert!(typechecker
+        .evaluator
+        .interner
+        .ptr_eq(typechecker.aux_remover.interner()));
+}
+
+#[test]
+fn vcon_names_affect_digest() {
+    let named =
+        parse_minimal_ast_or_panic(r#"(ind Set0 "Bool" () (("true" () ()) ("false" () ())))"#);
+    let swapped =
+        parse_minimal_ast_or_panic(r#"(ind Set0 "Bool" () (("false" () ()) ("true" () ())))"#);
+    let unnamed = parse_with_bool("<BOOL>");
+
+    assert_ne!(named.digest(), unnamed.digest());
+    assert_ne!(named.digest(), swapped.digest());
+}
+
+#[test]
+fn ind_tags_affect_digest() {
+    let untagged = parse_with_bool("<BOOL>");
+    let tagged_a = parse_minimal_ast_or_panic(r#"(ind Set0 "Bool" "a" () ((() ()) (() ())))"#);
+    let tagged_b = parse_minimal_ast_or_panic(r#"(ind Set0 "Bool" "b" () ((() ()) (() ())))"#);
+
+    assert_ne!(untagged.digest(), tagged_a.digest());
+    assert_ne!(tagged_a.digest(), tagged_b.digest());
+}
+
+#[test]
+fn minted_ind_tags_are_deterministic_and_positional() {
+    let tag = mint_ind_tag("a.may", "Meters", 0);
+    assert_eq!(tag, mint_ind_tag("a.may", "Meters", 0));
+    assert_ne!(tag, mint_ind_tag("a.may", "Meters", 1));
+    assert_ne!(tag, mint_ind_tag("a.may", "Seconds", 0));
+}
+
+#[test]
+fn minted_ind_tags_differ_between_sources() {
+    assert_ne!(
+        mint_ind_tag("a.may", "Meters", 0),
+        mint_ind_tag("b.may", "Meters", 0)
+    );
+}
@@ -0,0 +1,126 @@
+//! A minimal JSON value model and writer,
+//! shared by diagnostics and the expr interchange encodings.
+
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+/// A minimal JSON value.
+///
+/// Object keys are kept in insertion order,
+/// so the output is deterministic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    /// JSON only needs non-negative integers here.
+    Number(u64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub fn object<K: Into<String>>(entries: impl IntoIterator<Item = (K, JsonValue)>) -> Self {
+        JsonValue::Object(
+            entries
+                .into_iter()
+                .map(|(key, val)| (key.into(), val))
+                .collect(),
+        )
+    }
+
+    pub fn string(s: impl Into<String>) -> Self {
+        JsonValue::String(s.into())
+    }
+
+    pub fn opt_string(s: Option<impl Into<String>>) -> Self {
+        match s {
+            Some(s) => JsonValue::String(s.into()),
+            None => JsonValue::Null,
+        }
+    }
+}
+
+/// `{}` prints compact JSON.
+/// `{:#}` prints JSON indented with two spaces.
+impl Display for JsonValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let indent = if f.alternate() { Some(0) } else { None };
+        write_value(self, indent, f)
+    }
+}
+
+fn write_value(val: &JsonValue, indent: Option<usize>, f: &mut Formatter<'_>) -> FmtResult {
+    match val {
+        JsonValue::Null => write!(f, "null"),
+        JsonValue::Bool(b) => write!(f, "{b}"),
+        JsonValue::Number(n) => write!(f, "{n}"),
+        JsonValue::String(s) => write_string(s, f),
+
+        JsonValue::Array(items) => {
+            if items.is_empty() {
+                return write!(f, "[]");
+            }
+
+            write!(f, "[")?;
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write_newline_and_indent(indent.map(|n| n + 1), f)?;
+                write_value(item, indent.map(|n| n + 1), f)?;
+            }
+            write_newline_and_indent(indent, f)?;
+            write!(f, "]")
+        }
+
+        JsonValue::Object(entries) => {
+            if entries.is_empty() {
+                return write!(f, "{{}}");
+            }
+
+            write!(f, "{{")?;
+            for (i, (key, val)) in entries.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write_newline_and_indent(indent.map(|n| n + 1), f)?;
+                write_string(key, f)?;
+                write!(f, ":")?;
+                if indent.is_some() {
+                    write!(f, " ")?;
+                }
+                write_value(val, indent.map(|n| n + 1), f)?;
+            }
+            write_newline_and_indent(indent, f)?;
+            write!(f, "}}")
+        }
+    }
+}
+
+fn write_newline_and_indent(indent: Option<usize>, f: &mut Formatter<'_>) -> FmtResult {
+    let Some(indent) = indent else {
+        return Ok(());
+    };
+
+    writeln!(f)?;
+    for _ in 0..indent {
+        write!(f, "  ")?;
+    }
+    Ok(())
+}
+
+fn write_string(s: &str, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
@@ -0,0 +1,265 @@
+use super::*;
+
+use crate::{
+    pretty_print::PrettyPrint,
+    syntax_tree::{parser::*, remove_ast_aux_data::AuxDataRemover},
+};
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+pub const CERTIFICATE_HEADER: &str = "zo-certificate v1";
+
+/// A list of closed definitions, each with its digest and claimed type.
+///
+/// The text format is line-based:
+///
+/// ```text
+/// zo-certificate v1
+///
+/// def <name>
+/// digest <digest>
+/// term
+/// <Zo source of the term>
+/// type
+/// <Zo source of the claimed type>
+/// end
+/// ```
+///
+/// with any number of `def` blocks.
+/// A certificate is checked entirely by the kernel
+/// (see `Certificate::check`), so an auditor does not need
+/// to trust `TypeChecker` or the May and June compilers.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct Certificate {
+    pub entries: Vec<CertificateEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CertificateEntry {
+    pub name: String,
+    /// The `Debug` representation of the term's digest
+    /// (e.g., `0x1234...`).
+    pub digest: String,
+    pub term_src: String,
+    pub type_src: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CertificateError {
+    /// The certificate does not follow the format
+    /// described in `Certificate`.
+    /// `line` is 1-based.
+    /// If the certificate ends too early,
+    /// `line` is its last line.
+    Malformed {
+        line: usize,
+        message: &'static str,
+    },
+    Syntax {
+        name: String,
+        err: Box<SyntaxError>,
+    },
+    DigestMismatch {
+        name: String,
+        claimed: String,
+        actual: String,
+    },
+    Kernel {
+        name: String,
+        err: KernelError,
+    },
+    TypeMismatch {
+        name: String,
+        claimed_type: Term,
+        actual_type: Term,
+    },
+}
+
+impl CertificateEntry {
+    /// Returns an entry claiming that `term` has type `type_`.
+    pub fn new(name: String, term: &minimal_ast::Expr, type_: &minimal_ast::Expr) -> Self {
+        CertificateEntry {
+            name,
+            digest: format!("{:?}", term.digest()),
+            term_src: PrettyPrint(term).to_string(),
+            type_src: PrettyPrint(type_).to_string(),
+        }
+    }
+
+    /// Checks that the term has the claimed digest,
+    /// and that the kernel infers the claimed type.
+    pub fn check(&self) -> Result<(), CertificateError> {
+        let term = self.parse(&self.term_src)?;
+        let actual_digest = format!("{:?}", term.digest());
+        if actual_digest != self.digest {
+            return Err(CertificateError::DigestMismatch {
+                name: self.name.clone(),
+                claimed: self.digest.clone(),
+                actual: actual_digest,
+            });
+        }
+
+        let actual_type = check_closed_minimal(&term).map_err(|err| self.kernel_error(err))?;
+
+        let claimed_type = Term::from_minimal(&self.parse(&self.type_src)?)
+            .map_err(|err| self.kernel_error(err))?;
+        match check_closed(&claimed_type).map_err(|err| self.kernel_error(err))? {
+            Term::Universe(_) => {}
+            type_ => {
+                return Err(self.kernel_error(KernelError::NotAType {
+                    term: claimed_type,
+                    type_,
+                }))
+            }
+        }
+
+        let claimed_type = claimed_type.normalize();
+        if claimed_type != actual_type {
+            return Err(CertificateError::TypeMismatch {
+                name: self.name.clone(),
+                claimed_type,
+                actual_type,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn parse(&self, src: &str) -> Result<minimal_ast::Expr, CertificateError> {
+        let (cst, errors) = parse_src_with_recovery(src);
+        if let Some(err) = errors.into_iter().next() {
+            return Err(CertificateError::Syntax {
+                name: self.name.clone(),
+                err: Box::new(err),
+            });
+        }
+        let spanned: spanned_ast::Expr = cst.into();
+        Ok(AuxDataRemover::default().convert(spanned))
+    }
+
+    fn kernel_error(&self, err: KernelError) -> CertificateError {
+        CertificateError::Kernel {
+            name: self.name.clone(),
+            err,
+        }
+    }
+}
+
+impl Certificate {
+    /// Checks every entry, stopping at the first error.
+    pub fn check(&self) -> Result<(), CertificateError> {
+        self.entries.iter().try_for_each(CertificateEntry::check)
+    }
+
+    pub fn parse(src: &str) -> Result<Self, CertificateError> {
+        let mut lines = Lines {
+            lines: src.lines().enumerate(),
+            line_count: src.lines().count(),
+        };
+
+        let (line, header) = lines.next_nonblank().ok_or(CertificateError::Malformed {
+            line: lines.line_count.max(1),
+            message: "expected a header",
+        })?;
+        if header != CERTIFICATE_HEADER {
+            return Err(CertificateError::Malformed {
+                line,
+                message: "expected `zo-certificate v1`",
+            });
+        }
+
+        let mut entries = vec![];
+        while let Some((line, def)) = lines.next_nonblank() {
+            let name = def
+                .strip_prefix("def ")
+                .ok_or(CertificateError::Malformed {
+                    line,
+                    message: "expected `def <name>`",
+                })?
+                .trim()
+                .to_string();
+            let digest = lines
+                .next_with_prefix("digest ", "expected `digest <digest>`")?
+                .trim()
+                .to_string();
+            lines.next_with_prefix("term", "expected `term`")?;
+            let term_src = lines.collect_until("type")?;
+            let type_src = lines.collect_until("end")?;
+            entries.push(CertificateEntry {
+                name,
+                digest,
+                term_src,
+                type_src,
+            });
+        }
+
+        Ok(Certificate { entries })
+    }
+}
+
+struct Lines<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    line_count: usize,
+}
+
+impl<'a> Lines<'a> {
+    /// Returns the next line that is not blank, with its 1-based line number.
+    fn next_nonblank(&mut self) -> Option<(usize, &'a str)> {
+        self.lines
+            .by_ref()
+            .map(|(i, line)| (i + 1, line.trim_end()))
+            .find(|(_, line)| !line.trim().is_empty())
+    }
+
+    fn next_with_prefix(
+        &mut self,
+        prefix: &str,
+        message: &'static str,
+    ) -> Result<&'a str, CertificateError> {
+        let (line, text) = self.lines.next().ok_or_else(|| self.unexpected_end())?;
+        text.trim_end()
+            .strip_prefix(prefix)
+            .ok_or(CertificateError::Malformed {
+                line: line + 1,
+                message,
+            })
+    }
+
+    /// Joins the lines before the line `terminator`.
+    fn collect_until(&mut self, terminator: &str) -> Result<String, CertificateError> {
+        let mut collected = vec![];
+        for (_, line) in self.lines.by_ref() {
+            if line.trim_end() == terminator {
+                return Ok(collected.join("\n"));
+            }
+            collected.push(line);
+        }
+        Err(self.unexpected_end())
+    }
+
+    /// Reports the end of the certificate at its last line
+    /// (or at line 1, if the certificate is empty).
+    fn unexpected_end(&self) -> CertificateError {
+        CertificateError::Malformed {
+            line: self.line_count.max(1),
+            message: "unexpected end of certificate",
+        }
+    }
+}
+
+impl Display for Certificate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "{CERTIFICATE_HEADER}")?;
+        for entry in &self.entries {
+            writeln!(f)?;
+            writeln!(f, "def {}", entry.name)?;
+            writeln!(f, "digest {}", entry.digest)?;
+            writeln!(f, "term")?;
+            writeln!(f, "{}", entry.term_src)?;
+            writeln!(f, "type")?;
+            writeln!(f, "{}", entry.type_src)?;
+            writeln!(f, "end")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,455 @@
+use super::*;
+
+/// The types of the debs in scope.
+/// The entry at index `i` is the type of the `i`th binder
+/// (counting from the outermost),
+/// and lives in a context of length `i`.
+#[derive(Clone, Debug, Default)]
+pub(super) struct Context(Vec<Term>);
+
+impl Context {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get(&self, deb: usize) -> Option<Term> {
+        let level = self.len().checked_sub(deb + 1)?;
+        Some(self.0[level].upshift(deb + 1, 0))
+    }
+
+    fn with(&self, types: impl IntoIterator<Item = Term>) -> Context {
+        let mut extended = self.clone();
+        extended.0.extend(types);
+        extended
+    }
+}
+
+/// Returns the normal form of the type of `term`.
+pub(super) fn infer(term: &Term, ctx: &Context) -> Result<Term, KernelError> {
+    match term {
+        Term::Ind(ind) => infer_ind(ind, ctx),
+        Term::Vcon(ind, vcon_index) => {
+            infer_ind(ind, ctx)?;
+            let ind = ind.normalize();
+            if *vcon_index >= ind.vcon_defs.len() {
+                return Err(KernelError::InvalidVconIndex(term.clone()));
+            }
+            Ok(vcon_type(&ind, *vcon_index))
+        }
+        Term::Match(match_) => infer_match(match_, ctx),
+        Term::Fun(fun) => infer_fun(fun, ctx),
+        Term::App(callee, args) => infer_app(callee, args, ctx),
+        Term::For(param_types, return_type) => {
+            if param_types.is_empty() {
+                return Err(KernelError::EmptyParamList(term.clone()));
+            }
+            let (param_universes, ctx) = check_param_types(param_types, ctx)?;
+            let return_universe = infer_universe(return_type, &ctx)?;
+            let level = param_universes
+                .iter()
+                .map(|universe| universe.level)
+                .fold(return_universe.level, Ord::max);
+            Ok(Term::Universe(Universe {
+                level,
+                erasable: return_universe.erasable,
+            }))
+        }
+        Term::Deb(deb) => ctx.get(*deb).ok_or(KernelError::InvalidDeb(*deb)),
+        Term::Universe(universe) => Ok(Term::Universe(Universe {
+            level: UniverseLevel(universe.level.0 + 1),
+            erasable: true,
+        })),
+        Term::Lit(Lit::U64(_)) => Ok(Term::Prim(Prim::U64)),
+        Term::Lit(Lit::String(_)) => Ok(Term::Prim(Prim::String)),
+        Term::Prim(prim) => Ok(prim_type(*prim)),
+    }
+}
+
+fn check(term: &Term, expected_type: &Term, ctx: &Context) -> Result<(), KernelError> {
+    let actual_type = infer(term, ctx)?;
+    if actual_type != *expected_type {
+        return Err(KernelError::TypeMismatch {
+            term: term.clone(),
+            expected_type: expected_type.clone(),
+            actual_type,
+        });
+    }
+    Ok(())
+}
+
+fn infer_universe(term: &Term, ctx: &Context) -> Result<Universe, KernelError> {
+    match infer(term, ctx)? {
+        Term::Universe(universe) => Ok(universe),
+        type_ => Err(KernelError::NotAType {
+            term: term.clone(),
+            type_,
+        }),
+    }
+}
+
+/// Checks that each param type is a type
+/// (under the params before it).
+/// Returns the param types' universes,
+/// and `ctx` extended with the (normalized) param types.
+fn check_param_types(
+    param_types: &[Term],
+    ctx: &Context,
+) -> Result<(Vec<Universe>, Context), KernelError> {
+    let mut universes = Vec::with_capacity(param_types.len());
+    let mut ctx = ctx.clone();
+    for param_type in param_types {
+        universes.push(infer_universe(param_type, &ctx)?);
+        ctx = ctx.with([param_type.normalize()]);
+    }
+    Ok((universes, ctx))
+}
+
+fn infer_ind(ind: &Ind, ctx: &Context) -> Result<Term, KernelError> {
+    let (index_universes, _) = check_param_types(&ind.index_types, ctx)?;
+    assert_within_ind_universe(ind, &index_universes)?;
+
+    let index_types = normalize_terms(&ind.index_types);
+    let ind_type = Term::for_or_return_type(index_types.clone(), Term::Universe(ind.universe));
+
+    let ctx_with_ind = ctx.with([ind_type.clone()]);
+    for def in &ind.vcon_defs {
+        check_vcon_def(ind, def, &index_types, &ctx_with_ind)?;
+    }
+
+    check_positivity(ind)?;
+
+    Ok(ind_type)
+}
+
+fn check_vcon_def(
+    ind: &Ind,
+    def: &VconDef,
+    index_types: &[Term],
+    ctx_with_ind: &Context,
+) -> Result<(), KernelError> {
+    if def.index_args.len() != index_types.len() {
+        return Err(KernelError::WrongNumberOfIndexArgs(Rc::new(def.clone())));
+    }
+
+    let (param_universes, ctx_with_params) = check_param_types(&def.param_types, ctx_with_ind)?;
+    assert_within_ind_universe(ind, &param_universes)?;
+
+    let shift = 1 + def.param_types.len();
+    let index_args = normalize_terms(&def.index_args);
+    for (i, index_arg) in def.index_args.iter().enumerate() {
+        let expected_type = index_types[i]
+            .upshift(shift, i)
+            .substitute(&index_args[..i])
+            .normalize();
+        check(index_arg, &expected_type, &ctx_with_params)?;
+    }
+
+    Ok(())
+}
+
+fn assert_within_ind_universe(ind: &Ind, universes: &[Universe]) -> Result<(), KernelError> {
+    if universes
+        .iter()
+        .any(|universe| universe.level > ind.universe.level)
+    {
+        return Err(KernelError::UniverseInconsistency(Rc::new(ind.clone())));
+    }
+    Ok(())
+}
+
+/// The ind may only appear in a vcon def param type
+/// as the return type of the param type
+/// (i.e., as `I`, `(I args)`, `(for (params) I)`, or `(for (params) (I args))`),
+/// where the args and params do not mention the ind.
+/// It may not appear in the index args.
+///
+/// This is stricter than `PositivityChecker`,
+/// which also accepts nested occurrences.
+fn check_positivity(ind: &Ind) -> Result<(), KernelError> {
+    for def in &ind.vcon_defs {
+        for (i, param_type) in def.param_types.iter().enumerate() {
+            if !is_strictly_positive(&param_type.normalize(), i) {
+                return Err(KernelError::NonPositiveInd(Rc::new(ind.clone())));
+            }
+        }
+
+        let self_deb = def.param_types.len();
+        if def
+            .index_args
+            .iter()
+            .any(|arg| arg.normalize().contains_deb(self_deb))
+        {
+            return Err(KernelError::NonPositiveInd(Rc::new(ind.clone())));
+        }
+    }
+    Ok(())
+}
+
+fn is_strictly_positive(param_type: &Term, self_deb: usize) -> bool {
+    if !param_type.contains_deb(self_deb) {
+        return true;
+    }
+
+    let (return_type, self_deb) = match param_type {
+        Term::For(param_types, return_type) => {
+            let params_mention_self = param_types
+                .iter()
+                .enumerate()
+                .any(|(i, param_type)| param_type.contains_deb(self_deb + i));
+            if params_mention_self {
+                return false;
+            }
+            (&**return_type, self_deb + param_types.len())
+        }
+        _ => (param_type, self_deb),
+    };
+
+    match return_type {
+        Term::Deb(deb) => *deb == self_deb,
+        Term::App(callee, args) => {
+            **callee == Term::Deb(self_deb) && args.iter().all(|arg| !arg.contains_deb(self_deb))
+        }
+        _ => false,
+    }
+}
+
+/// `ind` must be normalized.
+fn vcon_type(ind: &Ind, vcon_index: usize) -> Term {
+    let def = &ind.vcon_defs[vcon_index];
+    let ind_term = Term::Ind(Rc::new(ind.clone()));
+
+    let param_types = def
+        .param_types
+        .iter()
+        .enumerate()
+        .map(|(i, param_type)| param_type.replace_deb(i, &ind_term))
+        .collect::<Vec<_>>();
+
+    let param_count = def.param_types.len();
+    let index_args = def
+        .index_args
+        .iter()
+        .map(|index_arg| index_arg.replace_deb(param_count, &ind_term))
+        .collect::<Vec<_>>();
+
+    let return_type = Term::app_or_callee(Term::Ind(Rc::new(ind.upshift(param_count))), index_args);
+    Term::for_or_return_type(param_types, return_type).normalize()
+}
+
+fn infer_match(match_: &Match, ctx: &Context) -> Result<Term, KernelError> {
+    let matchee_type = infer(&match_.matchee, ctx)?;
+    let Some((ind, matchee_type_args)) = matchee_type.as_ind_or_ind_app() else {
+        return Err(KernelError::NonInductiveMatchee(Rc::new(match_.clone())));
+    };
+
+    if match_.cases.len() != ind.vcon_defs.len()
+        || match_.return_type_arity != 1 + ind.index_types.len()
+    {
+        return Err(KernelError::MalformedMatch(Rc::new(match_.clone())));
+    }
+
+    let index_count = ind.index_types.len();
+    let matchee_type_g1 = Term::app_or_callee(
+        Term::Ind(Rc::new(ind.upshift(index_count))),
+        (0..index_count).rev().map(Term::Deb).collect(),
+    );
+    let return_type_ctx = check_param_types(&ind.index_types, ctx)?
+        .1
+        .with([matchee_type_g1]);
+    infer_universe(&match_.return_type, &return_type_ctx)?;
+
+    for (vcon_index, case) in match_.cases.iter().enumerate() {
+        check_match_case(match_, ind, matchee_type_args, vcon_index, case, ctx)?;
+    }
+
+    let new_terms: Vec<Term> = matchee_type_args
+        .iter()
+        .cloned()
+        .chain(std::iter::once(match_.matchee.normalize()))
+        .collect();
+    Ok(match_.return_type.substitute(&new_terms).normalize())
+}
+
+fn check_match_case(
+    match_: &Match,
+    ind: &Ind,
+    matchee_type_args: &[Term],
+    vcon_index: usize,
+    case: &MatchCase,
+    ctx: &Context,
+) -> Result<(), KernelError> {
+    let vcon_type = vcon_type(ind, vcon_index);
+    let (param_types, vcon_return_type) = match &vcon_type {
+        Term::For(param_types, return_type) => (param_types.to_vec(), (**return_type).clone()),
+        _ => (vec![], vcon_type.clone()),
+    };
+    if case.arity != param_types.len() {
+        return Err(KernelError::MalformedMatch(Rc::new(match_.clone())));
+    }
+
+    let vcon_index_args = match vcon_return_type.as_ind_or_ind_app() {
+        Some((_, args)) => args.to_vec(),
+        None => vec![],
+    };
+
+    let Some(return_val) = &case.return_val else {
+        let mut solutions = vec![None; case.arity];
+        let is_impossible =
+            vcon_index_args
+                .iter()
+                .zip(matchee_type_args)
+                .any(|(vcon_arg, matchee_arg)| {
+                    is_conflict(
+                        vcon_arg,
+                        &matchee_arg.upshift(case.arity, 0),
+                        &mut solutions,
+                    )
+                });
+        if !is_impossible {
+            return Err(KernelError::OmittedPossibleCase(Rc::new(match_.clone())));
+        }
+        return Ok(());
+    };
+
+    let vcon_capp = Term::app_or_callee(
+        Term::Vcon(Rc::new(ind.upshift(case.arity)), vcon_index),
+        (0..case.arity).rev().map(Term::Deb).collect(),
+    );
+    let new_terms: Vec<Term> = vcon_index_args
+        .into_iter()
+        .chain(std::iter::once(vcon_capp))
+        .collect();
+    let expected_type = match_
+        .return_type
+        .upshift(case.arity, match_.return_type_arity)
+        .substitute(&new_terms)
+        .normalize();
+
+    let (_, ctx_with_params) = check_param_types(&param_types, ctx)?;
+    check(return_val, &expected_type, &ctx_with_params)
+}
+
+/// Returns whether `pattern` (a vcon index arg,
+/// whose case params are the debs below `solutions.len()`)
+/// can never equal `term` (a matchee type index arg,
+/// which does not mention the case params).
+/// Both must be normal forms.
+///
+/// A case param is solved as the first term it is compared with,
+/// and its solution is compared with any later terms.
+/// Otherwise, only different vcons of the same ind
+/// (or different literals) conflict.
+/// Unlike `TypeChecker::unify_indices`, this never solves
+/// the debs in `term`.
+fn is_conflict(pattern: &Term, term: &Term, solutions: &mut [Option<Term>]) -> bool {
+    if let Term::Deb(deb) = pattern {
+        if let Some(solution) = solutions.get_mut(*deb) {
+            return match solution {
+                Some(solved) => is_conflict(&solved.clone(), term, &mut []),
+                None => {
+                    *solution = Some(term.clone());
+                    false
+                }
+            };
+        }
+    }
+
+    if let (Some((left_ind, left_index, left_args)), Some((right_ind, right_index, right_args))) =
+        (pattern.as_vcon_or_vcon_app(), term.as_vcon_or_vcon_app())
+    {
+        if left_ind != right_ind {
+            return false;
+        }
+        if left_index != right_index {
+            return true;
+        }
+        return left_args.len() == right_args.len()
+            && left_args
+                .iter()
+                .zip(right_args)
+                .any(|(left, right)| is_conflict(left, right, solutions));
+    }
+
+    matches!((pattern, term), (Term::Lit(left), Term::Lit(right)) if left != right)
+}
+
+fn infer_fun(fun: &Fun, ctx: &Context) -> Result<Term, KernelError> {
+    let param_count = fun.param_types.len();
+    if param_count == 0 {
+        return Err(KernelError::EmptyParamList(Term::Fun(Rc::new(fun.clone()))));
+    }
+    if fun
+        .decreasing_index
+        .is_some_and(|decreasing_index| decreasing_index >= param_count)
+    {
+        return Err(KernelError::DecreasingIndexTooBig(Rc::new(fun.clone())));
+    }
+
+    let (_, ctx_with_params) = check_param_types(&fun.param_types, ctx)?;
+    infer_universe(&fun.return_type, &ctx_with_params)?;
+
+    let param_types = normalize_terms(&fun.param_types);
+    let return_type = fun.return_type.normalize();
+
+    let self_type = Term::For(
+        Rc::new(
+            param_types
+                .iter()
+                .enumerate()
+                .map(|(i, param_type)| param_type.upshift(param_count, i))
+                .collect(),
+        ),
+        Rc::new(return_type.upshift(param_count, param_count)),
+    );
+    let ctx_with_self = ctx_with_params.with([self_type]);
+    check(&fun.return_val, &return_type.upshift(1, 0), &ctx_with_self)?;
+
+    Ok(Term::For(Rc::new(param_types), Rc::new(return_type)))
+}
+
+fn infer_app(callee: &Term, args: &[Term], ctx: &Context) -> Result<Term, KernelError> {
+    let callee_type = infer(callee, ctx)?;
+    let Term::For(param_types, return_type) = &callee_type else {
+        return Err(KernelError::NotAFunction {
+            callee: callee.clone(),
+            type_: callee_type,
+        });
+    };
+    if args.is_empty() || args.len() != param_types.len() {
+        return Err(KernelError::WrongNumberOfArgs {
+            callee: callee.clone(),
+            type_: callee_type.clone(),
+        });
+    }
+
+    let mut normalized_args = Vec::with_capacity(args.len());
+    for (arg, param_type) in args.iter().zip(param_types.iter()) {
+        let expected_type = param_type.substitute(&normalized_args).normalize();
+        check(arg, &expected_type, ctx)?;
+        normalized_args.push(arg.normalize());
+    }
+
+    Ok(return_type.substitute(&normalized_args).normalize())
+}
+
+fn prim_type(prim: Prim) -> Term {
+    let for_ = |param_types: &[Prim], return_type: Prim| {
+        Term::For(
+            Rc::new(param_types.iter().copied().map(Term::Prim).collect()),
+            Rc::new(Term::Prim(return_type)),
+        )
+    };
+    match prim {
+        Prim::U64 | Prim::String => Term::Universe(Universe {
+            level: UniverseLevel(0),
+            erasable: false,
+        }),
+        Prim::U64Add | Prim::U64Sub | Prim::U64Mul => for_(&[Prim::U64, Prim::U64], Prim::U64),
+        Prim::StringConcat => for_(&[Prim::String, Prim::String], Prim::String),
+        Prim::StringLen => for_(&[Prim::String], Prim::U64),
+    }
+}
+
+fn normalize_terms(terms: &[Term]) -> Vec<Term> {
+    terms.iter().map(Term::normalize).collect()
+}
@@ -0,0 +1,167 @@
+use super::*;
+
+impl Term {
+    /// Returns the normal form of the term.
+    ///
+    /// This mirrors `Evaluator::eval`, minus the caching:
+    /// recursive funs are only unfolded when their decreasing arg
+    /// is a vcon (or vcon app),
+    /// and matches are only reduced when their matchee is.
+    pub fn normalize(&self) -> Term {
+        match self {
+            Term::Ind(ind) => Term::Ind(Rc::new(ind.normalize())),
+            Term::Vcon(ind, vcon_index) => Term::Vcon(Rc::new(ind.normalize()), *vcon_index),
+            Term::Match(match_) => normalize_match(match_),
+            Term::Fun(fun) => Term::Fun(Rc::new(Fun {
+                decreasing_index: fun.decreasing_index,
+                param_types: normalize_all(&fun.param_types),
+                return_type: fun.return_type.normalize(),
+                return_val: fun.return_val.normalize(),
+            })),
+            Term::App(callee, args) => normalize_app(callee, args),
+            Term::For(param_types, return_type) => Term::For(
+                Rc::new(normalize_all(param_types)),
+                Rc::new(return_type.normalize()),
+            ),
+            Term::Deb(_) | Term::Universe(_) | Term::Lit(_) | Term::Prim(_) => self.clone(),
+        }
+    }
+
+    /// Returns `Some((ind, vcon_index, args))` if the term is
+    /// a vcon or a vcon app.
+    pub(super) fn as_vcon_or_vcon_app(&self) -> Option<(&Rc<Ind>, usize, &[Term])> {
+        match self {
+            Term::Vcon(ind, vcon_index) => Some((ind, *vcon_index, &[])),
+            Term::App(callee, args) => match &**callee {
+                Term::Vcon(ind, vcon_index) => Some((ind, *vcon_index, args)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns `Some((ind, args))` if the term is an ind or an ind app.
+    pub(super) fn as_ind_or_ind_app(&self) -> Option<(&Rc<Ind>, &[Term])> {
+        match self {
+            Term::Ind(ind) => Some((ind, &[])),
+            Term::App(callee, args) => match &**callee {
+                Term::Ind(ind) => Some((ind, args)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Ind {
+    pub(super) fn normalize(&self) -> Ind {
+        Ind {
+            name: self.name.clone(),
+            tag: self.tag.clone(),
+            universe: self.universe,
+            index_types: normalize_all(&self.index_types),
+            vcon_defs: self
+                .vcon_defs
+                .iter()
+                .map(|def| VconDef {
+                    param_types: normalize_all(&def.param_types),
+                    index_args: normalize_all(&def.index_args),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn normalize_all(terms: &[Term]) -> Vec<Term> {
+    terms.iter().map(Term::normalize).collect()
+}
+
+fn normalize_match(match_: &Match) -> Term {
+    let matchee = match_.matchee.normalize();
+
+    if let Some((_, vcon_index, args)) = matchee.as_vcon_or_vcon_app() {
+        if let Some(MatchCase {
+            return_val: Some(return_val),
+            ..
+        }) = match_.cases.get(vcon_index)
+        {
+            return return_val.substitute(args).normalize();
+        }
+    }
+
+    Term::Match(Rc::new(Match {
+        matchee,
+        return_type_arity: match_.return_type_arity,
+        return_type: match_.return_type.normalize(),
+        cases: match_
+            .cases
+            .iter()
+            .map(|case| MatchCase {
+                arity: case.arity,
+                return_val: case.return_val.as_ref().map(Term::normalize),
+            })
+            .collect(),
+    }))
+}
+
+fn normalize_app(callee: &Term, args: &[Term]) -> Term {
+    let callee = callee.normalize();
+    let args = normalize_all(args);
+
+    if let Term::Fun(fun) = &callee {
+        if can_unfold(fun, &args) {
+            let new_terms: Vec<Term> = args
+                .iter()
+                .cloned()
+                .chain(std::iter::once(callee.clone()))
+                .collect();
+            return fun.return_val.substitute(&new_terms).normalize();
+        }
+    }
+
+    if let Term::Prim(prim) = &callee {
+        if let Some(lit) = apply_prim(*prim, &args) {
+            return Term::Lit(lit);
+        }
+    }
+
+    Term::App(Rc::new(callee), Rc::new(args))
+}
+
+fn can_unfold(fun: &Fun, args: &[Term]) -> bool {
+    match fun.decreasing_index {
+        None => true,
+        Some(decreasing_index) => args
+            .get(decreasing_index)
+            .is_some_and(|arg| arg.as_vcon_or_vcon_app().is_some()),
+    }
+}
+
+fn apply_prim(prim: Prim, args: &[Term]) -> Option<Lit> {
+    match (prim, args) {
+        (Prim::U64Add, [a, b]) => Some(Lit::U64(as_u64(a)?.wrapping_add(as_u64(b)?))),
+        (Prim::U64Sub, [a, b]) => Some(Lit::U64(as_u64(a)?.wrapping_sub(as_u64(b)?))),
+        (Prim::U64Mul, [a, b]) => Some(Lit::U64(as_u64(a)?.wrapping_mul(as_u64(b)?))),
+        (Prim::StringConcat, [a, b]) => Some(Lit::String(Rc::new(StringValue(format!(
+            "{}{}",
+            as_string(a)?,
+            as_string(b)?
+        ))))),
+        (Prim::StringLen, [a]) => Some(Lit::U64(as_string(a)?.len() as u64)),
+        _ => None,
+    }
+}
+
+fn as_u64(term: &Term) -> Option<u64> {
+    match term {
+        Term::Lit(Lit::U64(value)) => Some(*value),
+        _ => None,
+    }
+}
+
+fn as_string(term: &Term) -> Option<&str> {
+    match term {
+        Term::Lit(Lit::String(value)) => Some(&value.0),
+        _ => None,
+    }
+}
@@ -0,0 +1,95 @@
+//! A small, independent typechecker for closed `minimal_ast` terms.
+//!
+//! `TypeChecker` is large: it has caches, metas, holes,
+//! error recovery, and index unification.
+//! This module re-checks fully elaborated terms without any of that,
+//! so that a proof can be audited by trusting only this module,
+//! the lexer and parser, and the hasher
+//! (see `Certificate`).
+//!
+//! The kernel is deliberately more conservative than `TypeChecker`.
+//! In particular, it does not accept:
+//!
+//! - nested occurrences of an `ind` in its own vcon defs
+//!   (e.g., `(for ((I)) I)` or `(List I)`),
+//! - matches (caseless or otherwise) as structurally smaller args
+//!   in recursive calls,
+//! - match cases whose return types are only correct after
+//!   refining them with the solved index args,
+//! - and omitted match cases, unless the vcon's index args conflict
+//!   with the matchee type's index args without solving any of
+//!   the matchee type's debs.
+//!
+//! Anything the kernel accepts, `TypeChecker` should accept too
+//! (with the same type).
+//! The May codegen tests check this on every program they compile.
+
+use crate::syntax_tree::ast::prelude::*;
+
+mod certificate;
+pub use certificate::*;
+
+mod check;
+
+mod eval;
+
+mod recursion;
+
+mod subst;
+
+mod term;
+pub use term::*;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KernelError {
+    /// The term contains a meta or a hole.
+    UnelaboratedTerm,
+    InvalidDeb(usize),
+    InvalidVconIndex(Term),
+    /// A `for` or `fun` has no params.
+    EmptyParamList(Term),
+    NotAType {
+        term: Term,
+        type_: Term,
+    },
+    TypeMismatch {
+        term: Term,
+        expected_type: Term,
+        actual_type: Term,
+    },
+    NotAFunction {
+        callee: Term,
+        type_: Term,
+    },
+    WrongNumberOfArgs {
+        callee: Term,
+        type_: Term,
+    },
+    WrongNumberOfIndexArgs(Rc<VconDef>),
+    /// An index type or vcon param type is in a higher universe
+    /// than the `ind`.
+    UniverseInconsistency(Rc<Ind>),
+    NonPositiveInd(Rc<Ind>),
+    NonInductiveMatchee(Rc<Match>),
+    /// The match has the wrong number of cases,
+    /// the wrong return type arity, or a case with the wrong arity.
+    MalformedMatch(Rc<Match>),
+    OmittedPossibleCase(Rc<Match>),
+    DecreasingIndexTooBig(Rc<Fun>),
+    IllegalRecursion(Term),
+}
+
+/// Returns the normal form of the type of `term`,
+/// which must be closed.
+pub fn check_closed(term: &Term) -> Result<Term, KernelError> {
+    recursion::check_recursion(term)?;
+    check::infer(term, &check::Context::default())
+}
+
+/// Converts `expr` to a kernel term, and returns its type.
+pub fn check_closed_minimal(expr: &minimal_ast::Expr) -> Result<Term, KernelError> {
+    check_closed(&Term::from_minimal(expr)?)
+}
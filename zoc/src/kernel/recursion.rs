@@ -0,0 +1,217 @@
+use super::*;
+
+/// What the recursion checker knows about a deb.
+#[derive(Clone, Copy, Debug)]
+enum Entry {
+    Unknown,
+    /// The deb is a substructure of the deb at `level`.
+    Substruct {
+        level: usize,
+        strict: bool,
+    },
+    /// The deb refers to the fun it is bound by.
+    SelfRef(Option<DecreasingParam>),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct DecreasingParam {
+    index: usize,
+    level: usize,
+}
+
+/// The entry at index `i` describes the `i`th binder
+/// (counting from the outermost).
+#[derive(Clone, Debug, Default)]
+struct Rcon(Vec<Entry>);
+
+impl Rcon {
+    fn level(&self, deb: usize) -> Option<usize> {
+        self.0.len().checked_sub(deb + 1)
+    }
+
+    fn get(&self, deb: usize) -> Entry {
+        self.level(deb)
+            .map(|level| self.0[level])
+            .unwrap_or(Entry::Unknown)
+    }
+
+    fn with(&self, entries: impl IntoIterator<Item = Entry>) -> Rcon {
+        let mut extended = self.clone();
+        extended.0.extend(entries);
+        extended
+    }
+
+    fn with_unknown(&self, count: usize) -> Rcon {
+        self.with(std::iter::repeat_n(Entry::Unknown, count))
+    }
+
+    /// Returns whether the deb at `level` is a strict substructure
+    /// of the deb at `superstruct_level`.
+    fn is_strict_substruct(&self, mut level: usize, superstruct_level: usize) -> bool {
+        let mut strict = false;
+        while let Entry::Substruct {
+            level: parent,
+            strict: parent_strict,
+        } = self.0[level]
+        {
+            strict |= parent_strict;
+            if parent == superstruct_level {
+                return strict;
+            }
+            level = parent;
+        }
+        false
+    }
+}
+
+/// Checks that every recursive call is made on a strict substructure
+/// of the decreasing param, and that recursive funs are only
+/// referred to in callee position.
+///
+/// Unlike `TypeChecker::check_recursion`, matches are never
+/// treated as substructures, not even caseless ones.
+pub(super) fn check_recursion(term: &Term) -> Result<(), KernelError> {
+    check(term, &Rcon::default())
+}
+
+fn check(term: &Term, rcon: &Rcon) -> Result<(), KernelError> {
+    match term {
+        Term::Ind(ind) => check_ind(ind, rcon),
+        Term::Vcon(ind, _) => check_ind(ind, rcon),
+        Term::Match(match_) => {
+            check(&match_.matchee, rcon)?;
+            check(
+                &match_.return_type,
+                &rcon.with_unknown(match_.return_type_arity),
+            )?;
+
+            let matchee_level = match &match_.matchee {
+                Term::Deb(deb) => rcon.level(*deb),
+                _ => None,
+            };
+            for case in &match_.cases {
+                let Some(return_val) = &case.return_val else {
+                    continue;
+                };
+                let entry = match matchee_level {
+                    Some(level) => Entry::Substruct {
+                        level,
+                        strict: true,
+                    },
+                    None => Entry::Unknown,
+                };
+                check(
+                    return_val,
+                    &rcon.with(std::iter::repeat_n(entry, case.arity)),
+                )?;
+            }
+            Ok(())
+        }
+        Term::Fun(fun) => {
+            let param_entries = vec![Entry::Unknown; fun.param_types.len()];
+            check_fun(fun, param_entries, rcon)
+        }
+        Term::App(callee, args) => {
+            check_callee(term, callee, args, rcon)?;
+            args.iter().try_for_each(|arg| check(arg, rcon))
+        }
+        Term::For(param_types, return_type) => {
+            check_dependent(param_types, rcon)?;
+            check(return_type, &rcon.with_unknown(param_types.len()))
+        }
+        Term::Deb(deb) => match rcon.get(*deb) {
+            Entry::SelfRef(_) => Err(KernelError::IllegalRecursion(term.clone())),
+            Entry::Unknown | Entry::Substruct { .. } => Ok(()),
+        },
+        Term::Universe(_) | Term::Lit(_) | Term::Prim(_) => Ok(()),
+    }
+}
+
+fn check_ind(ind: &Ind, rcon: &Rcon) -> Result<(), KernelError> {
+    check_dependent(&ind.index_types, rcon)?;
+
+    let rcon = rcon.with_unknown(1);
+    for def in &ind.vcon_defs {
+        check_dependent(&def.param_types, &rcon)?;
+
+        let rcon = rcon.with_unknown(def.param_types.len());
+        def.index_args
+            .iter()
+            .try_for_each(|arg| check(arg, &rcon))?;
+    }
+    Ok(())
+}
+
+fn check_fun(fun: &Fun, param_entries: Vec<Entry>, rcon: &Rcon) -> Result<(), KernelError> {
+    check_dependent(&fun.param_types, rcon)?;
+
+    let param_count = fun.param_types.len();
+    check(&fun.return_type, &rcon.with_unknown(param_count))?;
+
+    let decreasing = fun.decreasing_index.map(|index| DecreasingParam {
+        index,
+        level: rcon.0.len() + index,
+    });
+    let rcon = rcon.with(param_entries).with([Entry::SelfRef(decreasing)]);
+    check(&fun.return_val, &rcon)
+}
+
+fn check_callee(app: &Term, callee: &Term, args: &[Term], rcon: &Rcon) -> Result<(), KernelError> {
+    match callee {
+        Term::Deb(deb) => match rcon.get(*deb) {
+            Entry::SelfRef(None) => Err(KernelError::IllegalRecursion(app.clone())),
+            Entry::SelfRef(Some(decreasing)) => {
+                let is_decreasing = match args.get(decreasing.index) {
+                    Some(Term::Deb(arg)) => rcon
+                        .level(*arg)
+                        .is_some_and(|level| rcon.is_strict_substruct(level, decreasing.level)),
+                    _ => false,
+                };
+                if !is_decreasing {
+                    return Err(KernelError::IllegalRecursion(app.clone()));
+                }
+                Ok(())
+            }
+            Entry::Unknown | Entry::Substruct { .. } => Ok(()),
+        },
+
+        // Like `TypeChecker::check_recursion`, we let a fun literal's params
+        // inherit the size information of the args it is applied to.
+        Term::Fun(fun) => {
+            let param_entries = args
+                .iter()
+                .enumerate()
+                .map(|(i, arg)| {
+                    let inherits = match fun.decreasing_index {
+                        None => true,
+                        Some(decreasing_index) => i == decreasing_index,
+                    };
+                    match arg {
+                        Term::Deb(deb) if inherits => rcon
+                            .level(*deb)
+                            .map(|level| Entry::Substruct {
+                                level,
+                                strict: false,
+                            })
+                            .unwrap_or(Entry::Unknown),
+                        _ => Entry::Unknown,
+                    }
+                })
+                .collect::<Vec<_>>();
+            if param_entries.len() != fun.param_types.len() {
+                // The arg count is wrong, which the typechecker will reject.
+                return check(callee, rcon);
+            }
+            check_fun(fun, param_entries, rcon)
+        }
+
+        _ => check(callee, rcon),
+    }
+}
+
+fn check_dependent(terms: &[Term], rcon: &Rcon) -> Result<(), KernelError> {
+    for (i, term) in terms.iter().enumerate() {
+        check(term, &rcon.with_unknown(i))?;
+    }
+    Ok(())
+}
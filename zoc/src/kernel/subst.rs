@@ -0,0 +1,155 @@
+use super::*;
+
+use std::cmp::Ordering;
+
+impl Term {
+    /// Rebuilds the term, replacing each free deb `d`
+    /// (where `cutoff` is the number of binders the deb is under)
+    /// with `f(d, cutoff)`.
+    fn map_debs(&self, f: &impl Fn(usize, usize) -> Term, cutoff: usize) -> Term {
+        match self {
+            Term::Ind(ind) => Term::Ind(Rc::new(ind.map_debs(f, cutoff))),
+            Term::Vcon(ind, vcon_index) => {
+                Term::Vcon(Rc::new(ind.map_debs(f, cutoff)), *vcon_index)
+            }
+            Term::Match(match_) => Term::Match(Rc::new(Match {
+                matchee: match_.matchee.map_debs(f, cutoff),
+                return_type_arity: match_.return_type_arity,
+                return_type: match_
+                    .return_type
+                    .map_debs(f, cutoff + match_.return_type_arity),
+                cases: match_
+                    .cases
+                    .iter()
+                    .map(|case| MatchCase {
+                        arity: case.arity,
+                        return_val: case
+                            .return_val
+                            .as_ref()
+                            .map(|return_val| return_val.map_debs(f, cutoff + case.arity)),
+                    })
+                    .collect(),
+            })),
+            Term::Fun(fun) => {
+                let param_count = fun.param_types.len();
+                Term::Fun(Rc::new(Fun {
+                    decreasing_index: fun.decreasing_index,
+                    param_types: map_debs_in_dependent(&fun.param_types, f, cutoff),
+                    return_type: fun.return_type.map_debs(f, cutoff + param_count),
+                    return_val: fun.return_val.map_debs(f, cutoff + param_count + 1),
+                }))
+            }
+            Term::App(callee, args) => Term::App(
+                Rc::new(callee.map_debs(f, cutoff)),
+                Rc::new(args.iter().map(|arg| arg.map_debs(f, cutoff)).collect()),
+            ),
+            Term::For(param_types, return_type) => Term::For(
+                Rc::new(map_debs_in_dependent(param_types, f, cutoff)),
+                Rc::new(return_type.map_debs(f, cutoff + param_types.len())),
+            ),
+            Term::Deb(deb) if *deb >= cutoff => f(*deb, cutoff),
+            Term::Deb(_) | Term::Universe(_) | Term::Lit(_) | Term::Prim(_) => self.clone(),
+        }
+    }
+
+    pub(super) fn upshift(&self, amount: usize, cutoff: usize) -> Term {
+        if amount == 0 {
+            return self.clone();
+        }
+        self.map_debs(&|deb, _| Term::Deb(deb + amount), cutoff)
+    }
+
+    /// Replaces `0` with the last element of `new_terms`,
+    /// `1` with the second to last element, and so on.
+    /// Other free debs are downshifted by the length of `new_terms`.
+    pub(super) fn substitute(&self, new_terms: &[Term]) -> Term {
+        let len = new_terms.len();
+        if len == 0 {
+            return self.clone();
+        }
+        self.map_debs(
+            &|deb, cutoff| {
+                let adjusted = deb - cutoff;
+                if adjusted < len {
+                    new_terms[len - 1 - adjusted].upshift(cutoff, 0)
+                } else {
+                    Term::Deb(deb - len)
+                }
+            },
+            0,
+        )
+    }
+
+    /// Replaces `deb` with `new_term`, and downshifts the debs bound
+    /// outside of `deb` by one.
+    /// `new_term` must be valid in the context outside of `deb`.
+    pub(super) fn replace_deb(&self, deb: usize, new_term: &Term) -> Term {
+        self.map_debs(
+            &|other, cutoff| match (other - cutoff).cmp(&deb) {
+                Ordering::Less => Term::Deb(other),
+                Ordering::Equal => new_term.upshift(deb + cutoff, 0),
+                Ordering::Greater => Term::Deb(other - 1),
+            },
+            0,
+        )
+    }
+
+    /// Returns whether `deb` is free in the term.
+    pub(super) fn contains_deb(&self, deb: usize) -> bool {
+        let found = std::cell::Cell::new(false);
+        self.map_debs(
+            &|other, cutoff| {
+                if other == deb + cutoff {
+                    found.set(true);
+                }
+                Term::Deb(other)
+            },
+            0,
+        );
+        found.get()
+    }
+}
+
+impl Ind {
+    fn map_debs(&self, f: &impl Fn(usize, usize) -> Term, cutoff: usize) -> Ind {
+        Ind {
+            name: self.name.clone(),
+            tag: self.tag.clone(),
+            universe: self.universe,
+            index_types: map_debs_in_dependent(&self.index_types, f, cutoff),
+            vcon_defs: self
+                .vcon_defs
+                .iter()
+                .map(|def| VconDef {
+                    param_types: map_debs_in_dependent(&def.param_types, f, cutoff + 1),
+                    index_args: def
+                        .index_args
+                        .iter()
+                        .map(|arg| arg.map_debs(f, cutoff + 1 + def.param_types.len()))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// `(ind ...)` upshifted by `amount`.
+    pub(super) fn upshift(&self, amount: usize) -> Ind {
+        if amount == 0 {
+            return self.clone();
+        }
+        self.map_debs(&|deb, _| Term::Deb(deb + amount), 0)
+    }
+}
+
+/// Each term is under the binders of the terms before it.
+fn map_debs_in_dependent(
+    terms: &[Term],
+    f: &impl Fn(usize, usize) -> Term,
+    cutoff: usize,
+) -> Vec<Term> {
+    terms
+        .iter()
+        .enumerate()
+        .map(|(i, term)| term.map_debs(f, cutoff + i))
+        .collect()
+}
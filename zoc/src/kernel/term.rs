@@ -0,0 +1,251 @@
+use super::*;
+
+/// A kernel term.
+///
+/// Unlike `minimal_ast::Expr`, a term has no digests, aux data, or vcon names.
+/// Terms are compared structurally,
+/// so two terms are equal exactly when their `minimal_ast` counterparts
+/// have equal digests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Term {
+    Ind(Rc<Ind>),
+    Vcon(Rc<Ind>, usize),
+    Match(Rc<Match>),
+    Fun(Rc<Fun>),
+    App(Rc<Term>, Rc<Vec<Term>>),
+    For(Rc<Vec<Term>>, Rc<Term>),
+    Deb(usize),
+    Universe(Universe),
+    Lit(Lit),
+    Prim(Prim),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ind {
+    pub name: String,
+    pub tag: Option<String>,
+    pub universe: Universe,
+    pub index_types: Vec<Term>,
+    pub vcon_defs: Vec<VconDef>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VconDef {
+    pub param_types: Vec<Term>,
+    pub index_args: Vec<Term>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub matchee: Term,
+    pub return_type_arity: usize,
+    pub return_type: Term,
+    pub cases: Vec<MatchCase>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatchCase {
+    pub arity: usize,
+    pub return_val: Option<Term>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fun {
+    pub decreasing_index: Option<usize>,
+    pub param_types: Vec<Term>,
+    pub return_type: Term,
+    pub return_val: Term,
+}
+
+impl Term {
+    /// Returns `Err` if `expr` contains a meta or a hole,
+    /// since the kernel only checks fully elaborated terms.
+    pub fn from_minimal(expr: &minimal_ast::Expr) -> Result<Term, KernelError> {
+        Ok(match expr {
+            minimal_ast::Expr::Ind(e) => Term::Ind(Rc::new(Ind::from_minimal(&e.hashee)?)),
+            minimal_ast::Expr::Vcon(e) => Term::Vcon(
+                Rc::new(Ind::from_minimal(&e.hashee.ind.hashee)?),
+                e.hashee.vcon_index,
+            ),
+            minimal_ast::Expr::Match(e) => Term::Match(Rc::new(Match {
+                matchee: Term::from_minimal(&e.hashee.matchee)?,
+                return_type_arity: e.hashee.return_type_arity,
+                return_type: Term::from_minimal(&e.hashee.return_type)?,
+                cases: e
+                    .hashee
+                    .cases
+                    .hashee
+                    .iter()
+                    .map(|case| {
+                        Ok(MatchCase {
+                            arity: case.arity,
+                            return_val: case
+                                .return_val
+                                .as_ref()
+                                .map(Term::from_minimal)
+                                .transpose()?,
+                        })
+                    })
+                    .collect::<Result<_, _>>()?,
+            })),
+            minimal_ast::Expr::Fun(e) => Term::Fun(Rc::new(Fun {
+                decreasing_index: e.hashee.decreasing_index,
+                param_types: terms_from_minimal(&e.hashee.param_types.hashee)?,
+                return_type: Term::from_minimal(&e.hashee.return_type)?,
+                return_val: Term::from_minimal(&e.hashee.return_val)?,
+            })),
+            minimal_ast::Expr::App(e) => Term::App(
+                Rc::new(Term::from_minimal(&e.hashee.callee)?),
+                Rc::new(terms_from_minimal(&e.hashee.args.hashee)?),
+            ),
+            minimal_ast::Expr::For(e) => Term::For(
+                Rc::new(terms_from_minimal(&e.hashee.param_types.hashee)?),
+                Rc::new(Term::from_minimal(&e.hashee.return_type)?),
+            ),
+            minimal_ast::Expr::Deb(e) => Term::Deb(e.hashee.deb.0),
+            minimal_ast::Expr::Universe(e) => Term::Universe(e.hashee.universe),
+            minimal_ast::Expr::Lit(e) => Term::Lit(e.hashee.lit.clone()),
+            minimal_ast::Expr::Prim(e) => Term::Prim(e.hashee.prim),
+            minimal_ast::Expr::Meta(_) | minimal_ast::Expr::Hole(_) => {
+                return Err(KernelError::UnelaboratedTerm)
+            }
+        })
+    }
+
+    /// Converts the term back, so it can be pretty printed or hashed.
+    /// Vcon names are lost.
+    pub fn to_minimal(&self) -> minimal_ast::Expr {
+        match self {
+            Term::Ind(ind) => minimal_ast::Expr::Ind(ind.to_minimal()),
+            Term::Vcon(ind, vcon_index) => minimal_ast::Expr::Vcon(rc_hashed(minimal_ast::Vcon {
+                ind: ind.to_minimal(),
+                vcon_index: *vcon_index,
+                aux_data: (),
+            })),
+            Term::Match(match_) => minimal_ast::Expr::Match(rc_hashed(minimal_ast::Match {
+                matchee: match_.matchee.to_minimal(),
+                return_type_arity: match_.return_type_arity,
+                return_type: match_.return_type.to_minimal(),
+                cases: rc_hashed(
+                    match_
+                        .cases
+                        .iter()
+                        .map(|case| minimal_ast::MatchCase {
+                            arity: case.arity,
+                            return_val: case.return_val.as_ref().map(Term::to_minimal),
+                            aux_data: (),
+                        })
+                        .collect(),
+                ),
+                aux_data: (),
+            })),
+            Term::Fun(fun) => minimal_ast::Expr::Fun(rc_hashed(minimal_ast::Fun {
+                decreasing_index: fun.decreasing_index,
+                param_types: terms_to_minimal(&fun.param_types),
+                return_type: fun.return_type.to_minimal(),
+                return_val: fun.return_val.to_minimal(),
+                aux_data: (),
+            })),
+            Term::App(callee, args) => minimal_ast::Expr::App(rc_hashed(minimal_ast::App {
+                callee: callee.to_minimal(),
+                args: terms_to_minimal(args),
+                aux_data: (),
+            })),
+            Term::For(param_types, return_type) => {
+                minimal_ast::Expr::For(rc_hashed(minimal_ast::For {
+                    param_types: terms_to_minimal(param_types),
+                    return_type: return_type.to_minimal(),
+                    aux_data: (),
+                }))
+            }
+            Term::Deb(deb) => minimal_ast::Expr::Deb(rc_hashed(minimal_ast::DebNode {
+                deb: Deb(*deb),
+                aux_data: (),
+            })),
+            Term::Universe(universe) => {
+                minimal_ast::Expr::Universe(rc_hashed(minimal_ast::UniverseNode {
+                    universe: *universe,
+                    aux_data: (),
+                }))
+            }
+            Term::Lit(lit) => minimal_ast::Expr::Lit(rc_hashed(minimal_ast::LitNode {
+                lit: lit.clone(),
+                aux_data: (),
+            })),
+            Term::Prim(prim) => minimal_ast::Expr::Prim(rc_hashed(minimal_ast::PrimNode {
+                prim: *prim,
+                aux_data: (),
+            })),
+        }
+    }
+
+    /// Builds `(callee args...)`, or just `callee` if `args` is empty.
+    pub(super) fn app_or_callee(callee: Term, args: Vec<Term>) -> Term {
+        if args.is_empty() {
+            callee
+        } else {
+            Term::App(Rc::new(callee), Rc::new(args))
+        }
+    }
+
+    /// Builds `(for (param_types...) return_type)`,
+    /// or just `return_type` if `param_types` is empty.
+    pub(super) fn for_or_return_type(param_types: Vec<Term>, return_type: Term) -> Term {
+        if param_types.is_empty() {
+            return_type
+        } else {
+            Term::For(Rc::new(param_types), Rc::new(return_type))
+        }
+    }
+}
+
+impl Ind {
+    fn from_minimal(ind: &minimal_ast::Ind) -> Result<Ind, KernelError> {
+        Ok(Ind {
+            name: ind.name.0.clone(),
+            tag: ind.tag.as_ref().map(|tag| tag.0.clone()),
+            universe: ind.universe,
+            index_types: terms_from_minimal(&ind.index_types.hashee)?,
+            vcon_defs: ind
+                .vcon_defs
+                .hashee
+                .iter()
+                .map(|def| {
+                    Ok(VconDef {
+                        param_types: terms_from_minimal(&def.param_types.hashee)?,
+                        index_args: terms_from_minimal(&def.index_args.hashee)?,
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn to_minimal(&self) -> RcHashed<minimal_ast::Ind> {
+        rc_hashed(minimal_ast::Ind {
+            name: Rc::new(StringValue(self.name.clone())),
+            tag: self.tag.clone().map(|tag| Rc::new(StringValue(tag))),
+            universe: self.universe,
+            index_types: terms_to_minimal(&self.index_types),
+            vcon_defs: rc_hashed(
+                self.vcon_defs
+                    .iter()
+                    .map(|def| minimal_ast::VconDef {
+                        name: None,
+                        param_types: terms_to_minimal(&def.param_types),
+                        index_args: terms_to_minimal(&def.index_args),
+                        aux_data: (),
+                    })
+                    .collect(),
+            ),
+            aux_data: (),
+        })
+    }
+}
+
+fn terms_from_minimal(exprs: &[minimal_ast::Expr]) -> Result<Vec<Term>, KernelError> {
+    exprs.iter().map(Term::from_minimal).collect()
+}
+
+fn terms_to_minimal(terms: &[Term]) -> RcHashedVec<minimal_ast::Expr> {
+    rc_hashed(terms.iter().map(Term::to_minimal).collect())
+}
@@ -0,0 +1,276 @@
+use super::*;
+
+use crate::{eliminator::get_eliminator, pretty_print::*, test_utils::*};
+
+use pretty_assertions::assert_eq;
+
+fn nat_defs() -> [(&'static str, &'static str); 3] {
+    [
+        ("<NAT>", r#"(ind Set0 "Nat" () ((() ()) ((0) ())))"#),
+        ("<ZERO>", "(vcon <NAT> 0)"),
+        ("<SUCC>", "(vcon <NAT> 1)"),
+    ]
+}
+
+fn eq_defs() -> [(&'static str, &'static str); 4] {
+    let [nat, zero, succ] = nat_defs();
+    [
+        nat,
+        zero,
+        succ,
+        (
+            "<EQ>",
+            r#"(ind Prop0 "Eq" (<NAT> <NAT>) (((<NAT>) (0 0))))"#,
+        ),
+    ]
+}
+
+const ADD_SRC: &str = r#"
+(fun 0 (<NAT> <NAT>) <NAT>
+    (match 2 1 <NAT> (
+        (0 1)
+        (1 (<SUCC> (1 0 2)))
+    ))
+)"#;
+
+/// Checks that the kernel and `TypeChecker` agree on the type of `src`.
+fn check_kernel_agrees_or_panic(src: &str) -> Term {
+    let expected_type = get_type_under_empty_tcon_or_panic(src);
+    let expected_type = Term::from_minimal(expected_type.raw()).unwrap();
+
+    let expr = parse_minimal_ast_or_panic(src);
+    let actual_type = match check_closed_minimal(&expr) {
+        Ok(type_) => type_,
+        Err(err) => panic!("kernel rejected {src}: {err:?}"),
+    };
+
+    assert_eq!(
+        PrettyPrint(&expected_type.to_minimal()).to_string(),
+        PrettyPrint(&actual_type.to_minimal()).to_string()
+    );
+    assert_eq!(expected_type, actual_type);
+    actual_type
+}
+
+fn get_kernel_error_or_panic(src: &str) -> KernelError {
+    let expr = parse_minimal_ast_or_panic(src);
+    match check_closed_minimal(&expr) {
+        Ok(type_) => panic!(
+            "kernel accepted {src} with type {}",
+            PrettyPrint(&type_.to_minimal())
+        ),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn prims() {
+    check_kernel_agrees_or_panic(r#"(string_len (string_concat "ab" "c"))"#);
+    check_kernel_agrees_or_panic("(fun nonrec (U64) U64 (u64_mul 1 (u64_add 1 2u64)))");
+    check_kernel_agrees_or_panic("(for (U64 Set0) Prop1)");
+}
+
+#[test]
+fn nat_add() {
+    let src = substitute_with_compounding(nat_defs(), ADD_SRC);
+    check_kernel_agrees_or_panic(&src);
+}
+
+#[test]
+fn nat_add_app_normalizes() {
+    let src = substitute_with_compounding(
+        nat_defs(),
+        "(fun nonrec ((for (<NAT>) Set0) (0 (<ADD> (<SUCC> <ZERO>) <ZERO>))) (1 (<SUCC> <ZERO>)) 1)",
+    );
+    let src = src.replace("<ADD>", &substitute_with_compounding(nat_defs(), ADD_SRC));
+    check_kernel_agrees_or_panic(&src);
+}
+
+#[test]
+fn eq_symmetric() {
+    let src = substitute_with_compounding(
+        eq_defs(),
+        r#"
+(fun nonrec (<NAT> <NAT> (<EQ> 1 0)) (<EQ> 1 2)
+    (match 1 3 (<EQ> 1 2) (
+        (1 ((vcon <EQ> 0) 0))
+    ))
+)"#,
+    );
+    check_kernel_agrees_or_panic(&src);
+}
+
+#[test]
+fn nat_eliminator() {
+    let ind = match parse_minimal_ast_or_panic(nat_defs()[0].1) {
+        minimal_ast::Expr::Ind(ind) => ind,
+        _ => unreachable!(),
+    };
+    let universe = Universe {
+        level: UniverseLevel(0),
+        erasable: false,
+    };
+    let eliminator = get_eliminator(ind, universe).unwrap();
+    let type_ = check_kernel_agrees_or_panic(&PrettyPrint(&eliminator.term).to_string());
+
+    let expected_type = Term::from_minimal(&eliminator.type_).unwrap().normalize();
+    assert_eq!(expected_type, type_);
+}
+
+#[test]
+fn disjoint_vcon_case_can_be_omitted() {
+    let src = substitute_with_compounding(
+        eq_defs(),
+        r#"
+(fun nonrec ((<EQ> <ZERO> (<SUCC> <ZERO>))) U64
+    (match 1 3 U64 (
+        (1)
+    ))
+)"#,
+    );
+    check_kernel_agrees_or_panic(&src);
+}
+
+#[test]
+fn ill_typed_app() {
+    let err = get_kernel_error_or_panic("(u64_add 1u64 Set0)");
+    assert!(matches!(err, KernelError::TypeMismatch { .. }), "{err:?}");
+}
+
+#[test]
+fn unelaborated_term() {
+    let err = get_kernel_error_or_panic("(u64_add 1u64 ?)");
+    assert_eq!(KernelError::UnelaboratedTerm, err);
+}
+
+#[test]
+fn non_decreasing_recursive_call() {
+    let src = substitute_with_compounding(nat_defs(), "(fun 0 (<NAT>) <NAT> (1 0))");
+    let err = get_kernel_error_or_panic(&src);
+    assert!(matches!(err, KernelError::IllegalRecursion(_)), "{err:?}");
+}
+
+#[test]
+fn recursive_param_in_non_callee_position() {
+    let src = substitute_with_compounding(
+        nat_defs(),
+        "(fun 0 (<NAT>) <NAT> (fun nonrec (<NAT>) <NAT> 2))",
+    );
+    let err = get_kernel_error_or_panic(&src);
+    assert!(matches!(err, KernelError::IllegalRecursion(_)), "{err:?}");
+}
+
+#[test]
+fn negative_ind() {
+    let err = get_kernel_error_or_panic(r#"(ind Set0 "Bad" () ((((for (0) U64)) ())))"#);
+    assert!(matches!(err, KernelError::NonPositiveInd(_)), "{err:?}");
+}
+
+#[test]
+fn possible_case_cannot_be_omitted() {
+    let src = substitute_with_compounding(
+        eq_defs(),
+        r#"
+(fun nonrec ((<EQ> <ZERO> <ZERO>)) U64
+    (match 1 3 U64 (
+        (1)
+    ))
+)"#,
+    );
+    let err = get_kernel_error_or_panic(&src);
+    assert!(
+        matches!(err, KernelError::OmittedPossibleCase(_)),
+        "{err:?}"
+    );
+}
+
+fn nat_certificate() -> Certificate {
+    let zero_src = substitute_with_compounding(nat_defs(), "<ZERO>");
+    let add_src = substitute_with_compounding(nat_defs(), ADD_SRC);
+    let entries = [("zero", zero_src), ("add", add_src)]
+        .into_iter()
+        .map(|(name, src)| {
+            let term = parse_minimal_ast_or_panic(&src);
+            let type_ = get_type_under_empty_tcon_or_panic(&src);
+            CertificateEntry::new(name.to_string(), &term, type_.raw())
+        })
+        .collect();
+    Certificate { entries }
+}
+
+#[test]
+fn certificate_round_trip() {
+    let certificate = nat_certificate();
+    let text = certificate.to_string();
+    assert_eq!(Ok(certificate), Certificate::parse(&text));
+    Certificate::parse(&text).unwrap().check().unwrap();
+    insta::assert_display_snapshot!(text);
+}
+
+#[test]
+fn certificate_with_wrong_type() {
+    let mut certificate = nat_certificate();
+    certificate.entries[0].type_src = "U64".to_string();
+    let err = certificate.check().unwrap_err();
+    assert!(
+        matches!(&err, CertificateError::TypeMismatch { name, .. } if name == "zero"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn certificate_with_wrong_digest() {
+    let mut certificate = nat_certificate();
+    certificate.entries[1].digest = certificate.entries[0].digest.clone();
+    let err = certificate.check().unwrap_err();
+    assert!(
+        matches!(&err, CertificateError::DigestMismatch { name, .. } if name == "add"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn malformed_certificate() {
+    let err = Certificate::parse("zo-certificate v1\ndef zero\nterm\n").unwrap_err();
+    assert_eq!(
+        CertificateError::Malformed {
+            line: 3,
+            message: "expected `digest <digest>`",
+        },
+        err
+    );
+}
+
+#[test]
+fn wrong_header_after_blank_lines() {
+    let err = Certificate::parse("\n\nzo-certificate v2\ndef zero\n").unwrap_err();
+    assert_eq!(
+        CertificateError::Malformed {
+            line: 3,
+            message: "expected `zo-certificate v1`",
+        },
+        err
+    );
+}
+
+#[test]
+fn truncated_certificate() {
+    let err =
+        Certificate::parse("zo-certificate v1\ndef zero\ndigest 0x0\nterm\nzero\n").unwrap_err();
+    assert_eq!(
+        CertificateError::Malformed {
+            line: 5,
+            message: "unexpected end of certificate",
+        },
+        err
+    );
+
+    let err = Certificate::parse("zo-certificate v1\ndef zero").unwrap_err();
+    assert_eq!(
+        CertificateError::Malformed {
+            line: 2,
+            message: "unexpected end of certificate",
+        },
+        err
+    );
+}
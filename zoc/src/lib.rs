@@ -1,9 +1,15 @@
 pub mod check_erasability;
+pub mod diagnostic;
+pub mod eliminator;
 pub mod eval;
+pub mod export;
 pub mod hash;
+pub mod json;
+pub mod kernel;
 pub mod pretty_print;
 pub mod syntax_tree;
 pub mod typecheck;
+pub mod zofmt;
 
 #[cfg(test)]
 pub mod test_utils;
@@ -0,0 +1,217 @@
+use super::*;
+
+use std::fmt::Write;
+
+/// The line width used when no width is specified.
+pub const DEFAULT_LINE_WIDTH: usize = 80;
+
+/// A document to be laid out,
+/// in the style of Wadler's "A prettier printer".
+///
+/// A document is laid out by deciding, for each `Group`,
+/// whether to place it _flat_ (i.e., on a single line)
+/// or _broken_.
+/// A group is placed flat if it fits in the remaining width
+/// (including everything after it up to the next line break).
+/// Otherwise, it is broken, and every `Line` and `SoftLine`
+/// directly inside it (i.e., not inside a nested group)
+/// becomes a line break.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Doc {
+    Nil,
+    /// Text that must not contain a newline.
+    Text(String),
+    /// A line break, or a space if flat.
+    Line,
+    /// A line break, or nothing if flat.
+    SoftLine,
+    /// Forces every enclosing group to be broken.
+    /// This is used for things that must be followed by a line break
+    /// (e.g., comments).
+    BreakParent,
+    /// Indents every line break inside the document by one `SOFT_TAB`.
+    Nest(Box<Doc>),
+    Concat(Vec<Doc>),
+    Group(Box<Doc>),
+}
+
+impl Doc {
+    pub fn text(s: impl Into<String>) -> Self {
+        Doc::Text(s.into())
+    }
+
+    /// A line break, even if the enclosing group would otherwise be flat.
+    pub fn hard_line() -> Self {
+        Doc::Concat(vec![Doc::BreakParent, Doc::Line])
+    }
+
+    pub fn nest(self) -> Self {
+        Doc::Nest(Box::new(self))
+    }
+
+    pub fn group(self) -> Self {
+        Doc::Group(Box::new(self))
+    }
+
+    /// Joins `docs`, separated by `separator`.
+    pub fn join(docs: impl IntoIterator<Item = Doc>, separator: Doc) -> Self {
+        let mut out = vec![];
+        for doc in docs {
+            if !out.is_empty() {
+                out.push(separator.clone());
+            }
+            out.push(doc);
+        }
+        Doc::Concat(out)
+    }
+
+    /// Returns a group that lays out `items` either as `(a b c)`,
+    /// or with each item on its own line, indented:
+    ///
+    /// ```text
+    /// (
+    ///     a
+    ///     b
+    ///     c
+    /// )
+    /// ```
+    ///
+    /// If `items` is empty, this returns `()`.
+    pub fn parenthesized(items: impl IntoIterator<Item = Doc>) -> Self {
+        let items: Vec<Doc> = items.into_iter().collect();
+        if items.is_empty() {
+            return Doc::text("()");
+        }
+
+        Doc::Concat(vec![
+            Doc::text("("),
+            Doc::Concat(vec![Doc::SoftLine, Doc::join(items, Doc::Line)]).nest(),
+            Doc::SoftLine,
+            Doc::text(")"),
+        ])
+        .group()
+    }
+
+    /// Lays out `self` so that lines are at most `width` characters wide
+    /// (where possible), and writes the result to `out`.
+    pub fn render(&self, width: usize, out: &mut impl Write) -> FmtResult {
+        let mut column = 0;
+        let mut stack = vec![(0, Mode::Break, self)];
+
+        while let Some((indent, mode, doc)) = stack.pop() {
+            match doc {
+                Doc::Nil => {}
+
+                Doc::Text(s) => {
+                    out.write_str(s)?;
+                    column += s.chars().count();
+                }
+
+                Doc::Line | Doc::SoftLine if mode == Mode::Flat => {
+                    if *doc == Doc::Line {
+                        out.write_char(' ')?;
+                        column += 1;
+                    }
+                }
+
+                Doc::Line | Doc::SoftLine => {
+                    out.write_char('\n')?;
+                    for _ in 0..indent {
+                        out.write_str(SOFT_TAB)?;
+                    }
+                    column = indent * SOFT_TAB.len();
+                }
+
+                Doc::BreakParent => {}
+
+                Doc::Nest(inner) => stack.push((indent + 1, mode, inner)),
+
+                Doc::Concat(docs) => {
+                    stack.extend(docs.iter().rev().map(|doc| (indent, mode, doc)));
+                }
+
+                Doc::Group(inner) => {
+                    let inner_mode = if mode == Mode::Flat
+                        || fits(width.saturating_sub(column), inner, &stack)
+                    {
+                        Mode::Flat
+                    } else {
+                        Mode::Break
+                    };
+                    stack.push((indent, inner_mode, inner));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Shorthand for rendering into a new `String`.
+    pub fn pretty(&self, width: usize) -> String {
+        let mut out = String::new();
+        self.render(width, &mut out)
+            .expect("writing to a String should never fail");
+        out
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+/// Returns `true` if `doc` fits in `remaining_width` when placed flat,
+/// along with whatever follows it (i.e., `rest`) up to the next line break.
+fn fits(mut remaining_width: usize, doc: &Doc, rest: &[(usize, Mode, &Doc)]) -> bool {
+    // The `bool` is `true` for docs that come from `rest`.
+    let mut stack = vec![(Mode::Flat, false, doc)];
+    let mut rest = rest.iter().rev();
+
+    loop {
+        let (mode, is_rest, doc) = match stack.pop() {
+            Some(entry) => entry,
+            None => match rest.next() {
+                Some((_, mode, doc)) => (*mode, true, *doc),
+                None => return true,
+            },
+        };
+
+        match doc {
+            Doc::Nil => {}
+
+            Doc::Text(s) => {
+                let len = s.chars().count();
+                if len > remaining_width {
+                    return false;
+                }
+                remaining_width -= len;
+            }
+
+            Doc::Line | Doc::SoftLine if mode == Mode::Break => return true,
+
+            Doc::Line => {
+                if remaining_width == 0 {
+                    return false;
+                }
+                remaining_width -= 1;
+            }
+
+            Doc::SoftLine => {}
+
+            // `doc` cannot be flat if it contains a `BreakParent`.
+            // But if the `BreakParent` comes after `doc`,
+            // there will be a line break there,
+            // so everything before it fits.
+            Doc::BreakParent => return is_rest,
+
+            Doc::Nest(inner) => stack.push((mode, is_rest, inner)),
+
+            // A group that follows `doc` has not been laid out yet,
+            // so we conservatively assume it will be placed flat.
+            Doc::Group(inner) => stack.push((Mode::Flat, is_rest, inner)),
+
+            Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|doc| (mode, is_rest, doc))),
+        }
+    }
+}
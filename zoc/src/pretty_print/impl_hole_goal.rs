@@ -0,0 +1,67 @@
+use super::{
+    impl_type_error::{GetOptSpan, WithOptLocationAppended},
+    *,
+};
+
+use crate::{
+    syntax_tree::{ast::prelude::*, remove_ast_aux_data::AuxDataRemover},
+    typecheck::HoleGoal,
+};
+
+/// Every type is printed using the context entries' names.
+impl<A> Display for PrettyPrint<'_, HoleGoal<A>>
+where
+    A: AuxDataFamily,
+    ast::HoleNode<A>: GetOptSpan,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let goal = self.0;
+        let names = goal.context_names();
+        let hole_minimal = AuxDataRemover::default().convert_hole_node(&goal.hole);
+
+        let context: Vec<NamedType> = goal
+            .context
+            .iter()
+            .map(|entry| NamedType {
+                name: &entry.name,
+                type_: entry.type_.raw(),
+                free_deb_names: &names,
+            })
+            .collect();
+
+        f.debug_struct("HoleGoal")
+            .field(
+                "hole",
+                &hole_minimal
+                    .hashee
+                    .pretty_printed()
+                    .with_opt_location_appended(goal.hole.opt_span()),
+            )
+            .field("context", &context)
+            .field(
+                "expected_type",
+                &PrettyPrint(&WithFreeDebNames {
+                    val: goal.expected_type.raw(),
+                    free_deb_names: &names,
+                }),
+            )
+            .finish()
+    }
+}
+
+struct NamedType<'a> {
+    name: &'a str,
+    type_: &'a minimal_ast::Expr,
+    free_deb_names: &'a [String],
+}
+
+impl Debug for NamedType<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = self.name;
+        let type_ = PrettyPrint(&WithFreeDebNames {
+            val: self.type_,
+            free_deb_names: self.free_deb_names,
+        });
+        write!(f, "{name}: {type_}")
+    }
+}
@@ -2,327 +2,725 @@ use super::*;
 
 use crate::syntax_tree::ast::prelude::minimal_ast::*;
 
+use std::hash::Hash;
+
 impl Display for PrettyPrint<'_, Expr> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_expr(self.0.clone(), f, Indentation { soft_tab_count: 0 })
+        expr_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
+    }
+}
+
+impl Display for PrettyPrint<'_, WithFreeDebNames<'_, Expr>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let names = DebNames {
+            free_deb_names: self.0.free_deb_names,
+            ..DebNames::NONE
+        };
+        expr_doc(self.0.val, names).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, Ind> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_ind(&self.0, f, Indentation { soft_tab_count: 0 })
+        ind_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, [VconDef]> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_parenthesized_vcon_defs(self.0.clone(), f, Indentation { soft_tab_count: 0 })
+        parenthesized_vcon_defs_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, VconDef> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_vcon_def(self.0, f, Indentation { soft_tab_count: 0 })
+        vcon_def_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, Vcon> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_vcon(&self.0, f, Indentation { soft_tab_count: 0 })
+        vcon_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, Match> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_match(&self.0, f, Indentation { soft_tab_count: 0 })
+        match_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, [MatchCase]> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_parenthesized_match_cases(self.0.clone(), f, Indentation { soft_tab_count: 0 })
+        parenthesized_match_cases_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, MatchCase> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_match_case(self.0, f, Indentation { soft_tab_count: 0 })
+        match_case_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, Fun> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_fun(&self.0, f, Indentation { soft_tab_count: 0 })
+        fun_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, App> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_app(&self.0, f, Indentation { soft_tab_count: 0 })
+        app_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, For> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_for(&self.0, f, Indentation { soft_tab_count: 0 })
+        for_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, DebNode> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_deb_node(&self.0, f, Indentation { soft_tab_count: 0 })
+        deb_node_doc(self.0, DebNames::NONE).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
 impl Display for PrettyPrint<'_, UniverseNode> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_universe_node(&self.0, f, Indentation { soft_tab_count: 0 })
+        universe_node_doc(self.0).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
-// Every `fmt_{node}` function writes an indent
-// at the beginning.
-// It does _not_ write a newline at the end.
+impl Display for PrettyPrint<'_, LitNode> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        lit_node_doc(self.0).render(DEFAULT_LINE_WIDTH, f)
+    }
+}
 
-fn fmt_expr(expr: Expr, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    match expr {
-        Expr::Ind(e) => fmt_ind(&e.hashee, f, indent),
-        Expr::Vcon(e) => fmt_vcon(&e.hashee, f, indent),
-        Expr::Match(e) => fmt_match(&e.hashee, f, indent),
-        Expr::Fun(e) => fmt_fun(&e.hashee, f, indent),
-        Expr::App(e) => fmt_app(&e.hashee, f, indent),
-        Expr::For(e) => fmt_for(&e.hashee, f, indent),
-        Expr::Deb(e) => fmt_deb_node(&e.hashee, f, indent),
-        Expr::Universe(e) => fmt_universe_node(&e.hashee, f, indent),
+impl Display for PrettyPrint<'_, PrimNode> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        prim_node_doc(self.0).render(DEFAULT_LINE_WIDTH, f)
     }
 }
 
-fn fmt_ind(ind: &Ind, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    let i1 = indent.incremented();
-    write!(f, "{indent}(\n{i1}ind\n")?;
-    fmt_universe(ind.universe, f, i1)?;
-    write!(f, "\n")?;
+impl Display for PrettyPrint<'_, MetaNode> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        meta_node_doc(self.0).render(DEFAULT_LINE_WIDTH, f)
+    }
+}
 
-    fmt_str_literal(&ind.name, f, i1)?;
-    write!(f, "\n")?;
+impl Display for PrettyPrint<'_, HoleNode> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        hole_node_doc(self.0).render(DEFAULT_LINE_WIDTH, f)
+    }
+}
 
-    fmt_parenthesized_expressions(ind.index_types.clone(), f, i1)?;
-    write!(f, "\n")?;
+impl Display for PrettyPrintWithOptions<'_, Expr> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        expr_doc(self.val, DebNames::from_options(&self.options)).render(self.options.width, f)
+    }
+}
 
-    fmt_parenthesized_vcon_defs(&ind.vcon_defs.hashee, f, i1)?;
-    write!(f, "\n{indent})")?;
+impl Display for PrettyPrintWithOptions<'_, Ind> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        ind_doc(self.val, DebNames::from_options(&self.options)).render(self.options.width, f)
+    }
+}
 
-    Ok(())
+impl Display for PrettyPrintWithOptions<'_, [VconDef]> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        parenthesized_vcon_defs_doc(self.val, DebNames::from_options(&self.options))
+            .render(self.options.width, f)
+    }
 }
 
-fn fmt_universe(universe: Universe, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    let level = universe.level.0;
-    let set_or_prop = if universe.erasable { "Prop" } else { "Set" };
-    write!(f, "{indent}{set_or_prop}{level}")
+impl Display for PrettyPrintWithOptions<'_, VconDef> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        vcon_def_doc(self.val, DebNames::from_options(&self.options)).render(self.options.width, f)
+    }
 }
 
-fn fmt_str_literal(
-    str_literal: &StringValue,
-    f: &mut Formatter<'_>,
-    indent: Indentation,
-) -> FmtResult {
-    write!(f, "{indent}\"")?;
+impl Display for PrettyPrintWithOptions<'_, Vcon> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        vcon_doc(self.val, DebNames::from_options(&self.options)).render(self.options.width, f)
+    }
+}
 
-    for c in str_literal.0.chars() {
-        if c.is_ascii_alphanumeric() || " _`~!@#$%^&*()-_=+[]|;:',<.>/?".contains(c) {
-            write!(f, "{c}")?;
-        } else {
-            write!(f, "{{0x{:X}}}", u32::from(c))?;
-        }
+impl Display for PrettyPrintWithOptions<'_, Match> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match_doc(self.val, DebNames::from_options(&self.options)).render(self.options.width, f)
     }
+}
 
-    write!(f, "\"")?;
-    Ok(())
+impl Display for PrettyPrintWithOptions<'_, [MatchCase]> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        parenthesized_match_cases_doc(self.val, DebNames::from_options(&self.options))
+            .render(self.options.width, f)
+    }
 }
 
-fn fmt_parenthesized_vcon_defs(
-    defs: &[VconDef],
-    f: &mut Formatter<'_>,
-    indent: Indentation,
-) -> FmtResult {
-    if defs.is_empty() {
-        return write!(f, "{indent}()");
+impl Display for PrettyPrintWithOptions<'_, MatchCase> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match_case_doc(self.val, DebNames::from_options(&self.options))
+            .render(self.options.width, f)
     }
+}
 
-    write!(f, "{indent}(")?;
-    let i1 = indent.incremented();
+impl Display for PrettyPrintWithOptions<'_, Fun> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        fun_doc(self.val, DebNames::from_options(&self.options)).render(self.options.width, f)
+    }
+}
 
-    for def in defs.iter() {
-        write!(f, "\n")?;
-        fmt_vcon_def(def, f, i1)?;
+impl Display for PrettyPrintWithOptions<'_, App> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        app_doc(self.val, DebNames::from_options(&self.options)).render(self.options.width, f)
     }
+}
 
-    write!(f, "\n{indent})")?;
-    Ok(())
+impl Display for PrettyPrintWithOptions<'_, For> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for_doc(self.val, DebNames::from_options(&self.options)).render(self.options.width, f)
+    }
 }
 
-fn fmt_vcon_def(def: &VconDef, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    write!(f, "{indent}(\n")?;
+impl Display for PrettyPrintWithOptions<'_, DebNode> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        deb_node_doc(self.val, DebNames::from_options(&self.options)).render(self.options.width, f)
+    }
+}
 
-    let i1 = indent.incremented();
-    fmt_parenthesized_expressions(def.param_types.clone(), f, i1)?;
-    write!(f, "\n")?;
+impl Display for PrettyPrintWithOptions<'_, UniverseNode> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        universe_node_doc(self.val).render(self.options.width, f)
+    }
+}
 
-    fmt_parenthesized_expressions(def.index_args.clone(), f, i1)?;
+impl Display for PrettyPrintWithOptions<'_, LitNode> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        lit_node_doc(self.val).render(self.options.width, f)
+    }
+}
 
-    write!(f, "\n{indent})")?;
-    Ok(())
+impl Display for PrettyPrintWithOptions<'_, PrimNode> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        prim_node_doc(self.val).render(self.options.width, f)
+    }
 }
 
-fn fmt_vcon(vcon: &Vcon, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    let i1 = indent.incremented();
-    write!(f, "{indent}(\n{i1}vcon\n")?;
+impl Display for PrettyPrintWithOptions<'_, MetaNode> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        meta_node_doc(self.val).render(self.options.width, f)
+    }
+}
 
-    fmt_ind(&vcon.ind.hashee, f, i1)?;
+impl Display for PrettyPrintWithOptions<'_, HoleNode> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        hole_node_doc(self.val).render(self.options.width, f)
+    }
+}
 
-    let vcon_index = vcon.vcon_index;
-    write!(f, "\n{i1}{vcon_index}\n{indent})")?;
-    Ok(())
+/// Determines how debs are printed.
+///
+/// By default, debs that refer to binders within the printed expression
+/// are printed as indices.
+/// If `named` is `true`, every binder is given a fresh name,
+/// and such debs are printed as their binder's name instead.
+///
+/// Free debs are printed using `free_deb_names`
+/// (if it has an entry for them).
+#[derive(Clone, Copy)]
+struct DebNames<'a> {
+    /// The name of free deb `0` is the _last_ element,
+    /// the name of free deb `1` is the second to last element,
+    /// and so on.
+    free_deb_names: &'a [String],
+    binder_count: usize,
+    named: bool,
+    /// The names of the binders within the printed expression.
+    /// This is always `None` if `named` is `false`.
+    scope: Option<&'a NameScope<'a>>,
+    hints: Option<&'a NameHints>,
 }
 
-fn fmt_match(m: &Match, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    let i1 = indent.incremented();
-    write!(f, "{indent}(\n{i1}match\n")?;
+/// A group of binders introduced by the same node
+/// (e.g., a fun's params).
+struct NameScope<'a> {
+    /// The innermost binder is the _last_ element.
+    names: &'a [String],
+    parent: Option<&'a NameScope<'a>>,
+}
 
-    fmt_expr(m.matchee.clone(), f, i1)?;
-    write!(f, "\n")?;
+impl DebNames<'_> {
+    const NONE: DebNames<'static> = DebNames {
+        free_deb_names: &[],
+        binder_count: 0,
+        named: false,
+        scope: None,
+        hints: None,
+    };
+
+    fn from_options<'a>(options: &PrettyPrintOptions<'a>) -> DebNames<'a> {
+        DebNames {
+            named: options.named_debs,
+            hints: options.name_hints,
+            ..DebNames::NONE
+        }
+    }
 
-    let return_type_arity = m.return_type_arity;
-    write!(f, "{i1}{return_type_arity}\n")?;
+    /// Calls `f` with `self` extended with `binder_names`
+    /// (where the innermost binder is the _last_ element).
+    ///
+    /// If `self.named` is `false`, only the number of names matters.
+    fn with_binders<R>(self, binder_names: &[String], f: impl FnOnce(DebNames<'_>) -> R) -> R {
+        if !self.named {
+            return f(DebNames {
+                binder_count: self.binder_count + binder_names.len(),
+                ..self
+            });
+        }
 
-    fmt_expr(m.return_type.clone(), f, i1)?;
-    write!(f, "\n")?;
+        let scope = NameScope {
+            names: binder_names,
+            parent: self.scope,
+        };
+        f(DebNames {
+            binder_count: self.binder_count + binder_names.len(),
+            scope: Some(&scope),
+            ..self
+        })
+    }
 
-    fmt_parenthesized_match_cases(&m.cases.hashee, f, i1)?;
-    write!(f, "\n{indent})")?;
-    Ok(())
+    fn get_name(&self, deb: Deb) -> Option<&str> {
+        if deb.0 < self.binder_count {
+            return self.get_bound_name(deb);
+        }
+
+        let free_deb = deb.0 - self.binder_count;
+        let index = self.free_deb_names.len().checked_sub(1 + free_deb)?;
+        Some(&self.free_deb_names[index])
+    }
+
+    fn get_bound_name(&self, deb: Deb) -> Option<&str> {
+        let mut remaining = deb.0;
+        let mut scope = self.scope?;
+        loop {
+            if remaining < scope.names.len() {
+                return Some(&scope.names[scope.names.len() - 1 - remaining]);
+            }
+            remaining -= scope.names.len();
+            scope = scope.parent?;
+        }
+    }
+
+    /// Returns a name for each base name,
+    /// such that no returned name is already in scope,
+    /// and no two returned names are equal.
+    ///
+    /// If `self.named` is `false`, the returned names are empty,
+    /// since only their number matters.
+    fn fresh_names(&self, base_names: impl IntoIterator<Item = String>) -> Vec<String> {
+        if !self.named {
+            return base_names.into_iter().map(|_| String::new()).collect();
+        }
+
+        let mut out: Vec<String> = vec![];
+        for base_name in base_names {
+            let mut name = base_name.clone();
+            let mut suffix = 1;
+            while self.is_in_scope(&name) || out.contains(&name) {
+                name = format!("{base_name}{suffix}");
+                suffix += 1;
+            }
+            out.push(name);
+        }
+        out
+    }
+
+    fn is_in_scope(&self, name: &str) -> bool {
+        if self.free_deb_names.iter().any(|free| free == name) {
+            return true;
+        }
+
+        let mut scope = self.scope;
+        while let Some(current) = scope {
+            if current.names.iter().any(|bound| bound == name) {
+                return true;
+            }
+            scope = current.parent;
+        }
+        false
+    }
+
+    /// Returns the base names of the binders introduced by `node`.
+    ///
+    /// If there is a hint for `node` with one name per binder,
+    /// the hinted names are used,
+    /// except for anonymous (i.e., `_`) names,
+    /// which fall back to the corresponding element of `fallback`.
+    /// Otherwise, `fallback` is returned as-is.
+    fn get_base_names<T: Hash>(&self, node: T, fallback: Vec<String>) -> Vec<String> {
+        if !self.named {
+            return fallback;
+        }
+        let Some(hints) = self.hints else {
+            return fallback;
+        };
+        let Some(hinted) = hints.get(&Hashed::new(node).digest) else {
+            return fallback;
+        };
+        if hinted.len() != fallback.len() {
+            return fallback;
+        }
+
+        hinted
+            .iter()
+            .zip(fallback)
+            .map(|(hinted, fallback)| {
+                if hinted == "_" {
+                    fallback
+                } else {
+                    hinted.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+/// Picks a base name for a binder of type `type_`
+/// (e.g., `n` for a `Nat`).
+fn get_binder_base_name(type_: &Expr) -> String {
+    let ind_name = match type_ {
+        Expr::Ind(ind) => Some(&ind.hashee.name.0),
+        Expr::App(app) => match &app.hashee.callee {
+            Expr::Ind(ind) => Some(&ind.hashee.name.0),
+            _ => None,
+        },
+        _ => None,
+    };
+    if let Some(initial) = ind_name.and_then(|name| name.chars().find(char::is_ascii_alphabetic)) {
+        return initial.to_ascii_lowercase().to_string();
+    }
+
+    match type_ {
+        Expr::Universe(_) => "T",
+        Expr::For(_) => "g",
+        Expr::Prim(prim) => match prim.hashee.prim {
+            Prim::U64 => "n",
+            Prim::String => "s",
+            _ => "x",
+        },
+        _ => "x",
+    }
+    .to_string()
+}
+
+/// Returns the name of `ind`'s self binder
+/// (i.e., the binder vcon defs use to refer to `ind`).
+fn get_ind_self_base_name(ind: &Ind) -> String {
+    let name: String = ind
+        .name
+        .0
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect();
+    match name.chars().next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => name,
+        _ => "I".to_string(),
+    }
 }
 
-fn fmt_parenthesized_match_cases(
-    cases: &[MatchCase],
-    f: &mut Formatter<'_>,
-    indent: Indentation,
-) -> FmtResult {
-    if cases.is_empty() {
-        return write!(f, "{indent}()");
+fn expr_doc(expr: &Expr, names: DebNames) -> Doc {
+    match expr {
+        Expr::Ind(e) => ind_doc(&e.hashee, names),
+        Expr::Vcon(e) => vcon_doc(&e.hashee, names),
+        Expr::Match(e) => match_doc(&e.hashee, names),
+        Expr::Fun(e) => fun_doc(&e.hashee, names),
+        Expr::App(e) => app_doc(&e.hashee, names),
+        Expr::For(e) => for_doc(&e.hashee, names),
+        Expr::Deb(e) => deb_node_doc(&e.hashee, names),
+        Expr::Universe(e) => universe_node_doc(&e.hashee),
+        Expr::Lit(e) => lit_node_doc(&e.hashee),
+        Expr::Prim(e) => prim_node_doc(&e.hashee),
+        Expr::Meta(e) => meta_node_doc(&e.hashee),
+        Expr::Hole(e) => hole_node_doc(&e.hashee),
     }
+}
 
-    write!(f, "{indent}(")?;
-    let i1 = indent.incremented();
+fn ind_doc(ind: &Ind, names: DebNames) -> Doc {
+    let index_names = names.fresh_names(ind.index_types.hashee.iter().map(get_binder_base_name));
+    let self_name = names.fresh_names([get_ind_self_base_name(ind)]);
+
+    let tag = ind.tag.as_deref().map(str_literal_doc);
+    Doc::parenthesized(
+        [
+            keyword_doc("ind", &self_name, names),
+            universe_doc(ind.universe),
+            str_literal_doc(&ind.name),
+        ]
+        .into_iter()
+        .chain(tag)
+        .chain([
+            parenthesized_binder_types_doc(&ind.index_types.hashee, &index_names, names),
+            names.with_binders(&self_name, |names| {
+                parenthesized_vcon_defs_doc(&ind.vcon_defs.hashee, names)
+            }),
+        ]),
+    )
+}
 
-    for case in cases.iter() {
-        write!(f, "\n")?;
-        fmt_match_case(case, f, i1)?;
+fn universe_doc(universe: Universe) -> Doc {
+    let level = universe.level.0;
+    let set_or_prop = if universe.erasable { "Prop" } else { "Set" };
+    Doc::text(format!("{set_or_prop}{level}"))
+}
+
+fn str_literal_doc(str_literal: &StringValue) -> Doc {
+    let mut out = "\"".to_string();
+
+    for c in str_literal.0.chars() {
+        if c.is_ascii_alphanumeric() || " _`~!@#$%^&*()-_=+[]|;:',<.>/?".contains(c) {
+            out.push(c);
+        } else {
+            out += &format!("{{0x{:X}}}", u32::from(c));
+        }
     }
 
-    write!(f, "\n{indent})")?;
-    Ok(())
+    out.push('"');
+    Doc::Text(out)
+}
+
+fn parenthesized_vcon_defs_doc(defs: &[VconDef], names: DebNames) -> Doc {
+    Doc::parenthesized(defs.iter().map(|def| vcon_def_doc(def, names)))
 }
 
-fn fmt_match_case(case: &MatchCase, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    let i1 = indent.incremented();
-    let case_arity = case.arity;
-    write!(f, "{indent}(\n{i1}{case_arity}\n")?;
+fn vcon_def_doc(def: &VconDef, names: DebNames) -> Doc {
+    let param_names = names.fresh_names(def.param_types.hashee.iter().map(get_binder_base_name));
 
-    fmt_expr(case.return_val.clone(), f, i1)?;
-    write!(f, "\n{indent})")?;
-    Ok(())
+    let name = def.name.as_deref().map(str_literal_doc);
+    Doc::parenthesized(name.into_iter().chain([
+        parenthesized_binder_types_doc(&def.param_types.hashee, &param_names, names),
+        names.with_binders(&param_names, |names| {
+            parenthesized_expressions_doc(&def.index_args.hashee, names)
+        }),
+    ]))
 }
 
-fn fmt_fun(fun: &Fun, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    let i1 = indent.incremented();
-    write!(f, "{indent}(\n{i1}fun\n")?;
+/// Prints the vcon by name if its def has a name
+/// that no other def of the same ind has.
+/// Otherwise, prints the vcon by index.
+fn vcon_doc(vcon: &Vcon, names: DebNames) -> Doc {
+    let index_or_name = match get_unique_vcon_name(&vcon.ind.hashee, vcon.vcon_index) {
+        Some(name) => str_literal_doc(name),
+        None => Doc::text(vcon.vcon_index.to_string()),
+    };
+    Doc::parenthesized([
+        Doc::text("vcon"),
+        ind_doc(&vcon.ind.hashee, names),
+        index_or_name,
+    ])
+}
 
-    fmt_decreasing_index(fun.decreasing_index, f, i1)?;
-    write!(f, "\n")?;
+fn get_unique_vcon_name(ind: &Ind, vcon_index: usize) -> Option<&StringValue> {
+    let defs = &ind.vcon_defs.hashee;
+    let name = defs.get(vcon_index)?.name.as_deref()?;
+    let is_unique = defs
+        .iter()
+        .filter(|def| def.name.as_deref() == Some(name))
+        .count()
+        == 1;
+    is_unique.then_some(name)
+}
 
-    fmt_parenthesized_expressions(fun.param_types.clone(), f, i1)?;
-    write!(f, "\n")?;
+fn match_doc(m: &Match, names: DebNames) -> Doc {
+    // The return type binds the matchee type's index args,
+    // followed by the matchee itself.
+    let return_type_arity = m.return_type_arity;
+    let base_names = names.get_base_names(
+        m,
+        (0..return_type_arity)
+            .map(|i| {
+                if i + 1 == return_type_arity {
+                    "m".to_string()
+                } else {
+                    "i".to_string()
+                }
+            })
+            .collect(),
+    );
+    let return_type_binder_names = names.fresh_names(base_names);
+
+    Doc::parenthesized([
+        Doc::text("match"),
+        expr_doc(&m.matchee, names),
+        arity_doc(&return_type_binder_names, names),
+        names.with_binders(&return_type_binder_names, |names| {
+            expr_doc(&m.return_type, names)
+        }),
+        parenthesized_match_cases_doc(&m.cases.hashee, names),
+    ])
+}
+
+fn parenthesized_match_cases_doc(cases: &[MatchCase], names: DebNames) -> Doc {
+    Doc::parenthesized(cases.iter().map(|case| match_case_doc(case, names)))
+}
+
+fn match_case_doc(case: &MatchCase, names: DebNames) -> Doc {
+    let base_names = names.get_base_names(case, (0..case.arity).map(|_| "x".to_string()).collect());
+    let case_param_names = names.fresh_names(base_names);
 
-    fmt_expr(fun.return_type.clone(), f, i1)?;
-    write!(f, "\n")?;
+    let return_val = case.return_val.as_ref().map(|return_val| {
+        names.with_binders(&case_param_names, |names| expr_doc(return_val, names))
+    });
+    Doc::parenthesized(std::iter::once(arity_doc(&case_param_names, names)).chain(return_val))
+}
 
-    fmt_expr(fun.return_val.clone(), f, i1)?;
-    write!(f, "\n{indent})")?;
-    Ok(())
+fn fun_doc(fun: &Fun, names: DebNames) -> Doc {
+    let param_count = fun.param_types.hashee.len();
+    // The return val binds the params, followed by the fun itself.
+    let base_names = names.get_base_names(
+        fun,
+        fun.param_types
+            .hashee
+            .iter()
+            .map(get_binder_base_name)
+            .chain(std::iter::once("f".to_string()))
+            .collect(),
+    );
+    let binder_names = names.fresh_names(base_names);
+    let (param_names, self_name) = binder_names.split_at(param_count);
+
+    Doc::parenthesized([
+        keyword_doc("fun", self_name, names),
+        decreasing_index_doc(fun.decreasing_index),
+        parenthesized_binder_types_doc(&fun.param_types.hashee, param_names, names),
+        names.with_binders(param_names, |names| expr_doc(&fun.return_type, names)),
+        names.with_binders(&binder_names, |names| expr_doc(&fun.return_val, names)),
+    ])
 }
 
-fn fmt_decreasing_index(
-    index: Option<usize>,
-    f: &mut Formatter<'_>,
-    indent: Indentation,
-) -> FmtResult {
+fn decreasing_index_doc(index: Option<usize>) -> Doc {
     match index {
-        Some(index) => write!(f, "{indent}{index}"),
-        None => write!(f, "{indent}nonrec"),
+        Some(index) => Doc::text(index.to_string()),
+        None => Doc::text("nonrec"),
     }
 }
 
-fn fmt_app(app: &App, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
+fn app_doc(app: &App, names: DebNames) -> Doc {
     if app.args.hashee.is_empty() {
-        write!(f, "{indent}(")?;
-        fmt_expr(app.callee.clone(), f, indent)?;
-        write!(f, ")")?;
-        return Ok(());
+        return Doc::Concat(vec![
+            Doc::text("("),
+            expr_doc(&app.callee, names),
+            Doc::text(")"),
+        ]);
     }
 
-    write!(f, "{indent}(\n")?;
+    Doc::parenthesized(
+        std::iter::once(&app.callee)
+            .chain(app.args.hashee.iter())
+            .map(|expr| expr_doc(expr, names)),
+    )
+}
 
-    let i1 = indent.incremented();
-    fmt_expr(app.callee.clone(), f, i1)?;
+fn for_doc(for_: &For, names: DebNames) -> Doc {
+    let base_names = names.get_base_names(
+        for_,
+        for_.param_types
+            .hashee
+            .iter()
+            .map(get_binder_base_name)
+            .collect(),
+    );
+    let param_names = names.fresh_names(base_names);
+
+    Doc::parenthesized([
+        Doc::text("for"),
+        parenthesized_binder_types_doc(&for_.param_types.hashee, &param_names, names),
+        names.with_binders(&param_names, |names| expr_doc(&for_.return_type, names)),
+    ])
+}
 
-    for arg in app.args.hashee.iter() {
-        write!(f, "\n")?;
-        fmt_expr(arg.clone(), f, i1)?;
+fn deb_node_doc(node: &DebNode, names: DebNames) -> Doc {
+    if let Some(name) = names.get_name(node.deb) {
+        return Doc::text(name);
     }
 
-    write!(f, "\n{indent})")?;
-    Ok(())
+    Doc::text(node.deb.0.to_string())
+}
+
+fn universe_node_doc(node: &UniverseNode) -> Doc {
+    universe_doc(node.universe)
+}
+
+fn lit_node_doc(node: &LitNode) -> Doc {
+    match &node.lit {
+        Lit::U64(value) => Doc::text(format!("{value}u64")),
+        Lit::String(value) => str_literal_doc(value),
+    }
 }
 
-fn fmt_for(for_: &For, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    let i1 = indent.incremented();
-    write!(f, "{indent}(\n{i1}for\n")?;
+fn prim_node_doc(node: &PrimNode) -> Doc {
+    Doc::text(node.prim.name())
+}
 
-    fmt_parenthesized_expressions(for_.param_types.clone(), f, i1)?;
-    write!(f, "\n")?;
+fn meta_node_doc(node: &MetaNode) -> Doc {
+    let id = node.id.0;
+    Doc::text(format!("_{id}"))
+}
 
-    fmt_expr(for_.return_type.clone(), f, i1)?;
-    write!(f, "\n{indent})")?;
-    Ok(())
+fn hole_node_doc(_: &HoleNode) -> Doc {
+    Doc::text("?")
 }
 
-fn fmt_deb_node(node: &DebNode, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    let index = node.deb.0;
-    write!(f, "{indent}{index}")
+fn parenthesized_expressions_doc(parenthesized_expressions: &[Expr], names: DebNames) -> Doc {
+    Doc::parenthesized(
+        parenthesized_expressions
+            .iter()
+            .map(|expr| expr_doc(expr, names)),
+    )
 }
 
-fn fmt_universe_node(node: &UniverseNode, f: &mut Formatter<'_>, indent: Indentation) -> FmtResult {
-    fmt_universe(node.universe, f, indent)
+/// Returns the doc for the types of a dependent binder group (e.g., param types),
+/// where each type is under the binders of the types before it.
+///
+/// If `names.named` is `true`, each type is prefixed with its binder's name.
+fn parenthesized_binder_types_doc(types: &[Expr], binder_names: &[String], names: DebNames) -> Doc {
+    Doc::parenthesized(types.iter().enumerate().map(|(index, type_)| {
+        names.with_binders(&binder_names[..index], |names| {
+            if !names.named {
+                return expr_doc(type_, names);
+            }
+
+            let name = &binder_names[index];
+            Doc::Concat(vec![Doc::text(format!("{name}: ")), expr_doc(type_, names)])
+        })
+    }))
 }
 
-fn fmt_parenthesized_expressions(
-    parenthesized_expressions: RcHashedVec<Expr>,
-    f: &mut Formatter<'_>,
-    indent: Indentation,
-) -> FmtResult {
-    if parenthesized_expressions.hashee.is_empty() {
-        return write!(f, "{indent}()");
+/// Returns the doc for `keyword`,
+/// followed by the binder names
+/// (e.g., the name a `fun` uses to refer to itself)
+/// if `names.named` is `true`.
+fn keyword_doc(keyword: &str, binder_names: &[String], names: DebNames) -> Doc {
+    if !names.named {
+        return Doc::text(keyword);
     }
 
-    write!(f, "{indent}(")?;
-    let i1 = indent.incremented();
+    let mut out = keyword.to_string();
+    for name in binder_names {
+        out.push(' ');
+        out += name;
+    }
+    Doc::Text(out)
+}
 
-    for expr in parenthesized_expressions.hashee.iter() {
-        write!(f, "\n")?;
-        fmt_expr(expr.clone(), f, i1)?;
+/// Returns the doc for the number of binders,
+/// or if `names.named` is `true`, the binders' names.
+fn arity_doc(binder_names: &[String], names: DebNames) -> Doc {
+    if !names.named {
+        return Doc::text(binder_names.len().to_string());
     }
 
-    write!(f, "\n{indent})")?;
-    Ok(())
+    Doc::text(format!("({})", binder_names.join(" ")))
 }
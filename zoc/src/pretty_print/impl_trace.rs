@@ -0,0 +1,38 @@
+use super::*;
+
+use crate::eval::{ReductionKind, Trace};
+
+/// Prints the initial term, followed by each step's
+/// kind, redex location, and resulting term.
+impl Display for PrettyPrint<'_, Trace> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let trace = self.0;
+        writeln!(f, "{}", trace.initial.pretty_printed())?;
+
+        for (i, step) in trace.steps.iter().enumerate() {
+            let step_number = i + 1;
+            let kind = step.kind.pretty_printed();
+            let path: Vec<usize> = step.path.iter().map(|edge| edge.0).collect();
+            writeln!(f, "--> [{step_number}] {kind} at {path:?}")?;
+            writeln!(f, "{}", step.result.pretty_printed())?;
+        }
+
+        if !trace.reached_normal_form {
+            writeln!(f, "... (stopped before reaching a normal form)")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for PrettyPrint<'_, ReductionKind> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self.0 {
+            ReductionKind::Beta => "beta",
+            ReductionKind::Iota => "iota",
+            ReductionKind::Unfold => "unfold",
+            ReductionKind::Prim => "prim",
+        };
+        write!(f, "{name}")
+    }
+}
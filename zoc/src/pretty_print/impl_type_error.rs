@@ -22,6 +22,8 @@ where
     ast::For<A>: GetOptSpan,
     ast::DebNode<A>: GetOptSpan,
     ast::UniverseNode<A>: GetOptSpan,
+    ast::MetaNode<A>: GetOptSpan,
+    ast::HoleNode<A>: GetOptSpan,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.0 {
@@ -35,6 +37,7 @@ where
                         "deb",
                         &deb_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(deb.opt_span()),
                     )
                     .field("tcon_len", tcon_len)
@@ -50,6 +53,22 @@ where
                         &vcon_minimal
                             .hashee
                             .pretty_printed()
+                            .named()
+                            .with_opt_location_appended(vcon.opt_span()),
+                    )
+                    .finish()
+            }
+
+            TypeError::UnknownVconName(vcon) => {
+                let mut remover = AuxDataRemover::default();
+                let vcon_minimal = remover.convert_vcon(rc_hashed(vcon.clone()));
+                f.debug_struct("TypeError::UnknownVconName")
+                    .field(
+                        "vcon",
+                        &vcon_minimal
+                            .hashee
+                            .pretty_printed()
+                            .named()
                             .with_opt_location_appended(vcon.opt_span()),
                     )
                     .finish()
@@ -63,9 +82,10 @@ where
                         "expr",
                         &expr_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(expr.opt_span()),
                     )
-                    .field("type_", &type_.raw().pretty_printed())
+                    .field("type_", &type_.raw().pretty_printed().named())
                     .finish()
             }
 
@@ -82,6 +102,7 @@ where
                         "index_or_param_type",
                         &index_or_param_type_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(index_or_param_type.opt_span()),
                     )
                     .field("universe", &universe)
@@ -90,6 +111,7 @@ where
                         &ind_minimal
                             .hashee
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(ind.opt_span()),
                     )
                     .finish()
@@ -107,6 +129,7 @@ where
                         "def",
                         &def_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(def.opt_span()),
                     )
                     .field("expected", expected)
@@ -122,9 +145,10 @@ where
                         "expr",
                         &expr_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(expr.opt_span()),
                     )
-                    .field("type_", &type_.raw().pretty_printed())
+                    .field("type_", &type_.raw().pretty_printed().named())
                     .finish()
             }
 
@@ -140,9 +164,13 @@ where
                         &match_minimal
                             .hashee
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(match_.opt_span()),
                     )
-                    .field("matchee_type_ind", &matchee_type_ind.raw().pretty_printed())
+                    .field(
+                        "matchee_type_ind",
+                        &matchee_type_ind.raw().pretty_printed().named(),
+                    )
                     .finish()
             }
 
@@ -154,7 +182,7 @@ where
                 let match_minimal = remover.convert_match(rc_hashed(match_.clone()));
                 let matchee_type_args: Vec<_> = matchee_type_args
                     .iter()
-                    .map(|arg| arg.raw().pretty_printed())
+                    .map(|arg| arg.raw().pretty_printed().named())
                     .collect();
                 f.debug_struct("TypeError::WrongMatchReturnTypeArity")
                     .field(
@@ -162,6 +190,7 @@ where
                         &match_minimal
                             .hashee
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(match_.opt_span()),
                     )
                     .field("matchee_type_args", &matchee_type_args)
@@ -184,6 +213,26 @@ where
                         &match_minimal
                             .hashee
                             .pretty_printed()
+                            .named()
+                            .with_opt_location_appended(match_.opt_span()),
+                    )
+                    .field("match_case_index", match_case_index)
+                    .finish()
+            }
+
+            TypeError::MatchCaseIsNotImpossible {
+                match_,
+                match_case_index,
+            } => {
+                let mut remover = AuxDataRemover::default();
+                let match_minimal = remover.convert_match(rc_hashed(match_.clone()));
+                f.debug_struct("TypeError::MatchCaseIsNotImpossible")
+                    .field(
+                        "match_",
+                        &match_minimal
+                            .hashee
+                            .pretty_printed()
+                            .named()
                             .with_opt_location_appended(match_.opt_span()),
                     )
                     .field("match_case_index", match_case_index)
@@ -202,10 +251,14 @@ where
                         "expr",
                         &expr_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(expr.opt_span()),
                     )
-                    .field("expected_type", &expected_type.raw().pretty_printed())
-                    .field("actual_type", &actual_type.raw().pretty_printed())
+                    .field(
+                        "expected_type",
+                        &expected_type.raw().pretty_printed().named(),
+                    )
+                    .field("actual_type", &actual_type.raw().pretty_printed().named())
                     .finish()
             }
 
@@ -218,9 +271,10 @@ where
                         &app_minimal
                             .hashee
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(app.opt_span()),
                     )
-                    .field("callee_type", &callee_type.raw().pretty_printed())
+                    .field("callee_type", &callee_type.raw().pretty_printed().named())
                     .finish()
             }
 
@@ -238,9 +292,10 @@ where
                         &app_minimal
                             .hashee
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(app.opt_span()),
                     )
-                    .field("callee_type", &callee_type.raw().pretty_printed())
+                    .field("callee_type", &callee_type.raw().pretty_printed().named())
                     .field("expected", expected)
                     .field("actual", actual)
                     .finish()
@@ -254,6 +309,7 @@ where
                         "fun",
                         &fun_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(fun.opt_span()),
                     )
                     .finish()
@@ -268,6 +324,7 @@ where
                         &app_minimal
                             .hashee
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(app.opt_span()),
                     )
                     .finish()
@@ -281,6 +338,7 @@ where
                         "for_",
                         &for_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(for_.opt_span()),
                     )
                     .finish()
@@ -302,12 +360,14 @@ where
                         &app_minimal
                             .hashee
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(app.opt_span()),
                     )
                     .field(
                         "callee_deb_definition_src",
                         &callee_deb_definition_src_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(callee_deb_definition_src.opt_span()),
                     )
                     .field(
@@ -333,12 +393,14 @@ where
                         "deb",
                         &deb_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(deb.opt_span()),
                     )
                     .field(
                         "definition_src",
                         &definition_src_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(definition_src.opt_span()),
                     )
                     .finish()
@@ -359,12 +421,14 @@ where
                         "deb",
                         &deb_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(deb.opt_span()),
                     )
                     .field(
                         "definition_src",
                         &definition_src_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(definition_src.opt_span()),
                     )
                     .finish()
@@ -378,6 +442,7 @@ where
                         "fun",
                         &fun_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(fun.opt_span()),
                     )
                     .finish()
@@ -396,12 +461,13 @@ where
                         "def",
                         &def_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(def.opt_span()),
                     )
                     .field("param_type_index", param_type_index)
                     .field(
                         "normalized_param_type",
-                        &normalized_param_type.raw().pretty_printed(),
+                        &normalized_param_type.raw().pretty_printed().named(),
                     )
                     .field(
                         "path_from_param_type_to_problematic_deb",
@@ -423,12 +489,13 @@ where
                         "def",
                         &def_minimal
                             .pretty_printed()
+                            .named()
                             .with_opt_location_appended(def.opt_span()),
                     )
                     .field("index_arg_index", index_arg_index)
                     .field(
                         "normalized_index_arg",
-                        &normalized_index_arg.raw().pretty_printed(),
+                        &normalized_index_arg.raw().pretty_printed().named(),
                     )
                     .field(
                         "path_from_index_arg_to_problematic_deb",
@@ -436,6 +503,138 @@ where
                     )
                     .finish()
             }
+
+            TypeError::CannotInferMetaType { meta } => {
+                let mut remover = AuxDataRemover::default();
+                let meta_minimal = remover.convert_meta_node(meta);
+                f.debug_struct("TypeError::CannotInferMetaType")
+                    .field(
+                        "meta",
+                        &meta_minimal
+                            .hashee
+                            .pretty_printed()
+                            .named()
+                            .with_opt_location_appended(meta.opt_span()),
+                    )
+                    .finish()
+            }
+
+            TypeError::CannotInferHoleType { hole } => {
+                let mut remover = AuxDataRemover::default();
+                let hole_minimal = remover.convert_hole_node(hole);
+                f.debug_struct("TypeError::CannotInferHoleType")
+                    .field(
+                        "hole",
+                        &hole_minimal
+                            .hashee
+                            .pretty_printed()
+                            .named()
+                            .with_opt_location_appended(hole.opt_span()),
+                    )
+                    .finish()
+            }
+
+            TypeError::UnsolvedMetas { metas } => {
+                let mut remover = AuxDataRemover::default();
+                let metas_minimal: Vec<_> = metas
+                    .iter()
+                    .map(|meta| remover.convert_meta_node(meta))
+                    .collect();
+                let metas_printed: Vec<_> = metas_minimal
+                    .iter()
+                    .zip(metas)
+                    .map(|(meta_minimal, meta)| {
+                        meta_minimal
+                            .hashee
+                            .pretty_printed()
+                            .named()
+                            .with_opt_location_appended(meta.opt_span())
+                    })
+                    .collect();
+                f.debug_struct("TypeError::UnsolvedMetas")
+                    .field("metas", &metas_printed)
+                    .finish()
+            }
+
+            TypeError::InvalidCode { placeholder } => {
+                let mut remover = AuxDataRemover::default();
+                let placeholder_minimal = remover.convert_meta_node(placeholder);
+                f.debug_struct("TypeError::InvalidCode")
+                    .field(
+                        "placeholder",
+                        &placeholder_minimal
+                            .hashee
+                            .pretty_printed()
+                            .named()
+                            .with_opt_location_appended(placeholder.opt_span()),
+                    )
+                    .finish()
+            }
+        }
+    }
+}
+
+/// The span of the node the error is primarily about.
+/// This is used to sort errors by where they occur.
+impl<A> GetOptSpan for TypeError<A>
+where
+    A: AuxDataFamilyWhoseAstFamilyImplsGetOptSpan,
+    ast::Expr<A>: GetOptSpan,
+    ast::Ind<A>: GetOptSpan,
+    ast::VconDef<A>: GetOptSpan,
+    ast::Vcon<A>: GetOptSpan,
+    ast::Match<A>: GetOptSpan,
+    ast::MatchCase<A>: GetOptSpan,
+    ast::Fun<A>: GetOptSpan,
+    ast::App<A>: GetOptSpan,
+    ast::For<A>: GetOptSpan,
+    ast::DebNode<A>: GetOptSpan,
+    ast::UniverseNode<A>: GetOptSpan,
+    ast::MetaNode<A>: GetOptSpan,
+    ast::HoleNode<A>: GetOptSpan,
+{
+    fn opt_span(&self) -> Option<Span> {
+        match self {
+            TypeError::InvalidDeb { deb, .. } => deb.opt_span(),
+            TypeError::InvalidVconIndex(vcon) => vcon.opt_span(),
+            TypeError::UnknownVconName(vcon) => vcon.opt_span(),
+            TypeError::UnexpectedNonTypeExpression { expr, .. } => expr.opt_span(),
+            TypeError::UniverseInconsistencyInIndDef {
+                index_or_param_type,
+                ..
+            } => index_or_param_type.opt_span(),
+            TypeError::WrongNumberOfIndexArguments { def, .. } => def.opt_span(),
+            TypeError::NonInductiveMatcheeType { expr, .. } => expr.opt_span(),
+            TypeError::WrongNumberOfMatchCases { match_, .. } => match_.opt_span(),
+            TypeError::WrongMatchReturnTypeArity { match_, .. } => match_.opt_span(),
+            TypeError::WrongMatchCaseArity { match_, .. } => match_.opt_span(),
+            TypeError::MatchCaseIsNotImpossible {
+                match_,
+                match_case_index,
+            } => match_
+                .cases
+                .hashee
+                .get(*match_case_index)
+                .and_then(GetOptSpan::opt_span)
+                .or_else(|| match_.opt_span()),
+            TypeError::TypeMismatch { expr, .. } => expr.opt_span(),
+            TypeError::CalleeTypeIsNotAForExpression { app, .. } => app.opt_span(),
+            TypeError::WrongNumberOfAppArguments { app, .. } => app.opt_span(),
+            TypeError::FunHasZeroParams { fun } => fun.opt_span(),
+            TypeError::AppHasZeroArgs { app } => app.opt_span(),
+            TypeError::ForHasZeroParams { for_ } => for_.opt_span(),
+            TypeError::IllegalRecursiveCall { app, .. } => app.opt_span(),
+            TypeError::RecursiveFunParamInNonCalleePosition { deb, .. } => deb.opt_span(),
+            TypeError::DeclaredFunNonrecursiveButUsedRecursiveFunParam { deb, .. } => {
+                deb.opt_span()
+            }
+            TypeError::DecreasingArgIndexTooBig { fun } => fun.opt_span(),
+            TypeError::VconDefParamTypeFailsStrictPositivityCondition { def, .. } => def.opt_span(),
+            TypeError::RecursiveIndParamAppearsInVconDefIndexArg { def, .. } => def.opt_span(),
+            TypeError::CannotInferMetaType { meta } => meta.opt_span(),
+            TypeError::CannotInferHoleType { hole } => hole.opt_span(),
+            TypeError::UnsolvedMetas { metas } => metas.first().and_then(GetOptSpan::opt_span),
+            TypeError::InvalidCode { placeholder } => placeholder.opt_span(),
         }
     }
 }
@@ -443,7 +642,7 @@ where
 /// A marker trait for aux data families
 /// whose corresponding AST families
 /// implement `GetOptSpan`.
-trait AuxDataFamilyWhoseAstFamilyImplsGetOptSpan: AuxDataFamily
+pub trait AuxDataFamilyWhoseAstFamilyImplsGetOptSpan: AuxDataFamily
 where
     ast::Expr<Self>: GetOptSpan,
     ast::Ind<Self>: GetOptSpan,
@@ -456,6 +655,8 @@ where
     ast::For<Self>: GetOptSpan,
     ast::DebNode<Self>: GetOptSpan,
     ast::UniverseNode<Self>: GetOptSpan,
+    ast::MetaNode<Self>: GetOptSpan,
+    ast::HoleNode<Self>: GetOptSpan,
 {
 }
 
@@ -516,6 +717,16 @@ impl GetOptSpan for spanned_ast::UniverseNode {
         Some(self.span())
     }
 }
+impl GetOptSpan for spanned_ast::MetaNode {
+    fn opt_span(&self) -> Option<Span> {
+        Some(self.span())
+    }
+}
+impl GetOptSpan for spanned_ast::HoleNode {
+    fn opt_span(&self) -> Option<Span> {
+        Some(self.span())
+    }
+}
 
 impl AuxDataFamilyWhoseAstFamilyImplsGetOptSpan for UnitAuxDataFamily {}
 
@@ -574,8 +785,18 @@ impl GetOptSpan for minimal_ast::UniverseNode {
         None
     }
 }
+impl GetOptSpan for minimal_ast::MetaNode {
+    fn opt_span(&self) -> Option<Span> {
+        None
+    }
+}
+impl GetOptSpan for minimal_ast::HoleNode {
+    fn opt_span(&self) -> Option<Span> {
+        None
+    }
+}
 
-trait GetOptSpan {
+pub trait GetOptSpan {
     fn opt_span(&self) -> Option<Span>;
 }
 
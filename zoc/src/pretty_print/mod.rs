@@ -14,9 +14,24 @@ where
     }
 }
 
+mod doc;
+pub use doc::*;
+
 mod impl_erasability_error;
+mod impl_hole_goal;
 mod impl_minimal_ast;
+mod impl_trace;
 mod impl_type_error;
+pub use impl_type_error::{AuxDataFamilyWhoseAstFamilyImplsGetOptSpan, GetOptSpan};
+
+mod name_hints;
+pub use name_hints::*;
+
+mod source_snippet;
+pub use source_snippet::*;
+
+#[cfg(test)]
+mod tests;
 
 pub trait PrettyPrinted {
     fn pretty_printed(&self) -> PrettyPrint<'_, Self>
@@ -88,30 +103,81 @@ where
     }
 }
 
-pub const SOFT_TAB: &str = "    ";
+/// Wraps an expression so that its free debs are printed
+/// using `free_deb_names` rather than as indices.
+///
+/// The names are ordered like a type context.
+/// That is, the name of free deb `0` is the _last_ element.
+#[derive(Clone, Copy, Debug)]
+pub struct WithFreeDebNames<'a, T: ?Sized> {
+    pub val: &'a T,
+    pub free_deb_names: &'a [String],
+}
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-struct Indentation {
-    pub soft_tab_count: usize,
+/// Options for pretty printing Zo expressions.
+#[derive(Clone, Copy, Debug)]
+pub struct PrettyPrintOptions<'a> {
+    /// The maximum line width the layout tries to stay within.
+    pub width: usize,
+    /// If `true`, every binder is given a readable name,
+    /// and debs that refer to binders within the printed expression
+    /// are printed as those names rather than as indices.
+    ///
+    /// Free debs are still printed as indices.
+    pub named_debs: bool,
+    /// Only used if `named_debs` is `true`.
+    pub name_hints: Option<&'a NameHints>,
 }
 
-impl Indentation {
-    pub fn incremented(self) -> Self {
+impl Default for PrettyPrintOptions<'_> {
+    fn default() -> Self {
         Self {
-            soft_tab_count: self.soft_tab_count + 1,
+            width: DEFAULT_LINE_WIDTH,
+            named_debs: false,
+            name_hints: None,
         }
     }
 }
 
-impl Display for Indentation {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        for _ in 0..self.soft_tab_count {
-            f.write_str(SOFT_TAB)?;
+impl PrettyPrintOptions<'_> {
+    pub const NAMED: PrettyPrintOptions<'static> = PrettyPrintOptions {
+        width: DEFAULT_LINE_WIDTH,
+        named_debs: true,
+        name_hints: None,
+    };
+}
+
+#[derive(Clone, Copy)]
+pub struct PrettyPrintWithOptions<'a, T: ?Sized> {
+    pub val: &'a T,
+    pub options: PrettyPrintOptions<'a>,
+}
+
+impl<'a, T: ?Sized> PrettyPrint<'a, T> {
+    pub fn with_options(self, options: PrettyPrintOptions<'a>) -> PrettyPrintWithOptions<'a, T> {
+        PrettyPrintWithOptions {
+            val: self.0,
+            options,
         }
-        Ok(())
+    }
+
+    /// Shorthand for `self.with_options(PrettyPrintOptions::NAMED)`.
+    pub fn named(self) -> PrettyPrintWithOptions<'a, T> {
+        self.with_options(PrettyPrintOptions::NAMED)
     }
 }
 
+impl<'a, T: ?Sized> Debug for PrettyPrintWithOptions<'a, T>
+where
+    PrettyPrintWithOptions<'a, T>: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self, f)
+    }
+}
+
+pub const SOFT_TAB: &str = "    ";
+
 pub struct AppendLocation<T> {
     pub val: T,
     pub span: Span,
@@ -0,0 +1,43 @@
+use crate::hash::{Digest, NoHashHashMap};
+
+/// Binder names recovered from a front end's source code
+/// (e.g., the param names of a May or June function),
+/// for use by the named pretty printer.
+///
+/// The names are keyed by the digest of the node
+/// that introduces the binders
+/// (i.e., a `Fun`, `For`, `Match`, or `MatchCase`).
+/// The names are outermost first.
+/// For a `fun`, this means the params' names,
+/// followed by the fun's own name.
+/// For a `match`, this means the names bound by the return type
+/// (i.e., the index names, followed by the matchee name).
+///
+/// Anonymous binders are named `_`.
+/// The printer picks its own names for those.
+#[derive(Clone, Debug, Default)]
+pub struct NameHints {
+    binder_names: NoHashHashMap<Digest, Vec<String>>,
+}
+
+impl NameHints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, binder_digest: Digest, names: Vec<String>) {
+        self.binder_names.insert(binder_digest, names);
+    }
+
+    pub fn get(&self, binder_digest: &Digest) -> Option<&[String]> {
+        self.binder_names.get(binder_digest).map(Vec::as_slice)
+    }
+
+    pub fn len(&self) -> usize {
+        self.binder_names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.binder_names.is_empty()
+    }
+}
@@ -0,0 +1,327 @@
+use super::*;
+
+use crate::{
+    diagnostic::{Diagnostic, Severity, ToDiagnostic},
+    syntax_tree::token::ByteIndex,
+};
+
+/// Renders `val` (e.g., a `TypeError<SpanAuxDataFamily>`)
+/// along with the lines of `src` it refers to.
+/// The primary span is underlined with `^`,
+/// and related spans are underlined with `-`.
+///
+/// If `color` is true, ANSI color codes are emitted.
+pub struct SourceSnippet<'a, T: ?Sized> {
+    pub src: &'a str,
+    pub val: &'a T,
+    pub color: bool,
+}
+
+impl<T> Display for SourceSnippet<'_, T>
+where
+    T: ToDiagnostic + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let diagnostic = self.val.to_diagnostic();
+        SnippetRenderer {
+            src: self.src,
+            lines: LineIndex::new(self.src),
+            diagnostic: &diagnostic,
+            paint: Paint { color: self.color },
+        }
+        .render(f)
+    }
+}
+
+/// A 1-based line and column.
+/// Columns are counted in chars, not bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LineCol {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Converts byte indices into line-column pairs.
+#[derive(Clone, Debug)]
+pub struct LineIndex<'a> {
+    src: &'a str,
+    /// The byte index at which each line starts.
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(src: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(src.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { src, line_starts }
+    }
+
+    pub fn line_col(&self, index: ByteIndex) -> LineCol {
+        let index = index.0.min(self.src.len());
+        let line_index = match self.line_starts.binary_search(&index) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        let line_start = self.line_starts[line_index];
+        LineCol {
+            line: line_index + 1,
+            column: self.src[line_start..index].chars().count() + 1,
+        }
+    }
+
+    /// Returns the text of the given (1-based) line,
+    /// excluding the line terminator.
+    pub fn line(&self, line: usize) -> &'a str {
+        let start = self.line_starts[line - 1];
+        let end = self
+            .line_starts
+            .get(line)
+            .map(|next_start| next_start - 1)
+            .unwrap_or(self.src.len());
+        self.src[start..end].trim_end_matches('\r')
+    }
+}
+
+struct SnippetRenderer<'a> {
+    src: &'a str,
+    lines: LineIndex<'a>,
+    diagnostic: &'a Diagnostic,
+    paint: Paint,
+}
+
+/// The lines of a span that are longer than this
+/// are elided (except for the first and last lines).
+const MAX_SPAN_LINES: usize = 4;
+
+impl SnippetRenderer<'_> {
+    fn render(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let diagnostic = self.diagnostic;
+        let severity = match diagnostic.severity {
+            Severity::Error => self.paint.error("error"),
+            Severity::Warning => self.paint.warning("warning"),
+        };
+        writeln!(
+            f,
+            "{severity}[{}]: {}",
+            diagnostic.code,
+            self.paint.bold(&diagnostic.message)
+        )?;
+
+        let gutter_width = self.gutter_width();
+
+        if let Some(span) = diagnostic.primary_span {
+            let LineCol { line, column } = self.lines.line_col(span.0);
+            let arrow = self.paint.gutter("-->");
+            writeln!(f, "{:gutter_width$}{arrow} {line}:{column}", "")?;
+            self.render_span(f, span, '^', None, gutter_width)?;
+        }
+
+        for related in &diagnostic.related {
+            let LineCol { line, column } = self.lines.line_col(related.span.0);
+            let arrow = self.paint.gutter(":::");
+            writeln!(f, "{:gutter_width$}{arrow} {line}:{column}", "")?;
+            self.render_span(f, related.span, '-', Some(related.label), gutter_width)?;
+        }
+
+        if let Some(expected_type) = &diagnostic.expected_type {
+            self.render_note(f, "expected type", expected_type, gutter_width)?;
+        }
+        if let Some(actual_type) = &diagnostic.actual_type {
+            self.render_note(f, "actual type", actual_type, gutter_width)?;
+        }
+
+        Ok(())
+    }
+
+    fn gutter_width(&self) -> usize {
+        let spans = self
+            .diagnostic
+            .primary_span
+            .iter()
+            .chain(self.diagnostic.related.iter().map(|related| &related.span));
+        let max_line = spans
+            .map(|span| self.lines.line_col(span.1).line)
+            .max()
+            .unwrap_or(1);
+        max_line.to_string().len() + 1
+    }
+
+    fn render_span(
+        &self,
+        f: &mut Formatter<'_>,
+        span: Span,
+        underline_char: char,
+        label: Option<&str>,
+        gutter_width: usize,
+    ) -> FmtResult {
+        let start = self.lines.line_col(span.0);
+        let end = self.lines.line_col(self.last_char_index(span));
+
+        let bar = self.paint.gutter("|");
+        writeln!(f, "{:gutter_width$}{bar}", "")?;
+
+        let line_count = end.line - start.line + 1;
+        for line in start.line..=end.line {
+            let is_elided = line_count > MAX_SPAN_LINES && line > start.line + 1 && line < end.line;
+            if is_elided {
+                if line == start.line + 2 {
+                    writeln!(f, "{}", self.paint.gutter("..."))?;
+                }
+                continue;
+            }
+
+            let text = self.lines.line(line);
+            let line_number = self
+                .paint
+                .gutter(&format!("{line:>w$}", w = gutter_width - 1));
+            writeln!(f, "{line_number} {bar} {}", expand_tabs(text))?;
+
+            let (underline_start, underline_end) = if line == start.line && line == end.line {
+                (start.column, end.column)
+            } else if line == start.line {
+                (start.column, text.chars().count().max(start.column))
+            } else if line == end.line {
+                (first_non_whitespace_column(text), end.column)
+            } else {
+                (first_non_whitespace_column(text), text.chars().count())
+            };
+
+            // A span may end with a line terminator,
+            // which we do not underline.
+            let underline_end = underline_end.min(text.chars().count().max(underline_start));
+            let padding = display_width(text, underline_start - 1);
+            let width = display_width(text, underline_end).saturating_sub(padding);
+            let underline: String = std::iter::repeat_n(underline_char, width.max(1)).collect();
+            let underline = if underline_char == '^' {
+                self.paint.error(&underline)
+            } else {
+                self.paint.gutter(&underline)
+            };
+            write!(f, "{:gutter_width$}{bar} {:padding$}{underline}", "", "")?;
+            match label {
+                Some(label) if line == end.line => writeln!(f, " {label}")?,
+                _ => writeln!(f)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render_note(
+        &self,
+        f: &mut Formatter<'_>,
+        name: &str,
+        val: &str,
+        gutter_width: usize,
+    ) -> FmtResult {
+        let eq = self.paint.gutter("=");
+        let prefix = format!("{name}: ");
+        let mut val_lines = val.lines();
+        writeln!(
+            f,
+            "{:gutter_width$}{eq} {prefix}{}",
+            "",
+            val_lines.next().unwrap_or("")
+        )?;
+        for val_line in val_lines {
+            writeln!(
+                f,
+                "{:gutter_width$}  {:w$}{val_line}",
+                "",
+                "",
+                w = prefix.len()
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the last char covered by `span`.
+    ///
+    /// The span of a parenthesized node ends at its closing paren
+    /// (i.e., the end is inclusive),
+    /// whereas the span of a token is end-exclusive.
+    fn last_char_index(&self, span: Span) -> ByteIndex {
+        let is_parenthesized = self
+            .src
+            .get(span.0 .0..)
+            .is_some_and(|s| s.starts_with('('))
+            && self
+                .src
+                .get(span.1 .0..)
+                .is_some_and(|s| s.starts_with(')'));
+        if is_parenthesized || span.1 <= span.0 {
+            span.1.max(span.0)
+        } else {
+            self.prev_char_index(span.1)
+        }
+    }
+
+    fn prev_char_index(&self, index: ByteIndex) -> ByteIndex {
+        let index = index.0.min(self.src.len());
+        let prev = self.src[..index]
+            .char_indices()
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        ByteIndex(prev)
+    }
+}
+
+const TAB_WIDTH: usize = 4;
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', &" ".repeat(TAB_WIDTH))
+}
+
+/// Returns the display width of the first `char_count` chars of `text`.
+fn display_width(text: &str, char_count: usize) -> usize {
+    let chars_width: usize = text
+        .chars()
+        .take(char_count)
+        .map(|c| if c == '\t' { TAB_WIDTH } else { 1 })
+        .sum();
+    let missing = char_count.saturating_sub(text.chars().count());
+    chars_width + missing
+}
+
+fn first_non_whitespace_column(text: &str) -> usize {
+    text.chars().take_while(|c| c.is_whitespace()).count() + 1
+}
+
+#[derive(Clone, Copy)]
+struct Paint {
+    color: bool,
+}
+
+impl Paint {
+    const RED_BOLD: &'static str = "\x1b[1;31m";
+    const YELLOW_BOLD: &'static str = "\x1b[1;33m";
+    const BLUE_BOLD: &'static str = "\x1b[1;34m";
+    const BOLD: &'static str = "\x1b[1m";
+    const RESET: &'static str = "\x1b[0m";
+
+    fn error(self, s: &str) -> String {
+        self.apply(Self::RED_BOLD, s)
+    }
+
+    fn warning(self, s: &str) -> String {
+        self.apply(Self::YELLOW_BOLD, s)
+    }
+
+    fn gutter(self, s: &str) -> String {
+        self.apply(Self::BLUE_BOLD, s)
+    }
+
+    fn bold(self, s: &str) -> String {
+        self.apply(Self::BOLD, s)
+    }
+
+    fn apply(self, code: &str, s: &str) -> String {
+        if self.color {
+            format!("{code}{s}{}", Self::RESET)
+        } else {
+            s.to_string()
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+fn words(words: &[&str]) -> Doc {
+    Doc::parenthesized(words.iter().map(|word| Doc::text(*word)))
+}
+
+#[test]
+fn group_is_flat_if_it_fits() {
+    let doc = words(&["foo", "bar", "baz"]);
+    assert_eq!("(foo bar baz)", doc.pretty(13));
+}
+
+#[test]
+fn group_is_broken_if_it_does_not_fit() {
+    let doc = words(&["foo", "bar", "baz"]);
+    assert_eq!("(\n    foo\n    bar\n    baz\n)", doc.pretty(12));
+}
+
+#[test]
+fn inner_group_can_be_flat_when_outer_group_is_broken() {
+    let doc = Doc::parenthesized([
+        Doc::text("app"),
+        words(&["a", "b"]),
+        words(&["c", "d", "e", "f", "g", "h"]),
+    ]);
+    assert_eq!(
+        "(\n    app\n    (a b)\n    (c d e f g h)\n)",
+        doc.pretty(20)
+    );
+}
+
+#[test]
+fn text_after_group_counts_toward_width() {
+    // The inner group would fit on its own,
+    // but not together with the closing parentheses
+    // of the groups that enclose it.
+    let doc = Doc::Concat(vec![
+        Doc::text("(("),
+        words(&["abc", "def"]),
+        Doc::text("))"),
+    ]);
+    assert_eq!("(((abc def)))", doc.pretty(13));
+    assert_eq!("(((\n    abc\n    def\n)))", doc.pretty(12));
+}
+
+#[test]
+fn empty_parenthesized_is_unit() {
+    assert_eq!("()", Doc::parenthesized([]).pretty(0));
+}
+
+#[test]
+fn narrow_width() {
+    let src =
+        r#"(for (Set0 (for (0) Set0)) ((fun nonrec (U64 String) U64 (u64_add 2 2)) 9u64 "foo"))"#;
+    let expr = parse_minimal_ast_or_panic(src);
+    let options = PrettyPrintOptions {
+        width: 30,
+        ..PrettyPrintOptions::NAMED
+    };
+    insta::assert_display_snapshot!(expr.pretty_printed().with_options(options));
+}
+
+#[test]
+fn wide_width() {
+    let src =
+        r#"(for (Set0 (for (0) Set0)) ((fun nonrec (U64 String) U64 (u64_add 2 2)) 9u64 "foo"))"#;
+    let expr = parse_minimal_ast_or_panic(src);
+    let options = PrettyPrintOptions {
+        width: 200,
+        ..PrettyPrintOptions::NAMED
+    };
+    insta::assert_display_snapshot!(expr.pretty_printed().with_options(options));
+}
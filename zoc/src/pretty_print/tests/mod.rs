@@ -0,0 +1,7 @@
+use crate::{pretty_print::*, test_utils::*};
+
+mod layout;
+mod named;
+mod source_snippet;
+mod trace;
+mod vcon_names;
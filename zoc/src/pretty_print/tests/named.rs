@@ -0,0 +1,111 @@
+use super::*;
+
+use crate::{hash::Hashed, syntax_tree::ast::prelude::*};
+
+const NAT_DEF: (&str, &str) = (
+    "<NAT>",
+    r#"(ind Set0 "Nat" () (
+    (() ())
+    ((0) ())
+))"#,
+);
+
+const ADD_DEF: (&str, &str) = (
+    "<ADD>",
+    "
+(fun 0 (<NAT> <NAT>) <NAT>
+    (match 2 1 <NAT> (
+        (0 1)
+        (1 (<SUCC> (1 0 2)))
+    ))
+)",
+);
+
+fn add_src() -> String {
+    substitute_with_compounding([NAT_DEF, ("<SUCC>", "(vcon <NAT> 1)"), ADD_DEF], "<ADD>")
+}
+
+fn get_fun(expr: &minimal_ast::Expr) -> &minimal_ast::Fun {
+    match expr {
+        minimal_ast::Expr::Fun(fun) => &fun.hashee,
+        _ => panic!("expected a fun"),
+    }
+}
+
+#[test]
+fn add() {
+    let add = parse_minimal_ast_or_panic(&add_src());
+    insta::assert_display_snapshot!(add.pretty_printed().named());
+}
+
+#[test]
+fn clashing_names_get_suffixes() {
+    let src = substitute_with_compounding(
+        [NAT_DEF],
+        "(for (<NAT> <NAT>) (for (<NAT>) (for (Set0 (for (2) Set0)) 2)))",
+    );
+    let for_ = parse_minimal_ast_or_panic(&src);
+    insta::assert_display_snapshot!(for_.pretty_printed().named());
+}
+
+#[test]
+fn free_debs_are_printed_as_indices() {
+    let src = substitute_with_compounding([NAT_DEF], "(for (<NAT>) (3 0))");
+    let for_ = parse_minimal_ast_or_panic(&src);
+    insta::assert_display_snapshot!(for_.pretty_printed().named());
+}
+
+#[test]
+fn default_options_print_indices() {
+    let add = parse_minimal_ast_or_panic(&add_src());
+    assert_eq!(
+        add.pretty_printed().to_string(),
+        add.pretty_printed()
+            .with_options(PrettyPrintOptions::default())
+            .to_string()
+    );
+}
+
+#[test]
+fn name_hints() {
+    let add = parse_minimal_ast_or_panic(&add_src());
+    let fun = get_fun(&add);
+    let minimal_ast::Expr::Match(match_) = &fun.return_val else {
+        panic!("expected a match");
+    };
+
+    let mut hints = NameHints::new();
+    hints.insert(
+        Hashed::new(fun).digest,
+        vec!["a".to_string(), "b".to_string(), "plus".to_string()],
+    );
+    hints.insert(match_.digest.clone(), vec!["_".to_string()]);
+    hints.insert(
+        Hashed::new(&match_.hashee.cases.hashee[1]).digest,
+        vec!["pred".to_string()],
+    );
+
+    let options = PrettyPrintOptions {
+        named_debs: true,
+        name_hints: Some(&hints),
+        ..PrettyPrintOptions::default()
+    };
+    insta::assert_display_snapshot!(add.pretty_printed().with_options(options));
+}
+
+#[test]
+fn name_hints_with_wrong_arity_are_ignored() {
+    let add = parse_minimal_ast_or_panic(&add_src());
+    let mut hints = NameHints::new();
+    hints.insert(Hashed::new(get_fun(&add)).digest, vec!["a".to_string()]);
+
+    let options = PrettyPrintOptions {
+        named_debs: true,
+        name_hints: Some(&hints),
+        ..PrettyPrintOptions::default()
+    };
+    assert_eq!(
+        add.pretty_printed().named().to_string(),
+        add.pretty_printed().with_options(options).to_string()
+    );
+}
@@ -0,0 +1,262 @@
+use super::*;
+
+const BOOL_DEF: (&str, &str) = (
+    "<BOOL>",
+    r#"(ind Set0 "Bool" () (
+    (() ())
+    (() ())
+))"#,
+);
+
+const NAT_DEF: (&str, &str) = (
+    "<NAT>",
+    r#"(ind Set0 "Nat" () (
+    (() ())
+    ((0) ())
+))"#,
+);
+
+fn render_type_error(src: &str) -> String {
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+    SourceSnippet {
+        src,
+        val: &err,
+        color: false,
+    }
+    .to_string()
+}
+
+fn render_elaboration_error(src: &str) -> String {
+    let err = get_elaboration_error_under_empty_tcon_or_panic(src);
+    SourceSnippet {
+        src,
+        val: &err,
+        color: false,
+    }
+    .to_string()
+}
+
+#[test]
+fn line_col() {
+    let src = "ab\ncd\n\nλx";
+    let lines = LineIndex::new(src);
+    let line_col = |i| lines.line_col(crate::syntax_tree::token::ByteIndex(i));
+    assert_eq!(LineCol { line: 1, column: 1 }, line_col(0));
+    assert_eq!(LineCol { line: 1, column: 3 }, line_col(2));
+    assert_eq!(LineCol { line: 2, column: 1 }, line_col(3));
+    assert_eq!(LineCol { line: 3, column: 1 }, line_col(6));
+    assert_eq!(LineCol { line: 4, column: 2 }, line_col(9));
+    assert_eq!("λx", lines.line(4));
+}
+
+#[test]
+fn color() {
+    let src = r#"((fun nonrec (U64) U64 1) "a")"#;
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+    let rendered = SourceSnippet {
+        src,
+        val: &err,
+        color: true,
+    }
+    .to_string();
+    insta::assert_debug_snapshot!(rendered);
+}
+
+#[test]
+fn invalid_deb() {
+    let src = r#"(fun nonrec (U64) U64 5)"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn invalid_vcon_index() {
+    let src = substitute_with_compounding([BOOL_DEF], "(vcon <BOOL> 2)");
+    insta::assert_display_snapshot!(render_type_error(&src));
+}
+
+#[test]
+fn unexpected_non_type_expression() {
+    let src = r#"(fun nonrec (3u64) U64 1)"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn universe_inconsistency_in_ind_def() {
+    let src = r#"(ind Set0 "Foo" () (
+    ((Set0) ())
+))"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn wrong_number_of_index_arguments() {
+    let src = r#"(ind Set0 "Foo" (U64) (
+    (() ())
+))"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn non_inductive_matchee_type() {
+    let src = r#"(match 3u64 1 U64 ())"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn wrong_number_of_match_cases() {
+    let unsubstituted_src = r#"(fun nonrec (<BOOL>) U64
+    (match 1 1 U64 (
+        (0 3u64)
+    ))
+)"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+    insta::assert_display_snapshot!(render_type_error(&src));
+}
+
+#[test]
+fn wrong_match_return_type_arity() {
+    let unsubstituted_src = r#"(fun nonrec (<BOOL>) U64
+    (match 1 2 U64 (
+        (0 3u64)
+        (0 4u64)
+    ))
+)"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+    insta::assert_display_snapshot!(render_type_error(&src));
+}
+
+#[test]
+fn wrong_match_case_arity() {
+    let unsubstituted_src = r#"(fun nonrec (<BOOL>) U64
+    (match 1 1 U64 (
+        (0 3u64)
+        (1 4u64)
+    ))
+)"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+    insta::assert_display_snapshot!(render_type_error(&src));
+}
+
+#[test]
+fn type_mismatch() {
+    let src = r#"((fun nonrec (U64) U64 1) "a")"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn type_mismatch_with_multiline_types() {
+    let unsubstituted_src = r#"((fun nonrec (<BOOL>) U64 3u64)
+    "not a bool"
+)"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+    insta::assert_display_snapshot!(render_type_error(&src));
+}
+
+#[test]
+fn type_mismatch_with_type_wider_than_layout_width() {
+    let unsubstituted_src = r#"((fun nonrec ((for (<BOOL> <BOOL>) <BOOL>)) U64 3u64)
+    "not a bool"
+)"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+    insta::assert_display_snapshot!(render_type_error(&src));
+}
+
+#[test]
+fn callee_type_is_not_a_for_expression() {
+    let src = r#"(3u64 4u64)"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn wrong_number_of_app_arguments() {
+    let src = r#"((fun nonrec (U64) U64 1) 3u64 4u64)"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn fun_has_zero_params() {
+    let src = r#"(fun nonrec () U64 3u64)"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn app_has_zero_args() {
+    let src = r#"((fun nonrec (U64) U64 1))"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn for_has_zero_params() {
+    let src = r#"(for () U64)"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn illegal_recursive_call() {
+    let unsubstituted_src = r#"(fun 0 (<NAT>) <NAT>
+    (match 2 1 <NAT> (
+        (0 1)
+        (1 (1 2))
+    ))
+)"#;
+    let src = substitute_with_compounding([NAT_DEF], unsubstituted_src);
+    insta::assert_display_snapshot!(render_type_error(&src));
+}
+
+#[test]
+fn recursive_fun_param_in_non_callee_position() {
+    let src = substitute_with_compounding([NAT_DEF], r#"(fun 0 (<NAT>) U64 0)"#);
+    insta::assert_display_snapshot!(render_type_error(&src));
+}
+
+#[test]
+fn declared_fun_nonrecursive_but_used_recursive_fun_param() {
+    let src = r#"(fun nonrec (U64) U64 (0 3u64))"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn decreasing_arg_index_too_big() {
+    let src = substitute_with_compounding([NAT_DEF], r#"(fun 1 (<NAT>) <NAT> 1)"#);
+    insta::assert_display_snapshot!(render_type_error(&src));
+}
+
+#[test]
+fn vcon_def_param_type_fails_strict_positivity_condition() {
+    let src = r#"(ind Set0 "Bad" () (
+    (((for (0) U64)) ())
+))"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn recursive_ind_param_appears_in_vcon_def_index_arg() {
+    let unsubstituted_src = r#"(fun nonrec ((for (Set1) Set0)) (for (Set0) Set1)
+    (ind Set1 "Foo" (Set0) (
+        (() ((2 (0 <FALSE>))))
+    ))
+)"#;
+    let src = substitute_with_compounding(
+        [("<FALSE>", r#"(ind Set0 "False" () ())"#)],
+        unsubstituted_src,
+    );
+    insta::assert_display_snapshot!(render_type_error(&src));
+}
+
+#[test]
+fn cannot_infer_meta_type() {
+    let src = r#"(_0 3u64)"#;
+    insta::assert_display_snapshot!(render_elaboration_error(src));
+}
+
+#[test]
+fn cannot_infer_hole_type() {
+    let src = r#"(? 3u64)"#;
+    insta::assert_display_snapshot!(render_type_error(src));
+}
+
+#[test]
+fn unsolved_metas() {
+    let src = r#"((fun nonrec (Set0 Set0 U64) U64 1) _0 _1 3u64)"#;
+    insta::assert_display_snapshot!(render_elaboration_error(src));
+}
@@ -0,0 +1,22 @@
+use super::*;
+
+use crate::eval::{ReductionStrategy, Tracer};
+
+#[test]
+fn trace() {
+    let src = r#"((fun nonrec (U64) U64 (u64_add 1 1)) (u64_add 1u64 2u64))"#;
+    let expr = parse_minimal_ast_or_panic(src);
+    let trace = Tracer::new(ReductionStrategy::CallByValue).trace(expr);
+    insta::assert_display_snapshot!(trace.pretty_printed());
+}
+
+#[test]
+fn cut_short_trace() {
+    let src = r#"(u64_add (u64_add 1u64 2u64) (u64_add 3u64 4u64))"#;
+    let expr = parse_minimal_ast_or_panic(src);
+    let tracer = Tracer {
+        max_steps: 1,
+        ..Tracer::default()
+    };
+    insta::assert_display_snapshot!(tracer.trace(expr).pretty_printed());
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn named_vcon_round_trips() {
+    let src = r#"(vcon (ind Set0 "Nat" () (("zero" () ()) ("succ" (0) ()))) "succ")"#;
+    let vcon = parse_minimal_ast_or_panic(src);
+    assert_eq!(src, PrettyPrint(&vcon).to_string());
+}
+
+#[test]
+fn vcon_with_duplicate_name_is_printed_by_index() {
+    let src = r#"(vcon (ind Set0 "Bool" () (("b" () ()) ("b" () ()))) 1)"#;
+    let vcon = parse_minimal_ast_or_panic(src);
+    assert_eq!(src, PrettyPrint(&vcon).to_string());
+}
+
+#[test]
+fn vcon_with_unnamed_def_is_printed_by_index() {
+    let src = r#"(vcon (ind Set0 "Nat" () (("zero" () ()) ((0) ()))) 1)"#;
+    let vcon = parse_minimal_ast_or_panic(src);
+    assert_eq!(src, PrettyPrint(&vcon).to_string());
+}
+
+#[test]
+fn ind_tag_round_trips() {
+    let src = r#"(vcon (ind Set0 "Meters" "0xabc" () (("mk" () ()))) "mk")"#;
+    let vcon = parse_minimal_ast_or_panic(src);
+    assert_eq!(src, PrettyPrint(&vcon).to_string());
+}
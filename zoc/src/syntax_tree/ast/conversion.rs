@@ -41,6 +41,29 @@ impl<A: AuxDataFamily> From<RcHashed<UniverseNode<A>>> for Expr<A> {
     }
 }
 
+impl<A: AuxDataFamily> From<RcHashed<LitNode<A>>> for Expr<A> {
+    fn from(lit: RcHashed<LitNode<A>>) -> Self {
+        Expr::Lit(lit)
+    }
+}
+impl<A: AuxDataFamily> From<RcHashed<PrimNode<A>>> for Expr<A> {
+    fn from(prim: RcHashed<PrimNode<A>>) -> Self {
+        Expr::Prim(prim)
+    }
+}
+
+impl<A: AuxDataFamily> From<RcHashed<MetaNode<A>>> for Expr<A> {
+    fn from(meta: RcHashed<MetaNode<A>>) -> Self {
+        Expr::Meta(meta)
+    }
+}
+
+impl<A: AuxDataFamily> From<RcHashed<HoleNode<A>>> for Expr<A> {
+    fn from(hole: RcHashed<HoleNode<A>>) -> Self {
+        Expr::Hole(hole)
+    }
+}
+
 impl<A: AuxDataFamily> From<Ind<A>> for Expr<A> {
     fn from(ind: Ind<A>) -> Self {
         rc_hashed(ind).into()
@@ -81,6 +104,26 @@ impl<A: AuxDataFamily> From<UniverseNode<A>> for Expr<A> {
         rc_hashed(universe).into()
     }
 }
+impl<A: AuxDataFamily> From<LitNode<A>> for Expr<A> {
+    fn from(lit: LitNode<A>) -> Self {
+        rc_hashed(lit).into()
+    }
+}
+impl<A: AuxDataFamily> From<PrimNode<A>> for Expr<A> {
+    fn from(prim: PrimNode<A>) -> Self {
+        rc_hashed(prim).into()
+    }
+}
+impl<A: AuxDataFamily> From<MetaNode<A>> for Expr<A> {
+    fn from(meta: MetaNode<A>) -> Self {
+        rc_hashed(meta).into()
+    }
+}
+impl<A: AuxDataFamily> From<HoleNode<A>> for Expr<A> {
+    fn from(hole: HoleNode<A>) -> Self {
+        rc_hashed(hole).into()
+    }
+}
 
 impl<A: AuxDataFamily> Expr<A> {
     pub fn try_into_ind(self) -> Result<RcHashed<Ind<A>>, Self> {
@@ -138,4 +181,32 @@ impl<A: AuxDataFamily> Expr<A> {
             _ => Err(self),
         }
     }
+
+    pub fn try_into_lit(self) -> Result<RcHashed<LitNode<A>>, Self> {
+        match self {
+            Expr::Lit(e) => Ok(e),
+            _ => Err(self),
+        }
+    }
+
+    pub fn try_into_prim(self) -> Result<RcHashed<PrimNode<A>>, Self> {
+        match self {
+            Expr::Prim(e) => Ok(e),
+            _ => Err(self),
+        }
+    }
+
+    pub fn try_into_meta(self) -> Result<RcHashed<MetaNode<A>>, Self> {
+        match self {
+            Expr::Meta(e) => Ok(e),
+            _ => Err(self),
+        }
+    }
+
+    pub fn try_into_hole(self) -> Result<RcHashed<HoleNode<A>>, Self> {
+        match self {
+            Expr::Hole(e) => Ok(e),
+            _ => Err(self),
+        }
+    }
 }
@@ -16,6 +16,10 @@ impl AuxDataFamily for UnitAuxDataFamily {
     type For = ();
     type Deb = ();
     type Universe = ();
+    type Lit = ();
+    type Prim = ();
+    type Meta = ();
+    type Hole = ();
 
     type VconDef = ();
     type MatchCase = ();
@@ -32,6 +36,10 @@ pub type App = ast::App<UnitAuxDataFamily>;
 pub type For = ast::For<UnitAuxDataFamily>;
 pub type DebNode = ast::DebNode<UnitAuxDataFamily>;
 pub type UniverseNode = ast::UniverseNode<UnitAuxDataFamily>;
+pub type LitNode = ast::LitNode<UnitAuxDataFamily>;
+pub type PrimNode = ast::PrimNode<UnitAuxDataFamily>;
+pub type MetaNode = ast::MetaNode<UnitAuxDataFamily>;
+pub type HoleNode = ast::HoleNode<UnitAuxDataFamily>;
 
 impl Debug for Expr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
@@ -99,3 +107,28 @@ impl Debug for UniverseNode {
         write!(f, "{pretty:#}")
     }
 }
+impl Debug for LitNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
+        let pretty = self.pretty_printed();
+        write!(f, "{pretty:#}")
+    }
+}
+impl Debug for PrimNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
+        let pretty = self.pretty_printed();
+        write!(f, "{pretty:#}")
+    }
+}
+impl Debug for MetaNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
+        let pretty = self.pretty_printed();
+        write!(f, "{pretty:#}")
+    }
+}
+
+impl Debug for HoleNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
+        let pretty = self.pretty_printed();
+        write!(f, "{pretty:#}")
+    }
+}
@@ -1,2 +1,3 @@
 pub mod minimal_ast;
 pub mod spanned_ast;
+pub mod typed_ast;
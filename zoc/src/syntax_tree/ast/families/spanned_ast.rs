@@ -21,6 +21,10 @@ pub type App = ast::App<SpanAuxDataFamily>;
 pub type For = ast::For<SpanAuxDataFamily>;
 pub type DebNode = ast::DebNode<SpanAuxDataFamily>;
 pub type UniverseNode = ast::UniverseNode<SpanAuxDataFamily>;
+pub type LitNode = ast::LitNode<SpanAuxDataFamily>;
+pub type PrimNode = ast::PrimNode<SpanAuxDataFamily>;
+pub type MetaNode = ast::MetaNode<SpanAuxDataFamily>;
+pub type HoleNode = ast::HoleNode<SpanAuxDataFamily>;
 
 impl AuxDataFamily for SpanAuxDataFamily {
     type Ind = IndSpans;
@@ -31,6 +35,10 @@ impl AuxDataFamily for SpanAuxDataFamily {
     type For = ForSpans;
     type Deb = Span;
     type Universe = Span;
+    type Lit = Span;
+    type Prim = Span;
+    type Meta = Span;
+    type Hole = Span;
 
     type VconDef = VconDefSpans;
     type MatchCase = MatchCaseSpans;
@@ -54,6 +62,7 @@ pub struct VconSpans {
 #[derive(Debug, Clone, Hash)]
 pub struct VconDefSpans {
     pub span: Span,
+    pub name_span: Option<Span>,
     pub param_types_span: Span,
     pub index_args_span: Span,
 }
@@ -95,6 +104,10 @@ impl Expr {
             Self::For(e) => e.hashee.aux_data.span,
             Self::Deb(e) => e.hashee.aux_data,
             Self::Universe(e) => e.hashee.aux_data,
+            Self::Lit(e) => e.hashee.aux_data,
+            Self::Prim(e) => e.hashee.aux_data,
+            Self::Meta(e) => e.hashee.aux_data,
+            Self::Hole(e) => e.hashee.aux_data,
         }
     }
 }
@@ -148,6 +161,27 @@ impl UniverseNode {
         self.aux_data
     }
 }
+impl LitNode {
+    pub fn span(&self) -> Span {
+        self.aux_data
+    }
+}
+impl PrimNode {
+    pub fn span(&self) -> Span {
+        self.aux_data
+    }
+}
+impl MetaNode {
+    pub fn span(&self) -> Span {
+        self.aux_data
+    }
+}
+
+impl HoleNode {
+    pub fn span(&self) -> Span {
+        self.aux_data
+    }
+}
 
 impl Debug for Expr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
@@ -226,3 +260,32 @@ impl Debug for UniverseNode {
         write!(f, "{pretty:#}")
     }
 }
+impl Debug for LitNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
+        let minimal = AuxDataRemover::default().convert(Expr::from(self.clone()));
+        let pretty = minimal.pretty_printed().with_location_appended(self.span());
+        write!(f, "{pretty:#}")
+    }
+}
+impl Debug for PrimNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
+        let minimal = AuxDataRemover::default().convert(Expr::from(self.clone()));
+        let pretty = minimal.pretty_printed().with_location_appended(self.span());
+        write!(f, "{pretty:#}")
+    }
+}
+impl Debug for MetaNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
+        let minimal = AuxDataRemover::default().convert(Expr::from(self.clone()));
+        let pretty = minimal.pretty_printed().with_location_appended(self.span());
+        write!(f, "{pretty:#}")
+    }
+}
+
+impl Debug for HoleNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
+        let minimal = AuxDataRemover::default().convert(Expr::from(self.clone()));
+        let pretty = minimal.pretty_printed().with_location_appended(self.span());
+        write!(f, "{pretty:#}")
+    }
+}
@@ -0,0 +1,83 @@
+pub use crate::syntax_tree::ast::prelude::*;
+
+use crate::eval::NormalForm;
+
+use super::minimal_ast::UnitAuxDataFamily;
+
+use std::marker::PhantomData;
+
+/// An aux data family that records the type of each expression node,
+/// alongside the aux data of the underlying family `A`.
+///
+/// For example, `TypedAuxDataFamily<SpanAuxDataFamily>`
+/// records both the type and the span of each node.
+///
+/// Vcon defs and match cases are not expressions,
+/// so they only have `A`'s aux data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TypedAuxDataFamily<A = UnitAuxDataFamily>(PhantomData<A>);
+
+/// A node's type, along with the node's aux data
+/// from the underlying family.
+///
+/// The type lives in the node's own type context.
+/// For example, the type of a fun's return value
+/// may refer to the fun's params.
+#[derive(Debug, Clone, Hash)]
+pub struct Typed<T> {
+    pub type_: NormalForm,
+    pub inner: T,
+}
+
+impl<A: AuxDataFamily> AuxDataFamily for TypedAuxDataFamily<A> {
+    type Ind = Typed<A::Ind>;
+    type Vcon = Typed<A::Vcon>;
+    type Match = Typed<A::Match>;
+    type Fun = Typed<A::Fun>;
+    type App = Typed<A::App>;
+    type For = Typed<A::For>;
+    type Deb = Typed<A::Deb>;
+    type Universe = Typed<A::Universe>;
+    type Lit = Typed<A::Lit>;
+    type Prim = Typed<A::Prim>;
+    type Meta = Typed<A::Meta>;
+    type Hole = Typed<A::Hole>;
+
+    type VconDef = A::VconDef;
+    type MatchCase = A::MatchCase;
+}
+
+pub type Expr<A = UnitAuxDataFamily> = ast::Expr<TypedAuxDataFamily<A>>;
+pub type Ind<A = UnitAuxDataFamily> = ast::Ind<TypedAuxDataFamily<A>>;
+pub type VconDef<A = UnitAuxDataFamily> = ast::VconDef<TypedAuxDataFamily<A>>;
+pub type Vcon<A = UnitAuxDataFamily> = ast::Vcon<TypedAuxDataFamily<A>>;
+pub type Match<A = UnitAuxDataFamily> = ast::Match<TypedAuxDataFamily<A>>;
+pub type MatchCase<A = UnitAuxDataFamily> = ast::MatchCase<TypedAuxDataFamily<A>>;
+pub type Fun<A = UnitAuxDataFamily> = ast::Fun<TypedAuxDataFamily<A>>;
+pub type App<A = UnitAuxDataFamily> = ast::App<TypedAuxDataFamily<A>>;
+pub type For<A = UnitAuxDataFamily> = ast::For<TypedAuxDataFamily<A>>;
+pub type DebNode<A = UnitAuxDataFamily> = ast::DebNode<TypedAuxDataFamily<A>>;
+pub type UniverseNode<A = UnitAuxDataFamily> = ast::UniverseNode<TypedAuxDataFamily<A>>;
+pub type LitNode<A = UnitAuxDataFamily> = ast::LitNode<TypedAuxDataFamily<A>>;
+pub type PrimNode<A = UnitAuxDataFamily> = ast::PrimNode<TypedAuxDataFamily<A>>;
+pub type MetaNode<A = UnitAuxDataFamily> = ast::MetaNode<TypedAuxDataFamily<A>>;
+pub type HoleNode<A = UnitAuxDataFamily> = ast::HoleNode<TypedAuxDataFamily<A>>;
+
+impl<A: AuxDataFamily> Expr<A> {
+    pub fn type_(&self) -> &NormalForm {
+        match self {
+            Self::Ind(e) => &e.hashee.aux_data.type_,
+            Self::Vcon(e) => &e.hashee.aux_data.type_,
+            Self::Match(e) => &e.hashee.aux_data.type_,
+            Self::Fun(e) => &e.hashee.aux_data.type_,
+            Self::App(e) => &e.hashee.aux_data.type_,
+            Self::For(e) => &e.hashee.aux_data.type_,
+            Self::Deb(e) => &e.hashee.aux_data.type_,
+            Self::Universe(e) => &e.hashee.aux_data.type_,
+            Self::Lit(e) => &e.hashee.aux_data.type_,
+            Self::Prim(e) => &e.hashee.aux_data.type_,
+            Self::Meta(e) => &e.hashee.aux_data.type_,
+            Self::Hole(e) => &e.hashee.aux_data.type_,
+        }
+    }
+}
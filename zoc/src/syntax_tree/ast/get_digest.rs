@@ -11,6 +11,10 @@ impl<A: AuxDataFamily> GetDigest for Expr<A> {
             Expr::For(e) => &e.digest,
             Expr::Deb(e) => &e.digest,
             Expr::Universe(e) => &e.digest,
+            Expr::Lit(e) => &e.digest,
+            Expr::Prim(e) => &e.digest,
+            Expr::Meta(e) => &e.digest,
+            Expr::Hole(e) => &e.digest,
         }
     }
 }
@@ -54,3 +58,24 @@ impl<A: AuxDataFamily> GetDigest for RcHashed<UniverseNode<A>> {
         &self.digest
     }
 }
+impl<A: AuxDataFamily> GetDigest for RcHashed<LitNode<A>> {
+    fn digest(&self) -> &Digest {
+        &self.digest
+    }
+}
+impl<A: AuxDataFamily> GetDigest for RcHashed<PrimNode<A>> {
+    fn digest(&self) -> &Digest {
+        &self.digest
+    }
+}
+impl<A: AuxDataFamily> GetDigest for RcHashed<MetaNode<A>> {
+    fn digest(&self) -> &Digest {
+        &self.digest
+    }
+}
+
+impl<A: AuxDataFamily> GetDigest for RcHashed<HoleNode<A>> {
+    fn digest(&self) -> &Digest {
+        &self.digest
+    }
+}
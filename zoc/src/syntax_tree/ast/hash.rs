@@ -13,6 +13,10 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         match self {
@@ -24,6 +28,10 @@ where
             Expr::For(e) => e.hash(state),
             Expr::Deb(e) => e.hash(state),
             Expr::Universe(e) => e.hash(state),
+            Expr::Lit(e) => e.hash(state),
+            Expr::Prim(e) => e.hash(state),
+            Expr::Meta(e) => e.hash(state),
+            Expr::Hole(e) => e.hash(state),
         }
     }
 }
@@ -41,10 +49,21 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         delimiters::START_IND.hash(state);
         self.name.hash(state);
+        // We only hash the tag if there is one,
+        // so untagged inds keep the digests they had
+        // before tags were introduced.
+        if let Some(tag) = &self.tag {
+            delimiters::IND_TAG.hash(state);
+            tag.hash(state);
+        }
         self.universe.hash(state);
         self.index_types.digest.hash(state);
         self.vcon_defs.digest.hash(state);
@@ -65,9 +84,20 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         delimiters::START_VCON_DEF.hash(state);
+        // Like ind tags, we only hash the name if there is one,
+        // so unnamed vcon defs keep the digests they had
+        // before names were introduced.
+        if let Some(name) = &self.name {
+            delimiters::VCON_DEF_NAME.hash(state);
+            name.hash(state);
+        }
         self.param_types.digest.hash(state);
         self.index_args.digest.hash(state);
         delimiters::END.hash(state);
@@ -87,6 +117,10 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         delimiters::START_VCON.hash(state);
@@ -109,6 +143,10 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         delimiters::START_MATCH.hash(state);
@@ -132,11 +170,19 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         delimiters::START_MATCH_CASE.hash(state);
         self.arity.hash(state);
-        self.return_val.digest().hash(state);
+        // Omitted cases are hashed without a return value digest,
+        // so cases with return values keep their original digests.
+        if let Some(return_val) = &self.return_val {
+            return_val.digest().hash(state);
+        }
         delimiters::END.hash(state);
     }
 }
@@ -154,6 +200,10 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         delimiters::START_FUN.hash(state);
@@ -178,6 +228,10 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         delimiters::START_APP.hash(state);
@@ -200,6 +254,10 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         delimiters::START_FOR.hash(state);
@@ -222,6 +280,10 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         delimiters::START_DEB.hash(state);
@@ -243,6 +305,10 @@ where
     A::For: Hash,
     A::Deb: Hash,
     A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
 {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         delimiters::START_UNIVERSE.hash(state);
@@ -251,6 +317,105 @@ where
     }
 }
 
+impl<A> Hash for LitNode<A>
+where
+    A: AuxDataFamily,
+    A::Ind: Hash,
+    A::VconDef: Hash,
+    A::Vcon: Hash,
+    A::Match: Hash,
+    A::MatchCase: Hash,
+    A::Fun: Hash,
+    A::App: Hash,
+    A::For: Hash,
+    A::Deb: Hash,
+    A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        delimiters::START_LIT.hash(state);
+        self.lit.hash(state);
+        delimiters::END.hash(state);
+    }
+}
+
+impl<A> Hash for PrimNode<A>
+where
+    A: AuxDataFamily,
+    A::Ind: Hash,
+    A::VconDef: Hash,
+    A::Vcon: Hash,
+    A::Match: Hash,
+    A::MatchCase: Hash,
+    A::Fun: Hash,
+    A::App: Hash,
+    A::For: Hash,
+    A::Deb: Hash,
+    A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        delimiters::START_PRIM.hash(state);
+        self.prim.hash(state);
+        delimiters::END.hash(state);
+    }
+}
+
+impl<A> Hash for MetaNode<A>
+where
+    A: AuxDataFamily,
+    A::Ind: Hash,
+    A::VconDef: Hash,
+    A::Vcon: Hash,
+    A::Match: Hash,
+    A::MatchCase: Hash,
+    A::Fun: Hash,
+    A::App: Hash,
+    A::For: Hash,
+    A::Deb: Hash,
+    A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        delimiters::START_META.hash(state);
+        self.id.hash(state);
+        delimiters::END.hash(state);
+    }
+}
+
+impl<A> Hash for HoleNode<A>
+where
+    A: AuxDataFamily,
+    A::Ind: Hash,
+    A::VconDef: Hash,
+    A::Vcon: Hash,
+    A::Match: Hash,
+    A::MatchCase: Hash,
+    A::Fun: Hash,
+    A::App: Hash,
+    A::For: Hash,
+    A::Deb: Hash,
+    A::Universe: Hash,
+    A::Lit: Hash,
+    A::Prim: Hash,
+    A::Meta: Hash,
+    A::Hole: Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        delimiters::START_HOLE.hash(state);
+        delimiters::END.hash(state);
+    }
+}
+
 mod delimiters {
     pub const END: u8 = 1;
 
@@ -262,7 +427,14 @@ mod delimiters {
     pub const START_FOR: u8 = 7;
     pub const START_DEB: u8 = 8;
     pub const START_UNIVERSE: u8 = 9;
+    pub const START_LIT: u8 = 12;
+    pub const START_PRIM: u8 = 13;
+    pub const START_META: u8 = 14;
+    pub const START_HOLE: u8 = 15;
 
     pub const START_VCON_DEF: u8 = 10;
     pub const START_MATCH_CASE: u8 = 11;
+
+    pub const IND_TAG: u8 = 16;
+    pub const VCON_DEF_NAME: u8 = 17;
 }
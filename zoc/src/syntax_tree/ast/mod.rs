@@ -1,4 +1,4 @@
-use std::{fmt::Debug, hash::Hash, rc::Rc};
+use std::{fmt::Debug, hash::Hash};
 
 pub use crate::hash::*;
 
@@ -13,20 +13,26 @@ pub use node_path::{NodeEdge, NodePath};
 
 pub mod prelude;
 
+/// With the `sync` feature, aux data must be `Send + Sync`
+/// (see `MaybeSendSync`), so that trees can be shared between threads.
 pub trait AuxDataFamily:
-    'static + Debug + Clone + Copy + PartialEq + Eq + PartialOrd + Ord + Hash + Default
+    'static + Debug + Clone + Copy + PartialEq + Eq + PartialOrd + Ord + Hash + Default + MaybeSendSync
 {
-    type Ind: Clone + Hash;
-    type Vcon: Clone + Hash;
-    type Match: Clone + Hash;
-    type Fun: Clone + Hash;
-    type App: Clone + Hash;
-    type For: Clone + Hash;
-    type Deb: Clone + Hash;
-    type Universe: Clone + Hash;
+    type Ind: Clone + Hash + MaybeSendSync;
+    type Vcon: Clone + Hash + MaybeSendSync;
+    type Match: Clone + Hash + MaybeSendSync;
+    type Fun: Clone + Hash + MaybeSendSync;
+    type App: Clone + Hash + MaybeSendSync;
+    type For: Clone + Hash + MaybeSendSync;
+    type Deb: Clone + Hash + MaybeSendSync;
+    type Universe: Clone + Hash + MaybeSendSync;
+    type Lit: Clone + Hash + MaybeSendSync;
+    type Prim: Clone + Hash + MaybeSendSync;
+    type Meta: Clone + Hash + MaybeSendSync;
+    type Hole: Clone + Hash + MaybeSendSync;
 
-    type VconDef: Clone + Hash;
-    type MatchCase: Clone + Hash;
+    type VconDef: Clone + Hash + MaybeSendSync;
+    type MatchCase: Clone + Hash + MaybeSendSync;
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -39,11 +45,29 @@ pub enum Expr<A: AuxDataFamily> {
     For(RcHashed<For<A>>),
     Deb(RcHashed<DebNode<A>>),
     Universe(RcHashed<UniverseNode<A>>),
+    Lit(RcHashed<LitNode<A>>),
+    Prim(RcHashed<PrimNode<A>>),
+    Meta(RcHashed<MetaNode<A>>),
+    Hole(RcHashed<HoleNode<A>>),
 }
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Ind<A: AuxDataFamily> {
     pub name: Rc<StringValue>,
+    /// A nominal tag.
+    ///
+    /// Inds are compared by digest,
+    /// so two inds with the same name, universe, index types, and vcon defs
+    /// are the same type, even if they were declared separately
+    /// (e.g., `Meters` and `Seconds` wrappers that only differ in their names
+    /// if the names happen to collide).
+    /// The tag is part of the digest,
+    /// so inds with different tags are different types.
+    ///
+    /// Zo itself does not mint tags.
+    /// Front ends that want nominal inds should mint a tag
+    /// that is unique to the declaration (see [`mint_ind_tag`]).
+    pub tag: Option<Rc<StringValue>>,
     pub universe: Universe,
     pub index_types: RcHashedVec<Expr<A>>,
     pub vcon_defs: RcHashedVec<VconDef<A>>,
@@ -55,6 +79,17 @@ pub struct StringValue(pub String);
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct VconDef<A: AuxDataFamily> {
+    /// The name is used for printing and for
+    /// name-based vcon references (e.g., `(vcon <IND> "succ")`).
+    ///
+    /// Like an ind's name, it affects the digest.
+    /// Therefore, two inds that only differ in their vcon names
+    /// are different inds, and sharing nodes by digest
+    /// (e.g., when interning or caching evaluations)
+    /// never swaps one ind's vcon names for another's.
+    /// Front ends rely on this when they look up
+    /// vcon names in a normalized type.
+    pub name: Option<Rc<StringValue>>,
     pub param_types: RcHashedVec<Expr<A>>,
     pub index_args: RcHashedVec<Expr<A>>,
     pub aux_data: A::VconDef,
@@ -79,7 +114,13 @@ pub struct Match<A: AuxDataFamily> {
 #[derive(Clone, PartialEq, Eq)]
 pub struct MatchCase<A: AuxDataFamily> {
     pub arity: usize,
-    pub return_val: Expr<A>,
+    /// `None` means the case was omitted
+    /// because it is impossible
+    /// (i.e., the vcon's index args cannot be unified with
+    /// the matchee type's index args).
+    /// The typechecker verifies that omitted cases are indeed impossible.
+    /// The evaluator never reduces a match to an omitted case.
+    pub return_val: Option<Expr<A>>,
     pub aux_data: A::MatchCase,
 }
 
@@ -118,9 +159,49 @@ pub struct UniverseNode<A: AuxDataFamily> {
     pub aux_data: A::Universe,
 }
 
+#[derive(Clone, PartialEq, Eq)]
+pub struct LitNode<A: AuxDataFamily> {
+    pub lit: Lit,
+    pub aux_data: A::Lit,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct PrimNode<A: AuxDataFamily> {
+    pub prim: Prim,
+    pub aux_data: A::Prim,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct MetaNode<A: AuxDataFamily> {
+    pub id: MetaId,
+    pub aux_data: A::Meta,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct HoleNode<A: AuxDataFamily> {
+    pub aux_data: A::Hole,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
 pub struct Deb(pub usize);
 
+/// Identifies a metavariable (i.e., a placeholder for a term
+/// that the typechecker infers via unification).
+/// Every occurrence of a metavariable with the same `MetaId`
+/// refers to the same metavariable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct MetaId(pub usize);
+
+impl MetaId {
+    /// Stands in for code that could not be parsed
+    /// (see `cst::Expr::Invalid`).
+    ///
+    /// The typechecker reports `TypeError::InvalidCode` for it,
+    /// and otherwise treats it like an error meta,
+    /// so it does not cause follow-on errors.
+    pub const INVALID_CODE: MetaId = MetaId(usize::MAX);
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Universe {
     pub level: UniverseLevel,
@@ -130,6 +211,78 @@ pub struct Universe {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
 pub struct UniverseLevel(pub usize);
 
+/// A primitive literal value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Lit {
+    /// A literal of type `U64`.
+    U64(u64),
+    /// A literal of type `String`.
+    String(Rc<StringValue>),
+}
+
+/// A built-in type or operation.
+///
+/// The arithmetic operations wrap on overflow (and underflow).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Prim {
+    /// `U64 : Set0`
+    U64,
+    /// `String : Set0`
+    String,
+    /// `u64_add : (for (U64 U64) U64)`
+    U64Add,
+    /// `u64_sub : (for (U64 U64) U64)`
+    U64Sub,
+    /// `u64_mul : (for (U64 U64) U64)`
+    U64Mul,
+    /// `string_concat : (for (String String) String)`
+    StringConcat,
+    /// `string_len : (for (String) U64)`
+    ///
+    /// The length is measured in bytes (of the UTF-8 encoding).
+    StringLen,
+}
+
+impl Prim {
+    /// Every prim, in declaration order.
+    pub const ALL: [Prim; 7] = [
+        Prim::U64,
+        Prim::String,
+        Prim::U64Add,
+        Prim::U64Sub,
+        Prim::U64Mul,
+        Prim::StringConcat,
+        Prim::StringLen,
+    ];
+
+    /// Returns the prim's name in Zo source (e.g., `u64_add`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Prim::U64 => "U64",
+            Prim::String => "String",
+            Prim::U64Add => "u64_add",
+            Prim::U64Sub => "u64_sub",
+            Prim::U64Mul => "u64_mul",
+            Prim::StringConcat => "string_concat",
+            Prim::StringLen => "string_len",
+        }
+    }
+
+    /// Returns the prim named `name` in Zo source, if there is one.
+    pub fn from_name(name: &str) -> Option<Prim> {
+        Prim::ALL.into_iter().find(|prim| prim.name() == name)
+    }
+}
+
+impl<A: AuxDataFamily> Vcon<A> {
+    /// The `vcon_index` of a name-based vcon reference
+    /// (e.g., `(vcon <IND> "succ")`)
+    /// whose ind has no vcon def with that name.
+    ///
+    /// The typechecker reports `TypeError::UnknownVconName` for it.
+    pub const UNKNOWN_NAME_INDEX: usize = usize::MAX;
+}
+
 impl<A: AuxDataFamily> App<A> {
     pub fn collapse_if_nullary(self) -> Expr<A> {
         if self.args.hashee.is_empty() {
@@ -159,3 +312,22 @@ pub type RcHashedVec<T> = RcHashed<Vec<T>>;
 pub fn rc_hashed<T: Hash>(t: T) -> RcHashed<T> {
     Rc::new(Hashed::new(t))
 }
+
+/// Mints a tag for a nominal ind (see `Ind::tag`).
+///
+/// Front ends should pass an identifier of the source
+/// the declaration is in (e.g., its path),
+/// the ind's source name,
+/// and the byte index of its declaration.
+/// Since no two declarations in the same source share a byte index,
+/// and no two sources share an identifier,
+/// every nominal declaration gets its own tag.
+/// Minting is deterministic, so recompiling the same source
+/// produces the same tags (and therefore the same digests).
+pub fn mint_ind_tag(source_id: &str, name: &str, declaration_start: usize) -> StringValue {
+    let mut hasher = Sha256Hasher::new();
+    source_id.hash(&mut hasher);
+    name.hash(&mut hasher);
+    (declaration_start as u64).hash(&mut hasher);
+    StringValue(format!("{:?}", hasher.digest()))
+}
@@ -3,12 +3,10 @@ pub use crate::{
     syntax_tree::{
         ast::{
             self,
-            families::{minimal_ast, spanned_ast},
-            node_path, rc_hashed, AuxDataFamily, Deb, NodeEdge, NodePath, RcHashed, RcHashedVec,
-            StringValue, Universe, UniverseLevel,
+            families::{minimal_ast, spanned_ast, typed_ast},
+            node_path, rc_hashed, AuxDataFamily, Deb, Lit, MetaId, NodeEdge, NodePath, Prim,
+            RcHashed, RcHashedVec, StringValue, Universe, UniverseLevel,
         },
         token::{ByteIndex, Span},
     },
 };
-
-pub use std::rc::Rc;
@@ -26,6 +26,27 @@ impl From<cst::Expr> for spanned_ast::Expr {
             cst::Expr::Deb(cst) => spanned_ast::DebNode::from(cst).into(),
 
             cst::Expr::Universe(cst) => spanned_ast::UniverseNode::from(cst).into(),
+
+            cst::Expr::U64Lit(cst) => spanned_ast::LitNode::from(cst).into(),
+
+            cst::Expr::StringLit(cst) => spanned_ast::LitNode::from(cst).into(),
+
+            cst::Expr::Prim(cst) => spanned_ast::PrimNode::from(cst).into(),
+
+            cst::Expr::Meta(cst) => spanned_ast::MetaNode::from(cst).into(),
+
+            cst::Expr::Hole(start) => spanned_ast::HoleNode {
+                aux_data: (start, ByteIndex(start.0 + '?'.len_utf8())),
+            }
+            .into(),
+
+            // Later phases can still check the rest of the expression
+            // (see `MetaId::INVALID_CODE`).
+            cst::Expr::Invalid(span) => spanned_ast::MetaNode {
+                id: MetaId::INVALID_CODE,
+                aux_data: span,
+            }
+            .into(),
         }
     }
 }
@@ -38,6 +59,10 @@ impl From<cst::Ind> for spanned_ast::Ind {
                 erasable: cst.type_.erasable,
             },
             name: Rc::new(StringValue(cst.name.value.clone())),
+            tag: match &*cst.tag {
+                cst::OptString::None => None,
+                cst::OptString::Some(tag) => Some(Rc::new(StringValue(tag.value.clone()))),
+            },
             index_types: rc_hashed((*cst.index_types).into()),
             vcon_defs: rc_hashed((*cst.vcon_defs).into()),
             aux_data: IndSpans {
@@ -79,11 +104,20 @@ impl From<cst::ZeroOrMoreVconDefs> for Vec<spanned_ast::VconDef> {
 
 impl From<cst::VconDef> for spanned_ast::VconDef {
     fn from(cst: cst::VconDef) -> Self {
+        let (name, name_span) = match &*cst.name {
+            cst::OptString::None => (None, None),
+            cst::OptString::Some(name) => (
+                Some(Rc::new(StringValue(name.value.clone()))),
+                Some(name.span),
+            ),
+        };
         spanned_ast::VconDef {
+            name,
             param_types: rc_hashed((*cst.param_types).into()),
             index_args: rc_hashed((*cst.index_args).into()),
             aux_data: VconDefSpans {
                 span: (cst.lparen, cst.rparen),
+                name_span,
                 param_types_span: (cst.param_types_lparen, cst.param_types_rparen),
                 index_args_span: (cst.index_args_lparen, cst.index_args_rparen),
             },
@@ -93,17 +127,37 @@ impl From<cst::VconDef> for spanned_ast::VconDef {
 
 impl From<cst::Vcon> for spanned_ast::Vcon {
     fn from(cst: cst::Vcon) -> Self {
+        let ind: RcHashed<spanned_ast::Ind> = rc_hashed((*cst.ind).into());
+        let (vcon_index, vcon_index_span) = match &*cst.vcon_index {
+            cst::VconIndexOrName::Index(index) => (index.value, index.span),
+            cst::VconIndexOrName::Name(name) => {
+                (get_vcon_index_by_name(&ind.hashee, &name.value), name.span)
+            }
+        };
         spanned_ast::Vcon {
-            ind: rc_hashed((*cst.ind).into()),
-            vcon_index: cst.vcon_index.value,
+            ind,
+            vcon_index,
             aux_data: VconSpans {
                 span: (cst.lparen, cst.rparen),
-                vcon_index_span: cst.vcon_index.span,
+                vcon_index_span,
             },
         }
     }
 }
 
+/// Returns the index of the first vcon def named `name`.
+///
+/// If there is no such vcon def,
+/// this returns `Vcon::UNKNOWN_NAME_INDEX`,
+/// which the typechecker reports as `TypeError::UnknownVconName`.
+fn get_vcon_index_by_name(ind: &spanned_ast::Ind, name: &str) -> usize {
+    ind.vcon_defs
+        .hashee
+        .iter()
+        .position(|def| def.name.as_ref().is_some_and(|def_name| def_name.0 == name))
+        .unwrap_or(spanned_ast::Vcon::UNKNOWN_NAME_INDEX)
+}
+
 impl From<cst::Match> for spanned_ast::Match {
     fn from(cst: cst::Match) -> Self {
         spanned_ast::Match {
@@ -137,7 +191,10 @@ impl From<cst::MatchCase> for spanned_ast::MatchCase {
     fn from(cst: cst::MatchCase) -> Self {
         spanned_ast::MatchCase {
             arity: cst.arity.value,
-            return_val: (*cst.return_val.clone()).into(),
+            return_val: match &*cst.return_val {
+                cst::OptExpr::None => None,
+                cst::OptExpr::Some(return_val) => Some((**return_val).clone().into()),
+            },
             aux_data: MatchCaseSpans {
                 span: (cst.lparen, cst.rparen),
                 arity_span: cst.arity.span,
@@ -213,3 +270,39 @@ impl From<cst::UniverseLiteral> for spanned_ast::UniverseNode {
         }
     }
 }
+
+impl From<cst::U64Literal> for spanned_ast::LitNode {
+    fn from(cst: cst::U64Literal) -> Self {
+        spanned_ast::LitNode {
+            lit: Lit::U64(cst.value),
+            aux_data: cst.span,
+        }
+    }
+}
+
+impl From<cst::StringLiteral> for spanned_ast::LitNode {
+    fn from(cst: cst::StringLiteral) -> Self {
+        spanned_ast::LitNode {
+            lit: Lit::String(Rc::new(StringValue(cst.value))),
+            aux_data: cst.span,
+        }
+    }
+}
+
+impl From<cst::PrimLiteral> for spanned_ast::PrimNode {
+    fn from(cst: cst::PrimLiteral) -> Self {
+        spanned_ast::PrimNode {
+            prim: cst.prim,
+            aux_data: cst.span,
+        }
+    }
+}
+
+impl From<cst::MetaLiteral> for spanned_ast::MetaNode {
+    fn from(cst: cst::MetaLiteral) -> Self {
+        spanned_ast::MetaNode {
+            id: MetaId(cst.id),
+            aux_data: cst.span,
+        }
+    }
+}
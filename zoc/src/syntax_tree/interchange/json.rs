@@ -0,0 +1,238 @@
+use super::*;
+
+/// Returns the JSON encoding of `expr` (see the module docs),
+/// indented by two spaces per level.
+pub fn expr_to_json(expr: &Expr) -> String {
+    format!("{:#}\n", expr_to_value(expr))
+}
+
+/// Decodes the JSON encoding of an expr (see the module docs).
+pub fn expr_from_json(src: &str) -> Result<Expr, InterchangeError> {
+    let mut parser = JsonParser {
+        src,
+        index: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.index != src.len() {
+        return Err(parser.error("expected the end of the input"));
+    }
+    value_to_expr(&value, "$")
+}
+
+struct JsonParser<'a> {
+    src: &'a str,
+    index: usize,
+    /// The number of arrays and objects being parsed.
+    depth: usize,
+}
+
+impl JsonParser<'_> {
+    fn error(&self, message: &'static str) -> InterchangeError {
+        InterchangeError::Syntax {
+            byte_index: self.index,
+            message,
+        }
+    }
+
+    /// Enters an array or object that starts at the current index.
+    fn enter(&mut self) -> Result<(), InterchangeError> {
+        if self.depth == MAX_NESTING_DEPTH {
+            return Err(InterchangeError::TooDeep {
+                byte_index: self.index,
+            });
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.as_bytes().get(self.index).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.index += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8, message: &'static str) -> Result<(), InterchangeError> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(self.error(message));
+        }
+        self.index += 1;
+        Ok(())
+    }
+
+    fn keyword(&mut self, keyword: &str, value: JsonValue) -> Result<JsonValue, InterchangeError> {
+        if !self.src[self.index..].starts_with(keyword) {
+            return Err(self.error("expected a value"));
+        }
+        self.index += keyword.len();
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<JsonValue, InterchangeError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.keyword("null", JsonValue::Null),
+            Some(b't') => self.keyword("true", JsonValue::Bool(true)),
+            Some(b'f') => self.keyword("false", JsonValue::Bool(false)),
+            Some(b'"') => self.string().map(JsonValue::String),
+            Some(b'0'..=b'9') => self.number(),
+            Some(b'-') => Err(self.error("expected a non-negative integer")),
+            Some(b'[') => {
+                self.enter()?;
+                let array = self.array();
+                self.depth -= 1;
+                array
+            }
+            Some(b'{') => {
+                self.enter()?;
+                let object = self.object();
+                self.depth -= 1;
+                object
+            }
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn number(&mut self) -> Result<JsonValue, InterchangeError> {
+        let start = self.index;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.index += 1;
+        }
+        if let Some(b'.' | b'e' | b'E') = self.peek() {
+            return Err(self.error("expected a non-negative integer"));
+        }
+        let digits = &self.src[start..self.index];
+        if digits.len() > 1 && digits.starts_with('0') {
+            return Err(self.error("numbers must not have leading zeros"));
+        }
+        digits
+            .parse()
+            .map(JsonValue::Number)
+            .map_err(|_| self.error("integer is too large"))
+    }
+
+    fn string(&mut self) -> Result<String, InterchangeError> {
+        self.expect(b'"', "expected a string")?;
+        let mut value = String::new();
+        loop {
+            let Some(c) = self.src[self.index..].chars().next() else {
+                return Err(self.error("unterminated string"));
+            };
+            self.index += c.len_utf8();
+            match c {
+                '"' => return Ok(value),
+                '\\' => value.push(self.escape()?),
+                '\u{0}'..='\u{1f}' => {
+                    return Err(self.error("control characters in strings must be escaped"))
+                }
+                c => value.push(c),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, InterchangeError> {
+        let Some(byte) = self.peek() else {
+            return Err(self.error("unterminated string"));
+        };
+        self.index += 1;
+        Ok(match byte {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\u{8}',
+            b'f' => '\u{c}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+                let high = self.hex4()?;
+                if !(0xd800..0xdc00).contains(&high) {
+                    return char::from_u32(high)
+                        .ok_or_else(|| self.error("invalid unicode escape"));
+                }
+                if !self.src[self.index..].starts_with("\\u") {
+                    return Err(self.error("expected a low surrogate"));
+                }
+                self.index += 2;
+                let low = self.hex4()?;
+                if !(0xdc00..0xe000).contains(&low) {
+                    return Err(self.error("expected a low surrogate"));
+                }
+                let code_point = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+                char::from_u32(code_point).ok_or_else(|| self.error("invalid unicode escape"))?
+            }
+            _ => {
+                self.index -= 1;
+                return Err(self.error("invalid escape"));
+            }
+        })
+    }
+
+    fn hex4(&mut self) -> Result<u32, InterchangeError> {
+        let digits = self
+            .src
+            .get(self.index..self.index + 4)
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("expected 4 hex digits"))?;
+        self.index += 4;
+        Ok(u32::from_str_radix(digits, 16).expect("the digits should be hex"))
+    }
+
+    fn array(&mut self) -> Result<JsonValue, InterchangeError> {
+        self.expect(b'[', "expected an array")?;
+        let mut values = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.index += 1;
+            return Ok(JsonValue::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.index += 1,
+                Some(b']') => {
+                    self.index += 1;
+                    return Ok(JsonValue::Array(values));
+                }
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<JsonValue, InterchangeError> {
+        self.expect(b'{', "expected an object")?;
+        let mut entries: Vec<(String, JsonValue)> = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.index += 1;
+            return Ok(JsonValue::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            let key_index = self.index;
+            let key = self.string()?;
+            if entries.iter().any(|(existing, _)| *existing == key) {
+                self.index = key_index;
+                return Err(self.error("duplicate key"));
+            }
+            self.expect(b':', "expected `:`")?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.index += 1,
+                Some(b'}') => {
+                    self.index += 1;
+                    return Ok(JsonValue::Object(entries));
+                }
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+}
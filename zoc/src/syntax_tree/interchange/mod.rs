@@ -0,0 +1,673 @@
+//! Text encodings of `minimal_ast::Expr` for tools outside Rust
+//! (e.g., analysis scripts), which cannot use the parser.
+//!
+//! # JSON
+//!
+//! Every node is a JSON object whose `"node"` key names its kind.
+//! The other keys are:
+//!
+//! | `"node"`     | keys                                                                      |
+//! |--------------|---------------------------------------------------------------------------|
+//! | `"ind"`      | `"digest"`, `"name"`, `"tag"`, `"universe"`, `"index_types"`, `"vcon_defs"` |
+//! | `"vcon_def"` | `"name"`, `"param_types"`, `"index_args"`                                 |
+//! | `"vcon"`     | `"digest"`, `"ind"`, `"vcon_index"`                                       |
+//! | `"match"`    | `"digest"`, `"matchee"`, `"return_type_arity"`, `"return_type"`, `"cases"` |
+//! | `"case"`     | `"arity"`, `"return_val"`                                                 |
+//! | `"fun"`      | `"digest"`, `"decreasing_index"`, `"param_types"`, `"return_type"`, `"return_val"` |
+//! | `"app"`      | `"digest"`, `"callee"`, `"args"`                                          |
+//! | `"for"`      | `"digest"`, `"param_types"`, `"return_type"`                              |
+//! | `"deb"`      | `"digest"`, `"index"`                                                     |
+//! | `"universe"` | `"digest"`, `"level"`, `"erasable"`                                       |
+//! | `"u64"`      | `"digest"`, `"value"`                                                     |
+//! | `"string"`   | `"digest"`, `"value"`                                                     |
+//! | `"prim"`     | `"digest"`, `"prim"`                                                      |
+//! | `"meta"`     | `"digest"`, `"id"`                                                        |
+//! | `"hole"`     | `"digest"`                                                                |
+//!
+//! Keys hold exprs, arrays of exprs, strings, non-negative integers,
+//! or booleans, except that:
+//!
+//! - `"universe"` (of an `"ind"`) is a `"universe"` node without a digest,
+//! - `"vcon_defs"` and `"cases"` are arrays of `"vcon_def"` and `"case"` nodes,
+//! - `"ind"` (of a `"vcon"`) is an `"ind"` node,
+//! - `"prim"` is the prim's name in Zo source (e.g., `"u64_add"`),
+//! - `"digest"` is the node's digest as 64 lowercase hex digits,
+//! - and `"tag"`, `"name"` (of a `"vcon_def"`), `"decreasing_index"`,
+//!   and `"return_val"` may be `null`.
+//!
+//! The encoder writes every key.
+//! The decoder requires every key except `"digest"`,
+//! rejects unknown keys,
+//! and checks every digest that is present.
+//! Both decoders reject inputs that nest
+//! more than `MAX_NESTING_DEPTH` arrays, objects, or lists deep.
+//!
+//! # S-expressions
+//!
+//! The S-expression encoding is canonical:
+//! each expr has exactly one encoding.
+//! A node is a list of its kind followed by the values of its keys
+//! (in the order above, without `"digest"`),
+//! an array is a list of its elements,
+//! and `null`, `true`, and `false` are written `nil`, `true`, and `false`.
+//! Strings are quoted, with `"` and `\` escaped by a backslash.
+//! Lists are separated by single spaces.
+//! The whole encoding is `(zo_expr "<digest>" <expr>)`.
+//! For example, `(vcon (ind Set0 "Unit" () ((() ()))) 0)` is encoded as
+//!
+//! ```text
+//! (zo_expr "<digest>" (vcon (ind "Unit" nil (universe 0 false) () ((vcon_def nil () ()))) 0))
+//! ```
+
+use crate::json::JsonValue;
+use crate::syntax_tree::ast::prelude::minimal_ast::*;
+
+mod json;
+pub use json::*;
+
+mod sexpr;
+pub use sexpr::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The deepest nesting of arrays and objects (or lists) the decoders accept.
+///
+/// Decoding recurses once per level,
+/// so without a limit a deeply nested input would overflow the stack.
+pub const MAX_NESTING_DEPTH: usize = 512;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InterchangeError {
+    /// The text is not well-formed JSON (or S-expression) syntax.
+    Syntax {
+        byte_index: usize,
+        message: &'static str,
+    },
+    /// The array, object, or list starting at `byte_index`
+    /// is nested more than `MAX_NESTING_DEPTH` deep.
+    TooDeep { byte_index: usize },
+    /// The value at `path` (e.g., `$.cases[1].return_val`)
+    /// does not follow the encoding.
+    Invalid { path: String, message: &'static str },
+    DigestMismatch {
+        path: String,
+        claimed: String,
+        actual: String,
+    },
+}
+
+/// The keys of each kind of node (other than `"node"`), in order.
+const NODE_KEYS: [(&str, &[&str]); 15] = [
+    (
+        "ind",
+        &[
+            "digest",
+            "name",
+            "tag",
+            "universe",
+            "index_types",
+            "vcon_defs",
+        ],
+    ),
+    ("vcon_def", &["name", "param_types", "index_args"]),
+    ("vcon", &["digest", "ind", "vcon_index"]),
+    (
+        "match",
+        &[
+            "digest",
+            "matchee",
+            "return_type_arity",
+            "return_type",
+            "cases",
+        ],
+    ),
+    ("case", &["arity", "return_val"]),
+    (
+        "fun",
+        &[
+            "digest",
+            "decreasing_index",
+            "param_types",
+            "return_type",
+            "return_val",
+        ],
+    ),
+    ("app", &["digest", "callee", "args"]),
+    ("for", &["digest", "param_types", "return_type"]),
+    ("deb", &["digest", "index"]),
+    ("universe", &["digest", "level", "erasable"]),
+    ("u64", &["digest", "value"]),
+    ("string", &["digest", "value"]),
+    ("prim", &["digest", "prim"]),
+    ("meta", &["digest", "id"]),
+    ("hole", &["digest"]),
+];
+
+fn node_keys(node: &str) -> Option<&'static [&'static str]> {
+    NODE_KEYS
+        .iter()
+        .find(|(name, _)| *name == node)
+        .map(|(_, keys)| *keys)
+}
+
+/// Returns the digest as 64 lowercase hex digits.
+pub fn digest_hex(digest: &Digest) -> String {
+    digest.0.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn node(kind: &str, entries: Vec<(&str, JsonValue)>) -> JsonValue {
+    let mut object = vec![("node".to_string(), JsonValue::String(kind.to_string()))];
+    object.extend(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value)),
+    );
+    JsonValue::Object(object)
+}
+
+fn optional_string(value: &Option<Rc<StringValue>>) -> JsonValue {
+    match value {
+        Some(value) => JsonValue::String(value.0.clone()),
+        None => JsonValue::Null,
+    }
+}
+
+fn exprs_to_value(exprs: &RcHashedVec<Expr>) -> JsonValue {
+    JsonValue::Array(exprs.hashee.iter().map(expr_to_value).collect())
+}
+
+fn expr_to_value(expr: &Expr) -> JsonValue {
+    let digest = JsonValue::String(digest_hex(expr.digest()));
+    match expr {
+        Expr::Ind(ind) => ind_to_value(ind),
+        Expr::Vcon(vcon) => node(
+            "vcon",
+            vec![
+                ("digest", digest),
+                ("ind", ind_to_value(&vcon.hashee.ind)),
+                (
+                    "vcon_index",
+                    JsonValue::Number(vcon.hashee.vcon_index as u64),
+                ),
+            ],
+        ),
+        Expr::Match(match_) => {
+            let match_ = &match_.hashee;
+            let cases = match_
+                .cases
+                .hashee
+                .iter()
+                .map(|case| {
+                    node(
+                        "case",
+                        vec![
+                            ("arity", JsonValue::Number(case.arity as u64)),
+                            (
+                                "return_val",
+                                case.return_val
+                                    .as_ref()
+                                    .map(expr_to_value)
+                                    .unwrap_or(JsonValue::Null),
+                            ),
+                        ],
+                    )
+                })
+                .collect();
+            node(
+                "match",
+                vec![
+                    ("digest", digest),
+                    ("matchee", expr_to_value(&match_.matchee)),
+                    (
+                        "return_type_arity",
+                        JsonValue::Number(match_.return_type_arity as u64),
+                    ),
+                    ("return_type", expr_to_value(&match_.return_type)),
+                    ("cases", JsonValue::Array(cases)),
+                ],
+            )
+        }
+        Expr::Fun(fun) => {
+            let fun = &fun.hashee;
+            node(
+                "fun",
+                vec![
+                    ("digest", digest),
+                    (
+                        "decreasing_index",
+                        fun.decreasing_index
+                            .map(|index| JsonValue::Number(index as u64))
+                            .unwrap_or(JsonValue::Null),
+                    ),
+                    ("param_types", exprs_to_value(&fun.param_types)),
+                    ("return_type", expr_to_value(&fun.return_type)),
+                    ("return_val", expr_to_value(&fun.return_val)),
+                ],
+            )
+        }
+        Expr::App(app) => node(
+            "app",
+            vec![
+                ("digest", digest),
+                ("callee", expr_to_value(&app.hashee.callee)),
+                ("args", exprs_to_value(&app.hashee.args)),
+            ],
+        ),
+        Expr::For(for_) => node(
+            "for",
+            vec![
+                ("digest", digest),
+                ("param_types", exprs_to_value(&for_.hashee.param_types)),
+                ("return_type", expr_to_value(&for_.hashee.return_type)),
+            ],
+        ),
+        Expr::Deb(deb) => node(
+            "deb",
+            vec![
+                ("digest", digest),
+                ("index", JsonValue::Number(deb.hashee.deb.0 as u64)),
+            ],
+        ),
+        Expr::Universe(universe) => {
+            let universe = universe.hashee.universe;
+            node(
+                "universe",
+                vec![
+                    ("digest", digest),
+                    ("level", JsonValue::Number(universe.level.0 as u64)),
+                    ("erasable", JsonValue::Bool(universe.erasable)),
+                ],
+            )
+        }
+        Expr::Lit(lit) => match &lit.hashee.lit {
+            Lit::U64(value) => node(
+                "u64",
+                vec![("digest", digest), ("value", JsonValue::Number(*value))],
+            ),
+            Lit::String(value) => node(
+                "string",
+                vec![
+                    ("digest", digest),
+                    ("value", JsonValue::String(value.0.clone())),
+                ],
+            ),
+        },
+        Expr::Prim(prim) => node(
+            "prim",
+            vec![
+                ("digest", digest),
+                (
+                    "prim",
+                    JsonValue::String(prim.hashee.prim.name().to_string()),
+                ),
+            ],
+        ),
+        Expr::Meta(meta) => node(
+            "meta",
+            vec![
+                ("digest", digest),
+                ("id", JsonValue::Number(meta.hashee.id.0 as u64)),
+            ],
+        ),
+        Expr::Hole(_) => node("hole", vec![("digest", digest)]),
+    }
+}
+
+fn ind_to_value(ind: &RcHashed<Ind>) -> JsonValue {
+    let digest = JsonValue::String(digest_hex(ind.digest()));
+    let ind = &ind.hashee;
+    let vcon_defs = ind
+        .vcon_defs
+        .hashee
+        .iter()
+        .map(|def| {
+            node(
+                "vcon_def",
+                vec![
+                    ("name", optional_string(&def.name)),
+                    ("param_types", exprs_to_value(&def.param_types)),
+                    ("index_args", exprs_to_value(&def.index_args)),
+                ],
+            )
+        })
+        .collect();
+    node(
+        "ind",
+        vec![
+            ("digest", digest),
+            ("name", JsonValue::String(ind.name.0.clone())),
+            ("tag", optional_string(&ind.tag)),
+            (
+                "universe",
+                node(
+                    "universe",
+                    vec![
+                        ("level", JsonValue::Number(ind.universe.level.0 as u64)),
+                        ("erasable", JsonValue::Bool(ind.universe.erasable)),
+                    ],
+                ),
+            ),
+            ("index_types", exprs_to_value(&ind.index_types)),
+            ("vcon_defs", JsonValue::Array(vcon_defs)),
+        ],
+    )
+}
+
+fn invalid(path: &str, message: &'static str) -> InterchangeError {
+    InterchangeError::Invalid {
+        path: path.to_string(),
+        message,
+    }
+}
+
+/// A node being decoded.
+struct NodeFields<'a> {
+    path: &'a str,
+    kind: &'a str,
+    entries: &'a [(String, JsonValue)],
+}
+
+impl<'a> NodeFields<'a> {
+    /// Checks that `value` is a node with known keys.
+    fn new(value: &'a JsonValue, path: &'a str) -> Result<Self, InterchangeError> {
+        let JsonValue::Object(entries) = value else {
+            return Err(invalid(path, "expected a node (i.e., an object)"));
+        };
+        let kind_path = format!("{path}.node");
+        let kind = match entries.iter().find(|(key, _)| key == "node") {
+            Some((_, JsonValue::String(kind))) => kind,
+            Some(_) => return Err(invalid(&kind_path, "expected a string")),
+            None => return Err(invalid(&kind_path, "missing key")),
+        };
+        let keys = node_keys(kind).ok_or_else(|| invalid(&kind_path, "unknown node kind"))?;
+        for (key, _) in entries {
+            if key != "node" && !keys.contains(&key.as_str()) {
+                return Err(invalid(&format!("{path}.{key}"), "unknown key"));
+            }
+        }
+        Ok(NodeFields {
+            path,
+            kind,
+            entries,
+        })
+    }
+
+    fn expect_kind(&self, kind: &str) -> Result<(), InterchangeError> {
+        if self.kind != kind {
+            return Err(invalid(
+                &format!("{}.node", self.path),
+                "unexpected node kind",
+            ));
+        }
+        Ok(())
+    }
+
+    fn path(&self, key: &str) -> String {
+        format!("{}.{key}", self.path)
+    }
+
+    fn get(&self, key: &str) -> Option<&'a JsonValue> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value)
+    }
+
+    fn required(&self, key: &str) -> Result<&'a JsonValue, InterchangeError> {
+        self.get(key)
+            .ok_or_else(|| invalid(&self.path(key), "missing key"))
+    }
+
+    fn expr(&self, key: &str) -> Result<Expr, InterchangeError> {
+        value_to_expr(self.required(key)?, &self.path(key))
+    }
+
+    fn optional_expr(&self, key: &str) -> Result<Option<Expr>, InterchangeError> {
+        match self.required(key)? {
+            JsonValue::Null => Ok(None),
+            value => value_to_expr(value, &self.path(key)).map(Some),
+        }
+    }
+
+    fn array(&self, key: &str) -> Result<&'a [JsonValue], InterchangeError> {
+        match self.required(key)? {
+            JsonValue::Array(values) => Ok(values),
+            _ => Err(invalid(&self.path(key), "expected an array")),
+        }
+    }
+
+    fn exprs(&self, key: &str) -> Result<RcHashedVec<Expr>, InterchangeError> {
+        let path = self.path(key);
+        let exprs = self
+            .array(key)?
+            .iter()
+            .enumerate()
+            .map(|(i, value)| value_to_expr(value, &format!("{path}[{i}]")))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rc_hashed(exprs))
+    }
+
+    fn number(&self, key: &str) -> Result<u64, InterchangeError> {
+        match self.required(key)? {
+            JsonValue::Number(number) => Ok(*number),
+            _ => Err(invalid(&self.path(key), "expected a non-negative integer")),
+        }
+    }
+
+    fn usize(&self, key: &str) -> Result<usize, InterchangeError> {
+        usize::try_from(self.number(key)?)
+            .map_err(|_| invalid(&self.path(key), "integer is too large"))
+    }
+
+    fn optional_usize(&self, key: &str) -> Result<Option<usize>, InterchangeError> {
+        match self.required(key)? {
+            JsonValue::Null => Ok(None),
+            _ => self.usize(key).map(Some),
+        }
+    }
+
+    fn bool(&self, key: &str) -> Result<bool, InterchangeError> {
+        match self.required(key)? {
+            JsonValue::Bool(value) => Ok(*value),
+            _ => Err(invalid(&self.path(key), "expected a boolean")),
+        }
+    }
+
+    fn string(&self, key: &str) -> Result<&'a str, InterchangeError> {
+        match self.required(key)? {
+            JsonValue::String(value) => Ok(value),
+            _ => Err(invalid(&self.path(key), "expected a string")),
+        }
+    }
+
+    fn optional_string(&self, key: &str) -> Result<Option<Rc<StringValue>>, InterchangeError> {
+        match self.required(key)? {
+            JsonValue::Null => Ok(None),
+            _ => Ok(Some(Rc::new(StringValue(self.string(key)?.to_string())))),
+        }
+    }
+
+    fn universe(&self) -> Result<Universe, InterchangeError> {
+        Ok(Universe {
+            level: UniverseLevel(self.usize("level")?),
+            erasable: self.bool("erasable")?,
+        })
+    }
+
+    /// Checks the claimed digest (if any) against the actual digest.
+    fn check_digest(&self, actual: &Digest) -> Result<(), InterchangeError> {
+        let Some(claimed) = self.get("digest") else {
+            return Ok(());
+        };
+        let path = self.path("digest");
+        let JsonValue::String(claimed) = claimed else {
+            return Err(invalid(&path, "expected a string"));
+        };
+        let is_hex_digest = claimed.len() == 64
+            && claimed
+                .bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+        if !is_hex_digest {
+            return Err(invalid(&path, "expected 64 lowercase hex digits"));
+        }
+
+        let actual = digest_hex(actual);
+        if *claimed != actual {
+            return Err(InterchangeError::DigestMismatch {
+                path,
+                claimed: claimed.clone(),
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn value_to_expr(value: &JsonValue, path: &str) -> Result<Expr, InterchangeError> {
+    let fields = NodeFields::new(value, path)?;
+    let expr = match fields.kind {
+        "ind" => Expr::Ind(value_to_ind(value, path)?),
+        "vcon" => {
+            let ind_path = fields.path("ind");
+            let ind = value_to_ind(fields.required("ind")?, &ind_path)?;
+            let vcon_index = fields.usize("vcon_index")?;
+            if vcon_index >= ind.hashee.vcon_defs.hashee.len() {
+                return Err(invalid(
+                    &fields.path("vcon_index"),
+                    "vcon index is out of range",
+                ));
+            }
+            Expr::Vcon(rc_hashed(Vcon {
+                ind,
+                vcon_index,
+                aux_data: (),
+            }))
+        }
+        "match" => {
+            let cases_path = fields.path("cases");
+            let cases = fields
+                .array("cases")?
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let path = format!("{cases_path}[{i}]");
+                    let case = NodeFields::new(value, &path)?;
+                    case.expect_kind("case")?;
+                    Ok(MatchCase {
+                        arity: case.usize("arity")?,
+                        return_val: case.optional_expr("return_val")?,
+                        aux_data: (),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Expr::Match(rc_hashed(Match {
+                matchee: fields.expr("matchee")?,
+                return_type_arity: fields.usize("return_type_arity")?,
+                return_type: fields.expr("return_type")?,
+                cases: rc_hashed(cases),
+                aux_data: (),
+            }))
+        }
+        "fun" => {
+            let param_types = fields.exprs("param_types")?;
+            let decreasing_index = fields.optional_usize("decreasing_index")?;
+            if decreasing_index.is_some_and(|index| index >= param_types.hashee.len()) {
+                return Err(invalid(
+                    &fields.path("decreasing_index"),
+                    "decreasing index is out of range",
+                ));
+            }
+            Expr::Fun(rc_hashed(Fun {
+                decreasing_index,
+                param_types,
+                return_type: fields.expr("return_type")?,
+                return_val: fields.expr("return_val")?,
+                aux_data: (),
+            }))
+        }
+        "app" => Expr::App(rc_hashed(App {
+            callee: fields.expr("callee")?,
+            args: fields.exprs("args")?,
+            aux_data: (),
+        })),
+        "for" => Expr::For(rc_hashed(For {
+            param_types: fields.exprs("param_types")?,
+            return_type: fields.expr("return_type")?,
+            aux_data: (),
+        })),
+        "deb" => Expr::Deb(rc_hashed(DebNode {
+            deb: Deb(fields.usize("index")?),
+            aux_data: (),
+        })),
+        "universe" => Expr::Universe(rc_hashed(UniverseNode {
+            universe: fields.universe()?,
+            aux_data: (),
+        })),
+        "u64" => Expr::Lit(rc_hashed(LitNode {
+            lit: Lit::U64(fields.number("value")?),
+            aux_data: (),
+        })),
+        "string" => Expr::Lit(rc_hashed(LitNode {
+            lit: Lit::String(Rc::new(StringValue(fields.string("value")?.to_string()))),
+            aux_data: (),
+        })),
+        "prim" => Expr::Prim(rc_hashed(PrimNode {
+            prim: Prim::from_name(fields.string("prim")?)
+                .ok_or_else(|| invalid(&fields.path("prim"), "unknown prim"))?,
+            aux_data: (),
+        })),
+        "meta" => Expr::Meta(rc_hashed(MetaNode {
+            id: MetaId(fields.usize("id")?),
+            aux_data: (),
+        })),
+        "hole" => Expr::Hole(rc_hashed(HoleNode { aux_data: () })),
+        _ => return Err(invalid(&fields.path("node"), "expected an expr node kind")),
+    };
+    fields.check_digest(expr.digest())?;
+    Ok(expr)
+}
+
+fn value_to_ind(value: &JsonValue, path: &str) -> Result<RcHashed<Ind>, InterchangeError> {
+    let fields = NodeFields::new(value, path)?;
+    fields.expect_kind("ind")?;
+
+    let universe_path = fields.path("universe");
+    let universe = NodeFields::new(fields.required("universe")?, &universe_path)?;
+    universe.expect_kind("universe")?;
+    if universe.get("digest").is_some() {
+        return Err(invalid(
+            &universe.path("digest"),
+            "an ind's universe has no digest",
+        ));
+    }
+
+    let vcon_defs_path = fields.path("vcon_defs");
+    let vcon_defs = fields
+        .array("vcon_defs")?
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let path = format!("{vcon_defs_path}[{i}]");
+            let def = NodeFields::new(value, &path)?;
+            def.expect_kind("vcon_def")?;
+            Ok(VconDef {
+                name: def.optional_string("name")?,
+                param_types: def.exprs("param_types")?,
+                index_args: def.exprs("index_args")?,
+                aux_data: (),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let ind = rc_hashed(Ind {
+        name: Rc::new(StringValue(fields.string("name")?.to_string())),
+        tag: fields.optional_string("tag")?,
+        universe: universe.universe()?,
+        index_types: fields.exprs("index_types")?,
+        vcon_defs: rc_hashed(vcon_defs),
+        aux_data: (),
+    });
+    fields.check_digest(ind.digest())?;
+    Ok(ind)
+}
@@ -0,0 +1,250 @@
+use super::*;
+
+/// Returns the canonical S-expression encoding of `expr`
+/// (see the module docs).
+pub fn expr_to_sexpr(expr: &Expr) -> String {
+    let mut out = "(zo_expr ".to_string();
+    write_sexpr_string(&digest_hex(expr.digest()), &mut out);
+    out.push(' ');
+    write_sexpr(&expr_to_value(expr), &mut out);
+    out.push(')');
+    out
+}
+
+/// Decodes the S-expression encoding of an expr (see the module docs).
+///
+/// Whitespace between items may be any mix of spaces, tabs, and newlines,
+/// so the encoding may be reformatted by hand.
+pub fn expr_from_sexpr(src: &str) -> Result<Expr, InterchangeError> {
+    let mut parser = SexprParser {
+        src,
+        index: 0,
+        depth: 0,
+    };
+    let sexpr = parser.sexpr()?;
+    parser.skip_whitespace();
+    if parser.index != src.len() {
+        return Err(parser.error("expected the end of the input"));
+    }
+
+    let root_error = InterchangeError::Invalid {
+        path: "$".to_string(),
+        message: "expected `(zo_expr \"<digest>\" <expr>)`",
+    };
+    let Sexpr::List(items) = sexpr else {
+        return Err(root_error);
+    };
+    let [Sexpr::Symbol(head), Sexpr::String(digest), expr] = &items[..] else {
+        return Err(root_error);
+    };
+    if head != "zo_expr" {
+        return Err(root_error);
+    }
+
+    let mut value = sexpr_to_value(expr, "$")?;
+    if let JsonValue::Object(entries) = &mut value {
+        entries.push(("digest".to_string(), JsonValue::String(digest.clone())));
+    }
+    value_to_expr(&value, "$")
+}
+
+fn write_sexpr(value: &JsonValue, out: &mut String) {
+    match value {
+        JsonValue::Null => out.push_str("nil"),
+        JsonValue::Bool(value) => out.push_str(&value.to_string()),
+        JsonValue::Number(value) => out.push_str(&value.to_string()),
+        JsonValue::String(value) => write_sexpr_string(value, out),
+        JsonValue::Array(values) => {
+            out.push('(');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(' ');
+                }
+                write_sexpr(value, out);
+            }
+            out.push(')');
+        }
+        JsonValue::Object(entries) => {
+            let Some((_, JsonValue::String(kind))) = entries.iter().find(|(key, _)| key == "node")
+            else {
+                unreachable!("every encoded object should be a node")
+            };
+            out.push('(');
+            out.push_str(kind);
+            for key in positional_keys(kind) {
+                if let Some((_, value)) = entries.iter().find(|(entry_key, _)| entry_key == key) {
+                    out.push(' ');
+                    write_sexpr(value, out);
+                }
+            }
+            out.push(')');
+        }
+    }
+}
+
+fn write_sexpr_string(value: &str, out: &mut String) {
+    out.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push('"');
+}
+
+/// Returns the keys whose values follow a node's kind.
+fn positional_keys(kind: &str) -> impl Iterator<Item = &'static str> {
+    node_keys(kind)
+        .unwrap_or(&[])
+        .iter()
+        .copied()
+        .filter(|key| *key != "digest")
+}
+
+#[derive(Clone, Debug)]
+enum Sexpr {
+    Symbol(String),
+    Number(u64),
+    String(String),
+    List(Vec<Sexpr>),
+}
+
+fn sexpr_to_value(sexpr: &Sexpr, path: &str) -> Result<JsonValue, InterchangeError> {
+    match sexpr {
+        Sexpr::Symbol(symbol) => match symbol.as_str() {
+            "nil" => Ok(JsonValue::Null),
+            "true" => Ok(JsonValue::Bool(true)),
+            "false" => Ok(JsonValue::Bool(false)),
+            _ => Err(invalid(path, "unexpected symbol")),
+        },
+        Sexpr::Number(number) => Ok(JsonValue::Number(*number)),
+        Sexpr::String(value) => Ok(JsonValue::String(value.clone())),
+        Sexpr::List(items) => match items.first() {
+            Some(Sexpr::Symbol(kind)) if node_keys(kind).is_some() => {
+                let keys: Vec<&str> = positional_keys(kind).collect();
+                if items.len() - 1 != keys.len() {
+                    return Err(invalid(path, "wrong number of values for the node kind"));
+                }
+                let mut entries = vec![("node".to_string(), JsonValue::String(kind.clone()))];
+                for (key, item) in keys.into_iter().zip(&items[1..]) {
+                    let value = sexpr_to_value(item, &format!("{path}.{key}"))?;
+                    entries.push((key.to_string(), value));
+                }
+                Ok(JsonValue::Object(entries))
+            }
+            Some(Sexpr::Symbol(_)) => Err(invalid(&format!("{path}.node"), "unknown node kind")),
+            _ => items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| sexpr_to_value(item, &format!("{path}[{i}]")))
+                .collect::<Result<Vec<_>, _>>()
+                .map(JsonValue::Array),
+        },
+    }
+}
+
+struct SexprParser<'a> {
+    src: &'a str,
+    index: usize,
+    /// The number of lists being parsed.
+    depth: usize,
+}
+
+impl SexprParser<'_> {
+    fn error(&self, message: &'static str) -> InterchangeError {
+        InterchangeError::Syntax {
+            byte_index: self.index,
+            message,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.as_bytes().get(self.index).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.index += 1;
+        }
+    }
+
+    fn sexpr(&mut self) -> Result<Sexpr, InterchangeError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'(') => {
+                if self.depth == MAX_NESTING_DEPTH {
+                    return Err(InterchangeError::TooDeep {
+                        byte_index: self.index,
+                    });
+                }
+                self.depth += 1;
+                let list = self.list();
+                self.depth -= 1;
+                list
+            }
+            Some(b'"') => {
+                self.index += 1;
+                let mut value = String::new();
+                loop {
+                    let Some(mut c) = self.src[self.index..].chars().next() else {
+                        return Err(self.error("unterminated string"));
+                    };
+                    self.index += c.len_utf8();
+                    if c == '"' {
+                        return Ok(Sexpr::String(value));
+                    }
+                    if c == '\\' {
+                        match self.peek() {
+                            Some(b'"') => c = '"',
+                            Some(b'\\') => c = '\\',
+                            _ => return Err(self.error("invalid escape")),
+                        }
+                        self.index += 1;
+                    }
+                    value.push(c);
+                }
+            }
+            Some(b'0'..=b'9') => {
+                let digits = self.atom();
+                if digits.len() > 1 && digits.starts_with('0') {
+                    return Err(self.error("numbers must not have leading zeros"));
+                }
+                digits
+                    .parse()
+                    .map(Sexpr::Number)
+                    .map_err(|_| self.error("expected a non-negative integer"))
+            }
+            Some(b) if b.is_ascii_alphabetic() => Ok(Sexpr::Symbol(self.atom().to_string())),
+            _ => Err(self.error("expected an S-expression")),
+        }
+    }
+
+    fn list(&mut self) -> Result<Sexpr, InterchangeError> {
+        self.index += 1;
+        let mut items = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(b')') {
+                self.index += 1;
+                return Ok(Sexpr::List(items));
+            }
+            if self.peek().is_none() {
+                return Err(self.error("unterminated list"));
+            }
+            items.push(self.sexpr()?);
+        }
+    }
+
+    /// Consumes the longest run of ASCII alphanumerics and underscores.
+    fn atom(&mut self) -> &str {
+        let start = self.index;
+        while let Some(b) = self.peek() {
+            if !(b.is_ascii_alphanumeric() || b == b'_') {
+                break;
+            }
+            self.index += 1;
+        }
+        &self.src[start..self.index]
+    }
+}
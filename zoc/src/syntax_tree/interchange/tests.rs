@@ -0,0 +1,265 @@
+use super::*;
+
+use crate::test_utils::*;
+
+use pretty_assertions::assert_eq;
+
+const ADD_SRC: &str = r#"
+(fun 0 ((ind Set0 "Nat" () ((() ()) ((0) ()))) (ind Set0 "Nat" () ((() ()) ((0) ()))))
+    (ind Set0 "Nat" () ((() ()) ((0) ())))
+    (match 2 1 (ind Set0 "Nat" () ((() ()) ((0) ()))) (
+        (0 1)
+        (1 ((vcon (ind Set0 "Nat" () ((() ()) ((0) ()))) 1) (1 0 2)))
+    ))
+)"#;
+
+/// Between them, these use every kind of node.
+fn round_trip_srcs() -> Vec<&'static str> {
+    vec![
+        ADD_SRC,
+        r#"(ind Prop0 "Eq" "eq_tag" (Set0 0 0) (("refl" (Set0 0) (1 0 0))))"#,
+        r#"(fun nonrec (U64 String) (for (U64) Set1) (u64_add 5u64 (string_len "a b")))"#,
+        "(match 0 1 U64 ((0 5u64) (1)))",
+        "(_3 ? Prop2)",
+        r#"(string_len "q{0x22}b\\ {0xa}{0x1}{0x7f} é😀")"#,
+    ]
+}
+
+#[test]
+fn json_round_trip() {
+    for src in round_trip_srcs() {
+        let expr = parse_minimal_ast_or_panic(src);
+        let decoded = expr_from_json(&expr_to_json(&expr)).unwrap();
+        assert_eq!(expr, decoded, "{src}");
+    }
+}
+
+#[test]
+fn sexpr_round_trip() {
+    for src in round_trip_srcs() {
+        let expr = parse_minimal_ast_or_panic(src);
+        let decoded = expr_from_sexpr(&expr_to_sexpr(&expr)).unwrap();
+        assert_eq!(expr, decoded, "{src}");
+    }
+}
+
+#[test]
+fn json_unit() {
+    let expr = parse_minimal_ast_or_panic(r#"(vcon (ind Set0 "Unit" () ((() ()))) 0)"#);
+    insta::assert_snapshot!(expr_to_json(&expr));
+}
+
+#[test]
+fn sexpr_unit() {
+    let expr = parse_minimal_ast_or_panic(r#"(vcon (ind Set0 "Unit" () ((() ()))) 0)"#);
+    insta::assert_snapshot!(expr_to_sexpr(&expr));
+}
+
+#[test]
+fn json_without_digests() {
+    let json = r#"{"node": "app", "callee": {"node": "prim", "prim": "string_len"}, "args": [
+        {"node": "string", "value": "é😀"}
+    ]}"#;
+    let expected = parse_minimal_ast_or_panic("(string_len \"é😀\")");
+    assert_eq!(expected, expr_from_json(json).unwrap());
+}
+
+#[test]
+fn json_strings_are_escaped() {
+    let expr = parse_minimal_ast_or_panic(r#""q{0x22}b\\ {0xa}{0x1}{0x7f} é😀""#);
+    let json = expr_to_json(&expr);
+    assert!(
+        json.contains(r#""value": "q\"b\\\\ \n\u0001\u007f é😀""#),
+        "{json}"
+    );
+}
+
+#[test]
+fn json_surrogate_pair() {
+    let json = r#"{"node": "string", "value": "\uD83D\uDE00 \u00e9 \"\\"}"#;
+    let expected = parse_minimal_ast_or_panic(r#""😀 é {0x22}\""#);
+    assert_eq!(expected, expr_from_json(json).unwrap());
+}
+
+#[test]
+fn json_unpaired_surrogate() {
+    let json = r#"{"node": "string", "value": "\uD83D"}"#;
+    assert!(matches!(
+        expr_from_json(json),
+        Err(InterchangeError::Syntax {
+            message: "expected a low surrogate",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn sexpr_is_whitespace_insensitive() {
+    let expr = parse_minimal_ast_or_panic(ADD_SRC);
+    let reformatted = expr_to_sexpr(&expr).replace(' ', "\n\t ");
+    assert_eq!(expr, expr_from_sexpr(&reformatted).unwrap());
+}
+
+#[test]
+fn json_invalid_case_return_val() {
+    let json = r#"{"node": "match", "matchee": {"node": "deb", "index": 0},
+        "return_type_arity": 1, "return_type": {"node": "prim", "prim": "U64"},
+        "cases": [{"node": "case", "arity": 0, "return_val": null},
+                  {"node": "case", "arity": 1, "return_val": 7}]}"#;
+    assert_eq!(
+        InterchangeError::Invalid {
+            path: "$.cases[1].return_val".to_string(),
+            message: "expected a node (i.e., an object)",
+        },
+        expr_from_json(json).unwrap_err()
+    );
+}
+
+#[test]
+fn json_missing_key() {
+    let json = r#"{"node": "app", "callee": {"node": "hole"}}"#;
+    assert_eq!(
+        InterchangeError::Invalid {
+            path: "$.args".to_string(),
+            message: "missing key",
+        },
+        expr_from_json(json).unwrap_err()
+    );
+}
+
+#[test]
+fn json_unknown_key() {
+    let json = r#"{"node": "deb", "index": 0, "name": "x"}"#;
+    assert_eq!(
+        InterchangeError::Invalid {
+            path: "$.name".to_string(),
+            message: "unknown key",
+        },
+        expr_from_json(json).unwrap_err()
+    );
+}
+
+#[test]
+fn json_vcon_index_out_of_range() {
+    let json = r#"{"node": "vcon", "vcon_index": 1, "ind": {"node": "ind", "name": "Unit",
+        "tag": null, "universe": {"node": "universe", "level": 0, "erasable": false},
+        "index_types": [],
+        "vcon_defs": [{"node": "vcon_def", "name": null, "param_types": [], "index_args": []}]}}"#;
+    assert_eq!(
+        InterchangeError::Invalid {
+            path: "$.vcon_index".to_string(),
+            message: "vcon index is out of range",
+        },
+        expr_from_json(json).unwrap_err()
+    );
+}
+
+#[test]
+fn json_digest_mismatch() {
+    let deb_0 = parse_minimal_ast_or_panic("0");
+    let deb_1 = parse_minimal_ast_or_panic("1");
+    let json = expr_to_json(&parse_minimal_ast_or_panic("(U64 0)"))
+        .replace(&digest_hex(deb_0.digest()), &digest_hex(deb_1.digest()));
+    assert_eq!(
+        InterchangeError::DigestMismatch {
+            path: "$.args[0].digest".to_string(),
+            claimed: digest_hex(deb_1.digest()),
+            actual: digest_hex(deb_0.digest()),
+        },
+        expr_from_json(&json).unwrap_err()
+    );
+}
+
+#[test]
+fn json_syntax_error() {
+    assert_eq!(
+        InterchangeError::Syntax {
+            byte_index: 24,
+            message: "expected `:`",
+        },
+        expr_from_json(r#"{"node": "deb", "index" 0}"#).unwrap_err()
+    );
+}
+
+#[test]
+fn sexpr_wrong_arity() {
+    let hex = digest_hex(parse_minimal_ast_or_panic("(0 1)").digest());
+    let sexpr = format!(r#"(zo_expr "{hex}" (app (deb 0) ((deb 1 2))))"#);
+    assert_eq!(
+        InterchangeError::Invalid {
+            path: "$.args[0]".to_string(),
+            message: "wrong number of values for the node kind",
+        },
+        expr_from_sexpr(&sexpr).unwrap_err()
+    );
+}
+
+#[test]
+fn sexpr_root_digest_mismatch() {
+    let sexpr = expr_to_sexpr(&parse_minimal_ast_or_panic("0"));
+    let tampered = sexpr.replace("(deb 0)", "(deb 1)");
+    assert!(
+        matches!(
+            expr_from_sexpr(&tampered),
+            Err(InterchangeError::DigestMismatch { path, .. }) if path == "$.digest"
+        ),
+        "{tampered}"
+    );
+}
+
+#[test]
+fn json_raw_control_characters() {
+    let json = "{\"node\": \"string\", \"value\": \"\u{7f}\u{85}\"}";
+    let expected = parse_minimal_ast_or_panic(r#""{0x7f}{0x85}""#);
+    assert_eq!(expected, expr_from_json(json).unwrap());
+
+    let json = "{\"node\": \"string\", \"value\": \"\u{1f}\"}";
+    assert_eq!(
+        InterchangeError::Syntax {
+            byte_index: 30,
+            message: "control characters in strings must be escaped",
+        },
+        expr_from_json(json).unwrap_err()
+    );
+}
+
+#[test]
+fn json_nesting_limit() {
+    let at_limit = "[".repeat(MAX_NESTING_DEPTH) + &"]".repeat(MAX_NESTING_DEPTH);
+    assert!(matches!(
+        expr_from_json(&at_limit),
+        Err(InterchangeError::Invalid { .. })
+    ));
+
+    let too_deep = "[".repeat(MAX_NESTING_DEPTH + 1) + &"]".repeat(MAX_NESTING_DEPTH + 1);
+    assert_eq!(
+        InterchangeError::TooDeep {
+            byte_index: MAX_NESTING_DEPTH,
+        },
+        expr_from_json(&too_deep).unwrap_err()
+    );
+
+    let far_too_deep = r#"{"node": "app", "callee": "#.repeat(100_000);
+    assert!(matches!(
+        expr_from_json(&far_too_deep),
+        Err(InterchangeError::TooDeep { .. })
+    ));
+}
+
+#[test]
+fn sexpr_nesting_limit() {
+    let hex = digest_hex(parse_minimal_ast_or_panic("0").digest());
+    let far_too_deep = format!(r#"(zo_expr "{hex}" {}"#, "(app ".repeat(100_000));
+    assert!(matches!(
+        expr_from_sexpr(&far_too_deep),
+        Err(InterchangeError::TooDeep { .. })
+    ));
+}
+
+#[test]
+fn deeply_nested_apps_round_trip() {
+    let src = "(".repeat(100) + "0" + &" 0)".repeat(100);
+    let expr = parse_minimal_ast_or_panic(&src);
+    assert_eq!(expr, expr_from_json(&expr_to_json(&expr)).unwrap());
+    assert_eq!(expr, expr_from_sexpr(&expr_to_sexpr(&expr)).unwrap());
+}
@@ -0,0 +1,202 @@
+use crate::{
+    hash::{Hashed, Interner, MaybeSendSync},
+    syntax_tree::ast::prelude::*,
+};
+
+/// Deep interning.
+///
+/// `Interner::intern` only interns the node it is given.
+/// The methods in this impl also intern every descendant,
+/// so structurally equal subtrees end up sharing one allocation.
+///
+/// Since a node's digest only depends on its children's digests,
+/// rebuilding a node with interned children does not change its digest,
+/// so nodes are never rehashed.
+impl Interner {
+    pub fn intern_expr<A: AuxDataFamily>(&self, expr: ast::Expr<A>) -> ast::Expr<A> {
+        match expr {
+            ast::Expr::Ind(e) => self.intern_ind(e).into(),
+            ast::Expr::Vcon(e) => self.intern_vcon(e).into(),
+            ast::Expr::Match(e) => self.intern_match(e).into(),
+            ast::Expr::Fun(e) => self.intern_fun(e).into(),
+            ast::Expr::App(e) => self.intern_app(e).into(),
+            ast::Expr::For(e) => self.intern_for(e).into(),
+            ast::Expr::Deb(e) => self.intern_rc(e).into(),
+            ast::Expr::Universe(e) => self.intern_rc(e).into(),
+            ast::Expr::Lit(e) => self.intern_rc(e).into(),
+            ast::Expr::Prim(e) => self.intern_rc(e).into(),
+            ast::Expr::Meta(e) => self.intern_rc(e).into(),
+            ast::Expr::Hole(e) => self.intern_rc(e).into(),
+        }
+    }
+
+    pub fn intern_ind<A: AuxDataFamily>(
+        &self,
+        ind: RcHashed<ast::Ind<A>>,
+    ) -> RcHashed<ast::Ind<A>> {
+        if let Some(existing) = self.get(&ind.digest) {
+            return existing;
+        }
+
+        let vcon_defs = self.get(&ind.hashee.vcon_defs.digest).unwrap_or_else(|| {
+            let defs = &ind.hashee.vcon_defs;
+            self.insert_rebuilt(
+                defs,
+                defs.hashee
+                    .iter()
+                    .map(|def| ast::VconDef {
+                        name: def.name.clone(),
+                        param_types: self.intern_expressions(def.param_types.clone()),
+                        index_args: self.intern_expressions(def.index_args.clone()),
+                        aux_data: def.aux_data.clone(),
+                    })
+                    .collect(),
+            )
+        });
+        self.insert_rebuilt(
+            &ind,
+            ast::Ind {
+                name: ind.hashee.name.clone(),
+                tag: ind.hashee.tag.clone(),
+                universe: ind.hashee.universe,
+                index_types: self.intern_expressions(ind.hashee.index_types.clone()),
+                vcon_defs,
+                aux_data: ind.hashee.aux_data.clone(),
+            },
+        )
+    }
+
+    fn intern_vcon<A: AuxDataFamily>(
+        &self,
+        vcon: RcHashed<ast::Vcon<A>>,
+    ) -> RcHashed<ast::Vcon<A>> {
+        if let Some(existing) = self.get(&vcon.digest) {
+            return existing;
+        }
+
+        self.insert_rebuilt(
+            &vcon,
+            ast::Vcon {
+                ind: self.intern_ind(vcon.hashee.ind.clone()),
+                vcon_index: vcon.hashee.vcon_index,
+                aux_data: vcon.hashee.aux_data.clone(),
+            },
+        )
+    }
+
+    fn intern_match<A: AuxDataFamily>(
+        &self,
+        match_: RcHashed<ast::Match<A>>,
+    ) -> RcHashed<ast::Match<A>> {
+        if let Some(existing) = self.get(&match_.digest) {
+            return existing;
+        }
+
+        let cases = self.get(&match_.hashee.cases.digest).unwrap_or_else(|| {
+            let cases = &match_.hashee.cases;
+            self.insert_rebuilt(
+                cases,
+                cases
+                    .hashee
+                    .iter()
+                    .map(|case| ast::MatchCase {
+                        arity: case.arity,
+                        return_val: case
+                            .return_val
+                            .clone()
+                            .map(|return_val| self.intern_expr(return_val)),
+                        aux_data: case.aux_data.clone(),
+                    })
+                    .collect(),
+            )
+        });
+        self.insert_rebuilt(
+            &match_,
+            ast::Match {
+                matchee: self.intern_expr(match_.hashee.matchee.clone()),
+                return_type_arity: match_.hashee.return_type_arity,
+                return_type: self.intern_expr(match_.hashee.return_type.clone()),
+                cases,
+                aux_data: match_.hashee.aux_data.clone(),
+            },
+        )
+    }
+
+    fn intern_fun<A: AuxDataFamily>(&self, fun: RcHashed<ast::Fun<A>>) -> RcHashed<ast::Fun<A>> {
+        if let Some(existing) = self.get(&fun.digest) {
+            return existing;
+        }
+
+        self.insert_rebuilt(
+            &fun,
+            ast::Fun {
+                decreasing_index: fun.hashee.decreasing_index,
+                param_types: self.intern_expressions(fun.hashee.param_types.clone()),
+                return_type: self.intern_expr(fun.hashee.return_type.clone()),
+                return_val: self.intern_expr(fun.hashee.return_val.clone()),
+                aux_data: fun.hashee.aux_data.clone(),
+            },
+        )
+    }
+
+    fn intern_app<A: AuxDataFamily>(&self, app: RcHashed<ast::App<A>>) -> RcHashed<ast::App<A>> {
+        if let Some(existing) = self.get(&app.digest) {
+            return existing;
+        }
+
+        self.insert_rebuilt(
+            &app,
+            ast::App {
+                callee: self.intern_expr(app.hashee.callee.clone()),
+                args: self.intern_expressions(app.hashee.args.clone()),
+                aux_data: app.hashee.aux_data.clone(),
+            },
+        )
+    }
+
+    fn intern_for<A: AuxDataFamily>(&self, for_: RcHashed<ast::For<A>>) -> RcHashed<ast::For<A>> {
+        if let Some(existing) = self.get(&for_.digest) {
+            return existing;
+        }
+
+        self.insert_rebuilt(
+            &for_,
+            ast::For {
+                param_types: self.intern_expressions(for_.hashee.param_types.clone()),
+                return_type: self.intern_expr(for_.hashee.return_type.clone()),
+                aux_data: for_.hashee.aux_data.clone(),
+            },
+        )
+    }
+
+    pub fn intern_expressions<A: AuxDataFamily>(
+        &self,
+        exprs: RcHashedVec<ast::Expr<A>>,
+    ) -> RcHashedVec<ast::Expr<A>> {
+        if let Some(existing) = self.get(&exprs.digest) {
+            return existing;
+        }
+
+        self.insert_rebuilt(
+            &exprs,
+            exprs
+                .hashee
+                .iter()
+                .map(|expr| self.intern_expr(expr.clone()))
+                .collect(),
+        )
+    }
+
+    /// Inserts `rebuilt`, which must be structurally equal to `original`,
+    /// under `original`'s digest.
+    fn insert_rebuilt<T: 'static + MaybeSendSync>(
+        &self,
+        original: &RcHashed<T>,
+        rebuilt: T,
+    ) -> RcHashed<T> {
+        self.insert(Rc::new(Hashed {
+            hashee: rebuilt,
+            digest: original.digest.clone(),
+        }))
+    }
+}
@@ -1,15 +1,66 @@
-use crate::syntax_tree::token::*;
+use crate::syntax_tree::{ast::Prim, token::*};
+
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LexError(ByteIndex, ByteIndex, LexErrorKind);
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct LexError(ByteIndex, ByteIndex);
+pub enum LexErrorKind {
+    UnexpectedChar(char),
+    /// A word that is not a keyword, literal, primitive, or universe
+    /// (e.g., `lambda`, `Set01`, or `_`).
+    UnrecognizedWord(String),
+    UnterminatedString,
+    /// A `/` that does not start a `//` comment.
+    LoneSlash,
+    /// An escape sequence other than `{0x<hex code point>}`.
+    InvalidEscapeSequence,
+    /// A `{` without a matching `}`.
+    UnterminatedEscapeSequence,
+    /// A `}` that does not end an escape sequence.
+    UnescapedRightCurly,
+}
+
+impl LexError {
+    pub fn span(&self) -> Span {
+        (self.0, self.1)
+    }
+
+    pub fn kind(&self) -> &LexErrorKind {
+        &self.2
+    }
+}
 
 pub fn lex(s: &str) -> Result<Vec<Token>, LexError> {
+    let (tokens, _) = lex_with_comments(s)?;
+    Ok(tokens)
+}
+
+/// Like `lex`, but also returns the comments,
+/// which `lex` discards.
+pub fn lex_with_comments(s: &str) -> Result<(Vec<Token>, Vec<Comment>), LexError> {
+    let (tokens, comments, errors) = lex_with_recovery(s);
+    match errors.into_iter().next() {
+        Some(err) => Err(err),
+        None => Ok((tokens, comments)),
+    }
+}
+
+/// Like `lex_with_comments`, but instead of stopping at the first error,
+/// this replaces every unlexable span with a `Token::Error`
+/// and keeps going.
+///
+/// The errors are returned in source order.
+pub fn lex_with_recovery(s: &str) -> (Vec<Token>, Vec<Comment>, Vec<LexError>) {
     Lexer::new(s).lex()
 }
 
 struct Lexer<'a> {
     src: &'a str,
     out: Vec<Token>,
+    comments: Vec<Comment>,
+    errors: Vec<LexError>,
     state: State,
 }
 
@@ -19,7 +70,7 @@ enum State {
     Word { start: ByteIndex, byte_len: usize },
     String { start: ByteIndex, byte_len: usize },
     Slash(ByteIndex),
-    SingleLineComment,
+    SingleLineComment { start: ByteIndex },
 }
 
 impl<'a> Lexer<'a> {
@@ -27,21 +78,23 @@ impl<'a> Lexer<'a> {
         Lexer {
             src,
             out: Vec::new(),
+            comments: Vec::new(),
+            errors: Vec::new(),
             state: State::Main,
         }
     }
 }
 
 impl Lexer<'_> {
-    fn lex(mut self) -> Result<Vec<Token>, LexError> {
+    fn lex(mut self) -> (Vec<Token>, Vec<Comment>, Vec<LexError>) {
         for (current_index, curent) in self.src.char_indices() {
-            self.handle_char(ByteIndex(current_index), curent)?;
+            self.handle_char(ByteIndex(current_index), curent);
         }
-        self.finish_pending_state_and_reset()?;
-        Ok(self.out)
+        self.finish_pending_state_and_reset();
+        (self.out, self.comments, self.errors)
     }
 
-    fn handle_char(&mut self, current_index: ByteIndex, current: char) -> Result<(), LexError> {
+    fn handle_char(&mut self, current_index: ByteIndex, current: char) {
         match self.state {
             State::Main => self.handle_char_assuming_state_is_main(current_index, current),
             State::Word { start, byte_len } => {
@@ -50,18 +103,16 @@ impl Lexer<'_> {
             State::String { start, byte_len } => {
                 self.handle_char_assuming_state_is_string(current, start, byte_len)
             }
-            State::Slash(_) => self.handle_char_assuming_state_is_slash(current_index, current),
-            State::SingleLineComment => {
-                self.handle_char_assuming_state_is_single_line_comment(current)
+            State::Slash(start) => {
+                self.handle_char_assuming_state_is_slash(current_index, current, start)
+            }
+            State::SingleLineComment { .. } => {
+                self.handle_char_assuming_state_is_single_line_comment(current_index, current)
             }
         }
     }
 
-    fn handle_char_assuming_state_is_main(
-        &mut self,
-        current_index: ByteIndex,
-        current: char,
-    ) -> Result<(), LexError> {
+    fn handle_char_assuming_state_is_main(&mut self, current_index: ByteIndex, current: char) {
         match current {
             ' ' | '\t' | '\n' => {}
             '"' => {
@@ -78,15 +129,14 @@ impl Lexer<'_> {
             }
             '(' => self.out.push(Token::LParen(current_index)),
             ')' => self.out.push(Token::RParen(current_index)),
+            '?' => self.out.push(Token::Question(current_index)),
             '/' => self.state = State::Slash(current_index),
-            _ => {
-                return Err(LexError(
-                    current_index,
-                    ByteIndex(current_index.0 + current.len_utf8()),
-                ))
-            }
+            _ => self.push_error(
+                current_index,
+                ByteIndex(current_index.0 + current.len_utf8()),
+                LexErrorKind::UnexpectedChar(current),
+            ),
         }
-        Ok(())
     }
 
     fn handle_char_assuming_state_is_word(
@@ -95,7 +145,7 @@ impl Lexer<'_> {
         current: char,
         start: ByteIndex,
         byte_len: usize,
-    ) -> Result<(), LexError> {
+    ) {
         match current {
             'a'..='z' | 'A'..='Z' | '_' | '0'..='9' => {
                 self.state = State::Word {
@@ -104,11 +154,10 @@ impl Lexer<'_> {
                 }
             }
             _ => {
-                self.finish_pending_state_and_reset()?;
-                self.handle_char(current_index, current)?;
+                self.finish_pending_state_and_reset();
+                self.handle_char(current_index, current);
             }
         }
-        Ok(())
     }
 
     fn handle_char_assuming_state_is_string(
@@ -116,85 +165,124 @@ impl Lexer<'_> {
         current: char,
         start: ByteIndex,
         byte_len: usize,
-    ) -> Result<(), LexError> {
+    ) {
         self.state = State::String {
             start,
             byte_len: byte_len + current.len_utf8(),
         };
 
         if current == '"' {
-            self.finish_pending_state_and_reset()?;
+            self.finish_pending_state_and_reset();
         }
-
-        Ok(())
     }
 
     fn handle_char_assuming_state_is_slash(
         &mut self,
         current_index: ByteIndex,
         current: char,
-    ) -> Result<(), LexError> {
+        start: ByteIndex,
+    ) {
         if current == '/' {
-            self.state = State::SingleLineComment;
-            return Ok(());
+            self.state = State::SingleLineComment { start };
+            return;
         }
 
-        self.finish_pending_state_and_reset()?;
-        self.handle_char(current_index, current)
+        self.finish_pending_state_and_reset();
+        self.handle_char(current_index, current);
     }
 
     fn handle_char_assuming_state_is_single_line_comment(
         &mut self,
+        current_index: ByteIndex,
         current: char,
-    ) -> Result<(), LexError> {
+    ) {
         if current == '\n' {
+            self.finish_comment(current_index);
             self.state = State::Main;
         }
-        Ok(())
     }
 
-    fn finish_pending_state_and_reset(&mut self) -> Result<(), LexError> {
-        self.finish_pending_state()?;
+    /// Pushes the pending comment, which ends at `end`.
+    fn finish_comment(&mut self, end: ByteIndex) {
+        let State::SingleLineComment { start } = self.state else {
+            return;
+        };
+        let text = self.src[start.0..end.0].trim_end();
+        self.comments.push(Comment {
+            text: text.to_string(),
+            span: (start, ByteIndex(start.0 + text.len())),
+        });
+    }
+
+    /// Records the error, and pushes a `Token::Error` in place of
+    /// the unlexable span, so the parser can recover.
+    fn push_error(&mut self, start: ByteIndex, end: ByteIndex, kind: LexErrorKind) {
+        self.errors.push(LexError(start, end, kind));
+        self.out.push(Token::Error((start, end)));
+    }
+
+    fn finish_pending_state_and_reset(&mut self) {
+        self.finish_pending_state();
         self.state = State::Main;
-        Ok(())
     }
 
-    fn finish_pending_state(&mut self) -> Result<(), LexError> {
+    fn finish_pending_state(&mut self) {
         match self.state {
-            State::Main => Ok(()),
+            State::Main => {}
 
             State::Word { start, byte_len } => {
-                let word_src = &self.src[start.0..start.0 + byte_len];
-                let Some(word) = parse_word(word_src, start) else {
-                    return Err(LexError(start, ByteIndex(start.0 + byte_len)));
-                };
-                self.out.push(word);
-                Ok(())
+                let end = ByteIndex(start.0 + byte_len);
+                let word_src = &self.src[start.0..end.0];
+                match parse_word(word_src, start) {
+                    Some(word) => self.out.push(word),
+                    None => self.push_error(
+                        start,
+                        end,
+                        LexErrorKind::UnrecognizedWord(word_src.to_string()),
+                    ),
+                }
             }
 
             State::String { start, byte_len } => {
                 let end = ByteIndex(start.0 + byte_len);
+                let is_terminated =
+                    byte_len >= 2 * '"'.len_utf8() && self.src[start.0..end.0].ends_with('"');
+                if !is_terminated {
+                    // Only the end of the input
+                    // can interrupt a string.
+                    self.push_error(start, end, LexErrorKind::UnterminatedString);
+                    return;
+                }
+
                 let quote_exclusive_start = ByteIndex(start.0 + '"'.len_utf8());
                 let quote_exclusive_end = ByteIndex(start.0 + byte_len - '"'.len_utf8());
                 let quote_exclusive_string_src =
                     &self.src[quote_exclusive_start.0..quote_exclusive_end.0];
-                let value = match get_string_value(quote_exclusive_string_src) {
-                    Ok(v) => v,
-                    Err(local_span) => {
-                        return Err(LexError(
-                            quote_exclusive_start + local_span.0,
-                            quote_exclusive_start + local_span.1,
+                match get_string_value(quote_exclusive_string_src) {
+                    Ok(value) => self.out.push(Token::String(StringLiteral {
+                        value,
+                        span: (start, end),
+                    })),
+                    Err((local_start, local_end, kind)) => {
+                        // We report the precise span of the bad escape,
+                        // but replace the whole string with the error token.
+                        self.errors.push(LexError(
+                            quote_exclusive_start + local_start,
+                            quote_exclusive_start + local_end,
+                            kind,
                         ));
+                        self.out.push(Token::Error((start, end)));
                     }
-                };
-                self.out.push(Token::String(StringLiteral {
-                    value,
-                    span: (start, end),
-                }));
-                Ok(())
+                }
+            }
+            State::Slash(start) => self.push_error(
+                start,
+                ByteIndex(start.0 + '/'.len_utf8()),
+                LexErrorKind::LoneSlash,
+            ),
+            State::SingleLineComment { .. } => {
+                self.finish_comment(ByteIndex(self.src.len()));
             }
-            State::Slash(start) => Err(LexError(start, ByteIndex(start.0 + '/'.len_utf8()))),
-            State::SingleLineComment => Ok(()),
         }
     }
 }
@@ -207,6 +295,29 @@ fn parse_word(s: &str, start: ByteIndex) -> Option<Token> {
         }));
     }
 
+    if let Some(value_src) = s.strip_suffix("u64") {
+        let value = parse_without_extraneous_leading_zeros(value_src)?;
+        return Some(Token::U64(U64Literal {
+            value,
+            span: (start, ByteIndex(start.0 + s.len())),
+        }));
+    }
+
+    if let Some(id_src) = s.strip_prefix('_') {
+        let id = parse_without_extraneous_leading_zeros(id_src)?;
+        return Some(Token::Meta(MetaLiteral {
+            id,
+            span: (start, ByteIndex(start.0 + s.len())),
+        }));
+    }
+
+    if let Some(prim) = Prim::from_name(s) {
+        return Some(Token::Prim(PrimLiteral {
+            prim,
+            span: (start, ByteIndex(start.0 + s.len())),
+        }));
+    }
+
     match s {
         "ind" => return Some(Token::IndKw(start)),
         "vcon" => return Some(Token::VconKw(start)),
@@ -238,6 +349,19 @@ fn parse_word(s: &str, start: ByteIndex) -> Option<Token> {
     None
 }
 
+fn parse_without_extraneous_leading_zeros<T: FromStr>(s: &str) -> Option<T> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let has_extraneous_leading_zeros = s != "0" && s.starts_with('0');
+    if has_extraneous_leading_zeros {
+        return None;
+    }
+
+    s.parse::<T>().ok()
+}
+
 fn get_number_after_prefix(s: &str, prefix: &str) -> Option<usize> {
     let level_src = &s[prefix.len()..];
     if level_src.is_empty() {
@@ -266,8 +390,9 @@ mod string_parser {
     /// If `quote_exclusive_string_src` has double quotes,
     /// this function may produce an incorrect result.
     ///
-    /// This function returns `Err(span)` if it encounters
-    /// an invalid escape sequence or an unterminated escape sequence.
+    /// This function returns `Err((start, end, kind))` if it encounters
+    /// an invalid escape sequence, an unterminated escape sequence,
+    /// or an unescaped right curly brace.
     /// - If the escape sequence is invalid,
     ///   `span` is the span of the invalid escape sequence,
     ///   **excluding** the enclosing curly braces.
@@ -276,9 +401,11 @@ mod string_parser {
     ///   **including** the left curly brace.
     ///   By definition, there is no right curly brace
     ///   (otherwise the escape sequence would be terminated).
+    /// - If there is an unescaped right curly brace,
+    ///   `span` is the span of that brace.
     pub fn get_string_value(
         quote_exclusive_string_src: &str,
-    ) -> Result<String, (ByteIndex, ByteIndex)> {
+    ) -> Result<String, (ByteIndex, ByteIndex, LexErrorKind)> {
         StringParser::new(quote_exclusive_string_src).parse()
     }
 
@@ -305,15 +432,17 @@ mod string_parser {
     }
 
     impl StringParser<'_> {
-        fn parse(mut self) -> Result<String, (ByteIndex, ByteIndex)> {
+        fn parse(mut self) -> Result<String, (ByteIndex, ByteIndex, LexErrorKind)> {
             for (current_index, current) in self.quote_exclusive_string_src.char_indices() {
                 self.handle_char(ByteIndex(current_index), current)?;
             }
 
             match self.state {
-                State::Escape { start, .. } => {
-                    Err((start, ByteIndex(self.quote_exclusive_string_src.len())))
-                }
+                State::Escape { start, .. } => Err((
+                    start,
+                    ByteIndex(self.quote_exclusive_string_src.len()),
+                    LexErrorKind::UnterminatedEscapeSequence,
+                )),
                 State::Main => Ok(self.out),
             }
         }
@@ -322,7 +451,7 @@ mod string_parser {
             &mut self,
             current_index: ByteIndex,
             current: char,
-        ) -> Result<(), (ByteIndex, ByteIndex)> {
+        ) -> Result<(), (ByteIndex, ByteIndex, LexErrorKind)> {
             match self.state {
                 State::Main => self.handle_char_assuming_state_is_main(current_index, current),
                 State::Escape { start, byte_len } => self.handle_char_assuming_state_is_escape(
@@ -338,7 +467,7 @@ mod string_parser {
             &mut self,
             current_index: ByteIndex,
             current: char,
-        ) -> Result<(), (ByteIndex, ByteIndex)> {
+        ) -> Result<(), (ByteIndex, ByteIndex, LexErrorKind)> {
             match current {
                 '{' => {
                     self.state = State::Escape {
@@ -348,7 +477,11 @@ mod string_parser {
                     Ok(())
                 }
 
-                '}' => Err((current_index, ByteIndex(current_index.0 + '}'.len_utf8()))),
+                '}' => Err((
+                    current_index,
+                    ByteIndex(current_index.0 + '}'.len_utf8()),
+                    LexErrorKind::UnescapedRightCurly,
+                )),
 
                 _ => {
                     self.out.push(current);
@@ -363,7 +496,7 @@ mod string_parser {
             current: char,
             start: ByteIndex,
             byte_len: usize,
-        ) -> Result<(), (ByteIndex, ByteIndex)> {
+        ) -> Result<(), (ByteIndex, ByteIndex, LexErrorKind)> {
             if current == '}' {
                 let brace_exclusive_start = ByteIndex(start.0 + '{'.len_utf8());
                 let brace_exclusive_end = current_index;
@@ -383,8 +516,12 @@ mod string_parser {
             &mut self,
             brace_exclusive_start: ByteIndex,
             brace_exclusive_end: ByteIndex,
-        ) -> Result<(), (ByteIndex, ByteIndex)> {
-            let invalid_escape_sequence_err = Err((brace_exclusive_start, brace_exclusive_end));
+        ) -> Result<(), (ByteIndex, ByteIndex, LexErrorKind)> {
+            let invalid_escape_sequence_err = Err((
+                brace_exclusive_start,
+                brace_exclusive_end,
+                LexErrorKind::InvalidEscapeSequence,
+            ));
 
             let byte_len = brace_exclusive_end.0 - brace_exclusive_start.0;
             if byte_len < 3 {
@@ -461,70 +598,110 @@ mod string_parser {
         #[test]
         fn unterminated_invalid_escape() {
             let actual = get_string_value("hello {world");
-            let expected = Err((ByteIndex(6), ByteIndex(12)));
+            let expected = Err((
+                ByteIndex(6),
+                ByteIndex(12),
+                LexErrorKind::UnterminatedEscapeSequence,
+            ));
             assert_eq!(expected, actual);
         }
 
         #[test]
         fn unterminated_but_otherwise_valid_escape() {
             let actual = get_string_value("hello {0x22");
-            let expected = Err((ByteIndex(6), ByteIndex(11)));
+            let expected = Err((
+                ByteIndex(6),
+                ByteIndex(11),
+                LexErrorKind::UnterminatedEscapeSequence,
+            ));
             assert_eq!(expected, actual);
         }
 
         #[test]
         fn empty_escape() {
             let actual = get_string_value("hello {} world");
-            let expected = Err((ByteIndex(7), ByteIndex(7)));
+            let expected = Err((
+                ByteIndex(7),
+                ByteIndex(7),
+                LexErrorKind::InvalidEscapeSequence,
+            ));
             assert_eq!(expected, actual);
         }
 
         #[test]
         fn too_short_escape_1_char() {
             let actual = get_string_value("hello {0} world");
-            let expected = Err((ByteIndex(7), ByteIndex(8)));
+            let expected = Err((
+                ByteIndex(7),
+                ByteIndex(8),
+                LexErrorKind::InvalidEscapeSequence,
+            ));
             assert_eq!(expected, actual);
         }
 
         #[test]
         fn too_short_escape_2_char() {
             let actual = get_string_value("hello {0x} world");
-            let expected = Err((ByteIndex(7), ByteIndex(9)));
+            let expected = Err((
+                ByteIndex(7),
+                ByteIndex(9),
+                LexErrorKind::InvalidEscapeSequence,
+            ));
             assert_eq!(expected, actual);
         }
 
         #[test]
         fn bad_prefix_escape() {
             let actual = get_string_value("hello {BEEF} world");
-            let expected = Err((ByteIndex(7), ByteIndex(11)));
+            let expected = Err((
+                ByteIndex(7),
+                ByteIndex(11),
+                LexErrorKind::InvalidEscapeSequence,
+            ));
             assert_eq!(expected, actual);
         }
 
         #[test]
         fn curly_in_escape() {
             let actual = get_string_value("hello {0x{A}} world");
-            let expected = Err((ByteIndex(7), ByteIndex(11)));
+            let expected = Err((
+                ByteIndex(7),
+                ByteIndex(11),
+                LexErrorKind::InvalidEscapeSequence,
+            ));
             assert_eq!(expected, actual);
         }
 
         #[test]
         fn non_hex_escape() {
             let actual = get_string_value("hello {0xG} world");
-            let expected = Err((ByteIndex(7), ByteIndex(10)));
+            let expected = Err((
+                ByteIndex(7),
+                ByteIndex(10),
+                LexErrorKind::InvalidEscapeSequence,
+            ));
             assert_eq!(expected, actual);
         }
 
         #[test]
         fn capital_x_escape() {
             let actual = get_string_value("hello {0XA} world");
-            let expected = Err((ByteIndex(7), ByteIndex(10)));
+            let expected = Err((
+                ByteIndex(7),
+                ByteIndex(10),
+                LexErrorKind::InvalidEscapeSequence,
+            ));
             assert_eq!(expected, actual);
         }
 
         #[test]
         fn unescaped_rcurly() {
             let actual = get_string_value("hello } world");
-            let expected = Err((ByteIndex(6), ByteIndex(7)));
+            let expected = Err((
+                ByteIndex(6),
+                ByteIndex(7),
+                LexErrorKind::UnescapedRightCurly,
+            ));
             assert_eq!(expected, actual);
         }
     }
@@ -683,11 +860,30 @@ nonrec)"#;
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn comments_are_kept_by_lex_with_comments() {
+        let src = "(// foo \n// bar\nnonrec) // baz";
+        let (_, actual) = lex_with_comments(src).unwrap();
+        let comment = |text: &str| {
+            let start = src.find(text).unwrap();
+            Comment {
+                text: text.to_string(),
+                span: (ByteIndex(start), ByteIndex(start + text.len())),
+            }
+        };
+        let expected = vec![comment("// foo"), comment("// bar"), comment("// baz")];
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn set_zero_zero() {
         let src = r#"Set00"#;
         let actual = lex(src);
-        let expected = Err(LexError(ByteIndex(0), ByteIndex(src.len())));
+        let expected = Err(LexError(
+            ByteIndex(0),
+            ByteIndex(src.len()),
+            LexErrorKind::UnrecognizedWord(src.to_string()),
+        ));
         assert_eq!(expected, actual);
     }
 
@@ -695,7 +891,11 @@ nonrec)"#;
     fn set_zero_one() {
         let src = r#"Set01"#;
         let actual = lex(src);
-        let expected = Err(LexError(ByteIndex(0), ByteIndex(src.len())));
+        let expected = Err(LexError(
+            ByteIndex(0),
+            ByteIndex(src.len()),
+            LexErrorKind::UnrecognizedWord(src.to_string()),
+        ));
         assert_eq!(expected, actual);
     }
 
@@ -703,7 +903,11 @@ nonrec)"#;
     fn prop_zero_zero() {
         let src = r#"Prop00"#;
         let actual = lex(src);
-        let expected = Err(LexError(ByteIndex(0), ByteIndex(src.len())));
+        let expected = Err(LexError(
+            ByteIndex(0),
+            ByteIndex(src.len()),
+            LexErrorKind::UnrecognizedWord(src.to_string()),
+        ));
         assert_eq!(expected, actual);
     }
 
@@ -711,7 +915,125 @@ nonrec)"#;
     fn prop_zero_one() {
         let src = r#"Prop01"#;
         let actual = lex(src);
-        let expected = Err(LexError(ByteIndex(0), ByteIndex(src.len())));
+        let expected = Err(LexError(
+            ByteIndex(0),
+            ByteIndex(src.len()),
+            LexErrorKind::UnrecognizedWord(src.to_string()),
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn u64_literals() {
+        let src = r#"0u64 42u64 18446744073709551615u64"#;
+        let actual = lex(src);
+        let expected = Ok(vec![
+            Token::U64(U64Literal {
+                value: 0,
+                span: (ByteIndex(0), ByteIndex(4)),
+            }),
+            Token::U64(U64Literal {
+                value: 42,
+                span: (ByteIndex(5), ByteIndex(10)),
+            }),
+            Token::U64(U64Literal {
+                value: u64::MAX,
+                span: (ByteIndex(11), ByteIndex(src.len())),
+            }),
+        ]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn u64_literal_zero_one() {
+        let src = r#"01u64"#;
+        let actual = lex(src);
+        let expected = Err(LexError(
+            ByteIndex(0),
+            ByteIndex(src.len()),
+            LexErrorKind::UnrecognizedWord(src.to_string()),
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn u64_literal_overflow() {
+        let src = r#"18446744073709551616u64"#;
+        let actual = lex(src);
+        let expected = Err(LexError(
+            ByteIndex(0),
+            ByteIndex(src.len()),
+            LexErrorKind::UnrecognizedWord(src.to_string()),
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn prims() {
+        let src = r#"U64 String u64_add u64_sub u64_mul string_concat string_len"#;
+        let actual = lex(src);
+        let prim = |prim: Prim, word: &str| {
+            let start = src.find(word).unwrap();
+            Token::Prim(PrimLiteral {
+                prim,
+                span: (ByteIndex(start), ByteIndex(start + word.len())),
+            })
+        };
+        let expected = Ok(vec![
+            prim(Prim::U64, "U64"),
+            prim(Prim::String, "String"),
+            prim(Prim::U64Add, "u64_add"),
+            prim(Prim::U64Sub, "u64_sub"),
+            prim(Prim::U64Mul, "u64_mul"),
+            prim(Prim::StringConcat, "string_concat"),
+            prim(Prim::StringLen, "string_len"),
+        ]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn metas() {
+        let src = r#"_0 _17"#;
+        let actual = lex(src);
+        let expected = Ok(vec![
+            Token::Meta(MetaLiteral {
+                id: 0,
+                span: (ByteIndex(0), ByteIndex(2)),
+            }),
+            Token::Meta(MetaLiteral {
+                id: 17,
+                span: (ByteIndex(3), ByteIndex(6)),
+            }),
+        ]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn holes() {
+        let src = r#"(1 ? ?)"#;
+        let actual = lex(src);
+        let expected = Ok(vec![
+            Token::LParen(ByteIndex(0)),
+            Token::Number(NumberLiteral {
+                value: 1,
+                span: (ByteIndex(1), ByteIndex(2)),
+            }),
+            Token::Question(ByteIndex(3)),
+            Token::Question(ByteIndex(5)),
+            Token::RParen(ByteIndex(6)),
+        ]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn bare_underscore() {
+        let src = r#"_"#;
+        let actual = lex(src);
+        let expected = Err(LexError(
+            ByteIndex(0),
+            ByteIndex(src.len()),
+            LexErrorKind::UnrecognizedWord(src.to_string()),
+        ));
         assert_eq!(expected, actual);
     }
 }
@@ -1,6 +1,8 @@
 pub mod ast;
 pub mod cst;
 pub mod cst_to_spanned_ast;
+pub mod interchange;
+pub mod intern_ast;
 pub mod lexer;
 pub mod parser;
 pub mod remove_ast_aux_data;
@@ -3,7 +3,7 @@
 // You can read more at https://crates.io/crates/kiki
 //
 // This code was generated from a grammar with the following hash:
-// @sha256 6ac21ed00821cb12f9b715c5f7e52bb88a2639aec5ab21c4455199f232fa6bf2
+// @sha256 0053e60df6d0346f52f13df5ed1f6750d801bf1b5ed5b970375bed55fbc41d53
 
 // Since this code is automatically generated,
 // some parts may be unidiomatic.
@@ -17,6 +17,7 @@
 pub enum Token {
     LParen(crate::syntax_tree::token::ByteIndex),
     RParen(crate::syntax_tree::token::ByteIndex),
+    Question(crate::syntax_tree::token::ByteIndex),
     IndKw(crate::syntax_tree::token::ByteIndex),
     VconKw(crate::syntax_tree::token::ByteIndex),
     MatchKw(crate::syntax_tree::token::ByteIndex),
@@ -26,6 +27,10 @@ pub enum Token {
     Number(crate::syntax_tree::token::NumberLiteral),
     String(crate::syntax_tree::token::StringLiteral),
     Universe(crate::syntax_tree::token::UniverseLiteral),
+    U64(crate::syntax_tree::token::U64Literal),
+    Prim(crate::syntax_tree::token::PrimLiteral),
+    Meta(crate::syntax_tree::token::MetaLiteral),
+    Error(crate::syntax_tree::token::Span),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -54,6 +59,24 @@ pub enum Expr {
     Universe(
         crate::syntax_tree::token::UniverseLiteral,
     ),
+    U64Lit(
+        crate::syntax_tree::token::U64Literal,
+    ),
+    StringLit(
+        crate::syntax_tree::token::StringLiteral,
+    ),
+    Prim(
+        crate::syntax_tree::token::PrimLiteral,
+    ),
+    Meta(
+        crate::syntax_tree::token::MetaLiteral,
+    ),
+    Hole(
+        crate::syntax_tree::token::ByteIndex,
+    ),
+    Invalid(
+        crate::syntax_tree::token::Span,
+    ),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -61,6 +84,7 @@ pub struct Ind {
     pub lparen: crate::syntax_tree::token::ByteIndex,
     pub type_: crate::syntax_tree::token::UniverseLiteral,
     pub name: crate::syntax_tree::token::StringLiteral,
+    pub tag: Box<OptString>,
     pub index_types_lparen: crate::syntax_tree::token::ByteIndex,
     pub index_types: Box<ZeroOrMoreExprs>,
     pub index_types_rparen: crate::syntax_tree::token::ByteIndex,
@@ -91,6 +115,7 @@ pub enum ZeroOrMoreVconDefs {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct VconDef {
     pub lparen: crate::syntax_tree::token::ByteIndex,
+    pub name: Box<OptString>,
     pub param_types_lparen: crate::syntax_tree::token::ByteIndex,
     pub param_types: Box<ZeroOrMoreExprs>,
     pub param_types_rparen: crate::syntax_tree::token::ByteIndex,
@@ -100,14 +125,32 @@ pub struct VconDef {
     pub rparen: crate::syntax_tree::token::ByteIndex,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OptString {
+    None,
+    Some(
+        crate::syntax_tree::token::StringLiteral,
+    ),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Vcon {
     pub lparen: crate::syntax_tree::token::ByteIndex,
     pub ind: Box<Ind>,
-    pub vcon_index: crate::syntax_tree::token::NumberLiteral,
+    pub vcon_index: Box<VconIndexOrName>,
     pub rparen: crate::syntax_tree::token::ByteIndex,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VconIndexOrName {
+    Index(
+        crate::syntax_tree::token::NumberLiteral,
+    ),
+    Name(
+        crate::syntax_tree::token::StringLiteral,
+    ),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Match {
     pub lparen: crate::syntax_tree::token::ByteIndex,
@@ -133,10 +176,18 @@ pub enum ZeroOrMoreMatchCases {
 pub struct MatchCase {
     pub lparen: crate::syntax_tree::token::ByteIndex,
     pub arity: crate::syntax_tree::token::NumberLiteral,
-    pub return_val: Box<Expr>,
+    pub return_val: Box<OptExpr>,
     pub rparen: crate::syntax_tree::token::ByteIndex,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OptExpr {
+    None,
+    Some(
+        Box<Expr>,
+    ),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Fun {
     pub lparen: crate::syntax_tree::token::ByteIndex,
@@ -226,16 +277,21 @@ enum Quasiterminal {
 enum QuasiterminalKind {
     LParen = 0,
     RParen = 1,
-    IndKw = 2,
-    VconKw = 3,
-    MatchKw = 4,
-    FunKw = 5,
-    ForKw = 6,
-    NonrecKw = 7,
-    Number = 8,
-    String = 9,
-    Universe = 10,
-    Eof = 11,
+    Question = 2,
+    IndKw = 3,
+    VconKw = 4,
+    MatchKw = 5,
+    FunKw = 6,
+    ForKw = 7,
+    NonrecKw = 8,
+    Number = 9,
+    String = 10,
+    Universe = 11,
+    U64 = 12,
+    Prim = 13,
+    Meta = 14,
+    Error = 15,
+    Eof = 16,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -245,14 +301,17 @@ enum NonterminalKind {
     ZeroOrMoreExprs = 2,
     ZeroOrMoreVconDefs = 3,
     VconDef = 4,
-    Vcon = 5,
-    Match = 6,
-    ZeroOrMoreMatchCases = 7,
-    MatchCase = 8,
-    Fun = 9,
-    NumberOrNonrecKw = 10,
-    App = 11,
-    For = 12,
+    OptString = 5,
+    Vcon = 6,
+    VconIndexOrName = 7,
+    Match = 8,
+    ZeroOrMoreMatchCases = 9,
+    MatchCase = 10,
+    OptExpr = 11,
+    Fun = 12,
+    NumberOrNonrecKw = 13,
+    App = 14,
+    For = 15,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -325,6 +384,18 @@ enum State {
     S65 = 65,
     S66 = 66,
     S67 = 67,
+    S68 = 68,
+    S69 = 69,
+    S70 = 70,
+    S71 = 71,
+    S72 = 72,
+    S73 = 73,
+    S74 = 74,
+    S75 = 75,
+    S76 = 76,
+    S77 = 77,
+    S78 = 78,
+    S79 = 79,
 }
 
 enum Node {
@@ -333,16 +404,20 @@ enum Node {
     ZeroOrMoreExprs(ZeroOrMoreExprs),
     ZeroOrMoreVconDefs(ZeroOrMoreVconDefs),
     VconDef(VconDef),
+    OptString(OptString),
     Vcon(Vcon),
+    VconIndexOrName(VconIndexOrName),
     Match(Match),
     ZeroOrMoreMatchCases(ZeroOrMoreMatchCases),
     MatchCase(MatchCase),
+    OptExpr(OptExpr),
     Fun(Fun),
     NumberOrNonrecKw(NumberOrNonrecKw),
     App(App),
     For(For),
     LParen(crate::syntax_tree::token::ByteIndex),
     RParen(crate::syntax_tree::token::ByteIndex),
+    Question(crate::syntax_tree::token::ByteIndex),
     IndKw(crate::syntax_tree::token::ByteIndex),
     VconKw(crate::syntax_tree::token::ByteIndex),
     MatchKw(crate::syntax_tree::token::ByteIndex),
@@ -352,6 +427,10 @@ enum Node {
     Number(crate::syntax_tree::token::NumberLiteral),
     String(crate::syntax_tree::token::StringLiteral),
     Universe(crate::syntax_tree::token::UniverseLiteral),
+    U64(crate::syntax_tree::token::U64Literal),
+    Prim(crate::syntax_tree::token::PrimLiteral),
+    Meta(crate::syntax_tree::token::MetaLiteral),
+    Error(crate::syntax_tree::token::Span),
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -388,382 +467,602 @@ enum RuleKind {
     R21 = 21,
     R22 = 22,
     R23 = 23,
+    R24 = 24,
+    R25 = 25,
+    R26 = 26,
+    R27 = 27,
+    R28 = 28,
+    R29 = 29,
+    R30 = 30,
+    R31 = 31,
+    R32 = 32,
+    R33 = 33,
+    R34 = 34,
+    R35 = 35,
 }
 
 fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
     match rule_kind {
-        RuleKind::R0 => {
-            let t0 = Box::new(Ind::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Ind(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R1 => {
-            let t0 = Box::new(Vcon::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Vcon(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R2 => {
-            let t0 = Box::new(Match::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Match(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R3 => {
-            let t0 = Box::new(Fun::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Fun(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R4 => {
-            let t0 = Box::new(App::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::App(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R5 => {
-            let t0 = Box::new(For::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::For(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R6 => {
-            let t0 = nodes.pop().unwrap().try_into_number_8().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Deb(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R7 => {
-            let t0 = nodes.pop().unwrap().try_into_universe_10().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::Expr(Expr::Universe(
-                    t0,
-                )),
-                NonterminalKind::Expr,
-            )
-        }
-        RuleKind::R8 => {
-            let rparen_10 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let vcon_defs_rparen_9 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let vcon_defs_8 = Box::new(ZeroOrMoreVconDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let vcon_defs_lparen_7 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            let index_types_rparen_6 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let index_types_5 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let index_types_lparen_4 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            let name_3 = nodes.pop().unwrap().try_into_string_9().ok().unwrap();
-            let type__2 = nodes.pop().unwrap().try_into_universe_10().ok().unwrap();
-            nodes.pop().unwrap();
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 11);
-            
-            (
-                Node::Ind(Ind {
-                    lparen: lparen_0,
-                    type_: type__2,
-                    name: name_3,
-                    index_types_lparen: index_types_lparen_4,
-                    index_types: index_types_5,
-                    index_types_rparen: index_types_rparen_6,
-                    vcon_defs_lparen: vcon_defs_lparen_7,
-                    vcon_defs: vcon_defs_8,
-                    vcon_defs_rparen: vcon_defs_rparen_9,
-                    rparen: rparen_10,
-                }),
-                NonterminalKind::Ind,
-            )
-        }
-        RuleKind::R9 => {
-            (
-                Node::ZeroOrMoreExprs(ZeroOrMoreExprs::Nil),
-                NonterminalKind::ZeroOrMoreExprs,
-            )
-        }
-        RuleKind::R10 => {
-            let t1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::ZeroOrMoreExprs(ZeroOrMoreExprs::Snoc(
-                    t0,
-                    t1,
-                )),
-                NonterminalKind::ZeroOrMoreExprs,
-            )
-        }
-        RuleKind::R11 => {
-            (
-                Node::ZeroOrMoreVconDefs(ZeroOrMoreVconDefs::Nil),
-                NonterminalKind::ZeroOrMoreVconDefs,
-            )
-        }
-        RuleKind::R12 => {
-            let t1 = Box::new(VconDef::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(ZeroOrMoreVconDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::ZeroOrMoreVconDefs(ZeroOrMoreVconDefs::Snoc(
-                    t0,
-                    t1,
-                )),
-                NonterminalKind::ZeroOrMoreVconDefs,
-            )
-        }
-        RuleKind::R13 => {
-            let rparen_7 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let index_args_rparen_6 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let index_args_5 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let index_args_lparen_4 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            let param_types_rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let param_types_2 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let param_types_lparen_1 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 8);
-            
-            (
-                Node::VconDef(VconDef {
-                    lparen: lparen_0,
-                    param_types_lparen: param_types_lparen_1,
-                    param_types: param_types_2,
-                    param_types_rparen: param_types_rparen_3,
-                    index_args_lparen: index_args_lparen_4,
-                    index_args: index_args_5,
-                    index_args_rparen: index_args_rparen_6,
-                    rparen: rparen_7,
-                }),
-                NonterminalKind::VconDef,
-            )
-        }
-        RuleKind::R14 => {
-            let rparen_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let vcon_index_3 = nodes.pop().unwrap().try_into_number_8().ok().unwrap();
-            let ind_2 = Box::new(Ind::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 5);
-            
-            (
-                Node::Vcon(Vcon {
-                    lparen: lparen_0,
-                    ind: ind_2,
-                    vcon_index: vcon_index_3,
-                    rparen: rparen_4,
-                }),
-                NonterminalKind::Vcon,
-            )
-        }
-        RuleKind::R15 => {
-            let rparen_8 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let cases_rparen_7 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let cases_6 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let cases_lparen_5 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            let return_type_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let return_type_arity_3 = nodes.pop().unwrap().try_into_number_8().ok().unwrap();
-            let matchee_2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 9);
-            
-            (
-                Node::Match(Match {
-                    lparen: lparen_0,
-                    matchee: matchee_2,
-                    return_type_arity: return_type_arity_3,
-                    return_type: return_type_4,
-                    cases_lparen: cases_lparen_5,
-                    cases: cases_6,
-                    cases_rparen: cases_rparen_7,
-                    rparen: rparen_8,
-                }),
-                NonterminalKind::Match,
-            )
-        }
-        RuleKind::R16 => {
-            (
-                Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Nil),
-                NonterminalKind::ZeroOrMoreMatchCases,
-            )
-        }
-        RuleKind::R17 => {
-            let t1 = Box::new(MatchCase::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
-            
-            states.truncate(states.len() - 2);
-            
-            (
-                Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Snoc(
-                    t0,
-                    t1,
-                )),
-                NonterminalKind::ZeroOrMoreMatchCases,
-            )
-        }
-        RuleKind::R18 => {
-            let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let return_val_2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let arity_1 = nodes.pop().unwrap().try_into_number_8().ok().unwrap();
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::MatchCase(MatchCase {
-                    lparen: lparen_0,
-                    arity: arity_1,
-                    return_val: return_val_2,
-                    rparen: rparen_3,
-                }),
-                NonterminalKind::MatchCase,
-            )
-        }
-        RuleKind::R19 => {
-            let rparen_8 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let return_val_7 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let return_type_6 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let param_types_rparen_5 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let param_types_4 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let param_types_lparen_3 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            let decreasing_index_2 = Box::new(NumberOrNonrecKw::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 9);
-            
-            (
-                Node::Fun(Fun {
-                    lparen: lparen_0,
-                    decreasing_index: decreasing_index_2,
-                    param_types_lparen: param_types_lparen_3,
-                    param_types: param_types_4,
-                    param_types_rparen: param_types_rparen_5,
-                    return_type: return_type_6,
-                    return_val: return_val_7,
-                    rparen: rparen_8,
-                }),
-                NonterminalKind::Fun,
-            )
-        }
-        RuleKind::R20 => {
-            let t0 = nodes.pop().unwrap().try_into_number_8().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::NumberOrNonrecKw(NumberOrNonrecKw::Number(
-                    t0,
-                )),
-                NonterminalKind::NumberOrNonrecKw,
-            )
-        }
-        RuleKind::R21 => {
-            let t0 = nodes.pop().unwrap().try_into_nonrec_kw_7().ok().unwrap();
-            
-            states.truncate(states.len() - 1);
-            
-            (
-                Node::NumberOrNonrecKw(NumberOrNonrecKw::NonrecKw(
-                    t0,
-                )),
-                NonterminalKind::NumberOrNonrecKw,
-            )
-        }
-        RuleKind::R22 => {
-            let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let args_2 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let callee_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 4);
-            
-            (
-                Node::App(App {
-                    lparen: lparen_0,
-                    callee: callee_1,
-                    args: args_2,
-                    rparen: rparen_3,
-                }),
-                NonterminalKind::App,
-            )
-        }
-        RuleKind::R23 => {
-            let rparen_6 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let return_type_5 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let param_types_rparen_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
-            let param_types_3 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let param_types_lparen_2 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            nodes.pop().unwrap();
-            let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
-            
-            states.truncate(states.len() - 7);
-            
-            (
-                Node::For(For {
-                    lparen: lparen_0,
-                    param_types_lparen: param_types_lparen_2,
-                    param_types: param_types_3,
-                    param_types_rparen: param_types_rparen_4,
-                    return_type: return_type_5,
-                    rparen: rparen_6,
-                }),
-                NonterminalKind::For,
-            )
-        }
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+        RuleKind::R6 => reduce_r6(states, nodes),
+        RuleKind::R7 => reduce_r7(states, nodes),
+        RuleKind::R8 => reduce_r8(states, nodes),
+        RuleKind::R9 => reduce_r9(states, nodes),
+        RuleKind::R10 => reduce_r10(states, nodes),
+        RuleKind::R11 => reduce_r11(states, nodes),
+        RuleKind::R12 => reduce_r12(states, nodes),
+        RuleKind::R13 => reduce_r13(states, nodes),
+        RuleKind::R14 => reduce_r14(states, nodes),
+        RuleKind::R15 => reduce_r15(states, nodes),
+        RuleKind::R16 => reduce_r16(states, nodes),
+        RuleKind::R17 => reduce_r17(states, nodes),
+        RuleKind::R18 => reduce_r18(states, nodes),
+        RuleKind::R19 => reduce_r19(states, nodes),
+        RuleKind::R20 => reduce_r20(states, nodes),
+        RuleKind::R21 => reduce_r21(states, nodes),
+        RuleKind::R22 => reduce_r22(states, nodes),
+        RuleKind::R23 => reduce_r23(states, nodes),
+        RuleKind::R24 => reduce_r24(states, nodes),
+        RuleKind::R25 => reduce_r25(states, nodes),
+        RuleKind::R26 => reduce_r26(states, nodes),
+        RuleKind::R27 => reduce_r27(states, nodes),
+        RuleKind::R28 => reduce_r28(states, nodes),
+        RuleKind::R29 => reduce_r29(states, nodes),
+        RuleKind::R30 => reduce_r30(states, nodes),
+        RuleKind::R31 => reduce_r31(states, nodes),
+        RuleKind::R32 => reduce_r32(states, nodes),
+        RuleKind::R33 => reduce_r33(states, nodes),
+        RuleKind::R34 => reduce_r34(states, nodes),
+        RuleKind::R35 => reduce_r35(states, nodes),
     }
 }
 
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Ind::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Ind(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Vcon::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Vcon(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Match::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Match(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Fun::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Fun(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(App::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::App(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(For::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::For(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r6(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_number_9().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Deb(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r7(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_universe_11().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Universe(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r8(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_u64_12().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::U64Lit(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r9(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_string_10().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::StringLit(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r10(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_prim_13().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Prim(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r11(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_meta_14().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Meta(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r12(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_question_2().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Hole(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r13(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_error_15().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Invalid(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r14(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_11 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let vcon_defs_rparen_10 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let vcon_defs_9 = Box::new(ZeroOrMoreVconDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let vcon_defs_lparen_8 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+    let index_types_rparen_7 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let index_types_6 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let index_types_lparen_5 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+    let tag_4 = Box::new(OptString::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_3 = nodes.pop().unwrap().try_into_string_10().ok().unwrap();
+    let type__2 = nodes.pop().unwrap().try_into_universe_11().ok().unwrap();
+    nodes.pop().unwrap();
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 12);
+
+    (
+        Node::Ind(Ind {
+            lparen: lparen_0,
+            type_: type__2,
+            name: name_3,
+            tag: tag_4,
+            index_types_lparen: index_types_lparen_5,
+            index_types: index_types_6,
+            index_types_rparen: index_types_rparen_7,
+            vcon_defs_lparen: vcon_defs_lparen_8,
+            vcon_defs: vcon_defs_9,
+            vcon_defs_rparen: vcon_defs_rparen_10,
+            rparen: rparen_11,
+        }),
+        NonterminalKind::Ind,
+    )
+}
+
+fn reduce_r15(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::ZeroOrMoreExprs(ZeroOrMoreExprs::Nil),
+        NonterminalKind::ZeroOrMoreExprs,
+    )
+}
+
+fn reduce_r16(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let t0 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::ZeroOrMoreExprs(ZeroOrMoreExprs::Snoc(
+            t0,
+            t1,
+        )),
+        NonterminalKind::ZeroOrMoreExprs,
+    )
+}
+
+fn reduce_r17(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::ZeroOrMoreVconDefs(ZeroOrMoreVconDefs::Nil),
+        NonterminalKind::ZeroOrMoreVconDefs,
+    )
+}
+
+fn reduce_r18(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(VconDef::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let t0 = Box::new(ZeroOrMoreVconDefs::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::ZeroOrMoreVconDefs(ZeroOrMoreVconDefs::Snoc(
+            t0,
+            t1,
+        )),
+        NonterminalKind::ZeroOrMoreVconDefs,
+    )
+}
+
+fn reduce_r19(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_8 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let index_args_rparen_7 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let index_args_6 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let index_args_lparen_5 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+    let param_types_rparen_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let param_types_3 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let param_types_lparen_2 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+    let name_1 = Box::new(OptString::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 9);
+
+    (
+        Node::VconDef(VconDef {
+            lparen: lparen_0,
+            name: name_1,
+            param_types_lparen: param_types_lparen_2,
+            param_types: param_types_3,
+            param_types_rparen: param_types_rparen_4,
+            index_args_lparen: index_args_lparen_5,
+            index_args: index_args_6,
+            index_args_rparen: index_args_rparen_7,
+            rparen: rparen_8,
+        }),
+        NonterminalKind::VconDef,
+    )
+}
+
+fn reduce_r20(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptString(OptString::None),
+        NonterminalKind::OptString,
+    )
+}
+
+fn reduce_r21(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_string_10().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptString(OptString::Some(
+            t0,
+        )),
+        NonterminalKind::OptString,
+    )
+}
+
+fn reduce_r22(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let vcon_index_3 = Box::new(VconIndexOrName::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let ind_2 = Box::new(Ind::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::Vcon(Vcon {
+            lparen: lparen_0,
+            ind: ind_2,
+            vcon_index: vcon_index_3,
+            rparen: rparen_4,
+        }),
+        NonterminalKind::Vcon,
+    )
+}
+
+fn reduce_r23(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_number_9().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::VconIndexOrName(VconIndexOrName::Index(
+            t0,
+        )),
+        NonterminalKind::VconIndexOrName,
+    )
+}
+
+fn reduce_r24(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_string_10().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::VconIndexOrName(VconIndexOrName::Name(
+            t0,
+        )),
+        NonterminalKind::VconIndexOrName,
+    )
+}
+
+fn reduce_r25(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_8 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let cases_rparen_7 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let cases_6 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let cases_lparen_5 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+    let return_type_4 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let return_type_arity_3 = nodes.pop().unwrap().try_into_number_9().ok().unwrap();
+    let matchee_2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 9);
+
+    (
+        Node::Match(Match {
+            lparen: lparen_0,
+            matchee: matchee_2,
+            return_type_arity: return_type_arity_3,
+            return_type: return_type_4,
+            cases_lparen: cases_lparen_5,
+            cases: cases_6,
+            cases_rparen: cases_rparen_7,
+            rparen: rparen_8,
+        }),
+        NonterminalKind::Match,
+    )
+}
+
+fn reduce_r26(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Nil),
+        NonterminalKind::ZeroOrMoreMatchCases,
+    )
+}
+
+fn reduce_r27(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(MatchCase::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let t0 = Box::new(ZeroOrMoreMatchCases::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::ZeroOrMoreMatchCases(ZeroOrMoreMatchCases::Snoc(
+            t0,
+            t1,
+        )),
+        NonterminalKind::ZeroOrMoreMatchCases,
+    )
+}
+
+fn reduce_r28(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let return_val_2 = Box::new(OptExpr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let arity_1 = nodes.pop().unwrap().try_into_number_9().ok().unwrap();
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::MatchCase(MatchCase {
+            lparen: lparen_0,
+            arity: arity_1,
+            return_val: return_val_2,
+            rparen: rparen_3,
+        }),
+        NonterminalKind::MatchCase,
+    )
+}
+
+fn reduce_r29(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::OptExpr(OptExpr::None),
+        NonterminalKind::OptExpr,
+    )
+}
+
+fn reduce_r30(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::OptExpr(OptExpr::Some(
+            t0,
+        )),
+        NonterminalKind::OptExpr,
+    )
+}
+
+fn reduce_r31(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_8 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let return_val_7 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let return_type_6 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let param_types_rparen_5 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let param_types_4 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let param_types_lparen_3 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+    let decreasing_index_2 = Box::new(NumberOrNonrecKw::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 9);
+
+    (
+        Node::Fun(Fun {
+            lparen: lparen_0,
+            decreasing_index: decreasing_index_2,
+            param_types_lparen: param_types_lparen_3,
+            param_types: param_types_4,
+            param_types_rparen: param_types_rparen_5,
+            return_type: return_type_6,
+            return_val: return_val_7,
+            rparen: rparen_8,
+        }),
+        NonterminalKind::Fun,
+    )
+}
+
+fn reduce_r32(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_number_9().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::NumberOrNonrecKw(NumberOrNonrecKw::Number(
+            t0,
+        )),
+        NonterminalKind::NumberOrNonrecKw,
+    )
+}
+
+fn reduce_r33(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_nonrec_kw_8().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::NumberOrNonrecKw(NumberOrNonrecKw::NonrecKw(
+            t0,
+        )),
+        NonterminalKind::NumberOrNonrecKw,
+    )
+}
+
+fn reduce_r34(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_3 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let args_2 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let callee_1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::App(App {
+            lparen: lparen_0,
+            callee: callee_1,
+            args: args_2,
+            rparen: rparen_3,
+        }),
+        NonterminalKind::App,
+    )
+}
+
+fn reduce_r35(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let rparen_6 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let return_type_5 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let param_types_rparen_4 = nodes.pop().unwrap().try_into_r_paren_1().ok().unwrap();
+    let param_types_3 = Box::new(ZeroOrMoreExprs::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let param_types_lparen_2 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+    nodes.pop().unwrap();
+    let lparen_0 = nodes.pop().unwrap().try_into_l_paren_0().ok().unwrap();
+
+    states.truncate(states.len() - 7);
+
+    (
+        Node::For(For {
+            lparen: lparen_0,
+            param_types_lparen: param_types_lparen_2,
+            param_types: param_types_3,
+            param_types_rparen: param_types_rparen_4,
+            return_type: return_type_5,
+            rparen: rparen_6,
+        }),
+        NonterminalKind::For,
+    )
+}
+
 impl QuasiterminalKind {
     fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
         match quasiterminal {
@@ -776,6 +1075,7 @@ impl QuasiterminalKind {
         match terminal {
             Token::LParen(_) => Self::LParen,
             Token::RParen(_) => Self::RParen,
+            Token::Question(_) => Self::Question,
             Token::IndKw(_) => Self::IndKw,
             Token::VconKw(_) => Self::VconKw,
             Token::MatchKw(_) => Self::MatchKw,
@@ -785,6 +1085,10 @@ impl QuasiterminalKind {
             Token::Number(_) => Self::Number,
             Token::String(_) => Self::String,
             Token::Universe(_) => Self::Universe,
+            Token::U64(_) => Self::U64,
+            Token::Prim(_) => Self::Prim,
+            Token::Meta(_) => Self::Meta,
+            Token::Error(_) => Self::Error,
         }
     }
 }
@@ -794,6 +1098,7 @@ impl Node {
         match terminal {
             Token::LParen(t) => Self::LParen(t),
             Token::RParen(t) => Self::RParen(t),
+            Token::Question(t) => Self::Question(t),
             Token::IndKw(t) => Self::IndKw(t),
             Token::VconKw(t) => Self::VconKw(t),
             Token::MatchKw(t) => Self::MatchKw(t),
@@ -803,6 +1108,10 @@ impl Node {
             Token::Number(t) => Self::Number(t),
             Token::String(t) => Self::String(t),
             Token::Universe(t) => Self::Universe(t),
+            Token::U64(t) => Self::U64(t),
+            Token::Prim(t) => Self::Prim(t),
+            Token::Meta(t) => Self::Meta(t),
+            Token::Error(t) => Self::Error(t),
         }
     }
 }
@@ -816,23 +1125,29 @@ impl Quasiterminal {
     }
 }
 
-const ACTION_TABLE: [[Action; 12]; 68] = [
+static ACTION_TABLE: [[Action; 17]; 80] = [
     [
         Action::Shift(State::S0),
         Action::Err,
-        Action::Shift(State::S22),
-        Action::Shift(State::S31),
+        Action::Shift(State::S26),
+        Action::Shift(State::S28),
+        Action::Shift(State::S38),
         Action::Shift(State::S10),
-        Action::Shift(State::S57),
-        Action::Shift(State::S64),
+        Action::Shift(State::S69),
+        Action::Shift(State::S76),
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
+        Action::Shift(State::S33),
         Action::Shift(State::S26),
         Action::Err,
         Action::Err,
@@ -841,13 +1156,18 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
-        Action::Shift(State::S41),
+        Action::Shift(State::S49),
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -855,13 +1175,18 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
-        Action::Shift(State::S42),
+        Action::Shift(State::S50),
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -869,13 +1194,18 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
         Action::Shift(State::S7),
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -883,13 +1213,18 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
-        Action::Shift(State::S63),
+        Action::Shift(State::S75),
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -897,13 +1232,18 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
         Action::Shift(State::S13),
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -911,13 +1251,18 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
         Action::Err,
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -925,41 +1270,37 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
-        Action::Shift(State::S0),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S20),
         Action::Err,
-        Action::Shift(State::S21),
         Action::Err,
-    ],
-    [
         Action::Reduce(RuleKind::R0),
         Action::Reduce(RuleKind::R0),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
         Action::Reduce(RuleKind::R0),
-        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
         Action::Reduce(RuleKind::R0),
         Action::Reduce(RuleKind::R0),
     ],
     [
         Action::Shift(State::S0),
         Action::Err,
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -967,13 +1308,18 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
         Action::Err,
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -981,13 +1327,37 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
+        Action::Shift(State::S23),
+        Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S0),
+        Action::Reduce(RuleKind::R29),
+        Action::Shift(State::S26),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
+        Action::Shift(State::S20),
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
         Action::Err,
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -995,13 +1365,18 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
         Action::Err,
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1009,13 +1384,18 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
         Action::Shift(State::S0),
         Action::Err,
+        Action::Shift(State::S26),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1023,11 +1403,16 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Shift(State::S20),
-        Action::Err,
+        Action::Shift(State::S23),
         Action::Shift(State::S21),
+        Action::Shift(State::S22),
+        Action::Shift(State::S24),
+        Action::Shift(State::S25),
+        Action::Shift(State::S27),
         Action::Err,
     ],
     [
+        Action::Reduce(RuleKind::R1),
         Action::Reduce(RuleKind::R1),
         Action::Reduce(RuleKind::R1),
         Action::Err,
@@ -1037,11 +1422,16 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Reduce(RuleKind::R1),
-        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
         Action::Reduce(RuleKind::R1),
         Action::Reduce(RuleKind::R1),
     ],
     [
+        Action::Reduce(RuleKind::R2),
         Action::Reduce(RuleKind::R2),
         Action::Reduce(RuleKind::R2),
         Action::Err,
@@ -1051,11 +1441,16 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Reduce(RuleKind::R2),
-        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
         Action::Reduce(RuleKind::R2),
         Action::Reduce(RuleKind::R2),
     ],
     [
+        Action::Reduce(RuleKind::R3),
         Action::Reduce(RuleKind::R3),
         Action::Reduce(RuleKind::R3),
         Action::Err,
@@ -1065,11 +1460,16 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Reduce(RuleKind::R3),
-        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
         Action::Reduce(RuleKind::R3),
         Action::Reduce(RuleKind::R3),
     ],
     [
+        Action::Reduce(RuleKind::R4),
         Action::Reduce(RuleKind::R4),
         Action::Reduce(RuleKind::R4),
         Action::Err,
@@ -1079,11 +1479,16 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Reduce(RuleKind::R4),
-        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
         Action::Reduce(RuleKind::R4),
         Action::Reduce(RuleKind::R4),
     ],
     [
+        Action::Reduce(RuleKind::R5),
         Action::Reduce(RuleKind::R5),
         Action::Reduce(RuleKind::R5),
         Action::Err,
@@ -1093,11 +1498,16 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Reduce(RuleKind::R5),
-        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
         Action::Reduce(RuleKind::R5),
         Action::Reduce(RuleKind::R5),
     ],
     [
+        Action::Reduce(RuleKind::R6),
         Action::Reduce(RuleKind::R6),
         Action::Reduce(RuleKind::R6),
         Action::Err,
@@ -1107,11 +1517,16 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Reduce(RuleKind::R6),
-        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
         Action::Reduce(RuleKind::R6),
         Action::Reduce(RuleKind::R6),
     ],
     [
+        Action::Reduce(RuleKind::R7),
         Action::Reduce(RuleKind::R7),
         Action::Reduce(RuleKind::R7),
         Action::Err,
@@ -1121,83 +1536,151 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Reduce(RuleKind::R7),
-        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
         Action::Reduce(RuleKind::R7),
         Action::Reduce(RuleKind::R7),
     ],
     [
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+    ],
+    [
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S23),
         Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
     ],
     [
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+    ],
+    [
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S24),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
     ],
     [
-        Action::Shift(State::S25),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+    ],
+    [
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
     ],
     [
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-    ],
-    [
-        Action::Shift(State::S27),
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S29),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1205,13 +1688,17 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S30),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
-        Action::Shift(State::S40),
-        Action::Shift(State::S29),
+        Action::Reduce(RuleKind::R20),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1220,12 +1707,22 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S52),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
-        Action::Shift(State::S30),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1238,21 +1735,26 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
     ],
     [
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
     ],
     [
-        Action::Shift(State::S32),
+        Action::Shift(State::S34),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1264,108 +1766,90 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
         Action::Err,
-        Action::Shift(State::S22),
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
     ],
     [
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
+        Action::Shift(State::S47),
+        Action::Shift(State::S36),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-        Action::Reduce(RuleKind::R9),
-        Action::Err,
-    ],
-    [
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
     ],
     [
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
         Action::Err,
+        Action::Shift(State::S37),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-    ],
-    [
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
-        Action::Reduce(RuleKind::R9),
         Action::Err,
     ],
     [
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Err,
-        Action::Err,
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R10),
         Action::Err,
-        Action::Reduce(RuleKind::R10),
         Action::Err,
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
+        Action::Reduce(RuleKind::R14),
     ],
     [
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
+        Action::Shift(State::S39),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1376,23 +1860,20 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Shift(State::S33),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S28),
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Shift(State::S34),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1406,91 +1887,164 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
     ],
     [
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
-        Action::Shift(State::S43),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Reduce(RuleKind::R13),
-        Action::Reduce(RuleKind::R13),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
     ],
     [
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S45),
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
+        Action::Reduce(RuleKind::R15),
         Action::Err,
     ],
     [
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
         Action::Err,
-        Action::Shift(State::S46),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Reduce(RuleKind::R16),
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R18),
+        Action::Reduce(RuleKind::R18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R14),
         Action::Err,
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
     ],
     [
+        Action::Reduce(RuleKind::R20),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S52),
+        Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S8),
         Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
-        Action::Shift(State::S49),
+        Action::Shift(State::S40),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1504,8 +2058,13 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
     ],
     [
-        Action::Reduce(RuleKind::R16),
-        Action::Reduce(RuleKind::R16),
+        Action::Shift(State::S41),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1518,7 +2077,7 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
     ],
     [
-        Action::Shift(State::S54),
+        Action::Err,
         Action::Shift(State::S51),
         Action::Err,
         Action::Err,
@@ -1530,10 +2089,20 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
     ],
     [
+        Action::Reduce(RuleKind::R19),
+        Action::Reduce(RuleKind::R19),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
-        Action::Shift(State::S52),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1546,22 +2115,13 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
     ],
     [
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
-        Action::Err,
+        Action::Reduce(RuleKind::R21),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R15),
         Action::Err,
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
-    ],
-    [
-        Action::Reduce(RuleKind::R17),
-        Action::Reduce(RuleKind::R17),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1582,14 +2142,19 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S11),
+        Action::Err,
+        Action::Shift(State::S56),
+        Action::Shift(State::S57),
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
     ],
     [
         Action::Err,
-        Action::Shift(State::S56),
+        Action::Shift(State::S55),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1600,52 +2165,58 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Reduce(RuleKind::R18),
-        Action::Reduce(RuleKind::R18),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
     ],
     [
         Action::Err,
+        Action::Reduce(RuleKind::R23),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Shift(State::S62),
-        Action::Shift(State::S61),
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Shift(State::S35),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
         Action::Err,
+        Action::Reduce(RuleKind::R24),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
         Action::Err,
-        Action::Shift(State::S60),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1658,25 +2229,16 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
     ],
     [
-        Action::Reduce(RuleKind::R19),
-        Action::Reduce(RuleKind::R19),
-        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R19),
-        Action::Err,
-        Action::Reduce(RuleKind::R19),
-        Action::Reduce(RuleKind::R19),
-    ],
-    [
-        Action::Reduce(RuleKind::R20),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
+        Action::Shift(State::S9),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1686,9 +2248,7 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
     ],
     [
-        Action::Reduce(RuleKind::R21),
-        Action::Err,
-        Action::Err,
+        Action::Shift(State::S60),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1698,23 +2258,21 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Err,
-    ],
-    [
-        Action::Reduce(RuleKind::R22),
-        Action::Reduce(RuleKind::R22),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R22),
         Action::Err,
-        Action::Reduce(RuleKind::R22),
-        Action::Reduce(RuleKind::R22),
     ],
     [
-        Action::Shift(State::S37),
+        Action::Reduce(RuleKind::R26),
+        Action::Reduce(RuleKind::R26),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1728,8 +2286,13 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
     ],
     [
+        Action::Shift(State::S65),
+        Action::Shift(State::S62),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
         Action::Err,
-        Action::Shift(State::S66),
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1742,20 +2305,14 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
     ],
     [
-        Action::Reduce(RuleKind::R23),
-        Action::Reduce(RuleKind::R23),
         Action::Err,
+        Action::Shift(State::S63),
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
         Action::Err,
-        Action::Reduce(RuleKind::R23),
         Action::Err,
-        Action::Reduce(RuleKind::R23),
-        Action::Reduce(RuleKind::R23),
-    ],
-    [
         Action::Err,
         Action::Err,
         Action::Err,
@@ -1765,241 +2322,822 @@ const ACTION_TABLE: [[Action; 12]; 68] = [
         Action::Err,
         Action::Err,
         Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
         Action::Err,
         Action::Err,
-        Action::Accept,
-    ],
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+    ],
+    [
+        Action::Reduce(RuleKind::R27),
+        Action::Reduce(RuleKind::R27),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S67),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R28),
+        Action::Reduce(RuleKind::R28),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R30),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S74),
+        Action::Shift(State::S73),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S42),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S72),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+    ],
+    [
+        Action::Reduce(RuleKind::R32),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R33),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
+    ],
+    [
+        Action::Shift(State::S44),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S78),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
 ];
 
 fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
     ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
 }
 
-const GOTO_TABLE: [[Option<State>; 13]; 68] = [
+static GOTO_TABLE: [[Option<State>; 16]; 80] = [
+    [
+        Some(State::S43),
+        Some(State::S8),
+        None,
+        None,
+        None,
+        None,
+        Some(State::S15),
+        None,
+        Some(State::S16),
+        None,
+        None,
+        None,
+        Some(State::S17),
+        None,
+        Some(State::S18),
+        Some(State::S19),
+    ],
+    [
+        Some(State::S45),
+        Some(State::S8),
+        None,
+        None,
+        None,
+        None,
+        Some(State::S15),
+        None,
+        Some(State::S16),
+        None,
+        None,
+        None,
+        Some(State::S17),
+        None,
+        Some(State::S18),
+        Some(State::S19),
+    ],
     [
-        Some(State::S36),
-        Some(State::S9),
+        Some(State::S45),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        None,
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
-        Some(State::S38),
-        Some(State::S9),
+        Some(State::S45),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        None,
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
-        Some(State::S38),
-        Some(State::S9),
+        Some(State::S45),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        None,
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
-        Some(State::S38),
-        Some(State::S9),
+        Some(State::S45),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        None,
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
-        Some(State::S38),
-        Some(State::S9),
+        Some(State::S45),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        None,
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
-        Some(State::S38),
-        Some(State::S9),
+        Some(State::S12),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        None,
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
-        Some(State::S38),
-        Some(State::S9),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        Some(State::S59),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        None,
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
-        Some(State::S12),
-        Some(State::S9),
+        Some(State::S58),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        None,
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
-        Some(State::S48),
-        Some(State::S9),
+        Some(State::S68),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        Some(State::S66),
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
+        Some(State::S71),
+        Some(State::S8),
         None,
         None,
         None,
         None,
+        Some(State::S15),
         None,
+        Some(State::S16),
         None,
         None,
         None,
+        Some(State::S17),
         None,
-        None,
-        None,
-        None,
-        None,
+        Some(State::S18),
+        Some(State::S19),
     ],
     [
-        Some(State::S47),
-        Some(State::S9),
+        Some(State::S77),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        None,
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
-        Some(State::S55),
-        Some(State::S9),
+        Some(State::S79),
+        Some(State::S8),
+        None,
         None,
         None,
         None,
         Some(State::S15),
+        None,
         Some(State::S16),
         None,
         None,
+        None,
         Some(State::S17),
         None,
         Some(State::S18),
         Some(State::S19),
     ],
     [
-        Some(State::S59),
-        Some(State::S9),
         None,
         None,
         None,
-        Some(State::S15),
-        Some(State::S16),
         None,
         None,
-        Some(State::S17),
         None,
-        Some(State::S18),
-        Some(State::S19),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Some(State::S65),
-        Some(State::S9),
         None,
         None,
         None,
-        Some(State::S15),
-        Some(State::S16),
         None,
         None,
-        Some(State::S17),
         None,
-        Some(State::S18),
-        Some(State::S19),
-    ],
-    [
-        Some(State::S67),
-        Some(State::S9),
         None,
         None,
         None,
-        Some(State::S15),
-        Some(State::S16),
         None,
         None,
-        Some(State::S17),
         None,
-        Some(State::S18),
-        Some(State::S19),
+        None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2015,6 +3153,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2030,6 +3171,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2045,6 +3189,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2052,6 +3199,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        Some(State::S31),
+        None,
+        None,
         None,
         None,
         None,
@@ -2075,8 +3225,14 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
+        None,
+        None,
+        Some(State::S1),
         None,
         None,
         None,
@@ -2105,11 +3261,17 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
         None,
         None,
+        Some(State::S35),
+        None,
+        None,
         None,
         None,
         None,
@@ -2126,6 +3288,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        Some(State::S46),
+        None,
+        None,
         None,
         None,
         None,
@@ -2150,11 +3315,17 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
         None,
-        Some(State::S1),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -2167,6 +3338,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
     ],
     [
+        None,
+        Some(State::S53),
+        None,
         None,
         None,
         None,
@@ -2185,7 +3359,10 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
-        Some(State::S28),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -2197,11 +3374,14 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
     ],
     [
+        None,
+        None,
+        Some(State::S2),
+        None,
         None,
         None,
         None,
         None,
-        Some(State::S39),
         None,
         None,
         None,
@@ -2212,6 +3392,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
     ],
     [
+        None,
+        None,
+        Some(State::S3),
         None,
         None,
         None,
@@ -2227,6 +3410,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
     ],
     [
+        None,
+        None,
+        Some(State::S4),
         None,
         None,
         None,
@@ -2243,7 +3429,10 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
     ],
     [
         None,
-        Some(State::S44),
+        None,
+        Some(State::S5),
+        None,
+        None,
         None,
         None,
         None,
@@ -2257,6 +3446,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
     ],
     [
+        None,
+        None,
+        Some(State::S6),
         None,
         None,
         None,
@@ -2274,7 +3466,10 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
     [
         None,
         None,
-        Some(State::S2),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -2289,7 +3484,10 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
     [
         None,
         None,
-        Some(State::S3),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -2304,7 +3502,10 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
     [
         None,
         None,
-        Some(State::S4),
+        None,
+        None,
+        None,
+        Some(State::S48),
         None,
         None,
         None,
@@ -2319,7 +3520,10 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
     [
         None,
         None,
-        Some(State::S5),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -2334,7 +3538,10 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
     [
         None,
         None,
-        Some(State::S6),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -2360,6 +3567,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2375,6 +3585,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2390,6 +3603,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2399,6 +3615,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        Some(State::S54),
+        None,
+        None,
         None,
         None,
         None,
@@ -2420,6 +3639,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2435,6 +3657,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2450,6 +3675,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2465,6 +3693,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2480,6 +3711,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2495,6 +3729,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2506,6 +3743,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        Some(State::S61),
+        None,
+        None,
         None,
         None,
         None,
@@ -2519,7 +3759,10 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
-        Some(State::S50),
+        None,
+        None,
+        None,
+        Some(State::S64),
         None,
         None,
         None,
@@ -2535,7 +3778,10 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
-        Some(State::S53),
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -2555,6 +3801,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2570,6 +3819,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2585,6 +3837,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2600,6 +3855,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2615,6 +3873,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2630,6 +3891,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2642,7 +3906,10 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
-        Some(State::S58),
+        None,
+        None,
+        None,
+        Some(State::S70),
         None,
         None,
     ],
@@ -2660,6 +3927,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2675,6 +3945,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2690,6 +3963,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2705,6 +3981,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2720,6 +3999,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2735,6 +4017,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2750,6 +4035,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2765,6 +4053,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2780,6 +4071,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2795,6 +4089,9 @@ const GOTO_TABLE: [[Option<State>; 13]; 68] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
     ],
 ];
 
@@ -2857,6 +4154,17 @@ impl TryFrom<Node> for VconDef {
     }
 }
 
+impl TryFrom<Node> for OptString {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptString(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl TryFrom<Node> for Vcon {
     type Error = Node;
 
@@ -2868,6 +4176,17 @@ impl TryFrom<Node> for Vcon {
     }
 }
 
+impl TryFrom<Node> for VconIndexOrName {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::VconIndexOrName(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl TryFrom<Node> for Match {
     type Error = Node;
 
@@ -2901,6 +4220,17 @@ impl TryFrom<Node> for MatchCase {
     }
 }
 
+impl TryFrom<Node> for OptExpr {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptExpr(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl TryFrom<Node> for Fun {
     type Error = Node;
 
@@ -2952,74 +4282,109 @@ impl Node {
             _ => Err(self),
         }
     }
-    
+
     fn try_into_r_paren_1(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
         match self {
             Self::RParen(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_ind_kw_2(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
+
+    fn try_into_question_2(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
+        match self {
+            Self::Question(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_ind_kw_3(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
         match self {
             Self::IndKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_vcon_kw_3(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
+
+    fn try_into_vcon_kw_4(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
         match self {
             Self::VconKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_match_kw_4(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
+
+    fn try_into_match_kw_5(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
         match self {
             Self::MatchKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_fun_kw_5(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
+
+    fn try_into_fun_kw_6(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
         match self {
             Self::FunKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_for_kw_6(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
+
+    fn try_into_for_kw_7(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
         match self {
             Self::ForKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_nonrec_kw_7(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
+
+    fn try_into_nonrec_kw_8(self) -> Result<crate::syntax_tree::token::ByteIndex, Self> {
         match self {
             Self::NonrecKw(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_number_8(self) -> Result<crate::syntax_tree::token::NumberLiteral, Self> {
+
+    fn try_into_number_9(self) -> Result<crate::syntax_tree::token::NumberLiteral, Self> {
         match self {
             Self::Number(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_string_9(self) -> Result<crate::syntax_tree::token::StringLiteral, Self> {
+
+    fn try_into_string_10(self) -> Result<crate::syntax_tree::token::StringLiteral, Self> {
         match self {
             Self::String(t) => Ok(t),
             _ => Err(self),
         }
     }
-    
-    fn try_into_universe_10(self) -> Result<crate::syntax_tree::token::UniverseLiteral, Self> {
+
+    fn try_into_universe_11(self) -> Result<crate::syntax_tree::token::UniverseLiteral, Self> {
         match self {
             Self::Universe(t) => Ok(t),
             _ => Err(self),
         }
     }
+
+    fn try_into_u64_12(self) -> Result<crate::syntax_tree::token::U64Literal, Self> {
+        match self {
+            Self::U64(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_prim_13(self) -> Result<crate::syntax_tree::token::PrimLiteral, Self> {
+        match self {
+            Self::Prim(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_meta_14(self) -> Result<crate::syntax_tree::token::MetaLiteral, Self> {
+        match self {
+            Self::Meta(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_error_15(self) -> Result<crate::syntax_tree::token::Span, Self> {
+        match self {
+            Self::Error(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
 }
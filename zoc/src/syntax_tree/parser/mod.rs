@@ -1,5 +1,9 @@
+#[rustfmt::skip]
 mod generated_parser;
 pub use generated_parser::*;
 
+mod recover;
+pub use recover::*;
+
 #[cfg(test)]
 mod tests;
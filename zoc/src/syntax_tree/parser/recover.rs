@@ -0,0 +1,254 @@
+//! Error recovery for the generated parser.
+//!
+//! The generated parser stops at the first token it cannot accept.
+//! To report more than one error, we repeatedly parse,
+//! and after each failure, we replace the offending code with
+//! a `Token::Error`, which the grammar accepts as an expression
+//! (`Expr::Invalid`).
+//!
+//! We resynchronize on balanced parentheses:
+//! - If the offending token is an atom, we replace just that token.
+//! - If the offending token is a `(`, we replace its whole group.
+//! - Otherwise (i.e., the offending token is a `)`, an error token,
+//!   or the end of the input), the problem lies with the enclosing group,
+//!   so we replace the innermost unclosed group.
+//!
+//! Each step either shortens the token list
+//! or replaces a non-error token with an error token,
+//! so the loop always terminates.
+
+use super::*;
+
+use crate::syntax_tree::{
+    lexer::{lex_with_recovery, LexError},
+    token::*,
+};
+
+/// A token the parser could not accept.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    /// The token the parser choked on,
+    /// or `None` if the parser reached the end of the input.
+    pub unexpected: Option<Token>,
+    /// The tokens the parser would have accepted instead,
+    /// in `TokenKind` order.
+    /// `None` means the end of the input.
+    ///
+    /// This never includes `TokenKind::Error`.
+    pub expected: Vec<Option<TokenKind>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SyntaxError {
+    Lex(LexError),
+    Parse(ParseError),
+}
+
+impl SyntaxError {
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            SyntaxError::Lex(err) => Some(err.span()),
+            SyntaxError::Parse(err) => err.unexpected.as_ref().map(Token::span),
+        }
+    }
+}
+
+/// Lexes and parses `src`, reporting every syntax error
+/// instead of just the first one.
+///
+/// Unlexable or unparsable code appears in the returned CST
+/// as `Expr::Invalid` nodes.
+/// If there are no errors, the CST is the same as
+/// the one `parse(lex(src))` would return.
+///
+/// The errors are returned in source order.
+pub fn parse_src_with_recovery(src: &str) -> (Expr, Vec<SyntaxError>) {
+    let (tokens, _, lex_errors) = lex_with_recovery(src);
+    let (cst, parse_errors) = parse_with_recovery(tokens);
+
+    let mut errors: Vec<SyntaxError> = lex_errors
+        .into_iter()
+        .map(SyntaxError::Lex)
+        .chain(parse_errors.into_iter().map(SyntaxError::Parse))
+        .collect();
+    // Errors at the end of the input (which have no span)
+    // go last.
+    errors.sort_by_key(|err| err.span().map_or(ByteIndex(src.len()), |span| span.0));
+    (cst, errors)
+}
+
+/// Like `parse`, but instead of stopping at the first error,
+/// this replaces the offending code with `Expr::Invalid` nodes
+/// and keeps going.
+///
+/// Errors caused by `Token::Error`s that are already in `tokens`
+/// (e.g., from `lex_with_recovery`) are not reported,
+/// since whoever produced those tokens already reported them.
+pub fn parse_with_recovery(mut tokens: Vec<Token>) -> (Expr, Vec<ParseError>) {
+    let mut errors = vec![];
+
+    loop {
+        let unexpected = match parse(tokens.iter().cloned()) {
+            Ok(cst) => return (cst, errors),
+            Err(unexpected) => unexpected,
+        };
+
+        let index = match &unexpected {
+            Some(unexpected) => tokens
+                .iter()
+                .position(|token| token == unexpected)
+                .expect("the unexpected token should be one of the input tokens"),
+            None => tokens.len(),
+        };
+
+        if !matches!(unexpected, Some(Token::Error(_))) {
+            errors.push(ParseError {
+                expected: get_expected_tokens(&tokens[..index]),
+                unexpected,
+            });
+        }
+
+        recover(&mut tokens, index);
+    }
+}
+
+/// Returns the tokens (or end of input) that can follow `prefix`.
+///
+/// The generated parser does not expose its tables,
+/// so we probe it: an LR parser never shifts a token
+/// that cannot continue a valid input,
+/// so a candidate is expected if and only if
+/// the parser does not reject it.
+fn get_expected_tokens(prefix: &[Token]) -> Vec<Option<TokenKind>> {
+    let start = prefix.last().map_or(ByteIndex(0), |token| token.span().1);
+    let mut expected: Vec<Option<TokenKind>> = TokenKind::ALL
+        .into_iter()
+        .filter(|kind| *kind != TokenKind::Error)
+        .filter(|kind| {
+            let candidate = sample_token(*kind, start);
+            !matches!(
+                parse(prefix.iter().cloned().chain([candidate])),
+                Err(Some(_))
+            )
+        })
+        .map(Some)
+        .collect();
+
+    if parse(prefix.iter().cloned()).is_ok() {
+        expected.push(None);
+    }
+
+    expected
+}
+
+fn sample_token(kind: TokenKind, start: ByteIndex) -> Token {
+    let span = (start, start);
+    match kind {
+        TokenKind::LParen => Token::LParen(start),
+        TokenKind::RParen => Token::RParen(start),
+        TokenKind::Question => Token::Question(start),
+        TokenKind::IndKw => Token::IndKw(start),
+        TokenKind::VconKw => Token::VconKw(start),
+        TokenKind::MatchKw => Token::MatchKw(start),
+        TokenKind::FunKw => Token::FunKw(start),
+        TokenKind::ForKw => Token::ForKw(start),
+        TokenKind::NonrecKw => Token::NonrecKw(start),
+        TokenKind::Number => Token::Number(NumberLiteral { value: 0, span }),
+        TokenKind::String => Token::String(StringLiteral {
+            value: String::new(),
+            span,
+        }),
+        TokenKind::Universe => Token::Universe(UniverseLiteral {
+            level: 0,
+            span,
+            erasable: false,
+        }),
+        TokenKind::U64 => Token::U64(U64Literal { value: 0, span }),
+        TokenKind::Prim => Token::Prim(PrimLiteral {
+            prim: crate::syntax_tree::ast::Prim::U64,
+            span,
+        }),
+        TokenKind::Meta => Token::Meta(MetaLiteral { id: 0, span }),
+        TokenKind::Error => Token::Error(span),
+    }
+}
+
+/// Replaces the code responsible for the error at `tokens[index]`
+/// (or at the end of the input, if `index == tokens.len()`)
+/// with a single `Token::Error`.
+fn recover(tokens: &mut Vec<Token>, index: usize) {
+    match tokens.get(index) {
+        Some(Token::LParen(_)) => {
+            let end = get_group_end(tokens, index);
+            replace_with_error(tokens, index, end);
+        }
+
+        Some(Token::RParen(_)) | Some(Token::Error(_)) | None => {
+            if let Some(start) = get_innermost_unclosed_lparen(&tokens[..index]) {
+                let end = get_group_end(tokens, start);
+                replace_with_error(tokens, start, end);
+            } else if index > 0 {
+                // The tokens before `index` form a complete expression,
+                // so everything after it is extraneous.
+                tokens.truncate(index);
+            } else {
+                replace_with_error(tokens, 0, tokens.len());
+            }
+        }
+
+        Some(_) => replace_with_error(tokens, index, index + 1),
+    }
+}
+
+/// Returns the (exclusive) end of the group that starts with
+/// the `(` at `tokens[start]`.
+/// If the group is never closed, this returns `tokens.len()`.
+fn get_group_end(tokens: &[Token], start: usize) -> usize {
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::LParen(_) => depth += 1,
+            Token::RParen(_) => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens.len()
+}
+
+fn get_innermost_unclosed_lparen(tokens: &[Token]) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate().rev() {
+        match token {
+            Token::RParen(_) => depth += 1,
+            Token::LParen(_) => {
+                if depth == 0 {
+                    return Some(i);
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Replaces `tokens[start..end]` with a single `Token::Error`
+/// spanning them.
+/// If the range is empty, the error token is empty
+/// and placed at the end of the preceding token.
+fn replace_with_error(tokens: &mut Vec<Token>, start: usize, end: usize) {
+    let span = if start < end {
+        (tokens[start].span().0, tokens[end - 1].span().1)
+    } else {
+        let index = start
+            .checked_sub(1)
+            .map_or(ByteIndex(0), |i| tokens[i].span().1);
+        (index, index)
+    };
+    tokens.splice(start..end, [Token::Error(span)]);
+}
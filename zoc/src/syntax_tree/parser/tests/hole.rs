@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn hole() {
+    let src = r#"?"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
+
+#[test]
+fn hole_app() {
+    let src = r#"(0 ? ?)"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
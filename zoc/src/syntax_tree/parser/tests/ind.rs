@@ -71,3 +71,33 @@ Set0
     let err = parse(tokens).unwrap_err();
     insta::assert_debug_snapshot!(&err);
 }
+
+#[test]
+fn ind_with_vcon_names() {
+    let src = r#"
+(
+    ind
+
+    Set0
+
+    "Nat"
+
+    ()
+
+    (
+        ("zero" () ())
+        ("succ" (0) ())
+    )
+)"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
+
+#[test]
+fn ind_with_tag() {
+    let src = r#"(ind Set0 "Meters" "0xabc" () ((() ())))"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
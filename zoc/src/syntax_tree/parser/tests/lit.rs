@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn u64_lit() {
+    let src = r#"42u64"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
+
+#[test]
+fn string_lit() {
+    let src = r#""hello {0x22}world{0x22}""#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
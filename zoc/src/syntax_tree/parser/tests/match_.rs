@@ -77,3 +77,11 @@ fn non_parenthesized_cases() {
     let err = parse(tokens).unwrap_err();
     insta::assert_debug_snapshot!(&err);
 }
+
+#[test]
+fn omitted_case() {
+    let src = r#"(match 0 1 U64 ((0 1u64) (2)))"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
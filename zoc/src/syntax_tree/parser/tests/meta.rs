@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn meta() {
+    let src = r#"_3"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
+
+#[test]
+fn meta_app() {
+    let src = r#"(_0 1 0)"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
@@ -4,7 +4,12 @@ mod app;
 mod deb;
 mod for_;
 mod fun;
+mod hole;
 mod ind;
+mod lit;
 mod match_;
+mod meta;
+mod prim;
+mod recover;
 mod universe;
 mod vcon;
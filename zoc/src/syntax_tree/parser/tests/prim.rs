@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn u64_type() {
+    let src = r#"U64"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
+
+#[test]
+fn u64_add_app() {
+    let src = r#"(u64_add 2u64 3u64)"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
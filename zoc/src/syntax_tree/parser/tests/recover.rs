@@ -0,0 +1,99 @@
+use super::*;
+
+use crate::syntax_tree::{lexer::LexErrorKind, token::*};
+
+fn expected(kinds: &[TokenKind]) -> Vec<Option<TokenKind>> {
+    kinds.iter().copied().map(Some).collect()
+}
+
+#[test]
+fn well_formed_src_has_same_cst_as_parse() {
+    let src = r#"(fun nonrec (U64) U64 (u64_add 0 1u64))"#;
+    let (cst, errors) = parse_src_with_recovery(src);
+    assert_eq!(Vec::<SyntaxError>::new(), errors);
+    assert_eq!(parse(lex(src).unwrap()).unwrap(), cst);
+}
+
+#[test]
+fn every_malformed_group_is_reported() {
+    let src = r#"(u64_add (fun) 1u64 (for U64))"#;
+    let (cst, errors) = parse_src_with_recovery(src);
+    insta::assert_debug_snapshot!((cst, errors));
+}
+
+#[test]
+fn expected_tokens_after_fun_kw() {
+    let src = r#"(fun U64)"#;
+    let (_, errors) = parse_with_recovery(lex(src).unwrap());
+    assert_eq!(
+        vec![ParseError {
+            unexpected: Some(Token::Prim(PrimLiteral {
+                prim: crate::syntax_tree::ast::Prim::U64,
+                span: (ByteIndex(5), ByteIndex(8)),
+            })),
+            expected: expected(&[TokenKind::NonrecKw, TokenKind::Number]),
+        }],
+        errors
+    );
+}
+
+#[test]
+fn unclosed_paren() {
+    let src = r#"(u64_add 1u64"#;
+    let (cst, errors) = parse_src_with_recovery(src);
+    assert_eq!(Expr::Invalid((ByteIndex(0), ByteIndex(src.len()))), cst);
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn extraneous_token_after_expr() {
+    let src = r#"1 2"#;
+    let (cst, errors) = parse_with_recovery(lex(src).unwrap());
+    assert_eq!(parse(lex("1").unwrap()).unwrap(), cst);
+    assert_eq!(
+        vec![ParseError {
+            unexpected: Some(Token::Number(NumberLiteral {
+                value: 2,
+                span: (ByteIndex(2), ByteIndex(3)),
+            })),
+            expected: vec![None],
+        }],
+        errors
+    );
+}
+
+#[test]
+fn empty_src() {
+    let (cst, errors) = parse_src_with_recovery("");
+    assert_eq!(Expr::Invalid((ByteIndex(0), ByteIndex(0))), cst);
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn lex_errors_become_invalid_exprs() {
+    let src = r#"(u64_add lambda # "{0xZZ}" 1u64)"#;
+    let (cst, errors) = parse_src_with_recovery(src);
+    let kinds: Vec<LexErrorKind> = errors
+        .iter()
+        .map(|err| match err {
+            SyntaxError::Lex(err) => err.kind().clone(),
+            SyntaxError::Parse(err) => panic!("unexpected parse error {err:?}"),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            LexErrorKind::UnrecognizedWord("lambda".to_string()),
+            LexErrorKind::UnexpectedChar('#'),
+            LexErrorKind::InvalidEscapeSequence,
+        ],
+        kinds
+    );
+    insta::assert_debug_snapshot!(cst);
+}
+
+#[test]
+fn unterminated_string() {
+    let src = r#"(u64_add "abc 1u64)"#;
+    let (_, errors) = parse_src_with_recovery(src);
+    insta::assert_debug_snapshot!(errors);
+}
@@ -73,3 +73,18 @@ vcon
     let err = parse(tokens).unwrap_err();
     insta::assert_debug_snapshot!(&err);
 }
+
+#[test]
+fn vcon_by_name() {
+    let src = r#"
+(
+vcon
+
+(ind Set0 "Nat" () (("zero" () ()) ("succ" (0) ())))
+
+"succ"
+)"#;
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    insta::assert_debug_snapshot!(&cst);
+}
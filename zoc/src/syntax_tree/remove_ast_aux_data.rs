@@ -8,12 +8,26 @@ pub struct AuxDataRemover {
     fun_cache: NoHashHashMap<Digest, RcHashed<minimal_ast::Fun>>,
     app_cache: NoHashHashMap<Digest, RcHashed<minimal_ast::App>>,
     for_cache: NoHashHashMap<Digest, RcHashed<minimal_ast::For>>,
+    interner: Interner,
 }
 
 impl AuxDataRemover {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Creates an `AuxDataRemover` whose output nodes
+    /// are interned with `interner`.
+    pub fn with_interner(interner: Interner) -> Self {
+        Self {
+            interner,
+            ..Default::default()
+        }
+    }
+
+    pub fn interner(&self) -> &Interner {
+        &self.interner
+    }
 }
 
 impl AuxDataRemover {
@@ -27,6 +41,10 @@ impl AuxDataRemover {
             ast::Expr::For(e) => self.convert_for(e).into(),
             ast::Expr::Deb(e) => self.convert_deb_node(&e.hashee).into(),
             ast::Expr::Universe(e) => self.convert_universe_node(&e.hashee).into(),
+            ast::Expr::Lit(e) => self.convert_lit_node(&e.hashee).into(),
+            ast::Expr::Prim(e) => self.convert_prim_node(&e.hashee).into(),
+            ast::Expr::Meta(e) => self.convert_meta_node(&e.hashee).into(),
+            ast::Expr::Hole(e) => self.convert_hole_node(&e.hashee).into(),
         }
     }
 
@@ -55,13 +73,15 @@ impl AuxDataRemover {
         &mut self,
         original: RcHashed<ast::Ind<A>>,
     ) -> RcHashed<minimal_ast::Ind> {
-        rc_hashed(minimal_ast::Ind {
+        let ind = minimal_ast::Ind {
             name: original.hashee.name.clone(),
+            tag: original.hashee.tag.clone(),
             universe: original.hashee.universe,
             index_types: self.convert_expressions(&original.hashee.index_types.hashee),
             vcon_defs: self.convert_vcon_defs(original.hashee.vcon_defs.clone()),
             aux_data: (),
-        })
+        };
+        self.interner.intern(ind)
     }
 
     pub fn convert_vcon_defs<A: AuxDataFamily>(
@@ -74,7 +94,7 @@ impl AuxDataRemover {
             .cloned()
             .map(|def| self.convert_vcon_def(def))
             .collect();
-        rc_hashed(v)
+        self.interner.intern(v)
     }
 
     pub fn convert_vcon_def<A: AuxDataFamily>(
@@ -82,6 +102,7 @@ impl AuxDataRemover {
         original: ast::VconDef<A>,
     ) -> minimal_ast::VconDef {
         minimal_ast::VconDef {
+            name: original.name.clone(),
             param_types: self.convert_expressions(&original.param_types.hashee),
             index_args: self.convert_expressions(&original.index_args.hashee),
             aux_data: (),
@@ -113,11 +134,12 @@ impl AuxDataRemover {
         &mut self,
         original: RcHashed<ast::Vcon<A>>,
     ) -> RcHashed<minimal_ast::Vcon> {
-        rc_hashed(minimal_ast::Vcon {
+        let vcon = minimal_ast::Vcon {
             ind: self.convert_ind(original.hashee.ind.clone()),
             vcon_index: original.hashee.vcon_index,
             aux_data: (),
-        })
+        };
+        self.interner.intern(vcon)
     }
 
     pub fn convert_match<A: AuxDataFamily>(
@@ -145,13 +167,14 @@ impl AuxDataRemover {
         &mut self,
         original: RcHashed<ast::Match<A>>,
     ) -> RcHashed<minimal_ast::Match> {
-        rc_hashed(minimal_ast::Match {
+        let match_ = minimal_ast::Match {
             matchee: self.convert(original.hashee.matchee.clone()),
             return_type_arity: original.hashee.return_type_arity,
             return_type: self.convert(original.hashee.return_type.clone()),
             cases: self.convert_match_cases(original.hashee.cases.clone()),
             aux_data: (),
-        })
+        };
+        self.interner.intern(match_)
     }
 
     fn convert_match_cases<A: AuxDataFamily>(
@@ -164,7 +187,7 @@ impl AuxDataRemover {
             .cloned()
             .map(|case| self.convert_match_case(case))
             .collect();
-        rc_hashed(v)
+        self.interner.intern(v)
     }
 
     pub fn convert_match_case<A: AuxDataFamily>(
@@ -173,7 +196,9 @@ impl AuxDataRemover {
     ) -> minimal_ast::MatchCase {
         minimal_ast::MatchCase {
             arity: original.arity,
-            return_val: self.convert(original.return_val),
+            return_val: original
+                .return_val
+                .map(|return_val| self.convert(return_val)),
             aux_data: (),
         }
     }
@@ -203,13 +228,14 @@ impl AuxDataRemover {
         &mut self,
         original: RcHashed<ast::Fun<A>>,
     ) -> RcHashed<minimal_ast::Fun> {
-        rc_hashed(minimal_ast::Fun {
+        let fun = minimal_ast::Fun {
             decreasing_index: original.hashee.decreasing_index,
             param_types: self.convert_expressions(&original.hashee.param_types.hashee),
             return_type: self.convert(original.hashee.return_type.clone()),
             return_val: self.convert(original.hashee.return_val.clone()),
             aux_data: (),
-        })
+        };
+        self.interner.intern(fun)
     }
 
     pub fn convert_app<A: AuxDataFamily>(
@@ -237,11 +263,12 @@ impl AuxDataRemover {
         &mut self,
         original: RcHashed<ast::App<A>>,
     ) -> RcHashed<minimal_ast::App> {
-        rc_hashed(minimal_ast::App {
+        let app = minimal_ast::App {
             callee: self.convert(original.hashee.callee.clone()),
             args: self.convert_expressions(&original.hashee.args.hashee),
             aux_data: (),
-        })
+        };
+        self.interner.intern(app)
     }
 
     pub fn convert_for<A: AuxDataFamily>(
@@ -269,11 +296,12 @@ impl AuxDataRemover {
         &mut self,
         original: RcHashed<ast::For<A>>,
     ) -> RcHashed<minimal_ast::For> {
-        rc_hashed(minimal_ast::For {
+        let for_ = minimal_ast::For {
             param_types: self.convert_expressions(&original.hashee.param_types.hashee),
             return_type: self.convert(original.hashee.return_type.clone()),
             aux_data: (),
-        })
+        };
+        self.interner.intern(for_)
     }
 
     pub fn convert_expressions<A: AuxDataFamily>(
@@ -284,7 +312,7 @@ impl AuxDataRemover {
             .into_iter()
             .map(|expr| self.convert(expr.clone()))
             .collect();
-        rc_hashed(v)
+        self.interner.intern(v)
     }
 
     pub fn convert_deb_node<A: AuxDataFamily>(
@@ -296,7 +324,7 @@ impl AuxDataRemover {
         // so we take a normal reference instead of a `RcHashed`.
         original: &ast::DebNode<A>,
     ) -> RcHashed<minimal_ast::DebNode> {
-        rc_hashed(minimal_ast::DebNode {
+        self.interner.intern(minimal_ast::DebNode {
             deb: original.deb,
             aux_data: (),
         })
@@ -311,9 +339,64 @@ impl AuxDataRemover {
         // so we take a normal reference instead of a `RcHashed`.
         original: &ast::UniverseNode<A>,
     ) -> RcHashed<minimal_ast::UniverseNode> {
-        rc_hashed(minimal_ast::UniverseNode {
+        self.interner.intern(minimal_ast::UniverseNode {
             universe: original.universe,
             aux_data: (),
         })
     }
+
+    pub fn convert_lit_node<A: AuxDataFamily>(
+        &mut self,
+        // Since literals are leaf nodes,
+        // caching the conversion result
+        // will not save much time.
+        // Therefore, we do not need the digest,
+        // so we take a normal reference instead of a `RcHashed`.
+        original: &ast::LitNode<A>,
+    ) -> RcHashed<minimal_ast::LitNode> {
+        self.interner.intern(minimal_ast::LitNode {
+            lit: original.lit.clone(),
+            aux_data: (),
+        })
+    }
+
+    pub fn convert_prim_node<A: AuxDataFamily>(
+        &mut self,
+        // Since prims are leaf nodes,
+        // caching the conversion result
+        // will not save much time.
+        // Therefore, we do not need the digest,
+        // so we take a normal reference instead of a `RcHashed`.
+        original: &ast::PrimNode<A>,
+    ) -> RcHashed<minimal_ast::PrimNode> {
+        self.interner.intern(minimal_ast::PrimNode {
+            prim: original.prim,
+            aux_data: (),
+        })
+    }
+
+    pub fn convert_meta_node<A: AuxDataFamily>(
+        &mut self,
+        // Since metas are leaf nodes,
+        // caching the conversion result
+        // will not save much time.
+        // Therefore, we do not need the digest,
+        // so we take a normal reference instead of a `RcHashed`.
+        original: &ast::MetaNode<A>,
+    ) -> RcHashed<minimal_ast::MetaNode> {
+        self.interner.intern(minimal_ast::MetaNode {
+            id: original.id,
+            aux_data: (),
+        })
+    }
+
+    pub fn convert_hole_node<A: AuxDataFamily>(
+        &mut self,
+        // Holes have no data other than their aux data,
+        // so we only take the node for symmetry with
+        // the other `convert_*` methods.
+        _: &ast::HoleNode<A>,
+    ) -> RcHashed<minimal_ast::HoleNode> {
+        self.interner.intern(minimal_ast::HoleNode { aux_data: () })
+    }
 }
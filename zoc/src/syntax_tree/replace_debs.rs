@@ -5,10 +5,16 @@
 
 use crate::syntax_tree::ast::prelude::minimal_ast::*;
 
-use std::{hash::Hash, rc::Rc};
+use std::{cell::Cell, hash::Hash};
 
 pub trait DebReplacer {
     fn replace_deb(&self, original: RcHashed<DebNode>, cutoff: usize) -> Expr;
+
+    /// Most replacers leave metavariables untouched,
+    /// so this defaults to the identity.
+    fn replace_meta(&self, original: RcHashed<MetaNode>, _cutoff: usize) -> Expr {
+        Expr::Meta(original)
+    }
 }
 
 /// Replaces `0` with the last element of in `new_exprs`,
@@ -56,6 +62,48 @@ impl DebReplacer for DebUpshifter {
     }
 }
 
+/// Replaces `deb` with `new_expr`,
+/// leaving all other debs untouched.
+/// Both `deb` and `new_expr` are relative to cutoff `0`.
+pub struct DebSubstituter<'a> {
+    pub deb: Deb,
+    pub new_expr: &'a Expr,
+}
+
+impl DebReplacer for DebSubstituter<'_> {
+    fn replace_deb(&self, original: RcHashed<DebNode>, cutoff: usize) -> Expr {
+        if original.hashee.deb.0 != self.deb.0 + cutoff {
+            return Expr::Deb(original);
+        }
+
+        self.new_expr.clone().replace_debs(&DebUpshifter(cutoff), 0)
+    }
+}
+
+/// Returns whether `deb` (relative to cutoff `0`) is free in `expr`.
+pub fn contains_deb(expr: &Expr, deb: Deb) -> bool {
+    let finder = DebFinder {
+        target: deb,
+        found: Cell::new(false),
+    };
+    let _ = expr.clone().replace_debs(&finder, 0);
+    finder.found.get()
+}
+
+struct DebFinder {
+    target: Deb,
+    found: Cell<bool>,
+}
+
+impl DebReplacer for DebFinder {
+    fn replace_deb(&self, original: RcHashed<DebNode>, cutoff: usize) -> Expr {
+        if original.hashee.deb.0 == self.target.0 + cutoff {
+            self.found.set(true);
+        }
+        Expr::Deb(original)
+    }
+}
+
 pub trait ReplaceDebs {
     type Output;
 
@@ -153,7 +201,8 @@ impl ReplaceDebs for Expr {
             Expr::App(o) => Expr::App(o.replace_debs(replacer, cutoff)),
             Expr::For(o) => Expr::For(o.replace_debs(replacer, cutoff)),
             Expr::Deb(o) => replacer.replace_deb(o, cutoff),
-            Expr::Universe(_) => self,
+            Expr::Meta(o) => replacer.replace_meta(o, cutoff),
+            Expr::Universe(_) | Expr::Lit(_) | Expr::Prim(_) | Expr::Hole(_) => self,
         }
     }
 }
@@ -165,6 +214,7 @@ impl ReplaceDebs for RcHashed<Ind> {
         let original = &self.hashee;
         Rc::new(Hashed::new(Ind {
             name: original.name.clone(),
+            tag: original.tag.clone(),
             universe: original.universe,
             index_types: original
                 .index_types
@@ -184,6 +234,7 @@ impl ReplaceDebs for VconDef {
 
     fn replace_debs<R: DebReplacer>(self, replacer: &R, cutoff: usize) -> Self::Output {
         VconDef {
+            name: self.name.clone(),
             param_types: self
                 .param_types
                 .clone()
@@ -237,7 +288,9 @@ impl ReplaceDebs for MatchCase {
     fn replace_debs<R: DebReplacer>(self, replacer: &R, cutoff: usize) -> Self::Output {
         MatchCase {
             arity: self.arity,
-            return_val: self.return_val.replace_debs(replacer, cutoff + self.arity),
+            return_val: self
+                .return_val
+                .map(|return_val| return_val.replace_debs(replacer, cutoff + self.arity)),
             aux_data: self.aux_data.clone(),
         }
     }
@@ -1,3 +1,5 @@
+use crate::syntax_tree::ast::Prim;
+
 use std::ops::{Add, Sub};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
@@ -33,6 +35,24 @@ pub struct StringLiteral {
     pub span: Span,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct U64Literal {
+    pub value: u64,
+    pub span: Span,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PrimLiteral {
+    pub prim: Prim,
+    pub span: Span,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct MetaLiteral {
+    pub id: usize,
+    pub span: Span,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
 pub struct UniverseLiteral {
     pub level: usize,
@@ -42,4 +62,126 @@ pub struct UniverseLiteral {
     pub erasable: bool,
 }
 
+/// A `//` comment.
+/// Comments are not tokens, since the parser ignores them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Comment {
+    /// The comment's source, including the leading `//`,
+    /// but excluding trailing whitespace.
+    pub text: String,
+    pub span: Span,
+}
+
 pub use crate::syntax_tree::parser::Token;
+
+impl Token {
+    pub fn span(&self) -> Span {
+        match self {
+            Token::LParen(start) | Token::RParen(start) | Token::Question(start) => {
+                (*start, ByteIndex(start.0 + 1))
+            }
+            Token::IndKw(start) => (*start, ByteIndex(start.0 + "ind".len())),
+            Token::VconKw(start) => (*start, ByteIndex(start.0 + "vcon".len())),
+            Token::MatchKw(start) => (*start, ByteIndex(start.0 + "match".len())),
+            Token::FunKw(start) => (*start, ByteIndex(start.0 + "fun".len())),
+            Token::ForKw(start) => (*start, ByteIndex(start.0 + "for".len())),
+            Token::NonrecKw(start) => (*start, ByteIndex(start.0 + "nonrec".len())),
+            Token::Number(lit) => lit.span,
+            Token::String(lit) => lit.span,
+            Token::Universe(lit) => lit.span,
+            Token::U64(lit) => lit.span,
+            Token::Prim(lit) => lit.span,
+            Token::Meta(lit) => lit.span,
+            Token::Error(span) => *span,
+        }
+    }
+
+    pub fn kind(&self) -> TokenKind {
+        match self {
+            Token::LParen(_) => TokenKind::LParen,
+            Token::RParen(_) => TokenKind::RParen,
+            Token::Question(_) => TokenKind::Question,
+            Token::IndKw(_) => TokenKind::IndKw,
+            Token::VconKw(_) => TokenKind::VconKw,
+            Token::MatchKw(_) => TokenKind::MatchKw,
+            Token::FunKw(_) => TokenKind::FunKw,
+            Token::ForKw(_) => TokenKind::ForKw,
+            Token::NonrecKw(_) => TokenKind::NonrecKw,
+            Token::Number(_) => TokenKind::Number,
+            Token::String(_) => TokenKind::String,
+            Token::Universe(_) => TokenKind::Universe,
+            Token::U64(_) => TokenKind::U64,
+            Token::Prim(_) => TokenKind::Prim,
+            Token::Meta(_) => TokenKind::Meta,
+            Token::Error(_) => TokenKind::Error,
+        }
+    }
+}
+
+/// A `Token` without its data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TokenKind {
+    LParen,
+    RParen,
+    Question,
+    IndKw,
+    VconKw,
+    MatchKw,
+    FunKw,
+    ForKw,
+    NonrecKw,
+    Number,
+    String,
+    Universe,
+    U64,
+    Prim,
+    Meta,
+    /// Stands for source code that could not be lexed or parsed.
+    /// The lexer and the parser's error recovery emit these,
+    /// so the parser can accept the rest of the input.
+    Error,
+}
+
+impl TokenKind {
+    pub const ALL: [TokenKind; 16] = [
+        TokenKind::LParen,
+        TokenKind::RParen,
+        TokenKind::Question,
+        TokenKind::IndKw,
+        TokenKind::VconKw,
+        TokenKind::MatchKw,
+        TokenKind::FunKw,
+        TokenKind::ForKw,
+        TokenKind::NonrecKw,
+        TokenKind::Number,
+        TokenKind::String,
+        TokenKind::Universe,
+        TokenKind::U64,
+        TokenKind::Prim,
+        TokenKind::Meta,
+        TokenKind::Error,
+    ];
+
+    /// Returns a human-readable description
+    /// (e.g., `"`(`"` or `"a number"`).
+    pub fn description(self) -> &'static str {
+        match self {
+            TokenKind::LParen => "`(`",
+            TokenKind::RParen => "`)`",
+            TokenKind::Question => "`?`",
+            TokenKind::IndKw => "`ind`",
+            TokenKind::VconKw => "`vcon`",
+            TokenKind::MatchKw => "`match`",
+            TokenKind::FunKw => "`fun`",
+            TokenKind::ForKw => "`for`",
+            TokenKind::NonrecKw => "`nonrec`",
+            TokenKind::Number => "a number",
+            TokenKind::String => "a string literal",
+            TokenKind::Universe => "a universe",
+            TokenKind::U64 => "a u64 literal",
+            TokenKind::Prim => "a primitive",
+            TokenKind::Meta => "a metavariable",
+            TokenKind::Error => "an invalid token",
+        }
+    }
+}
@@ -0,0 +1,338 @@
+//! Well-typed example programs.
+//!
+//! `typecheck/tests/should_succeed.rs` checks that they typecheck,
+//! and the tests of later passes (e.g., the exporters) run on them,
+//! so every pass is tested on the same examples.
+
+use super::substitute_with_compounding;
+
+pub const NAT_DEF: (&str, &str) = (
+    "<NAT>",
+    r#"(ind Set0 "Nat" () (
+    (() ())
+    ((0) ())
+))"#,
+);
+pub const ZERO_DEF: (&str, &str) = ("<0>", "(vcon <NAT> 0)");
+pub const SUCC_DEF: (&str, &str) = ("<SUCC>", "(vcon <NAT> 1)");
+pub const ONE_DEF: (&str, &str) = ("<1>", "(<SUCC> <0>)");
+pub const TWO_DEF: (&str, &str) = ("<2>", "(<SUCC> <1>)");
+pub const THREE_DEF: (&str, &str) = ("<3>", "(<SUCC> <2>)");
+
+pub const FALSE_DEF: (&str, &str) = ("<FALSE>", r#"(ind Prop0 "False" () ())"#);
+pub const UNIT_DEF: (&str, &str) = ("<UNIT>", r#"(ind Prop0 "Unit" () ((() ())))"#);
+pub const UNITC_DEF: (&str, &str) = ("<UNITC>", "(vcon <UNIT> 0)");
+
+pub const BOOL_DEF: (&str, &str) = (
+    "<BOOL>",
+    r#"(ind Set0 "Bool" () (
+    (() ())
+    (() ())
+))"#,
+);
+pub const EQ_BOOL_DEF: (&str, &str) = (
+    "<EQ>",
+    r#"(ind Prop0 "Eq" (<BOOL> <BOOL>) (
+    ((<BOOL>) (0 0))
+))"#,
+);
+
+pub const LIST_DEFS: [(&str, &str); 4] = [
+    (
+        "<LIST_0>",
+        r#"(ind Set0 "List" () (
+    // DB index stack is
+    // 0 =>  List(T)
+    // 1 => List
+    // 2 => T
+
+    // nil
+    (() ())
+
+    // cons
+    ((
+        2
+
+        // DB index stack is
+        // 0 => car
+        // 1 => List(T)
+        // 2 => List
+        // 3 => T
+        1
+    ) ())
+))"#,
+    ),
+    ("<POLYMORPHIC_LIST>", "(fun nonrec (Set0) Set0 <LIST_0>)"),
+    (
+        "<POLYMORPHIC_NIL>",
+        "(fun nonrec (Set0) (<POLYMORPHIC_LIST> 0) (vcon <LIST_0> 0))",
+    ),
+    (
+        "<POLYMORPHIC_CONS>",
+        "(fun nonrec (Set0) (for (0 (<POLYMORPHIC_LIST> 1)) (<POLYMORPHIC_LIST> 2)) (vcon <LIST_0> 1))",
+    ),
+];
+pub const NAT_LIST_DEFS: [(&str, &str); 3] = [
+    ("<NAT_NIL>", "(<POLYMORPHIC_NIL> <NAT>)"),
+    ("<NAT_CONS>", "(<POLYMORPHIC_CONS> <NAT>)"),
+    (
+        "<123>",
+        "(<NAT_CONS> <1> (<NAT_CONS> <2> (<NAT_CONS> <3> <NAT_NIL>)))",
+    ),
+];
+
+pub const ADD_DEF: (&str, &str) = (
+    "<ADD>",
+    "(fun 0 (<NAT> <NAT>) <NAT>
+    (match 2 1 <NAT> (
+        (0 1)
+        (1 (<SUCC> (1 0 2)))
+    ))
+)",
+);
+pub const EQ_NAT_DEFS: [(&str, &str); 2] = [
+    (
+        "<EQ>",
+        r#"(fun nonrec (<NAT> <NAT>) Prop0
+    ((ind Prop0 "Eq" (<NAT>) ((() (3)))) 1)
+)"#,
+    ),
+    (
+        "<REFL>",
+        r#"(fun nonrec (<NAT>) (<EQ> 0 0)
+    (vcon (ind Prop0 "Eq" (<NAT>) ((() (2)))) 0)
+)"#,
+    ),
+];
+
+pub fn nat_defs() -> Vec<(&'static str, &'static str)> {
+    vec![NAT_DEF, ZERO_DEF, SUCC_DEF, ONE_DEF, TWO_DEF, THREE_DEF]
+}
+
+pub fn add_2_3_src() -> String {
+    let add_tailcall_impl_def = (
+        "<ADD_TAILCALL_IMPL>",
+        "(fun 0 (<NAT> <NAT>) <NAT>
+    (match 2 1 <NAT> (
+        (0 1)
+        (1 (1 0 (<SUCC> 2)))
+    ))
+)",
+    );
+    let defs = nat_defs().into_iter().chain([add_tailcall_impl_def]);
+    substitute_with_compounding(defs, "(<ADD_TAILCALL_IMPL> <2> <3>)")
+}
+
+pub fn rev_1_2_3_src() -> String {
+    let rev_def = (
+        "<REV>",
+        "(fun 0
+    (
+        (<POLYMORPHIC_LIST> <NAT>) // reversee
+        (<POLYMORPHIC_LIST> <NAT>) // out
+    )
+    (<POLYMORPHIC_LIST> <NAT>)
+    (match 2 1 (<POLYMORPHIC_LIST> <NAT>) (
+        (0 1)
+        (2
+            // DB index stack
+            // 0 => reversee.cdr
+            // 1 => reversee.car
+            // 2 => rev
+            // 3 => out
+            // 4 => reversee
+            (2 0 (<NAT_CONS> 1 3))
+        )
+    ))
+)",
+    );
+    let defs = nat_defs()
+        .into_iter()
+        .chain(LIST_DEFS)
+        .chain(NAT_LIST_DEFS)
+        .chain([rev_def]);
+    substitute_with_compounding(defs, "(<REV> <123> <NAT_NIL>)")
+}
+
+pub fn polymorphic_rev_1_2_3_src() -> String {
+    let rev_def = (
+        "<POLYMORPHIC_REV>",
+        "(fun 1
+    (
+        Set0 // T
+        (<POLYMORPHIC_LIST> 0) // reversee
+        (<POLYMORPHIC_LIST> 1) // out
+    )
+    (<POLYMORPHIC_LIST> 2)
+    (match 2 1 (<POLYMORPHIC_LIST> 4) (
+        (0 1)
+        (2
+            // DB index stack
+            // 0 => reversee.cdr
+            // 1 => reversee.car
+            // 2 => rev
+            // 3 => out
+            // 4 => reversee
+            // 5 => T
+            (2 5 0 ((<POLYMORPHIC_CONS> 5) 1 3))
+        )
+    ))
+)",
+    );
+    let defs = nat_defs()
+        .into_iter()
+        .chain(LIST_DEFS)
+        .chain(NAT_LIST_DEFS)
+        .chain([rev_def]);
+    substitute_with_compounding(defs, "(<POLYMORPHIC_REV> <NAT> <123> <NAT_NIL>)")
+}
+
+pub fn ex_falso_src() -> String {
+    substitute_with_compounding(
+        [NAT_DEF, FALSE_DEF],
+        "(fun nonrec (<FALSE>) <NAT> (match 1 1 <NAT> ()))",
+    )
+}
+
+pub fn eq_zero_one_src() -> String {
+    let eq_zero_def = ("<EQ_0>", r#"(ind Prop0 "Eq0" (<NAT>) ((() (<0>))))"#);
+    let is_zero_predicate_def = (
+        "<IS_ZERO_PREDICATE>",
+        "(fun nonrec (<NAT>) Prop0
+    (match 1 1 Prop0 (
+        (0 <UNIT>)
+        (1 <FALSE>)
+    ))
+)",
+    );
+    let defs = nat_defs().into_iter().chain([
+        eq_zero_def,
+        FALSE_DEF,
+        UNIT_DEF,
+        UNITC_DEF,
+        is_zero_predicate_def,
+    ]);
+    substitute_with_compounding(
+        defs,
+        "(fun nonrec ((<EQ_0> <1>)) <FALSE>
+    (match 1 2 (<IS_ZERO_PREDICATE> 1) (
+        (0 <UNITC>)
+    ))
+)",
+    )
+}
+
+pub fn eq_one_zero_src() -> String {
+    let eq_one_def = ("<EQ_1>", r#"(ind Prop0 "Eq1" (<NAT>) ((() (<1>))))"#);
+    let is_one_predicate_def = (
+        "<IS_ONE_PREDICATE>",
+        "(fun nonrec (<NAT>) Prop0
+    (match 1 1 Prop0 (
+        (0 <FALSE>)
+        (1 (match 0 1 Prop0 (
+            (0 <UNIT>)
+            (1 <FALSE>)
+        )))
+    ))
+)",
+    );
+    let defs = nat_defs().into_iter().chain([
+        eq_one_def,
+        FALSE_DEF,
+        UNIT_DEF,
+        UNITC_DEF,
+        is_one_predicate_def,
+    ]);
+    substitute_with_compounding(
+        defs,
+        "(fun nonrec ((<EQ_1> <0>)) <FALSE>
+    (match 1 2 (<IS_ONE_PREDICATE> 1) (
+        (0 <UNITC>)
+    ))
+)",
+    )
+}
+
+pub fn eq_commutative_src() -> String {
+    substitute_with_compounding(
+        [BOOL_DEF, EQ_BOOL_DEF],
+        "(fun nonrec (<BOOL> <BOOL> (<EQ> 1 0)) (<EQ> 1 2)
+    (match 1 3 (<EQ> 1 2) (
+        (1 ((vcon <EQ> 0) 0))
+    ))
+)",
+    )
+}
+
+pub fn eq_transitive_src() -> String {
+    substitute_with_compounding(
+        [BOOL_DEF, EQ_BOOL_DEF],
+        "(fun nonrec (<BOOL> <BOOL> <BOOL> (<EQ> 2 1) (<EQ> 2 1)) (<EQ> 4 2)
+    (
+        (match 2 3 (for ((<EQ> 1 6)) (<EQ> 3 7)) (
+            (1 (fun nonrec ((<EQ> 0 4)) (<EQ> 1 5) 1))
+        ))
+        1
+    )
+)",
+    )
+}
+
+/// An ind whose vcon index args must be typechecked
+/// against its index types substituted with the earlier index args.
+pub fn precise_src() -> String {
+    r#"(ind Set1 "Precise" (Set0 0) (((Set0 0) (1 0))))"#.to_string()
+}
+
+pub fn add_zero_src() -> String {
+    let defs = nat_defs().into_iter().chain([ADD_DEF]).chain(EQ_NAT_DEFS);
+    substitute_with_compounding(
+        defs,
+        "(fun 0 (<NAT>) (<EQ> 0 (<ADD> 0 <0>))
+    (match 1 1 (<EQ> 0 (<ADD> 0 <0>)) (
+        // `zero` case
+        (0 (<REFL> <0>))
+
+        // `(succ pred)` case
+        //   [goal: (EQ (SUCC 0) (ADD (SUCC 0) ZERO))]
+        //   [goal: (EQ (SUCC 0) (SUCC (ADD 0 ZERO)))]
+        //   [(1 0): (EQ 0 (ADD 0 ZERO))]
+        (1 (match (1 0) 2 (<EQ> (<SUCC> 2) (<SUCC> 1)) (
+            (0 (<REFL> (<SUCC> 0)))
+        )))
+    ))
+)",
+    )
+}
+
+pub fn add_succ_src() -> String {
+    let defs = nat_defs().into_iter().chain([ADD_DEF]).chain(EQ_NAT_DEFS);
+    substitute_with_compounding(
+        defs,
+        "(fun 0 (<NAT> <NAT>) (<EQ> (<ADD> 1 (<SUCC> 0)) (<SUCC> (<ADD> 1 0)))
+    (match 2 1 (<EQ> (<ADD> 0 (<SUCC> 2)) (<SUCC> (<ADD> 0 2))) (
+        // `zero` case
+        (0 (<REFL> (<SUCC> 1)))
+
+        // `(succ pred)` case
+        //   [goal: (EQ (ADD (SUCC 0) (SUCC 2)) (SUCC (ADD (SUCC 0) 2)))]
+        //   [goal: (EQ (SUCC (ADD 0 (SUCC 2))) (SUCC (SUCC (ADD 0 2))))]
+        //   [(1 0 2): (EQ (ADD 0 (SUCC 2)) (SUCC (ADD 0 2)))]
+        (1 (match (1 0 2) 2 (<EQ> (<SUCC> (<ADD> 2 (<SUCC> 4))) (<SUCC> 1)) (
+            (0 (<REFL> (<SUCC> (<ADD> 0 (<SUCC> 2)))))
+        )))
+    ))
+)",
+    )
+}
+
+/// An ind whose vcon def param type applies another ind
+/// to the recursive ind deb.
+pub fn mutual_src() -> String {
+    let mutual_def = (
+        "<MUTUAL>",
+        r#"(ind Set0 "Mutual" () ((((<POLYMORPHIC_LIST> 0) 1) ())))"#,
+    );
+    let defs = LIST_DEFS.into_iter().chain([mutual_def]);
+    substitute_with_compounding(defs, "(vcon <MUTUAL> 0)")
+}
@@ -0,0 +1,233 @@
+use crate::{
+    check_erasability::{ErasabilityChecker, ErasabilityError},
+    eval::{Evaluator, NormalForm, Normalized},
+    pretty_print::*,
+    syntax_tree::{
+        ast::prelude::{spanned_ast::SpanAuxDataFamily, typed_ast, *},
+        lexer::lex,
+        parser::parse,
+        remove_ast_aux_data::AuxDataRemover,
+    },
+    typecheck::{Elaboration, HoleGoal, LazyTypeContext, TypeChecker, TypeError},
+};
+
+pub mod examples;
+
+pub fn substitute_with_compounding<'a>(
+    iter: impl IntoIterator<Item = (&'a str, &'a str)>,
+    last: &'a str,
+) -> String {
+    let mut replacements = vec![];
+    for (from, unreplaced_to) in iter {
+        let to = substitute_without_compounding(&replacements, unreplaced_to);
+        replacements.push((from, to));
+    }
+    substitute_without_compounding(&replacements, last)
+}
+
+pub fn substitute_without_compounding(replacements: &[(&str, String)], original: &str) -> String {
+    let mut result = original.to_string();
+    for (from, to) in replacements {
+        result = result.replace(from, to);
+    }
+    result
+}
+
+pub fn parse_spanned_ast_or_panic(src: &str) -> spanned_ast::Expr {
+    let tokens = lex(src).unwrap();
+    let cst = parse(tokens).unwrap();
+    cst.into()
+}
+
+pub fn parse_minimal_ast_or_panic(src: &str) -> minimal_ast::Expr {
+    let spanned: spanned_ast::Expr = parse_spanned_ast_or_panic(src);
+    let mut remover = AuxDataRemover::default();
+    remover.convert(spanned)
+}
+
+pub fn eval_or_panic(src: &str) -> NormalForm {
+    let ast = parse_minimal_ast_or_panic(src);
+    Evaluator::default().eval(ast)
+}
+
+pub fn get_type_under_empty_tcon_or_panic(src: &str) -> NormalForm {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    get_type_or_panic(src, tcon)
+}
+
+pub fn get_type_or_panic(src: &str, tcon: LazyTypeContext) -> NormalForm {
+    let spanned = parse_spanned_ast_or_panic(src);
+    TypeChecker::default()
+        .get_type(spanned, tcon)
+        .pretty_unwrap()
+}
+
+pub fn get_hole_goals_under_empty_tcon_or_panic(src: &str) -> Vec<HoleGoal<SpanAuxDataFamily>> {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    get_hole_goals_or_panic(src, tcon)
+}
+
+pub fn get_hole_goals_or_panic(
+    src: &str,
+    tcon: LazyTypeContext,
+) -> Vec<HoleGoal<SpanAuxDataFamily>> {
+    let spanned = parse_spanned_ast_or_panic(src);
+    let (_, goals) = TypeChecker::default()
+        .get_type_and_hole_goals(spanned, tcon)
+        .pretty_unwrap();
+    goals
+}
+
+pub fn get_typed_ast_under_empty_tcon_or_panic(src: &str) -> typed_ast::Expr<SpanAuxDataFamily> {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    get_typed_ast_or_panic(src, tcon)
+}
+
+pub fn get_typed_ast_or_panic(
+    src: &str,
+    tcon: LazyTypeContext,
+) -> typed_ast::Expr<SpanAuxDataFamily> {
+    let spanned = parse_spanned_ast_or_panic(src);
+    TypeChecker::default()
+        .get_typed_ast(spanned, tcon)
+        .pretty_unwrap()
+}
+
+pub fn typecheck_and_eval_under_empty_tcon_or_panic(src: &str) -> NormalForm {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    typecheck_and_eval_or_panic(src, tcon)
+}
+
+pub fn typecheck_and_eval_or_panic(src: &str, tcon: LazyTypeContext) -> NormalForm {
+    get_type_or_panic(src, tcon);
+    eval_or_panic(src)
+}
+
+pub fn get_type_error_under_empty_tcon_or_panic(src: &str) -> TypeError<SpanAuxDataFamily> {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    get_type_error_or_panic(src, tcon)
+}
+
+pub fn get_type_error_or_panic(src: &str, tcon: LazyTypeContext) -> TypeError<SpanAuxDataFamily> {
+    let spanned = parse_spanned_ast_or_panic(src);
+    TypeChecker::default()
+        .get_type(spanned, tcon)
+        .map(Normalized::into_raw)
+        .pretty_unwrap_err()
+}
+
+pub fn get_type_errors_under_empty_tcon_or_panic(src: &str) -> Vec<TypeError<SpanAuxDataFamily>> {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    get_type_errors_or_panic(src, tcon)
+}
+
+pub fn get_type_errors_or_panic(
+    src: &str,
+    tcon: LazyTypeContext,
+) -> Vec<TypeError<SpanAuxDataFamily>> {
+    let spanned = parse_spanned_ast_or_panic(src);
+    match TypeChecker::default().get_type_collecting_errors(spanned, tcon) {
+        Ok(type_) => panic!(
+            "Expected type errors, but got type {}",
+            PrettyPrint(type_.raw())
+        ),
+        Err(errors) => errors,
+    }
+}
+
+pub fn check_type_under_empty_tcon_or_panic(src: &str, expected_type_src: &str) {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    check_type_or_panic(src, expected_type_src, tcon)
+}
+
+pub fn check_type_or_panic(src: &str, expected_type_src: &str, tcon: LazyTypeContext) {
+    let spanned = parse_spanned_ast_or_panic(src);
+    let expected_type = eval_or_panic(expected_type_src);
+    TypeChecker::default()
+        .check_type(spanned, expected_type, tcon)
+        .pretty_unwrap()
+}
+
+pub fn get_check_type_error_under_empty_tcon_or_panic(
+    src: &str,
+    expected_type_src: &str,
+) -> TypeError<SpanAuxDataFamily> {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    get_check_type_error_or_panic(src, expected_type_src, tcon)
+}
+
+pub fn get_check_type_error_or_panic(
+    src: &str,
+    expected_type_src: &str,
+    tcon: LazyTypeContext,
+) -> TypeError<SpanAuxDataFamily> {
+    let spanned = parse_spanned_ast_or_panic(src);
+    let expected_type = eval_or_panic(expected_type_src);
+    TypeChecker::default()
+        .check_type(spanned, expected_type, tcon)
+        .unwrap_err()
+}
+
+pub fn elaborate_under_empty_tcon_or_panic(src: &str) -> Elaboration {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    elaborate_or_panic(src, tcon)
+}
+
+pub fn elaborate_or_panic(src: &str, tcon: LazyTypeContext) -> Elaboration {
+    let spanned = parse_spanned_ast_or_panic(src);
+    TypeChecker::default()
+        .elaborate(spanned, tcon)
+        .pretty_unwrap()
+}
+
+pub fn get_elaboration_error_under_empty_tcon_or_panic(src: &str) -> TypeError<SpanAuxDataFamily> {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    get_elaboration_error_or_panic(src, tcon)
+}
+
+pub fn get_elaboration_error_or_panic(
+    src: &str,
+    tcon: LazyTypeContext,
+) -> TypeError<SpanAuxDataFamily> {
+    let spanned = parse_spanned_ast_or_panic(src);
+    TypeChecker::default()
+        .elaborate(spanned, tcon)
+        .map(|elaboration| elaboration.expr)
+        .pretty_unwrap_err()
+}
+
+pub fn get_erasability_error_under_empty_tcon_or_panic(src: &str) -> ErasabilityError {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    get_erasability_error_or_panic(src, tcon)
+}
+
+pub fn get_erasability_error_or_panic(src: &str, tcon: LazyTypeContext) -> ErasabilityError {
+    let normalized = typecheck_and_eval_or_panic(src, tcon);
+    ErasabilityChecker::default()
+        .check_erasability_of_well_typed_expr(normalized, tcon)
+        .unwrap_err()
+}
+
+pub fn check_erasability_under_empty_tcon_or_panic(src: &str) {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    check_erasability_or_panic(src, tcon)
+}
+
+pub fn check_erasability_or_panic(src: &str, tcon: LazyTypeContext) {
+    let normalized = typecheck_and_eval_or_panic(src, tcon);
+    ErasabilityChecker::default()
+        .check_erasability_of_well_typed_expr(normalized, tcon)
+        .pretty_unwrap()
+}
@@ -61,7 +61,11 @@ impl TypeChecker {
             ast::Expr::App(e) => self.check_recursion_in_app(&e.hashee, rcon),
             ast::Expr::For(e) => self.check_recursion_in_for(&e.hashee, rcon),
             ast::Expr::Deb(e) => self.check_recursion_in_deb(&e.hashee, rcon),
-            ast::Expr::Universe(_) => Ok(()),
+            ast::Expr::Universe(_)
+            | ast::Expr::Lit(_)
+            | ast::Expr::Prim(_)
+            | ast::Expr::Meta(_)
+            | ast::Expr::Hole(_) => Ok(()),
         }
     }
 
@@ -143,9 +147,13 @@ impl TypeChecker {
         matchee_bound: Option<SizeBound>,
         rcon: RecursionCheckingContext<A>,
     ) -> Result<(), TypeError<A>> {
+        let Some(return_val) = &case.return_val else {
+            return Ok(());
+        };
+
         let extension = get_rcon_extension_for_match_case_params(matchee_bound, case.arity);
         let extended_rcon = RecursionCheckingContext::Snoc(&rcon, &extension);
-        self.check_recursion(case.return_val.clone(), extended_rcon)?;
+        self.check_recursion(return_val.clone(), extended_rcon)?;
         Ok(())
     }
 
@@ -488,7 +496,11 @@ impl TypeChecker {
             | ast::Expr::Fun(_)
             | ast::Expr::App(_)
             | ast::Expr::For(_)
-            | ast::Expr::Universe(_) => None,
+            | ast::Expr::Universe(_)
+            | ast::Expr::Lit(_)
+            | ast::Expr::Prim(_)
+            | ast::Expr::Meta(_)
+            | ast::Expr::Hole(_) => None,
 
             ast::Expr::Match(e) => self.get_size_bound_of_match(&e.hashee, rcon),
 
@@ -526,9 +538,15 @@ impl TypeChecker {
         matchee_bound: Option<SizeBound>,
         rcon: RecursionCheckingContext<A>,
     ) -> Option<SizeBound> {
+        // Omitted cases are impossible,
+        // so like caseless matches, they are bounded by anything.
+        let Some(return_val) = &expr.return_val else {
+            return Some(SizeBound::CaselessMatch);
+        };
+
         let extension = get_rcon_extension_for_match_case_params(matchee_bound, expr.arity);
         let extended_rcon = RecursionCheckingContext::Snoc(&rcon, &extension);
-        self.get_size_bound(expr.return_val.clone(), extended_rcon)
+        self.get_size_bound(return_val.clone(), extended_rcon)
     }
 
     fn get_size_bound_of_deb<A: AuxDataFamily>(
@@ -124,7 +124,12 @@ impl PositivityChecker<'_> {
             ast::Expr::Fun(e) => self.check_fun(&e.hashee, context),
             ast::Expr::App(e) => self.check_app(&e.hashee, context),
             ast::Expr::For(e) => self.check_for(&e.hashee, context),
-            ast::Expr::Deb(_) | ast::Expr::Universe(_) => Ok(()),
+            ast::Expr::Deb(_)
+            | ast::Expr::Universe(_)
+            | ast::Expr::Lit(_)
+            | ast::Expr::Prim(_)
+            | ast::Expr::Meta(_)
+            | ast::Expr::Hole(_) => Ok(()),
         }
     }
 
@@ -210,9 +215,13 @@ impl PositivityChecker<'_> {
         case: &ast::MatchCase<A>,
         context: Context,
     ) -> Result<(), TypeError<A>> {
+        let Some(return_val) = &case.return_val else {
+            return Ok(());
+        };
+
         let return_val_extension = RestrictionStatusVec::unrestricted(case.arity);
         let return_val_context = context.collapsing_snoc(return_val_extension);
-        self.check(case.return_val.clone(), return_val_context)?;
+        self.check(return_val.clone(), return_val_context)?;
 
         Ok(())
     }
@@ -429,7 +438,11 @@ impl StrictPositivityChecker<'_> {
             minimal_ast::Expr::Vcon(_)
             | minimal_ast::Expr::Match(_)
             | minimal_ast::Expr::Fun(_)
-            | minimal_ast::Expr::Universe(_) => self.absence_checker().check(expr, context, path),
+            | minimal_ast::Expr::Universe(_)
+            | minimal_ast::Expr::Lit(_)
+            | minimal_ast::Expr::Prim(_)
+            | minimal_ast::Expr::Meta(_)
+            | minimal_ast::Expr::Hole(_) => self.absence_checker().check(expr, context, path),
         }
     }
 
@@ -520,7 +533,11 @@ impl StrictPositivityChecker<'_> {
             | minimal_ast::Expr::Fun(_)
             | minimal_ast::Expr::App(_)
             | minimal_ast::Expr::For(_)
-            | minimal_ast::Expr::Universe(_) => self.absence_checker().check(callee, context, path),
+            | minimal_ast::Expr::Universe(_)
+            | minimal_ast::Expr::Lit(_)
+            | minimal_ast::Expr::Prim(_)
+            | minimal_ast::Expr::Meta(_)
+            | minimal_ast::Expr::Hole(_) => self.absence_checker().check(callee, context, path),
         }
     }
 
@@ -591,7 +608,11 @@ impl AbsenceChecker<'_> {
             minimal_ast::Expr::App(e) => self.check_app(&e.hashee, context, path),
             minimal_ast::Expr::For(e) => self.check_for(&e.hashee, context, path),
             minimal_ast::Expr::Deb(e) => self.check_deb(&e.hashee, context, path),
-            minimal_ast::Expr::Universe(_) => Ok(()),
+            minimal_ast::Expr::Universe(_)
+            | minimal_ast::Expr::Lit(_)
+            | minimal_ast::Expr::Prim(_)
+            | minimal_ast::Expr::Meta(_)
+            | minimal_ast::Expr::Hole(_) => Ok(()),
         }
     }
 
@@ -684,10 +705,14 @@ impl AbsenceChecker<'_> {
         path: NodePath,
     ) -> Result<(), Vec<NodeEdge>> {
         for (i, case) in cases.iter().enumerate() {
+            let Some(return_val) = &case.return_val else {
+                continue;
+            };
+
             let extension = RestrictionStatusVec::unrestricted(case.arity);
             let extended_context = context.collapsing_snoc(extension);
             let extended_path = NodePath::Snoc(&path, NodeEdge(i));
-            self.check(case.return_val.clone(), extended_context, extended_path)?;
+            self.check(return_val.clone(), extended_context, extended_path)?;
         }
 
         Ok(())
@@ -0,0 +1,149 @@
+use super::*;
+
+/// The result of elaborating an expression that may contain metas.
+#[derive(Clone, Debug)]
+pub struct Elaboration {
+    /// The input expression, with every meta replaced by its solution.
+    pub expr: minimal_ast::Expr,
+    pub type_: NormalForm,
+}
+
+impl TypeChecker {
+    /// Typechecks `expr`, solving its metas along the way,
+    /// and returns `expr` with every meta replaced by its solution.
+    ///
+    /// Like every top-level call, this discards
+    /// any solutions from previous calls,
+    /// and returns `TypeError::UnsolvedMetas`
+    /// if any meta remains unsolved.
+    pub fn elaborate<A: AuxDataFamily>(
+        &mut self,
+        expr: ast::Expr<A>,
+        tcon: LazyTypeContext,
+    ) -> Result<Elaboration, TypeError<A>> {
+        let type_ = self.get_type(expr.clone(), tcon)?;
+
+        let expr_minimal = self.aux_remover.convert(expr);
+        Ok(Elaboration {
+            expr: self.zonk_unnormalized(expr_minimal, tcon.len()),
+            type_,
+        })
+    }
+
+    /// Runs `f` as part of a top-level call on `expr`.
+    ///
+    /// If no top-level call is in progress,
+    /// the meta table is cleared before `f` runs,
+    /// and once `f` succeeds, every meta in `expr` must be solved
+    /// (and `expr` must not contain invalid code).
+    /// Otherwise (i.e., if `f` is part of an enclosing call),
+    /// `f` is simply run.
+    pub(super) fn in_top_level_call<A: AuxDataFamily, T>(
+        &mut self,
+        expr: &ast::Expr<A>,
+        f: impl FnOnce(&mut Self) -> Result<T, TypeError<A>>,
+    ) -> Result<T, TypeError<A>> {
+        if self.is_in_top_level_call {
+            return f(self);
+        }
+
+        self.metas.clear();
+        self.is_in_top_level_call = true;
+        let result = f(self);
+        self.is_in_top_level_call = false;
+        let out = result?;
+
+        // A recovered error may leave a meta unsolved,
+        // and we do not want to report follow-on errors.
+        if self.error_recovery.has_errors() {
+            return Ok(out);
+        }
+
+        let unsolved: Vec<ast::MetaNode<A>> = get_first_occurrence_of_each_meta(expr)
+            .into_iter()
+            .filter(|meta| {
+                self.metas
+                    .get(meta.id)
+                    .is_none_or(|entry| entry.solution.is_none())
+            })
+            .collect();
+        // Invalid code is never solved,
+        // but it deserves its own error.
+        if let Some(placeholder) = unsolved.iter().find(|meta| meta.id == MetaId::INVALID_CODE) {
+            return Err(TypeError::InvalidCode {
+                placeholder: placeholder.clone(),
+            });
+        }
+        if !unsolved.is_empty() {
+            return Err(TypeError::UnsolvedMetas { metas: unsolved });
+        }
+
+        Ok(out)
+    }
+}
+
+/// Returns the metas in `expr` in the order they first appear,
+/// skipping repeated occurrences.
+fn get_first_occurrence_of_each_meta<A: AuxDataFamily>(
+    expr: &ast::Expr<A>,
+) -> Vec<ast::MetaNode<A>> {
+    let mut out = vec![];
+    collect_metas(expr, &mut out);
+    out
+}
+
+fn collect_metas<A: AuxDataFamily>(expr: &ast::Expr<A>, out: &mut Vec<ast::MetaNode<A>>) {
+    match expr {
+        ast::Expr::Ind(e) => collect_metas_in_ind(&e.hashee, out),
+        ast::Expr::Vcon(e) => collect_metas_in_ind(&e.hashee.ind.hashee, out),
+        ast::Expr::Match(e) => {
+            collect_metas(&e.hashee.matchee, out);
+            collect_metas(&e.hashee.return_type, out);
+            for case in e.hashee.cases.hashee.iter() {
+                if let Some(return_val) = &case.return_val {
+                    collect_metas(return_val, out);
+                }
+            }
+        }
+        ast::Expr::Fun(e) => {
+            collect_metas_in_each(&e.hashee.param_types.hashee, out);
+            collect_metas(&e.hashee.return_type, out);
+            collect_metas(&e.hashee.return_val, out);
+        }
+        ast::Expr::App(e) => {
+            collect_metas(&e.hashee.callee, out);
+            collect_metas_in_each(&e.hashee.args.hashee, out);
+        }
+        ast::Expr::For(e) => {
+            collect_metas_in_each(&e.hashee.param_types.hashee, out);
+            collect_metas(&e.hashee.return_type, out);
+        }
+        ast::Expr::Meta(e) => {
+            if !out.iter().any(|meta| meta.id == e.hashee.id) {
+                out.push(e.hashee.clone());
+            }
+        }
+        ast::Expr::Deb(_)
+        | ast::Expr::Universe(_)
+        | ast::Expr::Lit(_)
+        | ast::Expr::Prim(_)
+        | ast::Expr::Hole(_) => {}
+    }
+}
+
+fn collect_metas_in_ind<A: AuxDataFamily>(ind: &ast::Ind<A>, out: &mut Vec<ast::MetaNode<A>>) {
+    collect_metas_in_each(&ind.index_types.hashee, out);
+    for def in ind.vcon_defs.hashee.iter() {
+        collect_metas_in_each(&def.param_types.hashee, out);
+        collect_metas_in_each(&def.index_args.hashee, out);
+    }
+}
+
+fn collect_metas_in_each<A: AuxDataFamily>(
+    exprs: &[ast::Expr<A>],
+    out: &mut Vec<ast::MetaNode<A>>,
+) {
+    for expr in exprs {
+        collect_metas(expr, out);
+    }
+}
@@ -25,6 +25,10 @@ pub enum TypeError<A: AuxDataFamily> {
         tcon_len: usize,
     },
     InvalidVconIndex(ast::Vcon<A>),
+    /// The vcon referred to its vcon def by a name
+    /// that none of the ind's vcon defs have
+    /// (its index is `Vcon::UNKNOWN_NAME_INDEX`).
+    UnknownVconName(ast::Vcon<A>),
     UnexpectedNonTypeExpression {
         expr: ast::Expr<A>,
         type_: NormalForm,
@@ -57,6 +61,13 @@ pub enum TypeError<A: AuxDataFamily> {
         match_: ast::Match<A>,
         match_case_index: usize,
     },
+    /// A match case can only be omitted if unifying
+    /// the vcon's index args with the matchee type's index args
+    /// reveals a contradiction (i.e., the case is impossible).
+    MatchCaseIsNotImpossible {
+        match_: ast::Match<A>,
+        match_case_index: usize,
+    },
     TypeMismatch {
         expr: ast::Expr<A>,
         expected_type: NormalForm,
@@ -112,4 +123,28 @@ pub enum TypeError<A: AuxDataFamily> {
         normalized_index_arg: NormalForm,
         path_from_index_arg_to_problematic_deb: Vec<minimal_ast::NodeEdge>,
     },
+
+    /// A meta can only have its type inferred
+    /// if it has previously appeared in a checking position
+    /// (e.g., as an app argument).
+    CannotInferMetaType {
+        meta: ast::MetaNode<A>,
+    },
+    /// Holes are only accepted when collecting hole goals,
+    /// and even then, only in checking positions
+    /// (e.g., as an app argument),
+    /// since otherwise there is no expected type to report.
+    CannotInferHoleType {
+        hole: ast::HoleNode<A>,
+    },
+    /// Each meta is reported once,
+    /// at the first place it was introduced.
+    UnsolvedMetas {
+        metas: Vec<ast::MetaNode<A>>,
+    },
+    /// `placeholder` stands in for code that could not be parsed
+    /// (its id is `MetaId::INVALID_CODE`).
+    InvalidCode {
+        placeholder: ast::MetaNode<A>,
+    },
 }
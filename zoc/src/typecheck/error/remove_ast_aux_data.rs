@@ -12,6 +12,10 @@ impl<A: AuxDataFamily> TypeError<A> {
                 TypeError::InvalidVconIndex(remover.convert_vcon(rc_hashed(vcon)).hashee.clone())
             }
 
+            TypeError::UnknownVconName(vcon) => {
+                TypeError::UnknownVconName(remover.convert_vcon(rc_hashed(vcon)).hashee.clone())
+            }
+
             TypeError::UnexpectedNonTypeExpression { expr, type_ } => {
                 TypeError::UnexpectedNonTypeExpression {
                     expr: remover.convert(expr).clone(),
@@ -74,6 +78,14 @@ impl<A: AuxDataFamily> TypeError<A> {
                 match_case_index,
             },
 
+            TypeError::MatchCaseIsNotImpossible {
+                match_,
+                match_case_index,
+            } => TypeError::MatchCaseIsNotImpossible {
+                match_: remover.convert_match(rc_hashed(match_)).hashee.clone(),
+                match_case_index,
+            },
+
             TypeError::TypeMismatch {
                 expr,
                 expected_type,
@@ -179,6 +191,25 @@ impl<A: AuxDataFamily> TypeError<A> {
                 normalized_index_arg,
                 path_from_index_arg_to_problematic_deb,
             },
+
+            TypeError::CannotInferMetaType { meta } => TypeError::CannotInferMetaType {
+                meta: remover.convert_meta_node(&meta).hashee.clone(),
+            },
+
+            TypeError::InvalidCode { placeholder } => TypeError::InvalidCode {
+                placeholder: remover.convert_meta_node(&placeholder).hashee.clone(),
+            },
+
+            TypeError::CannotInferHoleType { hole } => TypeError::CannotInferHoleType {
+                hole: remover.convert_hole_node(&hole).hashee.clone(),
+            },
+
+            TypeError::UnsolvedMetas { metas } => TypeError::UnsolvedMetas {
+                metas: metas
+                    .iter()
+                    .map(|meta| remover.convert_meta_node(meta).hashee.clone())
+                    .collect(),
+            },
         }
     }
 }
@@ -0,0 +1,163 @@
+use super::*;
+
+use crate::pretty_print::GetOptSpan;
+
+use minimal_ast::{Expr, MetaNode};
+
+use std::collections::HashSet;
+
+/// State for the error-recovering typechecking mode.
+///
+/// When recovery is enabled, an error in a vcon def, a match case,
+/// or an app argument is recorded instead of returned,
+/// and the typechecker moves on to the next sibling.
+///
+/// If a recovered app argument is needed to compute a later type,
+/// it is replaced by a fresh _error meta_.
+/// An error meta is never solved, and unifying an error meta
+/// (or an expression stuck on one) with anything succeeds.
+/// This way, a recovered node does not cause follow-on errors.
+///
+/// The placeholder for invalid code (`MetaId::INVALID_CODE`)
+/// is treated as an error meta, whether or not recovery is enabled.
+#[derive(Clone, Debug)]
+pub struct ErrorRecovery {
+    enabled: bool,
+    /// `TypeChecker` is not generic over the aux data family,
+    /// so we erase the errors' types,
+    /// and downcast them when they are taken.
+    errors: Vec<Rc<DynAny>>,
+    error_metas: HashSet<MetaId>,
+    /// Error metas count down from just below `MetaId::INVALID_CODE`,
+    /// so they do not collide with metas in the source.
+    next_error_meta_id: usize,
+}
+
+impl Default for ErrorRecovery {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            errors: vec![],
+            error_metas: HashSet::new(),
+            next_error_meta_id: MetaId::INVALID_CODE.0 - 1,
+        }
+    }
+}
+
+impl ErrorRecovery {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn is_error_meta(&self, id: MetaId) -> bool {
+        id == MetaId::INVALID_CODE || self.error_metas.contains(&id)
+    }
+
+    pub(super) fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    fn reset(&mut self, enabled: bool) {
+        *self = Self {
+            enabled,
+            ..Self::default()
+        };
+    }
+
+    fn push<A: AuxDataFamily>(&mut self, err: TypeError<A>) {
+        self.errors.push(Rc::new(err));
+    }
+
+    fn take<A: AuxDataFamily>(&mut self) -> Vec<TypeError<A>> {
+        std::mem::take(&mut self.errors)
+            .into_iter()
+            .filter_map(|err| err.downcast_ref::<TypeError<A>>().cloned())
+            .collect()
+    }
+
+    fn fresh_error_meta(&mut self) -> MetaId {
+        let id = MetaId(self.next_error_meta_id);
+        self.next_error_meta_id -= 1;
+        self.error_metas.insert(id);
+        id
+    }
+}
+
+impl TypeChecker {
+    /// Like `get_type`, except typechecking does not stop
+    /// at the first error.
+    /// Instead, every error is collected, and the errors
+    /// are returned sorted by span.
+    pub fn get_type_collecting_errors<A: AuxDataFamily>(
+        &mut self,
+        expr: ast::Expr<A>,
+        tcon: LazyTypeContext,
+    ) -> Result<NormalForm, Vec<TypeError<A>>>
+    where
+        TypeError<A>: GetOptSpan,
+    {
+        self.error_recovery.reset(true);
+        let result = self.get_type(expr, tcon);
+        let mut errors = self.error_recovery.take::<A>();
+        self.error_recovery.reset(false);
+
+        match result {
+            Ok(type_) if errors.is_empty() => return Ok(type_),
+            Ok(_) => {}
+            Err(err) => errors.push(err),
+        }
+
+        errors.sort_by_key(GetOptSpan::opt_span);
+        Err(errors)
+    }
+
+    /// If recovery is enabled and `result` is an error,
+    /// the error is recorded and `Ok(None)` is returned.
+    /// Otherwise, `result` is returned as-is.
+    pub(super) fn recover<A: AuxDataFamily, T>(
+        &mut self,
+        result: Result<T, TypeError<A>>,
+    ) -> Result<Option<T>, TypeError<A>> {
+        match result {
+            Ok(val) => Ok(Some(val)),
+            Err(err) if self.error_recovery.enabled => {
+                self.error_recovery.push(err);
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Reports the placeholder for invalid code.
+    /// If recovery is enabled, the error is recorded,
+    /// and the placeholder's type is a fresh error meta
+    /// (so the placeholder checks against any type).
+    pub(super) fn report_invalid_code<A: AuxDataFamily>(
+        &mut self,
+        placeholder: ast::MetaNode<A>,
+    ) -> Result<NormalForm, TypeError<A>> {
+        self.recover::<A, ()>(Err(TypeError::InvalidCode { placeholder }))?;
+        Ok(self.error_meta_value())
+    }
+
+    /// Returns a fresh error meta,
+    /// to stand in for the value of a recovered node.
+    pub(super) fn error_meta_value(&mut self) -> NormalForm {
+        let id = self.error_recovery.fresh_error_meta();
+        self.evaluator
+            .eval(Expr::Meta(rc_hashed(MetaNode { id, aux_data: () })))
+    }
+
+    /// Returns whether `expr` is an error meta,
+    /// or is stuck on one (e.g., an app whose callee is an error meta,
+    /// or a match whose matchee is an error meta).
+    /// Such an expression stands in for an unknown value.
+    pub(super) fn is_blocked_on_error_meta(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Meta(meta) => self.error_recovery.is_error_meta(meta.hashee.id),
+            Expr::App(app) => self.is_blocked_on_error_meta(&app.hashee.callee),
+            Expr::Match(match_) => self.is_blocked_on_error_meta(&match_.hashee.matchee),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use super::*;
+
+use crate::{
+    pretty_print::PrettyPrinted,
+    syntax_tree::ast::prelude::{minimal_ast::UnitAuxDataFamily, spanned_ast::SpanAuxDataFamily},
+};
+
+use std::fmt::{Debug, Result as FmtResult};
+
+/// What a hole needs to be filled with,
+/// and what is in scope at the hole.
+#[derive(Clone)]
+pub struct HoleGoal<A: AuxDataFamily> {
+    pub hole: ast::HoleNode<A>,
+    pub expected_type: NormalForm,
+    /// The entries are ordered like a type context.
+    /// That is, the last entry is deb `0`.
+    ///
+    /// Every entry's type (and the expected type)
+    /// lives in the hole's context,
+    /// so the entries' names can be used to print any of them.
+    pub context: Vec<HoleContextEntry>,
+}
+
+#[derive(Clone, Debug)]
+pub struct HoleContextEntry {
+    /// A name reconstructed from the entry's type.
+    /// Zo has no variable names, so this is only a printing aid.
+    pub name: String,
+    pub deb: Deb,
+    pub type_: NormalForm,
+}
+
+impl<A: AuxDataFamily> HoleGoal<A> {
+    pub fn context_names(&self) -> Vec<String> {
+        self.context
+            .iter()
+            .map(|entry| entry.name.clone())
+            .collect()
+    }
+}
+
+impl Debug for HoleGoal<UnitAuxDataFamily> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
+        let pretty = self.pretty_printed();
+        write!(f, "{pretty:#}")
+    }
+}
+
+impl Debug for HoleGoal<SpanAuxDataFamily> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> FmtResult {
+        let pretty = self.pretty_printed();
+        write!(f, "{pretty:#}")
+    }
+}
+
+/// Holes are only accepted while the store is enabled
+/// (i.e., during `get_type_and_hole_goals`).
+///
+/// `TypeChecker` is not generic over the aux data family,
+/// so we erase the goals' types,
+/// and downcast them when they are taken.
+#[derive(Clone, Debug, Default)]
+pub struct HoleGoalStore {
+    enabled: bool,
+    goals: Vec<Rc<DynAny>>,
+}
+
+impl HoleGoalStore {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn clear(&mut self) {
+        self.goals.clear();
+    }
+
+    pub(super) fn push<A: AuxDataFamily>(&mut self, goal: HoleGoal<A>) {
+        self.goals.push(Rc::new(goal));
+    }
+
+    /// Removes and returns every goal recorded for the family `A`,
+    /// in the order the holes were checked.
+    pub fn take<A: AuxDataFamily>(&mut self) -> Vec<HoleGoal<A>> {
+        let (taken, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut self.goals)
+            .into_iter()
+            .partition(|goal| goal.is::<HoleGoal<A>>());
+        self.goals = kept;
+        taken
+            .into_iter()
+            .filter_map(|goal| goal.downcast_ref::<HoleGoal<A>>().cloned())
+            .collect()
+    }
+}
+
+impl TypeChecker {
+    /// Typechecks `expr`, and returns its type
+    /// along with the goal of every hole in `expr`.
+    pub fn get_type_and_hole_goals<A: AuxDataFamily>(
+        &mut self,
+        expr: ast::Expr<A>,
+        tcon: LazyTypeContext,
+    ) -> Result<(NormalForm, Vec<HoleGoal<A>>), TypeError<A>> {
+        self.hole_goals.clear();
+        let type_ = self.accepting_holes(|this| this.get_type(expr, tcon))?;
+
+        // Metas may have been solved after a hole was checked,
+        // so we zonk each goal now.
+        let goals = self
+            .hole_goals
+            .take::<A>()
+            .into_iter()
+            .map(|goal| self.zonk_hole_goal(goal))
+            .collect();
+        Ok((type_, goals))
+    }
+
+    /// Runs `f` with hole goal collection enabled,
+    /// so holes in checking positions are accepted.
+    pub(super) fn accepting_holes<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        self.hole_goals.enabled = true;
+        let out = f(self);
+        self.hole_goals.enabled = false;
+        out
+    }
+
+    fn zonk_hole_goal<A: AuxDataFamily>(&mut self, goal: HoleGoal<A>) -> HoleGoal<A> {
+        let tcon_len = goal.context.len();
+        HoleGoal {
+            hole: goal.hole,
+            expected_type: self.zonk(goal.expected_type, tcon_len),
+            context: goal
+                .context
+                .into_iter()
+                .map(|entry| HoleContextEntry {
+                    name: entry.name,
+                    deb: entry.deb,
+                    type_: self.zonk(entry.type_, tcon_len),
+                })
+                .collect(),
+        }
+    }
+
+    /// When hole goals are being collected, a hole checks against any type.
+    /// We record the expected type (and the context)
+    /// so we can later report them to the user.
+    /// Otherwise, holes are rejected.
+    pub(super) fn record_hole_goal<A: AuxDataFamily>(
+        &mut self,
+        hole: RcHashed<ast::HoleNode<A>>,
+        expected_type: NormalForm,
+        tcon: LazyTypeContext,
+    ) -> Result<(), TypeError<A>> {
+        if !self.hole_goals.enabled {
+            return Err(TypeError::CannotInferHoleType {
+                hole: hole.hashee.clone(),
+            });
+        }
+
+        let tcon_len = tcon.len();
+        let context = (0..tcon_len)
+            .map(|level| {
+                let deb = Deb(tcon_len - 1 - level);
+                let type_ = tcon
+                    .get(deb)
+                    .expect("Every deb less than the context length should be in the context.");
+                HoleContextEntry {
+                    name: reconstruct_name(type_.raw(), level),
+                    deb,
+                    type_,
+                }
+            })
+            .collect();
+
+        self.hole_goals.push(HoleGoal {
+            hole: hole.hashee.clone(),
+            expected_type,
+            context,
+        });
+        Ok(())
+    }
+}
+
+/// Names are based on the entry's type
+/// (e.g., an entry whose type is `Nat` or `(List ...)`
+/// is named `n<level>` or `l<level>`, respectively,
+/// types are named `T<level>`, and functions are named `f<level>`).
+/// Since the level is appended, names are always unique.
+fn reconstruct_name(type_: &minimal_ast::Expr, level: usize) -> String {
+    let prefix = match type_ {
+        minimal_ast::Expr::Universe(_) => "T".to_string(),
+        minimal_ast::Expr::For(_) => "f".to_string(),
+        minimal_ast::Expr::Ind(ind) => lowercase_initial(&ind.hashee.name.0),
+        minimal_ast::Expr::App(app) => match &app.hashee.callee {
+            minimal_ast::Expr::Ind(ind) => lowercase_initial(&ind.hashee.name.0),
+            _ => "x".to_string(),
+        },
+        _ => "x".to_string(),
+    };
+    format!("{prefix}{level}")
+}
+
+fn lowercase_initial(name: &str) -> String {
+    match name.chars().find(|c| c.is_ascii_alphabetic()) {
+        Some(c) => c.to_ascii_lowercase().to_string(),
+        None => "x".to_string(),
+    }
+}
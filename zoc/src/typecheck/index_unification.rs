@@ -0,0 +1,109 @@
+use super::*;
+
+use crate::eval::try_as_vcon_or_vcon_app;
+
+use minimal_ast::Expr;
+
+/// The result of unifying a vcon's index args
+/// with the matchee type's index args.
+pub(crate) enum IndexUnification {
+    /// Some pair of index args can never be equal
+    /// (e.g., they are applications of different vcons),
+    /// so the match case is impossible.
+    Conflict,
+    /// No contradiction was found.
+    /// Each solution `(deb, expr)` states that,
+    /// if the match case is ever reached,
+    /// then `deb` is definitionally equal to `expr`.
+    /// Solutions are listed in the order they were found,
+    /// so each solution should be applied after the previous ones.
+    ///
+    /// Pairs that could neither be solved nor refuted
+    /// (e.g., because they contain unreduced matches)
+    /// are ignored.
+    Solved(Vec<(Deb, Expr)>),
+}
+
+impl TypeChecker {
+    /// Unifies each `(left, right)` pair,
+    /// using injectivity and disjointness of vcons
+    /// (i.e., `(c a1 ... an) = (c b1 ... bn)` implies `ai = bi`,
+    /// and `(c ...) = (d ...)` is impossible when `c` and `d` are different vcons
+    /// of the same ind).
+    /// All expressions live in the same type context.
+    pub(crate) fn unify_indices(&mut self, pairs: Vec<(Expr, Expr)>) -> IndexUnification {
+        let mut pending = pairs;
+        pending.reverse();
+        let mut solutions: Vec<(Deb, Expr)> = vec![];
+
+        while let Some((left, right)) = pending.pop() {
+            let left = self.evaluator.eval(left).into_raw();
+            let right = self.evaluator.eval(right).into_raw();
+
+            if left.digest() == right.digest() {
+                continue;
+            }
+
+            if let Some((deb, new_expr)) =
+                try_solve_deb(&left, &right).or_else(|| try_solve_deb(&right, &left))
+            {
+                let substituter = DebSubstituter {
+                    deb,
+                    new_expr: &new_expr,
+                };
+                for (pending_left, pending_right) in &mut pending {
+                    *pending_left = pending_left.clone().replace_debs(&substituter, 0);
+                    *pending_right = pending_right.clone().replace_debs(&substituter, 0);
+                }
+                solutions.push((deb, new_expr));
+                continue;
+            }
+
+            if let (Some((left_vcon, left_args)), Some((right_vcon, right_args))) = (
+                try_as_vcon_or_vcon_app(&left),
+                try_as_vcon_or_vcon_app(&right),
+            ) {
+                if left_vcon.hashee.ind.digest() != right_vcon.hashee.ind.digest() {
+                    continue;
+                }
+
+                if left_vcon.hashee.vcon_index != right_vcon.hashee.vcon_index {
+                    return IndexUnification::Conflict;
+                }
+
+                if left_args.len() == right_args.len() {
+                    pending.extend(
+                        left_args
+                            .iter()
+                            .cloned()
+                            .zip(right_args.iter().cloned())
+                            .rev(),
+                    );
+                }
+                continue;
+            }
+
+            if let (Expr::Lit(_), Expr::Lit(_)) = (&left, &right) {
+                // The digests differ, so the literals differ.
+                return IndexUnification::Conflict;
+            }
+        }
+
+        IndexUnification::Solved(solutions)
+    }
+}
+
+/// Returns `Some((deb, other))` if `deb_candidate` is a deb
+/// that does not occur in `other`.
+fn try_solve_deb(deb_candidate: &Expr, other: &Expr) -> Option<(Deb, Expr)> {
+    let Expr::Deb(deb) = deb_candidate else {
+        return None;
+    };
+
+    let deb = deb.hashee.deb;
+    if contains_deb(other, deb) {
+        return None;
+    }
+
+    Some((deb, other.clone()))
+}
@@ -0,0 +1,164 @@
+use super::*;
+
+use minimal_ast::{DebNode, Expr, MetaNode};
+
+use std::collections::HashMap;
+
+/// Records the type and (if any) solution of every metavariable
+/// the typechecker has encountered.
+///
+/// A meta is scoped to the type context it was introduced in
+/// (i.e., the context of the first checking position it appeared in).
+/// Its type and solution are stored relative to that context.
+/// Whenever the meta appears under additional binders,
+/// its type and solution are upshifted accordingly.
+#[derive(Clone, Debug, Default)]
+pub struct MetaStore {
+    entries: HashMap<MetaId, MetaEntry>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MetaEntry {
+    pub intro_tcon_len: usize,
+    pub type_: NormalForm,
+    pub solution: Option<NormalForm>,
+}
+
+impl MetaStore {
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn get(&self, id: MetaId) -> Option<&MetaEntry> {
+        self.entries.get(&id)
+    }
+
+    pub(super) fn insert(&mut self, id: MetaId, entry: MetaEntry) {
+        self.entries.insert(id, entry);
+    }
+
+    pub(super) fn set_solution(&mut self, id: MetaId, solution: NormalForm) {
+        if let Some(entry) = self.entries.get_mut(&id) {
+            entry.solution = Some(solution);
+        }
+    }
+}
+
+/// Moves `expr` from a type context of length `from_tcon_len`
+/// to a type context of length `to_tcon_len`,
+/// where one context is a prefix of the other.
+///
+/// Returns `None` if `expr` refers to a deb that is
+/// not in scope in the shorter context.
+pub(super) fn rescope(expr: Expr, from_tcon_len: usize, to_tcon_len: usize) -> Option<Expr> {
+    if to_tcon_len >= from_tcon_len {
+        return Some(expr.replace_debs(&DebUpshifter(to_tcon_len - from_tcon_len), 0));
+    }
+
+    let downshifter = FallibleDebDownshifter {
+        amount: from_tcon_len - to_tcon_len,
+        failed: Cell::new(false),
+    };
+    let shifted = expr.replace_debs(&downshifter, 0);
+    if downshifter.failed.get() {
+        return None;
+    }
+    Some(shifted)
+}
+
+/// Downshifts free debs by `amount`.
+/// If a free deb is less than `amount`
+/// (and thus cannot be downshifted), `failed` is set.
+struct FallibleDebDownshifter {
+    amount: usize,
+    failed: Cell<bool>,
+}
+
+impl DebReplacer for FallibleDebDownshifter {
+    fn replace_deb(&self, original: RcHashed<DebNode>, cutoff: usize) -> Expr {
+        if original.hashee.deb.0 < cutoff {
+            return Expr::Deb(original);
+        }
+
+        if original.hashee.deb.0 < cutoff + self.amount {
+            self.failed.set(true);
+            return Expr::Deb(original);
+        }
+
+        Expr::Deb(rc_hashed(DebNode {
+            deb: Deb(original.hashee.deb.0 - self.amount),
+            aux_data: (),
+        }))
+    }
+}
+
+/// Replaces every solved meta with its (appropriately upshifted) solution.
+/// Unsolved metas are left as-is.
+struct MetaZonker<'a> {
+    metas: &'a MetaStore,
+    tcon_len: usize,
+}
+
+impl DebReplacer for MetaZonker<'_> {
+    fn replace_deb(&self, original: RcHashed<DebNode>, _cutoff: usize) -> Expr {
+        Expr::Deb(original)
+    }
+
+    fn replace_meta(&self, original: RcHashed<MetaNode>, cutoff: usize) -> Expr {
+        let Some(entry) = self.metas.get(original.hashee.id) else {
+            return Expr::Meta(original);
+        };
+        let Some(solution) = &entry.solution else {
+            return Expr::Meta(original);
+        };
+        let Some(rescoped) = rescope(
+            solution.raw().clone(),
+            entry.intro_tcon_len,
+            self.tcon_len + cutoff,
+        ) else {
+            return Expr::Meta(original);
+        };
+
+        // The solution may itself contain solved metas.
+        // This terminates because the unifier performs an occurs check.
+        rescoped.replace_debs(self, cutoff)
+    }
+}
+
+impl TypeChecker {
+    /// Replaces every solved meta in `expr` with its solution,
+    /// and then normalizes the result.
+    ///
+    /// `tcon_len` is the length of the type context `expr` lives in.
+    pub fn zonk(&mut self, expr: NormalForm, tcon_len: usize) -> NormalForm {
+        if self.metas.is_empty() {
+            return expr;
+        }
+
+        let zonker = MetaZonker {
+            metas: &self.metas,
+            tcon_len,
+        };
+        let zonked = expr.into_raw().replace_debs(&zonker, 0);
+        self.evaluator.eval(zonked)
+    }
+
+    /// Like `zonk`, except the result is not normalized.
+    /// This is used to zonk the elaborated input expression,
+    /// which (unlike types) we do not want to normalize.
+    pub(super) fn zonk_unnormalized(&self, expr: Expr, tcon_len: usize) -> Expr {
+        if self.metas.is_empty() {
+            return expr;
+        }
+
+        let zonker = MetaZonker {
+            metas: &self.metas,
+            tcon_len,
+        };
+        expr.replace_debs(&zonker, 0)
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     },
 };
 
-use std::{marker::PhantomData, rc::Rc};
+use std::{cell::Cell, marker::PhantomData};
 
 mod check_fun_recursion;
 use check_fun_recursion::*;
@@ -19,27 +19,84 @@ use check_positivity::*;
 mod equality_assertion;
 use equality_assertion::*;
 
+mod hole_goals;
+pub use hole_goals::*;
+
+mod index_unification;
+use index_unification::*;
+
+mod meta_store;
+pub use meta_store::*;
+
+#[cfg(feature = "sync")]
+mod parallel;
+#[cfg(feature = "sync")]
+pub use parallel::*;
+
+mod elaborate;
+pub use elaborate::*;
+
 mod error;
 pub use error::TypeError;
 
+mod error_recovery;
+pub use error_recovery::*;
+
 mod tcon;
 pub use tcon::*;
 
+mod type_cache;
+pub use type_cache::*;
+
 mod typechecker_impls;
 
+mod typed_ast;
+pub use typed_ast::*;
+
+mod unify;
+
 #[cfg(test)]
 mod tests;
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct TypeChecker {
     pub evaluator: Evaluator,
     pub aux_remover: AuxDataRemover,
+    pub metas: MetaStore,
+    pub hole_goals: HoleGoalStore,
+    pub error_recovery: ErrorRecovery,
+    pub type_recorder: TypeRecorder,
+    pub type_cache: TypeCache,
+    /// Whether a top-level call (e.g., `get_type`) is in progress.
+    /// Metas are scoped to a single top-level call.
+    is_in_top_level_call: bool,
+}
+
+impl Default for TypeChecker {
+    fn default() -> Self {
+        Self::with_interner(Interner::new())
+    }
 }
 
 impl TypeChecker {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Creates a `TypeChecker` whose evaluator and aux data remover
+    /// both intern their output with `interner`.
+    pub fn with_interner(interner: Interner) -> Self {
+        Self {
+            evaluator: Evaluator::with_interner(interner.clone()),
+            aux_remover: AuxDataRemover::with_interner(interner),
+            metas: MetaStore::default(),
+            hole_goals: HoleGoalStore::default(),
+            error_recovery: ErrorRecovery::default(),
+            type_recorder: TypeRecorder::default(),
+            type_cache: TypeCache::default(),
+            is_in_top_level_call: false,
+        }
+    }
 }
 
 impl minimal_ast::Expr {
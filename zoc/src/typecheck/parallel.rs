@@ -0,0 +1,101 @@
+use super::*;
+
+use crate::eval::SharedEvalCache;
+
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// Typechecks independent expressions
+/// (e.g., the top-level definitions of a module)
+/// on a pool of threads.
+///
+/// Each thread has its own `TypeChecker`,
+/// but all threads share one evaluation cache,
+/// so an `ind` that appears in many definitions
+/// is only normalized once.
+#[derive(Clone, Debug)]
+pub struct ParallelTypeChecker {
+    pub thread_count: NonZeroUsize,
+    pub shared_eval_cache: SharedEvalCache,
+}
+
+impl Default for ParallelTypeChecker {
+    fn default() -> Self {
+        Self::new(thread::available_parallelism().unwrap_or(NonZeroUsize::MIN))
+    }
+}
+
+impl ParallelTypeChecker {
+    pub fn new(thread_count: NonZeroUsize) -> Self {
+        Self {
+            thread_count,
+            shared_eval_cache: SharedEvalCache::new(),
+        }
+    }
+
+    /// Typechecks each expression under the empty type context.
+    /// The results are in the same order as `exprs`.
+    ///
+    /// The expressions are checked independently,
+    /// so metas and holes are scoped to a single expression.
+    pub fn get_types<A>(&self, exprs: &[ast::Expr<A>]) -> Vec<Result<NormalForm, TypeError<A>>>
+    where
+        A: AuxDataFamily,
+        ast::Expr<A>: Send + Sync,
+        TypeError<A>: Send,
+    {
+        let next_index = AtomicUsize::new(0);
+        let thread_count = self.thread_count.get().min(exprs.len());
+
+        let indexed_results: Vec<(usize, Result<NormalForm, TypeError<A>>)> =
+            thread::scope(|scope| {
+                let workers: Vec<_> = (0..thread_count)
+                    .map(|_| scope.spawn(|| self.run_worker(exprs, &next_index)))
+                    .collect();
+                workers
+                    .into_iter()
+                    .flat_map(|worker| {
+                        worker
+                            .join()
+                            .unwrap_or_else(|e| std::panic::resume_unwind(e))
+                    })
+                    .collect()
+            });
+
+        let mut results: Vec<Option<Result<NormalForm, TypeError<A>>>> =
+            (0..exprs.len()).map(|_| None).collect();
+        for (index, result) in indexed_results {
+            results[index] = Some(result);
+        }
+        results
+            .into_iter()
+            .map(|result| result.expect("every expression should be claimed by a worker"))
+            .collect()
+    }
+
+    fn run_worker<A: AuxDataFamily>(
+        &self,
+        exprs: &[ast::Expr<A>],
+        next_index: &AtomicUsize,
+    ) -> Vec<(usize, Result<NormalForm, TypeError<A>>)> {
+        let empty = Normalized::<[_; 0]>::new();
+        let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+
+        let mut typechecker = TypeChecker::default();
+        typechecker.evaluator.shared_cache = Some(self.shared_eval_cache.clone());
+
+        let mut out = vec![];
+        loop {
+            let index = next_index.fetch_add(1, Ordering::Relaxed);
+            let Some(expr) = exprs.get(index) else {
+                return out;
+            };
+
+            typechecker.hole_goals.clear();
+            out.push((index, typechecker.get_type(expr.clone(), tcon)));
+        }
+    }
+}
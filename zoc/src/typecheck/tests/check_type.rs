@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn u64_add_app() {
+    let src = r#"(u64_add 2u64 3u64)"#;
+    check_type_under_empty_tcon_or_panic(src, "U64");
+}
+
+#[test]
+fn polymorphic_identity_app() {
+    let src = r#"((fun nonrec (Set0 0) 1 1) U64 7u64)"#;
+    check_type_under_empty_tcon_or_panic(src, "U64");
+}
+
+#[test]
+fn wrong_expected_type() {
+    let src = r#"(u64_add 2u64 3u64)"#;
+    let err = get_check_type_error_under_empty_tcon_or_panic(src, "String");
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn mismatch_in_nested_app_arg() {
+    let src = r#"(u64_add (u64_mul 2u64 (string_len (string_concat "a" 3u64))) 1u64)"#;
+    let err = get_check_type_error_under_empty_tcon_or_panic(src, "U64");
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn mismatch_in_fun_return_val() {
+    let src = r#"(fun nonrec (U64) U64 (u64_add 1 (string_len 1)))"#;
+    let err = get_check_type_error_under_empty_tcon_or_panic(src, "(for (U64) U64)");
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn mismatch_in_match_case_return_val() {
+    let bool_def = (
+        "<BOOL>",
+        r#"
+(ind Set0 "Bool" () (
+    (() ())
+    (() ())
+))"#,
+    );
+    let unsubstituted_src = r#"
+(fun nonrec (<BOOL>) U64
+    (match 1 1 U64 (
+        (0 0u64)
+        (0 (u64_add 1u64 "one"))
+    ))
+)"#;
+    let src = substitute_with_compounding([bool_def], unsubstituted_src);
+    let expected_type_src = substitute_with_compounding([bool_def], "(for (<BOOL>) U64)");
+    let err = get_check_type_error_under_empty_tcon_or_panic(&src, &expected_type_src);
+    insta::assert_debug_snapshot!(err);
+}
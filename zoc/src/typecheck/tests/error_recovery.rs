@@ -0,0 +1,137 @@
+use super::*;
+
+use crate::{
+    pretty_print::PrettyUnwrapErr,
+    syntax_tree::{ast::prelude::spanned_ast, parser::parse_src_with_recovery},
+};
+
+const BOOL_DEF: (&str, &str) = (
+    "<BOOL>",
+    r#"
+(ind Set0 "Bool" () (
+    (() ())
+    (() ())
+))"#,
+);
+
+#[test]
+fn independent_app_args() {
+    let src = r#"((fun nonrec (U64 String) U64 2) "a" 3u64)"#;
+    let errors = get_type_errors_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn recovered_app_arg_causes_no_follow_on_errors() {
+    let src = r#"((fun nonrec (Set0 0) U64 5u64) 3u64 "a")"#;
+    let errors = get_type_errors_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn match_cases() {
+    let unsubstituted_src = r#"
+(fun nonrec (<BOOL>) U64
+    (match 1 1 U64 (
+        (0 "true")
+        (0 "false")
+    ))
+)"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+    let errors = get_type_errors_under_empty_tcon_or_panic(&src);
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn vcon_defs() {
+    let src = r#"
+(ind Set0 "Foo" () (
+    ((3u64) ())
+    ((Set0) ())
+    (("a") ())
+))"#;
+    let errors = get_type_errors_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn errors_in_different_constructs() {
+    let unsubstituted_src = r#"
+((fun nonrec (<BOOL> U64) U64
+    (match 2 1 U64 (
+        (0 "true")
+        (0 2)
+    ))
+) 5u64 "b")"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+    let errors = get_type_errors_under_empty_tcon_or_panic(&src);
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn well_typed_expression_has_no_errors() {
+    let src = r#"((fun nonrec (U64 String) U64 2) 3u64 "a")"#;
+    let spanned = parse_spanned_ast_or_panic(src);
+    let empty = Normalized::<[_; 0]>::new();
+    let type_ = crate::typecheck::TypeChecker::default()
+        .get_type_collecting_errors(spanned, LazyTypeContext::Base(empty.as_ref().convert_ref()))
+        .unwrap_or_else(|errors| panic!("Unexpected errors: {errors:#?}"));
+    insta::assert_display_snapshot!(PrettyPrint(type_.raw()));
+}
+
+#[test]
+fn recovered_app_arg_does_not_hide_mismatch_elsewhere_in_type() {
+    let src = r#"((fun nonrec (Set0 (for (U64) 1)) U64 5u64) 3u64 "a")"#;
+    let errors = get_type_errors_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(errors);
+}
+
+/// Parses `src`, replacing unparsable code with placeholders.
+fn parse_spanned_ast_with_syntax_errors(src: &str) -> spanned_ast::Expr {
+    let (cst, errors) = parse_src_with_recovery(src);
+    assert!(!errors.is_empty(), "Expected syntax errors in {src:?}");
+    cst.into()
+}
+
+#[test]
+fn invalid_code_causes_no_follow_on_errors() {
+    let src = r#"((fun nonrec (Set0 0) U64 5u64) (fun) 3u64)"#;
+    let spanned = parse_spanned_ast_with_syntax_errors(src);
+    let empty = Normalized::<[_; 0]>::new();
+    let errors = crate::typecheck::TypeChecker::default()
+        .get_type_collecting_errors(spanned, LazyTypeContext::Base(empty.as_ref().convert_ref()))
+        .map(Normalized::into_raw)
+        .pretty_unwrap_err();
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn invalid_code_is_rejected_by_get_type() {
+    let src = r#"((fun nonrec (Set0 0) U64 5u64) (fun) 3u64)"#;
+    let spanned = parse_spanned_ast_with_syntax_errors(src);
+    let empty = Normalized::<[_; 0]>::new();
+    let err = crate::typecheck::TypeChecker::default()
+        .get_type(spanned, LazyTypeContext::Base(empty.as_ref().convert_ref()))
+        .map(Normalized::into_raw)
+        .pretty_unwrap_err();
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn invalid_code_in_unchecked_match_return_type_is_reported() {
+    let unsubstituted_src = r#"
+(fun nonrec (<BOOL>) U64
+    (match 1 1 (fun) (
+        (0 5u64)
+        (0 6u64)
+    ))
+)"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+    let spanned = parse_spanned_ast_with_syntax_errors(&src);
+    let empty = Normalized::<[_; 0]>::new();
+    let errors = crate::typecheck::TypeChecker::default()
+        .get_type_collecting_errors(spanned, LazyTypeContext::Base(empty.as_ref().convert_ref()))
+        .map(Normalized::into_raw)
+        .pretty_unwrap_err();
+    insta::assert_debug_snapshot!(errors);
+}
@@ -0,0 +1,53 @@
+use super::*;
+
+use crate::pretty_print::PrettyUnwrapErr;
+
+#[test]
+fn hole_as_fun_body() {
+    let src = r#"(fun nonrec (Set0 0 U64) U64 ?)"#;
+    let goals = get_hole_goals_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(goals);
+}
+
+#[test]
+fn hole_as_app_arg() {
+    let src = r#"((fun nonrec (Set0 0 1) 2 2) U64 ? 3u64)"#;
+    let goals = get_hole_goals_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(goals);
+}
+
+#[test]
+fn hole_expected_type_is_zonked() {
+    let src = r#"((fun nonrec (Set0 0 1) 2 2) _0 ? 3u64)"#;
+    let goals = get_hole_goals_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(goals);
+}
+
+#[test]
+fn cannot_infer_hole_type() {
+    let src = r#"(? 3u64)"#;
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn hole_in_checking_position_is_rejected_by_get_type() {
+    let src = r#"((fun nonrec (Set0 0 1) 2 2) U64 ? 3u64)"#;
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn invalid_code_is_not_a_hole_goal() {
+    let src = r#"((fun nonrec (Set0 0 1) 2 2) U64 (fun) 3u64)"#;
+    let (cst, _) = crate::syntax_tree::parser::parse_src_with_recovery(src);
+    let empty = Normalized::<[_; 0]>::new();
+    let err = crate::typecheck::TypeChecker::default()
+        .get_type_and_hole_goals(
+            cst.into(),
+            LazyTypeContext::Base(empty.as_ref().convert_ref()),
+        )
+        .map(|(type_, _)| type_.into_raw())
+        .pretty_unwrap_err();
+    insta::assert_debug_snapshot!(err);
+}
@@ -0,0 +1,101 @@
+use super::*;
+
+fn iszo_defs() -> [(&'static str, &'static str); 4] {
+    [
+        ("<NAT>", r#"(ind Set0 "Nat" () ((() ()) ((0) ())))"#),
+        ("<ZERO>", "(vcon <NAT> 0)"),
+        ("<SUCC>", "(vcon <NAT> 1)"),
+        // `IsZeroOrOne n` holds if `n` is `zero` or `(succ zero)`.
+        (
+            "<ISZO>",
+            r#"
+(ind Prop0 "IsZeroOrOne" (<NAT>) (
+    (() (<ZERO>))
+    (() ((<SUCC> <ZERO>)))
+))"#,
+        ),
+    ]
+}
+
+#[test]
+fn disjoint_vcon_case_can_be_omitted() {
+    let unsubstituted_src = r#"
+(fun nonrec ((<ISZO> <ZERO>)) U64
+    (match 1 2 U64 (
+        (0 1u64)
+        (0)
+    ))
+)"#;
+    let src = substitute_with_compounding(iszo_defs(), unsubstituted_src);
+    let expected_type_src = substitute_with_compounding(iszo_defs(), "(for ((<ISZO> <ZERO>)) U64)");
+    check_type_under_empty_tcon_or_panic(&src, &expected_type_src);
+}
+
+#[test]
+fn injectivity_refines_return_type_and_rules_out_cases() {
+    // In the only possible case, `(succ zero) = (succ m)`,
+    // so `m` is solved as `zero`.
+    let unsubstituted_src = r#"
+(fun nonrec (<NAT> (<ISZO> (<SUCC> 0))) (<ISZO> (<SUCC> 1))
+    (match 1 2 (<ISZO> (<SUCC> 4)) (
+        (0)
+        (0 (vcon <ISZO> 1))
+    ))
+)"#;
+    let src = substitute_with_compounding(iszo_defs(), unsubstituted_src);
+    let expected_type_src = substitute_with_compounding(
+        iszo_defs(),
+        "(for (<NAT> (<ISZO> (<SUCC> 0))) (<ISZO> (<SUCC> 1)))",
+    );
+    check_type_under_empty_tcon_or_panic(&src, &expected_type_src);
+}
+
+#[test]
+fn solved_deb_refines_return_type() {
+    let unsubstituted_src = r#"
+(fun nonrec (<NAT> (<ISZO> 0)) (<ISZO> 1)
+    (match 1 2 (<ISZO> 4) (
+        (0 (vcon <ISZO> 0))
+        (0 (vcon <ISZO> 1))
+    ))
+)"#;
+    let src = substitute_with_compounding(iszo_defs(), unsubstituted_src);
+    let expected_type_src =
+        substitute_with_compounding(iszo_defs(), "(for (<NAT> (<ISZO> 0)) (<ISZO> 1))");
+    check_type_under_empty_tcon_or_panic(&src, &expected_type_src);
+}
+
+#[test]
+fn match_with_omitted_case_evaluates() {
+    let unsubstituted_src = r#"
+(
+    (fun nonrec ((<ISZO> <ZERO>)) U64
+        (match 1 2 U64 (
+            (0 1u64)
+            (0)
+        ))
+    )
+    (vcon <ISZO> 0)
+)"#;
+    let src = substitute_with_compounding(iszo_defs(), unsubstituted_src);
+    let actual = typecheck_and_eval_under_empty_tcon_or_panic(&src).into_raw();
+    let expected = parse_minimal_ast_or_panic("1u64");
+    assert_eq!(
+        PrettyPrint(&expected).to_string(),
+        PrettyPrint(&actual).to_string()
+    );
+}
+
+#[test]
+fn possible_case_cannot_be_omitted() {
+    let unsubstituted_src = r#"
+(fun nonrec (<NAT> (<ISZO> 0)) U64
+    (match 1 2 U64 (
+        (0 1u64)
+        (0)
+    ))
+)"#;
+    let src = substitute_with_compounding(iszo_defs(), unsubstituted_src);
+    let err = get_type_error_under_empty_tcon_or_panic(&src);
+    insta::assert_debug_snapshot!(err);
+}
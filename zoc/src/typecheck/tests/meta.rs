@@ -0,0 +1,75 @@
+use super::*;
+
+use crate::{pretty_print::PrettyUnwrap, typecheck::TypeChecker};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn implicit_type_arg() {
+    let src = r#"((fun nonrec (Set0 0) 1 1) _0 7u64)"#;
+    let elaboration = elaborate_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(PrettyPrint(&elaboration.expr));
+    insta::assert_display_snapshot!(PrettyPrint(elaboration.type_.raw()));
+}
+
+#[test]
+fn meta_solved_by_later_arg_is_reused() {
+    let src = r#"((fun nonrec (Set0 0 1) 2 2) _0 7u64 8u64)"#;
+    let elaboration = elaborate_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(PrettyPrint(&elaboration.expr));
+}
+
+#[test]
+fn pattern_unification_under_binder() {
+    let src = r#"
+((fun nonrec ((for (U64) Set0) (for (U64) (1 0))) (1 0u64) (1 0u64))
+    _0
+    (fun nonrec (U64) String "a")
+)"#;
+    let elaboration = elaborate_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(PrettyPrint(&elaboration.expr));
+    insta::assert_display_snapshot!(PrettyPrint(elaboration.type_.raw()));
+}
+
+#[test]
+fn mismatch_after_meta_is_solved() {
+    let src = r#"((fun nonrec (Set0 0 1) 2 2) _0 7u64 "eight")"#;
+    let err = get_elaboration_error_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn unsolved_meta() {
+    let src = r#"((fun nonrec (Set0 U64) U64 1) _0 3u64)"#;
+    let err = get_elaboration_error_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn cannot_infer_meta_type() {
+    let src = r#"(_0 3u64)"#;
+    let err = get_elaboration_error_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn get_type_rejects_unsolved_meta() {
+    let src = r#"((fun nonrec (Set0 U64) U64 1) _0 3u64)"#;
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn solutions_do_not_leak_between_calls() {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    let mut typechecker = TypeChecker::default();
+
+    let first = parse_spanned_ast_or_panic(r#"((fun nonrec (Set0 0) 1 1) _0 7u64)"#);
+    let first_type = typechecker.get_type(first, tcon).pretty_unwrap();
+    assert_eq!(eval_or_panic("U64"), first_type);
+
+    let second = parse_spanned_ast_or_panic(r#"((fun nonrec (Set0 0) 1 1) _0 "s")"#);
+    let second_type = typechecker.get_type(second, tcon).pretty_unwrap();
+    assert_eq!(eval_or_panic("String"), second_type);
+}
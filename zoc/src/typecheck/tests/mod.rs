@@ -2,8 +2,18 @@ use crate::{
     eval::Normalized, pretty_print::PrettyPrint, test_utils::*, typecheck::LazyTypeContext,
 };
 
+mod check_type;
+mod error_recovery;
 mod fun_recursion;
+mod hole;
+mod impossible_cases;
+mod meta;
+#[cfg(feature = "sync")]
+mod parallel;
 mod positivity;
+mod prim;
+mod type_cache;
+mod typed_ast;
 
 // General tests
 mod should_fail;
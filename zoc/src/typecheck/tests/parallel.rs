@@ -0,0 +1,97 @@
+use super::*;
+
+use crate::{
+    hash::GetDigest,
+    pretty_print::PrettyUnwrap,
+    syntax_tree::ast::prelude::*,
+    typecheck::{ParallelTypeChecker, TypeChecker},
+};
+
+use std::num::NonZeroUsize;
+
+const NAT_DEF: (&str, &str) = (
+    "<NAT>",
+    r#"(ind Set0 "Nat" () (
+    (() ())
+    ((0) ())
+))"#,
+);
+
+const ADD_DEF: (&str, &str) = (
+    "<ADD>",
+    r#"(fun 0 (<NAT> <NAT>) <NAT>
+    (match 2 1 <NAT> (
+        (0 1)
+        (1 ((vcon <NAT> 1) (1 0 2)))
+    ))
+)"#,
+);
+
+fn parse_defs(unsubstituted_srcs: &[&str]) -> Vec<spanned_ast::Expr> {
+    unsubstituted_srcs
+        .iter()
+        .map(|src| {
+            let src = substitute_with_compounding([NAT_DEF, ADD_DEF], src);
+            parse_spanned_ast_or_panic(&src)
+        })
+        .collect()
+}
+
+#[test]
+fn parallel_results_match_sequential_results() {
+    let defs = parse_defs(&[
+        "<NAT>",
+        "<ADD>",
+        "(vcon <NAT> 0)",
+        "(<ADD> ((vcon <NAT> 1) (vcon <NAT> 0)) (vcon <NAT> 0))",
+        r#"((fun nonrec (U64) U64 1) "a")"#,
+        "Set0",
+        "(for (Set0) Set0)",
+    ]);
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+
+    let parallel_results = ParallelTypeChecker::new(NonZeroUsize::new(3).unwrap()).get_types(&defs);
+
+    assert_eq!(defs.len(), parallel_results.len());
+    for (def, parallel_result) in defs.iter().zip(parallel_results) {
+        let sequential_result = TypeChecker::default().get_type(def.clone(), tcon);
+        match (parallel_result, sequential_result) {
+            (Ok(parallel_type), Ok(sequential_type)) => {
+                assert_eq!(parallel_type.raw().digest(), sequential_type.raw().digest())
+            }
+            (Err(_), Err(_)) => {}
+            (parallel_result, sequential_result) => panic!(
+                "Parallel and sequential results differ.\nParallel: {:?}\nSequential: {:?}",
+                parallel_result.map(|type_| type_.raw().digest().clone()),
+                sequential_result.map(|type_| type_.raw().digest().clone()),
+            ),
+        }
+    }
+}
+
+#[test]
+fn shared_eval_cache_is_populated() {
+    let defs = parse_defs(&["<ADD>", "(vcon <NAT> 0)"]);
+    let checker = ParallelTypeChecker::new(NonZeroUsize::new(2).unwrap());
+    assert!(checker.shared_eval_cache.is_empty());
+
+    for result in checker.get_types(&defs) {
+        result.pretty_unwrap();
+    }
+
+    assert!(!checker.shared_eval_cache.is_empty());
+}
+
+#[test]
+fn empty_input_spawns_no_work() {
+    let results = ParallelTypeChecker::default().get_types::<minimal_ast::UnitAuxDataFamily>(&[]);
+    assert!(results.is_empty());
+}
+
+#[test]
+fn typechecker_and_evaluator_are_send_and_sync() {
+    fn assert_send_and_sync<T: Send + Sync>() {}
+    assert_send_and_sync::<crate::typecheck::TypeChecker>();
+    assert_send_and_sync::<crate::eval::Evaluator>();
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn u64_lit() {
+    let src = r#"42u64"#;
+    let type_ = get_type_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(PrettyPrint(type_.raw()));
+}
+
+#[test]
+fn string_lit() {
+    let src = r#""hello""#;
+    let type_ = get_type_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(PrettyPrint(type_.raw()));
+}
+
+#[test]
+fn u64_type() {
+    let src = r#"U64"#;
+    let type_ = get_type_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(PrettyPrint(type_.raw()));
+}
+
+#[test]
+fn string_len() {
+    let src = r#"string_len"#;
+    let type_ = get_type_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(PrettyPrint(type_.raw()));
+}
+
+#[test]
+fn u64_add_app() {
+    let src = r#"(u64_add 2u64 3u64)"#;
+    let type_ = get_type_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(PrettyPrint(type_.raw()));
+}
+
+#[test]
+fn fun_using_string_concat() {
+    let src = r#"(fun nonrec (String) String (string_concat 1 "!"))"#;
+    let type_ = get_type_under_empty_tcon_or_panic(src);
+    insta::assert_display_snapshot!(PrettyPrint(type_.raw()));
+}
+
+#[test]
+fn u64_add_string_arg() {
+    let src = r#"(u64_add 2u64 "3")"#;
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(err);
+}
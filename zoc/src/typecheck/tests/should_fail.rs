@@ -118,4 +118,31 @@ fn for_has_zero_params() {
     insta::assert_debug_snapshot!(err);
 }
 
+#[test]
+fn unknown_vcon_name() {
+    let src = r#"(vcon (ind Set0 "Bool" () (("true" () ()) ("false" () ()))) "yes")"#;
+    let err = get_type_error_under_empty_tcon_or_panic(src);
+
+    insta::assert_debug_snapshot!(err);
+}
+
+#[test]
+fn inds_that_differ_only_in_vcon_names_are_different_types() {
+    let bool_tf_def = (
+        "<BOOL_TF>",
+        r#"(ind Set0 "Bool" () (("true" () ()) ("false" () ())))"#,
+    );
+    let bool_yes_no_def = (
+        "<BOOL_YES_NO>",
+        r#"(ind Set0 "Bool" () (("yes" () ()) ("no" () ())))"#,
+    );
+    let unsubstituted_src = "((fun nonrec (<BOOL_TF>) U64 0u64) (vcon <BOOL_YES_NO> 0))";
+
+    let src_defs = [bool_tf_def, bool_yes_no_def];
+    let src = substitute_with_compounding(src_defs, unsubstituted_src);
+    let err = get_type_error_under_empty_tcon_or_panic(&src);
+
+    insta::assert_debug_snapshot!(err);
+}
+
 // TODO: Add more tests.
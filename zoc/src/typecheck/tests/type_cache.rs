@@ -0,0 +1,107 @@
+use super::*;
+
+use crate::{
+    eval::NormalForm,
+    hash::GetDigest,
+    pretty_print::{PrettyUnwrap, PrettyUnwrapErr},
+    typecheck::{TypeCache, TypeCacheStats, TypeChecker, TypeError},
+};
+
+const BOOL_DEF: (&str, &str) = (
+    "<BOOL>",
+    r#"(ind Set0 "Bool" () (
+    (() ())
+    (() ())
+))"#,
+);
+
+fn get_type_and_stats(src: &str, cache_enabled: bool) -> (NormalForm, TypeCacheStats) {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    let spanned = parse_spanned_ast_or_panic(src);
+    let mut typechecker = TypeChecker::default();
+    typechecker.type_cache.enabled = cache_enabled;
+    let type_ = typechecker.get_type(spanned, tcon).pretty_unwrap();
+    (type_, typechecker.type_cache.stats)
+}
+
+#[test]
+fn repeated_ind_hits_cache() {
+    let unsubstituted_src =
+        r#"((fun nonrec (<BOOL> <BOOL>) <BOOL> 1) (vcon <BOOL> 0) (vcon <BOOL> 1))"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+
+    let (cached_type, stats) = get_type_and_stats(&src, true);
+    let (uncached_type, uncached_stats) = get_type_and_stats(&src, false);
+
+    assert_eq!(cached_type.raw().digest(), uncached_type.raw().digest());
+    assert!(stats.hits > 0, "expected cache hits, but got {stats:?}");
+    assert_eq!(TypeCacheStats::default(), uncached_stats);
+}
+
+#[test]
+fn cleared_cache_is_like_a_new_cache() {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    let unsubstituted_src =
+        r#"((fun nonrec (<BOOL> <BOOL>) <BOOL> 1) (vcon <BOOL> 0) (vcon <BOOL> 1))"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+    let mut typechecker = TypeChecker::default();
+    typechecker
+        .get_type(parse_spanned_ast_or_panic(&src), tcon)
+        .pretty_unwrap();
+    assert!(!typechecker.type_cache.is_empty());
+
+    typechecker.type_cache.clear();
+    assert_eq!(
+        format!("{:?}", TypeCache::default()),
+        format!("{:?}", typechecker.type_cache)
+    );
+}
+
+#[test]
+fn open_expressions_are_not_cached() {
+    // Deb `1` has a different type in each fun,
+    // so it must not be cached.
+    let unsubstituted_src = r#"((fun nonrec (U64) U64 1) ((fun nonrec (<BOOL>) U64 3u64) ((fun nonrec (<BOOL>) <BOOL> 1) (vcon <BOOL> 0))))"#;
+    let src = substitute_with_compounding([BOOL_DEF], unsubstituted_src);
+
+    let (cached_type, _) = get_type_and_stats(&src, true);
+    let (uncached_type, _) = get_type_and_stats(&src, false);
+
+    assert_eq!(cached_type.raw().digest(), uncached_type.raw().digest());
+}
+
+#[test]
+fn ill_typed_expression_is_not_cached() {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    let src = r#"((fun nonrec (U64) U64 1) "a")"#;
+    let mut typechecker = TypeChecker::default();
+
+    for _ in 0..2 {
+        let spanned = parse_spanned_ast_or_panic(src);
+        let err = typechecker
+            .get_type(spanned, tcon)
+            .map(Normalized::into_raw)
+            .pretty_unwrap_err();
+        assert!(matches!(err, TypeError::TypeMismatch { .. }));
+    }
+}
+
+#[test]
+fn expressions_with_metas_are_not_cached() {
+    let empty = Normalized::<[_; 0]>::new();
+    let tcon = LazyTypeContext::Base(empty.as_ref().convert_ref());
+    let src = r#"((fun nonrec (Set0 0) 1 1) _0 3u64)"#;
+    let mut typechecker = TypeChecker::default();
+
+    for _ in 0..2 {
+        let spanned = parse_spanned_ast_or_panic(src);
+        let elaboration = typechecker.elaborate(spanned, tcon).pretty_unwrap();
+        assert_eq!(
+            eval_or_panic("U64").raw().digest(),
+            elaboration.type_.raw().digest()
+        );
+    }
+}
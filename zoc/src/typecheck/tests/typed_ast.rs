@@ -0,0 +1,255 @@
+use super::*;
+
+use crate::{
+    hash::GetDigest,
+    pretty_print::{PrettyUnwrap, PrettyUnwrapErr},
+    syntax_tree::ast::prelude::{
+        ast, minimal_ast, spanned_ast::SpanAuxDataFamily, typed_ast, Span,
+    },
+    typecheck::TypeChecker,
+};
+
+/// Returns each node's source text and type, in preorder.
+fn get_node_types(
+    src: &str,
+    expr: &typed_ast::Expr<SpanAuxDataFamily>,
+) -> Vec<(String, minimal_ast::Expr)> {
+    let mut out = vec![];
+    collect_node_types(src, expr, &mut out);
+    out
+}
+
+fn collect_node_types(
+    src: &str,
+    expr: &typed_ast::Expr<SpanAuxDataFamily>,
+    out: &mut Vec<(String, minimal_ast::Expr)>,
+) {
+    let span = get_span(expr);
+    // The span of a parenthesized node ends at its closing paren.
+    let end = if src[span.0 .0..].starts_with('(') {
+        span.1 .0 + 1
+    } else {
+        span.1 .0
+    };
+    out.push((src[span.0 .0..end].to_string(), expr.type_().raw().clone()));
+
+    match expr {
+        ast::Expr::Ind(e) => collect_in_ind(src, &e.hashee, out),
+        ast::Expr::Vcon(e) => {
+            collect_node_types(src, &ast::Expr::Ind(e.hashee.ind.clone()), out);
+        }
+        ast::Expr::Match(e) => {
+            collect_node_types(src, &e.hashee.matchee, out);
+            collect_node_types(src, &e.hashee.return_type, out);
+            for case in e.hashee.cases.hashee.iter() {
+                if let Some(return_val) = &case.return_val {
+                    collect_node_types(src, return_val, out);
+                }
+            }
+        }
+        ast::Expr::Fun(e) => {
+            collect_in_each(src, &e.hashee.param_types.hashee, out);
+            collect_node_types(src, &e.hashee.return_type, out);
+            collect_node_types(src, &e.hashee.return_val, out);
+        }
+        ast::Expr::App(e) => {
+            collect_node_types(src, &e.hashee.callee, out);
+            collect_in_each(src, &e.hashee.args.hashee, out);
+        }
+        ast::Expr::For(e) => {
+            collect_in_each(src, &e.hashee.param_types.hashee, out);
+            collect_node_types(src, &e.hashee.return_type, out);
+        }
+        ast::Expr::Deb(_)
+        | ast::Expr::Universe(_)
+        | ast::Expr::Lit(_)
+        | ast::Expr::Prim(_)
+        | ast::Expr::Meta(_)
+        | ast::Expr::Hole(_) => {}
+    }
+}
+
+fn collect_in_ind(
+    src: &str,
+    ind: &typed_ast::Ind<SpanAuxDataFamily>,
+    out: &mut Vec<(String, minimal_ast::Expr)>,
+) {
+    collect_in_each(src, &ind.index_types.hashee, out);
+    for def in ind.vcon_defs.hashee.iter() {
+        collect_in_each(src, &def.param_types.hashee, out);
+        collect_in_each(src, &def.index_args.hashee, out);
+    }
+}
+
+fn collect_in_each(
+    src: &str,
+    exprs: &[typed_ast::Expr<SpanAuxDataFamily>],
+    out: &mut Vec<(String, minimal_ast::Expr)>,
+) {
+    for expr in exprs {
+        collect_node_types(src, expr, out);
+    }
+}
+
+fn get_span(expr: &typed_ast::Expr<SpanAuxDataFamily>) -> Span {
+    match expr {
+        ast::Expr::Ind(e) => e.hashee.aux_data.inner.span,
+        ast::Expr::Vcon(e) => e.hashee.aux_data.inner.span,
+        ast::Expr::Match(e) => e.hashee.aux_data.inner.span,
+        ast::Expr::Fun(e) => e.hashee.aux_data.inner.span,
+        ast::Expr::App(e) => e.hashee.aux_data.inner,
+        ast::Expr::For(e) => e.hashee.aux_data.inner.span,
+        ast::Expr::Deb(e) => e.hashee.aux_data.inner,
+        ast::Expr::Universe(e) => e.hashee.aux_data.inner,
+        ast::Expr::Lit(e) => e.hashee.aux_data.inner,
+        ast::Expr::Prim(e) => e.hashee.aux_data.inner,
+        ast::Expr::Meta(e) => e.hashee.aux_data.inner,
+        ast::Expr::Hole(e) => e.hashee.aux_data.inner,
+    }
+}
+
+#[test]
+fn root_type_matches_get_type() {
+    let src = r#"(fun nonrec (Set0 0) 1 1)"#;
+    let typed = get_typed_ast_under_empty_tcon_or_panic(src);
+    assert_eq!(
+        get_type_under_empty_tcon_or_panic(src).raw().digest(),
+        typed.type_().raw().digest()
+    );
+}
+
+#[test]
+fn fun_and_app() {
+    let src = r#"((fun nonrec (Set0 0) 1 1) U64 (u64_add 3u64 4u64))"#;
+    let typed = get_typed_ast_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(get_node_types(src, &typed));
+}
+
+#[test]
+fn ind_vcon_and_match() {
+    let unsubstituted_src = r#"(fun nonrec (<BOOL>) U64
+    (match 1 1 U64 (
+        (0 3u64)
+        (0 4u64)
+    ))
+)"#;
+    let src = substitute_with_compounding(
+        [("<BOOL>", r#"(ind Set0 "Bool" () ((() ()) (() ())))"#)],
+        unsubstituted_src,
+    );
+    let typed = get_typed_ast_under_empty_tcon_or_panic(&src);
+    insta::assert_debug_snapshot!(get_node_types(&src, &typed));
+}
+
+#[test]
+fn dependent_param_types() {
+    let src = r#"(for (Set0 0 (for (1) 2)) 2)"#;
+    let typed = get_typed_ast_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(get_node_types(src, &typed));
+}
+
+#[test]
+fn metas_are_zonked() {
+    let src = r#"((fun nonrec (Set0 0) 1 1) _0 3u64)"#;
+    let typed = get_typed_ast_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(get_node_types(src, &typed));
+}
+
+#[test]
+fn hole_has_expected_type() {
+    let src = r#"((fun nonrec (String U64) U64 1) "a" ?)"#;
+    let typed = get_typed_ast_under_empty_tcon_or_panic(src);
+    insta::assert_debug_snapshot!(get_node_types(src, &typed));
+}
+
+/// Returns the type of each deb, in preorder.
+fn get_deb_types(expr: &typed_ast::Expr) -> Vec<minimal_ast::Expr> {
+    let mut out = vec![];
+    collect_deb_types(expr, &mut out);
+    out
+}
+
+fn collect_deb_types(expr: &typed_ast::Expr, out: &mut Vec<minimal_ast::Expr>) {
+    let children: Vec<typed_ast::Expr> = match expr {
+        ast::Expr::Deb(e) => {
+            out.push(e.hashee.aux_data.type_.raw().clone());
+            vec![]
+        }
+        ast::Expr::Match(e) => std::iter::once(e.hashee.matchee.clone())
+            .chain(std::iter::once(e.hashee.return_type.clone()))
+            .chain(
+                e.hashee
+                    .cases
+                    .hashee
+                    .iter()
+                    .filter_map(|case| case.return_val.clone()),
+            )
+            .collect(),
+        ast::Expr::Fun(e) => e
+            .hashee
+            .param_types
+            .hashee
+            .iter()
+            .cloned()
+            .chain([e.hashee.return_type.clone(), e.hashee.return_val.clone()])
+            .collect(),
+        ast::Expr::App(e) => std::iter::once(e.hashee.callee.clone())
+            .chain(e.hashee.args.hashee.iter().cloned())
+            .collect(),
+        ast::Expr::For(e) => e
+            .hashee
+            .param_types
+            .hashee
+            .iter()
+            .cloned()
+            .chain(std::iter::once(e.hashee.return_type.clone()))
+            .collect(),
+        _ => vec![],
+    };
+    for child in &children {
+        collect_deb_types(child, out);
+    }
+}
+
+#[test]
+fn shared_deb_has_the_type_of_each_occurrence() {
+    // The two `1`s are interned into one node,
+    // but the outer one is a `U64`, and the inner one is a `String`.
+    let src = r#"
+(fun nonrec (String U64) U64
+    (u64_add 1 ((fun nonrec (String) U64 (string_len 1)) "s"))
+)"#;
+    let expr = parse_minimal_ast_or_panic(src);
+    let empty = Normalized::<[_; 0]>::new();
+    let typed = TypeChecker::default()
+        .get_typed_ast(expr, LazyTypeContext::Base(empty.as_ref().convert_ref()))
+        .pretty_unwrap();
+    assert_eq!(
+        vec![
+            eval_or_panic("U64").into_raw(),
+            eval_or_panic("String").into_raw()
+        ],
+        get_deb_types(&typed)
+    );
+}
+
+#[test]
+fn ill_typed_match_return_type_is_reported() {
+    let unsubstituted_src = r#"(fun nonrec (<BOOL>) U64
+    (match 1 1 3u64 (
+        (0 3u64)
+        (0 4u64)
+    ))
+)"#;
+    let src = substitute_with_compounding(
+        [("<BOOL>", r#"(ind Set0 "Bool" () ((() ()) (() ())))"#)],
+        unsubstituted_src,
+    );
+    let spanned = parse_spanned_ast_or_panic(&src);
+    let empty = Normalized::<[_; 0]>::new();
+    let err = TypeChecker::default()
+        .get_typed_ast(spanned, LazyTypeContext::Base(empty.as_ref().convert_ref()))
+        .map(|typed| typed.type_().clone().into_raw())
+        .pretty_unwrap_err();
+    insta::assert_debug_snapshot!(err);
+}
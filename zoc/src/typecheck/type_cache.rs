@@ -0,0 +1,204 @@
+use super::*;
+
+/// Caches the types of closed expressions.
+///
+/// A closed expression (i.e., one with no free debs)
+/// has the same type in every type context,
+/// so its type can be keyed by its digest alone.
+/// Expressions that contain metas or holes are never cached,
+/// since typechecking them has side effects
+/// (solving metas and recording hole goals, respectively).
+///
+/// The cache is bypassed while recovering from errors
+/// or recording types, since both rely on visiting every node.
+#[derive(Clone, Debug)]
+pub struct TypeCache {
+    pub enabled: bool,
+    pub stats: TypeCacheStats,
+    types: NoHashHashMap<Digest, NormalForm>,
+    /// See `get_scope_requirement`.
+    scope_requirements: NoHashHashMap<Digest, Option<usize>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TypeCacheStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl Default for TypeCache {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            stats: TypeCacheStats::default(),
+            types: NoHashHashMap::default(),
+            scope_requirements: NoHashHashMap::default(),
+        }
+    }
+}
+
+impl TypeCache {
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Empties the cache (keeping whether it is enabled).
+    pub fn clear(&mut self) {
+        *self = TypeCache {
+            enabled: self.enabled,
+            ..TypeCache::default()
+        };
+    }
+
+    /// Returns `Some(n)` if every free deb in `expr` is less than `n`
+    /// (so `Some(0)` means `expr` is closed),
+    /// or `None` if `expr` contains a meta or a hole.
+    ///
+    /// Requirements are cached by digest,
+    /// which does not depend on aux data,
+    /// so every AST family shares the same entries.
+    fn get_scope_requirement<A: AuxDataFamily>(&mut self, expr: &ast::Expr<A>) -> Option<usize> {
+        let digest = expr.digest();
+        if let Some(requirement) = self.scope_requirements.get(digest) {
+            return *requirement;
+        }
+
+        let requirement = self.get_unseen_scope_requirement(expr);
+        self.scope_requirements.insert(digest.clone(), requirement);
+        requirement
+    }
+
+    fn get_unseen_scope_requirement<A: AuxDataFamily>(
+        &mut self,
+        expr: &ast::Expr<A>,
+    ) -> Option<usize> {
+        match expr {
+            ast::Expr::Ind(e) => self.get_ind_scope_requirement(&e.hashee),
+            ast::Expr::Vcon(e) => self.get_ind_scope_requirement(&e.hashee.ind.hashee),
+            ast::Expr::Match(e) => {
+                let mut requirement = self.get_scope_requirement(&e.hashee.matchee)?;
+                requirement = requirement.max(
+                    self.get_scope_requirement(&e.hashee.return_type)?
+                        .saturating_sub(e.hashee.return_type_arity),
+                );
+                for case in e.hashee.cases.hashee.iter() {
+                    let Some(return_val) = &case.return_val else {
+                        continue;
+                    };
+                    requirement = requirement.max(
+                        self.get_scope_requirement(return_val)?
+                            .saturating_sub(case.arity),
+                    );
+                }
+                Some(requirement)
+            }
+            ast::Expr::Fun(e) => {
+                let param_count = e.hashee.param_types.hashee.len();
+                let requirement =
+                    self.get_dependent_scope_requirement(&e.hashee.param_types.hashee)?;
+                let return_type_requirement = self
+                    .get_scope_requirement(&e.hashee.return_type)?
+                    .saturating_sub(param_count);
+                let return_val_requirement = self
+                    .get_scope_requirement(&e.hashee.return_val)?
+                    .saturating_sub(param_count + 1);
+                Some(
+                    requirement
+                        .max(return_type_requirement)
+                        .max(return_val_requirement),
+                )
+            }
+            ast::Expr::App(e) => {
+                let mut requirement = self.get_scope_requirement(&e.hashee.callee)?;
+                for arg in e.hashee.args.hashee.iter() {
+                    requirement = requirement.max(self.get_scope_requirement(arg)?);
+                }
+                Some(requirement)
+            }
+            ast::Expr::For(e) => {
+                let param_count = e.hashee.param_types.hashee.len();
+                let requirement =
+                    self.get_dependent_scope_requirement(&e.hashee.param_types.hashee)?;
+                let return_type_requirement = self
+                    .get_scope_requirement(&e.hashee.return_type)?
+                    .saturating_sub(param_count);
+                Some(requirement.max(return_type_requirement))
+            }
+            ast::Expr::Deb(e) => Some(e.hashee.deb.0 + 1),
+            ast::Expr::Universe(_) | ast::Expr::Lit(_) | ast::Expr::Prim(_) => Some(0),
+            ast::Expr::Meta(_) | ast::Expr::Hole(_) => None,
+        }
+    }
+
+    fn get_ind_scope_requirement<A: AuxDataFamily>(&mut self, ind: &ast::Ind<A>) -> Option<usize> {
+        let mut requirement = self.get_dependent_scope_requirement(&ind.index_types.hashee)?;
+        for def in ind.vcon_defs.hashee.iter() {
+            let param_count = def.param_types.hashee.len();
+            let param_types_requirement =
+                self.get_dependent_scope_requirement(&def.param_types.hashee)?;
+            let mut def_requirement = param_types_requirement;
+            for index_arg in def.index_args.hashee.iter() {
+                def_requirement = def_requirement.max(
+                    self.get_scope_requirement(index_arg)?
+                        .saturating_sub(param_count),
+                );
+            }
+            // Vcon defs can refer to the ind itself (as deb `param_count`).
+            requirement = requirement.max(def_requirement.saturating_sub(1));
+        }
+        Some(requirement)
+    }
+
+    /// Returns the scope requirement of a list of expressions
+    /// where each expression can refer to the previous ones.
+    fn get_dependent_scope_requirement<A: AuxDataFamily>(
+        &mut self,
+        exprs: &[ast::Expr<A>],
+    ) -> Option<usize> {
+        let mut requirement = 0;
+        for (i, expr) in exprs.iter().enumerate() {
+            requirement = requirement.max(self.get_scope_requirement(expr)?.saturating_sub(i));
+        }
+        Some(requirement)
+    }
+}
+
+impl TypeChecker {
+    /// Returns the key to cache `expr`'s type under,
+    /// or `None` if `expr`'s type should not be cached.
+    pub(super) fn get_type_cache_key<A: AuxDataFamily>(
+        &mut self,
+        expr: &ast::Expr<A>,
+    ) -> Option<Digest> {
+        if !self.type_cache.enabled
+            || self.error_recovery.is_enabled()
+            || self.type_recorder.is_enabled()
+        {
+            return None;
+        }
+
+        if self.type_cache.get_scope_requirement(expr) != Some(0) {
+            return None;
+        }
+
+        Some(expr.digest().clone())
+    }
+
+    pub(super) fn get_cached_type(&mut self, key: &Digest) -> Option<NormalForm> {
+        let cached = self.type_cache.types.get(key).cloned();
+        if cached.is_some() {
+            self.type_cache.stats.hits += 1;
+        } else {
+            self.type_cache.stats.misses += 1;
+        }
+        cached
+    }
+
+    pub(super) fn cache_type(&mut self, key: Digest, type_: NormalForm) {
+        self.type_cache.types.insert(key, type_);
+    }
+}
@@ -16,23 +16,8 @@ impl TypeChecker {
 
         self.assert_arg_count_is_correct(app.clone(), callee_type.clone())?;
 
-        let arg_types = self.get_types_of_independent_expressions(&app.hashee.args.hashee, tcon)?;
-
-        let args_minimal = self
-            .aux_remover
-            .convert_expressions(&app.hashee.args.hashee);
-        let normalized_args = self.evaluator.eval_expressions(args_minimal);
-
-        let substituted_callee_type_param_types = self.substitute_callee_type_param_types(
-            callee_type_param_types.clone(),
-            normalized_args.clone(),
-        );
-
-        self.assert_expected_type_equalities_holds(ExpectedTypeEqualities {
-            exprs: &app.hashee.args.hashee,
-            expected_types: substituted_callee_type_param_types.to_hashee().derefed(),
-            actual_types: arg_types.to_derefed(),
-        })?;
+        let normalized_args =
+            self.check_args_and_normalize(&app.hashee.args.hashee, callee_type_param_types, tcon)?;
 
         let substituted_callee_type_return_type =
             self.substitute_callee_type_return_type(callee_type_return_type_g0f, normalized_args);
@@ -86,6 +71,51 @@ impl TypeChecker {
         Ok(())
     }
 
+    /// Checks each argument against its corresponding
+    /// (substituted) parameter type, from left to right.
+    ///
+    /// Each parameter type is substituted using only the
+    /// arguments before it, which have already been checked.
+    /// This way, we never evaluate an ill-typed argument.
+    /// (If an argument is recovered from,
+    /// an error meta is used in place of its value.)
+    fn check_args_and_normalize<A: AuxDataFamily>(
+        &mut self,
+        args: &[ast::Expr<A>],
+        param_types: Normalized<RcHashedVec<minimal_ast::Expr>>,
+        tcon: LazyTypeContext,
+    ) -> Result<Normalized<RcHashedVec<minimal_ast::Expr>>, TypeError<A>> {
+        let mut normalized_args: Normalized<Vec<minimal_ast::Expr>> =
+            Normalized::with_capacity(args.len());
+
+        for (param_index, arg) in args.iter().enumerate() {
+            let unsubstituted_param_type = param_types
+                .to_hashee()
+                .derefed()
+                .index_ref(param_index)
+                .cloned();
+            let substituter = DebDownshiftSubstituter {
+                new_exprs: normalized_args.raw(),
+            };
+            let substituted = unsubstituted_param_type
+                .into_raw()
+                .replace_debs(&substituter, 0);
+            let substituted_param_type = self.evaluator.eval(substituted);
+
+            let result = self.check_type(arg.clone(), substituted_param_type, tcon);
+            if self.recover(result)?.is_none() {
+                let error_meta = self.error_meta_value();
+                normalized_args.push(error_meta);
+                continue;
+            }
+
+            let arg_minimal = self.aux_remover.convert(arg.clone());
+            normalized_args.push(self.evaluator.eval(arg_minimal));
+        }
+
+        Ok(normalized_args.into_rc_hashed())
+    }
+
     pub(in crate::typecheck) fn substitute_callee_type_param_types(
         &mut self,
         param_types: Normalized<RcHashedVec<minimal_ast::Expr>>,
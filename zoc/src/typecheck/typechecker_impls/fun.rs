@@ -47,17 +47,12 @@ impl TypeChecker {
 
         let normalized_return_type_g2 = normalized_return_type_g1.clone().upshift(1, 0);
 
-        let return_val_type_g2 = self.get_type(
+        self.check_type(
             fun_g0.hashee.return_val.clone(),
+            normalized_return_type_g2,
             tcon_with_param_types_and_fun_types_g2,
         )?;
 
-        self.assert_expected_type_equality_holds(ExpectedTypeEquality {
-            expr: fun_g0.hashee.return_val.clone(),
-            expected_type: normalized_return_type_g2,
-            actual_type: return_val_type_g2,
-        })?;
-
         Ok(Normalized::for_(
             normalized_param_types_g0.into_rc_hashed(),
             normalized_return_type_g1,
@@ -65,6 +60,47 @@ impl TypeChecker {
         .into())
     }
 
+    /// If `expected_type` is a `for` with as many params as `fun`,
+    /// this solves `fun`'s meta param types and meta return type
+    /// with the corresponding types of `expected_type`.
+    pub(super) fn push_expected_type_into_fun<A: AuxDataFamily>(
+        &mut self,
+        fun: RcHashed<ast::Fun<A>>,
+        expected_type: &NormalForm,
+        tcon: LazyTypeContext,
+    ) -> Result<(), TypeError<A>> {
+        if !matches!(expected_type.raw(), minimal_ast::Expr::For(_)) {
+            return Ok(());
+        }
+        let expected_param_types = expected_type.clone().for_param_types_or_empty_vec();
+        let param_types = &fun.hashee.param_types.hashee;
+        if expected_param_types.raw().hashee.len() != param_types.len() {
+            return Ok(());
+        }
+
+        let mut visited_expected_param_types: Normalized<Vec<minimal_ast::Expr>> =
+            Normalized::with_capacity(param_types.len());
+        for (param_index, param_type) in param_types.iter().enumerate() {
+            let expected_param_type = expected_param_types
+                .to_hashee()
+                .derefed()
+                .index_ref(param_index)
+                .cloned();
+            let current_tcon =
+                LazyTypeContext::Snoc(&tcon, visited_expected_param_types.to_derefed());
+            self.push_expected_annotation(param_type, expected_param_type.clone(), current_tcon)?;
+            visited_expected_param_types.push(expected_param_type);
+        }
+
+        let tcon_with_param_types =
+            LazyTypeContext::Snoc(&tcon, visited_expected_param_types.to_derefed());
+        self.push_expected_annotation(
+            &fun.hashee.return_type,
+            expected_type.clone().for_return_type_or_self(),
+            tcon_with_param_types,
+        )
+    }
+
     fn assert_fun_has_at_least_one_param<A: AuxDataFamily>(
         &self,
         fun: RcHashed<ast::Fun<A>>,
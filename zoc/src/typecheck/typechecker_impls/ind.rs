@@ -25,33 +25,44 @@ impl TypeChecker {
         let tcon_with_ind_type_g1 =
             LazyTypeContext::Snoc(&tcon_g0, ind_type_singleton.as_ref().convert_ref());
 
-        self.typecheck_ind_vcon_defs(
+        let are_vcon_defs_well_typed = self.typecheck_ind_vcon_defs(
             ind.clone(),
             normalized_index_types_g0,
             tcon_with_ind_type_g1,
         )?;
 
-        self.get_positivity_checker()
-            .check_ind_positivity_assuming_it_is_otherwise_well_typed(ind.clone(), tcon_g0.len())?;
+        if are_vcon_defs_well_typed {
+            self.get_positivity_checker()
+                .check_ind_positivity_assuming_it_is_otherwise_well_typed(
+                    ind.clone(),
+                    tcon_g0.len(),
+                )?;
+        }
 
         Ok(ind_type_g0)
     }
 
+    /// Returns whether every vcon def is well-typed.
+    /// This can only be `false` if error recovery is enabled.
     fn typecheck_ind_vcon_defs<A: AuxDataFamily>(
         &mut self,
         ind: RcHashed<ast::Ind<A>>,
         normalized_index_types_g0: Normalized<RcHashedVec<minimal_ast::Expr>>,
         tcon_g1: LazyTypeContext,
-    ) -> Result<(), TypeError<A>> {
+    ) -> Result<bool, TypeError<A>> {
+        let mut are_all_well_typed = true;
         for def in &ind.hashee.vcon_defs.hashee {
-            self.typecheck_ind_vcon_def(
+            let result = self.typecheck_ind_vcon_def(
                 def,
                 ind.clone(),
                 normalized_index_types_g0.clone(),
                 tcon_g1,
-            )?;
+            );
+            if self.recover(result)?.is_none() {
+                are_all_well_typed = false;
+            }
         }
-        Ok(())
+        Ok(are_all_well_typed)
     }
 
     fn typecheck_ind_vcon_def<A: AuxDataFamily>(
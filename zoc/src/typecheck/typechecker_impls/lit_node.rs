@@ -0,0 +1,21 @@
+use super::*;
+
+impl TypeChecker {
+    pub fn get_type_of_lit<A: AuxDataFamily>(
+        &mut self,
+        lit_node: RcHashed<ast::LitNode<A>>,
+    ) -> Result<NormalForm, TypeError<A>> {
+        let type_prim = match lit_node.hashee.lit {
+            Lit::U64(_) => Prim::U64,
+            Lit::String(_) => Prim::String,
+        };
+        Ok(self
+            .evaluator
+            .eval(minimal_ast::Expr::Prim(Rc::new(Hashed::new(
+                minimal_ast::PrimNode {
+                    prim: type_prim,
+                    aux_data: (),
+                },
+            )))))
+    }
+}
@@ -23,6 +23,16 @@ impl TypeChecker {
             matchee_type_args_g0.clone(),
         )?;
 
+        // The return type is not otherwise typechecked,
+        // but a typed AST needs the type of each of its nodes.
+        if self.type_recorder.is_enabled() {
+            self.typecheck_match_return_type(
+                match_g0.clone(),
+                matchee_type_ind_g0.clone(),
+                tcon_g0,
+            )?;
+        }
+
         self.typecheck_match_cases_assuming_number_of_cases_is_correct(
             match_g0.clone(),
             matchee_type_ind_g0.clone(),
@@ -109,6 +119,32 @@ impl TypeChecker {
         Ok(())
     }
 
+    /// The return type is typechecked in a context extended with
+    /// the matchee type's index args, followed by the matchee itself.
+    fn typecheck_match_return_type<A: AuxDataFamily>(
+        &mut self,
+        match_g0: RcHashed<ast::Match<A>>,
+        matchee_type_ind_g0: Normalized<RcHashed<minimal_ast::Ind>>,
+        tcon_g0: LazyTypeContext,
+    ) -> Result<(), TypeError<A>> {
+        let index_types_g0 = matchee_type_ind_g0
+            .to_hashee()
+            .index_types()
+            .hashee()
+            .into_vec();
+        let index_count = index_types_g0.len();
+        let matchee_type_gn =
+            NormalForm::ind_capp_of_descending_debs(matchee_type_ind_g0.upshift(index_count, 0));
+        let extension: Normalized<Vec<minimal_ast::Expr>> = index_types_g0
+            .into_iter()
+            .chain(std::iter::once(matchee_type_gn))
+            .collect();
+        let extended_tcon = LazyTypeContext::Snoc(&tcon_g0, extension.to_derefed());
+
+        self.assert_expr_type_is_universe(match_g0.hashee.return_type.clone(), extended_tcon)?;
+        Ok(())
+    }
+
     fn typecheck_match_cases_assuming_number_of_cases_is_correct<A: AuxDataFamily>(
         &mut self,
         match_: RcHashed<ast::Match<A>>,
@@ -117,13 +153,14 @@ impl TypeChecker {
         tcon: LazyTypeContext,
     ) -> Result<(), TypeError<A>> {
         for i in 0..match_.hashee.cases.hashee.len() {
-            self.typecheck_match_case(
+            let result = self.typecheck_match_case(
                 i,
                 match_.clone(),
                 matchee_type_ind.clone(),
                 matchee_type_args.clone(),
                 tcon,
-            )?;
+            );
+            self.recover(result)?;
         }
         Ok(())
     }
@@ -154,13 +191,37 @@ impl TypeChecker {
         let extended_tcon_g1 =
             LazyTypeContext::Snoc(&tcon_g0, param_types_g0.to_hashee().derefed());
 
-        let case_return_val_type_g1 = self.get_type(case.return_val.clone(), extended_tcon_g1)?;
-
         // TODO: Clean this up.
         let match_arity = 1 + matchee_type_args_g0.raw().hashee.len();
         let vcon_type_cfor_return_type_capp_args_g1 = vcon_type_g0
             .for_return_type_or_self()
             .app_args_or_empty_vec();
+        let matchee_type_args_g1 = matchee_type_args_g0.upshift_with_constant_cutoff(case.arity);
+        let index_unification = self.unify_indices(
+            vcon_type_cfor_return_type_capp_args_g1
+                .raw()
+                .hashee
+                .iter()
+                .cloned()
+                .zip(matchee_type_args_g1.raw().hashee.iter().cloned())
+                .collect(),
+        );
+        let Some(return_val) = &case.return_val else {
+            return match index_unification {
+                IndexUnification::Conflict => Ok(()),
+                IndexUnification::Solved(_) => Err(TypeError::MatchCaseIsNotImpossible {
+                    match_: match_g0.hashee.clone(),
+                    match_case_index: case_index,
+                }),
+            };
+        };
+        // If an impossible case has a return value anyway,
+        // we still typecheck it (without any index solutions).
+        let solutions_g1 = match index_unification {
+            IndexUnification::Conflict => vec![],
+            IndexUnification::Solved(solutions) => solutions,
+        };
+
         let matchee_type_ind_g1 = matchee_type_ind_g0.upshift(case.arity, 0);
         let vcon_capp_g1 =
             NormalForm::vcon_capp_of_descending_debs(matchee_type_ind_g1, case_index).into_raw();
@@ -182,13 +243,61 @@ impl TypeChecker {
             .replace_debs(&substituter, 0);
         let normalized_match_return_type_g1 = self.evaluator.eval(match_return_type_g1);
 
-        self.assert_expected_type_equality_holds(ExpectedTypeEquality {
-            expr: case.return_val.clone(),
-            expected_type: normalized_match_return_type_g1,
-            actual_type: case_return_val_type_g1,
-        })?;
+        self.check_match_case_return_val_type(
+            return_val.clone(),
+            normalized_match_return_type_g1,
+            &solutions_g1,
+            extended_tcon_g1,
+        )
+    }
 
-        Ok(())
+    /// Like `check_type`, except we also accept `return_val`
+    /// if its type and `expected_type` become equal after
+    /// applying the index solutions (in order).
+    /// This is sound because the match case is only reached
+    /// if the solutions hold definitionally.
+    ///
+    /// To avoid solving metas based on the solutions
+    /// (which do not hold outside of the match case),
+    /// the refined types are compared by digest.
+    fn check_match_case_return_val_type<A: AuxDataFamily>(
+        &mut self,
+        return_val: ast::Expr<A>,
+        expected_type: NormalForm,
+        solutions: &[(Deb, minimal_ast::Expr)],
+        tcon: LazyTypeContext,
+    ) -> Result<(), TypeError<A>> {
+        if solutions.is_empty() || matches!(return_val, ast::Expr::Meta(_) | ast::Expr::Hole(_)) {
+            return self.check_type(return_val, expected_type, tcon);
+        }
+
+        let actual_type = self.get_type(return_val.clone(), tcon)?;
+        let refined_actual_type = self.apply_index_solutions(actual_type.clone(), solutions);
+        let refined_expected_type = self.apply_index_solutions(expected_type.clone(), solutions);
+        if refined_actual_type.raw().digest() == refined_expected_type.raw().digest() {
+            return Ok(());
+        }
+
+        self.assert_expected_type_unifies(return_val, expected_type, actual_type, tcon)
+    }
+
+    fn apply_index_solutions(
+        &mut self,
+        expr: NormalForm,
+        solutions: &[(Deb, minimal_ast::Expr)],
+    ) -> NormalForm {
+        let refined = solutions
+            .iter()
+            .fold(expr.into_raw(), |expr, (deb, new_expr)| {
+                expr.replace_debs(
+                    &DebSubstituter {
+                        deb: *deb,
+                        new_expr,
+                    },
+                    0,
+                )
+            });
+        self.evaluator.eval(refined)
     }
 
     fn assert_stated_case_arity_is_correct<A: AuxDataFamily>(
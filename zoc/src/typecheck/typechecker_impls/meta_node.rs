@@ -0,0 +1,66 @@
+use super::*;
+
+impl TypeChecker {
+    pub fn get_type_of_meta<A: AuxDataFamily>(
+        &mut self,
+        meta_node: RcHashed<ast::MetaNode<A>>,
+        tcon: LazyTypeContext,
+    ) -> Result<NormalForm, TypeError<A>> {
+        if meta_node.hashee.id == MetaId::INVALID_CODE {
+            return self.report_invalid_code(meta_node.hashee.clone());
+        }
+
+        let Some(type_) = self.get_recorded_meta_type(meta_node.hashee.id, tcon.len()) else {
+            return Err(TypeError::CannotInferMetaType {
+                meta: meta_node.hashee.clone(),
+            });
+        };
+
+        Ok(type_)
+    }
+
+    /// Returns the type the meta was introduced with,
+    /// moved to a type context of length `tcon_len`.
+    fn get_recorded_meta_type(&mut self, id: MetaId, tcon_len: usize) -> Option<NormalForm> {
+        let entry = self.metas.get(id)?;
+        let type_ = rescope(entry.type_.raw().clone(), entry.intro_tcon_len, tcon_len)?;
+        Some(self.evaluator.eval(type_))
+    }
+
+    /// The first time a meta appears in a checking position,
+    /// we record the expected type as the meta's type.
+    /// On subsequent appearances, we unify the recorded type
+    /// with the expected type.
+    pub(super) fn check_type_of_meta<A: AuxDataFamily>(
+        &mut self,
+        meta_node: RcHashed<ast::MetaNode<A>>,
+        expected_type: NormalForm,
+        tcon: LazyTypeContext,
+    ) -> Result<(), TypeError<A>> {
+        let id = meta_node.hashee.id;
+        if id == MetaId::INVALID_CODE {
+            self.report_invalid_code(meta_node.hashee.clone())?;
+            return Ok(());
+        }
+
+        if self.metas.get(id).is_none() {
+            self.metas.insert(
+                id,
+                MetaEntry {
+                    intro_tcon_len: tcon.len(),
+                    type_: expected_type,
+                    solution: None,
+                },
+            );
+            return Ok(());
+        }
+
+        let actual_type = self.get_type_of_meta(meta_node.clone(), tcon)?;
+        self.assert_expected_type_unifies(
+            ast::Expr::Meta(meta_node),
+            expected_type,
+            actual_type,
+            tcon,
+        )
+    }
+}
@@ -5,15 +5,62 @@ mod deb_node;
 mod for_;
 mod fun;
 mod ind;
+mod lit_node;
 mod match_;
+mod meta_node;
+mod prim_node;
 mod universe_node;
 mod vcon;
 
 impl TypeChecker {
+    /// Returns the type of `expr`.
+    ///
+    /// Metas are scoped to a single top-level call:
+    /// solutions from previous calls are discarded,
+    /// and if any meta in `expr` is left unsolved,
+    /// this returns `TypeError::UnsolvedMetas`.
+    /// The type a top-level call returns has every meta
+    /// replaced by its solution.
     pub fn get_type<A: AuxDataFamily>(
         &mut self,
         expr: ast::Expr<A>,
         tcon: LazyTypeContext,
+    ) -> Result<NormalForm, TypeError<A>> {
+        let is_top_level = !self.is_in_top_level_call;
+        let type_ = self.in_top_level_call(&expr, |this| {
+            let type_ = this.get_type_using_cache(expr.clone(), tcon)?;
+            this.type_recorder.record(&expr, &type_, tcon.len());
+            Ok(type_)
+        })?;
+
+        if is_top_level {
+            return Ok(self.zonk(type_, tcon.len()));
+        }
+        Ok(type_)
+    }
+
+    fn get_type_using_cache<A: AuxDataFamily>(
+        &mut self,
+        expr: ast::Expr<A>,
+        tcon: LazyTypeContext,
+    ) -> Result<NormalForm, TypeError<A>> {
+        let Some(key) = self.get_type_cache_key(&expr) else {
+            return self.get_type_ignoring_cache(expr, tcon);
+        };
+
+        if let Some(type_) = self.get_cached_type(&key) {
+            return Ok(type_);
+        }
+
+        let type_ = self.get_type_ignoring_cache(expr, tcon)?;
+        self.cache_type(key, type_.clone());
+        Ok(type_)
+    }
+
+    fn get_type_ignoring_cache<A: AuxDataFamily>(
+        &mut self,
+        expr: ast::Expr<A>,
+        tcon: LazyTypeContext,
     ) -> Result<NormalForm, TypeError<A>> {
         match expr {
             ast::Expr::Ind(e) => self.get_type_of_ind(e, tcon),
@@ -24,9 +71,137 @@ impl TypeChecker {
             ast::Expr::For(e) => self.get_type_of_for(e, tcon),
             ast::Expr::Deb(e) => self.get_type_of_deb(e, tcon),
             ast::Expr::Universe(e) => self.get_type_of_universe(e),
+            ast::Expr::Lit(e) => self.get_type_of_lit(e),
+            ast::Expr::Prim(e) => self.get_type_of_prim(e),
+            ast::Expr::Meta(e) => self.get_type_of_meta(e, tcon),
+            ast::Expr::Hole(e) => Err(TypeError::CannotInferHoleType {
+                hole: e.hashee.clone(),
+            }),
         }
     }
 
+    /// Checks that `expr` has type `expected_type`.
+    ///
+    /// The expected type is pushed into funs and matches:
+    /// a fun's meta param types and meta return type
+    /// are solved with the corresponding types of `expected_type`
+    /// (if it is a `for` with as many params),
+    /// and a match's meta return type is solved with `expected_type`
+    /// (i.e., with a return type that does not depend on the matchee).
+    /// After that, `expr`'s type is inferred (using `get_type`),
+    /// and compared to `expected_type`.
+    /// `get_type` itself checks
+    /// match case return values, fun return values, and app arguments
+    /// against the types they must have,
+    /// so the expected type reaches nested funs and matches in those positions,
+    /// and a mismatch inside one of them is reported at
+    /// the offending sub-expression, rather than at `expr`.
+    ///
+    /// If the actual type and the expected type differ,
+    /// we try to unify them (solving metas as needed)
+    /// before reporting a mismatch.
+    /// Like `get_type`, a top-level call rejects unsolved metas.
+    pub fn check_type<A: AuxDataFamily>(
+        &mut self,
+        expr: ast::Expr<A>,
+        expected_type: NormalForm,
+        tcon: LazyTypeContext,
+    ) -> Result<(), TypeError<A>> {
+        self.in_top_level_call(&expr, |this| {
+            this.check_type_in_call(expr.clone(), expected_type, tcon)
+        })
+    }
+
+    fn check_type_in_call<A: AuxDataFamily>(
+        &mut self,
+        expr: ast::Expr<A>,
+        expected_type: NormalForm,
+        tcon: LazyTypeContext,
+    ) -> Result<(), TypeError<A>> {
+        if let ast::Expr::Meta(meta_node) = &expr {
+            self.check_type_of_meta(meta_node.clone(), expected_type.clone(), tcon)?;
+            self.type_recorder.record(&expr, &expected_type, tcon.len());
+            return Ok(());
+        }
+
+        if let ast::Expr::Hole(hole) = &expr {
+            self.type_recorder.record(&expr, &expected_type, tcon.len());
+            return self.record_hole_goal(hole.clone(), expected_type, tcon);
+        }
+
+        match &expr {
+            ast::Expr::Fun(fun) => {
+                self.push_expected_type_into_fun(fun.clone(), &expected_type, tcon)?;
+            }
+            ast::Expr::Match(match_) => {
+                self.push_expected_annotation(
+                    &match_.hashee.return_type,
+                    expected_type.clone(),
+                    tcon,
+                )?;
+            }
+            _ => {}
+        }
+
+        let actual_type = self.get_type(expr.clone(), tcon)?;
+        self.assert_expected_type_unifies(expr, expected_type, actual_type, tcon)
+    }
+
+    /// If `annotation` (a type written in a fun or match) is a meta,
+    /// this solves it with `expected_annotation`.
+    /// Otherwise, this does nothing,
+    /// and the annotation is typechecked as usual.
+    ///
+    /// If the universe of `expected_annotation` cannot be inferred
+    /// (e.g., it is stuck on an error meta),
+    /// the meta is left unsolved.
+    fn push_expected_annotation<A: AuxDataFamily>(
+        &mut self,
+        annotation: &ast::Expr<A>,
+        expected_annotation: NormalForm,
+        tcon: LazyTypeContext,
+    ) -> Result<(), TypeError<A>> {
+        let ast::Expr::Meta(meta_node) = annotation else {
+            return Ok(());
+        };
+        let Ok(universe) = self.get_type(expected_annotation.raw().clone(), tcon) else {
+            return Ok(());
+        };
+        if !universe.raw().is_universe() {
+            return Ok(());
+        }
+
+        self.check_type_of_meta(meta_node.clone(), universe, tcon)?;
+        let meta_minimal = self.aux_remover.convert(annotation.clone());
+        let meta = self.evaluator.eval(meta_minimal);
+        // If this fails, the mismatch is reported when
+        // the fun or match's type is compared to the expected type.
+        self.unify(meta, expected_annotation, tcon.len());
+        Ok(())
+    }
+
+    fn assert_expected_type_unifies<A: AuxDataFamily>(
+        &mut self,
+        expr: ast::Expr<A>,
+        expected_type: NormalForm,
+        actual_type: NormalForm,
+        tcon: LazyTypeContext,
+    ) -> Result<(), TypeError<A>> {
+        if actual_type.raw().digest() == expected_type.raw().digest()
+            || self.unify(actual_type.clone(), expected_type.clone(), tcon.len())
+        {
+            return Ok(());
+        }
+
+        let expected_type = self.zonk(expected_type, tcon.len());
+        let actual_type = self.zonk(actual_type, tcon.len());
+        self.assert_expected_type_equality_holds(ExpectedTypeEquality {
+            expr,
+            expected_type,
+            actual_type,
+        })
+    }
+
     pub(crate) fn get_types_of_dependent_expressions<A: AuxDataFamily>(
         &mut self,
         exprs: &[ast::Expr<A>],
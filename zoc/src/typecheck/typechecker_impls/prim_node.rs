@@ -0,0 +1,46 @@
+use super::*;
+
+impl TypeChecker {
+    pub fn get_type_of_prim<A: AuxDataFamily>(
+        &mut self,
+        prim_node: RcHashed<ast::PrimNode<A>>,
+    ) -> Result<NormalForm, TypeError<A>> {
+        let type_ = match prim_node.hashee.prim {
+            Prim::U64 | Prim::String => set0(),
+
+            Prim::U64Add | Prim::U64Sub | Prim::U64Mul => {
+                prim_for(&[Prim::U64, Prim::U64], Prim::U64)
+            }
+
+            Prim::StringConcat => prim_for(&[Prim::String, Prim::String], Prim::String),
+
+            Prim::StringLen => prim_for(&[Prim::String], Prim::U64),
+        };
+        Ok(self.evaluator.eval(type_))
+    }
+}
+
+fn set0() -> minimal_ast::Expr {
+    minimal_ast::Expr::Universe(rc_hashed(minimal_ast::UniverseNode {
+        universe: Universe {
+            level: UniverseLevel(0),
+            erasable: false,
+        },
+        aux_data: (),
+    }))
+}
+
+/// Since prim types are closed,
+/// we don't need to shift them when
+/// they appear under binders.
+fn prim_for(param_types: &[Prim], return_type: Prim) -> minimal_ast::Expr {
+    minimal_ast::Expr::For(rc_hashed(minimal_ast::For {
+        param_types: rc_hashed(param_types.iter().copied().map(prim_expr).collect()),
+        return_type: prim_expr(return_type),
+        aux_data: (),
+    }))
+}
+
+fn prim_expr(prim: Prim) -> minimal_ast::Expr {
+    minimal_ast::Expr::Prim(rc_hashed(minimal_ast::PrimNode { prim, aux_data: () }))
+}
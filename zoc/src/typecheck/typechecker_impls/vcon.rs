@@ -24,6 +24,10 @@ impl TypeChecker {
         vcon: RcHashed<ast::Vcon<A>>,
     ) -> Result<(), TypeError<A>> {
         let vcon_index = vcon.hashee.vcon_index;
+        if vcon_index == ast::Vcon::<A>::UNKNOWN_NAME_INDEX {
+            return Err(TypeError::UnknownVconName(vcon.hashee.clone()));
+        }
+
         let defs = &vcon.hashee.ind.hashee.vcon_defs;
         if vcon_index >= defs.hashee.len() {
             return Err(TypeError::InvalidVconIndex(vcon.hashee.clone()));
@@ -36,7 +40,8 @@ impl TypeChecker {
         ind: RcHashed<ast::Ind<A>>,
         tcon: LazyTypeContext,
     ) -> Result<Normalized<RcHashed<minimal_ast::Ind>>, TypeError<A>> {
-        self.get_type_of_ind(ind.clone(), tcon)?;
+        // We go through `get_type` so the ind's type is recorded.
+        self.get_type(ast::Expr::Ind(ind.clone()), tcon)?;
 
         let ind_minimal = self.aux_remover.convert_ind(ind);
         let normalized = self.evaluator.eval_ind(ind_minimal);
@@ -0,0 +1,314 @@
+use super::*;
+
+use crate::syntax_tree::ast::prelude::typed_ast::{self, Typed};
+
+use std::collections::HashMap;
+
+/// Records the type of every node the typechecker visits,
+/// so that `get_typed_ast` can annotate the tree
+/// without typechecking each node again.
+///
+/// Nodes are identified by their address.
+/// A shared node (i.e., the same `Rc` in multiple places)
+/// may have a different type in each place
+/// (e.g., a deb under different binders),
+/// so `get_typed_ast` first gives each place its own node
+/// (see `unshare`).
+#[derive(Clone, Debug, Default)]
+pub struct TypeRecorder {
+    enabled: bool,
+    types: HashMap<NodeAddress, RecordedType>,
+}
+
+type NodeAddress = usize;
+
+#[derive(Clone, Debug)]
+struct RecordedType {
+    type_: NormalForm,
+    tcon_len: usize,
+}
+
+impl TypeRecorder {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub(super) fn record<A: AuxDataFamily>(
+        &mut self,
+        expr: &ast::Expr<A>,
+        type_: &NormalForm,
+        tcon_len: usize,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        self.types.insert(
+            node_address(expr),
+            RecordedType {
+                type_: type_.clone(),
+                tcon_len,
+            },
+        );
+    }
+}
+
+fn node_address<A: AuxDataFamily>(expr: &ast::Expr<A>) -> NodeAddress {
+    match expr {
+        ast::Expr::Ind(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::Vcon(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::Match(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::Fun(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::App(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::For(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::Deb(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::Universe(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::Lit(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::Prim(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::Meta(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+        ast::Expr::Hole(e) => Rc::as_ptr(e) as *const () as NodeAddress,
+    }
+}
+
+impl TypeChecker {
+    /// Typechecks `expr`, and returns `expr` with
+    /// every node annotated with its type.
+    ///
+    /// Metas solved anywhere in `expr` are substituted
+    /// into every recorded type.
+    /// Holes in checking positions are accepted,
+    /// and annotated with their expected type.
+    pub fn get_typed_ast<A: AuxDataFamily>(
+        &mut self,
+        expr: ast::Expr<A>,
+        tcon: LazyTypeContext,
+    ) -> Result<typed_ast::Expr<A>, TypeError<A>> {
+        self.type_recorder = TypeRecorder {
+            enabled: true,
+            types: HashMap::new(),
+        };
+        let expr = unshare(&expr);
+        let result = self.accepting_holes(|this| this.get_type(expr.clone(), tcon));
+        self.hole_goals.clear();
+        let types = std::mem::take(&mut self.type_recorder).types;
+        result?;
+
+        Ok(TypeAnnotator {
+            typechecker: self,
+            types: &types,
+        }
+        .annotate(expr))
+    }
+}
+
+/// Returns a copy of `expr` in which every node is a fresh `Rc`,
+/// so no two places in the copy share a node.
+fn unshare<A: AuxDataFamily>(expr: &ast::Expr<A>) -> ast::Expr<A> {
+    match expr {
+        ast::Expr::Ind(e) => ast::Expr::Ind(unshare_ind(e)),
+        ast::Expr::Vcon(e) => ast::Expr::Vcon(rc_hashed(ast::Vcon {
+            ind: unshare_ind(&e.hashee.ind),
+            vcon_index: e.hashee.vcon_index,
+            aux_data: e.hashee.aux_data.clone(),
+        })),
+        ast::Expr::Match(e) => ast::Expr::Match(rc_hashed(ast::Match {
+            matchee: unshare(&e.hashee.matchee),
+            return_type_arity: e.hashee.return_type_arity,
+            return_type: unshare(&e.hashee.return_type),
+            cases: rc_hashed(
+                e.hashee
+                    .cases
+                    .hashee
+                    .iter()
+                    .map(|case| ast::MatchCase {
+                        arity: case.arity,
+                        return_val: case.return_val.as_ref().map(unshare),
+                        aux_data: case.aux_data.clone(),
+                    })
+                    .collect(),
+            ),
+            aux_data: e.hashee.aux_data.clone(),
+        })),
+        ast::Expr::Fun(e) => ast::Expr::Fun(rc_hashed(ast::Fun {
+            decreasing_index: e.hashee.decreasing_index,
+            param_types: unshare_expressions(&e.hashee.param_types.hashee),
+            return_type: unshare(&e.hashee.return_type),
+            return_val: unshare(&e.hashee.return_val),
+            aux_data: e.hashee.aux_data.clone(),
+        })),
+        ast::Expr::App(e) => ast::Expr::App(rc_hashed(ast::App {
+            callee: unshare(&e.hashee.callee),
+            args: unshare_expressions(&e.hashee.args.hashee),
+            aux_data: e.hashee.aux_data.clone(),
+        })),
+        ast::Expr::For(e) => ast::Expr::For(rc_hashed(ast::For {
+            param_types: unshare_expressions(&e.hashee.param_types.hashee),
+            return_type: unshare(&e.hashee.return_type),
+            aux_data: e.hashee.aux_data.clone(),
+        })),
+        ast::Expr::Deb(e) => ast::Expr::Deb(rc_hashed(e.hashee.clone())),
+        ast::Expr::Universe(e) => ast::Expr::Universe(rc_hashed(e.hashee.clone())),
+        ast::Expr::Lit(e) => ast::Expr::Lit(rc_hashed(e.hashee.clone())),
+        ast::Expr::Prim(e) => ast::Expr::Prim(rc_hashed(e.hashee.clone())),
+        ast::Expr::Meta(e) => ast::Expr::Meta(rc_hashed(e.hashee.clone())),
+        ast::Expr::Hole(e) => ast::Expr::Hole(rc_hashed(e.hashee.clone())),
+    }
+}
+
+fn unshare_ind<A: AuxDataFamily>(ind: &RcHashed<ast::Ind<A>>) -> RcHashed<ast::Ind<A>> {
+    rc_hashed(ast::Ind {
+        name: ind.hashee.name.clone(),
+        tag: ind.hashee.tag.clone(),
+        universe: ind.hashee.universe,
+        index_types: unshare_expressions(&ind.hashee.index_types.hashee),
+        vcon_defs: rc_hashed(
+            ind.hashee
+                .vcon_defs
+                .hashee
+                .iter()
+                .map(|def| ast::VconDef {
+                    name: def.name.clone(),
+                    param_types: unshare_expressions(&def.param_types.hashee),
+                    index_args: unshare_expressions(&def.index_args.hashee),
+                    aux_data: def.aux_data.clone(),
+                })
+                .collect(),
+        ),
+        aux_data: ind.hashee.aux_data.clone(),
+    })
+}
+
+fn unshare_expressions<A: AuxDataFamily>(exprs: &[ast::Expr<A>]) -> RcHashedVec<ast::Expr<A>> {
+    rc_hashed(exprs.iter().map(unshare).collect())
+}
+
+struct TypeAnnotator<'a> {
+    typechecker: &'a mut TypeChecker,
+    types: &'a HashMap<NodeAddress, RecordedType>,
+}
+
+impl TypeAnnotator<'_> {
+    fn annotate<A: AuxDataFamily>(&mut self, expr: ast::Expr<A>) -> typed_ast::Expr<A> {
+        let address = node_address(&expr);
+        match expr {
+            ast::Expr::Ind(e) => self.annotate_ind(e).into(),
+            ast::Expr::Vcon(e) => ast::Expr::Vcon(rc_hashed(ast::Vcon {
+                ind: self.annotate_ind(e.hashee.ind.clone()),
+                vcon_index: e.hashee.vcon_index,
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+            ast::Expr::Match(e) => ast::Expr::Match(rc_hashed(ast::Match {
+                matchee: self.annotate(e.hashee.matchee.clone()),
+                return_type_arity: e.hashee.return_type_arity,
+                return_type: self.annotate(e.hashee.return_type.clone()),
+                cases: rc_hashed(
+                    e.hashee
+                        .cases
+                        .hashee
+                        .iter()
+                        .map(|case| ast::MatchCase {
+                            arity: case.arity,
+                            return_val: case
+                                .return_val
+                                .clone()
+                                .map(|return_val| self.annotate(return_val)),
+                            aux_data: case.aux_data.clone(),
+                        })
+                        .collect(),
+                ),
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+            ast::Expr::Fun(e) => ast::Expr::Fun(rc_hashed(ast::Fun {
+                decreasing_index: e.hashee.decreasing_index,
+                param_types: self.annotate_expressions(&e.hashee.param_types.hashee),
+                return_type: self.annotate(e.hashee.return_type.clone()),
+                return_val: self.annotate(e.hashee.return_val.clone()),
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+            ast::Expr::App(e) => ast::Expr::App(rc_hashed(ast::App {
+                callee: self.annotate(e.hashee.callee.clone()),
+                args: self.annotate_expressions(&e.hashee.args.hashee),
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+            ast::Expr::For(e) => ast::Expr::For(rc_hashed(ast::For {
+                param_types: self.annotate_expressions(&e.hashee.param_types.hashee),
+                return_type: self.annotate(e.hashee.return_type.clone()),
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+            ast::Expr::Deb(e) => ast::Expr::Deb(rc_hashed(ast::DebNode {
+                deb: e.hashee.deb,
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+            ast::Expr::Universe(e) => ast::Expr::Universe(rc_hashed(ast::UniverseNode {
+                universe: e.hashee.universe,
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+            ast::Expr::Lit(e) => ast::Expr::Lit(rc_hashed(ast::LitNode {
+                lit: e.hashee.lit.clone(),
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+            ast::Expr::Prim(e) => ast::Expr::Prim(rc_hashed(ast::PrimNode {
+                prim: e.hashee.prim,
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+            ast::Expr::Meta(e) => ast::Expr::Meta(rc_hashed(ast::MetaNode {
+                id: e.hashee.id,
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+            ast::Expr::Hole(e) => ast::Expr::Hole(rc_hashed(ast::HoleNode {
+                aux_data: self.typed(address, e.hashee.aux_data.clone()),
+            })),
+        }
+    }
+
+    fn annotate_ind<A: AuxDataFamily>(
+        &mut self,
+        ind: RcHashed<ast::Ind<A>>,
+    ) -> RcHashed<typed_ast::Ind<A>> {
+        let address = node_address(&ast::Expr::Ind(ind.clone()));
+        rc_hashed(ast::Ind {
+            name: ind.hashee.name.clone(),
+            tag: ind.hashee.tag.clone(),
+            universe: ind.hashee.universe,
+            index_types: self.annotate_expressions(&ind.hashee.index_types.hashee),
+            vcon_defs: rc_hashed(
+                ind.hashee
+                    .vcon_defs
+                    .hashee
+                    .iter()
+                    .map(|def| ast::VconDef {
+                        name: def.name.clone(),
+                        param_types: self.annotate_expressions(&def.param_types.hashee),
+                        index_args: self.annotate_expressions(&def.index_args.hashee),
+                        aux_data: def.aux_data.clone(),
+                    })
+                    .collect(),
+            ),
+            aux_data: self.typed(address, ind.hashee.aux_data.clone()),
+        })
+    }
+
+    fn annotate_expressions<A: AuxDataFamily>(
+        &mut self,
+        exprs: &[ast::Expr<A>],
+    ) -> RcHashedVec<typed_ast::Expr<A>> {
+        rc_hashed(
+            exprs
+                .iter()
+                .map(|expr| self.annotate(expr.clone()))
+                .collect(),
+        )
+    }
+
+    fn typed<T>(&mut self, address: NodeAddress, inner: T) -> Typed<T> {
+        let recorded = self
+            .types
+            .get(&address)
+            .expect("every node of a well-typed expression should have a recorded type")
+            .clone();
+        Typed {
+            type_: self.typechecker.zonk(recorded.type_, recorded.tcon_len),
+            inner,
+        }
+    }
+}
@@ -0,0 +1,404 @@
+use super::*;
+
+use minimal_ast::{DebNode, Expr, Ind, MetaNode};
+
+impl TypeChecker {
+    /// Attempts to make `left` and `right` definitionally equal
+    /// by solving metas.
+    /// Both expressions live in a type context of length `tcon_len`.
+    ///
+    /// We only solve metas in (Miller) pattern position.
+    /// That is, a meta `m` is solved only if it appears as
+    /// `m` or `(m x1 ... xn)`, where `x1`, ..., `xn` are distinct debs.
+    /// Other constraints are compared structurally.
+    ///
+    /// If unification fails, some metas may have been solved
+    /// nonetheless.
+    /// This is fine, since a failed unification
+    /// always results in a type error.
+    pub fn unify(&mut self, left: NormalForm, right: NormalForm, tcon_len: usize) -> bool {
+        self.unify_raw(left.into_raw(), right.into_raw(), tcon_len)
+    }
+
+    fn unify_raw(&mut self, left: Expr, right: Expr, tcon_len: usize) -> bool {
+        let left = self.zonk_and_eval(left, tcon_len);
+        let right = self.zonk_and_eval(right, tcon_len);
+
+        if left.digest() == right.digest() {
+            return true;
+        }
+
+        // Error metas stand in for nodes that failed to typecheck,
+        // so an error meta matches anything at the position it occurs.
+        // Mismatches elsewhere in the expressions are still reported.
+        if self.is_blocked_on_error_meta(&left) || self.is_blocked_on_error_meta(&right) {
+            return true;
+        }
+
+        if let Some(result) = self.try_solve_flex(&left, &right, tcon_len) {
+            return result;
+        }
+        if let Some(result) = self.try_solve_flex(&right, &left, tcon_len) {
+            return result;
+        }
+
+        self.unify_structurally(left, right, tcon_len)
+    }
+
+    fn zonk_and_eval(&mut self, expr: Expr, tcon_len: usize) -> Expr {
+        let normalized = self.evaluator.eval(expr);
+        self.zonk(normalized, tcon_len).into_raw()
+    }
+
+    fn unify_structurally(&mut self, left: Expr, right: Expr, tcon_len: usize) -> bool {
+        match (left, right) {
+            (Expr::Ind(left), Expr::Ind(right)) => self.unify_inds(left, right, tcon_len),
+
+            (Expr::Vcon(left), Expr::Vcon(right)) => {
+                left.hashee.vcon_index == right.hashee.vcon_index
+                    && self.unify_inds(left.hashee.ind.clone(), right.hashee.ind.clone(), tcon_len)
+            }
+
+            (Expr::Match(left), Expr::Match(right)) => {
+                let left = &left.hashee;
+                let right = &right.hashee;
+                left.return_type_arity == right.return_type_arity
+                    && left.cases.hashee.len() == right.cases.hashee.len()
+                    && self.unify_raw(left.matchee.clone(), right.matchee.clone(), tcon_len)
+                    && self.unify_raw(
+                        left.return_type.clone(),
+                        right.return_type.clone(),
+                        tcon_len + left.return_type_arity,
+                    )
+                    && left.cases.hashee.iter().zip(right.cases.hashee.iter()).all(
+                        |(left_case, right_case)| {
+                            left_case.arity == right_case.arity
+                                && match (&left_case.return_val, &right_case.return_val) {
+                                    (Some(left_return_val), Some(right_return_val)) => self
+                                        .unify_raw(
+                                            left_return_val.clone(),
+                                            right_return_val.clone(),
+                                            tcon_len + left_case.arity,
+                                        ),
+                                    (None, None) => true,
+                                    _ => false,
+                                }
+                        },
+                    )
+            }
+
+            (Expr::Fun(left), Expr::Fun(right)) => {
+                let left = &left.hashee;
+                let right = &right.hashee;
+                let param_count = left.param_types.hashee.len();
+                left.decreasing_index == right.decreasing_index
+                    && self.unify_dependent_exprs(
+                        &left.param_types.hashee,
+                        &right.param_types.hashee,
+                        tcon_len,
+                    )
+                    && self.unify_raw(
+                        left.return_type.clone(),
+                        right.return_type.clone(),
+                        tcon_len + param_count,
+                    )
+                    && self.unify_raw(
+                        left.return_val.clone(),
+                        right.return_val.clone(),
+                        tcon_len + param_count + 1,
+                    )
+            }
+
+            (Expr::App(left), Expr::App(right)) => {
+                let left = &left.hashee;
+                let right = &right.hashee;
+                left.args.hashee.len() == right.args.hashee.len()
+                    && self.unify_raw(left.callee.clone(), right.callee.clone(), tcon_len)
+                    && left.args.hashee.iter().zip(right.args.hashee.iter()).all(
+                        |(left_arg, right_arg)| {
+                            self.unify_raw(left_arg.clone(), right_arg.clone(), tcon_len)
+                        },
+                    )
+            }
+
+            (Expr::For(left), Expr::For(right)) => {
+                let left = &left.hashee;
+                let right = &right.hashee;
+                self.unify_dependent_exprs(
+                    &left.param_types.hashee,
+                    &right.param_types.hashee,
+                    tcon_len,
+                ) && self.unify_raw(
+                    left.return_type.clone(),
+                    right.return_type.clone(),
+                    tcon_len + left.param_types.hashee.len(),
+                )
+            }
+
+            // All other pairs of nodes are either leaves
+            // (which we already compared via their digests)
+            // or have different node kinds.
+            _ => false,
+        }
+    }
+
+    fn unify_inds(&mut self, left: RcHashed<Ind>, right: RcHashed<Ind>, tcon_len: usize) -> bool {
+        let left = &left.hashee;
+        let right = &right.hashee;
+        left.name == right.name
+            && left.tag == right.tag
+            && left.universe == right.universe
+            && left.vcon_defs.hashee.len() == right.vcon_defs.hashee.len()
+            && self.unify_dependent_exprs(
+                &left.index_types.hashee,
+                &right.index_types.hashee,
+                tcon_len,
+            )
+            && left
+                .vcon_defs
+                .hashee
+                .iter()
+                .zip(right.vcon_defs.hashee.iter())
+                .all(|(left_def, right_def)| {
+                    let param_count = left_def.param_types.hashee.len();
+                    left_def.name == right_def.name
+                        && left_def.index_args.hashee.len() == right_def.index_args.hashee.len()
+                        && self.unify_dependent_exprs(
+                            &left_def.param_types.hashee,
+                            &right_def.param_types.hashee,
+                            tcon_len + 1,
+                        )
+                        && left_def
+                            .index_args
+                            .hashee
+                            .iter()
+                            .zip(right_def.index_args.hashee.iter())
+                            .all(|(left_arg, right_arg)| {
+                                self.unify_raw(
+                                    left_arg.clone(),
+                                    right_arg.clone(),
+                                    tcon_len + 1 + param_count,
+                                )
+                            })
+                })
+    }
+
+    fn unify_dependent_exprs(&mut self, left: &[Expr], right: &[Expr], tcon_len: usize) -> bool {
+        left.len() == right.len()
+            && left
+                .iter()
+                .zip(right.iter())
+                .enumerate()
+                .all(|(index, (left, right))| {
+                    self.unify_raw(left.clone(), right.clone(), tcon_len + index)
+                })
+    }
+}
+
+impl TypeChecker {
+    /// If `flex` is an unsolved meta in pattern position,
+    /// this attempts to solve it so that it equals `other`,
+    /// and returns whether it succeeded.
+    /// Otherwise, this returns `None`.
+    fn try_solve_flex(&mut self, flex: &Expr, other: &Expr, tcon_len: usize) -> Option<bool> {
+        let (meta, spine) = as_pattern(flex)?;
+        let entry = self.metas.get(meta.hashee.id)?.clone();
+        if entry.solution.is_some() || tcon_len < entry.intro_tcon_len {
+            return None;
+        }
+
+        let solution = if spine.is_empty() {
+            self.get_bare_solution(meta.hashee.id, &entry, other, tcon_len)
+        } else {
+            self.get_spine_solution(meta.hashee.id, &entry, &spine, other, tcon_len)
+        };
+
+        let Some(solution) = solution else {
+            return Some(false);
+        };
+        let solution = self.evaluator.eval(solution);
+        self.metas.set_solution(meta.hashee.id, solution);
+        Some(true)
+    }
+
+    /// Solves `m = other`.
+    fn get_bare_solution(
+        &self,
+        id: MetaId,
+        entry: &MetaEntry,
+        other: &Expr,
+        tcon_len: usize,
+    ) -> Option<Expr> {
+        if !self.are_metas_in_scope(id, other, entry.intro_tcon_len) {
+            return None;
+        }
+
+        rescope(other.clone(), tcon_len, entry.intro_tcon_len)
+    }
+
+    /// Solves `(m x1 ... xn) = other` by setting `m` to
+    /// ```zolike
+    /// (fun nonrec (<param types of m's type>) <return type of m's type>
+    ///     <other, with each xi replaced by the ith param>
+    /// )
+    /// ```
+    fn get_spine_solution(
+        &self,
+        id: MetaId,
+        entry: &MetaEntry,
+        spine: &[Deb],
+        other: &Expr,
+        tcon_len: usize,
+    ) -> Option<Expr> {
+        let Expr::For(meta_type) = entry.type_.raw() else {
+            return None;
+        };
+        let meta_type = &meta_type.hashee;
+        if meta_type.param_types.hashee.len() != spine.len() {
+            return None;
+        }
+
+        if !self.are_metas_in_scope(id, other, entry.intro_tcon_len) {
+            return None;
+        }
+
+        let renamer = PatternRenamer {
+            spine,
+            locals_len: tcon_len - entry.intro_tcon_len,
+            failed: Cell::new(false),
+        };
+        let return_val = other.clone().replace_debs(&renamer, 0);
+        if renamer.failed.get() {
+            return None;
+        }
+
+        Some(Expr::Fun(rc_hashed(minimal_ast::Fun {
+            decreasing_index: None,
+            param_types: meta_type.param_types.clone(),
+            return_type: meta_type.return_type.clone(),
+            return_val,
+            aux_data: (),
+        })))
+    }
+
+    /// Returns `false` if `expr` contains `id` (i.e., the occurs check fails),
+    /// or if `expr` contains a meta that was introduced in a context
+    /// longer than `max_intro_tcon_len`.
+    ///
+    /// The second condition is conservative.
+    /// It guarantees that moving `expr` into the solution of `id`
+    /// never moves a meta outside the context it was introduced in.
+    fn are_metas_in_scope(&self, id: MetaId, expr: &Expr, max_intro_tcon_len: usize) -> bool {
+        let checker = MetaScopeChecker {
+            metas: &self.metas,
+            id,
+            max_intro_tcon_len,
+            failed: Cell::new(false),
+        };
+        let _ = expr.clone().replace_debs(&checker, 0);
+        !checker.failed.get()
+    }
+}
+
+/// Returns the meta and spine if `expr` is of the form
+/// `m` or `(m x1 ... xn)`,
+/// where `m` is a meta and `x1`, ..., `xn` are distinct debs.
+fn as_pattern(expr: &Expr) -> Option<(RcHashed<MetaNode>, Vec<Deb>)> {
+    match expr {
+        Expr::Meta(meta) => Some((meta.clone(), vec![])),
+
+        Expr::App(app) => {
+            let Expr::Meta(meta) = &app.hashee.callee else {
+                return None;
+            };
+
+            let mut spine: Vec<Deb> = Vec::with_capacity(app.hashee.args.hashee.len());
+            for arg in app.hashee.args.hashee.iter() {
+                let Expr::Deb(deb) = arg else {
+                    return None;
+                };
+                if spine.contains(&deb.hashee.deb) {
+                    return None;
+                }
+                spine.push(deb.hashee.deb);
+            }
+
+            Some((meta.clone(), spine))
+        }
+
+        _ => None,
+    }
+}
+
+struct MetaScopeChecker<'a> {
+    metas: &'a MetaStore,
+    id: MetaId,
+    max_intro_tcon_len: usize,
+    failed: Cell<bool>,
+}
+
+impl DebReplacer for MetaScopeChecker<'_> {
+    fn replace_deb(&self, original: RcHashed<DebNode>, _cutoff: usize) -> Expr {
+        Expr::Deb(original)
+    }
+
+    fn replace_meta(&self, original: RcHashed<MetaNode>, _cutoff: usize) -> Expr {
+        let id = original.hashee.id;
+        let is_in_scope = self
+            .metas
+            .get(id)
+            .is_some_and(|entry| entry.intro_tcon_len <= self.max_intro_tcon_len);
+        if id == self.id || !is_in_scope {
+            self.failed.set(true);
+        }
+        Expr::Meta(original)
+    }
+}
+
+/// Moves an expression from the context the pattern
+/// `(m x1 ... xn)` lives in to the body of `m`'s solution.
+///
+/// The pattern context is `m`'s introduction context
+/// followed by `locals_len` local debs.
+/// The solution body context is `m`'s introduction context
+/// followed by the `n` params and the fun itself.
+///
+/// Each `xi` is replaced with the corresponding param.
+/// Other local debs are out of scope, so `failed` is set
+/// if we encounter one.
+struct PatternRenamer<'a> {
+    spine: &'a [Deb],
+    locals_len: usize,
+    failed: Cell<bool>,
+}
+
+impl DebReplacer for PatternRenamer<'_> {
+    fn replace_deb(&self, original: RcHashed<DebNode>, cutoff: usize) -> Expr {
+        let deb = original.hashee.deb.0;
+        if deb < cutoff {
+            return Expr::Deb(original);
+        }
+
+        let adjusted = deb - cutoff;
+        let param_count = self.spine.len();
+        let new_deb = if let Some(param_index) = self
+            .spine
+            .iter()
+            .position(|spine_deb| spine_deb.0 == adjusted)
+        {
+            // The fun itself is deb 0 in the body,
+            // so the last param is deb 1.
+            param_count - param_index
+        } else if adjusted >= self.locals_len {
+            adjusted - self.locals_len + param_count + 1
+        } else {
+            self.failed.set(true);
+            return Expr::Deb(original);
+        };
+
+        Expr::Deb(rc_hashed(DebNode {
+            deb: Deb(new_deb + cutoff),
+            aux_data: (),
+        }))
+    }
+}
@@ -0,0 +1,66 @@
+use super::*;
+
+use std::collections::HashMap;
+
+/// Comments, attached to the tokens they belong to.
+///
+/// A comment that shares a line with the token before it
+/// is a _trailing_ comment of that token.
+/// Every other comment is a _leading_ comment
+/// of the token after it.
+/// Comments after the last token are _dangling_.
+///
+/// Tokens are identified by their start index.
+#[derive(Debug, Default)]
+pub(super) struct AttachedComments<'a> {
+    leading: HashMap<ByteIndex, Vec<&'a str>>,
+    trailing: HashMap<ByteIndex, &'a str>,
+    dangling: Vec<&'a str>,
+}
+
+impl<'a> AttachedComments<'a> {
+    /// `tokens` must be in source order.
+    pub fn new(src: &str, tokens: &[Token], comments: &'a [Comment]) -> Self {
+        let mut out = Self::default();
+
+        for comment in comments {
+            let comment_start = comment.span.0;
+            let next_index = tokens.partition_point(|token| token.span().0 < comment_start);
+
+            let prev = next_index.checked_sub(1).map(|i| tokens[i].span());
+            if let Some(prev) = prev {
+                let gap = &src[prev.1 .0..comment_start.0];
+                if !gap.contains('\n') {
+                    out.trailing.insert(prev.0, &comment.text);
+                    continue;
+                }
+            }
+
+            match tokens.get(next_index) {
+                Some(next) => out
+                    .leading
+                    .entry(next.span().0)
+                    .or_default()
+                    .push(&comment.text),
+                None => out.dangling.push(&comment.text),
+            }
+        }
+
+        out
+    }
+
+    pub fn leading(&self, token_start: ByteIndex) -> &[&'a str] {
+        self.leading
+            .get(&token_start)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn trailing(&self, token_start: ByteIndex) -> Option<&'a str> {
+        self.trailing.get(&token_start).copied()
+    }
+
+    pub fn dangling(&self) -> &[&'a str] {
+        &self.dangling
+    }
+}
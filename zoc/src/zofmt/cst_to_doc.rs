@@ -0,0 +1,280 @@
+use super::*;
+
+pub(super) struct CstToDoc<'a> {
+    pub src: &'a str,
+    /// The tokens `src` was parsed from, in source order.
+    pub tokens: &'a [Token],
+    pub comments: &'a AttachedComments<'a>,
+}
+
+impl CstToDoc<'_> {
+    pub fn file(&self, expr: &cst::Expr) -> Doc {
+        let mut out = vec![self.expr(expr)];
+        for comment in self.comments.dangling() {
+            out.push(Doc::hard_line());
+            out.push(Doc::text(*comment));
+        }
+        Doc::Concat(out)
+    }
+
+    fn expr(&self, expr: &cst::Expr) -> Doc {
+        match expr {
+            cst::Expr::Ind(e) => self.ind(e),
+            cst::Expr::Vcon(e) => self.vcon(e),
+            cst::Expr::Match(e) => self.match_(e),
+            cst::Expr::Fun(e) => self.fun(e),
+            cst::Expr::App(e) => self.app(e),
+            cst::Expr::For(e) => self.for_(e),
+            cst::Expr::Deb(lit) => self.token(lit.span.0),
+            cst::Expr::Universe(lit) => self.token(lit.span.0),
+            cst::Expr::U64Lit(lit) => self.token(lit.span.0),
+            cst::Expr::StringLit(lit) => self.token(lit.span.0),
+            cst::Expr::Prim(lit) => self.token(lit.span.0),
+            cst::Expr::Meta(lit) => self.token(lit.span.0),
+            cst::Expr::Hole(start) => self.token(*start),
+            cst::Expr::Invalid(span) => self.token(span.0),
+        }
+    }
+
+    fn ind(&self, ind: &cst::Ind) -> Doc {
+        let tag = match &*ind.tag {
+            cst::OptString::None => None,
+            cst::OptString::Some(tag) => Some(self.token(tag.span.0)),
+        };
+        self.list(
+            ind.lparen,
+            [
+                self.token_after(ind.lparen),
+                self.token(ind.type_.span.0),
+                self.token(ind.name.span.0),
+            ]
+            .into_iter()
+            .chain(tag)
+            .chain([
+                self.list(
+                    ind.index_types_lparen,
+                    self.exprs(&ind.index_types),
+                    ind.index_types_rparen,
+                ),
+                self.list(
+                    ind.vcon_defs_lparen,
+                    self.vcon_defs(&ind.vcon_defs),
+                    ind.vcon_defs_rparen,
+                ),
+            ])
+            .collect(),
+            ind.rparen,
+        )
+    }
+
+    fn vcon_defs(&self, defs: &cst::ZeroOrMoreVconDefs) -> Vec<Doc> {
+        match defs {
+            cst::ZeroOrMoreVconDefs::Nil => vec![],
+            cst::ZeroOrMoreVconDefs::Snoc(rdc, rac) => {
+                let mut rdc = self.vcon_defs(rdc);
+                rdc.push(self.vcon_def(rac));
+                rdc
+            }
+        }
+    }
+
+    fn vcon_def(&self, def: &cst::VconDef) -> Doc {
+        let name = match &*def.name {
+            cst::OptString::None => None,
+            cst::OptString::Some(name) => Some(self.token(name.span.0)),
+        };
+        self.list(
+            def.lparen,
+            name.into_iter()
+                .chain([
+                    self.list(
+                        def.param_types_lparen,
+                        self.exprs(&def.param_types),
+                        def.param_types_rparen,
+                    ),
+                    self.list(
+                        def.index_args_lparen,
+                        self.exprs(&def.index_args),
+                        def.index_args_rparen,
+                    ),
+                ])
+                .collect(),
+            def.rparen,
+        )
+    }
+
+    fn vcon(&self, vcon: &cst::Vcon) -> Doc {
+        self.list(
+            vcon.lparen,
+            vec![
+                self.token_after(vcon.lparen),
+                self.ind(&vcon.ind),
+                self.token(match &*vcon.vcon_index {
+                    cst::VconIndexOrName::Index(index) => index.span.0,
+                    cst::VconIndexOrName::Name(name) => name.span.0,
+                }),
+            ],
+            vcon.rparen,
+        )
+    }
+
+    fn match_(&self, match_: &cst::Match) -> Doc {
+        self.list(
+            match_.lparen,
+            vec![
+                self.token_after(match_.lparen),
+                self.expr(&match_.matchee),
+                self.token(match_.return_type_arity.span.0),
+                self.expr(&match_.return_type),
+                self.list(
+                    match_.cases_lparen,
+                    self.match_cases(&match_.cases),
+                    match_.cases_rparen,
+                ),
+            ],
+            match_.rparen,
+        )
+    }
+
+    fn match_cases(&self, cases: &cst::ZeroOrMoreMatchCases) -> Vec<Doc> {
+        match cases {
+            cst::ZeroOrMoreMatchCases::Nil => vec![],
+            cst::ZeroOrMoreMatchCases::Snoc(rdc, rac) => {
+                let mut rdc = self.match_cases(rdc);
+                rdc.push(self.match_case(rac));
+                rdc
+            }
+        }
+    }
+
+    fn match_case(&self, case: &cst::MatchCase) -> Doc {
+        self.list(
+            case.lparen,
+            std::iter::once(self.token(case.arity.span.0))
+                .chain(match &*case.return_val {
+                    cst::OptExpr::None => None,
+                    cst::OptExpr::Some(return_val) => Some(self.expr(return_val)),
+                })
+                .collect(),
+            case.rparen,
+        )
+    }
+
+    fn fun(&self, fun: &cst::Fun) -> Doc {
+        let decreasing_index_start = match &*fun.decreasing_index {
+            cst::NumberOrNonrecKw::Number(lit) => lit.span.0,
+            cst::NumberOrNonrecKw::NonrecKw(start) => *start,
+        };
+        self.list(
+            fun.lparen,
+            vec![
+                self.token_after(fun.lparen),
+                self.token(decreasing_index_start),
+                self.list(
+                    fun.param_types_lparen,
+                    self.exprs(&fun.param_types),
+                    fun.param_types_rparen,
+                ),
+                self.expr(&fun.return_type),
+                self.expr(&fun.return_val),
+            ],
+            fun.rparen,
+        )
+    }
+
+    fn app(&self, app: &cst::App) -> Doc {
+        let mut items = vec![self.expr(&app.callee)];
+        items.extend(self.exprs(&app.args));
+        self.list(app.lparen, items, app.rparen)
+    }
+
+    fn for_(&self, for_: &cst::For) -> Doc {
+        self.list(
+            for_.lparen,
+            vec![
+                self.token_after(for_.lparen),
+                self.list(
+                    for_.param_types_lparen,
+                    self.exprs(&for_.param_types),
+                    for_.param_types_rparen,
+                ),
+                self.expr(&for_.return_type),
+            ],
+            for_.rparen,
+        )
+    }
+
+    fn exprs(&self, exprs: &cst::ZeroOrMoreExprs) -> Vec<Doc> {
+        match exprs {
+            cst::ZeroOrMoreExprs::Nil => vec![],
+            cst::ZeroOrMoreExprs::Snoc(rdc, rac) => {
+                let mut rdc = self.exprs(rdc);
+                rdc.push(self.expr(rac));
+                rdc
+            }
+        }
+    }
+
+    /// Returns a group like `Doc::parenthesized`,
+    /// but with the parentheses' comments.
+    fn list(&self, lparen: ByteIndex, mut items: Vec<Doc>, rparen: ByteIndex) -> Doc {
+        let mut out = self.leading_comments(lparen);
+
+        let mut group = vec![Doc::text("(")];
+        group.extend(self.trailing_comment(lparen));
+
+        // Comments before the right parenthesis
+        // are indented like the items.
+        for comment in self.comments.leading(rparen) {
+            items.push(Doc::Concat(vec![Doc::text(*comment), Doc::BreakParent]));
+        }
+        if !items.is_empty() {
+            group.push(Doc::Concat(vec![Doc::SoftLine, Doc::join(items, Doc::Line)]).nest());
+        }
+
+        group.push(Doc::SoftLine);
+        group.push(Doc::text(")"));
+        out.push(Doc::Concat(group).group());
+
+        out.extend(self.trailing_comment(rparen));
+        Doc::Concat(out)
+    }
+
+    fn token(&self, start: ByteIndex) -> Doc {
+        let span = self.tokens[self.get_token_index(start)].span();
+
+        let mut out = self.leading_comments(start);
+        out.push(Doc::text(&self.src[span.0 .0..span.1 .0]));
+        out.extend(self.trailing_comment(start));
+        Doc::Concat(out)
+    }
+
+    /// Returns the doc for the token after the token at `start`.
+    /// This is used for keywords, since the CST omits them.
+    fn token_after(&self, start: ByteIndex) -> Doc {
+        let next = &self.tokens[self.get_token_index(start) + 1];
+        self.token(next.span().0)
+    }
+
+    fn get_token_index(&self, start: ByteIndex) -> usize {
+        self.tokens
+            .binary_search_by_key(&start, |token| token.span().0)
+            .expect("every CST node should start at a token")
+    }
+
+    fn leading_comments(&self, token_start: ByteIndex) -> Vec<Doc> {
+        self.comments
+            .leading(token_start)
+            .iter()
+            .flat_map(|comment| [Doc::text(*comment), Doc::hard_line()])
+            .collect()
+    }
+
+    fn trailing_comment(&self, token_start: ByteIndex) -> Option<Doc> {
+        let comment = self.comments.trailing(token_start)?;
+        Some(Doc::Concat(vec![
+            Doc::text(format!(" {comment}")),
+            Doc::BreakParent,
+        ]))
+    }
+}
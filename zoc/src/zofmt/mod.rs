@@ -0,0 +1,76 @@
+//! A canonical formatter for textual Zo.
+//!
+//! The formatter lays out the CST with the same `Doc` engine
+//! the pretty printer uses, so formatted code looks like
+//! pretty printed code, except that:
+//!
+//! - `//` comments are preserved, and
+//! - literals are printed exactly as they were written.
+
+use crate::{
+    pretty_print::{Doc, DEFAULT_LINE_WIDTH},
+    syntax_tree::{
+        cst,
+        lexer::{lex_with_comments, LexError},
+        parser::parse,
+        token::*,
+    },
+};
+
+mod attach_comments;
+use attach_comments::*;
+
+mod cst_to_doc;
+use cst_to_doc::*;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FormatError {
+    Lex(LexError),
+    /// The token the parser choked on,
+    /// or `None` if the parser reached the end of the input.
+    Parse(Option<Token>),
+    /// Only returned by `check`.
+    NotFormatted {
+        formatted: String,
+    },
+}
+
+/// Returns the canonical formatting of `src`,
+/// trying to keep lines at most `width` characters wide.
+///
+/// The output always ends with a single newline.
+pub fn format(src: &str, width: usize) -> Result<String, FormatError> {
+    let (tokens, comments) = lex_with_comments(src).map_err(FormatError::Lex)?;
+    let cst = parse(tokens.iter().cloned()).map_err(FormatError::Parse)?;
+
+    let comments = AttachedComments::new(src, &tokens, &comments);
+    let doc = CstToDoc {
+        src,
+        tokens: &tokens,
+        comments: &comments,
+    }
+    .file(&cst);
+
+    let mut out = doc.pretty(width);
+    out.push('\n');
+    Ok(out)
+}
+
+/// Shorthand for `format(src, DEFAULT_LINE_WIDTH)`.
+pub fn format_with_default_width(src: &str) -> Result<String, FormatError> {
+    format(src, DEFAULT_LINE_WIDTH)
+}
+
+/// Returns `Err(FormatError::NotFormatted { .. })`
+/// if `src` is not already formatted.
+pub fn check(src: &str, width: usize) -> Result<(), FormatError> {
+    let formatted = format(src, width)?;
+    if formatted == src {
+        return Ok(());
+    }
+
+    Err(FormatError::NotFormatted { formatted })
+}
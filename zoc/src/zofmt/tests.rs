@@ -0,0 +1,91 @@
+use super::*;
+
+fn format_or_panic(src: &str, width: usize) -> String {
+    let formatted = format(src, width).unwrap();
+    assert_eq!(
+        Ok(()),
+        check(&formatted, width),
+        "formatting is not idempotent"
+    );
+    formatted
+}
+
+#[test]
+fn short_expression_stays_on_one_line() {
+    let src = "(fun   nonrec\n(U64)\n    U64 1)";
+    insta::assert_display_snapshot!(format_or_panic(src, DEFAULT_LINE_WIDTH));
+}
+
+#[test]
+fn long_expression_is_broken() {
+    let src = r#"(fun nonrec ((ind Set0 "Nat" () ((() ()) ((0) ())))) (ind Set0 "Nat" () ((() ()) ((0) ()))) (match 1 1 (ind Set0 "Nat" () ((() ()) ((0) ()))) ((0 (vcon (ind Set0 "Nat" () ((() ()) ((0) ()))) 0)) (1 0))))"#;
+    insta::assert_display_snapshot!(format_or_panic(src, DEFAULT_LINE_WIDTH));
+}
+
+#[test]
+fn narrow_width() {
+    let src = r#"(for (U64 U64) (ind Set0 "Bool" () ((() ()) (() ()))))"#;
+    insta::assert_display_snapshot!(format_or_panic(src, 20));
+}
+
+#[test]
+fn literals_are_kept_verbatim() {
+    let src = r#"("a  b" 3u64 _0 ? Prop0 Set1 u64_add)"#;
+    assert_eq!(
+        "(\"a  b\" 3u64 _0 ? Prop0 Set1 u64_add)\n",
+        format_or_panic(src, DEFAULT_LINE_WIDTH)
+    );
+}
+
+#[test]
+fn comments_are_kept() {
+    let src = r#"// Leading comment
+(fun nonrec // Trailing comment
+(U64) U64
+// Comment before an item
+(u64_add 0 // Trailing comment after an item
+ 1)
+    // Comment before a closing parenthesis
+) // Trailing comment after the last token
+// Comment at the end of the file
+"#;
+    insta::assert_display_snapshot!(format_or_panic(src, DEFAULT_LINE_WIDTH));
+}
+
+#[test]
+fn comment_forces_enclosing_groups_to_break() {
+    let src = "((0 // zero\n1) 2)";
+    insta::assert_display_snapshot!(format_or_panic(src, DEFAULT_LINE_WIDTH));
+}
+
+#[test]
+fn check_accepts_formatted_src() {
+    let src = "(u64_add 1u64 2u64)\n";
+    assert_eq!(Ok(()), check(src, DEFAULT_LINE_WIDTH));
+}
+
+#[test]
+fn check_rejects_unformatted_src() {
+    let src = "(u64_add  1u64 2u64)";
+    assert_eq!(
+        Err(FormatError::NotFormatted {
+            formatted: "(u64_add 1u64 2u64)\n".to_string(),
+        }),
+        check(src, DEFAULT_LINE_WIDTH)
+    );
+}
+
+#[test]
+fn lex_error() {
+    let src = "(u64_add 1u64 #)";
+    insta::assert_debug_snapshot!(format_with_default_width(src));
+}
+
+#[test]
+fn parse_error() {
+    let src = "(u64_add 1u64";
+    assert_eq!(
+        Err(FormatError::Parse(None)),
+        format_with_default_width(src)
+    );
+}